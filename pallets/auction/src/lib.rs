@@ -25,20 +25,31 @@
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
 
-use frame_support::traits::{Currency, ExistenceRequirement, LockableCurrency, ReservableCurrency};
+use frame_support::traits::{BalanceStatus, Currency, ExistenceRequirement, LockableCurrency, ReservableCurrency};
 use frame_support::{ensure, pallet_prelude::*, transactional};
-use frame_system::{self as system, ensure_signed};
+use frame_system::{self as system, ensure_root, ensure_signed};
 use sp_core::sp_std::convert::TryInto;
 use sp_runtime::SaturatedConversion;
 use sp_runtime::{
-	traits::{CheckedDiv, One, Saturating, Zero},
+	traits::{CheckedDiv, Hash, One, Saturating, Zero},
 	DispatchError, DispatchResult, Perbill,
 };
+use sp_std::vec::Vec;
 
-use auction_manager::{Auction, AuctionHandler, AuctionInfo, AuctionItem, AuctionType, Change, OnNewBidResult};
+use auction_manager::{
+	ActiveAuctionSummary, AssetOffer, Auction, AuctionHandler, AuctionInfo, AuctionItem, AuctionSummary, AuctionType,
+	BidIncrement, BidIncrementRule, BidderAllowlist, Change, CrossListingHandler, CrossListingV1, InstallmentPurchase,
+	LandBlockSale, ListingDurationLimits, ListingItemType, MarketplaceId, OnNewBidResult, PartnerMarketplaceInfo,
+	SwapProposal, VersionedCrossListing,
+};
+use core_primitives::InvariantMonitor;
 pub use pallet::*;
 use pallet_nft::Pallet as NFTModule;
-use primitives::{continuum::Continuum, estate::Estate, AuctionId, ItemId};
+use primitives::telemetry::{ActivityCounter, ActivityRecorder};
+use primitives::{
+	continuum::Continuum, estate::Estate, AuctionId, BundleId, ItemId, SwapId, UndeployedLandBlockId,
+	UndeployedLandBlockType,
+};
 pub use weights::WeightInfo;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -48,11 +59,20 @@ pub mod benchmarking;
 mod mock;
 #[cfg(test)]
 mod tests;
+#[cfg(test)]
+mod xcm_mock;
+#[cfg(test)]
+mod xcm_tests;
 
+mod streaming;
 pub mod weights;
+pub mod xcm_adapter;
 
 pub struct AuctionLogicHandler;
 
+/// Maximum number of auction ids scanned by a single active auctions query
+pub const MAX_ACTIVE_AUCTIONS_PAGE: u32 = 100;
+
 pub mod migration_v2 {
 	use codec::FullCodec;
 	use codec::{Decode, Encode};
@@ -106,7 +126,7 @@ pub mod pallet {
 	use orml_traits::{MultiCurrency, MultiReservableCurrency};
 
 	use auction_manager::{CheckAuctionItemHandler, ListingLevel};
-	use core_primitives::{MetaverseTrait, NFTTrait};
+	use core_primitives::{MetaverseTrait, NFTTrait, UndeployedLandBlocksTrait};
 	use primitives::{AssetId, Balance, ClassId, FungibleTokenId, MetaverseId, TokenId};
 
 	use crate::migration_v2::V1ItemId;
@@ -140,17 +160,97 @@ pub mod pallet {
 		>;
 		/// Metaverse info trait
 		type MetaverseInfoSource: MetaverseTrait<Self::AccountId>;
+		/// Minimum listing duration of item types without configured duration limits
 		#[pallet::constant]
 		type MinimumAuctionDuration: Get<Self::BlockNumber>;
 		/// Handle Estate logic
 		type EstateHandler: Estate<Self::AccountId>;
-		/// Loyalty fee in percentage applied NFT promotion
+		/// Network fee taken from the seller proceeds of every settled sale
+		#[pallet::constant]
+		type NetworkFee: Get<Perbill>;
+		/// Account receiving the network fee of settled sales
 		#[pallet::constant]
-		type RoyaltyFee: Get<u16>;
+		type NetworkFeeAccount: Get<Self::AccountId>;
+		/// Share of the network fee paid to the treasury of the metaverse the sold item lives in
+		#[pallet::constant]
+		type MetaverseFeeShare: Get<Perbill>;
 		#[pallet::constant]
 		type MaxFinality: Get<u32>;
 		/// NFT Handler
 		type NFTHandler: NFTTrait<Self::AccountId, BalanceOf<Self>, ClassId = ClassId, TokenId = TokenId>;
+		/// Period from the start of a land unit auction where only adjacent land owners can bid
+		#[pallet::constant]
+		type ExpansionRightPeriod: Get<Self::BlockNumber>;
+		/// Notify partner marketplaces on sibling chains about cross listed auctions
+		type CrossListingHandler: CrossListingHandler<Self::AccountId, BalanceOf<Self>, Self::BlockNumber>;
+		/// Origin of partner marketplaces submitting remote purchase intents, resolving to the
+		/// marketplace and its sovereign account paying for the purchases
+		type CrossChainOrigin: EnsureOrigin<Self::Origin, Success = (MarketplaceId, Self::AccountId)>;
+		/// Maximum number of items listed in a single bundle auction
+		#[pallet::constant]
+		type MaxBundleItems: Get<u32>;
+		/// Number of blocks in an archive era, settled auctions are archived per era
+		#[pallet::constant]
+		type ArchiveEraLength: Get<Self::BlockNumber>;
+		/// Number of blocks settled auctions are kept after their era ends before they can be pruned
+		#[pallet::constant]
+		type AuctionRetentionPeriod: Get<Self::BlockNumber>;
+		/// Share of the price paid upfront on installment purchases
+		#[pallet::constant]
+		type InstallmentDownPayment: Get<Perbill>;
+		/// Number of blocks between installments
+		#[pallet::constant]
+		type InstallmentPeriod: Get<Self::BlockNumber>;
+		/// Number of installments paying the price left after the down payment
+		#[pallet::constant]
+		type InstallmentCount: Get<u32>;
+		/// Share of escrowed payments forfeited to the seller when the buyer misses an installment
+		#[pallet::constant]
+		type InstallmentDefaultForfeit: Get<Perbill>;
+		/// Chain activity telemetry counting settled auctions
+		type ActivityRecorder: ActivityRecorder;
+		/// Deposit reserved with a sealed bid commitment, forfeited to the seller if the bid is not
+		/// revealed
+		#[pallet::constant]
+		type SealedBidDeposit: Get<BalanceOf<Self>>;
+		/// Number of blocks after sealed bid commitments close during which bids can be revealed
+		#[pallet::constant]
+		type SealedBidRevealPeriod: Get<Self::BlockNumber>;
+		/// Bids placed within this number of blocks before the auction end extend the auction
+		#[pallet::constant]
+		type AntiSnipeThreshold: Get<Self::BlockNumber>;
+		/// Number of blocks the auction end is extended by on a late bid
+		#[pallet::constant]
+		type AntiSnipeExtension: Get<Self::BlockNumber>;
+		/// Maximum number of times an auction end can be extended
+		#[pallet::constant]
+		type MaxAuctionExtensions: Get<u32>;
+		/// Maximum number of open offers on a single item
+		#[pallet::constant]
+		type MaxOffersPerItem: Get<u32>;
+		/// Share of the highest bid the seller pays to the highest bidder to cancel an auction
+		#[pallet::constant]
+		type AuctionCancellationPenalty: Get<Perbill>;
+		/// Maximum number of ended auctions settled per block, the rest wait for the next blocks
+		#[pallet::constant]
+		type MaxSettlementsPerBlock: Get<u32>;
+		/// Maximum number of accounts on an auction bidder allowlist
+		#[pallet::constant]
+		type MaxAuctionAllowlistSize: Get<u32>;
+		/// Issues undeployed land blocks to winners of land block auctions
+		type UndeployedLandBlockHandler: UndeployedLandBlocksTrait<Self::AccountId>;
+		/// Origin allowed to list new undeployed land blocks for auction
+		type LandSaleOrigin: EnsureOrigin<Self::Origin>;
+		/// Account receiving the proceeds of land block auctions
+		#[pallet::constant]
+		type LandSaleTreasury: Get<Self::AccountId>;
+		/// Maximum length of the salt revealed with a hidden reserve or sealed bid
+		#[pallet::constant]
+		type MaxRevealSaltLength: Get<u32>;
+		/// Share of the highest bid a new bid has to exceed it by when the auction has no bid
+		/// increment rule
+		#[pallet::constant]
+		type DefaultBidIncrement: Get<Perbill>;
 	}
 
 	#[pallet::storage]
@@ -187,6 +287,198 @@ pub mod pallet {
 	pub(super) type MetaverseCollection<T: Config> =
 		StorageDoubleMap<_, Twox64Concat, MetaverseId, Twox64Concat, ClassId, (), OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn partner_marketplaces)]
+	/// Partner marketplaces on sibling chains receiving cross listings
+	pub(super) type PartnerMarketplaces<T: Config> =
+		StorageMap<_, Twox64Concat, MarketplaceId, PartnerMarketplaceInfo, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn cross_listing_opt_in)]
+	/// Accounts opted in to mirror their listings to partner marketplaces
+	pub(super) type CrossListingOptIn<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn cross_listed_auctions)]
+	/// Auctions mirrored to partner marketplaces
+	pub(super) type CrossListedAuctions<T: Config> = StorageMap<_, Twox64Concat, AuctionId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn remote_purchase_intents)]
+	/// Purchase intents received from partner marketplaces per auction
+	pub(super) type RemotePurchaseIntents<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		AuctionId,
+		Twox64Concat,
+		MarketplaceId,
+		(T::AccountId, BalanceOf<T>),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn settlement_cursor)]
+	/// Earliest end block that may still have auctions waiting for settlement
+	pub(super) type SettlementCursor<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn bundles_index)]
+	/// Track the next bundle ID.
+	pub(super) type BundlesIndex<T: Config> = StorageValue<_, BundleId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_bundle)]
+	/// Items of bundles listed in auction
+	pub(super) type Bundles<T: Config> = StorageMap<_, Twox64Concat, BundleId, Vec<ItemId>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn land_block_sales_index)]
+	/// Track the next land block sale ID.
+	pub(super) type LandBlockSalesIndex<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn land_block_sale)]
+	/// Undeployed land blocks to issue to the winners of land block auctions
+	pub(super) type LandBlockSales<T: Config> = StorageMap<_, Twox64Concat, u64, LandBlockSale, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn swaps_index)]
+	/// Track the next swap ID.
+	pub(super) type SwapsIndex<T: Config> = StorageValue<_, SwapId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn swap_proposal)]
+	/// Open barter trades waiting for the counterparty to accept
+	pub(super) type SwapProposals<T: Config> =
+		StorageMap<_, Twox64Concat, SwapId, SwapProposal<T::AccountId, BalanceOf<T>, T::BlockNumber>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn settled_auctions)]
+	/// Summaries of settled auctions per archive era, kept until the era is pruned
+	pub(super) type SettledAuctions<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		Twox64Concat,
+		AuctionId,
+		AuctionSummary<T::AccountId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn archived_auction_roots)]
+	/// Merkle root and number of settled auction summaries of pruned archive eras
+	pub(super) type ArchivedAuctionRoots<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, (T::Hash, u32), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_archive_leaves)]
+	/// Leaves of settled auctions already pruned from an archive era which is still being pruned,
+	/// the merkle root is computed once the era has no settled auctions left
+	pub(super) type PendingArchiveLeaves<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, Vec<T::Hash>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn installment_purchases)]
+	/// Estates bought in installments, held by the marketplace until fully paid
+	pub(super) type InstallmentPurchases<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		AuctionId,
+		InstallmentPurchase<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn installment_deadlines)]
+	/// Index installment purchases by the block their next installment is due
+	pub(super) type InstallmentDeadlines<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, AuctionId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn installment_stream_cursor)]
+	/// Earliest installment deadline block that may still have installments waiting to be streamed
+	pub(super) type InstallmentStreamCursor<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn escrow_shortfall)]
+	/// Auction whose escrowed bid could not be fully released, pending the invariant monitor
+	pub(super) type EscrowShortfall<T: Config> = StorageValue<_, AuctionId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn listing_duration_limits)]
+	/// Listing duration constraints per item type, set by governance
+	pub(super) type ItemTypeDurationLimits<T: Config> =
+		StorageMap<_, Twox64Concat, ListingItemType, ListingDurationLimits<T::BlockNumber>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn dutch_auction_floor_price)]
+	/// Floor price a Dutch auction descends to by its end, the start price is the listed amount
+	pub(super) type DutchAuctionFloorPrices<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionId, BalanceOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn auction_buy_now_price)]
+	/// Instant purchase price attached to an auction by the seller
+	pub(super) type AuctionBuyNowPrices<T: Config> = StorageMap<_, Twox64Concat, AuctionId, BalanceOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn auction_extensions)]
+	/// Number of times the end of an auction was extended by late bids
+	pub(super) type AuctionExtensions<T: Config> = StorageMap<_, Twox64Concat, AuctionId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn sealed_bid_reveal_start)]
+	/// Block sealed bid commitments close and the reveal window of the auction opens
+	pub(super) type SealedBidRevealStart<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionId, T::BlockNumber, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn sealed_bid_commitment)]
+	/// Unrevealed sealed bid commitments and their reserved deposits
+	pub(super) type SealedBidCommitments<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, AuctionId, Twox64Concat, T::AccountId, (T::Hash, BalanceOf<T>), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn hidden_reserve)]
+	/// Hash of (reserve price, salt) committed by the seller when listing with a hidden reserve
+	pub(super) type HiddenReserves<T: Config> = StorageMap<_, Twox64Concat, AuctionId, T::Hash, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn revealed_reserve)]
+	/// Hidden reserve prices revealed by sellers ahead of settlement
+	pub(super) type RevealedReserves<T: Config> = StorageMap<_, Twox64Concat, AuctionId, BalanceOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn bid_increment_rule)]
+	/// Minimum bid increment rules set by sellers on their auctions
+	pub(super) type AuctionBidIncrements<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionId, BidIncrementRule<BalanceOf<T>>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn bidder_allowlist)]
+	/// Allowlists restricting which accounts can bid on gated auctions
+	pub(super) type AuctionBidderAllowlists<T: Config> =
+		StorageMap<_, Twox64Concat, AuctionId, BidderAllowlist<T::AccountId>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn offers)]
+	/// Offers escrowed by buyers against items that are not listed
+	pub(super) type Offers<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ItemId,
+		Twox64Concat,
+		T::AccountId,
+		AssetOffer<BalanceOf<T>, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn offer_count)]
+	/// Number of open offers per item
+	pub(super) type OfferCount<T: Config> = StorageMap<_, Twox64Concat, ItemId, u32, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -205,24 +497,123 @@ pub mod pallet {
 		AuctionFinalizedNoBid(AuctionId),
 		CollectionAuthorizedInMetaverse(ClassId, MetaverseId),
 		CollectionAuthorizationRemoveInMetaverse(ClassId, MetaverseId),
+		PartnerMarketplaceRegistered(MarketplaceId),
+		PartnerMarketplaceRemoved(MarketplaceId),
+		CrossListingPreferenceUpdated(T::AccountId, bool),
+		/// Auction Id, Notified Marketplace Ids
+		AuctionCrossListed(AuctionId, Vec<MarketplaceId>),
+		/// Auction Id, Marketplace Id, Remote Buyer, Amount
+		RemotePurchaseIntentReceived(AuctionId, MarketplaceId, T::AccountId, BalanceOf<T>),
+		/// Auction Id, Bundle Id, Bundle Items
+		NewAuctionBundle(AuctionId, BundleId, Vec<ItemId>),
+		/// Archive Era, Merkle Root, Number of Settled Auctions
+		SettledAuctionsArchived(T::BlockNumber, T::Hash, u32),
+		/// Auction Id, Bidder, Shortfall
+		EscrowShortfallDetected(AuctionId, T::AccountId, BalanceOf<T>),
+		/// Auction Id, Buyer, Down Payment
+		InstallmentPurchaseStarted(AuctionId, T::AccountId, BalanceOf<T>),
+		/// Auction Id, Buyer, Amount, Total Paid
+		InstallmentPaid(AuctionId, T::AccountId, BalanceOf<T>, BalanceOf<T>),
+		/// Auction Id, Buyer, Estate Id
+		InstallmentPurchaseCompleted(AuctionId, T::AccountId, EstateId),
+		/// Auction Id, Buyer, Forfeited Amount, Refunded Amount
+		InstallmentPurchaseDefaulted(AuctionId, T::AccountId, BalanceOf<T>, BalanceOf<T>),
+		/// Item Type, Duration Limits
+		ListingDurationLimitsUpdated(ListingItemType, Option<ListingDurationLimits<T::BlockNumber>>),
+		/// Auction Id, Start Price, Floor Price
+		NewDutchAuction(AuctionId, BalanceOf<T>, BalanceOf<T>),
+		/// Auction Id, New End Block
+		AuctionExtended(AuctionId, T::BlockNumber),
+		/// Auction Id, Listing Currency
+		AuctionCurrencySet(AuctionId, FungibleTokenId),
+		/// Auction Id, Seller Amount, Royalty, Network Fee
+		SaleProceedsSplit(AuctionId, BalanceOf<T>, BalanceOf<T>, BalanceOf<T>),
+		/// Metaverse Id, Fee Paid To Metaverse Treasury
+		MetaverseTreasuryFeePaid(MetaverseId, BalanceOf<T>),
+		/// Auction Id, Buy Now Price
+		AuctionBuyNowPriceUpdated(AuctionId, Option<BalanceOf<T>>),
+		/// Auction Id, Reveal Start
+		NewSealedBidAuction(AuctionId, T::BlockNumber),
+		/// Auction Id, Bidder
+		SealedBidCommitted(AuctionId, T::AccountId),
+		/// Auction Id, Bidder, Bid
+		SealedBidRevealed(AuctionId, T::AccountId, BalanceOf<T>),
+		/// Auction Id, Bidder, Forfeited Deposit
+		SealedBidForfeited(AuctionId, T::AccountId, BalanceOf<T>),
+		/// Item Id, Buyer, Offer Amount, Expiry Block
+		OfferMade(ItemId, T::AccountId, BalanceOf<T>, T::BlockNumber),
+		/// Item Id, Buyer, Seller, Offer Amount
+		OfferAccepted(ItemId, T::AccountId, T::AccountId, BalanceOf<T>),
+		/// Item Id, Buyer
+		OfferWithdrawn(ItemId, T::AccountId),
+		/// Auction Id, Penalty Paid To Highest Bidder
+		AuctionCancelled(AuctionId, BalanceOf<T>),
+		/// Auction Id
+		HiddenReserveSet(AuctionId),
+		/// Auction Id, Reserve Price
+		HiddenReserveRevealed(AuctionId, BalanceOf<T>),
+		/// Auction Id, Revealed Reserve Price
+		HiddenReserveNotMet(AuctionId, Option<BalanceOf<T>>),
+		/// Auction Id, Bid Increment Rule
+		BidIncrementRuleUpdated(AuctionId, Option<BidIncrementRule<BalanceOf<T>>>),
+		/// Auction Id, Bidder Allowlist
+		BidderAllowlistUpdated(AuctionId, Option<BidderAllowlist<T::AccountId>>),
+		/// Auction Id, Land Block Sale Id, Number Of Land Units
+		NewLandBlockAuction(AuctionId, u64, u32),
+		/// Auction Id, Winner, Issued Undeployed Land Block Id
+		LandBlockAuctionSettled(AuctionId, T::AccountId, UndeployedLandBlockId),
+		/// Swap Id, Proposer, Counterparty
+		SwapProposed(SwapId, T::AccountId, T::AccountId),
+		/// Swap Id
+		SwapAccepted(SwapId),
+		/// Swap Id
+		SwapCancelled(SwapId),
+		/// Archive Era, Number of Settled Auctions Pruned, Total Pruned from the Era so far
+		SettledAuctionsPruned(T::BlockNumber, u32, u32),
+		/// Auction Id, Buyer, Refunded Amount
+		InstallmentPurchaseRefunded(AuctionId, T::AccountId, BalanceOf<T>),
+		/// Auction Id, Penalty Paid To Highest Bidder For An Unrevealed Reserve
+		UnrevealedReservePenaltyPaid(AuctionId, BalanceOf<T>),
+		/// Auction Id, Refunded Winner, Refunded Amount, Issuance Error
+		LandBlockIssuanceFailed(AuctionId, T::AccountId, BalanceOf<T>, DispatchError),
+		/// Auction Id, Refunded Buyer, Refunded Amount, Handover Error
+		SaleHandoverFailed(AuctionId, T::AccountId, BalanceOf<T>, DispatchError),
 	}
 
 	/// Errors inform users that something went wrong.
 	#[pallet::error]
 	pub enum Error<T> {
+		/// Auction does not exist
 		AuctionNotExist,
+		/// Asset does not exist
 		AssetIsNotExist,
+		/// Auction has not started yet
 		AuctionNotStarted,
+		/// Auction has already ended
 		AuctionIsExpired,
+		/// Auction type is not supported for this operation
 		AuctionTypeIsNotSupported,
+		/// Bid is not accepted by the auction or bidder is not allowed to bid
 		BidNotAccepted,
+		/// Bidder does not have enough free balance
 		InsufficientFreeBalance,
+		/// Bid is lower than the minimum or current highest bid
 		InvalidBidPrice,
+		/// No available auction id
 		NoAvailableAuctionId,
-		NoPermissionToCreateAuction,
+		/// Only NFTs can be listed with this call
+		OnlyNftCanBeListed,
+		/// Listing account is not the owner of the item
+		NotItemOwner,
+		/// Item is bound to its owner and can not be sold
+		ItemNotTransferable,
+		/// Owner can not bid on own auction
 		SelfBidNotAccepted,
+		/// Owner can not buy own listing
 		CannotBidOnOwnAuction,
+		/// Buy now price does not match the listing price
 		InvalidBuyItNowPrice,
+		/// Buyer does not have enough funds
 		InsufficientFunds,
 		/// Invalid auction type
 		InvalidAuctionType,
@@ -234,16 +625,122 @@ pub mod pallet {
 		FungibleTokenCurrencyNotFound,
 		/// Minimum Duration Is Too Low
 		AuctionEndIsLessThanMinimumDuration,
+		/// Listing runs longer than the maximum duration of the item type
+		AuctionEndExceedsMaximumDuration,
+		/// Minimum duration is higher than the maximum duration
+		InvalidListingDurationLimits,
 		/// Overflow
 		Overflow,
+		/// Estate does not exist
 		EstateDoesNotExist,
+		/// Land unit does not exist
 		LandUnitDoesNotExist,
+		/// Estate is frozen by governance
+		EstateIsFrozen,
 		/// User has no permission to authorise collection
 		NoPermissionToAuthoriseCollection,
 		/// Collection has already authorised
 		CollectionAlreadyAuthorised,
 		/// Collection is not authorised
 		CollectionIsNotAuthorised,
+		/// Only adjacent land owners can bid during expansion right period
+		ExpansionRightPeriodActive,
+		/// Partner marketplace already registered
+		PartnerMarketplaceAlreadyRegistered,
+		/// Partner marketplace is not registered
+		PartnerMarketplaceNotRegistered,
+		/// Auction is not cross listed
+		AuctionNotCrossListed,
+		/// Bundle has no items
+		EmptyBundle,
+		/// Bundle exceeds maximum number of items
+		ExceedMaximumBundleItems,
+		/// Item can not be listed in bundle
+		InvalidBundleItem,
+		/// Bundle is not exist
+		BundleDoesNotExist,
+		/// No available bundle id
+		NoAvailableBundleId,
+		/// Archive era is still within the retention period
+		ArchiveRetentionPeriodNotPassed,
+		/// Archive era has no settled auctions
+		NoSettledAuctionsInEra,
+		/// Archive era has more settled auctions than the given maximum
+		TooManySettledAuctions,
+		/// Only estate listings can be bought in installments
+		OnlyEstateSupportsInstallments,
+		/// Installment purchase does not exist
+		InstallmentPurchaseNotFound,
+		/// Only the buyer can pay installments
+		NotInstallmentBuyer,
+		/// Dutch auction floor price is higher than the start price
+		InvalidDutchAuctionPrice,
+		/// Only the seller can update the listing
+		NoPermissionToUpdateListing,
+		/// Buy now price must be above the reserve price and the highest bid
+		InvalidAuctionBuyNowPrice,
+		/// Only NFTs and estates can be listed in sealed bid auctions
+		SealedBidItemNotSupported,
+		/// Sealed bid commitments are closed
+		SealedBidCommitmentClosed,
+		/// Bidder already committed a sealed bid
+		SealedBidAlreadyCommitted,
+		/// Sealed bid reveal window is not open
+		SealedBidRevealNotOpen,
+		/// Sealed bid commitment does not exist
+		SealedBidCommitmentNotFound,
+		/// Revealed bid does not match the commitment
+		InvalidSealedBidReveal,
+		/// Only NFTs and estates accept offers
+		OfferItemNotSupported,
+		/// Owner can not make an offer on own item
+		CannotOfferOnOwnItem,
+		/// Offer expiry block has already passed
+		InvalidOfferExpiry,
+		/// Item has reached the maximum number of open offers
+		OfferBookIsFull,
+		/// Offer does not exist
+		OfferNotFound,
+		/// Offer has expired
+		OfferIsExpired,
+		/// Only NFTs and estates can be listed with a hidden reserve
+		HiddenReserveItemNotSupported,
+		/// Auction has no hidden reserve
+		HiddenReserveNotFound,
+		/// Revealed reserve does not match the committed hash
+		InvalidHiddenReserveReveal,
+		/// Bid increment rule cannot be changed once the auction has a bid
+		BidIncrementRuleLocked,
+		/// Bid does not exceed the highest bid by the minimum bid increment
+		BidBelowMinimumIncrement,
+		/// Bidder allowlist cannot be changed once the auction has a bid
+		BidderAllowlistLocked,
+		/// Bidder allowlist has more accounts than allowed
+		BidderAllowlistTooLarge,
+		/// Account is not allowed to bid on this auction
+		BidderNotAllowlisted,
+		/// Account does not meet the land buyer gate of the metaverse
+		LandBuyerNotAllowed,
+		/// Land block auctions need at least one land unit
+		InvalidLandBlockSize,
+		/// No available land block sale id
+		NoAvailableLandBlockSaleId,
+		/// No available swap id
+		NoAvailableSwapId,
+		/// Both sides of a swap need to give items or tokens
+		InvalidSwap,
+		/// Swap does not exist
+		SwapNotFound,
+		/// Swap expiry block has passed
+		SwapIsExpired,
+		/// Only the swap proposer or counterparty can do this
+		NoPermissionToUpdateSwap,
+		/// Only NFTs, estates and land units can be listed in a Dutch auction
+		DutchAuctionItemNotSupported,
+		/// Revealed salt exceeds the maximum length
+		RevealSaltTooLong,
+		/// Estate owes idle land tax
+		LandTaxOutstanding,
 	}
 
 	#[pallet::call]
@@ -254,152 +751,91 @@ pub mod pallet {
 		pub fn bid(origin: OriginFor<T>, id: AuctionId, value: BalanceOf<T>) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
 
-			let auction_item: AuctionItem<T::AccountId, T::BlockNumber, BalanceOf<T>> =
-				Self::get_auction_item(id.clone()).ok_or(Error::<T>::AuctionNotExist)?;
-			ensure!(
-				auction_item.auction_type == AuctionType::Auction,
-				Error::<T>::InvalidAuctionType
-			);
-			ensure!(auction_item.recipient != from, Error::<T>::SelfBidNotAccepted);
-
-			<Auctions<T>>::try_mutate_exists(id, |auction| -> DispatchResult {
-				let mut auction = auction.as_mut().ok_or(Error::<T>::AuctionNotExist)?;
-
-				let block_number = <system::Pallet<T>>::block_number();
-
-				// make sure auction is started
-				ensure!(block_number >= auction.start, Error::<T>::AuctionNotStarted);
-
-				let auction_end: Option<T::BlockNumber> = auction.end;
-
-				ensure!(block_number < auction_end.unwrap(), Error::<T>::AuctionIsExpired);
-
-				if let Some(ref current_bid) = auction.bid {
-					ensure!(value > current_bid.1, Error::<T>::InvalidBidPrice);
-				} else {
-					ensure!(!value.is_zero(), Error::<T>::InvalidBidPrice);
-				}
-				// implement hooks for future event
-				let bid_result = T::Handler::on_new_bid(block_number, id, (from.clone(), value), auction.bid.clone());
-
-				ensure!(bid_result.accept_bid, Error::<T>::BidNotAccepted);
-
-				ensure!(
-					<T as Config>::Currency::free_balance(&from) >= value,
-					Error::<T>::InsufficientFreeBalance
-				);
+			Self::do_bid(from, id, value)?;
 
-				Self::auction_bid_handler(block_number, id, (from.clone(), value), auction.bid.clone())?;
+			Ok(().into())
+		}
 
-				auction.bid = Some((from.clone(), value));
-				Self::deposit_event(Event::Bid(id, from, value));
+		/// User can buy now on listing
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn buy_now(origin: OriginFor<T>, auction_id: AuctionId, value: BalanceOf<T>) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
 
-				Ok(())
-			})?;
+			Self::do_buy_now(from, auction_id, value)?;
 
 			Ok(().into())
 		}
 
-		/// User can buy now on listing
+		/// Buy an estate listing with a down payment, the rest of the price is paid in
+		/// installments while the estate is held by the marketplace
 		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		pub fn buy_now(origin: OriginFor<T>, auction_id: AuctionId, value: BalanceOf<T>) -> DispatchResultWithPostInfo {
+		#[transactional]
+		pub fn buy_now_with_installments(origin: OriginFor<T>, auction_id: AuctionId) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
 
-			let auction = Self::auctions(auction_id.clone()).ok_or(Error::<T>::AuctionNotExist)?;
-			let auction_item = Self::get_auction_item(auction_id.clone()).ok_or(Error::<T>::AuctionNotExist)?;
+			let auction = Self::auctions(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			let auction_item = Self::get_auction_item(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
 
 			ensure!(
 				auction_item.auction_type == AuctionType::BuyNow,
 				Error::<T>::InvalidAuctionType
 			);
-
+			let estate_id = match auction_item.item_id {
+				ItemId::Estate(estate_id) => estate_id,
+				_ => return Err(Error::<T>::OnlyEstateSupportsInstallments.into()),
+			};
+			ensure!(
+				!T::EstateHandler::is_estate_frozen(estate_id),
+				Error::<T>::EstateIsFrozen
+			);
 			ensure!(auction_item.recipient != from, Error::<T>::CannotBidOnOwnAuction);
+			Self::ensure_expansion_right(&auction_item, &from)?;
+			Self::ensure_allowlisted_bidder(auction_id, &from)?;
+			Self::ensure_land_buyer_allowed(&auction_item.item_id, &from)?;
 
 			let block_number = <system::Pallet<T>>::block_number();
 			ensure!(block_number >= auction.start, Error::<T>::AuctionNotStarted);
-			if !(auction.end.is_none()) {
-				let auction_end: T::BlockNumber = auction.end.unwrap();
+			if let Some(auction_end) = auction.end {
 				ensure!(block_number < auction_end, Error::<T>::AuctionIsExpired);
 			}
 
-			ensure!(value == auction_item.amount, Error::<T>::InvalidBuyItNowPrice);
-			ensure!(
-				<T as Config>::Currency::free_balance(&from) >= value,
-				Error::<T>::InsufficientFunds
-			);
+			let price = auction_item.amount;
+			let down_payment = T::InstallmentDownPayment::get() * price;
+			<T as Config>::Currency::reserve(&from, down_payment).map_err(|_| Error::<T>::InsufficientFunds)?;
+
+			// Estate stays marked as in auction so the seller can not move it while it is escrowed
+			Self::remove_auction(auction_id, auction_item.item_id);
+			<ItemsInAuction<T>>::insert(auction_item.item_id, true);
+
+			let installment_count: BalanceOf<T> = T::InstallmentCount::get().max(1).into();
+			let purchase = InstallmentPurchase {
+				buyer: from.clone(),
+				seller: auction_item.recipient,
+				estate_id,
+				price,
+				paid: down_payment,
+				installment: price.saturating_sub(down_payment) / installment_count,
+				// First installment falls due one period from now
+				next_due: block_number,
+			};
+			Self::deposit_event(Event::InstallmentPurchaseStarted(auction_id, from, down_payment));
+			Self::continue_installment_purchase(auction_id, purchase);
 
-			Self::remove_auction(auction_id.clone(), auction_item.item_id);
+			Ok(().into())
+		}
 
-			// Transfer balance from buy it now user to asset owner
-			let currency_transfer = <T as Config>::Currency::transfer(
-				&from,
-				&auction_item.recipient,
-				value,
-				ExistenceRequirement::KeepAlive,
-			);
-			match currency_transfer {
-				Err(_e) => {}
-				Ok(_v) => {
-					// Transfer asset from asset owner to buy it now user
-					<ItemsInAuction<T>>::remove(auction_item.item_id);
-					match auction_item.item_id {
-						ItemId::NFT(class_id, token_id) => {
-							Self::collect_royalty_fee(
-								&value,
-								&auction_item.recipient,
-								&(class_id, token_id),
-								FungibleTokenId::NativeToken(0),
-							);
+		/// Pay the next installment of an estate bought in installments, the estate is
+		/// transferred to the buyer once the full price is paid
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		#[transactional]
+		pub fn pay_installment(origin: OriginFor<T>, auction_id: AuctionId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let purchase = Self::installment_purchases(auction_id).ok_or(Error::<T>::InstallmentPurchaseNotFound)?;
+			ensure!(purchase.buyer == who, Error::<T>::NotInstallmentBuyer);
+
+			Self::stream_installment(auction_id, purchase).map_err(|_| Error::<T>::InsufficientFunds)?;
 
-							let asset_transfer =
-								T::NFTHandler::transfer_nft(&auction_item.recipient, &from, &(class_id, token_id));
-							match asset_transfer {
-								Err(_) => (),
-								Ok(_) => {
-									Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
-								}
-							}
-						}
-						ItemId::Spot(spot_id, metaverse_id) => {
-							let continuum_spot = T::ContinuumHandler::transfer_spot(
-								spot_id,
-								&auction_item.recipient,
-								&(from.clone(), metaverse_id),
-							);
-							match continuum_spot {
-								Err(_) => (),
-								Ok(_) => {
-									Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
-								}
-							}
-						}
-						ItemId::Estate(estate_id) => {
-							let estate =
-								T::EstateHandler::transfer_estate(estate_id, &auction_item.recipient, &from.clone());
-							match estate {
-								Err(_) => (),
-								Ok(_) => {
-									Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
-								}
-							}
-						}
-						ItemId::LandUnit(coordinate, metaverse_id) => {
-							let land_unit = T::EstateHandler::transfer_landunit(
-								coordinate,
-								&auction_item.recipient,
-								&(from.clone(), metaverse_id),
-							);
-							match land_unit {
-								Err(_) => (),
-								Ok(_) => {
-									Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
-								}
-							}
-						}
-						_ => {} // Future implementation for Land, Metaverse
-					}
-				}
-			}
 			Ok(().into())
 		}
 
@@ -413,21 +849,11 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
 
-			ensure!(
-				matches!(item_id, ItemId::NFT(_, _)),
-				Error::<T>::NoPermissionToCreateAuction
-			);
+			ensure!(matches!(item_id, ItemId::NFT(_, _)), Error::<T>::OnlyNftCanBeListed);
 
 			let start_time: T::BlockNumber = <system::Pallet<T>>::block_number();
 
-			let remaining_time: T::BlockNumber = end_time.checked_sub(&start_time).ok_or(Error::<T>::Overflow)?;
-
-			ensure!(
-				remaining_time >= T::MinimumAuctionDuration::get(),
-				Error::<T>::AuctionEndIsLessThanMinimumDuration
-			);
-
-			Self::create_auction(
+			let auction_id = Self::create_auction(
 				AuctionType::Auction,
 				item_id,
 				Some(end_time),
@@ -436,6 +862,8 @@ pub mod pallet {
 				start_time,
 				listing_level.clone(),
 			)?;
+			Self::cross_list_auction(auction_id);
+
 			Ok(().into())
 		}
 
@@ -448,20 +876,10 @@ pub mod pallet {
 			listing_level: ListingLevel<T::AccountId>,
 		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
-			ensure!(
-				matches!(item_id, ItemId::NFT(_, _)),
-				Error::<T>::NoPermissionToCreateAuction
-			);
+			ensure!(matches!(item_id, ItemId::NFT(_, _)), Error::<T>::OnlyNftCanBeListed);
 
 			let start_time: T::BlockNumber = <system::Pallet<T>>::block_number();
-			let remaining_time: T::BlockNumber = end_time.checked_sub(&start_time).ok_or(Error::<T>::Overflow)?;
-
-			ensure!(
-				remaining_time >= T::MinimumAuctionDuration::get(),
-				Error::<T>::AuctionEndIsLessThanMinimumDuration
-			);
-
-			Self::create_auction(
+			let auction_id = Self::create_auction(
 				AuctionType::BuyNow,
 				item_id,
 				Some(end_time),
@@ -470,551 +888,2557 @@ pub mod pallet {
 				start_time,
 				listing_level.clone(),
 			)?;
+			Self::cross_list_auction(auction_id);
 
 			Ok(().into())
 		}
 
+		/// List an NFT, estate or land unit in a Dutch auction, the price descends linearly from the
+		/// start price to the floor price at the end of the listing
 		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		pub fn authorise_metaverse_collection(
+		#[transactional]
+		pub fn create_new_dutch_auction(
 			origin: OriginFor<T>,
-			class_id: ClassId,
-			metaverse_id: MetaverseId,
+			item_id: ItemId,
+			start_price: BalanceOf<T>,
+			floor_price: BalanceOf<T>,
+			end_time: T::BlockNumber,
+			listing_level: ListingLevel<T::AccountId>,
 		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
-			ensure!(
-				T::MetaverseInfoSource::check_ownership(&from, &metaverse_id),
-				Error::<T>::NoPermissionToAuthoriseCollection
-			);
-
-			ensure!(
-				!MetaverseCollection::<T>::contains_key(metaverse_id, class_id),
-				Error::<T>::CollectionAlreadyAuthorised
-			);
-
-			MetaverseCollection::<T>::insert(metaverse_id.clone(), class_id.clone(), ());
+			match item_id {
+				ItemId::NFT(_, _) => {}
+				ItemId::Estate(estate_id) => ensure!(
+					T::EstateHandler::check_estate_ownership(&from, estate_id)?,
+					Error::<T>::NotItemOwner
+				),
+				ItemId::LandUnit(coordinate, metaverse_id) => ensure!(
+					T::EstateHandler::check_landunit_ownership(&from, metaverse_id, coordinate)?,
+					Error::<T>::NotItemOwner
+				),
+				_ => return Err(Error::<T>::DutchAuctionItemNotSupported.into()),
+			}
+			ensure!(floor_price <= start_price, Error::<T>::InvalidDutchAuctionPrice);
 
-			Self::deposit_event(Event::<T>::CollectionAuthorizedInMetaverse(class_id, metaverse_id));
+			let start_time: T::BlockNumber = <system::Pallet<T>>::block_number();
+			let auction_id = Self::create_auction(
+				AuctionType::Dutch,
+				item_id,
+				Some(end_time),
+				from.clone(),
+				start_price,
+				start_time,
+				listing_level,
+			)?;
+			Self::record_estate_owner_activity(&from, &[item_id]);
+			DutchAuctionFloorPrices::<T>::insert(auction_id, floor_price);
+			Self::deposit_event(Event::<T>::NewDutchAuction(auction_id, start_price, floor_price));
+			Self::cross_list_auction(auction_id);
 
 			Ok(().into())
 		}
 
+		/// List an NFT in an auction or buy now listing denominated in any currency with issuance,
+		/// bids, settlement and royalty are paid in the listing currency
 		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		pub fn remove_authorise_metaverse_collection(
+		#[transactional]
+		pub fn create_new_auction_in_currency(
 			origin: OriginFor<T>,
-			class_id: ClassId,
-			metaverse_id: MetaverseId,
+			auction_type: AuctionType,
+			item_id: ItemId,
+			currency_id: FungibleTokenId,
+			value: BalanceOf<T>,
+			end_time: T::BlockNumber,
+			listing_level: ListingLevel<T::AccountId>,
 		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
+			ensure!(matches!(item_id, ItemId::NFT(_, _)), Error::<T>::OnlyNftCanBeListed);
 			ensure!(
-				T::MetaverseInfoSource::check_ownership(&from, &metaverse_id),
-				Error::<T>::NoPermissionToAuthoriseCollection
+				auction_type == AuctionType::Auction || auction_type == AuctionType::BuyNow,
+				Error::<T>::InvalidAuctionType
 			);
-
 			ensure!(
-				MetaverseCollection::<T>::contains_key(metaverse_id, class_id),
-				Error::<T>::CollectionIsNotAuthorised
+				currency_id == FungibleTokenId::NativeToken(0)
+					|| !T::FungibleTokenCurrency::total_issuance(currency_id).is_zero(),
+				Error::<T>::FungibleTokenCurrencyNotFound
 			);
 
-			MetaverseCollection::<T>::remove(metaverse_id.clone(), class_id.clone());
-			Self::deposit_event(Event::<T>::CollectionAuthorizationRemoveInMetaverse(
-				class_id,
-				metaverse_id,
-			));
+			let start_time: T::BlockNumber = <system::Pallet<T>>::block_number();
+			let auction_id = Self::create_auction(
+				auction_type,
+				item_id,
+				Some(end_time),
+				from,
+				value,
+				start_time,
+				listing_level,
+			)?;
+			<AuctionItems<T>>::mutate(auction_id, |auction_item| {
+				if let Some(auction_item) = auction_item {
+					auction_item.currency_id = currency_id;
+				}
+			});
+			Self::deposit_event(Event::<T>::AuctionCurrencySet(auction_id, currency_id));
+			Self::cross_list_auction(auction_id);
+
 			Ok(().into())
 		}
-	}
 
-	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
-		fn on_finalize(now: T::BlockNumber) {
-			for (auction_id, _) in <AuctionEndTime<T>>::drain_prefix(&now) {
-				if let Some(auction) = <Auctions<T>>::get(&auction_id) {
-					if let Some(auction_item) = <AuctionItems<T>>::get(&auction_id) {
-						Self::remove_auction(auction_id.clone(), auction_item.item_id);
-						// Transfer balance from high bidder to asset owner
-						if let Some(current_bid) = auction.bid {
-							let (high_bidder, high_bid_price): (T::AccountId, BalanceOf<T>) = current_bid;
-							// Handle listing
-							<T as Config>::Currency::unreserve(&high_bidder, high_bid_price);
-
-							// Handle balance transfer
-							let currency_transfer = <T as Config>::Currency::transfer(
-								&high_bidder,
-								&auction_item.recipient,
-								high_bid_price,
-								ExistenceRequirement::KeepAlive,
-							);
+		/// List an NFT or estate in an auction with a hidden reserve committed as the hash of
+		/// (reserve price, salt). The seller reveals the reserve before the auction ends, the highest
+		/// bid only wins if it meets the revealed reserve.
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(2))]
+		#[transactional]
+		pub fn create_new_auction_with_hidden_reserve(
+			origin: OriginFor<T>,
+			item_id: ItemId,
+			value: BalanceOf<T>,
+			end_time: T::BlockNumber,
+			listing_level: ListingLevel<T::AccountId>,
+			reserve_hash: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			match item_id {
+				ItemId::NFT(_, _) => {}
+				ItemId::Estate(estate_id) => ensure!(
+					T::EstateHandler::check_estate_ownership(&from, estate_id)?,
+					Error::<T>::NotItemOwner
+				),
+				_ => return Err(Error::<T>::HiddenReserveItemNotSupported.into()),
+			}
 
-							match currency_transfer {
-								Err(_e) => continue,
-								Ok(_v) => {
-									// Transfer asset from asset owner to high bidder
-									// Check asset type and handle internal logic
-
-									match auction_item.item_id {
-										ItemId::NFT(class_id, token_id) => {
-											Self::collect_royalty_fee(
-												&high_bid_price,
-												&auction_item.recipient,
-												&(class_id, token_id),
-												FungibleTokenId::NativeToken(0),
-											);
-											let asset_transfer = T::NFTHandler::transfer_nft(
-												&auction_item.recipient,
-												&high_bidder,
-												&(class_id, token_id),
-											);
+			let start_time: T::BlockNumber = <system::Pallet<T>>::block_number();
+			let auction_id = Self::create_auction(
+				AuctionType::Auction,
+				item_id,
+				Some(end_time),
+				from.clone(),
+				value,
+				start_time,
+				listing_level,
+			)?;
+			Self::record_estate_owner_activity(&from, &[item_id]);
+			HiddenReserves::<T>::insert(auction_id, reserve_hash);
+			Self::deposit_event(Event::<T>::HiddenReserveSet(auction_id));
+			Self::cross_list_auction(auction_id);
 
-											match asset_transfer {
-												Err(_) => continue,
-												Ok(_) => {
-													Self::deposit_event(Event::AuctionFinalized(
-														auction_id,
-														high_bidder,
-														high_bid_price,
-													));
-												}
-											}
-										}
-										ItemId::Spot(spot_id, metaverse_id) => {
-											let continuum_spot = T::ContinuumHandler::transfer_spot(
-												spot_id,
-												&auction_item.recipient,
-												&(high_bidder.clone(), metaverse_id),
-											);
-											match continuum_spot {
-												Err(_) => continue,
-												Ok(_) => {
-													Self::deposit_event(Event::AuctionFinalized(
-														auction_id,
-														high_bidder,
-														high_bid_price,
-													));
-												}
-											}
-										}
-										ItemId::Estate(estate_id) => {
-											let estate = T::EstateHandler::transfer_estate(
-												estate_id,
-												&auction_item.recipient,
-												&high_bidder.clone(),
-											);
-											match estate {
-												Err(_) => (),
-												Ok(_) => {
-													Self::deposit_event(Event::AuctionFinalized(
-														auction_id,
-														high_bidder,
-														high_bid_price,
-													));
-												}
-											}
-										}
-										ItemId::LandUnit(coordinate, metaverse_id) => {
-											let land_unit = T::EstateHandler::transfer_landunit(
-												coordinate,
-												&auction_item.recipient,
-												&(high_bidder.clone(), metaverse_id),
-											);
-											match land_unit {
-												Err(_) => (),
-												Ok(_) => {
-													Self::deposit_event(Event::AuctionFinalized(
-														auction_id,
-														high_bidder,
-														high_bid_price,
-													));
-												}
-											}
-										}
-										_ => {} // Future implementation for Spot, Metaverse
-									}
-									<ItemsInAuction<T>>::remove(auction_item.item_id);
-								}
-							}
-						} else {
-							Self::deposit_event(Event::AuctionFinalizedNoBid(auction_id));
-						}
-					}
-				};
-			}
+			Ok(().into())
 		}
-	}
 
-	impl<T: Config> Auction<T::AccountId, T::BlockNumber> for Pallet<T> {
-		type Balance = BalanceOf<T>;
+		/// Seller reveals the hidden reserve of an auction, unrevealed reserves are not met at
+		/// settlement
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn reveal_hidden_reserve(
+			origin: OriginFor<T>,
+			auction_id: AuctionId,
+			reserve: BalanceOf<T>,
+			salt: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			ensure!(
+				salt.len() as u32 <= T::MaxRevealSaltLength::get(),
+				Error::<T>::RevealSaltTooLong
+			);
 
-		fn update_auction(
-			id: AuctionId,
-			info: AuctionInfo<T::AccountId, Self::Balance, T::BlockNumber>,
-		) -> DispatchResult {
-			let auction = <Auctions<T>>::get(id).ok_or(Error::<T>::AuctionNotExist)?;
-			if let Some(old_end) = auction.end {
-				<AuctionEndTime<T>>::remove(&old_end, id);
-			}
-			if let Some(new_end) = info.end {
-				<AuctionEndTime<T>>::insert(&new_end, id, ());
-			}
-			<Auctions<T>>::insert(id, info);
-			Ok(())
-		}
+			let auction_item = Self::get_auction_item(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			ensure!(Self::auctions(auction_id).is_some(), Error::<T>::AuctionNotExist);
+			ensure!(auction_item.recipient == from, Error::<T>::NoPermissionToUpdateListing);
+			let reserve_hash = Self::hidden_reserve(auction_id).ok_or(Error::<T>::HiddenReserveNotFound)?;
+			ensure!(
+				T::Hashing::hash_of(&(reserve, salt)) == reserve_hash,
+				Error::<T>::InvalidHiddenReserveReveal
+			);
 
-		fn new_auction(
-			_recipient: T::AccountId,
-			_initial_amount: Self::Balance,
-			start: T::BlockNumber,
-			end: Option<T::BlockNumber>,
-		) -> Result<AuctionId, DispatchError> {
-			let auction: AuctionInfo<T::AccountId, Self::Balance, T::BlockNumber> =
-				AuctionInfo { bid: None, start, end };
+			RevealedReserves::<T>::insert(auction_id, reserve);
+			Self::deposit_event(Event::<T>::HiddenReserveRevealed(auction_id, reserve));
 
-			let auction_id: AuctionId = AuctionsIndex::<T>::try_mutate(|n| -> Result<AuctionId, DispatchError> {
-				let id = *n;
-				ensure!(id != AuctionId::max_value(), Error::<T>::NoAvailableAuctionId);
-				*n = n.checked_add(One::one()).ok_or(Error::<T>::NoAvailableAuctionId)?;
-				Ok(id)
-			})?;
+			Ok(().into())
+		}
 
-			<Auctions<T>>::insert(auction_id, auction);
+		/// Attach an instant purchase price to an auction, or remove it if none. Any account paying
+		/// the price with buy now ends the auction immediately
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn set_auction_buy_now_price(
+			origin: OriginFor<T>,
+			auction_id: AuctionId,
+			price: Option<BalanceOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
 
-			if let Some(end_block) = end {
-				<AuctionEndTime<T>>::insert(&end_block, auction_id, ());
+			let auction = Self::auctions(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			let auction_item = Self::get_auction_item(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			ensure!(auction_item.recipient == from, Error::<T>::NoPermissionToUpdateListing);
+			ensure!(
+				auction_item.auction_type == AuctionType::Auction,
+				Error::<T>::InvalidAuctionType
+			);
+			if let Some(price) = price {
+				ensure!(
+					price >= auction_item.initial_amount,
+					Error::<T>::InvalidAuctionBuyNowPrice
+				);
+				if let Some((_, high_bid_price)) = auction.bid {
+					ensure!(price > high_bid_price, Error::<T>::InvalidAuctionBuyNowPrice);
+				}
 			}
 
-			Ok(auction_id)
+			AuctionBuyNowPrices::<T>::set(auction_id, price);
+			Self::deposit_event(Event::<T>::AuctionBuyNowPriceUpdated(auction_id, price));
+
+			Ok(().into())
 		}
 
-		fn create_auction(
-			auction_type: AuctionType,
+		/// List an NFT or estate in a sealed bid auction. Bidders commit to a bid until the commit
+		/// end, then reveal it during the reveal period; the highest revealed bid wins
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		#[transactional]
+		pub fn create_new_sealed_bid_auction(
+			origin: OriginFor<T>,
 			item_id: ItemId,
-			_end: Option<T::BlockNumber>,
-			recipient: T::AccountId,
-			initial_amount: Self::Balance,
-			_start: T::BlockNumber,
+			reserve_price: BalanceOf<T>,
+			commit_end: T::BlockNumber,
 			listing_level: ListingLevel<T::AccountId>,
-		) -> Result<AuctionId, DispatchError> {
-			ensure!(
-				Self::items_in_auction(item_id) == None,
-				Error::<T>::ItemAlreadyInAuction
-			);
-
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
 			match item_id {
-				ItemId::NFT(class_id, token_id) => {
-					// Check ownership
-					let is_owner = T::NFTHandler::check_ownership(&recipient, &(class_id, token_id))?;
+				ItemId::NFT(_, _) => {}
+				ItemId::Estate(estate_id) => ensure!(
+					T::EstateHandler::check_estate_ownership(&from, estate_id)?,
+					Error::<T>::NotItemOwner
+				),
+				_ => return Err(Error::<T>::SealedBidItemNotSupported.into()),
+			}
 
-					ensure!(is_owner == true, Error::<T>::NoPermissionToCreateAuction);
+			let start_time: T::BlockNumber = <system::Pallet<T>>::block_number();
+			let auction_id = Self::create_auction(
+				AuctionType::SealedBid,
+				item_id,
+				Some(commit_end.saturating_add(T::SealedBidRevealPeriod::get())),
+				from,
+				reserve_price,
+				start_time,
+				listing_level,
+			)?;
+			SealedBidRevealStart::<T>::insert(auction_id, commit_end);
+			Self::deposit_event(Event::<T>::NewSealedBidAuction(auction_id, commit_end));
 
-					let is_transferable = T::NFTHandler::is_transferable(&(class_id, token_id))?;
+			Ok(().into())
+		}
 
-					ensure!(is_transferable == true, Error::<T>::NoPermissionToCreateAuction);
+		/// Commit to a sealed bid with the hash of (bidder, bid, salt), reserving the sealed bid
+		/// deposit until the bid is revealed
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		#[transactional]
+		pub fn commit_sealed_bid(
+			origin: OriginFor<T>,
+			auction_id: AuctionId,
+			commitment: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
 
-					// Ensure NFT authorised to sell
-					match listing_level {
-						ListingLevel::Local(metaverse_id) => {
-							ensure!(
-								MetaverseCollection::<T>::contains_key(metaverse_id, class_id)
-									|| T::MetaverseInfoSource::check_ownership(&recipient, &metaverse_id),
-								Error::<T>::NoPermissionToCreateAuction
-							);
-						}
-						_ => {}
-					}
+			let auction_item = Self::get_auction_item(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			ensure!(
+				auction_item.auction_type == AuctionType::SealedBid,
+				Error::<T>::InvalidAuctionType
+			);
+			ensure!(auction_item.recipient != from, Error::<T>::SelfBidNotAccepted);
+			Self::ensure_allowlisted_bidder(auction_id, &from)?;
+			Self::ensure_land_buyer_allowed(&auction_item.item_id, &from)?;
+			let reveal_start = Self::sealed_bid_reveal_start(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			ensure!(
+				<system::Pallet<T>>::block_number() < reveal_start,
+				Error::<T>::SealedBidCommitmentClosed
+			);
+			ensure!(
+				!SealedBidCommitments::<T>::contains_key(auction_id, &from),
+				Error::<T>::SealedBidAlreadyCommitted
+			);
 
-					let start_time = <system::Pallet<T>>::block_number();
+			let deposit = T::SealedBidDeposit::get();
+			<T as Config>::Currency::reserve(&from, deposit).map_err(|_| Error::<T>::InsufficientFunds)?;
+			SealedBidCommitments::<T>::insert(auction_id, &from, (commitment, deposit));
+			Self::deposit_event(Event::<T>::SealedBidCommitted(auction_id, from));
 
-					let mut end_time = start_time + T::AuctionTimeToClose::get();
-					if let Some(_end_block) = _end {
-						end_time = _end_block
-					}
-					let auction_id = Self::new_auction(recipient.clone(), initial_amount, start_time, Some(end_time))?;
-					let mut currency_id: FungibleTokenId = FungibleTokenId::NativeToken(0);
+			Ok(().into())
+		}
 
-					let new_auction_item = AuctionItem {
-						item_id,
-						recipient: recipient.clone(),
-						initial_amount: initial_amount,
-						amount: initial_amount,
-						start_time,
-						end_time,
-						auction_type,
-						listing_level: listing_level.clone(),
-						currency_id,
-					};
+		/// Reveal a committed sealed bid, the deposit is returned and the bid is reserved if it is
+		/// the highest revealed bid at or above the reserve price
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		#[transactional]
+		pub fn reveal_sealed_bid(
+			origin: OriginFor<T>,
+			auction_id: AuctionId,
+			value: BalanceOf<T>,
+			salt: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			ensure!(
+				salt.len() as u32 <= T::MaxRevealSaltLength::get(),
+				Error::<T>::RevealSaltTooLong
+			);
 
-					<AuctionItems<T>>::insert(auction_id, new_auction_item);
+			let mut auction = Self::auctions(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			let auction_item = Self::get_auction_item(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			let reveal_start = Self::sealed_bid_reveal_start(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			let block_number = <system::Pallet<T>>::block_number();
+			ensure!(block_number >= reveal_start, Error::<T>::SealedBidRevealNotOpen);
 
-					Self::deposit_event(Event::NewAuctionItem(
-						auction_id,
-						recipient,
-						listing_level,
-						initial_amount,
-						initial_amount,
-						end_time,
-					));
-					<ItemsInAuction<T>>::insert(item_id, true);
-					Ok(auction_id)
+			let (commitment, deposit) =
+				Self::sealed_bid_commitment(auction_id, &from).ok_or(Error::<T>::SealedBidCommitmentNotFound)?;
+			ensure!(
+				T::Hashing::hash_of(&(from.clone(), value, salt)) == commitment,
+				Error::<T>::InvalidSealedBidReveal
+			);
+			SealedBidCommitments::<T>::remove(auction_id, &from);
+			<T as Config>::Currency::unreserve(&from, deposit);
+
+			let is_highest = match &auction.bid {
+				Some((_, highest_bid)) => value > *highest_bid,
+				None => value >= auction_item.initial_amount,
+			};
+			if is_highest {
+				<T as Config>::Currency::reserve(&from, value).map_err(|_| Error::<T>::InsufficientFunds)?;
+				if let Some((previous_bidder, previous_bid)) = auction.bid.take() {
+					<T as Config>::Currency::unreserve(&previous_bidder, previous_bid);
 				}
-				ItemId::Spot(_spot_id, _metaverse_id) => {
-					let start_time = <system::Pallet<T>>::block_number();
-					let end_time: T::BlockNumber = start_time + T::AuctionTimeToClose::get();
-					let auction_id = Self::new_auction(recipient.clone(), initial_amount, start_time, Some(end_time))?;
+				auction.bid = Some((from.clone(), value));
+				<Auctions<T>>::insert(auction_id, auction);
+			}
+			Self::deposit_event(Event::<T>::SealedBidRevealed(auction_id, from, value));
 
-					let new_auction_item = AuctionItem {
-						item_id,
-						recipient: recipient.clone(),
-						initial_amount,
-						amount: initial_amount,
-						start_time,
-						end_time,
-						auction_type,
-						listing_level: listing_level.clone(),
-						currency_id: FungibleTokenId::NativeToken(0),
-					};
+			Ok(().into())
+		}
 
-					<AuctionItems<T>>::insert(auction_id, new_auction_item);
+		/// List estates, land units and NFTs in a single auction that settles atomically to the winner
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(items.len() as u64 * 2, items.len() as u64 + 4))]
+		#[transactional]
+		pub fn create_new_bundle_auction(
+			origin: OriginFor<T>,
+			auction_type: AuctionType,
+			items: Vec<ItemId>,
+			value: BalanceOf<T>,
+			end_time: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
 
-					Self::deposit_event(Event::NewAuctionItem(
-						auction_id,
-						recipient,
-						listing_level,
-						initial_amount,
-						initial_amount,
-						end_time,
-					));
-					<ItemsInAuction<T>>::insert(item_id, true);
-					Ok(auction_id)
+			let start_time: T::BlockNumber = <system::Pallet<T>>::block_number();
+			let bundle_id = BundlesIndex::<T>::try_mutate(|n| -> Result<BundleId, DispatchError> {
+				let id = *n;
+				*n = n.checked_add(One::one()).ok_or(Error::<T>::NoAvailableBundleId)?;
+				Ok(id)
+			})?;
+			Bundles::<T>::insert(bundle_id, items.clone());
+
+			let auction_id = Self::create_auction(
+				auction_type,
+				ItemId::Bundle(bundle_id),
+				Some(end_time),
+				from.clone(),
+				value,
+				start_time,
+				ListingLevel::Global,
+			)?;
+			Self::record_estate_owner_activity(&from, &items);
+			Self::deposit_event(Event::<T>::NewAuctionBundle(auction_id, bundle_id, items));
+			Self::cross_list_auction(auction_id);
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn authorise_metaverse_collection(
+			origin: OriginFor<T>,
+			class_id: ClassId,
+			metaverse_id: MetaverseId,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			ensure!(
+				T::MetaverseInfoSource::check_ownership(&from, &metaverse_id),
+				Error::<T>::NoPermissionToAuthoriseCollection
+			);
+
+			ensure!(
+				!MetaverseCollection::<T>::contains_key(metaverse_id, class_id),
+				Error::<T>::CollectionAlreadyAuthorised
+			);
+
+			MetaverseCollection::<T>::insert(metaverse_id.clone(), class_id.clone(), ());
+
+			Self::deposit_event(Event::<T>::CollectionAuthorizedInMetaverse(class_id, metaverse_id));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn remove_authorise_metaverse_collection(
+			origin: OriginFor<T>,
+			class_id: ClassId,
+			metaverse_id: MetaverseId,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			ensure!(
+				T::MetaverseInfoSource::check_ownership(&from, &metaverse_id),
+				Error::<T>::NoPermissionToAuthoriseCollection
+			);
+
+			ensure!(
+				MetaverseCollection::<T>::contains_key(metaverse_id, class_id),
+				Error::<T>::CollectionIsNotAuthorised
+			);
+
+			MetaverseCollection::<T>::remove(metaverse_id.clone(), class_id.clone());
+			Self::deposit_event(Event::<T>::CollectionAuthorizationRemoveInMetaverse(
+				class_id,
+				metaverse_id,
+			));
+			Ok(().into())
+		}
+
+		/// Register partner marketplace on a sibling chain to receive cross listings
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn register_partner_marketplace(
+			origin: OriginFor<T>,
+			marketplace_id: MarketplaceId,
+			marketplace: PartnerMarketplaceInfo,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			ensure!(
+				!PartnerMarketplaces::<T>::contains_key(marketplace_id),
+				Error::<T>::PartnerMarketplaceAlreadyRegistered
+			);
+
+			PartnerMarketplaces::<T>::insert(marketplace_id, marketplace);
+			Self::deposit_event(Event::<T>::PartnerMarketplaceRegistered(marketplace_id));
+
+			Ok(().into())
+		}
+
+		/// Remove partner marketplace
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn remove_partner_marketplace(
+			origin: OriginFor<T>,
+			marketplace_id: MarketplaceId,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			ensure!(
+				PartnerMarketplaces::<T>::contains_key(marketplace_id),
+				Error::<T>::PartnerMarketplaceNotRegistered
+			);
+
+			PartnerMarketplaces::<T>::remove(marketplace_id);
+			Self::deposit_event(Event::<T>::PartnerMarketplaceRemoved(marketplace_id));
+
+			Ok(().into())
+		}
+
+		/// Opt in or out of mirroring new listings to partner marketplaces
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn set_cross_listing(origin: OriginFor<T>, enabled: bool) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			if enabled {
+				CrossListingOptIn::<T>::insert(&from, ());
+			} else {
+				CrossListingOptIn::<T>::remove(&from);
+			}
+			Self::deposit_event(Event::<T>::CrossListingPreferenceUpdated(from, enabled));
+
+			Ok(().into())
+		}
+
+		/// Partner marketplace submits purchase intent of a remote buyer for cross listed auction.
+		/// The marketplace escrows the payment on its own chain and funds the buyer from its
+		/// sovereign account, the intent is then placed as a bid or buy now of the buyer.
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(6, 4))]
+		#[transactional]
+		pub fn submit_remote_purchase_intent(
+			origin: OriginFor<T>,
+			auction_id: AuctionId,
+			buyer: T::AccountId,
+			value: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let (marketplace_id, sovereign_account) = T::CrossChainOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				PartnerMarketplaces::<T>::contains_key(marketplace_id),
+				Error::<T>::PartnerMarketplaceNotRegistered
+			);
+			ensure!(
+				CrossListedAuctions::<T>::contains_key(auction_id),
+				Error::<T>::AuctionNotCrossListed
+			);
+			ensure!(!value.is_zero(), Error::<T>::InvalidBidPrice);
+
+			let auction_item = Self::get_auction_item(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			ensure!(
+				auction_item.currency_id == FungibleTokenId::NativeToken(0),
+				Error::<T>::InvalidAuctionType
+			);
+
+			RemotePurchaseIntents::<T>::insert(auction_id, marketplace_id, (buyer.clone(), value));
+			Self::deposit_event(Event::<T>::RemotePurchaseIntentReceived(
+				auction_id,
+				marketplace_id,
+				buyer.clone(),
+				value,
+			));
+
+			<T as Config>::Currency::transfer(&sovereign_account, &buyer, value, ExistenceRequirement::KeepAlive)?;
+			match auction_item.auction_type {
+				AuctionType::Auction if Self::auction_buy_now_price(auction_id) != Some(value) => {
+					Self::do_bid(buyer, auction_id, value)?
 				}
-				ItemId::Estate(_estate_id_) => {
-					// Ensure the _estate_id_ exist/minted
+				_ => Self::do_buy_now(buyer, auction_id, value)?,
+			}
+
+			Ok(().into())
+		}
+
+		/// Prune up to `max_auctions` settled auctions of an archive era past the retention period.
+		/// Eras larger than `max_auctions` are pruned over several calls, the merkle root of their
+		/// summaries is stored once the last settled auction is pruned so the history stays provable
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1, 1).saturating_mul(*max_auctions as u64 + 2))]
+		pub fn prune_settled_auctions(
+			origin: OriginFor<T>,
+			era: T::BlockNumber,
+			max_auctions: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let era_length = T::ArchiveEraLength::get();
+			let prunable_from = era
+				.saturating_add(One::one())
+				.saturating_mul(era_length)
+				.saturating_add(T::AuctionRetentionPeriod::get());
+			ensure!(
+				<system::Pallet<T>>::block_number() >= prunable_from,
+				Error::<T>::ArchiveRetentionPeriodNotPassed
+			);
+
+			ensure!(max_auctions > 0, Error::<T>::TooManySettledAuctions);
+
+			// Pruned summaries are removed, so iterating the era again resumes where the previous
+			// call stopped
+			let mut summaries: Vec<(AuctionId, AuctionSummary<T::AccountId, BalanceOf<T>>)> =
+				SettledAuctions::<T>::iter_prefix(era)
+					.take(max_auctions.saturating_add(1) as usize)
+					.collect();
+			let has_remaining = summaries.len() as u32 > max_auctions;
+			summaries.truncate(max_auctions as usize);
+			let mut leaves = PendingArchiveLeaves::<T>::take(era);
+			ensure!(
+				!summaries.is_empty() || !leaves.is_empty(),
+				Error::<T>::NoSettledAuctionsInEra
+			);
+
+			for (auction_id, summary) in summaries.iter() {
+				leaves.push(T::Hashing::hash_of(&(auction_id, summary)));
+				AuctionItems::<T>::remove(auction_id);
+				SettledAuctions::<T>::remove(era, auction_id);
+			}
+
+			if has_remaining {
+				let pruned = leaves.len() as u32;
+				PendingArchiveLeaves::<T>::insert(era, leaves);
+				Self::deposit_event(Event::<T>::SettledAuctionsPruned(era, summaries.len() as u32, pruned));
+			} else {
+				let count = leaves.len() as u32;
+				let root = Self::settled_auctions_root(leaves);
+				ArchivedAuctionRoots::<T>::insert(era, (root, count));
+				Self::deposit_event(Event::<T>::SettledAuctionsArchived(era, root, count));
+			}
+
+			Ok(().into())
+		}
+
+		/// Set listing duration constraints of an item type, item types without limits fall back to
+		/// the minimum auction duration
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn set_listing_duration_limits(
+			origin: OriginFor<T>,
+			item_type: ListingItemType,
+			limits: Option<ListingDurationLimits<T::BlockNumber>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			match limits {
+				Some(limits) => {
+					if let Some(max_duration) = limits.max_duration {
+						ensure!(
+							limits.min_duration <= max_duration,
+							Error::<T>::InvalidListingDurationLimits
+						);
+					}
+					ItemTypeDurationLimits::<T>::insert(item_type, limits);
+				}
+				None => ItemTypeDurationLimits::<T>::remove(item_type),
+			}
+			Self::deposit_event(Event::<T>::ListingDurationLimitsUpdated(item_type, limits));
+
+			Ok(().into())
+		}
+
+		/// Escrow funds against an NFT or estate that is not listed, replacing any previous offer
+		/// of the buyer on the item. The offer can be accepted by the owner until the expiry block.
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3, 3))]
+		#[transactional]
+		pub fn make_offer(
+			origin: OriginFor<T>,
+			item_id: ItemId,
+			value: BalanceOf<T>,
+			expiry: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			ensure!(
+				Self::items_in_auction(item_id) == None,
+				Error::<T>::ItemAlreadyInAuction
+			);
+			ensure!(!value.is_zero(), Error::<T>::InvalidBidPrice);
+			let now = <system::Pallet<T>>::block_number();
+			ensure!(expiry >= now, Error::<T>::InvalidOfferExpiry);
+
+			let is_owner = match item_id {
+				ItemId::NFT(class_id, token_id) => {
 					ensure!(
-						T::EstateHandler::check_estate(_estate_id_)?,
+						T::NFTHandler::is_transferable(&(class_id, token_id))?,
+						Error::<T>::ItemNotTransferable
+					);
+					T::NFTHandler::check_ownership(&from, &(class_id, token_id))?
+				}
+				ItemId::Estate(estate_id) => {
+					ensure!(
+						T::EstateHandler::check_estate(estate_id)?,
 						Error::<T>::EstateDoesNotExist
 					);
+					T::EstateHandler::check_estate_ownership(&from, estate_id)?
+				}
+				_ => return Err(Error::<T>::OfferItemNotSupported.into()),
+			};
+			ensure!(!is_owner, Error::<T>::CannotOfferOnOwnItem);
+			Self::ensure_land_buyer_allowed(&item_id, &from)?;
+
+			match Self::offers(item_id, &from) {
+				Some(previous) => {
+					<T as Config>::Currency::unreserve(&from, previous.amount);
+				}
+				None => {
+					if Self::offer_count(item_id) >= T::MaxOffersPerItem::get() {
+						Self::remove_expired_offers(item_id, now);
+					}
+					ensure!(
+						Self::offer_count(item_id) < T::MaxOffersPerItem::get(),
+						Error::<T>::OfferBookIsFull
+					);
+					OfferCount::<T>::mutate(item_id, |count| *count = count.saturating_add(1));
+				}
+			}
 
-					let start_time = <system::Pallet<T>>::block_number();
-					let end_time: T::BlockNumber = start_time + T::AuctionTimeToClose::get(); // add 7 days block for default auction
-					let auction_id = Self::new_auction(recipient.clone(), initial_amount, start_time, Some(end_time))?;
+			<T as Config>::Currency::reserve(&from, value).map_err(|_| Error::<T>::InsufficientFreeBalance)?;
+			Offers::<T>::insert(item_id, &from, AssetOffer { amount: value, expiry });
+			Self::deposit_event(Event::<T>::OfferMade(item_id, from, value, expiry));
 
-					let new_auction_item = AuctionItem {
-						item_id,
-						recipient: recipient.clone(),
-						initial_amount,
-						amount: initial_amount,
-						start_time,
-						end_time,
-						auction_type,
-						listing_level: ListingLevel::Global,
-						currency_id: FungibleTokenId::NativeToken(0),
-					};
+			Ok(().into())
+		}
 
-					<AuctionItems<T>>::insert(auction_id, new_auction_item);
+		/// Owner accepts an open offer, selling the item for the escrowed funds
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(4, 5))]
+		#[transactional]
+		pub fn accept_offer(origin: OriginFor<T>, item_id: ItemId, buyer: T::AccountId) -> DispatchResultWithPostInfo {
+			let seller = ensure_signed(origin)?;
 
-					Self::deposit_event(Event::NewAuctionItem(
-						auction_id,
-						recipient,
-						listing_level,
-						initial_amount,
-						initial_amount,
-						end_time,
-					));
-					<ItemsInAuction<T>>::insert(item_id, true);
-					Ok(auction_id)
+			let offer = Self::offers(item_id, &buyer).ok_or(Error::<T>::OfferNotFound)?;
+			ensure!(
+				<system::Pallet<T>>::block_number() <= offer.expiry,
+				Error::<T>::OfferIsExpired
+			);
+			ensure!(
+				Self::items_in_auction(item_id) == None,
+				Error::<T>::ItemAlreadyInAuction
+			);
+
+			let is_owner = match item_id {
+				ItemId::NFT(class_id, token_id) => {
+					T::NFTHandler::check_ownership(&seller, &(class_id, token_id))?
+						&& T::NFTHandler::is_transferable(&(class_id, token_id))?
 				}
-				ItemId::LandUnit(_coordinate_, _metaverse_id_) => {
-					// Ensure the _coordinate_ exist/minted
+				ItemId::Estate(estate_id) => {
 					ensure!(
-						T::EstateHandler::check_landunit(_metaverse_id_, _coordinate_)?,
-						Error::<T>::LandUnitDoesNotExist
+						!T::EstateHandler::is_estate_frozen(estate_id),
+						Error::<T>::EstateIsFrozen
+					);
+					ensure!(
+						T::EstateHandler::is_land_tax_settled(estate_id),
+						Error::<T>::LandTaxOutstanding
 					);
+					T::EstateHandler::check_estate_ownership(&seller, estate_id)?
+				}
+				_ => return Err(Error::<T>::OfferItemNotSupported.into()),
+			};
+			ensure!(is_owner, Error::<T>::NotItemOwner);
+
+			Self::remove_offer(item_id, &buyer);
+			<T as Config>::Currency::repatriate_reserved(&buyer, &seller, offer.amount, BalanceStatus::Free)?;
+			match item_id {
+				ItemId::NFT(class_id, token_id) => {
+					T::NFTHandler::transfer_nft(&seller, &buyer, &(class_id, token_id))?;
+				}
+				ItemId::Estate(estate_id) => {
+					T::EstateHandler::transfer_estate(estate_id, &seller, &buyer)?;
+					T::EstateHandler::record_estate_sale(estate_id, offer.amount.saturated_into());
+				}
+				_ => return Err(Error::<T>::OfferItemNotSupported.into()),
+			}
+			Self::pay_sale_fees(&item_id, offer.amount, &seller, FungibleTokenId::NativeToken(0), None);
+
+			Self::deposit_event(Event::<T>::OfferAccepted(item_id, buyer, seller, offer.amount));
+
+			Ok(().into())
+		}
+
+		/// Buyer withdraws an offer and releases the escrowed funds
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2, 2))]
+		pub fn withdraw_offer(origin: OriginFor<T>, item_id: ItemId) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			let offer = Self::offers(item_id, &from).ok_or(Error::<T>::OfferNotFound)?;
+			<T as Config>::Currency::unreserve(&from, offer.amount);
+			Self::remove_offer(item_id, &from);
+			Self::deposit_event(Event::<T>::OfferWithdrawn(item_id, from));
+
+			Ok(().into())
+		}
+
+		/// Seller cancels an auction. Cancelling is free before any bid, afterwards the seller
+		/// compensates the highest bidder with the cancellation penalty share of the highest bid.
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(3, 10))]
+		#[transactional]
+		pub fn cancel_auction(origin: OriginFor<T>, auction_id: AuctionId) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			let auction = Self::auctions(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			let auction_item = Self::get_auction_item(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			ensure!(auction_item.recipient == from, Error::<T>::NoPermissionToUpdateListing);
+
+			let mut penalty: BalanceOf<T> = Zero::zero();
+			if let Some((high_bidder, high_bid_price)) = auction.bid {
+				penalty = T::AuctionCancellationPenalty::get() * high_bid_price;
+				if !penalty.is_zero() {
+					Self::transfer_in_listing_currency(auction_item.currency_id, &from, &high_bidder, penalty)?;
+				}
+				let shortfall =
+					Self::unreserve_in_listing_currency(auction_item.currency_id, &high_bidder, high_bid_price);
+				Self::record_escrow_shortfall(auction_id, &high_bidder, shortfall);
+			}
+			// Unrevealed sealed bids are refunded in full
+			for (bidder, (_, deposit)) in SealedBidCommitments::<T>::drain_prefix(auction_id) {
+				<T as Config>::Currency::unreserve(&bidder, deposit);
+			}
+
+			Self::remove_auction(auction_id, auction_item.item_id);
+			AuctionItems::<T>::remove(auction_id);
+			if let ItemId::Bundle(bundle_id) = auction_item.item_id {
+				Self::release_bundle(bundle_id);
+			}
+			Self::deposit_event(Event::<T>::AuctionCancelled(auction_id, penalty));
+
+			Ok(().into())
+		}
+
+		/// Seller sets the minimum amount each bid has to exceed the highest bid by, or removes it
+		/// if none. The rule can only be changed before the first bid.
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2, 1))]
+		pub fn set_bid_increment_rule(
+			origin: OriginFor<T>,
+			auction_id: AuctionId,
+			rule: Option<BidIncrementRule<BalanceOf<T>>>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			let auction = Self::auctions(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			let auction_item = Self::get_auction_item(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			ensure!(auction_item.recipient == from, Error::<T>::NoPermissionToUpdateListing);
+			ensure!(
+				auction_item.auction_type == AuctionType::Auction,
+				Error::<T>::InvalidAuctionType
+			);
+			ensure!(auction.bid.is_none(), Error::<T>::BidIncrementRuleLocked);
+
+			AuctionBidIncrements::<T>::set(auction_id, rule.clone());
+			Self::deposit_event(Event::<T>::BidIncrementRuleUpdated(auction_id, rule));
+
+			Ok(().into())
+		}
+
+		/// Seller restricts bidding on an auction to an explicit list of accounts or to holders of
+		/// an NFT class, or lifts the restriction if none. The allowlist can only be changed before
+		/// the first bid.
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2, 1))]
+		pub fn set_bidder_allowlist(
+			origin: OriginFor<T>,
+			auction_id: AuctionId,
+			allowlist: Option<BidderAllowlist<T::AccountId>>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			let auction = Self::auctions(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			let auction_item = Self::get_auction_item(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+			ensure!(auction_item.recipient == from, Error::<T>::NoPermissionToUpdateListing);
+			ensure!(auction.bid.is_none(), Error::<T>::BidderAllowlistLocked);
+			if let Some(BidderAllowlist::Accounts(ref accounts)) = allowlist {
+				ensure!(
+					accounts.len() as u32 <= T::MaxAuctionAllowlistSize::get(),
+					Error::<T>::BidderAllowlistTooLarge
+				);
+			}
+
+			AuctionBidderAllowlists::<T>::set(auction_id, allowlist.clone());
+			Self::deposit_event(Event::<T>::BidderAllowlistUpdated(auction_id, allowlist));
+
+			Ok(().into())
+		}
+
+		/// List an undeployed land block that is not issued yet. The winner receives a freshly
+		/// issued undeployed land block on settlement and the proceeds go to the land sale treasury.
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2, 6))]
+		#[transactional]
+		pub fn create_land_block_auction(
+			origin: OriginFor<T>,
+			number_land_units: u32,
+			undeployed_land_block_type: UndeployedLandBlockType,
+			value: BalanceOf<T>,
+			end_time: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			T::LandSaleOrigin::ensure_origin(origin)?;
+			ensure!(number_land_units > 0, Error::<T>::InvalidLandBlockSize);
+
+			let start_time: T::BlockNumber = <system::Pallet<T>>::block_number();
+			let sale_id = LandBlockSalesIndex::<T>::try_mutate(|n| -> Result<u64, DispatchError> {
+				let id = *n;
+				*n = n
+					.checked_add(One::one())
+					.ok_or(Error::<T>::NoAvailableLandBlockSaleId)?;
+				Ok(id)
+			})?;
+			LandBlockSales::<T>::insert(
+				sale_id,
+				LandBlockSale {
+					number_land_units,
+					undeployed_land_block_type,
+				},
+			);
+
+			let auction_id = Self::create_auction(
+				AuctionType::Auction,
+				ItemId::Block(sale_id),
+				Some(end_time),
+				T::LandSaleTreasury::get(),
+				value,
+				start_time,
+				ListingLevel::Global,
+			)?;
+			Self::deposit_event(Event::<T>::NewLandBlockAuction(auction_id, sale_id, number_land_units));
+
+			Ok(().into())
+		}
+
+		/// Propose to trade NFTs, estates and land units, optionally plus tokens, for items and
+		/// tokens of the counterparty. The offered tokens are escrowed until the swap is accepted
+		/// or cancelled.
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(offered_items.len() as u64 * 2 + 1, 3))]
+		#[transactional]
+		pub fn propose_swap(
+			origin: OriginFor<T>,
+			counterparty: T::AccountId,
+			offered_items: Vec<ItemId>,
+			offered_amount: BalanceOf<T>,
+			requested_items: Vec<ItemId>,
+			requested_amount: BalanceOf<T>,
+			expiry: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			ensure!(from != counterparty, Error::<T>::InvalidSwap);
+			ensure!(
+				!(offered_items.is_empty() && offered_amount.is_zero())
+					&& !(requested_items.is_empty() && requested_amount.is_zero()),
+				Error::<T>::InvalidSwap
+			);
+			ensure!(expiry >= <system::Pallet<T>>::block_number(), Error::<T>::SwapIsExpired);
+			Self::ensure_swap_items(&from, &offered_items)?;
+			ensure!(
+				requested_items.len() as u32 <= T::MaxBundleItems::get(),
+				Error::<T>::ExceedMaximumBundleItems
+			);
+			Self::ensure_swap_receiver_allowed(&offered_items, &counterparty)?;
+			Self::ensure_swap_receiver_allowed(&requested_items, &from)?;
+
+			let swap_id = SwapsIndex::<T>::try_mutate(|n| -> Result<SwapId, DispatchError> {
+				let id = *n;
+				*n = n.checked_add(One::one()).ok_or(Error::<T>::NoAvailableSwapId)?;
+				Ok(id)
+			})?;
+			<T as Config>::Currency::reserve(&from, offered_amount)?;
+			SwapProposals::<T>::insert(
+				swap_id,
+				SwapProposal {
+					proposer: from.clone(),
+					counterparty: counterparty.clone(),
+					offered_items,
+					offered_amount,
+					requested_items,
+					requested_amount,
+					expiry,
+				},
+			);
+			Self::deposit_event(Event::<T>::SwapProposed(swap_id, from, counterparty));
+
+			Ok(().into())
+		}
+
+		/// Counterparty accepts a swap, exchanging all items and tokens of both sides atomically
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2 * T::MaxBundleItems::get() as u64 + 1, 2 * T::MaxBundleItems::get() as u64 + 3))]
+		#[transactional]
+		pub fn accept_swap(origin: OriginFor<T>, swap_id: SwapId) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			let swap = Self::swap_proposal(swap_id).ok_or(Error::<T>::SwapNotFound)?;
+			ensure!(swap.counterparty == from, Error::<T>::NoPermissionToUpdateSwap);
+			ensure!(
+				<system::Pallet<T>>::block_number() <= swap.expiry,
+				Error::<T>::SwapIsExpired
+			);
+			Self::ensure_swap_items(&swap.proposer, &swap.offered_items)?;
+			Self::ensure_swap_items(&swap.counterparty, &swap.requested_items)?;
+			Self::ensure_swap_receiver_allowed(&swap.offered_items, &swap.counterparty)?;
+			Self::ensure_swap_receiver_allowed(&swap.requested_items, &swap.proposer)?;
+
+			SwapProposals::<T>::remove(swap_id);
+			<T as Config>::Currency::repatriate_reserved(
+				&swap.proposer,
+				&swap.counterparty,
+				swap.offered_amount,
+				BalanceStatus::Free,
+			)?;
+			<T as Config>::Currency::transfer(
+				&swap.counterparty,
+				&swap.proposer,
+				swap.requested_amount,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			Self::transfer_swap_items(&swap.offered_items, &swap.proposer, &swap.counterparty)?;
+			Self::transfer_swap_items(&swap.requested_items, &swap.counterparty, &swap.proposer)?;
+			Self::deposit_event(Event::<T>::SwapAccepted(swap_id));
+
+			Ok(().into())
+		}
+
+		/// Proposer withdraws a swap or counterparty rejects it, releasing the escrowed tokens
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1, 2))]
+		pub fn cancel_swap(origin: OriginFor<T>, swap_id: SwapId) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+
+			let swap = Self::swap_proposal(swap_id).ok_or(Error::<T>::SwapNotFound)?;
+			ensure!(
+				swap.proposer == from || swap.counterparty == from,
+				Error::<T>::NoPermissionToUpdateSwap
+			);
+
+			SwapProposals::<T>::remove(swap_id);
+			<T as Config>::Currency::unreserve(&swap.proposer, swap.offered_amount);
+			Self::deposit_event(Event::<T>::SwapCancelled(swap_id));
+
+			Ok(().into())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			Self::settle_ended_auctions(now).saturating_add(Self::stream_due_installments(now))
+		}
+	}
+
+	impl<T: Config> Auction<T::AccountId, T::BlockNumber> for Pallet<T> {
+		type Balance = BalanceOf<T>;
+
+		fn update_auction(
+			id: AuctionId,
+			info: AuctionInfo<T::AccountId, Self::Balance, T::BlockNumber>,
+		) -> DispatchResult {
+			let auction = <Auctions<T>>::get(id).ok_or(Error::<T>::AuctionNotExist)?;
+			if let Some(old_end) = auction.end {
+				<AuctionEndTime<T>>::remove(&old_end, id);
+			}
+			if let Some(new_end) = info.end {
+				<AuctionEndTime<T>>::insert(&new_end, id, ());
+			}
+			<Auctions<T>>::insert(id, info);
+			Ok(())
+		}
+
+		fn new_auction(
+			_recipient: T::AccountId,
+			_initial_amount: Self::Balance,
+			start: T::BlockNumber,
+			end: Option<T::BlockNumber>,
+		) -> Result<AuctionId, DispatchError> {
+			let auction: AuctionInfo<T::AccountId, Self::Balance, T::BlockNumber> =
+				AuctionInfo { bid: None, start, end };
+
+			let auction_id: AuctionId = AuctionsIndex::<T>::try_mutate(|n| -> Result<AuctionId, DispatchError> {
+				let id = *n;
+				ensure!(id != AuctionId::max_value(), Error::<T>::NoAvailableAuctionId);
+				*n = n.checked_add(One::one()).ok_or(Error::<T>::NoAvailableAuctionId)?;
+				Ok(id)
+			})?;
+
+			<Auctions<T>>::insert(auction_id, auction);
+
+			if let Some(end_block) = end {
+				<AuctionEndTime<T>>::insert(&end_block, auction_id, ());
+			}
+
+			Ok(auction_id)
+		}
+
+		fn create_auction(
+			auction_type: AuctionType,
+			item_id: ItemId,
+			_end: Option<T::BlockNumber>,
+			recipient: T::AccountId,
+			initial_amount: Self::Balance,
+			_start: T::BlockNumber,
+			listing_level: ListingLevel<T::AccountId>,
+		) -> Result<AuctionId, DispatchError> {
+			ensure!(
+				Self::items_in_auction(item_id) == None,
+				Error::<T>::ItemAlreadyInAuction
+			);
+
+			let start_time = <system::Pallet<T>>::block_number();
+			let end_time = _end.unwrap_or(start_time + T::AuctionTimeToClose::get());
+			Self::ensure_listing_duration(&item_id, start_time, end_time)?;
+
+			match item_id {
+				ItemId::NFT(class_id, token_id) => {
+					// Check ownership
+					let is_owner = T::NFTHandler::check_ownership(&recipient, &(class_id, token_id))?;
+
+					ensure!(is_owner == true, Error::<T>::NotItemOwner);
+
+					let is_transferable = T::NFTHandler::is_transferable(&(class_id, token_id))?;
+
+					ensure!(is_transferable == true, Error::<T>::ItemNotTransferable);
+
+					// Ensure NFT authorised to sell
+					match listing_level {
+						ListingLevel::Local(metaverse_id) => {
+							ensure!(
+								MetaverseCollection::<T>::contains_key(metaverse_id, class_id)
+									|| T::MetaverseInfoSource::check_ownership(&recipient, &metaverse_id),
+								Error::<T>::CollectionIsNotAuthorised
+							);
+						}
+						_ => {}
+					}
+
+					let auction_id = Self::new_auction(recipient.clone(), initial_amount, start_time, Some(end_time))?;
+					let mut currency_id: FungibleTokenId = FungibleTokenId::NativeToken(0);
+
+					let new_auction_item = AuctionItem {
+						item_id,
+						recipient: recipient.clone(),
+						initial_amount: initial_amount,
+						amount: initial_amount,
+						start_time,
+						end_time,
+						auction_type,
+						listing_level: listing_level.clone(),
+						currency_id,
+					};
+
+					<AuctionItems<T>>::insert(auction_id, new_auction_item);
+
+					Self::deposit_event(Event::NewAuctionItem(
+						auction_id,
+						recipient,
+						listing_level,
+						initial_amount,
+						initial_amount,
+						end_time,
+					));
+					<ItemsInAuction<T>>::insert(item_id, true);
+					Ok(auction_id)
+				}
+				ItemId::Spot(_spot_id, _metaverse_id) => {
+					let auction_id = Self::new_auction(recipient.clone(), initial_amount, start_time, Some(end_time))?;
+
+					let new_auction_item = AuctionItem {
+						item_id,
+						recipient: recipient.clone(),
+						initial_amount,
+						amount: initial_amount,
+						start_time,
+						end_time,
+						auction_type,
+						listing_level: listing_level.clone(),
+						currency_id: FungibleTokenId::NativeToken(0),
+					};
+
+					<AuctionItems<T>>::insert(auction_id, new_auction_item);
+
+					Self::deposit_event(Event::NewAuctionItem(
+						auction_id,
+						recipient,
+						listing_level,
+						initial_amount,
+						initial_amount,
+						end_time,
+					));
+					<ItemsInAuction<T>>::insert(item_id, true);
+					Ok(auction_id)
+				}
+				ItemId::Estate(_estate_id_) => {
+					// Ensure the _estate_id_ exist/minted
+					ensure!(
+						T::EstateHandler::check_estate(_estate_id_)?,
+						Error::<T>::EstateDoesNotExist
+					);
+					ensure!(
+						!T::EstateHandler::is_estate_frozen(_estate_id_),
+						Error::<T>::EstateIsFrozen
+					);
+					ensure!(
+						T::EstateHandler::is_land_tax_settled(_estate_id_),
+						Error::<T>::LandTaxOutstanding
+					);
+
+					let auction_id = Self::new_auction(recipient.clone(), initial_amount, start_time, Some(end_time))?;
+
+					let new_auction_item = AuctionItem {
+						item_id,
+						recipient: recipient.clone(),
+						initial_amount,
+						amount: initial_amount,
+						start_time,
+						end_time,
+						auction_type,
+						listing_level: ListingLevel::Global,
+						currency_id: FungibleTokenId::NativeToken(0),
+					};
+
+					<AuctionItems<T>>::insert(auction_id, new_auction_item);
+
+					Self::deposit_event(Event::NewAuctionItem(
+						auction_id,
+						recipient,
+						listing_level,
+						initial_amount,
+						initial_amount,
+						end_time,
+					));
+					<ItemsInAuction<T>>::insert(item_id, true);
+					Ok(auction_id)
+				}
+				ItemId::LandUnit(_coordinate_, _metaverse_id_) => {
+					// Ensure the _coordinate_ exist/minted
+					ensure!(
+						T::EstateHandler::check_landunit(_metaverse_id_, _coordinate_)?,
+						Error::<T>::LandUnitDoesNotExist
+					);
+
+					let auction_id = Self::new_auction(recipient.clone(), initial_amount, start_time, Some(end_time))?;
+
+					let new_auction_item = AuctionItem {
+						item_id,
+						recipient: recipient.clone(),
+						initial_amount,
+						amount: initial_amount,
+						start_time,
+						end_time,
+						auction_type,
+						listing_level: ListingLevel::Global,
+						currency_id: FungibleTokenId::NativeToken(0),
+					};
+
+					<AuctionItems<T>>::insert(auction_id, new_auction_item);
+
+					Self::deposit_event(Event::NewAuctionItem(
+						auction_id,
+						recipient,
+						listing_level,
+						initial_amount,
+						initial_amount,
+						end_time,
+					));
+					<ItemsInAuction<T>>::insert(item_id, true);
+					Ok(auction_id)
+				}
+				ItemId::Bundle(bundle_id) => {
+					let items = Self::get_bundle(bundle_id).ok_or(Error::<T>::BundleDoesNotExist)?;
+					Self::ensure_bundle_items(&recipient, &items)?;
+
+					let auction_id = Self::new_auction(recipient.clone(), initial_amount, start_time, Some(end_time))?;
+
+					let new_auction_item = AuctionItem {
+						item_id,
+						recipient: recipient.clone(),
+						initial_amount,
+						amount: initial_amount,
+						start_time,
+						end_time,
+						auction_type,
+						listing_level: ListingLevel::Global,
+						currency_id: FungibleTokenId::NativeToken(0),
+					};
+
+					<AuctionItems<T>>::insert(auction_id, new_auction_item);
+
+					Self::deposit_event(Event::NewAuctionItem(
+						auction_id,
+						recipient,
+						listing_level,
+						initial_amount,
+						initial_amount,
+						end_time,
+					));
+					// Bundle items can not be listed or transferred separately while in auction
+					for bundle_item in items {
+						<ItemsInAuction<T>>::insert(bundle_item, true);
+					}
+					<ItemsInAuction<T>>::insert(item_id, true);
+					Ok(auction_id)
+				}
+				ItemId::Block(sale_id) => {
+					ensure!(
+						LandBlockSales::<T>::contains_key(sale_id),
+						Error::<T>::AuctionTypeIsNotSupported
+					);
+
+					let auction_id = Self::new_auction(recipient.clone(), initial_amount, start_time, Some(end_time))?;
+
+					let new_auction_item = AuctionItem {
+						item_id,
+						recipient: recipient.clone(),
+						initial_amount,
+						amount: initial_amount,
+						start_time,
+						end_time,
+						auction_type,
+						listing_level: ListingLevel::Global,
+						currency_id: FungibleTokenId::NativeToken(0),
+					};
+
+					<AuctionItems<T>>::insert(auction_id, new_auction_item);
+
+					Self::deposit_event(Event::NewAuctionItem(
+						auction_id,
+						recipient,
+						listing_level,
+						initial_amount,
+						initial_amount,
+						end_time,
+					));
+					<ItemsInAuction<T>>::insert(item_id, true);
+					Ok(auction_id)
+				}
+				_ => Err(Error::<T>::AuctionTypeIsNotSupported.into()),
+			}
+		}
+
+		fn remove_auction(id: AuctionId, item_id: ItemId) {
+			if let Some(auction) = <Auctions<T>>::get(&id) {
+				if let Some(end_block) = auction.end {
+					<AuctionEndTime<T>>::remove(end_block, id);
+					<Auctions<T>>::remove(&id);
+					<ItemsInAuction<T>>::remove(item_id);
+					if let ItemId::Estate(estate_id) = item_id {
+						T::EstateHandler::record_estate_delisted(estate_id);
+					}
+					<CrossListedAuctions<T>>::remove(&id);
+					<DutchAuctionFloorPrices<T>>::remove(&id);
+					<AuctionBuyNowPrices<T>>::remove(&id);
+					<AuctionExtensions<T>>::remove(&id);
+					<SealedBidRevealStart<T>>::remove(&id);
+					<HiddenReserves<T>>::remove(&id);
+					<RevealedReserves<T>>::remove(&id);
+					<AuctionBidIncrements<T>>::remove(&id);
+					<AuctionBidderAllowlists<T>>::remove(&id);
+					<RemotePurchaseIntents<T>>::remove_prefix(&id, None);
+					if let ItemId::Block(sale_id) = item_id {
+						<LandBlockSales<T>>::remove(sale_id);
+					}
+				}
+			}
+		}
+
+		fn auction_bid_handler(
+			_now: T::BlockNumber,
+			id: AuctionId,
+			new_bid: (T::AccountId, Self::Balance),
+			last_bid: Option<(T::AccountId, Self::Balance)>,
+		) -> DispatchResult {
+			let (new_bidder, new_bid_price) = new_bid;
+			ensure!(!new_bid_price.is_zero(), Error::<T>::InvalidBidPrice);
+
+			<AuctionItems<T>>::try_mutate_exists(id, |auction_item| -> DispatchResult {
+				let mut auction_item = auction_item.as_mut().ok_or(Error::<T>::AuctionNotExist)?;
+
+				match auction_item.clone().listing_level {
+					ListingLevel::NetworkSpot(allowed_bidders) => {
+						ensure!(allowed_bidders.contains(&new_bidder), Error::<T>::BidNotAccepted);
+					}
+					_ => {}
+				}
+
+				let last_bid_price = last_bid.clone().map_or(Zero::zero(), |(_, price)| price); // get last bid price
+				let last_bidder = last_bid.as_ref().map(|(who, _)| who);
+
+				if let Some(last_bidder) = last_bidder {
+					//unlock reserve amount
+					if !last_bid_price.is_zero() {
+						//Unreserve balance of last bidder
+						let shortfall = <T as Config>::Currency::unreserve(&last_bidder, last_bid_price);
+						Self::record_escrow_shortfall(id, last_bidder, shortfall);
+					}
+				}
+
+				// Lock fund of new bidder
+				// Reserve balance
+				<T as Config>::Currency::reserve(&new_bidder, new_bid_price)?;
+				auction_item.amount = new_bid_price.clone();
+
+				Ok(())
+			})
+		}
+
+		fn local_auction_bid_handler(
+			_now: T::BlockNumber,
+			id: AuctionId,
+			new_bid: (T::AccountId, Self::Balance),
+			last_bid: Option<(T::AccountId, Self::Balance)>,
+			social_currency_id: FungibleTokenId,
+		) -> DispatchResult {
+			let (new_bidder, new_bid_price) = new_bid;
+			ensure!(!new_bid_price.is_zero(), Error::<T>::InvalidBidPrice);
+
+			<AuctionItems<T>>::try_mutate_exists(id, |auction_item| -> DispatchResult {
+				let mut auction_item = auction_item.as_mut().ok_or(Error::<T>::AuctionNotExist)?;
+
+				let last_bid_price = last_bid.clone().map_or(Zero::zero(), |(_, price)| price); // get last bid price
+				let last_bidder = last_bid.as_ref().map(|(who, _)| who);
+
+				if let Some(last_bidder) = last_bidder {
+					// unlock reserve amount
+					if !last_bid_price.is_zero() {
+						// Un-reserve balance of last bidder
+						let shortfall = T::FungibleTokenCurrency::unreserve(
+							social_currency_id,
+							&last_bidder,
+							last_bid_price.saturated_into(),
+						);
+						Self::record_escrow_shortfall(id, last_bidder, shortfall.saturated_into());
+					}
+				}
+
+				// Lock fund of new bidder
+				// Reserve balance
+				T::FungibleTokenCurrency::reserve(social_currency_id, &new_bidder, new_bid_price.saturated_into())?;
+				auction_item.amount = new_bid_price.clone();
+
+				Ok(())
+			})
+		}
+
+		fn auction_info(id: AuctionId) -> Option<AuctionInfo<T::AccountId, Self::Balance, T::BlockNumber>> {
+			Self::auctions(id)
+		}
+
+		fn collect_royalty_fee(
+			high_bid_price: &Self::Balance,
+			high_bidder: &T::AccountId,
+			asset_id: &(ClassId, TokenId),
+			social_currency_id: FungibleTokenId,
+		) -> DispatchResult {
+			// Calculate royalty fee configured on the token, or on the class and deposit to class fund
+			let (class_fund, royalty) = match T::NFTHandler::get_token_royalty(asset_id) {
+				Some(token_royalty) => token_royalty,
+				None => (
+					T::NFTHandler::get_class_fund(&asset_id.0),
+					T::NFTHandler::get_nft_detail((asset_id.0, asset_id.1))?.royalty_fee,
+				),
+			};
+			let royalty_fee = royalty * *high_bid_price;
+
+			// Transfer loyalty fee from winner to class fund pot
+			if social_currency_id == FungibleTokenId::NativeToken(0) {
+				<T as Config>::Currency::transfer(
+					&high_bidder,
+					&class_fund,
+					royalty_fee,
+					ExistenceRequirement::KeepAlive,
+				)?;
+			} else {
+				T::FungibleTokenCurrency::transfer(
+					social_currency_id.clone(),
+					&high_bidder,
+					&class_fund,
+					royalty_fee.saturated_into(),
+				)?;
+			}
+			Ok(())
+		}
+	}
+
+	impl<T: Config> CheckAuctionItemHandler for Pallet<T> {
+		fn check_item_in_auction(item_id: ItemId) -> bool {
+			Self::items_in_auction(item_id) == Some(true)
+		}
+	}
+
+	impl<T: Config> AuctionHandler<T::AccountId, BalanceOf<T>, T::BlockNumber, AuctionId> for Pallet<T> {
+		fn on_new_bid(
+			_now: T::BlockNumber,
+			_id: AuctionId,
+			_new_bid: (T::AccountId, BalanceOf<T>),
+			_last_bid: Option<(T::AccountId, BalanceOf<T>)>,
+		) -> OnNewBidResult<T::BlockNumber> {
+			OnNewBidResult {
+				accept_bid: true,
+				auction_end_change: Change::NoChange,
+			}
+		}
+
+		fn on_auction_ended(_id: AuctionId, _winner: Option<(T::AccountId, BalanceOf<T>)>) {}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Ensure listing duration is within the limits of the item type
+		fn ensure_listing_duration(
+			item_id: &ItemId,
+			start_time: T::BlockNumber,
+			end_time: T::BlockNumber,
+		) -> DispatchResult {
+			let duration = end_time.checked_sub(&start_time).ok_or(Error::<T>::Overflow)?;
+			let limits = ListingItemType::of(item_id).and_then(|item_type| Self::listing_duration_limits(item_type));
+
+			let (min_duration, max_duration) = match limits {
+				Some(limits) => (limits.min_duration, limits.max_duration),
+				None => (T::MinimumAuctionDuration::get(), None),
+			};
+			ensure!(
+				duration >= min_duration,
+				Error::<T>::AuctionEndIsLessThanMinimumDuration
+			);
+			if let Some(max_duration) = max_duration {
+				ensure!(duration <= max_duration, Error::<T>::AuctionEndExceedsMaximumDuration);
+			}
+
+			Ok(())
+		}
+
+		/// Current price of a Dutch auction, descending linearly from the start price to the floor
+		/// price over the listing period
+		pub fn dutch_auction_price(auction_id: AuctionId) -> Option<BalanceOf<T>> {
+			let auction_item = Self::get_auction_item(auction_id)?;
+			if auction_item.auction_type != AuctionType::Dutch {
+				return None;
+			}
+			let floor_price = Self::dutch_auction_floor_price(auction_id)?;
+
+			let now = <system::Pallet<T>>::block_number();
+			let duration: u32 = auction_item
+				.end_time
+				.saturating_sub(auction_item.start_time)
+				.saturated_into();
+			let elapsed: u32 = now.saturating_sub(auction_item.start_time).saturated_into();
+			if duration.is_zero() || elapsed >= duration {
+				return Some(floor_price);
+			}
+
+			let decay = Perbill::from_rational(elapsed, duration) * auction_item.amount.saturating_sub(floor_price);
+			Some(auction_item.amount.saturating_sub(decay))
+		}
+
+		/// Minimum amount a new bid has to exceed the highest bid by, the default bid increment share
+		/// of the highest bid if the auction has no rule. With an end increment the minimum moves
+		/// linearly from the start to the end increment over the listing period.
+		pub fn minimum_bid_increment(auction_id: AuctionId, highest_bid: BalanceOf<T>) -> BalanceOf<T> {
+			let rule = match Self::bid_increment_rule(auction_id) {
+				Some(rule) => rule,
+				None => return T::DefaultBidIncrement::get() * highest_bid,
+			};
+			let start_increment = Self::bid_increment_amount(&rule.start, highest_bid);
+			let end_increment = match rule.end {
+				Some(ref end) => Self::bid_increment_amount(end, highest_bid),
+				None => return start_increment,
+			};
+			let auction_item = match Self::get_auction_item(auction_id) {
+				Some(auction_item) => auction_item,
+				None => return start_increment,
+			};
+
+			let now = <system::Pallet<T>>::block_number();
+			let duration: u32 = auction_item
+				.end_time
+				.saturating_sub(auction_item.start_time)
+				.saturated_into();
+			let elapsed: u32 = now.saturating_sub(auction_item.start_time).saturated_into();
+			if duration.is_zero() || elapsed >= duration {
+				return end_increment;
+			}
+
+			let progress = Perbill::from_rational(elapsed, duration);
+			if end_increment >= start_increment {
+				start_increment.saturating_add(progress * end_increment.saturating_sub(start_increment))
+			} else {
+				start_increment.saturating_sub(progress * start_increment.saturating_sub(end_increment))
+			}
+		}
+
+		fn bid_increment_amount(increment: &BidIncrement<BalanceOf<T>>, highest_bid: BalanceOf<T>) -> BalanceOf<T> {
+			match increment {
+				BidIncrement::Absolute(amount) => *amount,
+				BidIncrement::Percentage(share) => *share * highest_bid,
+			}
+		}
+
+		/// Active auctions with an id in start..start + limit in ascending order. The limit is capped
+		/// at MAX_ACTIVE_AUCTIONS_PAGE, the next page starts at start + limit
+		pub fn active_auctions(
+			start: AuctionId,
+			limit: u32,
+		) -> Vec<ActiveAuctionSummary<T::AccountId, BalanceOf<T>, T::BlockNumber>> {
+			let end = start
+				.saturating_add(limit.min(MAX_ACTIVE_AUCTIONS_PAGE) as AuctionId)
+				.min(Self::auctions_index());
+			(start..end)
+				.filter_map(|auction_id| {
+					let auction = Self::auctions(auction_id)?;
+					let auction_item = Self::get_auction_item(auction_id)?;
+					Some(ActiveAuctionSummary {
+						auction_id,
+						item_id: auction_item.item_id,
+						auction_type: auction_item.auction_type,
+						seller: auction_item.recipient,
+						amount: auction_item.amount,
+						bid: auction.bid,
+						end: auction.end,
+						currency_id: auction_item.currency_id,
+					})
+				})
+				.collect()
+		}
+
+		/// Place a bid on an auction on behalf of the bidder
+		fn do_bid(from: T::AccountId, id: AuctionId, value: BalanceOf<T>) -> DispatchResult {
+			let auction_item: AuctionItem<T::AccountId, T::BlockNumber, BalanceOf<T>> =
+				Self::get_auction_item(id.clone()).ok_or(Error::<T>::AuctionNotExist)?;
+			ensure!(
+				auction_item.auction_type == AuctionType::Auction,
+				Error::<T>::InvalidAuctionType
+			);
+			ensure!(auction_item.recipient != from, Error::<T>::SelfBidNotAccepted);
+			Self::ensure_expansion_right(&auction_item, &from)?;
+			Self::ensure_allowlisted_bidder(id, &from)?;
+			Self::ensure_land_buyer_allowed(&auction_item.item_id, &from)?;
+
+			<Auctions<T>>::try_mutate_exists(id, |auction| -> DispatchResult {
+				let mut auction = auction.as_mut().ok_or(Error::<T>::AuctionNotExist)?;
+
+				let block_number = <system::Pallet<T>>::block_number();
+
+				// make sure auction is started
+				ensure!(block_number >= auction.start, Error::<T>::AuctionNotStarted);
+
+				let auction_end: Option<T::BlockNumber> = auction.end;
+
+				ensure!(block_number < auction_end.unwrap(), Error::<T>::AuctionIsExpired);
+
+				if let Some(ref current_bid) = auction.bid {
+					ensure!(value > current_bid.1, Error::<T>::InvalidBidPrice);
+					let min_increment = Self::minimum_bid_increment(id, current_bid.1);
+					ensure!(
+						value >= current_bid.1.saturating_add(min_increment),
+						Error::<T>::BidBelowMinimumIncrement
+					);
+				} else {
+					ensure!(!value.is_zero(), Error::<T>::InvalidBidPrice);
+				}
+				// implement hooks for future event
+				let bid_result = T::Handler::on_new_bid(block_number, id, (from.clone(), value), auction.bid.clone());
+
+				ensure!(bid_result.accept_bid, Error::<T>::BidNotAccepted);
+
+				ensure!(
+					Self::listing_currency_free_balance(auction_item.currency_id, &from) >= value,
+					Error::<T>::InsufficientFreeBalance
+				);
+
+				if auction_item.currency_id == FungibleTokenId::NativeToken(0) {
+					Self::auction_bid_handler(block_number, id, (from.clone(), value), auction.bid.clone())?;
+				} else {
+					Self::local_auction_bid_handler(
+						block_number,
+						id,
+						(from.clone(), value),
+						auction.bid.clone(),
+						auction_item.currency_id,
+					)?;
+				}
+
+				auction.bid = Some((from.clone(), value));
+				Self::deposit_event(Event::Bid(id, from, value));
+				Self::extend_sniped_auction(id, auction, block_number);
+
+				// A bid at or above the buy now price makes the instant purchase price obsolete
+				if Self::auction_buy_now_price(id).map_or(false, |price| value >= price) {
+					AuctionBuyNowPrices::<T>::remove(id);
+					Self::deposit_event(Event::<T>::AuctionBuyNowPriceUpdated(id, None));
+				}
+
+				Ok(())
+			})?;
+
+			Ok(())
+		}
+
+		/// Buy a listing at its buy now price on behalf of the buyer
+		fn do_buy_now(from: T::AccountId, auction_id: AuctionId, value: BalanceOf<T>) -> DispatchResult {
+			let auction = Self::auctions(auction_id.clone()).ok_or(Error::<T>::AuctionNotExist)?;
+			let auction_item = Self::get_auction_item(auction_id.clone()).ok_or(Error::<T>::AuctionNotExist)?;
+
+			let buy_now_price = Self::auction_buy_now_price(auction_id);
+			ensure!(
+				auction_item.auction_type == AuctionType::BuyNow
+					|| auction_item.auction_type == AuctionType::Dutch
+					|| (auction_item.auction_type == AuctionType::Auction && buy_now_price.is_some()),
+				Error::<T>::InvalidAuctionType
+			);
+
+			ensure!(auction_item.recipient != from, Error::<T>::CannotBidOnOwnAuction);
+			Self::ensure_expansion_right(&auction_item, &from)?;
+			Self::ensure_allowlisted_bidder(auction_id, &from)?;
+			Self::ensure_land_buyer_allowed(&auction_item.item_id, &from)?;
+
+			let block_number = <system::Pallet<T>>::block_number();
+			ensure!(block_number >= auction.start, Error::<T>::AuctionNotStarted);
+			if !(auction.end.is_none()) {
+				let auction_end: T::BlockNumber = auction.end.unwrap();
+				ensure!(block_number < auction_end, Error::<T>::AuctionIsExpired);
+			}
+
+			// Dutch auction is won by the first buyer at or above the current price, who pays the current price
+			let value = match (auction_item.auction_type, buy_now_price) {
+				(AuctionType::Dutch, _) => {
+					let price = Self::dutch_auction_price(auction_id).ok_or(Error::<T>::AuctionNotExist)?;
+					ensure!(value >= price, Error::<T>::InvalidBuyItNowPrice);
+					price
+				}
+				(AuctionType::Auction, Some(price)) => {
+					ensure!(value == price, Error::<T>::InvalidBuyItNowPrice);
+					if let Some((_, high_bid_price)) = auction.bid {
+						ensure!(price > high_bid_price, Error::<T>::InvalidBuyItNowPrice);
+					}
+					value
+				}
+				_ => {
+					ensure!(value == auction_item.amount, Error::<T>::InvalidBuyItNowPrice);
+					value
+				}
+			};
+			ensure!(
+				Self::listing_currency_free_balance(auction_item.currency_id, &from) >= value,
+				Error::<T>::InsufficientFunds
+			);
+
+			Self::remove_auction(auction_id.clone(), auction_item.item_id);
+			// Buying an auction at its buy now price releases the escrow of the highest bidder
+			if let Some((high_bidder, high_bid_price)) = auction.bid {
+				let shortfall =
+					Self::unreserve_in_listing_currency(auction_item.currency_id, &high_bidder, high_bid_price);
+				Self::record_escrow_shortfall(auction_id, &high_bidder, shortfall);
+			}
+
+			// Transfer balance from buy it now user to asset owner
+			let currency_transfer = match auction_item.item_id {
+				ItemId::Estate(_) | ItemId::Bundle(_) => {
+					Self::pay_and_hand_over(auction_id, &auction_item, &from, value)
+				}
+				_ => {
+					Self::transfer_in_listing_currency(auction_item.currency_id, &from, &auction_item.recipient, value)
+				}
+			};
+			let winning_bid = currency_transfer.as_ref().ok().map(|_| (from.clone(), value));
+			Self::record_settled_auction(auction_id, &auction_item, winning_bid);
+			match currency_transfer {
+				Err(_e) => {}
+				Ok(_v) => {
+					// Transfer asset from asset owner to buy it now user
+					<ItemsInAuction<T>>::remove(auction_item.item_id);
+					match auction_item.item_id {
+						ItemId::NFT(class_id, token_id) => {
+							Self::split_sale_proceeds(
+								auction_id,
+								&auction_item.item_id,
+								value,
+								&auction_item.recipient,
+								auction_item.currency_id,
+							);
+
+							let asset_transfer =
+								T::NFTHandler::transfer_nft(&auction_item.recipient, &from, &(class_id, token_id));
+							match asset_transfer {
+								Err(_) => (),
+								Ok(_) => {
+									Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
+								}
+							}
+						}
+						ItemId::Spot(spot_id, metaverse_id) => {
+							let continuum_spot = T::ContinuumHandler::transfer_spot(
+								spot_id,
+								&auction_item.recipient,
+								&(from.clone(), metaverse_id),
+							);
+							match continuum_spot {
+								Err(_) => (),
+								Ok(_) => {
+									Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
+								}
+							}
+						}
+						ItemId::Estate(estate_id) => {
+							// The estate was handed over together with the payment
+							Self::split_sale_proceeds(
+								auction_id,
+								&auction_item.item_id,
+								value,
+								&auction_item.recipient,
+								auction_item.currency_id,
+							);
+							T::EstateHandler::record_estate_sale(estate_id, value.saturated_into());
+							Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
+						}
+						ItemId::LandUnit(coordinate, metaverse_id) => {
+							let land_unit = T::EstateHandler::transfer_landunit(
+								coordinate,
+								&auction_item.recipient,
+								&(from.clone(), metaverse_id),
+							);
+							match land_unit {
+								Err(_) => (),
+								Ok(_) => {
+									Self::split_sale_proceeds(
+										auction_id,
+										&auction_item.item_id,
+										value,
+										&auction_item.recipient,
+										auction_item.currency_id,
+									);
+									Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
+								}
+							}
+						}
+						ItemId::Bundle(_) => {
+							// The bundle was handed over together with the payment
+							Self::deposit_event(Event::BuyNowFinalised(auction_id, from, value));
+						}
+						_ => {} // Future implementation for Land, Metaverse
+					}
+				}
+			}
+			if let ItemId::Bundle(bundle_id) = auction_item.item_id {
+				Self::release_bundle(bundle_id);
+			}
+
+			Ok(())
+		}
+
+		/// Mirror new listing to partner marketplaces if the seller opted in, a failed notification
+		/// does not block the local listing
+		fn cross_list_auction(auction_id: AuctionId) {
+			let auction_item = match Self::get_auction_item(auction_id) {
+				Some(auction_item) => auction_item,
+				None => return,
+			};
+			if !CrossListingOptIn::<T>::contains_key(&auction_item.recipient) {
+				return;
+			}
+
+			let listing = VersionedCrossListing::V1(CrossListingV1 {
+				auction_id,
+				item_id: auction_item.item_id,
+				recipient: auction_item.recipient,
+				amount: auction_item.amount,
+				end_time: auction_item.end_time,
+				auction_type: auction_item.auction_type,
+			});
+
+			let notified: Vec<MarketplaceId> = PartnerMarketplaces::<T>::iter()
+				.filter_map(|(marketplace_id, marketplace)| {
+					match T::CrossListingHandler::notify_listing(marketplace_id, &marketplace, listing.clone()) {
+						Ok(_) => Some(marketplace_id),
+						Err(e) => {
+							log::warn!("Cross listing to marketplace {:?} failed: {:?}", marketplace_id, e);
+							None
+						}
+					}
+				})
+				.collect();
+
+			CrossListedAuctions::<T>::insert(auction_id, ());
+			Self::deposit_event(Event::<T>::AuctionCrossListed(auction_id, notified));
+		}
+
+		/// Ensure bundle items are owned by the seller, supported and not listed elsewhere
+		fn ensure_bundle_items(owner: &T::AccountId, items: &[ItemId]) -> DispatchResult {
+			ensure!(!items.is_empty(), Error::<T>::EmptyBundle);
+			ensure!(
+				items.len() as u32 <= T::MaxBundleItems::get(),
+				Error::<T>::ExceedMaximumBundleItems
+			);
+
+			for (index, item_id) in items.iter().enumerate() {
+				ensure!(!items[..index].contains(item_id), Error::<T>::InvalidBundleItem);
+				ensure!(
+					Self::items_in_auction(item_id) == None,
+					Error::<T>::ItemAlreadyInAuction
+				);
+
+				let is_owner = match *item_id {
+					ItemId::NFT(class_id, token_id) => {
+						T::NFTHandler::check_ownership(owner, &(class_id, token_id))?
+							&& T::NFTHandler::is_transferable(&(class_id, token_id))?
+					}
+					ItemId::Estate(estate_id) => {
+						ensure!(
+							!T::EstateHandler::is_estate_frozen(estate_id),
+							Error::<T>::EstateIsFrozen
+						);
+						ensure!(
+							T::EstateHandler::is_land_tax_settled(estate_id),
+							Error::<T>::LandTaxOutstanding
+						);
+						T::EstateHandler::check_estate_ownership(owner, estate_id)?
+					}
+					ItemId::LandUnit(coordinate, metaverse_id) => {
+						T::EstateHandler::check_landunit_ownership(owner, metaverse_id, coordinate)?
+					}
+					_ => return Err(Error::<T>::InvalidBundleItem.into()),
+				};
+				ensure!(is_owner, Error::<T>::NotItemOwner);
+			}
+			Ok(())
+		}
+
+		/// Estate owners listing on the marketplace are still active for beneficiary inheritance
+		fn record_estate_owner_activity(owner: &T::AccountId, items: &[ItemId]) {
+			if items.iter().any(|item_id| matches!(item_id, ItemId::Estate(_))) {
+				T::EstateHandler::record_owner_heartbeat(owner);
+			}
+		}
+
+		/// Transfer all bundle items to the winner, reverting every transfer if one of them fails
+		#[transactional]
+		fn transfer_bundle(bundle_id: BundleId, from: &T::AccountId, to: &T::AccountId) -> DispatchResult {
+			let items = Self::get_bundle(bundle_id).ok_or(Error::<T>::BundleDoesNotExist)?;
+			for item_id in items {
+				match item_id {
+					ItemId::NFT(class_id, token_id) => {
+						T::NFTHandler::transfer_nft(from, to, &(class_id, token_id))?;
+					}
+					ItemId::Estate(estate_id) => {
+						T::EstateHandler::transfer_estate(estate_id, from, to)?;
+					}
+					ItemId::LandUnit(coordinate, metaverse_id) => {
+						T::EstateHandler::transfer_landunit(coordinate, from, &(to.clone(), metaverse_id))?;
+					}
+					_ => return Err(Error::<T>::InvalidBundleItem.into()),
+				}
+			}
+			Ok(())
+		}
+
+		/// Swap items follow the bundle item rules, a side of a swap may have no items
+		fn ensure_swap_items(owner: &T::AccountId, items: &[ItemId]) -> DispatchResult {
+			if items.is_empty() {
+				return Ok(());
+			}
+			Self::ensure_bundle_items(owner, items)
+		}
 
-					let start_time = <system::Pallet<T>>::block_number();
-					let end_time: T::BlockNumber = start_time + T::AuctionTimeToClose::get(); // add 7 days block for default auction
-					let auction_id = Self::new_auction(recipient.clone(), initial_amount, start_time, Some(end_time))?;
+		/// Land units and estates of a swap side only go to a receiver allowed to buy land in
+		/// their metaverse
+		fn ensure_swap_receiver_allowed(items: &[ItemId], receiver: &T::AccountId) -> DispatchResult {
+			items
+				.iter()
+				.try_for_each(|item_id| Self::ensure_land_buyer_allowed(item_id, receiver))
+		}
 
-					let new_auction_item = AuctionItem {
-						item_id,
-						recipient: recipient.clone(),
-						initial_amount,
-						amount: initial_amount,
-						start_time,
-						end_time,
-						auction_type,
-						listing_level: ListingLevel::Global,
-						currency_id: FungibleTokenId::NativeToken(0),
-					};
+		fn transfer_swap_items(items: &[ItemId], from: &T::AccountId, to: &T::AccountId) -> DispatchResult {
+			for item_id in items {
+				// Estates and land units only move while held by the marketplace
+				<ItemsInAuction<T>>::insert(item_id, true);
+				let transfer = match *item_id {
+					ItemId::NFT(class_id, token_id) => T::NFTHandler::transfer_nft(from, to, &(class_id, token_id)),
+					ItemId::Estate(estate_id) => T::EstateHandler::transfer_estate(estate_id, from, to).map(|_| ()),
+					ItemId::LandUnit(coordinate, metaverse_id) => {
+						T::EstateHandler::transfer_landunit(coordinate, from, &(to.clone(), metaverse_id)).map(|_| ())
+					}
+					_ => Err(Error::<T>::InvalidBundleItem.into()),
+				};
+				<ItemsInAuction<T>>::remove(item_id);
+				transfer?;
+			}
+			Ok(())
+		}
 
-					<AuctionItems<T>>::insert(auction_id, new_auction_item);
+		/// Archive era of the given block
+		pub fn archive_era(block_number: T::BlockNumber) -> T::BlockNumber {
+			let era_length = T::ArchiveEraLength::get();
+			if era_length.is_zero() {
+				return Zero::zero();
+			}
+			block_number / era_length
+		}
 
-					Self::deposit_event(Event::NewAuctionItem(
-						auction_id,
-						recipient,
-						listing_level,
-						initial_amount,
-						initial_amount,
-						end_time,
-					));
-					<ItemsInAuction<T>>::insert(item_id, true);
-					Ok(auction_id)
+		/// Keep compact summary of settled auction until its archive era is pruned
+		fn record_settled_auction(
+			auction_id: AuctionId,
+			auction_item: &AuctionItem<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+			winning_bid: Option<(T::AccountId, BalanceOf<T>)>,
+		) {
+			let (winner, price) = match winning_bid {
+				Some((winner, price)) => (Some(winner), price),
+				None => (None, Zero::zero()),
+			};
+			let fees = match winner {
+				Some(_) => {
+					let royalty = Self::sale_royalty(&auction_item.item_id, price)
+						.map(|(_, royalty)| royalty)
+						.unwrap_or_else(Zero::zero);
+					royalty.saturating_add(T::NetworkFee::get() * price)
 				}
-				_ => Err(Error::<T>::AuctionTypeIsNotSupported.into()),
+				None => Zero::zero(),
+			};
+			T::ActivityRecorder::record_activity(ActivityCounter::AuctionsSettled, 1);
+			let era = Self::archive_era(<system::Pallet<T>>::block_number());
+			SettledAuctions::<T>::insert(
+				era,
+				auction_id,
+				AuctionSummary {
+					item_id: auction_item.item_id,
+					winner,
+					price,
+					fees,
+				},
+			);
+		}
+
+		/// Free balance of an account in the currency of a listing
+		fn listing_currency_free_balance(currency_id: FungibleTokenId, who: &T::AccountId) -> BalanceOf<T> {
+			if currency_id == FungibleTokenId::NativeToken(0) {
+				<T as Config>::Currency::free_balance(who)
+			} else {
+				T::FungibleTokenCurrency::free_balance(currency_id, who).saturated_into()
 			}
 		}
 
-		fn remove_auction(id: AuctionId, item_id: ItemId) {
-			if let Some(auction) = <Auctions<T>>::get(&id) {
-				if let Some(end_block) = auction.end {
-					<AuctionEndTime<T>>::remove(end_block, id);
-					<Auctions<T>>::remove(&id);
-					<ItemsInAuction<T>>::remove(item_id);
-				}
+		/// Release escrowed bid in the currency of a listing, returns the amount that could not be
+		/// released
+		fn unreserve_in_listing_currency(
+			currency_id: FungibleTokenId,
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> BalanceOf<T> {
+			if currency_id == FungibleTokenId::NativeToken(0) {
+				<T as Config>::Currency::unreserve(who, amount)
+			} else {
+				T::FungibleTokenCurrency::unreserve(currency_id, who, amount.saturated_into()).saturated_into()
 			}
 		}
 
-		fn auction_bid_handler(
-			_now: T::BlockNumber,
-			id: AuctionId,
-			new_bid: (T::AccountId, Self::Balance),
-			last_bid: Option<(T::AccountId, Self::Balance)>,
+		/// Pay for a listing in its currency
+		fn transfer_in_listing_currency(
+			currency_id: FungibleTokenId,
+			from: &T::AccountId,
+			to: &T::AccountId,
+			amount: BalanceOf<T>,
 		) -> DispatchResult {
-			let (new_bidder, new_bid_price) = new_bid;
-			ensure!(!new_bid_price.is_zero(), Error::<T>::InvalidBidPrice);
+			if currency_id == FungibleTokenId::NativeToken(0) {
+				<T as Config>::Currency::transfer(from, to, amount, ExistenceRequirement::KeepAlive)
+			} else {
+				T::FungibleTokenCurrency::transfer(currency_id, from, to, amount.saturated_into())
+			}
+		}
 
-			<AuctionItems<T>>::try_mutate_exists(id, |auction_item| -> DispatchResult {
-				let mut auction_item = auction_item.as_mut().ok_or(Error::<T>::AuctionNotExist)?;
+		/// Pay the seller and hand the item over to the buyer. If the handover fails the payment is
+		/// reverted, so the buyer keeps the funds
+		fn pay_and_hand_over(
+			auction_id: AuctionId,
+			auction_item: &AuctionItem<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+			buyer: &T::AccountId,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			let settlement = Self::do_pay_and_hand_over(auction_item, buyer, price);
+			if let Err(e) = settlement {
+				Self::deposit_event(Event::SaleHandoverFailed(auction_id, buyer.clone(), price, e));
+			}
+			settlement
+		}
 
-				match auction_item.clone().listing_level {
-					ListingLevel::NetworkSpot(allowed_bidders) => {
-						ensure!(allowed_bidders.contains(&new_bidder), Error::<T>::BidNotAccepted);
-					}
-					_ => {}
+		#[transactional]
+		fn do_pay_and_hand_over(
+			auction_item: &AuctionItem<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+			buyer: &T::AccountId,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			Self::transfer_in_listing_currency(auction_item.currency_id, buyer, &auction_item.recipient, price)?;
+			match auction_item.item_id {
+				ItemId::Estate(estate_id) => {
+					// Estates only move while held by the marketplace
+					<ItemsInAuction<T>>::insert(auction_item.item_id, true);
+					let transfer = T::EstateHandler::transfer_estate(estate_id, &auction_item.recipient, buyer);
+					<ItemsInAuction<T>>::remove(auction_item.item_id);
+					transfer?;
 				}
+				ItemId::Bundle(bundle_id) => {
+					Self::transfer_bundle(bundle_id, &auction_item.recipient, buyer)?;
+				}
+				_ => return Err(Error::<T>::AuctionTypeIsNotSupported.into()),
+			}
+			Ok(())
+		}
 
-				let last_bid_price = last_bid.clone().map_or(Zero::zero(), |(_, price)| price); // get last bid price
-				let last_bidder = last_bid.as_ref().map(|(who, _)| who);
-
-				if let Some(last_bidder) = last_bidder {
-					//unlock reserve amount
-					if !last_bid_price.is_zero() {
-						//Unreserve balance of last bidder
-						<T as Config>::Currency::unreserve(&last_bidder, last_bid_price);
+		/// Royalty recipient and amount owed on a sale of the item at the given price
+		fn sale_royalty(item_id: &ItemId, price: BalanceOf<T>) -> Option<(T::AccountId, BalanceOf<T>)> {
+			let (recipient, royalty) = match *item_id {
+				ItemId::NFT(class_id, token_id) => match T::NFTHandler::get_token_royalty(&(class_id, token_id)) {
+					Some(token_royalty) => token_royalty,
+					None => {
+						let class_royalty = T::NFTHandler::get_nft_detail((class_id, token_id)).ok()?.royalty_fee;
+						(T::NFTHandler::get_class_fund(&class_id), class_royalty)
 					}
+				},
+				ItemId::Estate(estate_id) => T::EstateHandler::get_estate_royalty(estate_id)?,
+				_ => return None,
+			};
+			Some((recipient, royalty * price))
+		}
+
+		/// Pay royalty and network fee out of the seller proceeds of a settled sale
+		fn split_sale_proceeds(
+			auction_id: AuctionId,
+			item_id: &ItemId,
+			price: BalanceOf<T>,
+			seller: &T::AccountId,
+			currency_id: FungibleTokenId,
+		) {
+			// Locally listed items earn fees for the metaverse they are listed in
+			let listing_metaverse =
+				Self::get_auction_item(auction_id).and_then(|auction_item| match auction_item.listing_level {
+					ListingLevel::Local(metaverse_id) => Some(metaverse_id),
+					_ => None,
+				});
+			let (seller_amount, royalty, network_fee) =
+				Self::pay_sale_fees(item_id, price, seller, currency_id, listing_metaverse);
+			Self::deposit_event(Event::SaleProceedsSplit(
+				auction_id,
+				seller_amount,
+				royalty,
+				network_fee,
+			));
+		}
+
+		/// Transfer royalty and network fee of a sale from the seller, returning the seller amount,
+		/// royalty and network fee actually paid
+		fn pay_sale_fees(
+			item_id: &ItemId,
+			price: BalanceOf<T>,
+			seller: &T::AccountId,
+			currency_id: FungibleTokenId,
+			listing_metaverse: Option<MetaverseId>,
+		) -> (BalanceOf<T>, BalanceOf<T>, BalanceOf<T>) {
+			let mut royalty: BalanceOf<T> = Zero::zero();
+			if let Some((recipient, amount)) = Self::sale_royalty(item_id, price) {
+				if recipient != *seller
+					&& Self::transfer_in_listing_currency(currency_id, seller, &recipient, amount).is_ok()
+				{
+					royalty = amount;
 				}
+			}
 
-				// Lock fund of new bidder
-				// Reserve balance
-				<T as Config>::Currency::reserve(&new_bidder, new_bid_price)?;
-				auction_item.amount = new_bid_price.clone();
+			let network_fee: BalanceOf<T> = T::NetworkFee::get() * price;
+			let mut treasury_fee = network_fee;
+			let mut paid_network_fee: BalanceOf<T> = Zero::zero();
+			if let Some(metaverse_id) = Self::item_metaverse(item_id).or(listing_metaverse) {
+				let metaverse_fee = T::MetaverseFeeShare::get() * network_fee;
+				treasury_fee = network_fee.saturating_sub(metaverse_fee);
+				let metaverse_treasury = T::MetaverseInfoSource::get_metaverse_treasury(metaverse_id);
+				if !metaverse_fee.is_zero()
+					&& Self::transfer_in_listing_currency(currency_id, seller, &metaverse_treasury, metaverse_fee)
+						.is_ok()
+				{
+					paid_network_fee = metaverse_fee;
+					Self::deposit_event(Event::MetaverseTreasuryFeePaid(metaverse_id, metaverse_fee));
+				}
+			}
+			if !treasury_fee.is_zero()
+				&& Self::transfer_in_listing_currency(currency_id, seller, &T::NetworkFeeAccount::get(), treasury_fee)
+					.is_ok()
+			{
+				paid_network_fee = paid_network_fee.saturating_add(treasury_fee);
+			}
+			let network_fee = paid_network_fee;
 
-				Ok(())
-			})
+			let seller_amount = price.saturating_sub(royalty).saturating_sub(network_fee);
+			(seller_amount, royalty, network_fee)
 		}
 
-		fn local_auction_bid_handler(
-			_now: T::BlockNumber,
-			id: AuctionId,
-			new_bid: (T::AccountId, Self::Balance),
-			last_bid: Option<(T::AccountId, Self::Balance)>,
-			social_currency_id: FungibleTokenId,
-		) -> DispatchResult {
-			let (new_bidder, new_bid_price) = new_bid;
-			ensure!(!new_bid_price.is_zero(), Error::<T>::InvalidBidPrice);
-
-			<AuctionItems<T>>::try_mutate_exists(id, |auction_item| -> DispatchResult {
-				let mut auction_item = auction_item.as_mut().ok_or(Error::<T>::AuctionNotExist)?;
+		/// Settle auctions ended before the current block in end block order, at most
+		/// MaxSettlementsPerBlock per block. Auctions left over are settled in the next blocks.
+		fn settle_ended_auctions(now: T::BlockNumber) -> Weight {
+			let max_settlements = T::MaxSettlementsPerBlock::get();
+			let mut cursor = Self::settlement_cursor().unwrap_or_else(|| now.saturating_sub(One::one()));
+			let mut settled: u32 = 0;
+			let mut visited_blocks: u32 = 0;
+
+			while cursor < now && settled < max_settlements && visited_blocks < max_settlements {
+				visited_blocks = visited_blocks.saturating_add(1);
+				let due: Vec<AuctionId> = <AuctionEndTime<T>>::iter_prefix(&cursor)
+					.map(|(auction_id, _)| auction_id)
+					.take(max_settlements.saturating_sub(settled) as usize)
+					.collect();
+				for auction_id in due {
+					<AuctionEndTime<T>>::remove(&cursor, auction_id);
+					Self::finalize_auction(auction_id);
+					settled = settled.saturating_add(1);
+				}
+				if <AuctionEndTime<T>>::iter_prefix(&cursor).next().is_some() {
+					break;
+				}
+				cursor = cursor.saturating_add(One::one());
+			}
+			SettlementCursor::<T>::put(cursor);
+
+			let settled = settled as Weight;
+			let visited_blocks = visited_blocks as Weight;
+			T::DbWeight::get().reads_writes(
+				settled
+					.saturating_mul(8)
+					.saturating_add(visited_blocks.saturating_mul(2))
+					.saturating_add(1),
+				settled.saturating_mul(12).saturating_add(1),
+			)
+		}
 
-				let last_bid_price = last_bid.clone().map_or(Zero::zero(), |(_, price)| price); // get last bid price
-				let last_bidder = last_bid.as_ref().map(|(who, _)| who);
+		/// Settle an ended auction, paying the seller and handing the item to the highest bidder
+		fn finalize_auction(auction_id: AuctionId) {
+			if let Some(auction) = <Auctions<T>>::get(&auction_id) {
+				if let Some(auction_item) = <AuctionItems<T>>::get(&auction_id) {
+					let winning_bid = Self::apply_hidden_reserve(auction_id, &auction_item, auction.bid);
+					let land_block_sale = match auction_item.item_id {
+						ItemId::Block(sale_id) => LandBlockSales::<T>::take(sale_id),
+						_ => None,
+					};
+					Self::remove_auction(auction_id.clone(), auction_item.item_id);
+					if auction_item.auction_type == AuctionType::SealedBid {
+						Self::forfeit_sealed_bid_deposits(auction_id, &auction_item.recipient);
+					}
+					// Transfer balance from high bidder to asset owner
+					if let Some(current_bid) = winning_bid {
+						let (high_bidder, high_bid_price): (T::AccountId, BalanceOf<T>) = current_bid;
+						// Handle listing
+						let shortfall =
+							Self::unreserve_in_listing_currency(auction_item.currency_id, &high_bidder, high_bid_price);
+						Self::record_escrow_shortfall(auction_id, &high_bidder, shortfall);
+
+						// Handle balance transfer
+						let currency_transfer = match auction_item.item_id {
+							ItemId::Estate(_) | ItemId::Bundle(_) => {
+								Self::pay_and_hand_over(auction_id, &auction_item, &high_bidder, high_bid_price)
+							}
+							_ => Self::transfer_in_listing_currency(
+								auction_item.currency_id,
+								&high_bidder,
+								&auction_item.recipient,
+								high_bid_price,
+							),
+						};
+						let winning_bid = currency_transfer
+							.as_ref()
+							.ok()
+							.map(|_| (high_bidder.clone(), high_bid_price));
+						Self::record_settled_auction(auction_id, &auction_item, winning_bid);
+
+						match currency_transfer {
+							Err(_e) => {}
+							Ok(_v) => {
+								// Transfer asset from asset owner to high bidder
+								// Check asset type and handle internal logic
+
+								match auction_item.item_id {
+									ItemId::NFT(class_id, token_id) => {
+										Self::split_sale_proceeds(
+											auction_id,
+											&auction_item.item_id,
+											high_bid_price,
+											&auction_item.recipient,
+											auction_item.currency_id,
+										);
+										let asset_transfer = T::NFTHandler::transfer_nft(
+											&auction_item.recipient,
+											&high_bidder,
+											&(class_id, token_id),
+										);
+
+										match asset_transfer {
+											Err(_) => return,
+											Ok(_) => {
+												Self::deposit_event(Event::AuctionFinalized(
+													auction_id,
+													high_bidder,
+													high_bid_price,
+												));
+											}
+										}
+									}
+									ItemId::Spot(spot_id, metaverse_id) => {
+										let continuum_spot = T::ContinuumHandler::transfer_spot(
+											spot_id,
+											&auction_item.recipient,
+											&(high_bidder.clone(), metaverse_id),
+										);
+										match continuum_spot {
+											Err(_) => return,
+											Ok(_) => {
+												Self::deposit_event(Event::AuctionFinalized(
+													auction_id,
+													high_bidder,
+													high_bid_price,
+												));
+											}
+										}
+									}
+									ItemId::Estate(estate_id) => {
+										// The estate was handed over together with the payment
+										Self::split_sale_proceeds(
+											auction_id,
+											&auction_item.item_id,
+											high_bid_price,
+											&auction_item.recipient,
+											auction_item.currency_id,
+										);
+										T::EstateHandler::record_estate_sale(
+											estate_id,
+											high_bid_price.saturated_into(),
+										);
+										Self::deposit_event(Event::AuctionFinalized(
+											auction_id,
+											high_bidder,
+											high_bid_price,
+										));
+									}
+									ItemId::LandUnit(coordinate, metaverse_id) => {
+										let land_unit = T::EstateHandler::transfer_landunit(
+											coordinate,
+											&auction_item.recipient,
+											&(high_bidder.clone(), metaverse_id),
+										);
+										match land_unit {
+											Err(_) => (),
+											Ok(_) => {
+												Self::split_sale_proceeds(
+													auction_id,
+													&auction_item.item_id,
+													high_bid_price,
+													&auction_item.recipient,
+													auction_item.currency_id,
+												);
+												Self::deposit_event(Event::AuctionFinalized(
+													auction_id,
+													high_bidder,
+													high_bid_price,
+												));
+											}
+										}
+									}
+									ItemId::Bundle(_) => {
+										// The bundle was handed over together with the payment
+										Self::deposit_event(Event::AuctionFinalized(
+											auction_id,
+											high_bidder,
+											high_bid_price,
+										));
+									}
+									ItemId::Block(_) => {
+										if let Some(sale) = land_block_sale {
+											let issued = T::UndeployedLandBlockHandler::issue_undeployed_land_blocks(
+												&high_bidder,
+												1,
+												sale.number_land_units,
+												sale.undeployed_land_block_type,
+											);
+											match issued {
+												Ok(undeployed_land_block_ids) => {
+													for undeployed_land_block_id in undeployed_land_block_ids {
+														Self::deposit_event(Event::LandBlockAuctionSettled(
+															auction_id,
+															high_bidder.clone(),
+															undeployed_land_block_id,
+														));
+													}
+													Self::deposit_event(Event::AuctionFinalized(
+														auction_id,
+														high_bidder,
+														high_bid_price,
+													));
+												}
+												Err(e) => {
+													// The winner already paid, refund the payment from the land sale treasury
+													let _ = <T as Config>::Currency::transfer(
+														&auction_item.recipient,
+														&high_bidder,
+														high_bid_price,
+														ExistenceRequirement::AllowDeath,
+													);
+													Self::deposit_event(Event::LandBlockIssuanceFailed(
+														auction_id,
+														high_bidder,
+														high_bid_price,
+														e,
+													));
+												}
+											}
+										}
+									}
+									_ => {} // Future implementation for Spot, Metaverse
+								}
+								<ItemsInAuction<T>>::remove(auction_item.item_id);
+							}
+						}
+					} else {
+						Self::record_settled_auction(auction_id, &auction_item, None);
+						Self::deposit_event(Event::AuctionFinalizedNoBid(auction_id));
+					}
+					if let ItemId::Bundle(bundle_id) = auction_item.item_id {
+						Self::release_bundle(bundle_id);
+					}
+				}
+			}
+		}
 
-				if let Some(last_bidder) = last_bidder {
-					// unlock reserve amount
-					if !last_bid_price.is_zero() {
-						// Un-reserve balance of last bidder
-						T::FungibleTokenCurrency::unreserve(
-							social_currency_id,
-							&last_bidder,
-							last_bid_price.saturated_into(),
-						);
+		/// Highest bid of an ending auction if it meets the hidden reserve, the escrow of a bid below
+		/// the reserve is released. A seller who never revealed the reserve pays the highest bidder the
+		/// cancellation penalty, as if the auction had been cancelled
+		fn apply_hidden_reserve(
+			auction_id: AuctionId,
+			auction_item: &AuctionItem<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+			bid: Option<(T::AccountId, BalanceOf<T>)>,
+		) -> Option<(T::AccountId, BalanceOf<T>)> {
+			if !HiddenReserves::<T>::contains_key(auction_id) {
+				return bid;
+			}
+			let (high_bidder, high_bid_price) = bid?;
+			let reserve = Self::revealed_reserve(auction_id);
+			match reserve {
+				Some(reserve) if high_bid_price >= reserve => Some((high_bidder, high_bid_price)),
+				_ => {
+					let shortfall =
+						Self::unreserve_in_listing_currency(auction_item.currency_id, &high_bidder, high_bid_price);
+					Self::record_escrow_shortfall(auction_id, &high_bidder, shortfall);
+					if reserve.is_none() {
+						let penalty = T::AuctionCancellationPenalty::get() * high_bid_price;
+						if !penalty.is_zero()
+							&& Self::transfer_in_listing_currency(
+								auction_item.currency_id,
+								&auction_item.recipient,
+								&high_bidder,
+								penalty,
+							)
+							.is_ok()
+						{
+							Self::deposit_event(Event::UnrevealedReservePenaltyPaid(auction_id, penalty));
+						}
 					}
+					Self::deposit_event(Event::HiddenReserveNotMet(auction_id, reserve));
+					None
 				}
+			}
+		}
 
-				// Lock fund of new bidder
-				// Reserve balance
-				T::FungibleTokenCurrency::reserve(social_currency_id, &new_bidder, new_bid_price.saturated_into())?;
-				auction_item.amount = new_bid_price.clone();
+		/// Metaverse a land unit, spot or estate lives in
+		fn item_metaverse(item_id: &ItemId) -> Option<MetaverseId> {
+			match *item_id {
+				ItemId::LandUnit(_, metaverse_id) | ItemId::Spot(_, metaverse_id) => Some(metaverse_id),
+				ItemId::Estate(estate_id) => T::EstateHandler::get_estate_metaverse(estate_id),
+				_ => None,
+			}
+		}
 
-				Ok(())
-			})
+		/// Remove an offer from the offer book of the item
+		fn remove_offer(item_id: ItemId, buyer: &T::AccountId) {
+			Offers::<T>::remove(item_id, buyer);
+			OfferCount::<T>::mutate(item_id, |count| *count = count.saturating_sub(1));
 		}
 
-		fn auction_info(id: AuctionId) -> Option<AuctionInfo<T::AccountId, Self::Balance, T::BlockNumber>> {
-			Self::auctions(id)
+		/// Release the escrow of expired offers on the item to make room in its offer book
+		fn remove_expired_offers(item_id: ItemId, now: T::BlockNumber) {
+			let expired: Vec<(T::AccountId, BalanceOf<T>)> = Offers::<T>::iter_prefix(item_id)
+				.filter(|(_, offer)| offer.expiry < now)
+				.map(|(buyer, offer)| (buyer, offer.amount))
+				.collect();
+			for (buyer, amount) in expired {
+				<T as Config>::Currency::unreserve(&buyer, amount);
+				Self::remove_offer(item_id, &buyer);
+				Self::deposit_event(Event::<T>::OfferWithdrawn(item_id, buyer));
+			}
 		}
 
-		fn collect_royalty_fee(
-			high_bid_price: &Self::Balance,
-			high_bidder: &T::AccountId,
-			asset_id: &(ClassId, TokenId),
-			social_currency_id: FungibleTokenId,
-		) -> DispatchResult {
-			let fee_scale = T::RoyaltyFee::get();
-			// Calculate loyalty fee and deposit to pot fund
-			let royalty_fee = high_bid_price
-				.saturating_mul(fee_scale.into())
-				.checked_div(&10000u128.saturated_into())
-				.ok_or("Overflow")?;
+		/// Extend the auction end when a bid arrives within the anti-snipe threshold of the end,
+		/// up to the maximum number of extensions
+		fn extend_sniped_auction(
+			id: AuctionId,
+			auction: &mut AuctionInfo<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+			now: T::BlockNumber,
+		) {
+			let end = match auction.end {
+				Some(end) => end,
+				None => return,
+			};
+			if end.saturating_sub(now) > T::AntiSnipeThreshold::get() {
+				return;
+			}
+			let extensions = Self::auction_extensions(id);
+			if extensions >= T::MaxAuctionExtensions::get() {
+				return;
+			}
+
+			let new_end = end.saturating_add(T::AntiSnipeExtension::get());
+			<AuctionEndTime<T>>::remove(end, id);
+			<AuctionEndTime<T>>::insert(new_end, id, ());
+			auction.end = Some(new_end);
+			<AuctionItems<T>>::mutate(id, |auction_item| {
+				if let Some(auction_item) = auction_item {
+					auction_item.end_time = new_end;
+				}
+			});
+			AuctionExtensions::<T>::insert(id, extensions.saturating_add(1));
+			Self::deposit_event(Event::AuctionExtended(id, new_end));
+		}
 
-			// Collect loyalty fee
-			// and deposit to class fund
+		/// Deposits of sealed bids that were never revealed go to the seller
+		fn forfeit_sealed_bid_deposits(auction_id: AuctionId, seller: &T::AccountId) {
+			for (bidder, (_, deposit)) in SealedBidCommitments::<T>::drain_prefix(auction_id) {
+				let forfeited = deposit.saturating_sub(
+					<T as Config>::Currency::repatriate_reserved(&bidder, seller, deposit, BalanceStatus::Free)
+						.unwrap_or(deposit),
+				);
+				Self::deposit_event(Event::SealedBidForfeited(auction_id, bidder, forfeited));
+			}
+		}
 
-			// Get royalty fee
-			//			let fee = T::NFTHandler::get_nft_detail((asset_id.0,
-			// asset_id.1)).ok_or(Error::<T>::AssetIsNotExist)?;
+		/// Flag bid escrow that could not be fully released for the invariant monitor
+		fn record_escrow_shortfall(auction_id: AuctionId, bidder: &T::AccountId, shortfall: BalanceOf<T>) {
+			if shortfall.is_zero() {
+				return;
+			}
+			EscrowShortfall::<T>::put(auction_id);
+			Self::deposit_event(Event::EscrowShortfallDetected(auction_id, bidder.clone(), shortfall));
+		}
 
-			let class_fund = T::NFTHandler::get_class_fund(&asset_id.0);
-			// Transfer loyalty fee from winner to class fund pot
-			if social_currency_id == FungibleTokenId::NativeToken(0) {
-				<T as Config>::Currency::transfer(
-					&high_bidder,
-					&class_fund,
-					royalty_fee,
-					ExistenceRequirement::KeepAlive,
-				)?;
-			} else {
-				T::FungibleTokenCurrency::transfer(
-					social_currency_id.clone(),
-					&high_bidder,
-					&class_fund,
-					royalty_fee.saturated_into(),
-				)?;
+		/// Binary merkle root of settled auction summaries, odd node is carried to the next level
+		pub fn settled_auctions_root(mut nodes: Vec<T::Hash>) -> T::Hash {
+			if nodes.is_empty() {
+				return T::Hash::default();
 			}
-			Ok(())
+			while nodes.len() > 1 {
+				nodes = nodes
+					.chunks(2)
+					.map(|pair| {
+						if pair.len() == 2 {
+							T::Hashing::hash_of(&(pair[0], pair[1]))
+						} else {
+							pair[0]
+						}
+					})
+					.collect();
+			}
+			nodes[0]
 		}
-	}
 
-	impl<T: Config> CheckAuctionItemHandler for Pallet<T> {
-		fn check_item_in_auction(item_id: ItemId) -> bool {
-			Self::items_in_auction(item_id) == Some(true)
+		/// Unlock bundle items once the bundle auction is settled
+		fn release_bundle(bundle_id: BundleId) {
+			if let Some(items) = Bundles::<T>::take(bundle_id) {
+				for item_id in items {
+					<ItemsInAuction<T>>::remove(item_id);
+					if let ItemId::Estate(estate_id) = item_id {
+						T::EstateHandler::record_estate_delisted(estate_id);
+					}
+				}
+			}
 		}
-	}
 
-	impl<T: Config> AuctionHandler<T::AccountId, BalanceOf<T>, T::BlockNumber, AuctionId> for Pallet<T> {
-		fn on_new_bid(
-			_now: T::BlockNumber,
-			_id: AuctionId,
-			_new_bid: (T::AccountId, BalanceOf<T>),
-			_last_bid: Option<(T::AccountId, BalanceOf<T>)>,
-		) -> OnNewBidResult<T::BlockNumber> {
-			OnNewBidResult {
-				accept_bid: true,
-				auction_end_change: Change::NoChange,
+		/// Auctions with a bidder allowlist only accept bids and purchases from allowlisted accounts
+		fn ensure_allowlisted_bidder(auction_id: AuctionId, who: &T::AccountId) -> DispatchResult {
+			let is_allowed = match Self::bidder_allowlist(auction_id) {
+				None => true,
+				Some(BidderAllowlist::Accounts(accounts)) => accounts.contains(who),
+				Some(BidderAllowlist::NftHolders(class_id)) => T::NFTHandler::holds_class_token(who, &class_id),
+			};
+			ensure!(is_allowed, Error::<T>::BidderNotAllowlisted);
+			Ok(())
+		}
+
+		/// Land units and estates can only be bought by accounts meeting the land buyer gate of
+		/// their metaverse
+		fn ensure_land_buyer_allowed(item_id: &ItemId, who: &T::AccountId) -> DispatchResult {
+			if let ItemId::Bundle(bundle_id) = item_id {
+				// Bundles hold no nested bundles, every land unit and estate in it is checked
+				return Self::get_bundle(*bundle_id)
+					.unwrap_or_default()
+					.iter()
+					.try_for_each(|bundled_item| Self::ensure_land_buyer_allowed(bundled_item, who));
+			}
+			let metaverse_id = match item_id {
+				ItemId::LandUnit(_, metaverse_id) => Some(*metaverse_id),
+				ItemId::Estate(estate_id) => T::EstateHandler::get_estate_metaverse(*estate_id),
+				_ => None,
+			};
+			if let Some(metaverse_id) = metaverse_id {
+				ensure!(
+					T::EstateHandler::is_allowed_to_buy_land(who, metaverse_id),
+					Error::<T>::LandBuyerNotAllowed
+				);
 			}
+			Ok(())
 		}
 
-		fn on_auction_ended(_id: AuctionId, _winner: Option<(T::AccountId, BalanceOf<T>)>) {}
-	}
+		/// Adjacent land owners have the right of first refusal when a land unit is auctioned, land
+		/// the seller owns next to it does not count
+		fn ensure_expansion_right(
+			auction_item: &AuctionItem<T::AccountId, T::BlockNumber, BalanceOf<T>>,
+			who: &T::AccountId,
+		) -> DispatchResult {
+			if let ItemId::LandUnit(coordinate, metaverse_id) = auction_item.item_id {
+				let block_number = <system::Pallet<T>>::block_number();
+				if block_number < auction_item.start_time.saturating_add(T::ExpansionRightPeriod::get())
+					&& T::EstateHandler::has_adjacent_landunits(&auction_item.recipient, metaverse_id, coordinate)
+				{
+					ensure!(
+						T::EstateHandler::check_adjacent_landunit_owner(who, metaverse_id, coordinate),
+						Error::<T>::ExpansionRightPeriodActive
+					);
+				}
+			}
+			Ok(())
+		}
 
-	impl<T: Config> Pallet<T> {
 		//		pub fn upgrade_asset_auction_data_v2() -> Weight {
 		//			log::info!("Start upgrading nft class data v2");
 		//			let mut num_auction_item = 0;
@@ -1056,3 +3480,9 @@ pub mod pallet {
 		//		}
 	}
 }
+
+impl<T: Config> InvariantMonitor for Pallet<T> {
+	fn violated_invariant() -> Option<Vec<u8>> {
+		EscrowShortfall::<T>::take().map(|_| b"AuctionEscrowMismatch".to_vec())
+	}
+}
@@ -2,15 +2,18 @@
 
 use frame_support::traits::{EqualPrivilegeOnly, Nothing};
 use frame_support::{construct_runtime, pallet_prelude::Hooks, parameter_types, PalletId};
-use frame_system::EnsureRoot;
+use frame_system::{EnsureRoot, RawOrigin};
 use orml_traits::parameter_type_with_key;
 use sp_core::H256;
-use sp_runtime::traits::AccountIdConversion;
-use sp_runtime::{testing::Header, traits::IdentityLookup};
+use sp_runtime::traits::{AccountIdConversion, IdentifyAccount, Lazy, Verify};
+use sp_runtime::{testing::Header, traits::IdentityLookup, Perbill};
 
 use auction_manager::{CheckAuctionItemHandler, ListingLevel};
-use core_primitives::{MetaverseInfo, MetaverseTrait, NftAssetData, NftClassData};
-use primitives::{continuum::Continuum, estate::Estate, Amount, AuctionId, ClassId, EstateId, FungibleTokenId};
+use core_primitives::{MetaverseInfo, MetaverseTrait, NftAssetData, NftClassData, UndeployedLandBlocksTrait};
+use primitives::{
+	continuum::Continuum, estate::Estate, Amount, AuctionId, ClassId, EstateId, FungibleTokenId, UndeployedLandBlockId,
+	UndeployedLandBlockType,
+};
 
 use crate as auction;
 
@@ -34,10 +37,22 @@ pub const BOB_METAVERSE_ID: MetaverseId = 2;
 
 pub const ESTATE_ID_EXIST: EstateId = 0;
 pub const ESTATE_ID_EXIST_1: EstateId = 1;
+pub const ESTATE_ID_WITH_ROYALTY: EstateId = 2;
+pub const ESTATE_ID_NOT_TRANSFERABLE: EstateId = 3;
+pub const ESTATE_ID_WITH_LAND_TAX: EstateId = 4;
 pub const ESTATE_ID_NOT_EXIST: EstateId = 99;
 pub const LAND_UNIT_EXIST: (i32, i32) = (0, 0);
 pub const LAND_UNIT_EXIST_1: (i32, i32) = (1, 1);
 pub const LAND_UNIT_NOT_EXIST: (i32, i32) = (99, 99);
+pub const LAND_UNIT_WITHOUT_NEIGHBOURS: (i32, i32) = (5, 5);
+pub const PARTNER_ACCOUNT: AccountId = 3;
+pub const ESTATE_ROYALTY_ACCOUNT: AccountId = 4;
+pub const NETWORK_FEE_ACCOUNT: AccountId = 5;
+pub const LAND_SALE_TREASURY: AccountId = 6;
+pub const LAND_BLOCK_SIZE_NOT_ISSUABLE: u32 = 999;
+pub const PARTNER_SOVEREIGN: AccountId = 7;
+pub const PARTNER_MARKETPLACE_ID: MarketplaceId = 2000;
+pub const UNREACHABLE_MARKETPLACE_ID: MarketplaceId = 2001;
 
 impl frame_system::Config for Runtime {
 	type Origin = Origin;
@@ -93,8 +108,11 @@ impl Continuum<u128> for Continuumm {
 pub struct EstateHandler;
 
 impl Estate<u128> for EstateHandler {
-	fn transfer_estate(_estate_id: EstateId, _from: &AccountId, _to: &AccountId) -> Result<EstateId, DispatchError> {
-		Ok(1)
+	fn transfer_estate(estate_id: EstateId, _from: &AccountId, _to: &AccountId) -> Result<EstateId, DispatchError> {
+		match estate_id {
+			ESTATE_ID_NOT_TRANSFERABLE => Err(DispatchError::Other("Estate is not transferable")),
+			_ => Ok(1),
+		}
 	}
 
 	fn transfer_landunit(
@@ -107,7 +125,11 @@ impl Estate<u128> for EstateHandler {
 
 	fn check_estate(estate_id: EstateId) -> Result<bool, DispatchError> {
 		match estate_id {
-			ESTATE_ID_EXIST | ESTATE_ID_EXIST_1 => Ok(true),
+			ESTATE_ID_EXIST
+			| ESTATE_ID_EXIST_1
+			| ESTATE_ID_WITH_ROYALTY
+			| ESTATE_ID_NOT_TRANSFERABLE
+			| ESTATE_ID_WITH_LAND_TAX => Ok(true),
 			ESTATE_ID_NOT_EXIST => Ok(false),
 			_ => Ok(false),
 		}
@@ -115,12 +137,31 @@ impl Estate<u128> for EstateHandler {
 
 	fn check_landunit(_metaverse_id: MetaverseId, coordinate: (i32, i32)) -> Result<bool, DispatchError> {
 		match coordinate {
-			LAND_UNIT_EXIST | LAND_UNIT_EXIST_1 => Ok(true),
+			LAND_UNIT_EXIST | LAND_UNIT_EXIST_1 | LAND_UNIT_WITHOUT_NEIGHBOURS => Ok(true),
 			LAND_UNIT_NOT_EXIST => Ok(false),
 			_ => Ok(false),
 		}
 	}
 
+	fn check_estate_ownership(owner: &AccountId, estate_id: EstateId) -> Result<bool, DispatchError> {
+		Ok(*owner == ALICE && Self::check_estate(estate_id)?)
+	}
+
+	fn get_estate_royalty(estate_id: EstateId) -> Option<(AccountId, Perbill)> {
+		match estate_id {
+			ESTATE_ID_WITH_ROYALTY => Some((ESTATE_ROYALTY_ACCOUNT, Perbill::from_percent(10))),
+			_ => None,
+		}
+	}
+
+	fn check_landunit_ownership(
+		owner: &AccountId,
+		metaverse_id: MetaverseId,
+		coordinate: (i32, i32),
+	) -> Result<bool, DispatchError> {
+		Ok(*owner == ALICE && Self::check_landunit(metaverse_id, coordinate)?)
+	}
+
 	fn get_total_land_units() -> u64 {
 		100
 	}
@@ -128,6 +169,21 @@ impl Estate<u128> for EstateHandler {
 	fn get_total_undeploy_land_units() -> u64 {
 		100
 	}
+	fn check_adjacent_landunit_owner(who: &AccountId, _metaverse_id: MetaverseId, _coordinate: (i32, i32)) -> bool {
+		*who == ALICE
+	}
+
+	fn has_adjacent_landunits(seller: &AccountId, _metaverse_id: MetaverseId, coordinate: (i32, i32)) -> bool {
+		coordinate != LAND_UNIT_WITHOUT_NEIGHBOURS && *seller != ALICE
+	}
+
+	fn is_allowed_to_buy_land(who: &AccountId, metaverse_id: MetaverseId) -> bool {
+		metaverse_id != BOB_METAVERSE_ID || *who == ALICE
+	}
+
+	fn is_land_tax_settled(estate_id: EstateId) -> bool {
+		estate_id != ESTATE_ID_WITH_LAND_TAX
+	}
 }
 
 pub struct Handler;
@@ -185,9 +241,30 @@ parameter_types! {
 	pub const AuctionTimeToClose: u64 = 100;
 	// Test auction end within 100 blocks
 	pub const MinimumAuctionDuration: u64 = 10;
-	// Test 1% royalty fee
-	pub const RoyaltyFee: u16 = 100;
+	pub static NetworkFee: Perbill = Perbill::zero();
+	pub const NetworkFeeAccount: AccountId = NETWORK_FEE_ACCOUNT;
+	pub const LandSaleTreasury: AccountId = LAND_SALE_TREASURY;
+	pub const MetaverseFeeShare: Perbill = Perbill::from_percent(50);
 	pub const MaxFinality: u32 = 100;
+	pub const ExpansionRightPeriod: u64 = 10;
+	pub const MaxBundleItems: u32 = 3;
+	pub const ArchiveEraLength: u64 = 100;
+	pub const AuctionRetentionPeriod: u64 = 50;
+	pub const InstallmentDownPayment: Perbill = Perbill::from_percent(20);
+	pub const InstallmentPeriod: u64 = 10;
+	pub const InstallmentCount: u32 = 3;
+	pub const InstallmentDefaultForfeit: Perbill = Perbill::from_percent(10);
+	pub const SealedBidDeposit: Balance = 10;
+	pub const SealedBidRevealPeriod: BlockNumber = 10;
+	pub const AntiSnipeThreshold: BlockNumber = 10;
+	pub const AntiSnipeExtension: BlockNumber = 10;
+	pub const MaxAuctionExtensions: u32 = 2;
+	pub const MaxOffersPerItem: u32 = 2;
+	pub const AuctionCancellationPenalty: Perbill = Perbill::from_percent(10);
+	pub const MaxSettlementsPerBlock: u32 = 3;
+	pub const MaxAuctionAllowlistSize: u32 = 2;
+	pub const MaxRevealSaltLength: u32 = 8;
+	pub static DefaultBidIncrement: Perbill = Perbill::zero();
 }
 
 pub struct MetaverseInfoSource {}
@@ -220,6 +297,90 @@ impl MetaverseTrait<AccountId> for MetaverseInfoSource {
 	fn get_metaverse_estate_class(metaverse_id: MetaverseId) -> ClassId {
 		16u32
 	}
+
+	fn get_metaverse_treasury(metaverse_id: MetaverseId) -> AccountId {
+		100
+	}
+}
+
+pub struct MockCrossListingHandler;
+
+impl CrossListingHandler<AccountId, Balance, BlockNumber> for MockCrossListingHandler {
+	fn notify_listing(
+		marketplace_id: MarketplaceId,
+		_marketplace: &PartnerMarketplaceInfo,
+		_listing: VersionedCrossListing<AccountId, Balance, BlockNumber>,
+	) -> DispatchResult {
+		match marketplace_id {
+			UNREACHABLE_MARKETPLACE_ID => Err(DispatchError::Other("Unreachable")),
+			_ => Ok(()),
+		}
+	}
+}
+
+pub fn partner_marketplace_info() -> PartnerMarketplaceInfo {
+	PartnerMarketplaceInfo {
+		listing_call_index: [60, 0],
+		listing_call_weight: 1_000_000,
+		execution_fee: 1_000,
+	}
+}
+
+pub struct MockUndeployedLandBlockHandler;
+
+impl UndeployedLandBlocksTrait<AccountId> for MockUndeployedLandBlockHandler {
+	fn issue_undeployed_land_blocks(
+		_beneficiary: &AccountId,
+		number_of_land_block: u32,
+		number_land_units_per_land_block: u32,
+		_undeployed_land_block_type: UndeployedLandBlockType,
+	) -> Result<Vec<UndeployedLandBlockId>, DispatchError> {
+		if number_land_units_per_land_block == LAND_BLOCK_SIZE_NOT_ISSUABLE {
+			return Err(DispatchError::Other("Land block can not be issued"));
+		}
+		// Issued block ids carry the number of land units so tests can check the issued size
+		Ok((0..number_of_land_block)
+			.map(|_| number_land_units_per_land_block as UndeployedLandBlockId)
+			.collect())
+	}
+
+	fn transfer_undeployed_land_block(
+		_who: &AccountId,
+		_to: &AccountId,
+		undeployed_land_block_id: UndeployedLandBlockId,
+	) -> Result<UndeployedLandBlockId, DispatchError> {
+		Ok(undeployed_land_block_id)
+	}
+
+	fn burn_undeployed_land_block(
+		undeployed_land_block_id: UndeployedLandBlockId,
+	) -> Result<UndeployedLandBlockId, DispatchError> {
+		Ok(undeployed_land_block_id)
+	}
+
+	fn freeze_undeployed_land_block(
+		undeployed_land_block_id: UndeployedLandBlockId,
+	) -> Result<UndeployedLandBlockId, DispatchError> {
+		Ok(undeployed_land_block_id)
+	}
+}
+
+pub struct EnsurePartnerMarketplace;
+
+impl EnsureOrigin<Origin> for EnsurePartnerMarketplace {
+	type Success = (MarketplaceId, AccountId);
+
+	fn try_origin(o: Origin) -> Result<Self::Success, Origin> {
+		Into::<Result<RawOrigin<AccountId>, Origin>>::into(o).and_then(|o| match o {
+			RawOrigin::Signed(PARTNER_ACCOUNT) => Ok((PARTNER_MARKETPLACE_ID, PARTNER_SOVEREIGN)),
+			r => Err(Origin::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> Origin {
+		Origin::signed(PARTNER_ACCOUNT)
+	}
 }
 
 impl Config for Runtime {
@@ -232,9 +393,36 @@ impl Config for Runtime {
 	type MetaverseInfoSource = MetaverseInfoSource;
 	type MinimumAuctionDuration = MinimumAuctionDuration;
 	type EstateHandler = EstateHandler;
-	type RoyaltyFee = RoyaltyFee;
+	type NetworkFee = NetworkFee;
+	type NetworkFeeAccount = NetworkFeeAccount;
+	type MetaverseFeeShare = MetaverseFeeShare;
 	type MaxFinality = MaxFinality;
 	type NFTHandler = NFTModule;
+	type ExpansionRightPeriod = ExpansionRightPeriod;
+	type CrossListingHandler = MockCrossListingHandler;
+	type CrossChainOrigin = EnsurePartnerMarketplace;
+	type MaxBundleItems = MaxBundleItems;
+	type ArchiveEraLength = ArchiveEraLength;
+	type AuctionRetentionPeriod = AuctionRetentionPeriod;
+	type InstallmentDownPayment = InstallmentDownPayment;
+	type InstallmentPeriod = InstallmentPeriod;
+	type InstallmentCount = InstallmentCount;
+	type InstallmentDefaultForfeit = InstallmentDefaultForfeit;
+	type SealedBidDeposit = SealedBidDeposit;
+	type SealedBidRevealPeriod = SealedBidRevealPeriod;
+	type AntiSnipeThreshold = AntiSnipeThreshold;
+	type AntiSnipeExtension = AntiSnipeExtension;
+	type MaxAuctionExtensions = MaxAuctionExtensions;
+	type MaxOffersPerItem = MaxOffersPerItem;
+	type AuctionCancellationPenalty = AuctionCancellationPenalty;
+	type MaxSettlementsPerBlock = MaxSettlementsPerBlock;
+	type MaxAuctionAllowlistSize = MaxAuctionAllowlistSize;
+	type UndeployedLandBlockHandler = MockUndeployedLandBlockHandler;
+	type LandSaleOrigin = EnsureRoot<AccountId>;
+	type LandSaleTreasury = LandSaleTreasury;
+	type MaxRevealSaltLength = MaxRevealSaltLength;
+	type DefaultBidIncrement = DefaultBidIncrement;
+	type ActivityRecorder = ();
 }
 
 pub type AdaptedBasicCurrency = currencies::BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
@@ -249,6 +437,8 @@ impl currencies::Config for Runtime {
 	type MultiSocialCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type GetNativeCurrencyId = NativeCurrencyId;
+	type TreasuryAccount = TreasuryModuleAccount;
+	type UpdateOrigin = EnsureRoot<AccountId>;
 }
 
 parameter_types! {
@@ -275,6 +465,10 @@ parameter_types! {
 	pub MaxBatchTransfer: u32 = 3;
 	pub MaxBatchMinting: u32 = 2000;
 	pub MaxMetadata: u32 = 10;
+	pub MaxRarityAttributes: u32 = 5;
+	pub MaxTypedAttributes: u32 = 2;
+	pub MaxNestingDepth: u32 = 2;
+	pub GenerativeRevealDelay: BlockNumber = 2;
 }
 
 impl pallet_nft::Config for Runtime {
@@ -291,6 +485,51 @@ impl pallet_nft::Config for Runtime {
 	type MiningResourceId = MiningCurrencyId;
 	type AssetMintingFee = AssetMintingFee;
 	type ClassMintingFee = ClassMintingFee;
+	type ActivityRecorder = ();
+	type MaxRarityAttributes = MaxRarityAttributes;
+	type MaxTypedAttributes = MaxTypedAttributes;
+	type MaxNestingDepth = MaxNestingDepth;
+	type OffchainSignature = MockSignature;
+	type OffchainPublic = MockPublic;
+	type Randomness = MockRandomness;
+	type GenerativeRevealDelay = GenerativeRevealDelay;
+	type OnNftTransfer = ();
+}
+
+/// Randomness of the mock, derived from the subject and block number
+pub struct MockRandomness;
+
+impl frame_support::traits::Randomness<H256, BlockNumber> for MockRandomness {
+	fn random(subject: &[u8]) -> (H256, BlockNumber) {
+		let block_number = System::block_number();
+		(
+			<sp_runtime::traits::BlakeTwo256 as sp_runtime::traits::Hash>::hash_of(&(subject, block_number)),
+			block_number,
+		)
+	}
+}
+
+/// Signature of a mock account, valid for the message it was created with
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MockSignature(pub AccountId, pub Vec<u8>);
+
+impl Verify for MockSignature {
+	type Signer = MockPublic;
+
+	fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &AccountId) -> bool {
+		self.0 == *signer && self.1 == msg.get()
+	}
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MockPublic(pub AccountId);
+
+impl IdentifyAccount for MockPublic {
+	type AccountId = AccountId;
+
+	fn into_account(self) -> AccountId {
+		self.0
+	}
 }
 
 parameter_types! {
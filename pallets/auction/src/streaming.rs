@@ -0,0 +1,184 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Payment streaming of installment purchases. The price left after the down payment streams from
+// the buyer into escrow one installment every period, the estate is handed over once the full
+// price is escrowed.
+use frame_support::traits::{BalanceStatus, Get, ReservableCurrency};
+use frame_support::{transactional, weights::Weight};
+use sp_runtime::traits::{One, Saturating, Zero};
+use sp_runtime::{DispatchResult, SaturatedConversion};
+use sp_std::vec::Vec;
+
+use auction_manager::InstallmentPurchase;
+use primitives::{estate::Estate, AuctionId, ItemId};
+
+use crate::pallet::{
+	BalanceOf, Config, Event, InstallmentDeadlines, InstallmentPurchases, InstallmentStreamCursor, ItemsInAuction,
+	Pallet,
+};
+
+pub(crate) type InstallmentPurchaseOf<T> =
+	InstallmentPurchase<<T as frame_system::Config>::AccountId, BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+impl<T: Config> Pallet<T> {
+	/// Amount of the next installment, the last installment pays the remainder
+	pub(crate) fn installment_due(purchase: &InstallmentPurchaseOf<T>) -> BalanceOf<T> {
+		let remaining = purchase.price.saturating_sub(purchase.paid);
+		if purchase.installment.is_zero() {
+			remaining
+		} else {
+			purchase.installment.min(remaining)
+		}
+	}
+
+	/// Escrow the next installment from the buyer, then schedule the following one or hand the
+	/// estate over when the full price is escrowed
+	pub(crate) fn stream_installment(auction_id: AuctionId, mut purchase: InstallmentPurchaseOf<T>) -> DispatchResult {
+		let amount = Self::installment_due(&purchase);
+		<T as Config>::Currency::reserve(&purchase.buyer, amount)?;
+		purchase.paid = purchase.paid.saturating_add(amount);
+
+		<InstallmentDeadlines<T>>::remove(purchase.next_due, auction_id);
+		Self::deposit_event(Event::InstallmentPaid(
+			auction_id,
+			purchase.buyer.clone(),
+			amount,
+			purchase.paid,
+		));
+		Self::continue_installment_purchase(auction_id, purchase);
+		Ok(())
+	}
+
+	/// Schedule the next installment of the purchase, or complete it once the full price is
+	/// escrowed. A purchase which can not be completed is refunded in full
+	pub(crate) fn continue_installment_purchase(auction_id: AuctionId, mut purchase: InstallmentPurchaseOf<T>) {
+		if purchase.paid >= purchase.price {
+			<InstallmentPurchases<T>>::remove(auction_id);
+			if Self::complete_installment_purchase(auction_id, &purchase).is_err() {
+				Self::refund_installment_purchase(auction_id, purchase);
+			}
+		} else {
+			purchase.next_due = purchase.next_due.saturating_add(T::InstallmentPeriod::get());
+			<InstallmentDeadlines<T>>::insert(purchase.next_due, auction_id, ());
+			<InstallmentPurchases<T>>::insert(auction_id, purchase);
+		}
+	}
+
+	/// Stream installments which fell due before `now`, at most MaxSettlementsPerBlock per block.
+	/// Purchases whose buyer can not pay the installment are defaulted, purchases left over are
+	/// streamed in the next blocks.
+	pub(crate) fn stream_due_installments(now: T::BlockNumber) -> Weight {
+		let max_installments = T::MaxSettlementsPerBlock::get();
+		let mut cursor = Self::installment_stream_cursor().unwrap_or_else(|| now.saturating_sub(One::one()));
+		let mut streamed: u32 = 0;
+		let mut visited_blocks: u32 = 0;
+
+		while cursor < now && streamed < max_installments && visited_blocks < max_installments {
+			visited_blocks = visited_blocks.saturating_add(1);
+			let due: Vec<AuctionId> = <InstallmentDeadlines<T>>::iter_prefix(&cursor)
+				.map(|(auction_id, _)| auction_id)
+				.take(max_installments.saturating_sub(streamed) as usize)
+				.collect();
+			for auction_id in due {
+				<InstallmentDeadlines<T>>::remove(&cursor, auction_id);
+				if let Some(purchase) = <InstallmentPurchases<T>>::get(auction_id) {
+					if Self::stream_installment(auction_id, purchase.clone()).is_err() {
+						<InstallmentPurchases<T>>::remove(auction_id);
+						Self::default_installment_purchase(auction_id, purchase);
+					}
+				}
+				streamed = streamed.saturating_add(1);
+			}
+			if <InstallmentDeadlines<T>>::iter_prefix(&cursor).next().is_some() {
+				break;
+			}
+			cursor = cursor.saturating_add(One::one());
+		}
+		InstallmentStreamCursor::<T>::put(cursor);
+
+		let streamed = streamed as Weight;
+		let visited_blocks = visited_blocks as Weight;
+		T::DbWeight::get().reads_writes(
+			streamed
+				.saturating_mul(6)
+				.saturating_add(visited_blocks.saturating_mul(2))
+				.saturating_add(1),
+			streamed.saturating_mul(8).saturating_add(1),
+		)
+	}
+
+	/// Release escrowed payments to the seller and hand the estate over to the buyer
+	#[transactional]
+	pub(crate) fn complete_installment_purchase(
+		auction_id: AuctionId,
+		purchase: &InstallmentPurchaseOf<T>,
+	) -> DispatchResult {
+		<T as Config>::Currency::repatriate_reserved(
+			&purchase.buyer,
+			&purchase.seller,
+			purchase.price,
+			BalanceStatus::Free,
+		)?;
+		<ItemsInAuction<T>>::remove(ItemId::Estate(purchase.estate_id));
+		T::EstateHandler::transfer_estate(purchase.estate_id, &purchase.seller, &purchase.buyer)?;
+		T::EstateHandler::record_estate_sale(purchase.estate_id, purchase.price.saturated_into());
+
+		Self::deposit_event(Event::InstallmentPurchaseCompleted(
+			auction_id,
+			purchase.buyer.clone(),
+			purchase.estate_id,
+		));
+		Ok(())
+	}
+
+	/// Release all escrowed payments to the buyer and the estate to the seller when the estate
+	/// can not be handed over
+	pub(crate) fn refund_installment_purchase(auction_id: AuctionId, purchase: InstallmentPurchaseOf<T>) {
+		<T as Config>::Currency::unreserve(&purchase.buyer, purchase.paid);
+		<ItemsInAuction<T>>::remove(ItemId::Estate(purchase.estate_id));
+
+		Self::deposit_event(Event::InstallmentPurchaseRefunded(
+			auction_id,
+			purchase.buyer,
+			purchase.paid,
+		));
+	}
+
+	/// Return the estate to the seller, forfeit a share of escrowed payments and refund the rest
+	pub(crate) fn default_installment_purchase(auction_id: AuctionId, purchase: InstallmentPurchaseOf<T>) {
+		let forfeit = T::InstallmentDefaultForfeit::get() * purchase.paid;
+		let not_forfeited = <T as Config>::Currency::repatriate_reserved(
+			&purchase.buyer,
+			&purchase.seller,
+			forfeit,
+			BalanceStatus::Free,
+		)
+		.unwrap_or(forfeit);
+		let forfeited = forfeit.saturating_sub(not_forfeited);
+		let refund = purchase.paid.saturating_sub(forfeited);
+		<T as Config>::Currency::unreserve(&purchase.buyer, refund);
+		<ItemsInAuction<T>>::remove(ItemId::Estate(purchase.estate_id));
+
+		Self::deposit_event(Event::InstallmentPurchaseDefaulted(
+			auction_id,
+			purchase.buyer,
+			forfeited,
+			refund,
+		));
+	}
+}
@@ -1,11 +1,15 @@
 #![cfg(test)]
 
 use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+use sp_runtime::traits::{BadOrigin, BlakeTwo256};
 use sp_std::collections::btree_map::BTreeMap;
 
 use auction_manager::ListingLevel;
-use core_primitives::{Attributes, CollectionType, NFTTrait, TokenType};
+use core_primitives::{Attributes, CollectionType, MetaverseTrait, NFTTrait, TokenType};
 use mock::{Event, *};
+use orml_traits::{MultiCurrency, MultiReservableCurrency};
+use primitives::FungibleTokenId;
 use primitives::ItemId::NFT;
 
 use super::*;
@@ -180,7 +184,7 @@ fn create_auction_fail() {
 				0,
 				ListingLevel::Global
 			),
-			Error::<Runtime>::NoPermissionToCreateAuction
+			Error::<Runtime>::NotItemOwner
 		);
 
 		assert_ok!(NFTModule::<Runtime>::create_class(
@@ -212,7 +216,7 @@ fn create_auction_fail() {
 				0,
 				ListingLevel::Global
 			),
-			Error::<Runtime>::NoPermissionToCreateAuction
+			Error::<Runtime>::ItemNotTransferable
 		);
 
 		//Asset is already in an auction
@@ -331,6 +335,100 @@ fn bid_works_for_valid_land_unit() {
 	});
 }
 
+#[test]
+fn bid_land_unit_should_respect_expansion_right() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::LandUnit(LAND_UNIT_EXIST, ALICE_METAVERSE_ID);
+
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			item_id,
+			None,
+			PARTNER_ACCOUNT,
+			100,
+			0,
+			ListingLevel::Global
+		));
+
+		// Only adjacent land owners can bid during expansion right period
+		assert_noop!(
+			AuctionModule::bid(Origin::signed(BOB), 0, 200),
+			Error::<Runtime>::ExpansionRightPeriodActive
+		);
+
+		System::set_block_number(System::block_number() + ExpansionRightPeriod::get());
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 200));
+		assert_eq!(last_event(), Event::AuctionModule(crate::Event::Bid(0, BOB, 200)));
+	});
+}
+
+#[test]
+fn bid_land_unit_without_neighbours_should_skip_expansion_right() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::LandUnit(LAND_UNIT_WITHOUT_NEIGHBOURS, ALICE_METAVERSE_ID);
+
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			item_id,
+			None,
+			ALICE,
+			100,
+			0,
+			ListingLevel::Global
+		));
+
+		// No adjacent land owner can exercise the expansion right
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 200));
+		assert_eq!(last_event(), Event::AuctionModule(crate::Event::Bid(0, BOB, 200)));
+	});
+}
+
+#[test]
+fn bid_land_unit_next_to_seller_land_should_skip_expansion_right() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::LandUnit(LAND_UNIT_EXIST, ALICE_METAVERSE_ID);
+
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			item_id,
+			None,
+			ALICE,
+			100,
+			0,
+			ListingLevel::Global
+		));
+
+		// Seller owns every adjacent land unit, nobody else holds the expansion right
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 200));
+		assert_eq!(last_event(), Event::AuctionModule(crate::Event::Bid(0, BOB, 200)));
+	});
+}
+
+#[test]
+fn bid_land_unit_should_respect_land_buyer_gate() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id: ItemId = ItemId::LandUnit(LAND_UNIT_EXIST, BOB_METAVERSE_ID);
+
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			item_id,
+			None,
+			PARTNER_ACCOUNT,
+			100,
+			0,
+			ListingLevel::Global
+		));
+
+		System::set_block_number(System::block_number() + ExpansionRightPeriod::get());
+		assert_noop!(
+			AuctionModule::bid(Origin::signed(BOB), 0, 200),
+			Error::<Runtime>::LandBuyerNotAllowed
+		);
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 200));
+		assert_eq!(last_event(), Event::AuctionModule(crate::Event::Bid(0, ALICE, 200)));
+	});
+}
+
 #[test]
 fn cannot_bid_on_non_existent_auction() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -581,6 +679,230 @@ fn buy_now_works_for_valid_estate() {
 	});
 }
 
+#[test]
+fn buy_now_with_installments_should_transfer_estate_when_fully_paid() {
+	ExtBuilder::default().build().execute_with(|| {
+		let buyer = Origin::signed(ALICE);
+
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::BuyNow,
+			ItemId::Estate(ESTATE_ID_EXIST),
+			None,
+			BOB,
+			150,
+			0,
+			ListingLevel::Global
+		));
+
+		assert_ok!(AuctionModule::buy_now_with_installments(buyer.clone(), 0));
+		assert_eq!(Balances::reserved_balance(ALICE), 30);
+		assert_eq!(AuctionModule::auctions(0), None);
+		assert_eq!(
+			AuctionModule::items_in_auction(ItemId::Estate(ESTATE_ID_EXIST)),
+			Some(true)
+		);
+
+		assert_noop!(
+			AuctionModule::pay_installment(Origin::signed(BOB), 0),
+			Error::<Runtime>::NotInstallmentBuyer
+		);
+
+		assert_ok!(AuctionModule::pay_installment(buyer.clone(), 0));
+		assert_ok!(AuctionModule::pay_installment(buyer.clone(), 0));
+		assert_eq!(AuctionModule::installment_purchases(0).map(|p| p.paid), Some(110));
+
+		assert_ok!(AuctionModule::pay_installment(buyer.clone(), 0));
+		assert_eq!(AuctionModule::installment_purchases(0), None);
+		assert_eq!(AuctionModule::items_in_auction(ItemId::Estate(ESTATE_ID_EXIST)), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 99850);
+		assert_eq!(Balances::free_balance(BOB), 650);
+
+		let event = mock::Event::AuctionModule(crate::Event::InstallmentPurchaseCompleted(0, ALICE, ESTATE_ID_EXIST));
+		assert_eq!(last_event(), event);
+	});
+}
+
+#[test]
+fn installments_should_stream_from_buyer_when_due() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::BuyNow,
+			ItemId::Estate(ESTATE_ID_EXIST),
+			None,
+			BOB,
+			150,
+			0,
+			ListingLevel::Global
+		));
+		assert_ok!(AuctionModule::buy_now_with_installments(Origin::signed(ALICE), 0));
+
+		let next_due = AuctionModule::installment_purchases(0).unwrap().next_due;
+		run_to_block(next_due + 1);
+		assert_eq!(AuctionModule::installment_purchases(0).map(|p| p.paid), Some(70));
+		assert_eq!(Balances::reserved_balance(ALICE), 70);
+		assert_eq!(
+			last_event(),
+			mock::Event::AuctionModule(crate::Event::InstallmentPaid(0, ALICE, 40, 70))
+		);
+
+		run_to_block(next_due + 2 * InstallmentPeriod::get() + 1);
+		assert_eq!(AuctionModule::installment_purchases(0), None);
+		assert_eq!(AuctionModule::items_in_auction(ItemId::Estate(ESTATE_ID_EXIST)), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 99850);
+		assert_eq!(Balances::free_balance(BOB), 650);
+		assert_eq!(
+			last_event(),
+			mock::Event::AuctionModule(crate::Event::InstallmentPurchaseCompleted(0, ALICE, ESTATE_ID_EXIST))
+		);
+	});
+}
+
+#[test]
+fn installment_purchase_should_be_refunded_when_estate_can_not_be_transferred() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::BuyNow,
+			ItemId::Estate(ESTATE_ID_NOT_TRANSFERABLE),
+			None,
+			BOB,
+			150,
+			0,
+			ListingLevel::Global
+		));
+		assert_ok!(AuctionModule::buy_now_with_installments(Origin::signed(ALICE), 0));
+		assert_ok!(AuctionModule::pay_installment(Origin::signed(ALICE), 0));
+		assert_ok!(AuctionModule::pay_installment(Origin::signed(ALICE), 0));
+		assert_ok!(AuctionModule::pay_installment(Origin::signed(ALICE), 0));
+
+		// Full price is returned to the buyer instead of being forfeited
+		assert_eq!(AuctionModule::installment_purchases(0), None);
+		assert_eq!(
+			AuctionModule::items_in_auction(ItemId::Estate(ESTATE_ID_NOT_TRANSFERABLE)),
+			None
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 100000);
+		assert_eq!(Balances::free_balance(BOB), 500);
+		assert_eq!(
+			last_event(),
+			mock::Event::AuctionModule(crate::Event::InstallmentPurchaseRefunded(0, ALICE, 150))
+		);
+	});
+}
+
+#[test]
+fn create_auction_should_fail_when_estate_owes_land_tax() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AuctionModule::create_auction(
+				AuctionType::BuyNow,
+				ItemId::Estate(ESTATE_ID_WITH_LAND_TAX),
+				None,
+				BOB,
+				150,
+				0,
+				ListingLevel::Global
+			),
+			Error::<Runtime>::LandTaxOutstanding
+		);
+	});
+}
+
+#[test]
+fn buy_now_should_refund_buyer_when_estate_can_not_be_transferred() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::BuyNow,
+			ItemId::Estate(ESTATE_ID_NOT_TRANSFERABLE),
+			None,
+			BOB,
+			150,
+			0,
+			ListingLevel::Global
+		));
+		assert_ok!(AuctionModule::buy_now(Origin::signed(ALICE), 0, 150));
+
+		// Seller is not paid for an estate that never moved
+		assert_eq!(AuctionModule::auctions(0), None);
+		assert_eq!(Balances::free_balance(ALICE), 100000);
+		assert_eq!(Balances::free_balance(BOB), 500);
+		assert_eq!(
+			last_event(),
+			mock::Event::AuctionModule(crate::Event::SaleHandoverFailed(
+				0,
+				ALICE,
+				150,
+				DispatchError::Other("Estate is not transferable")
+			))
+		);
+	});
+}
+
+#[test]
+fn finalize_auction_should_refund_bidder_when_estate_can_not_be_transferred() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::Estate(ESTATE_ID_NOT_TRANSFERABLE),
+			None,
+			BOB,
+			100,
+			0,
+			ListingLevel::Global
+		));
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 100));
+		run_to_block(102);
+
+		assert_eq!(AuctionModule::auctions(0), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 100000);
+		assert_eq!(Balances::free_balance(BOB), 500);
+		assert_eq!(
+			last_event(),
+			mock::Event::AuctionModule(crate::Event::SaleHandoverFailed(
+				0,
+				ALICE,
+				100,
+				DispatchError::Other("Estate is not transferable")
+			))
+		);
+	});
+}
+
+#[test]
+fn missed_installment_should_return_estate_and_forfeit_share_of_payments() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::BuyNow,
+			ItemId::Estate(ESTATE_ID_EXIST),
+			None,
+			BOB,
+			150,
+			0,
+			ListingLevel::Global
+		));
+		assert_ok!(AuctionModule::buy_now_with_installments(Origin::signed(ALICE), 0));
+		assert_ok!(AuctionModule::pay_installment(Origin::signed(ALICE), 0));
+
+		// Buyer can not cover the next installment when it is streamed
+		assert_ok!(Balances::reserve(&ALICE, 99925));
+		let next_due = AuctionModule::installment_purchases(0).unwrap().next_due;
+		run_to_block(next_due + 1);
+
+		// 10% of the 70 paid is forfeited to the seller
+		assert_eq!(AuctionModule::installment_purchases(0), None);
+		assert_eq!(AuctionModule::items_in_auction(ItemId::Estate(ESTATE_ID_EXIST)), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 99925);
+		assert_eq!(Balances::free_balance(ALICE), 68);
+		assert_eq!(Balances::free_balance(BOB), 507);
+
+		let event = mock::Event::AuctionModule(crate::Event::InstallmentPurchaseDefaulted(0, ALICE, 7, 63));
+		assert_eq!(last_event(), event);
+	});
+}
+
 #[test]
 // Private bid_auction should work
 fn buy_now_works_for_valid_landunit() {
@@ -707,7 +1029,7 @@ fn buy_now_should_fail() {
 				0,
 				ListingLevel::Global
 			),
-			Error::<Runtime>::NoPermissionToCreateAuction
+			Error::<Runtime>::NotItemOwner
 		);
 	});
 }
@@ -816,3 +1138,1620 @@ fn list_item_on_buy_now_local_marketplace_should_work() {
 		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), Some(true))
 	});
 }
+
+#[test]
+fn register_partner_marketplace_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AuctionModule::register_partner_marketplace(
+				Origin::signed(ALICE),
+				PARTNER_MARKETPLACE_ID,
+				partner_marketplace_info()
+			),
+			BadOrigin
+		);
+
+		assert_ok!(AuctionModule::register_partner_marketplace(
+			Origin::root(),
+			PARTNER_MARKETPLACE_ID,
+			partner_marketplace_info()
+		));
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::PartnerMarketplaceRegistered(PARTNER_MARKETPLACE_ID))
+		);
+		assert_noop!(
+			AuctionModule::register_partner_marketplace(
+				Origin::root(),
+				PARTNER_MARKETPLACE_ID,
+				partner_marketplace_info()
+			),
+			Error::<Runtime>::PartnerMarketplaceAlreadyRegistered
+		);
+
+		assert_ok!(AuctionModule::remove_partner_marketplace(
+			Origin::root(),
+			PARTNER_MARKETPLACE_ID
+		));
+		assert_eq!(AuctionModule::partner_marketplaces(PARTNER_MARKETPLACE_ID), None);
+	});
+}
+
+#[test]
+fn create_new_auction_should_cross_list_when_opted_in() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(AuctionModule::register_partner_marketplace(
+			Origin::root(),
+			PARTNER_MARKETPLACE_ID,
+			partner_marketplace_info()
+		));
+		assert_ok!(AuctionModule::register_partner_marketplace(
+			Origin::root(),
+			UNREACHABLE_MARKETPLACE_ID,
+			partner_marketplace_info()
+		));
+		assert_ok!(AuctionModule::set_cross_listing(origin.clone(), true));
+
+		assert_ok!(AuctionModule::create_new_auction(
+			origin,
+			ItemId::NFT(0, 0),
+			100,
+			102,
+			ListingLevel::Global,
+		));
+
+		// Unreachable marketplace does not block the listing
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::AuctionCrossListed(0, vec![PARTNER_MARKETPLACE_ID]))
+		);
+		assert_eq!(AuctionModule::cross_listed_auctions(0), Some(()));
+	});
+}
+
+#[test]
+fn submit_remote_purchase_intent_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(AuctionModule::create_new_auction(
+			origin.clone(),
+			ItemId::NFT(0, 0),
+			100,
+			102,
+			ListingLevel::Global,
+		));
+
+		assert_noop!(
+			AuctionModule::submit_remote_purchase_intent(Origin::signed(BOB), 0, BOB, 150),
+			BadOrigin
+		);
+		assert_noop!(
+			AuctionModule::submit_remote_purchase_intent(Origin::signed(PARTNER_ACCOUNT), 0, BOB, 150),
+			Error::<Runtime>::PartnerMarketplaceNotRegistered
+		);
+
+		assert_ok!(AuctionModule::register_partner_marketplace(
+			Origin::root(),
+			PARTNER_MARKETPLACE_ID,
+			partner_marketplace_info()
+		));
+		// Listing was created before opting in
+		assert_noop!(
+			AuctionModule::submit_remote_purchase_intent(Origin::signed(PARTNER_ACCOUNT), 0, BOB, 150),
+			Error::<Runtime>::AuctionNotCrossListed
+		);
+
+		assert_ok!(AuctionModule::set_cross_listing(origin.clone(), true));
+		assert_ok!(NFTModule::<Runtime>::mint(
+			origin.clone(),
+			CLASS_ID,
+			vec![1],
+			test_attributes(1),
+			1
+		));
+		assert_ok!(AuctionModule::create_new_buy_now(
+			origin,
+			ItemId::NFT(CLASS_ID, 1),
+			150,
+			102,
+			ListingLevel::Global,
+		));
+		// Sovereign account of the marketplace can not pay for the purchase
+		assert_noop!(
+			AuctionModule::submit_remote_purchase_intent(Origin::signed(PARTNER_ACCOUNT), 1, BOB, 150),
+			pallet_balances::Error::<Runtime>::InsufficientBalance
+		);
+
+		let _ = Balances::deposit_creating(&PARTNER_SOVEREIGN, 1000);
+		let alice_balance = Balances::free_balance(ALICE);
+		assert_ok!(AuctionModule::submit_remote_purchase_intent(
+			Origin::signed(PARTNER_ACCOUNT),
+			1,
+			BOB,
+			150
+		));
+		assert!(System::events().iter().any(|record| record.event
+			== Event::AuctionModule(crate::Event::RemotePurchaseIntentReceived(
+				1,
+				PARTNER_MARKETPLACE_ID,
+				BOB,
+				150
+			))));
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::BuyNowFinalised(1, BOB, 150))
+		);
+		assert_eq!(
+			AuctionModule::remote_purchase_intents(1, PARTNER_MARKETPLACE_ID),
+			Some((BOB, 150))
+		);
+		assert_eq!(Balances::free_balance(PARTNER_SOVEREIGN), 850);
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 150);
+		assert_eq!(NFTModule::<Runtime>::check_ownership(&BOB, &(CLASS_ID, 1)), Ok(true));
+	});
+}
+
+#[test]
+fn create_new_bundle_auction_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(ALICE);
+		init_test_nft(owner.clone());
+		let bundle_items = vec![
+			ItemId::NFT(CLASS_ID, 0),
+			ItemId::Estate(ESTATE_ID_EXIST),
+			ItemId::LandUnit(LAND_UNIT_EXIST, ALICE_METAVERSE_ID),
+		];
+
+		assert_ok!(AuctionModule::create_new_bundle_auction(
+			owner.clone(),
+			AuctionType::BuyNow,
+			bundle_items.clone(),
+			150,
+			102,
+		));
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::NewAuctionBundle(0, 0, bundle_items.clone()))
+		);
+		assert_eq!(AuctionModule::get_bundle(0), Some(bundle_items.clone()));
+		assert_eq!(AuctionModule::items_in_auction(ItemId::Bundle(0)), Some(true));
+		for item_id in bundle_items.iter() {
+			assert_eq!(AuctionModule::items_in_auction(item_id), Some(true));
+		}
+
+		// Bundle items can not be listed separately
+		assert_noop!(
+			AuctionModule::create_new_auction(owner, ItemId::NFT(CLASS_ID, 0), 100, 102, ListingLevel::Global),
+			Error::<Runtime>::ItemAlreadyInAuction
+		);
+
+		assert_ok!(AuctionModule::buy_now(Origin::signed(BOB), 0, 150));
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::BuyNowFinalised(0, BOB, 150))
+		);
+		assert_eq!(NFTModule::<Runtime>::check_ownership(&BOB, &(CLASS_ID, 0)), Ok(true));
+		assert_eq!(AuctionModule::get_bundle(0), None);
+		assert_eq!(AuctionModule::items_in_auction(ItemId::Bundle(0)), None);
+		for item_id in bundle_items.iter() {
+			assert_eq!(AuctionModule::items_in_auction(item_id), None);
+		}
+	});
+}
+
+#[test]
+fn buy_now_bundle_should_respect_land_buyer_gate() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(ALICE);
+		init_test_nft(owner.clone());
+		let bundle_items = vec![
+			ItemId::NFT(CLASS_ID, 0),
+			ItemId::LandUnit(LAND_UNIT_EXIST, BOB_METAVERSE_ID),
+		];
+
+		assert_ok!(AuctionModule::create_new_bundle_auction(
+			owner,
+			AuctionType::BuyNow,
+			bundle_items,
+			150,
+			102,
+		));
+		assert_noop!(
+			AuctionModule::buy_now(Origin::signed(BOB), 0, 150),
+			Error::<Runtime>::LandBuyerNotAllowed
+		);
+	});
+}
+
+#[test]
+fn buy_now_bundle_should_refund_buyer_when_an_item_can_not_be_transferred() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(ALICE);
+		init_test_nft(owner.clone());
+		let bundle_items = vec![ItemId::NFT(CLASS_ID, 0), ItemId::Estate(ESTATE_ID_NOT_TRANSFERABLE)];
+		let seller_balance = Balances::free_balance(ALICE);
+
+		assert_ok!(AuctionModule::create_new_bundle_auction(
+			owner,
+			AuctionType::BuyNow,
+			bundle_items.clone(),
+			150,
+			102,
+		));
+		assert_ok!(AuctionModule::buy_now(Origin::signed(BOB), 0, 150));
+
+		// Neither the payment nor the items already moved are kept
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::SaleHandoverFailed(
+				0,
+				BOB,
+				150,
+				DispatchError::Other("Estate is not transferable")
+			))
+		);
+		assert_eq!(NFTModule::<Runtime>::check_ownership(&ALICE, &(CLASS_ID, 0)), Ok(true));
+		assert_eq!(Balances::free_balance(ALICE), seller_balance);
+		assert_eq!(Balances::free_balance(BOB), 500);
+		assert_eq!(AuctionModule::get_bundle(0), None);
+		for item_id in bundle_items.iter() {
+			assert_eq!(AuctionModule::items_in_auction(item_id), None);
+		}
+	});
+}
+
+#[test]
+fn finalize_bundle_auction_should_refund_bidder_when_an_item_can_not_be_transferred() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(ALICE);
+		init_test_nft(owner.clone());
+		let seller_balance = Balances::free_balance(ALICE);
+
+		assert_ok!(AuctionModule::create_new_bundle_auction(
+			owner,
+			AuctionType::Auction,
+			vec![ItemId::NFT(CLASS_ID, 0), ItemId::Estate(ESTATE_ID_NOT_TRANSFERABLE)],
+			100,
+			102,
+		));
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 150));
+		run_to_block(103);
+
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::SaleHandoverFailed(
+				0,
+				BOB,
+				150,
+				DispatchError::Other("Estate is not transferable")
+			))
+		);
+		assert_eq!(AuctionModule::auctions(0), None);
+		assert_eq!(NFTModule::<Runtime>::check_ownership(&ALICE, &(CLASS_ID, 0)), Ok(true));
+		assert_eq!(Balances::free_balance(ALICE), seller_balance);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::free_balance(BOB), 500);
+	});
+}
+
+#[test]
+fn create_new_bundle_auction_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(ALICE);
+		init_test_nft(owner.clone());
+
+		assert_noop!(
+			AuctionModule::create_new_bundle_auction(owner.clone(), AuctionType::Auction, vec![], 100, 102),
+			Error::<Runtime>::EmptyBundle
+		);
+		assert_noop!(
+			AuctionModule::create_new_bundle_auction(
+				owner.clone(),
+				AuctionType::Auction,
+				vec![
+					ItemId::NFT(CLASS_ID, 0),
+					ItemId::Estate(ESTATE_ID_EXIST),
+					ItemId::Estate(ESTATE_ID_EXIST_1),
+					ItemId::LandUnit(LAND_UNIT_EXIST, ALICE_METAVERSE_ID),
+				],
+				100,
+				102
+			),
+			Error::<Runtime>::ExceedMaximumBundleItems
+		);
+		assert_noop!(
+			AuctionModule::create_new_bundle_auction(
+				owner.clone(),
+				AuctionType::Auction,
+				vec![ItemId::Estate(ESTATE_ID_EXIST), ItemId::Estate(ESTATE_ID_EXIST)],
+				100,
+				102
+			),
+			Error::<Runtime>::InvalidBundleItem
+		);
+		assert_noop!(
+			AuctionModule::create_new_bundle_auction(
+				owner.clone(),
+				AuctionType::Auction,
+				vec![ItemId::Estate(ESTATE_ID_EXIST), ItemId::Spot(0, ALICE_METAVERSE_ID)],
+				100,
+				102
+			),
+			Error::<Runtime>::InvalidBundleItem
+		);
+		assert_noop!(
+			AuctionModule::create_new_bundle_auction(
+				Origin::signed(BOB),
+				AuctionType::Auction,
+				vec![ItemId::Estate(ESTATE_ID_EXIST)],
+				100,
+				102
+			),
+			Error::<Runtime>::NotItemOwner
+		);
+		assert_eq!(AuctionModule::bundles_index(), 0);
+	});
+}
+
+#[test]
+fn prune_settled_auctions_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		init_test_nft(owner.clone());
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::NFT(0, 0),
+			None,
+			BOB,
+			100,
+			0,
+			ListingLevel::Global
+		));
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 100));
+		run_to_block(102);
+
+		// Auction settled at block 101 belongs to archive era 1
+		let summary = AuctionSummary {
+			item_id: ItemId::NFT(0, 0),
+			winner: Some(ALICE),
+			price: 100,
+			fees: 1,
+		};
+		assert_eq!(AuctionModule::settled_auctions(1, 0), Some(summary.clone()));
+		assert!(AuctionModule::get_auction_item(0).is_some());
+
+		assert_noop!(
+			AuctionModule::prune_settled_auctions(Origin::signed(ALICE), 1, 10),
+			Error::<Runtime>::ArchiveRetentionPeriodNotPassed
+		);
+
+		run_to_block(250);
+		assert_noop!(
+			AuctionModule::prune_settled_auctions(Origin::signed(ALICE), 0, 10),
+			Error::<Runtime>::NoSettledAuctionsInEra
+		);
+		assert_noop!(
+			AuctionModule::prune_settled_auctions(Origin::signed(ALICE), 1, 0),
+			Error::<Runtime>::TooManySettledAuctions
+		);
+		assert_ok!(AuctionModule::prune_settled_auctions(Origin::signed(ALICE), 1, 10));
+
+		let root = <Runtime as frame_system::Config>::Hashing::hash_of(&(0 as AuctionId, summary));
+		assert_eq!(AuctionModule::archived_auction_roots(1), Some((root, 1)));
+		assert_eq!(AuctionModule::settled_auctions(1, 0), None);
+		assert_eq!(AuctionModule::get_auction_item(0), None);
+		let event = mock::Event::AuctionModule(crate::Event::SettledAuctionsArchived(1, root, 1));
+		assert_eq!(last_event(), event);
+	});
+}
+
+#[test]
+fn prune_settled_auctions_should_resume_large_era() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		init_test_nft(owner.clone());
+		assert_ok!(NFTModule::<Runtime>::mint(
+			owner.clone(),
+			CLASS_ID,
+			vec![1],
+			test_attributes(1),
+			2
+		));
+		for token_id in 0..3 {
+			assert_ok!(AuctionModule::create_auction(
+				AuctionType::Auction,
+				ItemId::NFT(0, token_id),
+				None,
+				BOB,
+				100,
+				0,
+				ListingLevel::Global
+			));
+		}
+		run_to_block(250);
+
+		let leaves: Vec<H256> = SettledAuctions::<Runtime>::iter_prefix(1)
+			.map(|(auction_id, summary)| BlakeTwo256::hash_of(&(auction_id, summary)))
+			.collect();
+		assert_eq!(leaves.len(), 3);
+
+		assert_ok!(AuctionModule::prune_settled_auctions(Origin::signed(ALICE), 1, 2));
+		let event = mock::Event::AuctionModule(crate::Event::SettledAuctionsPruned(1, 2, 2));
+		assert_eq!(last_event(), event);
+		assert_eq!(AuctionModule::archived_auction_roots(1), None);
+		assert_eq!(AuctionModule::pending_archive_leaves(1), leaves[..2].to_vec());
+
+		assert_ok!(AuctionModule::prune_settled_auctions(Origin::signed(ALICE), 1, 2));
+		let root = AuctionModule::settled_auctions_root(leaves);
+		assert_eq!(AuctionModule::archived_auction_roots(1), Some((root, 3)));
+		assert!(AuctionModule::pending_archive_leaves(1).is_empty());
+		assert_eq!(SettledAuctions::<Runtime>::iter_prefix(1).count(), 0);
+		assert_noop!(
+			AuctionModule::prune_settled_auctions(Origin::signed(ALICE), 1, 2),
+			Error::<Runtime>::NoSettledAuctionsInEra
+		);
+	});
+}
+
+#[test]
+fn settled_auctions_root_should_carry_odd_node() {
+	ExtBuilder::default().build().execute_with(|| {
+		let leaves: Vec<H256> = (0u8..3).map(|i| H256::repeat_byte(i)).collect();
+		let left = BlakeTwo256::hash_of(&(leaves[0], leaves[1]));
+		let expected = BlakeTwo256::hash_of(&(left, leaves[2]));
+		assert_eq!(AuctionModule::settled_auctions_root(leaves), expected);
+	});
+}
+
+#[test]
+fn listing_duration_limits_should_apply_per_item_type() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		let limits = ListingDurationLimits {
+			min_duration: 20,
+			max_duration: Some(50),
+		};
+		assert_noop!(
+			AuctionModule::set_listing_duration_limits(origin.clone(), ListingItemType::NFT, Some(limits)),
+			BadOrigin
+		);
+		assert_noop!(
+			AuctionModule::set_listing_duration_limits(
+				Origin::root(),
+				ListingItemType::NFT,
+				Some(ListingDurationLimits {
+					min_duration: 60,
+					max_duration: Some(50),
+				})
+			),
+			Error::<Runtime>::InvalidListingDurationLimits
+		);
+		assert_ok!(AuctionModule::set_listing_duration_limits(
+			Origin::root(),
+			ListingItemType::NFT,
+			Some(limits)
+		));
+		assert_eq!(
+			AuctionModule::listing_duration_limits(ListingItemType::NFT),
+			Some(limits)
+		);
+
+		// Above the global minimum but below the NFT minimum
+		assert_noop!(
+			AuctionModule::create_new_auction(origin.clone(), ItemId::NFT(0, 0), 100, 16, ListingLevel::Global),
+			Error::<Runtime>::AuctionEndIsLessThanMinimumDuration
+		);
+		assert_noop!(
+			AuctionModule::create_new_auction(origin.clone(), ItemId::NFT(0, 0), 100, 61, ListingLevel::Global),
+			Error::<Runtime>::AuctionEndExceedsMaximumDuration
+		);
+		// Estates have no limits and still use the global minimum
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::Estate(ESTATE_ID_EXIST),
+			Some(101),
+			ALICE,
+			100,
+			0,
+			ListingLevel::Global
+		));
+		assert_ok!(AuctionModule::create_new_auction(
+			origin,
+			ItemId::NFT(0, 0),
+			100,
+			51,
+			ListingLevel::Global
+		));
+
+		let event = mock::Event::AuctionModule(crate::Event::ListingDurationLimitsUpdated(
+			ListingItemType::NFT,
+			Some(limits),
+		));
+		assert!(System::events().iter().any(|record| record.event == event));
+	});
+}
+
+#[test]
+fn dutch_auction_should_sell_to_first_buyer_at_current_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		init_test_nft(owner.clone());
+
+		assert_noop!(
+			AuctionModule::create_new_dutch_auction(
+				owner.clone(),
+				ItemId::NFT(0, 0),
+				200,
+				1000,
+				101,
+				ListingLevel::Global
+			),
+			Error::<Runtime>::InvalidDutchAuctionPrice
+		);
+		assert_ok!(AuctionModule::create_new_dutch_auction(
+			owner,
+			ItemId::NFT(0, 0),
+			1000,
+			200,
+			101,
+			ListingLevel::Global
+		));
+		assert_eq!(AuctionModule::dutch_auction_price(0), Some(1000));
+		assert_noop!(
+			AuctionModule::bid(Origin::signed(ALICE), 0, 1000),
+			Error::<Runtime>::InvalidAuctionType
+		);
+
+		// Half way through the listing the price has dropped half way to the floor
+		run_to_block(51);
+		assert_eq!(AuctionModule::dutch_auction_price(0), Some(600));
+		assert_noop!(
+			AuctionModule::buy_now(Origin::signed(ALICE), 0, 500),
+			Error::<Runtime>::InvalidBuyItNowPrice
+		);
+
+		// Buyer pays the current price even when offering more
+		assert_ok!(AuctionModule::buy_now(Origin::signed(ALICE), 0, 700));
+		assert_eq!(NFTModule::<Runtime>::check_ownership(&ALICE, &(0, 0)), Ok(true));
+		assert_eq!(Balances::free_balance(ALICE), 99400);
+		assert_eq!(AuctionModule::dutch_auction_floor_price(0), None);
+		assert_eq!(
+			last_event(),
+			mock::Event::AuctionModule(crate::Event::BuyNowFinalised(0, ALICE, 600))
+		);
+	});
+}
+
+#[test]
+fn dutch_auction_should_sell_estates_and_land_units() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AuctionModule::create_new_dutch_auction(
+				Origin::signed(BOB),
+				ItemId::Estate(ESTATE_ID_EXIST),
+				300,
+				100,
+				101,
+				ListingLevel::Global
+			),
+			Error::<Runtime>::NotItemOwner
+		);
+		assert_noop!(
+			AuctionModule::create_new_dutch_auction(
+				Origin::signed(ALICE),
+				ItemId::Spot(0, ALICE_METAVERSE_ID),
+				300,
+				100,
+				101,
+				ListingLevel::Global
+			),
+			Error::<Runtime>::DutchAuctionItemNotSupported
+		);
+		assert_ok!(AuctionModule::create_new_dutch_auction(
+			Origin::signed(ALICE),
+			ItemId::Estate(ESTATE_ID_EXIST),
+			300,
+			100,
+			101,
+			ListingLevel::Global
+		));
+		assert_ok!(AuctionModule::create_new_dutch_auction(
+			Origin::signed(ALICE),
+			ItemId::LandUnit(LAND_UNIT_WITHOUT_NEIGHBOURS, ALICE_METAVERSE_ID),
+			300,
+			100,
+			101,
+			ListingLevel::Global
+		));
+
+		run_to_block(51);
+		assert_ok!(AuctionModule::buy_now(Origin::signed(BOB), 0, 200));
+		assert_eq!(
+			last_event(),
+			mock::Event::AuctionModule(crate::Event::BuyNowFinalised(0, BOB, 200))
+		);
+		assert_eq!(AuctionModule::items_in_auction(ItemId::Estate(ESTATE_ID_EXIST)), None);
+
+		assert_ok!(AuctionModule::buy_now(Origin::signed(BOB), 1, 200));
+		assert_eq!(
+			last_event(),
+			mock::Event::AuctionModule(crate::Event::BuyNowFinalised(1, BOB, 200))
+		);
+		assert_eq!(
+			AuctionModule::items_in_auction(ItemId::LandUnit(LAND_UNIT_WITHOUT_NEIGHBOURS, ALICE_METAVERSE_ID)),
+			None
+		);
+		assert_eq!(Balances::free_balance(BOB), 100);
+	});
+}
+
+#[test]
+fn sealed_bid_auction_should_settle_to_highest_revealed_bid() {
+	ExtBuilder::default().build().execute_with(|| {
+		let _ = Balances::deposit_creating(&PARTNER_ACCOUNT, 1000);
+		assert_ok!(AuctionModule::create_new_sealed_bid_auction(
+			Origin::signed(ALICE),
+			ItemId::Estate(ESTATE_ID_EXIST),
+			100,
+			21,
+			ListingLevel::Global
+		));
+		assert_eq!(AuctionModule::sealed_bid_reveal_start(0), Some(21));
+
+		let salt = b"salt".to_vec();
+		let bob_commitment = BlakeTwo256::hash_of(&(BOB, 300u128, salt.clone()));
+		assert_ok!(AuctionModule::commit_sealed_bid(Origin::signed(BOB), 0, bob_commitment));
+		assert_ok!(AuctionModule::commit_sealed_bid(
+			Origin::signed(PARTNER_ACCOUNT),
+			0,
+			BlakeTwo256::hash_of(&(PARTNER_ACCOUNT, 500u128, salt.clone()))
+		));
+		assert_noop!(
+			AuctionModule::commit_sealed_bid(Origin::signed(BOB), 0, bob_commitment),
+			Error::<Runtime>::SealedBidAlreadyCommitted
+		);
+		assert_noop!(
+			AuctionModule::reveal_sealed_bid(Origin::signed(BOB), 0, 300, salt.clone()),
+			Error::<Runtime>::SealedBidRevealNotOpen
+		);
+
+		run_to_block(21);
+		assert_noop!(
+			AuctionModule::commit_sealed_bid(Origin::signed(BOB), 0, bob_commitment),
+			Error::<Runtime>::SealedBidCommitmentClosed
+		);
+		assert_noop!(
+			AuctionModule::reveal_sealed_bid(Origin::signed(BOB), 0, 400, salt.clone()),
+			Error::<Runtime>::InvalidSealedBidReveal
+		);
+		assert_ok!(AuctionModule::reveal_sealed_bid(Origin::signed(BOB), 0, 300, salt));
+		assert_eq!(Balances::reserved_balance(BOB), 300);
+
+		// Partner never reveals and forfeits the deposit to the seller
+		run_to_block(32);
+		assert_eq!(AuctionModule::auctions(0), None);
+		assert_eq!(Balances::free_balance(BOB), 200);
+		assert_eq!(Balances::free_balance(PARTNER_ACCOUNT), 990);
+		assert_eq!(Balances::free_balance(ALICE), 100310);
+		let event = mock::Event::AuctionModule(crate::Event::AuctionFinalized(0, BOB, 300));
+		assert!(System::events().iter().any(|record| record.event == event));
+	});
+}
+
+#[test]
+fn buy_now_price_should_end_auction_and_release_highest_bid() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		init_test_nft(owner.clone());
+
+		assert_ok!(AuctionModule::create_new_auction(
+			owner.clone(),
+			ItemId::NFT(0, 0),
+			100,
+			101,
+			ListingLevel::Global
+		));
+		assert_noop!(
+			AuctionModule::buy_now(Origin::signed(ALICE), 0, 1000),
+			Error::<Runtime>::InvalidAuctionType
+		);
+		assert_noop!(
+			AuctionModule::set_auction_buy_now_price(Origin::signed(ALICE), 0, Some(1000)),
+			Error::<Runtime>::NoPermissionToUpdateListing
+		);
+		assert_noop!(
+			AuctionModule::set_auction_buy_now_price(owner.clone(), 0, Some(50)),
+			Error::<Runtime>::InvalidAuctionBuyNowPrice
+		);
+		assert_ok!(AuctionModule::set_auction_buy_now_price(owner, 0, Some(1000)));
+
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 200));
+		assert_eq!(Balances::reserved_balance(ALICE), 200);
+		assert_noop!(
+			AuctionModule::buy_now(Origin::signed(ALICE), 0, 900),
+			Error::<Runtime>::InvalidBuyItNowPrice
+		);
+
+		assert_ok!(AuctionModule::buy_now(Origin::signed(ALICE), 0, 1000));
+		assert_eq!(AuctionModule::auctions(0), None);
+		assert_eq!(AuctionModule::auction_buy_now_price(0), None);
+		assert_eq!(NFTModule::<Runtime>::check_ownership(&ALICE, &(0, 0)), Ok(true));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 99000);
+	});
+}
+
+#[test]
+fn bid_reaching_buy_now_price_should_clear_it() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		init_test_nft(owner.clone());
+		let _ = Balances::deposit_creating(&PARTNER_ACCOUNT, 1000);
+
+		assert_ok!(AuctionModule::create_new_auction(
+			owner.clone(),
+			ItemId::NFT(0, 0),
+			100,
+			101,
+			ListingLevel::Global
+		));
+		assert_ok!(AuctionModule::set_auction_buy_now_price(owner, 0, Some(300)));
+
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 300));
+		assert_eq!(AuctionModule::auction_buy_now_price(0), None);
+		assert_eq!(
+			last_event(),
+			mock::Event::AuctionModule(crate::Event::AuctionBuyNowPriceUpdated(0, None))
+		);
+
+		// The auction can no longer be bought below the highest bid
+		assert_noop!(
+			AuctionModule::buy_now(Origin::signed(PARTNER_ACCOUNT), 0, 300),
+			Error::<Runtime>::InvalidAuctionType
+		);
+		assert_eq!(Balances::reserved_balance(ALICE), 300);
+	});
+}
+
+#[test]
+fn late_bid_should_extend_auction_up_to_max_extensions() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		init_test_nft(owner.clone());
+		assert_ok!(AuctionModule::create_new_auction(
+			owner,
+			ItemId::NFT(0, 0),
+			100,
+			101,
+			ListingLevel::Global
+		));
+
+		// Bids outside the anti-snipe threshold keep the end
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 100));
+		assert_eq!(AuctionModule::auctions(0).and_then(|auction| auction.end), Some(101));
+
+		run_to_block(95);
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 200));
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::AuctionExtended(0, 111))
+		);
+		assert_eq!(AuctionModule::auction_end_time(101, 0), None);
+		assert_eq!(AuctionModule::auction_end_time(111, 0), Some(()));
+
+		run_to_block(105);
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 300));
+		assert_eq!(AuctionModule::get_auction_item(0).map(|item| item.end_time), Some(121));
+
+		// Maximum number of extensions reached
+		run_to_block(115);
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 400));
+		assert_eq!(AuctionModule::auctions(0).and_then(|auction| auction.end), Some(121));
+		assert_eq!(AuctionModule::auction_extensions(0), 2);
+
+		run_to_block(122);
+		assert_eq!(AuctionModule::auctions(0), None);
+		assert_eq!(NFTModule::<Runtime>::check_ownership(&ALICE, &(0, 0)), Ok(true));
+	});
+}
+
+#[test]
+fn auction_in_listing_currency_should_settle_in_that_currency() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		init_test_nft(owner.clone());
+		let currency_id = FungibleTokenId::FungibleToken(1);
+
+		assert_noop!(
+			AuctionModule::create_new_auction_in_currency(
+				owner.clone(),
+				AuctionType::Auction,
+				ItemId::NFT(0, 0),
+				currency_id,
+				100,
+				101,
+				ListingLevel::Global
+			),
+			Error::<Runtime>::FungibleTokenCurrencyNotFound
+		);
+		assert_ok!(Tokens::deposit(currency_id, &ALICE, 1000));
+		assert_ok!(AuctionModule::create_new_auction_in_currency(
+			owner,
+			AuctionType::Auction,
+			ItemId::NFT(0, 0),
+			currency_id,
+			100,
+			101,
+			ListingLevel::Global
+		));
+		assert_eq!(
+			AuctionModule::get_auction_item(0).map(|item| item.currency_id),
+			Some(currency_id)
+		);
+
+		assert_noop!(
+			AuctionModule::bid(Origin::signed(ALICE), 0, 2000),
+			Error::<Runtime>::InsufficientFreeBalance
+		);
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 200));
+		assert_eq!(Tokens::reserved_balance(currency_id, &ALICE), 200);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+
+		run_to_block(102);
+		assert_eq!(NFTModule::<Runtime>::check_ownership(&ALICE, &(0, 0)), Ok(true));
+		assert_eq!(Tokens::free_balance(currency_id, &ALICE), 800);
+		// Seller pays the 1% royalty in the listing currency
+		assert_eq!(Tokens::free_balance(currency_id, &BOB), 198);
+		assert_eq!(Balances::free_balance(ALICE), 100000);
+	});
+}
+
+#[test]
+fn estate_sale_should_split_proceeds_between_seller_royalty_and_network() {
+	ExtBuilder::default().build().execute_with(|| {
+		NetworkFee::set(Perbill::from_percent(1));
+
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::BuyNow,
+			ItemId::Estate(ESTATE_ID_WITH_ROYALTY),
+			None,
+			BOB,
+			200,
+			0,
+			ListingLevel::Global
+		));
+		assert_ok!(AuctionModule::buy_now(Origin::signed(ALICE), 0, 200));
+
+		assert_eq!(Balances::free_balance(ALICE), 99800);
+		// 10% estate royalty and 1% network fee are taken from the seller proceeds
+		assert_eq!(Balances::free_balance(BOB), 678);
+		assert_eq!(Balances::free_balance(ESTATE_ROYALTY_ACCOUNT), 20);
+		assert_eq!(Balances::free_balance(NETWORK_FEE_ACCOUNT), 2);
+
+		let event = mock::Event::AuctionModule(crate::Event::SaleProceedsSplit(0, 178, 20, 2));
+		assert!(System::events().iter().any(|record| record.event == event));
+		assert_eq!(
+			AuctionModule::settled_auctions(0, 0).map(|summary| summary.fees),
+			Some(22)
+		);
+	});
+}
+
+#[test]
+fn accept_offer_should_sell_unlisted_nft_for_escrowed_funds() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(Origin::signed(BOB));
+
+		assert_noop!(
+			AuctionModule::make_offer(Origin::signed(BOB), ItemId::NFT(0, 0), 200, 10),
+			Error::<Runtime>::CannotOfferOnOwnItem
+		);
+		assert_ok!(AuctionModule::make_offer(
+			Origin::signed(ALICE),
+			ItemId::NFT(0, 0),
+			200,
+			10
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), 200);
+		assert_eq!(AuctionModule::offer_count(ItemId::NFT(0, 0)), 1);
+
+		let bob_balance = Balances::free_balance(BOB);
+		assert_noop!(
+			AuctionModule::accept_offer(Origin::signed(PARTNER_ACCOUNT), ItemId::NFT(0, 0), ALICE),
+			Error::<Runtime>::NotItemOwner
+		);
+		assert_ok!(AuctionModule::accept_offer(
+			Origin::signed(BOB),
+			ItemId::NFT(0, 0),
+			ALICE
+		));
+
+		assert_eq!(NFTModule::<Runtime>::check_ownership(&ALICE, &(0, 0)), Ok(true));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), 99800);
+		// Seller pays the 1% class royalty out of the offer
+		assert_eq!(Balances::free_balance(BOB), bob_balance + 198);
+		assert_eq!(AuctionModule::offers(ItemId::NFT(0, 0), ALICE), None);
+		assert_eq!(AuctionModule::offer_count(ItemId::NFT(0, 0)), 0);
+
+		let event = mock::Event::AuctionModule(crate::Event::OfferAccepted(ItemId::NFT(0, 0), ALICE, BOB, 200));
+		assert_eq!(last_event(), event);
+	});
+}
+
+#[test]
+fn offer_book_should_be_bounded_and_release_expired_offers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let item_id = ItemId::Estate(ESTATE_ID_EXIST);
+		Balances::make_free_balance_be(&PARTNER_ACCOUNT, 500);
+		Balances::make_free_balance_be(&NETWORK_FEE_ACCOUNT, 500);
+
+		assert_noop!(
+			AuctionModule::make_offer(Origin::signed(ALICE), item_id, 100, 10),
+			Error::<Runtime>::CannotOfferOnOwnItem
+		);
+		assert_ok!(AuctionModule::make_offer(Origin::signed(BOB), item_id, 100, 5));
+		assert_ok!(AuctionModule::make_offer(
+			Origin::signed(PARTNER_ACCOUNT),
+			item_id,
+			100,
+			20
+		));
+		assert_noop!(
+			AuctionModule::make_offer(Origin::signed(NETWORK_FEE_ACCOUNT), item_id, 100, 20),
+			Error::<Runtime>::OfferBookIsFull
+		);
+
+		run_to_block(6);
+		assert_noop!(
+			AuctionModule::accept_offer(Origin::signed(ALICE), item_id, BOB),
+			Error::<Runtime>::OfferIsExpired
+		);
+
+		// Expired offer makes room for a new one and its escrow is released
+		assert_ok!(AuctionModule::make_offer(
+			Origin::signed(NETWORK_FEE_ACCOUNT),
+			item_id,
+			100,
+			20
+		));
+		assert_eq!(AuctionModule::offers(item_id, BOB), None);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(AuctionModule::offer_count(item_id), 2);
+
+		assert_ok!(AuctionModule::withdraw_offer(Origin::signed(PARTNER_ACCOUNT), item_id));
+		assert_eq!(Balances::reserved_balance(PARTNER_ACCOUNT), 0);
+		assert_eq!(AuctionModule::offer_count(item_id), 1);
+		assert_noop!(
+			AuctionModule::withdraw_offer(Origin::signed(PARTNER_ACCOUNT), item_id),
+			Error::<Runtime>::OfferNotFound
+		);
+	});
+}
+
+#[test]
+fn cancel_auction_should_compensate_highest_bidder() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(Origin::signed(BOB));
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::NFT(0, 0),
+			None,
+			BOB,
+			100,
+			0,
+			ListingLevel::Global
+		));
+		assert_noop!(
+			AuctionModule::cancel_auction(Origin::signed(ALICE), 0),
+			Error::<Runtime>::NoPermissionToUpdateListing
+		);
+
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 200));
+		let bob_balance = Balances::free_balance(BOB);
+		assert_ok!(AuctionModule::cancel_auction(Origin::signed(BOB), 0));
+
+		// Seller pays 10% of the highest bid to the bidder
+		assert_eq!(Balances::free_balance(BOB), bob_balance - 20);
+		assert_eq!(Balances::free_balance(ALICE), 100020);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(AuctionModule::auctions(0), None);
+		assert_eq!(AuctionModule::items_in_auction(ItemId::NFT(0, 0)), None);
+		assert_eq!(
+			last_event(),
+			mock::Event::AuctionModule(crate::Event::AuctionCancelled(0, 20))
+		);
+
+		// Cancelling before any bid is free
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::NFT(0, 0),
+			None,
+			BOB,
+			100,
+			0,
+			ListingLevel::Global
+		));
+		assert_ok!(AuctionModule::cancel_auction(Origin::signed(BOB), 1));
+		assert_eq!(Balances::free_balance(BOB), bob_balance - 20);
+		assert_eq!(
+			last_event(),
+			mock::Event::AuctionModule(crate::Event::AuctionCancelled(1, 0))
+		);
+	});
+}
+
+#[test]
+fn hidden_reserve_should_only_sell_to_bids_meeting_revealed_reserve() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(Origin::signed(BOB));
+		let salt = vec![7u8];
+		let reserve_hash = BlakeTwo256::hash_of(&(300u128, salt.clone()));
+		assert_ok!(AuctionModule::create_new_auction_with_hidden_reserve(
+			Origin::signed(BOB),
+			ItemId::NFT(0, 0),
+			100,
+			101,
+			ListingLevel::Global,
+			reserve_hash
+		));
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 200));
+
+		assert_noop!(
+			AuctionModule::reveal_hidden_reserve(Origin::signed(ALICE), 0, 300, salt.clone()),
+			Error::<Runtime>::NoPermissionToUpdateListing
+		);
+		assert_noop!(
+			AuctionModule::reveal_hidden_reserve(Origin::signed(BOB), 0, 250, salt.clone()),
+			Error::<Runtime>::InvalidHiddenReserveReveal
+		);
+		assert_ok!(AuctionModule::reveal_hidden_reserve(
+			Origin::signed(BOB),
+			0,
+			300,
+			salt.clone()
+		));
+
+		// Highest bid is below the reserve, the item stays with the seller
+		run_to_block(102);
+		assert_eq!(NFTModule::<Runtime>::check_ownership(&BOB, &(0, 0)), Ok(true));
+		assert_eq!(Balances::free_balance(ALICE), 100000);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		let event = mock::Event::AuctionModule(crate::Event::HiddenReserveNotMet(0, Some(300)));
+		assert!(System::events().iter().any(|record| record.event == event));
+
+		assert_ok!(AuctionModule::create_new_auction_with_hidden_reserve(
+			Origin::signed(BOB),
+			ItemId::NFT(0, 0),
+			100,
+			203,
+			ListingLevel::Global,
+			reserve_hash
+		));
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 1, 300));
+		assert_ok!(AuctionModule::reveal_hidden_reserve(Origin::signed(BOB), 1, 300, salt));
+
+		run_to_block(204);
+		assert_eq!(NFTModule::<Runtime>::check_ownership(&ALICE, &(0, 0)), Ok(true));
+		assert_eq!(Balances::free_balance(ALICE), 99700);
+	});
+}
+
+#[test]
+fn unrevealed_hidden_reserve_should_pay_cancellation_penalty() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(Origin::signed(BOB));
+		let salt = vec![7u8];
+		assert_ok!(AuctionModule::create_new_auction_with_hidden_reserve(
+			Origin::signed(BOB),
+			ItemId::NFT(0, 0),
+			100,
+			101,
+			ListingLevel::Global,
+			BlakeTwo256::hash_of(&(300u128, salt))
+		));
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 200));
+		assert_noop!(
+			AuctionModule::reveal_hidden_reserve(Origin::signed(BOB), 0, 300, vec![7u8; 9]),
+			Error::<Runtime>::RevealSaltTooLong
+		);
+		let bob_balance = Balances::free_balance(BOB);
+
+		// Seller never reveals the reserve and pays 10% of the highest bid to the bidder
+		run_to_block(102);
+		assert_eq!(NFTModule::<Runtime>::check_ownership(&BOB, &(0, 0)), Ok(true));
+		assert_eq!(Balances::free_balance(BOB), bob_balance - 20);
+		assert_eq!(Balances::free_balance(ALICE), 100020);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		let event = mock::Event::AuctionModule(crate::Event::UnrevealedReservePenaltyPaid(0, 20));
+		assert!(System::events().iter().any(|record| record.event == event));
+	});
+}
+
+#[test]
+fn ended_auctions_should_be_settled_within_per_block_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		init_test_nft(owner.clone());
+		assert_ok!(NFTModule::<Runtime>::mint(
+			owner,
+			CLASS_ID,
+			vec![1],
+			test_attributes(1),
+			3
+		));
+		for token_id in 0..4 {
+			assert_ok!(AuctionModule::create_auction(
+				AuctionType::Auction,
+				ItemId::NFT(0, token_id),
+				None,
+				BOB,
+				100,
+				0,
+				ListingLevel::Global
+			));
+		}
+
+		// Only 3 of the 4 auctions ending at block 101 are settled in the next block
+		run_to_block(102);
+		let open_auctions = (0..4u64).filter(|id| AuctionModule::auctions(id).is_some()).count();
+		assert_eq!(open_auctions, 1);
+		assert_eq!(AuctionModule::settlement_cursor(), Some(101));
+
+		run_to_block(103);
+		let open_auctions = (0..4u64).filter(|id| AuctionModule::auctions(id).is_some()).count();
+		assert_eq!(open_auctions, 0);
+		assert_eq!(AuctionModule::settlement_cursor(), Some(103));
+	});
+}
+
+#[test]
+fn network_fee_should_be_shared_with_metaverse_treasury() {
+	ExtBuilder::default().build().execute_with(|| {
+		NetworkFee::set(Perbill::from_percent(10));
+		let metaverse_treasury = MetaverseInfoSource::get_metaverse_treasury(ALICE_METAVERSE_ID);
+
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::BuyNow,
+			ItemId::LandUnit(LAND_UNIT_EXIST, ALICE_METAVERSE_ID),
+			None,
+			BOB,
+			200,
+			0,
+			ListingLevel::Global
+		));
+		assert_ok!(AuctionModule::buy_now(Origin::signed(ALICE), 0, 200));
+
+		// 10% network fee is split evenly between the network and the metaverse treasury
+		assert_eq!(Balances::free_balance(BOB), 680);
+		assert_eq!(Balances::free_balance(NETWORK_FEE_ACCOUNT), 10);
+		assert_eq!(Balances::free_balance(metaverse_treasury), 10);
+
+		let event = mock::Event::AuctionModule(crate::Event::MetaverseTreasuryFeePaid(ALICE_METAVERSE_ID, 10));
+		assert!(System::events().iter().any(|record| record.event == event));
+		let event = mock::Event::AuctionModule(crate::Event::SaleProceedsSplit(0, 180, 0, 20));
+		assert!(System::events().iter().any(|record| record.event == event));
+	});
+}
+
+#[test]
+fn active_auctions_should_be_paginated_by_auction_id() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(BOB);
+		init_test_nft(owner.clone());
+		assert_ok!(NFTModule::<Runtime>::mint(
+			owner,
+			CLASS_ID,
+			vec![1],
+			test_attributes(1),
+			2
+		));
+		for token_id in 0..3 {
+			assert_ok!(AuctionModule::create_auction(
+				AuctionType::Auction,
+				ItemId::NFT(0, token_id),
+				None,
+				BOB,
+				100,
+				0,
+				ListingLevel::Global
+			));
+		}
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 150));
+		assert_ok!(AuctionModule::cancel_auction(Origin::signed(BOB), 1));
+
+		let active_ids = |start, limit| -> Vec<AuctionId> {
+			AuctionModule::active_auctions(start, limit)
+				.iter()
+				.map(|summary| summary.auction_id)
+				.collect()
+		};
+		assert_eq!(active_ids(0, 10), vec![0, 2]);
+		assert_eq!(active_ids(1, 1), vec![]);
+		assert_eq!(active_ids(1, 2), vec![2]);
+		assert_eq!(active_ids(0, 1), vec![0]);
+		assert_eq!(active_ids(0, u32::MAX), vec![0, 2]);
+
+		assert_eq!(
+			AuctionModule::active_auctions(0, 1),
+			vec![ActiveAuctionSummary {
+				auction_id: 0,
+				item_id: ItemId::NFT(0, 0),
+				auction_type: AuctionType::Auction,
+				seller: BOB,
+				amount: 150,
+				bid: Some((ALICE, 150)),
+				end: Some(101),
+				currency_id: FungibleTokenId::NativeToken(0),
+			}]
+		);
+	});
+}
+
+#[test]
+fn bid_increment_rule_should_rise_over_auction_period() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(Origin::signed(BOB));
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::NFT(0, 0),
+			Some(100),
+			BOB,
+			100,
+			0,
+			ListingLevel::Global
+		));
+		let rule = BidIncrementRule {
+			start: BidIncrement::Absolute(10),
+			end: Some(BidIncrement::Absolute(110)),
+		};
+		assert_noop!(
+			AuctionModule::set_bid_increment_rule(Origin::signed(ALICE), 0, Some(rule.clone())),
+			Error::<Runtime>::NoPermissionToUpdateListing
+		);
+		assert_ok!(AuctionModule::set_bid_increment_rule(
+			Origin::signed(BOB),
+			0,
+			Some(rule.clone())
+		));
+
+		System::set_block_number(1);
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 100));
+		assert_noop!(
+			AuctionModule::set_bid_increment_rule(Origin::signed(BOB), 0, None),
+			Error::<Runtime>::BidIncrementRuleLocked
+		);
+		// 1% into the auction the minimum increment is 11
+		assert_eq!(AuctionModule::minimum_bid_increment(0, 100), 11);
+		assert_noop!(
+			AuctionModule::bid(Origin::signed(ALICE), 0, 101),
+			Error::<Runtime>::BidBelowMinimumIncrement
+		);
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 111));
+
+		// Halfway through the auction the minimum increment is 60
+		System::set_block_number(50);
+		assert_noop!(
+			AuctionModule::bid(Origin::signed(ALICE), 0, 170),
+			Error::<Runtime>::BidBelowMinimumIncrement
+		);
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 171));
+	});
+}
+
+#[test]
+fn default_bid_increment_should_apply_without_rule() {
+	ExtBuilder::default().build().execute_with(|| {
+		DefaultBidIncrement::set(Perbill::from_percent(10));
+		init_test_nft(Origin::signed(BOB));
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::NFT(0, 0),
+			Some(100),
+			BOB,
+			100,
+			0,
+			ListingLevel::Global
+		));
+
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 200));
+		assert_eq!(AuctionModule::minimum_bid_increment(0, 200), 20);
+		assert_noop!(
+			AuctionModule::bid(Origin::signed(ALICE), 0, 219),
+			Error::<Runtime>::BidBelowMinimumIncrement
+		);
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 220));
+	});
+}
+
+#[test]
+fn bidder_allowlist_should_gate_bids() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(Origin::signed(BOB));
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::Auction,
+			ItemId::NFT(0, 0),
+			None,
+			BOB,
+			100,
+			0,
+			ListingLevel::Global
+		));
+
+		// Only holders of class 0 tokens can bid, ALICE holds none
+		assert_ok!(AuctionModule::set_bidder_allowlist(
+			Origin::signed(BOB),
+			0,
+			Some(BidderAllowlist::NftHolders(0))
+		));
+		assert_noop!(
+			AuctionModule::bid(Origin::signed(ALICE), 0, 200),
+			Error::<Runtime>::BidderNotAllowlisted
+		);
+
+		assert_noop!(
+			AuctionModule::set_bidder_allowlist(
+				Origin::signed(BOB),
+				0,
+				Some(BidderAllowlist::Accounts(vec![ALICE, PARTNER_ACCOUNT, 10]))
+			),
+			Error::<Runtime>::BidderAllowlistTooLarge
+		);
+		assert_ok!(AuctionModule::set_bidder_allowlist(
+			Origin::signed(BOB),
+			0,
+			Some(BidderAllowlist::Accounts(vec![ALICE, PARTNER_ACCOUNT]))
+		));
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 200));
+
+		assert_noop!(
+			AuctionModule::set_bidder_allowlist(Origin::signed(BOB), 0, None),
+			Error::<Runtime>::BidderAllowlistLocked
+		);
+	});
+}
+
+#[test]
+fn land_block_auction_should_issue_undeployed_land_block_to_winner() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AuctionModule::create_land_block_auction(
+				Origin::signed(ALICE),
+				10,
+				UndeployedLandBlockType::Transferable,
+				100,
+				101
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			AuctionModule::create_land_block_auction(
+				Origin::root(),
+				0,
+				UndeployedLandBlockType::Transferable,
+				100,
+				101
+			),
+			Error::<Runtime>::InvalidLandBlockSize
+		);
+		assert_ok!(AuctionModule::create_land_block_auction(
+			Origin::root(),
+			10,
+			UndeployedLandBlockType::Transferable,
+			100,
+			101
+		));
+		assert_eq!(
+			last_event(),
+			Event::AuctionModule(crate::Event::NewLandBlockAuction(0, 0, 10))
+		);
+		assert_eq!(AuctionModule::items_in_auction(ItemId::Block(0)), Some(true));
+
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 200));
+		run_to_block(102);
+
+		let event = Event::AuctionModule(crate::Event::LandBlockAuctionSettled(0, ALICE, 10));
+		assert!(System::events().iter().any(|record| record.event == event));
+		assert_eq!(Balances::free_balance(LAND_SALE_TREASURY), 200);
+		assert_eq!(AuctionModule::land_block_sale(0), None);
+		assert_eq!(AuctionModule::items_in_auction(ItemId::Block(0)), None);
+	});
+}
+
+#[test]
+fn land_block_auction_should_refund_winner_when_issuance_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AuctionModule::create_land_block_auction(
+			Origin::root(),
+			LAND_BLOCK_SIZE_NOT_ISSUABLE,
+			UndeployedLandBlockType::Transferable,
+			100,
+			101
+		));
+		assert_ok!(AuctionModule::bid(Origin::signed(ALICE), 0, 200));
+		run_to_block(102);
+
+		let event = Event::AuctionModule(crate::Event::LandBlockIssuanceFailed(
+			0,
+			ALICE,
+			200,
+			DispatchError::Other("Land block can not be issued"),
+		));
+		assert!(System::events().iter().any(|record| record.event == event));
+		assert_eq!(Balances::free_balance(ALICE), 100000);
+		assert_eq!(Balances::free_balance(LAND_SALE_TREASURY), 0);
+		assert_eq!(AuctionModule::land_block_sale(0), None);
+	});
+}
+
+#[test]
+fn cancelled_land_block_auction_should_remove_sale() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(AuctionModule::create_land_block_auction(
+			Origin::root(),
+			10,
+			UndeployedLandBlockType::Transferable,
+			100,
+			101
+		));
+		assert!(AuctionModule::land_block_sale(0).is_some());
+
+		assert_ok!(AuctionModule::cancel_auction(Origin::signed(LAND_SALE_TREASURY), 0));
+		assert_eq!(AuctionModule::land_block_sale(0), None);
+		assert_eq!(AuctionModule::items_in_auction(ItemId::Block(0)), None);
+	});
+}
+
+#[test]
+fn token_royalty_override_should_be_paid_at_settlement() {
+	ExtBuilder::default().build().execute_with(|| {
+		let co_creator: AccountId = 7;
+		init_test_nft(Origin::signed(BOB));
+		assert_ok!(NFTModule::<Runtime>::mint_with_royalty(
+			Origin::signed(BOB),
+			CLASS_ID,
+			vec![1],
+			test_attributes(1),
+			1,
+			co_creator,
+			Perbill::from_percent(10)
+		));
+
+		assert_ok!(AuctionModule::create_auction(
+			AuctionType::BuyNow,
+			ItemId::NFT(0, 1),
+			None,
+			BOB,
+			200,
+			0,
+			ListingLevel::Global
+		));
+		assert_ok!(AuctionModule::buy_now(Origin::signed(ALICE), 0, 200));
+
+		// 10% token royalty goes to the co-creator instead of the 1% class royalty
+		assert_eq!(Balances::free_balance(co_creator), 20);
+		let event = mock::Event::AuctionModule(crate::Event::SaleProceedsSplit(0, 180, 20, 0));
+		assert!(System::events().iter().any(|record| record.event == event));
+	});
+}
+
+#[test]
+fn accept_swap_should_exchange_items_and_tokens_atomically() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(Origin::signed(BOB));
+		assert_noop!(
+			AuctionModule::propose_swap(
+				Origin::signed(ALICE),
+				BOB,
+				vec![ItemId::Estate(ESTATE_ID_EXIST)],
+				50,
+				vec![],
+				0,
+				10
+			),
+			Error::<Runtime>::InvalidSwap
+		);
+		assert_ok!(AuctionModule::propose_swap(
+			Origin::signed(ALICE),
+			BOB,
+			vec![ItemId::Estate(ESTATE_ID_EXIST)],
+			50,
+			vec![ItemId::NFT(0, 0)],
+			0,
+			10
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), 50);
+		assert_noop!(
+			AuctionModule::accept_swap(Origin::signed(ALICE), 0),
+			Error::<Runtime>::NoPermissionToUpdateSwap
+		);
+
+		let bob_balance = Balances::free_balance(BOB);
+		assert_ok!(AuctionModule::accept_swap(Origin::signed(BOB), 0));
+		assert_eq!(NFTModule::<Runtime>::check_ownership(&ALICE, &(0, 0)), Ok(true));
+		assert_eq!(Balances::free_balance(BOB), bob_balance + 50);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(AuctionModule::swap_proposal(0), None);
+		assert_eq!(AuctionModule::items_in_auction(ItemId::Estate(ESTATE_ID_EXIST)), None);
+		assert_eq!(last_event(), Event::AuctionModule(crate::Event::SwapAccepted(0)));
+
+		// Counterparty can reject a swap, releasing the escrowed tokens
+		assert_ok!(AuctionModule::propose_swap(
+			Origin::signed(ALICE),
+			BOB,
+			vec![],
+			50,
+			vec![ItemId::Estate(ESTATE_ID_EXIST_1)],
+			0,
+			10
+		));
+		assert_noop!(
+			AuctionModule::cancel_swap(Origin::signed(PARTNER_ACCOUNT), 1),
+			Error::<Runtime>::NoPermissionToUpdateSwap
+		);
+		assert_ok!(AuctionModule::cancel_swap(Origin::signed(BOB), 1));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+	});
+}
+
+#[test]
+fn propose_swap_should_respect_land_buyer_gate() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AuctionModule::propose_swap(
+				Origin::signed(ALICE),
+				BOB,
+				vec![ItemId::LandUnit(LAND_UNIT_EXIST, BOB_METAVERSE_ID)],
+				0,
+				vec![],
+				50,
+				10
+			),
+			Error::<Runtime>::LandBuyerNotAllowed
+		);
+		assert_ok!(AuctionModule::propose_swap(
+			Origin::signed(ALICE),
+			BOB,
+			vec![ItemId::LandUnit(LAND_UNIT_EXIST, ALICE_METAVERSE_ID)],
+			0,
+			vec![],
+			50,
+			10
+		));
+	});
+}
+
+#[test]
+fn make_offer_on_soulbound_nft_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let owner = Origin::signed(ALICE);
+		init_test_nft(owner.clone());
+		assert_ok!(NFTModule::<Runtime>::create_class(
+			owner.clone(),
+			vec![1],
+			Default::default(),
+			COLLECTION_ID,
+			TokenType::BoundToAddress,
+			CollectionType::Collectable,
+			Perbill::from_percent(0u32)
+		));
+		assert_ok!(NFTModule::<Runtime>::issue_soulbound_nft(
+			owner,
+			1,
+			BOB,
+			vec![1],
+			Default::default()
+		));
+		assert_eq!(NFTModule::<Runtime>::check_ownership(&BOB, &(1, 0)), Ok(true));
+
+		assert_noop!(
+			AuctionModule::make_offer(Origin::signed(ALICE), ItemId::NFT(1, 0), 200, 10),
+			Error::<Runtime>::ItemNotTransferable
+		);
+	});
+}
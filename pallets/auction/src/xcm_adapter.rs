@@ -0,0 +1,89 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Delivery of cross listings to partner marketplaces over XCM.
+//!
+//! Listings are sent to the partner chain as a call of its listing pallet, the call index and
+//! weight are registered with the partner marketplace. Execution is bought with the relay chain
+//! token held by our sovereign account on the partner chain and the surplus is deposited back.
+
+use codec::Encode;
+use frame_support::traits::Get;
+use sp_runtime::{DispatchError, DispatchResult};
+use sp_std::{marker::PhantomData, vec, vec::Vec};
+use xcm::latest::prelude::*;
+
+use auction_manager::{CrossListingHandler, MarketplaceId, PartnerMarketplaceInfo, VersionedCrossListing};
+
+/// Build the message delivering `listing` to the partner marketplace. `self_location` is the
+/// location of this chain as seen by the partner chain.
+pub fn cross_listing_message<AccountId: Encode, Balance: Encode, BlockNumber: Encode>(
+	marketplace: &PartnerMarketplaceInfo,
+	listing: &VersionedCrossListing<AccountId, Balance, BlockNumber>,
+	self_location: MultiLocation,
+) -> Xcm<()> {
+	let fee = MultiAsset {
+		id: Concrete(MultiLocation::parent()),
+		fun: Fungible(marketplace.execution_fee),
+	};
+	let mut call: Vec<u8> = marketplace.listing_call_index.to_vec();
+	listing.encode_to(&mut call);
+
+	Xcm(vec![
+		WithdrawAsset(fee.clone().into()),
+		BuyExecution {
+			fees: fee,
+			weight_limit: Unlimited,
+		},
+		Transact {
+			origin_type: OriginKind::Native,
+			require_weight_at_most: marketplace.listing_call_weight,
+			call: call.into(),
+		},
+		RefundSurplus,
+		DepositAsset {
+			assets: All.into(),
+			max_assets: 1,
+			beneficiary: self_location,
+		},
+	])
+}
+
+/// Send cross listings to partner marketplaces on sibling parachains, the marketplace id is the
+/// parachain id of the partner chain
+pub struct XcmCrossListingNotifier<XcmSender, SelfLocation>(PhantomData<(XcmSender, SelfLocation)>);
+
+impl<AccountId, Balance, BlockNumber, XcmSender, SelfLocation> CrossListingHandler<AccountId, Balance, BlockNumber>
+	for XcmCrossListingNotifier<XcmSender, SelfLocation>
+where
+	AccountId: Encode,
+	Balance: Encode,
+	BlockNumber: Encode,
+	XcmSender: SendXcm,
+	SelfLocation: Get<MultiLocation>,
+{
+	fn notify_listing(
+		marketplace_id: MarketplaceId,
+		marketplace: &PartnerMarketplaceInfo,
+		listing: VersionedCrossListing<AccountId, Balance, BlockNumber>,
+	) -> DispatchResult {
+		let destination = MultiLocation::new(1, X1(Parachain(marketplace_id)));
+		let message = cross_listing_message(marketplace, &listing, SelfLocation::get());
+
+		XcmSender::send_xcm(destination, message).map_err(|_| DispatchError::Other("CrossListingNotificationFailed"))
+	}
+}
@@ -0,0 +1,91 @@
+use cumulus_primitives_core::ParaId;
+use frame_support::traits::GenesisBuild;
+use sp_io::TestExternalities;
+use xcm_simulator::{decl_test_network, decl_test_parachain, decl_test_relay_chain};
+
+use crate::mock::{ALICE, BOB};
+
+pub mod para;
+pub mod relay;
+
+pub const METAVERSE_PARA_ID: u32 = 1;
+pub const PARTNER_PARA_ID: u32 = 2000;
+pub const SOVEREIGN_BALANCE: u128 = 1_000_000_000_000;
+
+decl_test_parachain! {
+	pub struct MetaverseChain {
+		Runtime = para::Runtime,
+		XcmpMessageHandler = para::XcmpQueue,
+		DmpMessageHandler = para::DmpQueue,
+		new_ext = para_ext(METAVERSE_PARA_ID),
+	}
+}
+
+decl_test_parachain! {
+	pub struct PartnerChain {
+		Runtime = para::Runtime,
+		XcmpMessageHandler = para::XcmpQueue,
+		DmpMessageHandler = para::DmpQueue,
+		new_ext = para_ext(PARTNER_PARA_ID),
+	}
+}
+
+decl_test_relay_chain! {
+	pub struct Relay {
+		Runtime = relay::Runtime,
+		XcmConfig = relay::XcmConfig,
+		new_ext = relay_ext(),
+	}
+}
+
+decl_test_network! {
+	pub struct TestNet {
+		relay_chain = Relay,
+		parachains = vec![
+			(1, MetaverseChain),
+			(2000, PartnerChain),
+		],
+	}
+}
+
+/// Parachain with funded users and sovereign accounts of both sibling chains
+pub fn para_ext(para_id: u32) -> TestExternalities {
+	use para::{sibling_account, Runtime, System};
+
+	let mut t = frame_system::GenesisConfig::default()
+		.build_storage::<Runtime>()
+		.unwrap();
+
+	let parachain_info_config = parachain_info::GenesisConfig {
+		parachain_id: ParaId::from(para_id),
+	};
+	<parachain_info::GenesisConfig as GenesisBuild<Runtime, _>>::assimilate_storage(&parachain_info_config, &mut t)
+		.unwrap();
+
+	pallet_balances::GenesisConfig::<Runtime> {
+		balances: vec![
+			(ALICE, 100_000),
+			(BOB, 500),
+			(sibling_account(METAVERSE_PARA_ID), SOVEREIGN_BALANCE),
+			(sibling_account(PARTNER_PARA_ID), SOVEREIGN_BALANCE),
+		],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+pub fn relay_ext() -> TestExternalities {
+	use relay::{Runtime, System};
+
+	let t = frame_system::GenesisConfig::default()
+		.build_storage::<Runtime>()
+		.unwrap();
+
+	let mut ext = TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
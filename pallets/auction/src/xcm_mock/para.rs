@@ -0,0 +1,400 @@
+use cumulus_primitives_core::ParaId;
+use frame_support::traits::{EnsureOrigin, Everything, Nothing};
+use frame_support::weights::Weight;
+use frame_support::{construct_runtime, parameter_types};
+use frame_system::EnsureRoot;
+use polkadot_parachain::primitives::Sibling;
+use sp_core::H256;
+use sp_runtime::testing::Header;
+use sp_runtime::traits::{AccountIdConversion, IdentityLookup};
+use xcm::latest::prelude::*;
+use xcm_builder::{
+	AllowTopLevelPaidExecutionFrom, CurrencyAdapter, FixedRateOfFungible, FixedWeightBounds, IsConcrete,
+	LocationInverter, ParentIsPreset, RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
+	SovereignSignedViaLocation, TakeWeightCredit,
+};
+use xcm_executor::{traits::Convert, XcmExecutor};
+
+use auction_manager::MarketplaceId;
+use core_primitives::{NftAssetData, NftClassData};
+use primitives::{Amount, FungibleTokenId};
+
+use crate as auction;
+use crate::mock::{
+	AntiSnipeExtension, AntiSnipeThreshold, ArchiveEraLength, AssetMintingFee, AuctionCancellationPenalty,
+	AuctionRetentionPeriod, AuctionTimeToClose, ClassMintingFee, Continuumm, DefaultBidIncrement, EstateHandler,
+	ExistentialDeposits, ExpansionRightPeriod, GenerativeRevealDelay, Handler, InstallmentCount,
+	InstallmentDefaultForfeit, InstallmentDownPayment, InstallmentPeriod, LandSaleTreasury, MaxAuctionAllowlistSize,
+	MaxAuctionExtensions, MaxBatchMinting, MaxBatchTransfer, MaxBundleItems, MaxClassMetadata, MaxFinality,
+	MaxMetadata, MaxNestingDepth, MaxOffersPerItem, MaxRarityAttributes, MaxRevealSaltLength, MaxSettlementsPerBlock,
+	MaxTokenMetadata, MaxTypedAttributes, MetaverseFeeShare, MetaverseInfoSource, MetaverseNetworkTreasuryPalletId,
+	MinimumAuctionDuration, MiningCurrencyId, MockAuctionManager, MockPublic, MockRandomness, MockSignature,
+	MockUndeployedLandBlockHandler, NativeCurrencyId, NetworkFee, NetworkFeeAccount, NftPalletId, SealedBidDeposit,
+	SealedBidRevealPeriod, TreasuryModuleAccount,
+};
+
+pub type AccountId = u128;
+pub type Balance = u128;
+pub type BlockNumber = u64;
+
+/// Pallet index of the partner marketplace pallet, listings are delivered to its first call
+pub const PARTNER_MARKETPLACE_PALLET_INDEX: u8 = 12;
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = Everything;
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type MaxLocks = ();
+	type WeightInfo = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+}
+
+parameter_types! {
+	pub const ReservedXcmpWeight: Weight = 1_000_000_000;
+	pub const ReservedDmpWeight: Weight = 1_000_000_000;
+}
+
+impl cumulus_pallet_parachain_system::Config for Runtime {
+	type Event = Event;
+	type SelfParaId = parachain_info::Pallet<Runtime>;
+	type DmpMessageHandler = DmpQueue;
+	type ReservedDmpWeight = ReservedDmpWeight;
+	type OutboundXcmpMessageSource = XcmpQueue;
+	type XcmpMessageHandler = XcmpQueue;
+	type ReservedXcmpWeight = ReservedXcmpWeight;
+	type OnSystemEvent = ();
+}
+
+impl parachain_info::Config for Runtime {}
+
+parameter_types! {
+	pub const RelayLocation: MultiLocation = MultiLocation::parent();
+	pub RelayChainOrigin: Origin = cumulus_pallet_xcm::Origin::Relay.into();
+	pub Ancestry: MultiLocation = Parachain(ParachainInfo::parachain_id().into()).into();
+	pub SelfLocation: MultiLocation = MultiLocation::new(1, X1(Parachain(ParachainInfo::parachain_id().into())));
+	pub RelayPerSecond: (AssetId, u128) = (MultiLocation::parent().into(), 1_000_000_000_000);
+	pub const UnitWeightCost: Weight = 10;
+	pub const MaxInstructions: u32 = 100;
+	pub NftPalletLocation: MultiLocation = MultiLocation::new(
+		1,
+		X2(Parachain(ParachainInfo::parachain_id().into()), PalletInstance(10))
+	);
+}
+
+/// Converts local `AccountIndex64` locations into mock accounts
+pub struct AccountIndexAsAccountId;
+
+impl Convert<MultiLocation, AccountId> for AccountIndexAsAccountId {
+	fn convert(location: MultiLocation) -> Result<AccountId, MultiLocation> {
+		match location {
+			MultiLocation {
+				parents: 0,
+				interior: X1(AccountIndex64 { index, .. }),
+			} => Ok(index.into()),
+			_ => Err(location),
+		}
+	}
+}
+
+pub type LocationToAccountId = (
+	ParentIsPreset<AccountId>,
+	SiblingParachainConvertsVia<Sibling, AccountId>,
+	AccountIndexAsAccountId,
+);
+
+/// Balances of the mock represent the relay chain token
+pub type LocalAssetTransactor =
+	CurrencyAdapter<Balances, IsConcrete<RelayLocation>, LocationToAccountId, AccountId, ()>;
+
+pub type NftAssetTransactor =
+	pallet_nft::xcm_adapter::NonFungibleAdapter<Runtime, LocationToAccountId, NftPalletLocation>;
+
+pub type XcmOriginToCallOrigin = (
+	SovereignSignedViaLocation<LocationToAccountId, Origin>,
+	RelayChainAsNative<RelayChainOrigin, Origin>,
+	SiblingParachainAsNative<cumulus_pallet_xcm::Origin, Origin>,
+);
+
+pub type XcmRouter = super::ParachainXcmRouter<ParachainInfo>;
+
+pub type Barrier = (TakeWeightCredit, AllowTopLevelPaidExecutionFrom<Everything>);
+
+pub struct XcmConfig;
+
+impl xcm_executor::Config for XcmConfig {
+	type Call = Call;
+	type XcmSender = XcmRouter;
+	type AssetTransactor = (NftAssetTransactor, LocalAssetTransactor);
+	type OriginConverter = XcmOriginToCallOrigin;
+	type IsReserve = pallet_nft::xcm_adapter::ForeignNftReserve<Runtime>;
+	type IsTeleporter = ();
+	type LocationInverter = LocationInverter<Ancestry>;
+	type Barrier = Barrier;
+	type Weigher = FixedWeightBounds<UnitWeightCost, Call, MaxInstructions>;
+	type Trader = FixedRateOfFungible<RelayPerSecond, ()>;
+	type ResponseHandler = ();
+	type AssetTrap = ();
+	type AssetClaims = ();
+	type SubscriptionService = ();
+}
+
+impl cumulus_pallet_xcmp_queue::Config for Runtime {
+	type Event = Event;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type ChannelInfo = ParachainSystem;
+	type VersionWrapper = ();
+	type ExecuteOverweightOrigin = EnsureRoot<AccountId>;
+	type ControllerOrigin = EnsureRoot<AccountId>;
+	type ControllerOriginConverter = XcmOriginToCallOrigin;
+}
+
+impl cumulus_pallet_dmp_queue::Config for Runtime {
+	type Event = Event;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type ExecuteOverweightOrigin = EnsureRoot<AccountId>;
+}
+
+impl cumulus_pallet_xcm::Config for Runtime {
+	type Event = Event;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+}
+
+impl orml_tokens::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = FungibleTokenId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = orml_tokens::TransferDust<Runtime, TreasuryModuleAccount>;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Nothing;
+}
+
+pub type AdaptedBasicCurrency = currencies::BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
+
+impl currencies::Config for Runtime {
+	type Event = Event;
+	type MultiSocialCurrency = Tokens;
+	type NativeCurrency = AdaptedBasicCurrency;
+	type GetNativeCurrencyId = NativeCurrencyId;
+	type TreasuryAccount = TreasuryModuleAccount;
+	type UpdateOrigin = EnsureRoot<AccountId>;
+}
+
+impl orml_nft::Config for Runtime {
+	type ClassId = u32;
+	type TokenId = u64;
+	type ClassData = NftClassData<Balance>;
+	type TokenData = NftAssetData<Balance>;
+	type MaxClassMetadata = MaxClassMetadata;
+	type MaxTokenMetadata = MaxTokenMetadata;
+}
+
+impl pallet_nft::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type Treasury = MetaverseNetworkTreasuryPalletId;
+	type PalletId = NftPalletId;
+	type AuctionHandler = MockAuctionManager;
+	type WeightInfo = ();
+	type MaxBatchTransfer = MaxBatchTransfer;
+	type MaxBatchMinting = MaxBatchMinting;
+	type MaxMetadata = MaxMetadata;
+	type MultiCurrency = Currencies;
+	type MiningResourceId = MiningCurrencyId;
+	type AssetMintingFee = AssetMintingFee;
+	type ClassMintingFee = ClassMintingFee;
+	type ActivityRecorder = ();
+	type MaxRarityAttributes = MaxRarityAttributes;
+	type MaxTypedAttributes = MaxTypedAttributes;
+	type MaxNestingDepth = MaxNestingDepth;
+	type OffchainSignature = MockSignature;
+	type OffchainPublic = MockPublic;
+	type Randomness = MockRandomness;
+	type GenerativeRevealDelay = GenerativeRevealDelay;
+	type OnNftTransfer = ();
+}
+
+/// Sibling parachain origin, resolving to the parachain id and its sovereign account
+pub struct EnsureSiblingMarketplace;
+
+impl EnsureOrigin<Origin> for EnsureSiblingMarketplace {
+	type Success = (MarketplaceId, AccountId);
+
+	fn try_origin(o: Origin) -> Result<Self::Success, Origin> {
+		Into::<Result<cumulus_pallet_xcm::Origin, Origin>>::into(o).and_then(|o| match o {
+			cumulus_pallet_xcm::Origin::SiblingParachain(para_id) => {
+				Ok((para_id.into(), Sibling::from(para_id).into_account()))
+			}
+			r => Err(Origin::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> Origin {
+		Origin::from(cumulus_pallet_xcm::Origin::SiblingParachain(ParaId::from(2000)))
+	}
+}
+
+impl auction::Config for Runtime {
+	type Event = Event;
+	type AuctionTimeToClose = AuctionTimeToClose;
+	type Handler = Handler;
+	type Currency = Balances;
+	type ContinuumHandler = Continuumm;
+	type FungibleTokenCurrency = Tokens;
+	type MetaverseInfoSource = MetaverseInfoSource;
+	type MinimumAuctionDuration = MinimumAuctionDuration;
+	type EstateHandler = EstateHandler;
+	type NetworkFee = NetworkFee;
+	type NetworkFeeAccount = NetworkFeeAccount;
+	type MetaverseFeeShare = MetaverseFeeShare;
+	type MaxFinality = MaxFinality;
+	type NFTHandler = NFTModule;
+	type ExpansionRightPeriod = ExpansionRightPeriod;
+	type CrossListingHandler = auction::xcm_adapter::XcmCrossListingNotifier<XcmRouter, SelfLocation>;
+	type CrossChainOrigin = EnsureSiblingMarketplace;
+	type MaxBundleItems = MaxBundleItems;
+	type ArchiveEraLength = ArchiveEraLength;
+	type AuctionRetentionPeriod = AuctionRetentionPeriod;
+	type InstallmentDownPayment = InstallmentDownPayment;
+	type InstallmentPeriod = InstallmentPeriod;
+	type InstallmentCount = InstallmentCount;
+	type InstallmentDefaultForfeit = InstallmentDefaultForfeit;
+	type SealedBidDeposit = SealedBidDeposit;
+	type SealedBidRevealPeriod = SealedBidRevealPeriod;
+	type AntiSnipeThreshold = AntiSnipeThreshold;
+	type AntiSnipeExtension = AntiSnipeExtension;
+	type MaxAuctionExtensions = MaxAuctionExtensions;
+	type MaxOffersPerItem = MaxOffersPerItem;
+	type AuctionCancellationPenalty = AuctionCancellationPenalty;
+	type MaxSettlementsPerBlock = MaxSettlementsPerBlock;
+	type MaxAuctionAllowlistSize = MaxAuctionAllowlistSize;
+	type UndeployedLandBlockHandler = MockUndeployedLandBlockHandler;
+	type LandSaleOrigin = EnsureRoot<AccountId>;
+	type LandSaleTreasury = LandSaleTreasury;
+	type MaxRevealSaltLength = MaxRevealSaltLength;
+	type DefaultBidIncrement = DefaultBidIncrement;
+	type ActivityRecorder = ();
+}
+
+/// Listing pallet of a partner marketplace, storing the cross listings it receives
+#[frame_support::pallet]
+pub mod partner_marketplace {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_std::vec::Vec;
+
+	use auction_manager::{MarketplaceId, VersionedCrossListing};
+
+	use super::{AccountId, Balance, BlockNumber};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type MarketplaceOrigin: EnsureOrigin<Self::Origin, Success = (MarketplaceId, Self::AccountId)>;
+	}
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	#[pallet::getter(fn listings)]
+	pub type Listings<T: Config> =
+		StorageValue<_, Vec<(MarketplaceId, VersionedCrossListing<AccountId, Balance, BlockNumber>)>, ValueQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::weight(10_000)]
+		pub fn receive_cross_listing(
+			origin: OriginFor<T>,
+			listing: VersionedCrossListing<AccountId, Balance, BlockNumber>,
+		) -> DispatchResult {
+			let (marketplace_id, _) = T::MarketplaceOrigin::ensure_origin(origin)?;
+			Listings::<T>::append((marketplace_id, listing));
+			Ok(())
+		}
+	}
+}
+
+impl partner_marketplace::Config for Runtime {
+	type MarketplaceOrigin = EnsureSiblingMarketplace;
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>} = 0,
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>} = 1,
+		ParachainSystem: cumulus_pallet_parachain_system::{Pallet, Call, Config, Storage, Inherent, Event<T>} = 2,
+		ParachainInfo: parachain_info::{Pallet, Storage, Config} = 3,
+		XcmpQueue: cumulus_pallet_xcmp_queue::{Pallet, Call, Storage, Event<T>} = 4,
+		DmpQueue: cumulus_pallet_dmp_queue::{Pallet, Call, Storage, Event<T>} = 5,
+		CumulusXcm: cumulus_pallet_xcm::{Pallet, Event<T>, Origin} = 6,
+		Tokens: orml_tokens::{Pallet, Call, Storage, Config<T>, Event<T>} = 7,
+		Currencies: currencies::{Pallet, Storage, Call, Event<T>} = 8,
+		OrmlNft: orml_nft::{Pallet, Storage, Config<T>} = 9,
+		NFTModule: pallet_nft::{Pallet, Storage, Call, Event<T>} = 10,
+		AuctionModule: auction::{Pallet, Call, Storage, Event<T>} = 11,
+		PartnerMarketplace: partner_marketplace::{Pallet, Call, Storage} = 12,
+	}
+);
+
+/// Sovereign account of a sibling parachain on this chain
+pub fn sibling_account(para_id: u32) -> AccountId {
+	Sibling::from(ParaId::from(para_id)).into_account()
+}
+
+/// Location of a local account of the parachain
+pub fn account_location(who: AccountId) -> MultiLocation {
+	X1(AccountIndex64 {
+		network: NetworkId::Any,
+		index: who as u64,
+	})
+	.into()
+}
@@ -0,0 +1,257 @@
+#![cfg(test)]
+
+use codec::Encode;
+use frame_support::assert_ok;
+use sp_runtime::Perbill;
+use sp_std::collections::btree_map::BTreeMap;
+use xcm::latest::prelude::*;
+use xcm_simulator::TestExt;
+
+use auction_manager::{AuctionType, CrossListingV1, ListingLevel, PartnerMarketplaceInfo, VersionedCrossListing};
+use core_primitives::{Attributes, CollectionType, NFTTrait, TokenType};
+use primitives::ItemId;
+
+use crate::mock::{ALICE, BOB, CLASS_ID, COLLECTION_ID};
+use crate::xcm_mock::para::{
+	account_location, sibling_account, AuctionModule, Balances, Call, NFTModule, Origin, PartnerMarketplace, Runtime,
+	XcmRouter, PARTNER_MARKETPLACE_PALLET_INDEX,
+};
+use crate::xcm_mock::*;
+
+const EXECUTION_FEE: u128 = 1_000_000;
+
+fn partner_marketplace() -> PartnerMarketplaceInfo {
+	PartnerMarketplaceInfo {
+		listing_call_index: [PARTNER_MARKETPLACE_PALLET_INDEX, 0],
+		listing_call_weight: 10_000,
+		execution_fee: EXECUTION_FEE,
+	}
+}
+
+fn test_attributes(x: u8) -> Attributes {
+	let mut attr: Attributes = BTreeMap::new();
+	attr.insert(vec![x, x + 5], vec![x, x + 10]);
+	attr
+}
+
+/// Register the partner chain and cross list a buy now listing of an NFT of Alice
+fn cross_list_buy_now(price: u128) {
+	MetaverseChain::execute_with(|| {
+		assert_ok!(AuctionModule::register_partner_marketplace(
+			Origin::root(),
+			PARTNER_PARA_ID,
+			partner_marketplace()
+		));
+		assert_ok!(AuctionModule::set_cross_listing(Origin::signed(ALICE), true));
+
+		assert_ok!(NFTModule::create_group(Origin::root(), vec![1], vec![1]));
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			Perbill::from_percent(0u32)
+		));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(ALICE),
+			CLASS_ID,
+			vec![1],
+			test_attributes(1),
+			1
+		));
+		assert_ok!(AuctionModule::create_new_buy_now(
+			Origin::signed(ALICE),
+			ItemId::NFT(CLASS_ID, 0),
+			price,
+			102,
+			ListingLevel::Global,
+		));
+	});
+}
+
+#[test]
+fn cross_listing_should_be_delivered_to_partner_marketplace() {
+	TestNet::reset();
+
+	cross_list_buy_now(150);
+
+	PartnerChain::execute_with(|| {
+		assert_eq!(
+			PartnerMarketplace::listings(),
+			vec![(
+				METAVERSE_PARA_ID,
+				VersionedCrossListing::V1(CrossListingV1 {
+					auction_id: 0,
+					item_id: ItemId::NFT(CLASS_ID, 0),
+					recipient: ALICE,
+					amount: 150,
+					end_time: 102,
+					auction_type: AuctionType::BuyNow,
+				})
+			)]
+		);
+
+		// Execution is paid by the sovereign account of the metaverse chain and the surplus is
+		// refunded to it
+		let sovereign_balance = Balances::free_balance(sibling_account(METAVERSE_PARA_ID));
+		assert!(sovereign_balance < SOVEREIGN_BALANCE);
+		assert!(sovereign_balance > SOVEREIGN_BALANCE - EXECUTION_FEE);
+	});
+}
+
+#[test]
+fn remote_purchase_intent_should_buy_cross_listed_item() {
+	TestNet::reset();
+
+	cross_list_buy_now(150);
+	let alice_balance = MetaverseChain::execute_with(|| Balances::free_balance(ALICE));
+
+	PartnerChain::execute_with(|| {
+		let call = Call::AuctionModule(crate::Call::<Runtime>::submit_remote_purchase_intent {
+			auction_id: 0,
+			buyer: BOB,
+			value: 150,
+		});
+		let fee = MultiAsset {
+			id: Concrete(MultiLocation::parent()),
+			fun: Fungible(EXECUTION_FEE),
+		};
+		let message = Xcm(vec![
+			WithdrawAsset(fee.clone().into()),
+			BuyExecution {
+				fees: fee,
+				weight_limit: Unlimited,
+			},
+			Transact {
+				origin_type: OriginKind::Native,
+				require_weight_at_most: 100_000,
+				call: call.encode().into(),
+			},
+			RefundSurplus,
+			DepositAsset {
+				assets: All.into(),
+				max_assets: 1,
+				beneficiary: MultiLocation::new(1, X1(Parachain(PARTNER_PARA_ID))),
+			},
+		]);
+		assert_ok!(XcmRouter::send_xcm(
+			MultiLocation::new(1, X1(Parachain(METAVERSE_PARA_ID))),
+			message
+		));
+	});
+
+	MetaverseChain::execute_with(|| {
+		assert_eq!(
+			AuctionModule::remote_purchase_intents(0, PARTNER_PARA_ID),
+			Some((BOB, 150))
+		);
+		assert_eq!(NFTModule::check_ownership(&BOB, &(CLASS_ID, 0)), Ok(true));
+		assert_eq!(Balances::free_balance(ALICE), alice_balance + 150);
+		// The sovereign account of the partner chain pays for the purchase and the execution
+		let sovereign_balance = Balances::free_balance(sibling_account(PARTNER_PARA_ID));
+		assert!(sovereign_balance < SOVEREIGN_BALANCE - 150);
+		assert!(sovereign_balance > SOVEREIGN_BALANCE - 150 - EXECUTION_FEE);
+	});
+}
+
+/// Register a collection of the partner chain as the foreign class of a new class of Alice
+fn register_partner_collection() -> MultiLocation {
+	let collection = MultiLocation::new(1, X3(Parachain(PARTNER_PARA_ID), PalletInstance(10), GeneralIndex(0)));
+	MetaverseChain::execute_with(|| {
+		assert_ok!(NFTModule::create_group(Origin::root(), vec![1], vec![1]));
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			Perbill::from_percent(0u32)
+		));
+		assert_ok!(NFTModule::register_foreign_class(
+			Origin::root(),
+			Box::new(collection.clone()),
+			Some(CLASS_ID)
+		));
+	});
+	collection
+}
+
+/// Send a foreign NFT from the partner chain to Bob on the metaverse chain
+fn send_foreign_nft(nft: MultiAsset) {
+	PartnerChain::execute_with(|| {
+		let fee = MultiAsset {
+			id: Concrete(MultiLocation::parent()),
+			fun: Fungible(EXECUTION_FEE),
+		};
+		let message = Xcm(vec![
+			WithdrawAsset(fee.clone().into()),
+			BuyExecution {
+				fees: fee,
+				weight_limit: Unlimited,
+			},
+			ReserveAssetDeposited(nft.into()),
+			DepositAsset {
+				assets: All.into(),
+				max_assets: 2,
+				beneficiary: account_location(BOB),
+			},
+		]);
+		assert_ok!(XcmRouter::send_xcm(
+			MultiLocation::new(1, X1(Parachain(METAVERSE_PARA_ID))),
+			message
+		));
+	});
+}
+
+#[test]
+fn foreign_nft_from_its_reserve_chain_should_mint_derivative() {
+	TestNet::reset();
+
+	let collection = register_partner_collection();
+	send_foreign_nft((collection, AssetInstance::Index(7)).into());
+
+	MetaverseChain::execute_with(|| {
+		assert_eq!(NFTModule::foreign_token(CLASS_ID, 7), Some(0));
+		assert_eq!(NFTModule::check_ownership(&BOB, &(CLASS_ID, 0)), Ok(true));
+	});
+}
+
+#[test]
+fn foreign_nft_should_be_rejected_from_other_chains_and_over_max_supply() {
+	TestNet::reset();
+
+	let collection = register_partner_collection();
+	// A collection living on another chain cannot be deposited by the partner chain
+	let other_collection = MultiLocation::new(1, X2(Parachain(3000), GeneralIndex(0)));
+	MetaverseChain::execute_with(|| {
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			Perbill::from_percent(0u32)
+		));
+		assert_ok!(NFTModule::register_foreign_class(
+			Origin::root(),
+			Box::new(other_collection.clone()),
+			Some(CLASS_ID + 1)
+		));
+		assert_ok!(NFTModule::set_max_supply(Origin::signed(ALICE), CLASS_ID, 1));
+	});
+
+	send_foreign_nft((other_collection, AssetInstance::Index(1)).into());
+	send_foreign_nft((collection.clone(), AssetInstance::Index(1)).into());
+	send_foreign_nft((collection, AssetInstance::Index(2)).into());
+
+	MetaverseChain::execute_with(|| {
+		assert_eq!(NFTModule::foreign_token(CLASS_ID + 1, 1), None);
+		assert_eq!(NFTModule::foreign_token(CLASS_ID, 1), Some(0));
+		// The derivative class is capped at one NFT
+		assert_eq!(NFTModule::foreign_token(CLASS_ID, 2), None);
+	});
+}
@@ -0,0 +1,300 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use codec::{Decode, Encode};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Currency, ExistenceRequirement},
+	transactional, PalletId,
+};
+use frame_system::pallet_prelude::*;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AccountIdConversion, Saturating, Zero},
+	Perbill, RuntimeDebug,
+};
+
+pub use module::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub type BountyTierId = u8;
+pub type BountyPayoutId = u64;
+
+/// Bounty paid to a reporter, the justification hash points to the public disclosure
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BountyPayout<AccountId, Balance, Hash, BlockNumber> {
+	pub reporter: AccountId,
+	pub tier: BountyTierId,
+	pub amount: Balance,
+	pub justification: Hash,
+	pub approved_at: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Currency the bounties are paid in.
+		type Currency: Currency<Self::AccountId>;
+
+		/// Account of the bounty payout pot.
+		#[pallet::constant]
+		type BountyPotId: Get<PalletId>;
+
+		/// Treasury account refilling the payout pot.
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Origin which defines bounty tiers, the pot target and approves pot refills.
+		type GovernanceOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Security council origin which approves payouts to reporters.
+		type SecurityCouncilOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Number of blocks in a payout period.
+		#[pallet::constant]
+		type PayoutPeriod: Get<Self::BlockNumber>;
+
+		/// Maximum amount paid out in a payout period.
+		#[pallet::constant]
+		type MaxPayoutPerPeriod: Get<BalanceOf<Self>>;
+
+		/// Share of the pot target below which a pot refill is proposed.
+		#[pallet::constant]
+		type RefillThreshold: Get<Perbill>;
+	}
+
+	/// Payout amount of each bounty tier.
+	#[pallet::storage]
+	#[pallet::getter(fn bounty_tier)]
+	pub type BountyTiers<T: Config> = StorageMap<_, Twox64Concat, BountyTierId, BalanceOf<T>, OptionQuery>;
+
+	/// Balance the payout pot is refilled to.
+	#[pallet::storage]
+	#[pallet::getter(fn pot_target)]
+	pub type PotTarget<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// Payout period and the amount paid out in it.
+	#[pallet::storage]
+	#[pallet::getter(fn period_payout)]
+	pub type PeriodPayout<T: Config> = StorageValue<_, (T::BlockNumber, BalanceOf<T>), ValueQuery>;
+
+	/// Pot refill amount proposed to governance.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_refill)]
+	pub type PendingRefill<T: Config> = StorageValue<_, BalanceOf<T>, OptionQuery>;
+
+	/// Approved bounty payouts.
+	#[pallet::storage]
+	#[pallet::getter(fn payouts)]
+	pub type Payouts<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BountyPayoutId,
+		BountyPayout<T::AccountId, BalanceOf<T>, T::Hash, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_payout_id)]
+	pub type NextPayoutId<T: Config> = StorageValue<_, BountyPayoutId, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Bounty tier payout updated, removed if none
+		BountyTierUpdated {
+			tier: BountyTierId,
+			amount: Option<BalanceOf<T>>,
+		},
+		/// Pot target updated
+		PotTargetUpdated { target: BalanceOf<T> },
+		/// Bounty paid to a reporter
+		BountyPaid {
+			payout_id: BountyPayoutId,
+			reporter: T::AccountId,
+			amount: BalanceOf<T>,
+			justification: T::Hash,
+		},
+		/// Pot fell below the refill threshold, refill proposed to governance
+		PotRefillProposed { amount: BalanceOf<T> },
+		/// Pot refilled from the treasury
+		PotRefilled { amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Bounty tier is not defined
+		BountyTierNotFound,
+		/// Payout exceeds the cap of the payout period
+		PeriodPayoutCapExceeded,
+		/// Payout pot has not enough balance
+		InsufficientPotBalance,
+		/// No pot refill is proposed
+		NoPendingRefill,
+	}
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the payout of a bounty tier, or remove the tier if none
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn set_bounty_tier(
+			origin: OriginFor<T>,
+			tier: BountyTierId,
+			amount: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			BountyTiers::<T>::set(tier, amount);
+			Self::deposit_event(Event::BountyTierUpdated { tier, amount });
+			Ok(())
+		}
+
+		/// Set the balance the payout pot is refilled to
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn set_pot_target(origin: OriginFor<T>, target: BalanceOf<T>) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			PotTarget::<T>::put(target);
+			Self::deposit_event(Event::PotTargetUpdated { target });
+			Self::propose_refill_if_needed();
+			Ok(())
+		}
+
+		/// Pay the bounty of a tier from the pot to a reporter, the justification hash is
+		/// recorded with the payout
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(4, 4))]
+		#[transactional]
+		pub fn approve_payout(
+			origin: OriginFor<T>,
+			reporter: T::AccountId,
+			tier: BountyTierId,
+			justification: T::Hash,
+		) -> DispatchResult {
+			T::SecurityCouncilOrigin::ensure_origin(origin)?;
+
+			let amount = Self::bounty_tier(tier).ok_or(Error::<T>::BountyTierNotFound)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			let period = Self::payout_period_of(now);
+			let (last_period, paid) = Self::period_payout();
+			let paid = if last_period == period { paid } else { Zero::zero() };
+			let paid = paid.saturating_add(amount);
+			ensure!(
+				paid <= T::MaxPayoutPerPeriod::get(),
+				Error::<T>::PeriodPayoutCapExceeded
+			);
+
+			T::Currency::transfer(
+				&Self::pot_account(),
+				&reporter,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)
+			.map_err(|_| Error::<T>::InsufficientPotBalance)?;
+			PeriodPayout::<T>::put((period, paid));
+
+			let payout_id = NextPayoutId::<T>::mutate(|id| {
+				let payout_id = *id;
+				*id = id.saturating_add(1);
+				payout_id
+			});
+			Payouts::<T>::insert(
+				payout_id,
+				BountyPayout {
+					reporter: reporter.clone(),
+					tier,
+					amount,
+					justification,
+					approved_at: now,
+				},
+			);
+			Self::deposit_event(Event::BountyPaid {
+				payout_id,
+				reporter,
+				amount,
+				justification,
+			});
+
+			Self::propose_refill_if_needed();
+			Ok(())
+		}
+
+		/// Approve the proposed pot refill, transferring the amount from the treasury
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2, 2))]
+		#[transactional]
+		pub fn approve_pot_refill(origin: OriginFor<T>) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+
+			let amount = PendingRefill::<T>::take().ok_or(Error::<T>::NoPendingRefill)?;
+			T::Currency::transfer(
+				&T::TreasuryAccount::get(),
+				&Self::pot_account(),
+				amount,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			Self::deposit_event(Event::PotRefilled { amount });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn pot_account() -> T::AccountId {
+		T::BountyPotId::get().into_account()
+	}
+
+	fn payout_period_of(now: T::BlockNumber) -> T::BlockNumber {
+		let period_length = T::PayoutPeriod::get();
+		if period_length.is_zero() {
+			return Zero::zero();
+		}
+		now / period_length
+	}
+
+	/// Propose a refill up to the pot target once the pot falls below the refill threshold
+	fn propose_refill_if_needed() {
+		let target = Self::pot_target();
+		let balance = T::Currency::free_balance(&Self::pot_account());
+		if balance >= T::RefillThreshold::get() * target {
+			return;
+		}
+		let amount = target.saturating_sub(balance);
+		if Self::pending_refill() != Some(amount) {
+			PendingRefill::<T>::put(amount);
+			Self::deposit_event(Event::PotRefillProposed { amount });
+		}
+	}
+}
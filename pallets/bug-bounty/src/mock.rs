@@ -0,0 +1,137 @@
+#![cfg(test)]
+
+use frame_support::{construct_runtime, ord_parameter_types, parameter_types, PalletId};
+use frame_system::{EnsureRoot, EnsureSignedBy};
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup, Perbill};
+
+use crate as bug_bounty;
+
+use super::*;
+
+pub type AccountId = u128;
+pub type Balance = u128;
+pub type BlockNumber = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const SECURITY_COUNCIL: AccountId = 3;
+pub const TREASURY: AccountId = 100;
+
+ord_parameter_types! {
+	pub const SecurityCouncil: AccountId = SECURITY_COUNCIL;
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+}
+
+impl frame_system::Config for Runtime {
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = frame_support::traits::Everything;
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type MaxLocks = ();
+	type WeightInfo = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+}
+
+parameter_types! {
+	pub const BountyPotId: PalletId = PalletId(*b"bit/bbty");
+	pub const TreasuryAccount: AccountId = TREASURY;
+	pub const PayoutPeriod: BlockNumber = 100;
+	pub const MaxPayoutPerPeriod: Balance = 1500;
+	pub const RefillThreshold: Perbill = Perbill::from_percent(50);
+}
+
+impl Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type BountyPotId = BountyPotId;
+	type TreasuryAccount = TreasuryAccount;
+	type GovernanceOrigin = EnsureRoot<AccountId>;
+	type SecurityCouncilOrigin = EnsureSignedBy<SecurityCouncil, AccountId>;
+	type PayoutPeriod = PayoutPeriod;
+	type MaxPayoutPerPeriod = MaxPayoutPerPeriod;
+	type RefillThreshold = RefillThreshold;
+}
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		BugBounty: bug_bounty::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+pub type BugBountyModule = Pallet<Runtime>;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+pub struct ExtBuilder;
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		ExtBuilder
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		pallet_balances::GenesisConfig::<Runtime> {
+			balances: vec![
+				(ALICE, 1000),
+				(TREASURY, 100000),
+				(BugBountyModule::pot_account(), 2000),
+			],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
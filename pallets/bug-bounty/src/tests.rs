@@ -0,0 +1,84 @@
+#![cfg(test)]
+
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+use sp_runtime::traits::BadOrigin;
+
+use mock::{Event, *};
+
+use super::*;
+
+#[test]
+fn approve_payout_should_pay_reporter_within_period_cap() {
+	ExtBuilder::default().build().execute_with(|| {
+		let justification = H256::repeat_byte(1);
+		assert_ok!(BugBountyModule::set_bounty_tier(Origin::root(), 1, Some(1000)));
+		assert_noop!(
+			BugBountyModule::approve_payout(Origin::signed(ALICE), BOB, 1, justification),
+			BadOrigin
+		);
+		assert_noop!(
+			BugBountyModule::approve_payout(Origin::signed(SECURITY_COUNCIL), BOB, 2, justification),
+			Error::<Runtime>::BountyTierNotFound
+		);
+
+		assert_ok!(BugBountyModule::approve_payout(
+			Origin::signed(SECURITY_COUNCIL),
+			BOB,
+			1,
+			justification
+		));
+		assert_eq!(Balances::free_balance(BOB), 1000);
+		assert_eq!(
+			BugBountyModule::payouts(0).map(|payout| payout.justification),
+			Some(justification)
+		);
+		assert!(System::events().iter().any(|record| record.event
+			== Event::BugBounty(crate::Event::BountyPaid {
+				payout_id: 0,
+				reporter: BOB,
+				amount: 1000,
+				justification,
+			})));
+
+		// Second payout would exceed the cap of the period
+		assert_noop!(
+			BugBountyModule::approve_payout(Origin::signed(SECURITY_COUNCIL), BOB, 1, justification),
+			Error::<Runtime>::PeriodPayoutCapExceeded
+		);
+		System::set_block_number(100);
+		assert_ok!(BugBountyModule::approve_payout(
+			Origin::signed(SECURITY_COUNCIL),
+			ALICE,
+			1,
+			justification
+		));
+	});
+}
+
+#[test]
+fn low_pot_should_propose_refill_from_treasury() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			BugBountyModule::approve_pot_refill(Origin::root()),
+			Error::<Runtime>::NoPendingRefill
+		);
+		assert_ok!(BugBountyModule::set_pot_target(Origin::root(), 3000));
+		assert_eq!(BugBountyModule::pending_refill(), None);
+
+		assert_ok!(BugBountyModule::set_bounty_tier(Origin::root(), 1, Some(1000)));
+		assert_ok!(BugBountyModule::approve_payout(
+			Origin::signed(SECURITY_COUNCIL),
+			BOB,
+			1,
+			H256::repeat_byte(1)
+		));
+		// Pot holds 1000, below half of the 3000 target
+		assert_eq!(BugBountyModule::pending_refill(), Some(2000));
+
+		assert_ok!(BugBountyModule::approve_pot_refill(Origin::root()));
+		assert_eq!(Balances::free_balance(BugBountyModule::pot_account()), 3000);
+		assert_eq!(Balances::free_balance(TREASURY), 98000);
+		assert_eq!(BugBountyModule::pending_refill(), None);
+	});
+}
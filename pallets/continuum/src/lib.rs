@@ -39,6 +39,8 @@
 //!   spot
 //! - Simple majority negative voting applied - the bidder who has more than 51% vote nay will be
 //!   rejected
+//! - Neighbours can back their vote with locked NEER and a conviction multiplier, the lock is
+//!   released with `unlock_vote` once the conviction locking period after the vote round passed
 //! - The auction will start on pallet_auction.
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -47,20 +49,22 @@
 use codec::{Decode, Encode};
 #[cfg(feature = "std")]
 use frame_support::traits::GenesisBuild;
-use frame_support::traits::{Currency, LockableCurrency, ReservableCurrency};
-use frame_support::{dispatch::DispatchResult, ensure, traits::Get, PalletId};
+use frame_support::traits::{
+	Currency, GetStorageVersion, LockIdentifier, LockableCurrency, ReservableCurrency, WithdrawReasons,
+};
+use frame_support::{dispatch::DispatchResult, ensure, traits::Get, weights::Weight, PalletId};
 use frame_system::{ensure_root, ensure_signed};
 use scale_info::TypeInfo;
-use sp_runtime::traits::CheckedAdd;
+use sp_runtime::traits::{CheckedAdd, Saturating};
 use sp_runtime::{
-	traits::{AccountIdConversion, One, Zero},
+	traits::{AccountIdConversion, One, UniqueSaturatedInto, Zero},
 	DispatchError, RuntimeDebug,
 };
 use sp_std::vec;
 use sp_std::vec::Vec;
 
 use auction_manager::{Auction, AuctionType, CheckAuctionItemHandler, ListingLevel};
-use core_primitives::MetaverseTrait;
+use core_primitives::{MetaverseSunsetHandler, MetaverseTrait};
 pub use pallet::*;
 use primitives::{continuum::Continuum, ItemId, MetaverseId, SpotId};
 pub use types::*;
@@ -74,6 +78,8 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+const CONTINUUM_VOTE_LOCK_ID: LockIdentifier = *b"bcctmvot";
+
 #[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub enum ContinuumAuctionSlotStatus {
 	/// Accept participation
@@ -142,6 +148,17 @@ pub mod pallet {
 			+ LockableCurrency<Self::AccountId, Moment = Self::BlockNumber>;
 		/// Source of Metaverse Network Info
 		type MetaverseInfoSource: MetaverseTrait<Self::AccountId>;
+		/// Vote locking period, NEER behind a conviction vote stays locked for conviction lock
+		/// periods of this length after the vote round concludes
+		#[pallet::constant]
+		type VoteLockingPeriod: Get<Self::BlockNumber>;
+		/// Vote weight of a standard vote, counted as this much NEER locked at 1x conviction
+		#[pallet::constant]
+		type StandardVoteWeight: Get<BalanceOf<Self>>;
+		/// Maximum number of spots checked per block when releasing the spots of a metaverse in
+		/// sunset, leftovers are checked in the next blocks
+		#[pallet::constant]
+		type MaxSpotsReleasedPerBlock: Get<u32>;
 	}
 
 	#[pallet::genesis_config]
@@ -180,7 +197,10 @@ pub mod pallet {
 		}
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 
@@ -189,12 +209,23 @@ pub mod pallet {
 		/// Initialization
 		fn on_initialize(now: T::BlockNumber) -> Weight {
 			let auction_duration: T::BlockNumber = T::SessionDuration::get();
-			if !auction_duration.is_zero() && (now % auction_duration).is_zero() {
+			let rotation_weight = if !auction_duration.is_zero() && (now % auction_duration).is_zero() {
 				Self::rotate_auction_slots(now);
 				20_000_000
 			} else {
 				0
+			};
+			rotation_weight.saturating_add(Self::release_sunset_spots())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Self::on_chain_storage_version();
+			if on_chain_version < 1 {
+				let weight = Self::upgrade_referendum_tallies_v1();
+				STORAGE_VERSION.put::<Pallet<T>>();
+				return weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
 			}
+			T::DbWeight::get().reads(1)
 		}
 	}
 
@@ -239,7 +270,20 @@ pub mod pallet {
 	/// All votes of a particular voter
 	#[pallet::storage]
 	#[pallet::getter(fn get_voting_info)]
-	pub type VotingOf<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Voting<T::AccountId>>;
+	pub type VotingOf<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Voting<T::AccountId, BalanceOf<T>>>;
+
+	/// NEER locked by conviction votes of a voter per spot and the block it can be unlocked at
+	#[pallet::storage]
+	#[pallet::getter(fn get_vote_lock)]
+	pub type VoteLocks<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		SpotId,
+		(BalanceOf<T>, T::BlockNumber),
+		OptionQuery,
+	>;
 
 	/// Get max bound
 	#[pallet::storage]
@@ -260,6 +304,11 @@ pub mod pallet {
 	#[pallet::getter(fn next_spot_id)]
 	pub type NextContinuumSpotId<T: Config> = StorageValue<_, SpotId, ValueQuery>;
 
+	/// Metaverses in sunset with the next spot id to check for spots to release
+	#[pallet::storage]
+	#[pallet::getter(fn sunset_spot_cursor)]
+	pub(super) type SunsetSpotCursors<T: Config> = StorageMap<_, Twox64Concat, MetaverseId, SpotId, OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn allow_buy_now)]
 	pub type AllowBuyNow<T: Config> = StorageValue<_, bool, ValueQuery>;
@@ -289,6 +338,12 @@ pub mod pallet {
 		NewAuctionSlotRotated(T::BlockNumber),
 		/// Finalize vote
 		FinalizedVote(SpotId),
+		/// NEER locked behind a conviction vote [voter, spot_id, balance, unlock_at]
+		ConvictionVoteLocked(T::AccountId, SpotId, BalanceOf<T>, T::BlockNumber),
+		/// NEER lock of a conviction vote released [voter, spot_id]
+		ConvictionVoteUnlocked(T::AccountId, SpotId),
+		/// Spot of a decommissioned metaverse is available again [spot_id, metaverse_id]
+		ContinuumSpotReleased(SpotId, MetaverseId),
 	}
 
 	#[pallet::error]
@@ -327,6 +382,10 @@ pub mod pallet {
 		ContinuumBuyNowIsDisabled,
 		/// Continuum Spot is in auction
 		SpotIsInAuction,
+		/// No NEER is locked by a vote on this spot
+		VoteLockNotFound,
+		/// Conviction locking period of the vote has not passed yet
+		VoteLockNotExpired,
 	}
 
 	#[pallet::call]
@@ -466,12 +525,33 @@ pub mod pallet {
 			Ok(().into())
 		}
 		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		pub fn vote(origin: OriginFor<T>, id: SpotId, reject: AccountVote<T::AccountId>) -> DispatchResultWithPostInfo {
+		pub fn vote(
+			origin: OriginFor<T>,
+			id: SpotId,
+			reject: AccountVote<T::AccountId, BalanceOf<T>>,
+		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			Self::try_vote(&sender, id, reject)?;
 			Ok(().into())
 		}
 
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(2))]
+		/// Release NEER locked behind a conviction vote once its locking period passed
+		pub fn unlock_vote(origin: OriginFor<T>, spot_id: SpotId) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let (_, unlock_at) = VoteLocks::<T>::get(&sender, spot_id).ok_or(Error::<T>::VoteLockNotFound)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() >= unlock_at,
+				Error::<T>::VoteLockNotExpired
+			);
+
+			VoteLocks::<T>::remove(&sender, spot_id);
+			Self::update_vote_lock(&sender);
+
+			Self::deposit_event(Event::ConvictionVoteUnlocked(sender, spot_id));
+			Ok(().into())
+		}
+
 		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
 		pub fn emergency_shutdown(origin: OriginFor<T>, spot_id: SpotId) -> DispatchResultWithPostInfo {
 			// Only some origins can execute this function
@@ -547,12 +627,9 @@ impl<T: Config> Pallet<T> {
 					.collect();
 
 				for banned_account in banned_list {
-					let account_index = recent_slot
-						.participants
-						.iter()
-						.position(|x| *x == banned_account)
-						.unwrap();
-					recent_slot.participants.remove(account_index);
+					if let Some(account_index) = recent_slot.participants.iter().position(|x| *x == banned_account) {
+						recent_slot.participants.remove(account_index);
+					}
 					recent_slot.status = ContinuumAuctionSlotStatus::GNPConfirmed;
 				}
 				let treasury = Self::account_id();
@@ -644,8 +721,8 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	fn try_vote(who: &T::AccountId, spot_id: SpotId, vote: AccountVote<T::AccountId>) -> DispatchResult {
-		let status = Self::referendum_status(spot_id)?;
+	fn try_vote(who: &T::AccountId, spot_id: SpotId, vote: AccountVote<T::AccountId, BalanceOf<T>>) -> DispatchResult {
+		let mut status = Self::referendum_status(spot_id)?;
 
 		let spot = ContinuumSpots::<T>::get(spot_id);
 		let neighbors = spot.find_neighbour();
@@ -663,41 +740,59 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(is_neighbour, Error::<T>::NoPermission);
 
-		VotingOf::<T>::try_mutate(who, |maybe_voting| -> DispatchResult {
-			match maybe_voting {
-				Some(voting) => {
-					let ref mut votes = voting.votes;
-					match votes.binary_search_by_key(&spot_id, |i| i.0) {
-						// Already voted
-						Ok(_i) => {}
-						Err(i) => {
-							// Haven't vote for this spot id
-							// Add votes under user
-							let new_vote: AccountVote<T::AccountId> = vote.clone();
-							let who = new_vote.vote_who();
-							votes.insert(i, (spot_id, vote.clone()));
-
-							// Find existing tally of bidder
-							for mut tally in status.tallies {
-								// Existing vote
-								if tally.who == who.who {
-									tally.add(vote.clone()).ok_or(Error::<T>::TallyOverflow)?
-								}
-							}
-						}
-					}
-				}
-				None => {
-					// No voting exists
-					let mut new_vote: Vec<(SpotId, AccountVote<T::AccountId>)> = Vec::new();
-					new_vote.push((spot_id, vote.clone()));
-					let vote_o = Voting { votes: new_vote };
-					VotingOf::<T>::insert(who.clone(), vote_o);
+		if let AccountVote::Locked { balance, .. } = vote.clone() {
+			ensure!(
+				!balance.is_zero() && T::Currency::free_balance(who) >= balance,
+				Error::<T>::InsufficientFund
+			);
+		}
+
+		let is_new_vote = VotingOf::<T>::try_mutate(who, |maybe_voting| -> Result<bool, DispatchError> {
+			let voting = maybe_voting.get_or_insert_with(|| Voting { votes: Vec::new() });
+			match voting.votes.binary_search_by_key(&spot_id, |i| i.0) {
+				// Already voted
+				Ok(_i) => Ok(false),
+				// Haven't vote for this spot id - add votes under user
+				Err(i) => {
+					voting.votes.insert(i, (spot_id, vote.clone()));
+					Ok(true)
 				}
 			}
+		})?;
 
-			Ok(())
-		})
+		if !is_new_vote {
+			return Ok(());
+		}
+
+		// Add the vote to the tally of the bidder
+		let bidder = vote.clone().vote_who();
+		match status.tallies.iter().position(|tally| tally.who == bidder.who) {
+			Some(index) => status.tallies[index]
+				.add(vote.clone(), Self::standard_vote_weight())
+				.ok_or(Error::<T>::TallyOverflow)?,
+			None => {
+				let mut tally = ContinuumSpotTally::new(bidder);
+				tally
+					.add(vote.clone(), Self::standard_vote_weight())
+					.ok_or(Error::<T>::TallyOverflow)?;
+				status.tallies.push(tally);
+			}
+		}
+		let end = status.end;
+		ReferendumInfoOf::<T>::insert(spot_id, ReferendumInfo::Ongoing(status));
+
+		if let AccountVote::Locked {
+			conviction, balance, ..
+		} = vote
+		{
+			let unlock_at =
+				end.saturating_add(T::VoteLockingPeriod::get().saturating_mul(conviction.lock_periods().into()));
+			VoteLocks::<T>::insert(who, spot_id, (balance, unlock_at));
+			Self::update_vote_lock(who);
+			Self::deposit_event(Event::ConvictionVoteLocked(who.clone(), spot_id, balance, unlock_at));
+		}
+
+		Ok(())
 	}
 
 	fn referendum_status(spot_id: SpotId) -> Result<ReferendumStatus<T::AccountId, T::BlockNumber>, DispatchError> {
@@ -723,13 +818,92 @@ impl<T: Config> Pallet<T> {
 		Self::transfer_spot(spot_id, from, to)
 	}
 
+	fn standard_vote_weight() -> u128 {
+		T::StandardVoteWeight::get().unique_saturated_into()
+	}
+
+	/// Migrate referendum tallies counting votes in u8 to the weighted tallies, every vote
+	/// counted before the upgrade is a standard vote
+	pub fn upgrade_referendum_tallies_v1() -> Weight {
+		let standard_weight = Self::standard_vote_weight();
+		let mut num_referenda = 0u64;
+
+		ReferendumInfoOf::<T>::translate(|_k, info: ReferendumInfoV0<T::AccountId, T::BlockNumber>| {
+			num_referenda += 1;
+			let info = match info {
+				ReferendumInfoV0::Ongoing(status) => ReferendumInfo::Ongoing(ReferendumStatus {
+					end: status.end,
+					spot_id: status.spot_id,
+					tallies: status
+						.tallies
+						.into_iter()
+						.map(|tally| ContinuumSpotTally {
+							nays: (tally.nays as u128).saturating_mul(standard_weight),
+							who: tally.who,
+							turnout: (tally.turnout as u128).saturating_mul(standard_weight),
+						})
+						.collect(),
+				}),
+				ReferendumInfoV0::Finished { approved, end } => ReferendumInfo::Finished { approved, end },
+			};
+			Some(info)
+		});
+
+		T::DbWeight::get().reads_writes(num_referenda, num_referenda)
+	}
+
+	/// Release the spots of a metaverse in sunset back to the continuum, checking at most
+	/// MaxSpotsReleasedPerBlock spots per block. Spots left over are checked in the next blocks.
+	fn release_sunset_spots() -> Weight {
+		let (metaverse_id, start) = match SunsetSpotCursors::<T>::iter().next() {
+			Some(cursor) => cursor,
+			None => return T::DbWeight::get().reads(1),
+		};
+		let next_spot_id = NextContinuumSpotId::<T>::get();
+		let end = start
+			.saturating_add(T::MaxSpotsReleasedPerBlock::get() as SpotId)
+			.min(next_spot_id);
+
+		let mut released_spots: Weight = 0;
+		for spot_id in start..end {
+			let mut spot = ContinuumSpots::<T>::get(spot_id);
+			if spot.metaverse_id == metaverse_id {
+				spot.metaverse_id = 0;
+				ContinuumSpots::<T>::insert(spot_id, spot);
+				released_spots = released_spots.saturating_add(1);
+				Self::deposit_event(Event::<T>::ContinuumSpotReleased(spot_id, metaverse_id));
+			}
+		}
+		if end < next_spot_id {
+			SunsetSpotCursors::<T>::insert(metaverse_id, end);
+		} else {
+			SunsetSpotCursors::<T>::remove(metaverse_id);
+		}
+
+		let checked_spots = end.saturating_sub(start) as Weight;
+		T::DbWeight::get().reads_writes(checked_spots.saturating_add(2), released_spots.saturating_add(1))
+	}
+
 	fn check_approved(tally: &ContinuumSpotTally<T::AccountId>) -> bool {
-		let nay_ratio = tally.turnout.checked_div(tally.nays).unwrap_or(0);
-		let nay_percent = nay_ratio.checked_mul(100).unwrap_or(0);
+		let nay_percent = tally.nays.saturating_mul(100).checked_div(tally.turnout).unwrap_or(0);
 
 		nay_percent > 51
 	}
 
+	/// Lock the largest NEER amount behind the remaining conviction votes of the voter
+	fn update_vote_lock(who: &T::AccountId) {
+		let locked = VoteLocks::<T>::iter_prefix_values(who)
+			.map(|(balance, _)| balance)
+			.max()
+			.unwrap_or_else(Zero::zero);
+
+		if locked.is_zero() {
+			T::Currency::remove_lock(CONTINUUM_VOTE_LOCK_ID, who);
+		} else {
+			T::Currency::set_lock(CONTINUUM_VOTE_LOCK_ID, who, locked, WithdrawReasons::all());
+		}
+	}
+
 	fn check_spot_ownership(spot_id: Option<SpotId>, coordinate: (i32, i32)) -> Result<SpotId, DispatchError> {
 		match spot_id {
 			None => {
@@ -766,6 +940,14 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> MetaverseSunsetHandler for Pallet<T> {
+	fn on_metaverse_sunset(metaverse_id: MetaverseId) {
+		// Spots of the metaverse go back to the continuum to be bought or auctioned again, in
+		// bounded batches from the next block
+		SunsetSpotCursors::<T>::insert(metaverse_id, SpotId::zero());
+	}
+}
+
 impl<T: Config> Continuum<T::AccountId> for Pallet<T> {
 	fn transfer_spot(
 		spot_id: SpotId,
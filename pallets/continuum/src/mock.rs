@@ -177,6 +177,9 @@ parameter_types! {
 	pub const SessionDuration: BlockNumber = 10;
 	// Default 43200 Blocks
 	pub const SpotAuctionChillingDuration: BlockNumber = 10;
+	pub const VoteLockingPeriod: BlockNumber = 5;
+	pub const StandardVoteWeight: Balance = 100;
+	pub const MaxSpotsReleasedPerBlock: u32 = 2;
 }
 
 pub struct MetaverseInfoSource {}
@@ -210,6 +213,10 @@ impl MetaverseTrait<AccountId> for MetaverseInfoSource {
 	fn get_metaverse_estate_class(metaverse_id: MetaverseId) -> ClassId {
 		16u32
 	}
+
+	fn get_metaverse_treasury(metaverse_id: MetaverseId) -> AccountId {
+		100
+	}
 }
 
 impl Config for Runtime {
@@ -222,6 +229,9 @@ impl Config for Runtime {
 	type ContinuumTreasury = ContinuumTreasuryPalletId;
 	type Currency = Balances;
 	type MetaverseInfoSource = MetaverseInfoSource;
+	type VoteLockingPeriod = VoteLockingPeriod;
+	type StandardVoteWeight = StandardVoteWeight;
+	type MaxSpotsReleasedPerBlock = MaxSpotsReleasedPerBlock;
 }
 
 pub type ContinuumModule = Pallet<Runtime>;
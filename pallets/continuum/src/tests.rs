@@ -18,7 +18,7 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
 use mock::BlockNumber as MBlockNumber;
 use mock::{Event, *};
 
@@ -374,3 +374,162 @@ fn buy_now_continuum_should_fail_if_buy_now_setting_is_disabled() {
 		);
 	})
 }
+
+#[test]
+fn conviction_vote_should_weight_tally_and_lock_until_period_passed() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			(0, 0)
+		));
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(BOB),
+			BOB_METAVERSE_ID,
+			(0, 0)
+		));
+		assert_ok!(ContinuumModule::set_allow_buy_now(Origin::root(), true));
+		assert_ok!(ContinuumModule::buy_continuum_spot(
+			Origin::signed(CHARLIE),
+			(-1, 1),
+			CHARLIE_METAVERSE_ID
+		));
+		// Referendum of spot 0 ends at block 30
+		run_to_block(20);
+
+		let locked_vote = AccountVote::Locked {
+			vote: Vote { nay: true, who: ALICE },
+			conviction: Conviction::Locked2x,
+			balance: 1000,
+		};
+		assert_noop!(
+			ContinuumModule::vote(
+				Origin::signed(CHARLIE),
+				0,
+				AccountVote::Locked {
+					vote: Vote { nay: true, who: ALICE },
+					conviction: Conviction::Locked2x,
+					balance: 1_000_000,
+				}
+			),
+			Error::<Runtime>::InsufficientFund
+		);
+		assert_ok!(ContinuumModule::vote(Origin::signed(CHARLIE), 0, locked_vote));
+		assert_eq!(
+			last_event(),
+			Event::Continuum(crate::Event::ConvictionVoteLocked(CHARLIE, 0, 1000, 40))
+		);
+
+		let tally = ContinuumModule::referendum_status(0).unwrap().tallies[0].clone();
+		assert_eq!(tally.who, ALICE);
+		assert_eq!(tally.nays, 2000);
+		assert_eq!(tally.turnout, 2000);
+		assert_eq!(Balances::locks(CHARLIE)[0].amount, 1000);
+
+		assert_noop!(
+			ContinuumModule::unlock_vote(Origin::signed(CHARLIE), 0),
+			Error::<Runtime>::VoteLockNotExpired
+		);
+		run_to_block(40);
+		assert_ok!(ContinuumModule::unlock_vote(Origin::signed(CHARLIE), 0));
+		assert!(Balances::locks(CHARLIE).is_empty());
+		assert_eq!(ContinuumModule::get_vote_lock(CHARLIE, 0), None);
+	})
+}
+
+#[test]
+fn standard_vote_should_weigh_standard_vote_weight() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			(0, 0)
+		));
+		assert_ok!(ContinuumModule::register_interest(
+			Origin::signed(BOB),
+			BOB_METAVERSE_ID,
+			(0, 0)
+		));
+		assert_ok!(ContinuumModule::set_allow_buy_now(Origin::root(), true));
+		assert_ok!(ContinuumModule::buy_continuum_spot(
+			Origin::signed(CHARLIE),
+			(-1, 1),
+			CHARLIE_METAVERSE_ID
+		));
+		run_to_block(20);
+
+		assert_ok!(ContinuumModule::vote(
+			Origin::signed(CHARLIE),
+			0,
+			AccountVote::Standard {
+				vote: Vote { nay: true, who: ALICE },
+			}
+		));
+
+		let tally = ContinuumModule::referendum_status(0).unwrap().tallies[0].clone();
+		assert_eq!(tally.nays, StandardVoteWeight::get() as u128);
+		assert_eq!(tally.turnout, StandardVoteWeight::get() as u128);
+		assert!(Balances::locks(CHARLIE).is_empty());
+	})
+}
+
+#[test]
+fn referendum_tallies_upgrade_should_weigh_counted_votes() {
+	ExtBuilder::default().build().execute_with(|| {
+		let legacy: ReferendumInfoV0<AccountId, MBlockNumber> = ReferendumInfoV0::Ongoing(ReferendumStatusV0 {
+			end: 30,
+			spot_id: 0,
+			tallies: vec![ContinuumSpotTallyV0 {
+				nays: 2,
+				who: ALICE,
+				turnout: 3,
+			}],
+		});
+		frame_support::storage::unhashed::put(&ReferendumInfoOf::<Runtime>::hashed_key_for(0), &legacy);
+
+		ContinuumModule::upgrade_referendum_tallies_v1();
+
+		let tally = ContinuumModule::referendum_status(0).unwrap().tallies[0].clone();
+		assert_eq!(tally.who, ALICE);
+		assert_eq!(tally.nays, 2 * StandardVoteWeight::get() as u128);
+		assert_eq!(tally.turnout, 3 * StandardVoteWeight::get() as u128);
+	})
+}
+
+#[test]
+fn metaverse_sunset_should_release_spots_in_batches() {
+	ExtBuilder::default().build().execute_with(|| {
+		for (spot_id, metaverse_id) in [ALICE_METAVERSE_ID, BOB_METAVERSE_ID, ALICE_METAVERSE_ID]
+			.iter()
+			.enumerate()
+		{
+			ContinuumSpots::<Runtime>::insert(
+				spot_id as SpotId,
+				ContinuumSpot {
+					x: spot_id as i32,
+					y: 0,
+					metaverse_id: *metaverse_id,
+				},
+			);
+		}
+		NextContinuumSpotId::<Runtime>::put(3);
+
+		ContinuumModule::on_metaverse_sunset(ALICE_METAVERSE_ID);
+		assert_eq!(ContinuumModule::sunset_spot_cursor(ALICE_METAVERSE_ID), Some(0));
+
+		// At most MaxSpotsReleasedPerBlock spots are checked per block
+		ContinuumModule::on_initialize(1);
+		assert_eq!(ContinuumModule::get_continuum_spot(0).metaverse_id, 0);
+		assert_eq!(ContinuumModule::get_continuum_spot(1).metaverse_id, BOB_METAVERSE_ID);
+		assert_eq!(ContinuumModule::get_continuum_spot(2).metaverse_id, ALICE_METAVERSE_ID);
+		assert_eq!(ContinuumModule::sunset_spot_cursor(ALICE_METAVERSE_ID), Some(2));
+
+		ContinuumModule::on_initialize(2);
+		assert_eq!(ContinuumModule::get_continuum_spot(2).metaverse_id, 0);
+		assert_eq!(ContinuumModule::sunset_spot_cursor(ALICE_METAVERSE_ID), None);
+		assert_eq!(
+			last_event(),
+			Event::Continuum(crate::Event::ContinuumSpotReleased(2, ALICE_METAVERSE_ID))
+		);
+	})
+}
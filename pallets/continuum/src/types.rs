@@ -21,7 +21,7 @@ use crate::{AccountVote, Vote};
 use codec::{Decode, Encode};
 use primitives::{MetaverseId, SpotId};
 use scale_info::TypeInfo;
-use sp_runtime::traits::{One, Zero};
+use sp_runtime::traits::{UniqueSaturatedInto, Zero};
 use sp_runtime::{DispatchError, RuntimeDebug};
 use sp_std::vec;
 use sp_std::vec::Vec;
@@ -60,11 +60,11 @@ impl ContinuumSpot {
 /// Info regarding an ongoing referendum.
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub struct ContinuumSpotTally<AccountId> {
-	/// The number of nay votes, expressed in terms of post-conviction lock-vote.
-	pub(crate) nays: u8,
+	/// The weight of nay votes, expressed in terms of post-conviction lock-vote.
+	pub(crate) nays: u128,
 	pub(crate) who: AccountId,
-	/// The amount of funds currently expressing its opinion. Pre-conviction.
-	pub(crate) turnout: u8,
+	/// The weight of all votes currently expressing their opinion. Post-conviction.
+	pub(crate) turnout: u128,
 }
 
 impl<AccountId> ContinuumSpotTally<AccountId> {
@@ -77,22 +77,27 @@ impl<AccountId> ContinuumSpotTally<AccountId> {
 		}
 	}
 
-	/// Add an account's vote into the tally.
-	pub fn add(&mut self, vote: AccountVote<AccountId>) -> Option<()> {
-		match vote {
-			AccountVote::Standard { vote } => {
-				self.turnout = self.turnout.checked_add(One::one())?;
-				self.nays = self.nays.checked_add(One::one())?;
-				self.who = vote.who;
-			}
+	/// Add an account's vote into the tally, weighted by its conviction. Standard votes weigh
+	/// `standard_weight`.
+	pub fn add<Balance: Copy + UniqueSaturatedInto<u128>>(
+		&mut self,
+		vote: AccountVote<AccountId, Balance>,
+		standard_weight: u128,
+	) -> Option<()> {
+		let weight = vote.weight(standard_weight);
+		let vote = vote.vote_who();
+		self.turnout = self.turnout.checked_add(weight)?;
+		if vote.nay {
+			self.nays = self.nays.checked_add(weight)?;
 		}
+		self.who = vote.who;
 		Some(())
 	}
 
 	/// Remove an account's vote from the tally.
-	pub fn remove(&mut self, vote: AccountVote<AccountId>) -> Option<()> {
+	pub fn remove<Balance>(&mut self, vote: AccountVote<AccountId, Balance>) -> Option<()> {
 		match vote {
-			AccountVote::Standard { vote: _ } => {
+			AccountVote::Standard { vote: _ } | AccountVote::Locked { vote: _, .. } => {
 				self.turnout = self.turnout.checked_add(Zero::zero())?;
 				self.nays = self.nays.checked_add(Zero::zero())?;
 			}
@@ -160,6 +165,29 @@ impl<AccountId, BlockNumber: Default> ReferendumInfo<AccountId, BlockNumber> {
 	}
 }
 
+/// Tally of a spot referendum before votes were weighted, counting every vote as one nay
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ContinuumSpotTallyV0<AccountId> {
+	pub(crate) nays: u8,
+	pub(crate) who: AccountId,
+	pub(crate) turnout: u8,
+}
+
+/// Status of an ongoing referendum before votes were weighted
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ReferendumStatusV0<AccountId, BlockNumber> {
+	pub(crate) end: BlockNumber,
+	pub(crate) spot_id: SpotId,
+	pub(crate) tallies: Vec<ContinuumSpotTallyV0<AccountId>>,
+}
+
+/// Info of a referendum before votes were weighted
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum ReferendumInfoV0<AccountId, BlockNumber> {
+	Ongoing(ReferendumStatusV0<AccountId, BlockNumber>),
+	Finished { approved: bool, end: BlockNumber },
+}
+
 /// Whether an `unvote` operation is able to make actions that are not strictly always in the
 /// interest of an account.
 pub enum UnvoteScope {
@@ -1,8 +1,10 @@
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
+use sp_runtime::traits::UniqueSaturatedInto;
 use sp_runtime::RuntimeDebug;
 use sp_std::prelude::*;
 
+pub use pallet_governance::Conviction;
 use primitives::SpotId;
 
 /// Struct of every Continuum vote
@@ -14,21 +16,39 @@ pub struct Vote<AccountId> {
 
 /// Keep track of voting activities of an account
 #[derive(Encode, Decode, Clone, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
-pub struct Voting<AccountId> {
-	pub votes: Vec<(SpotId, AccountVote<AccountId>)>,
+pub struct Voting<AccountId, Balance> {
+	pub votes: Vec<(SpotId, AccountVote<AccountId, Balance>)>,
 }
 
 /// A vote for a referendum of a particular account.
 #[derive(Encode, Decode, Copy, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
-pub enum AccountVote<AccountId> {
+pub enum AccountVote<AccountId, Balance> {
 	/// A standard continuum vote
 	Standard { vote: Vote<AccountId> },
+	/// A continuum vote backed by NEER locked with conviction
+	Locked {
+		vote: Vote<AccountId>,
+		conviction: Conviction,
+		balance: Balance,
+	},
 }
 
-impl<AccountId> AccountVote<AccountId> {
+impl<AccountId, Balance: Copy + UniqueSaturatedInto<u128>> AccountVote<AccountId, Balance> {
 	pub fn vote_who(self) -> Vote<AccountId> {
 		match self {
 			AccountVote::Standard { vote } => vote,
+			AccountVote::Locked { vote, .. } => vote,
+		}
+	}
+
+	/// Weight of the vote in the tally, a standard vote counts `standard_weight` and a locked
+	/// vote the conviction votes of its locked NEER
+	pub fn weight(&self, standard_weight: u128) -> u128 {
+		match *self {
+			AccountVote::Standard { .. } => standard_weight,
+			AccountVote::Locked {
+				conviction, balance, ..
+			} => conviction.votes(balance.unique_saturated_into()).votes,
 		}
 	}
 }
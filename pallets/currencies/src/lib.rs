@@ -24,7 +24,7 @@
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
 
-use codec::Codec;
+use codec::{Codec, Decode, Encode};
 use frame_support::traits::{
 	Currency as PalletCurrency, ExistenceRequirement, Get, LockableCurrency as PalletLockableCurrency,
 	ReservableCurrency as PalletReservableCurrency, WithdrawReasons,
@@ -34,13 +34,14 @@ use frame_system::ensure_signed;
 use frame_system::pallet_prelude::*;
 use orml_traits::{
 	arithmetic::{Signed, SimpleArithmetic},
-	currency::TransferAll,
+	currency::{OnDust, TransferAll},
 	BalanceStatus, BasicCurrency, BasicCurrencyExtended, BasicLockableCurrency, BasicReservableCurrency,
 	LockIdentifier, MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency, MultiReservableCurrency,
 };
+use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{CheckedSub, MaybeSerializeDeserialize, StaticLookup, Zero},
-	DispatchError, DispatchResult,
+	DispatchError, DispatchResult, RuntimeDebug,
 };
 use sp_std::{
 	convert::{TryFrom, TryInto},
@@ -59,6 +60,17 @@ type CurrencyIdOf<T> =
 type AmountOf<T> =
 	<<T as Config>::MultiSocialCurrency as MultiCurrencyExtended<<T as frame_system::Config>::AccountId>>::Amount;
 
+/// How dust removed from an account of a given currency is handled
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum DustPolicy<AccountId> {
+	/// Dust is burned and leaves the total issuance
+	Burn,
+	/// Dust is moved to the treasury account
+	Treasury,
+	/// Dust is moved to a given account, e.g. the asset provider
+	Account(AccountId),
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -80,8 +92,18 @@ pub mod pallet {
 		#[pallet::constant]
 		/// The native currency id
 		type GetNativeCurrencyId: Get<FungibleTokenId>;
+		/// Account receiving dust of currencies without a dust policy
+		type TreasuryAccount: Get<Self::AccountId>;
+		/// Origin allowed to update dust policies
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
 	}
 
+	/// Dust policy of each currency, currencies without one send dust to the treasury
+	#[pallet::storage]
+	#[pallet::getter(fn dust_policy)]
+	pub type DustPolicies<T: Config> =
+		StorageMap<_, Twox64Concat, FungibleTokenId, DustPolicy<T::AccountId>, OptionQuery>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Transfer amount should be non-zero
@@ -153,6 +175,22 @@ pub mod pallet {
 			<Self as MultiCurrencyExtended<T::AccountId>>::update_balance(currency_id, &dest, amount)?;
 			Ok(().into())
 		}
+
+		/// Set how dust of `currency_id` is handled, `None` restores the treasury default.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		#[pallet::weight(10_000)]
+		pub fn set_dust_policy(
+			origin: OriginFor<T>,
+			currency_id: FungibleTokenId,
+			policy: Option<DustPolicy<T::AccountId>>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			DustPolicies::<T>::mutate_exists(currency_id, |maybe_policy| *maybe_policy = policy.clone());
+
+			Self::deposit_event(Event::DustPolicyUpdated(currency_id, policy));
+			Ok(().into())
+		}
 	}
 
 	#[pallet::event]
@@ -166,6 +204,12 @@ pub mod pallet {
 		Deposited(CurrencyIdOf<T>, T::AccountId, BalanceOf<T>),
 		/// Withdraw success. [currency_id, who, amount]
 		Withdrawn(CurrencyIdOf<T>, T::AccountId, BalanceOf<T>),
+		/// Dust policy updated. [currency_id, policy]
+		DustPolicyUpdated(FungibleTokenId, Option<DustPolicy<T::AccountId>>),
+		/// Dust burned. [currency_id, who, amount]
+		DustBurned(FungibleTokenId, T::AccountId, BalanceOf<T>),
+		/// Dust transferred. [currency_id, from, to, amount]
+		DustTransferred(FungibleTokenId, T::AccountId, T::AccountId, BalanceOf<T>),
 	}
 
 	#[pallet::hooks]
@@ -376,6 +420,29 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 	}
 }
 
+/// `OnDust` handler for `orml_tokens` applying the dust policy of each currency.
+pub struct DustHandler<T>(marker::PhantomData<T>);
+
+impl<T: Config> OnDust<T::AccountId, FungibleTokenId, BalanceOf<T>> for DustHandler<T> {
+	fn on_dust(who: &T::AccountId, currency_id: FungibleTokenId, amount: BalanceOf<T>) {
+		let receiver = match Pallet::<T>::dust_policy(currency_id) {
+			Some(DustPolicy::Burn) => {
+				if T::MultiSocialCurrency::withdraw(currency_id, who, amount).is_ok() {
+					Pallet::<T>::deposit_event(Event::DustBurned(currency_id, who.clone(), amount));
+				}
+				return;
+			}
+			Some(DustPolicy::Account(account)) => account,
+			Some(DustPolicy::Treasury) | None => T::TreasuryAccount::get(),
+		};
+
+		// Dust removal can not fail, dust stays with the account if the transfer does
+		if T::MultiSocialCurrency::transfer(currency_id, who, &receiver, amount).is_ok() {
+			Pallet::<T>::deposit_event(Event::DustTransferred(currency_id, who.clone(), receiver, amount));
+		}
+	}
+}
+
 pub struct Currency<T, GetCurrencyId>(marker::PhantomData<T>, marker::PhantomData<GetCurrencyId>);
 
 impl<T, GetCurrencyId> BasicCurrency<T::AccountId> for Currency<T, GetCurrencyId>
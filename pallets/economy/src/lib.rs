@@ -30,13 +30,14 @@ use orml_traits::{DataFeeder, DataProvider, MultiCurrency, MultiReservableCurren
 use sp_runtime::traits::{BlockNumberProvider, CheckedAdd, CheckedMul, Saturating};
 use sp_runtime::{
 	traits::{AccountIdConversion, One, Zero},
-	ArithmeticError, DispatchError, Perbill,
+	ArithmeticError, DispatchError, Perbill, SaturatedConversion,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::*, vec::Vec};
 
 use core_primitives::NFTTrait;
 use core_primitives::*;
 pub use pallet::*;
+use primitives::staking::MetaverseStakingTrait;
 use primitives::{
 	AssetId, Balance, ClassId, DomainId, ElementId, FungibleTokenId, MetaverseId, NftId, PowerAmount, RoundIndex,
 };
@@ -75,6 +76,28 @@ pub struct OrderInfo<BlockNumber> {
 	commission_fee: Balance,
 }
 
+/// Staking reward smoothing parameters, APY band is relative to total metaverse staked amount
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct RewardSmoothingInfo {
+	/// Share of round staking rewards diverted into the smoothing reserve
+	pub reserve_ratio: Perbill,
+	/// Lower bound of target APY, reserve tops up rewards falling below it
+	pub min_apy: Perbill,
+	/// Upper bound of target APY, rewards above it are diverted into the reserve
+	pub max_apy: Perbill,
+}
+
+/// NFT staked to generate power, the power rate of its class is fixed at staking time
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct NftStakingInfo<AccountId, BlockNumber> {
+	/// Account receiving the generated power
+	pub staker: AccountId,
+	/// Power generated per block
+	pub power_rate: PowerAmount,
+	/// Block from which power is generated
+	pub since: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use orml_traits::MultiCurrencyExtended;
@@ -119,8 +142,16 @@ pub mod pallet {
 		type MinimumStake: Get<BalanceOf<Self>>;
 		#[pallet::constant]
 		type PowerAmountPerBlock: Get<PowerAmount>;
+		/// Metaverse staking which receives smoothed staking rewards
+		type MetaverseStakingHandler: MetaverseStakingTrait<Balance>;
+		/// Number of blocks per year, divided by the current round length to convert APY band into
+		/// round rewards
+		#[pallet::constant]
+		type BlocksPerYear: Get<u32>;
 		/// Weight info
 		type WeightInfo: WeightInfo;
+		/// Records mining resource burned when buying power
+		type MiningBurnRecorder: MiningBurnRecorder;
 	}
 
 	#[pallet::storage]
@@ -189,6 +220,32 @@ pub mod pallet {
 	/// Total native token locked in this pallet
 	type TotalStake<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn reward_smoothing_config)]
+	/// Reward smoothing band parameters set by governance
+	pub type RewardSmoothing<T: Config> = StorageValue<_, RewardSmoothingInfo, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn smoothing_reserve)]
+	/// Staking rewards buffered for rounds falling short of target APY band
+	pub type SmoothingReserve<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn round_reward_smoothing)]
+	/// Diverted and topped up staking reward per round
+	pub type RoundRewardSmoothing<T: Config> = StorageMap<_, Twox64Concat, RoundIndex, (Balance, Balance), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_nft_power_rate)]
+	/// Power generated per block by staking an NFT of the class
+	pub type NftPowerRates<T: Config> = StorageMap<_, Twox64Concat, ClassId, PowerAmount, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_staked_nft)]
+	/// NFTs staked for power generation
+	pub type StakedNfts<T: Config> =
+		StorageMap<_, Twox64Concat, (ClassId, TokenId), NftStakingInfo<T::AccountId, T::BlockNumber>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -208,6 +265,14 @@ pub mod pallet {
 		SetPowerBalance(T::AccountId, PowerAmount),
 		CommissionUpdated((ClassId, TokenId), Perbill),
 		CancelPowerConversionRequest((ClassId, TokenId), T::AccountId),
+		RewardSmoothingConfigUpdated(Option<RewardSmoothingInfo>),
+		/// Round, Mining Allocation, Paid Reward, Reserve
+		StakingRewardSmoothed(RoundIndex, Balance, Balance, Balance),
+		/// Class, Power per block, or removed if None
+		NftPowerRateUpdated(ClassId, Option<PowerAmount>),
+		NftStakedForPower(T::AccountId, (ClassId, TokenId)),
+		/// Staker, NFT, Generated power
+		NftUnstakedFromPower(T::AccountId, (ClassId, TokenId), PowerAmount),
 	}
 
 	#[pallet::error]
@@ -276,6 +341,16 @@ pub mod pallet {
 		NotReadyToExecute,
 		// Order needs to reach target before cancelling
 		OrderIsNotReadyForCancel,
+		// Minimum APY is greater than maximum APY
+		InvalidApyBand,
+		// NFT class does not generate power when staked
+		NftClassNotPowerGenerating,
+		// NFT is already staked
+		NftAlreadyStaked,
+		// NFT is not staked
+		NftNotStaked,
+		// NFT is locked, listed or not transferable
+		NftNotStakeable,
 	}
 
 	#[pallet::call]
@@ -833,7 +908,11 @@ pub mod pallet {
 			);
 
 			// Burn BIT
-			T::FungibleTokenCurrency::withdraw(T::MiningCurrencyId::get(), &generator_nft_account_id, bit_amount);
+			if T::FungibleTokenCurrency::withdraw(T::MiningCurrencyId::get(), &generator_nft_account_id, bit_amount)
+				.is_ok()
+			{
+				T::MiningBurnRecorder::record_mining_burn(bit_amount);
+			}
 
 			// Update Power Balance
 			Self::distribute_power_by_network(power_amount.into(), &generator_nft_account_id);
@@ -979,6 +1058,107 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Set or remove staking reward smoothing band
+		#[pallet::weight(T::WeightInfo::set_reward_smoothing_config())]
+		pub fn set_reward_smoothing_config(
+			origin: OriginFor<T>,
+			config: Option<RewardSmoothingInfo>,
+		) -> DispatchResultWithPostInfo {
+			// Only root can update
+			ensure_root(origin)?;
+
+			match config.clone() {
+				Some(config) => {
+					ensure!(config.min_apy <= config.max_apy, Error::<T>::InvalidApyBand);
+					RewardSmoothing::<T>::put(config);
+				}
+				None => RewardSmoothing::<T>::kill(),
+			}
+
+			Self::deposit_event(Event::<T>::RewardSmoothingConfigUpdated(config));
+
+			Ok(().into())
+		}
+
+		/// Set or remove power generated per block by staking NFTs of the class. NFTs already
+		/// staked keep the rate they were staked at.
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		pub fn set_nft_power_rate(
+			origin: OriginFor<T>,
+			class_id: ClassId,
+			power_rate: Option<PowerAmount>,
+		) -> DispatchResultWithPostInfo {
+			// Only root can update
+			ensure_root(origin)?;
+
+			match power_rate {
+				Some(power_rate) => {
+					ensure!(!power_rate.is_zero(), Error::<T>::PowerAmountIsZero);
+					NftPowerRates::<T>::insert(class_id, power_rate);
+				}
+				None => NftPowerRates::<T>::remove(class_id),
+			}
+
+			Self::deposit_event(Event::<T>::NftPowerRateUpdated(class_id, power_rate));
+
+			Ok(().into())
+		}
+
+		/// Stake NFT of a power generating class, the NFT is locked until unstaked
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(2))]
+		#[transactional]
+		pub fn stake_nft(origin: OriginFor<T>, nft_id: (ClassId, TokenId)) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let power_rate = NftPowerRates::<T>::get(nft_id.0).ok_or(Error::<T>::NftClassNotPowerGenerating)?;
+			ensure!(!StakedNfts::<T>::contains_key(nft_id), Error::<T>::NftAlreadyStaked);
+
+			// Check if origin is the owner of the NFT
+			ensure!(
+				T::NFTHandler::check_nft_ownership(&who, &nft_id)?,
+				Error::<T>::NoPermission
+			);
+			ensure!(
+				T::NFTHandler::is_transferable(&nft_id)? && !T::NFTHandler::check_item_on_listing(nft_id.0, nft_id.1)?,
+				Error::<T>::NftNotStakeable
+			);
+
+			T::NFTHandler::set_lock_nft(&nft_id, true)?;
+			StakedNfts::<T>::insert(
+				nft_id,
+				NftStakingInfo {
+					staker: who.clone(),
+					power_rate,
+					since: <frame_system::Pallet<T>>::block_number(),
+				},
+			);
+
+			Self::deposit_event(Event::<T>::NftStakedForPower(who, nft_id));
+
+			Ok(().into())
+		}
+
+		/// Unstake NFT, unlocking it and adding the power generated while staked to the staker
+		/// power balance
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(3))]
+		#[transactional]
+		pub fn unstake_nft(origin: OriginFor<T>, nft_id: (ClassId, TokenId)) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let staking_info = StakedNfts::<T>::get(nft_id).ok_or(Error::<T>::NftNotStaked)?;
+			ensure!(staking_info.staker == who, Error::<T>::NoPermission);
+
+			let generated_power = Self::nft_staking_power(&staking_info);
+			PowerBalance::<T>::mutate(&who, |balance| *balance = balance.saturating_add(generated_power));
+
+			T::NFTHandler::set_lock_nft(&nft_id, false)?;
+			StakedNfts::<T>::remove(nft_id);
+
+			Self::deposit_event(Event::<T>::NftUnstakedFromPower(who, nft_id, generated_power));
+
+			Ok(().into())
+		}
 	}
 
 	#[pallet::hooks]
@@ -990,6 +1170,14 @@ impl<T: Config> Pallet<T> {
 		T::EconomyTreasury::get().into_account()
 	}
 
+	/// Power generated by a staked NFT up to the current block
+	pub fn nft_staking_power(staking_info: &NftStakingInfo<T::AccountId, T::BlockNumber>) -> PowerAmount {
+		let staked_blocks: PowerAmount = <frame_system::Pallet<T>>::block_number()
+			.saturating_sub(staking_info.since)
+			.saturated_into();
+		staked_blocks.saturating_mul(staking_info.power_rate)
+	}
+
 	pub fn convert_power_to_bit(power_amount: Balance, commission: Perbill) -> (Balance, Balance) {
 		let rate = Self::get_bit_power_exchange_rate();
 
@@ -1009,7 +1197,9 @@ impl<T: Config> Pallet<T> {
 			return Ok(());
 		}
 
-		T::FungibleTokenCurrency::withdraw(T::MiningCurrencyId::get(), who, amount);
+		if T::FungibleTokenCurrency::withdraw(T::MiningCurrencyId::get(), who, amount).is_ok() {
+			T::MiningBurnRecorder::record_mining_burn(amount);
+		}
 
 		Self::deposit_event(Event::<T>::MiningResourceBurned(amount));
 
@@ -1122,6 +1312,46 @@ impl<T: Config> Pallet<T> {
 		current_block_number >= target
 	}
 
+	/// Divert part of the round staking reward into the reserve or top it up from the reserve so
+	/// the paid reward stays within the target APY band where possible
+	pub fn smooth_staking_reward(round: RoundIndex, total_reward: Balance) -> Balance {
+		let config = match RewardSmoothing::<T>::get() {
+			Some(config) => config,
+			None => return total_reward,
+		};
+
+		let round_length = T::RoundHandler::get_current_round_info().length.max(1);
+		let rounds_per_year = (T::BlocksPerYear::get() / round_length).max(1) as Balance;
+		let total_staked = T::MetaverseStakingHandler::get_total_staked(round);
+		let min_reward = config.min_apy * total_staked / rounds_per_year;
+		let max_reward = config.max_apy * total_staked / rounds_per_year;
+
+		let reserve = SmoothingReserve::<T>::get();
+		let (paid_reward, diverted, topped_up) = if total_reward < min_reward {
+			let topped_up = min_reward.saturating_sub(total_reward).min(reserve);
+			(total_reward.saturating_add(topped_up), Zero::zero(), topped_up)
+		} else {
+			// Divert the reserve share or everything above the band, without dropping below the band
+			let diverted = (config.reserve_ratio * total_reward)
+				.max(total_reward.saturating_sub(max_reward))
+				.min(total_reward.saturating_sub(min_reward));
+			(total_reward.saturating_sub(diverted), diverted, Zero::zero())
+		};
+
+		let new_reserve = reserve.saturating_add(diverted).saturating_sub(topped_up);
+		SmoothingReserve::<T>::put(new_reserve);
+		RoundRewardSmoothing::<T>::insert(round, (diverted, topped_up));
+
+		Self::deposit_event(Event::<T>::StakingRewardSmoothed(
+			round,
+			total_reward,
+			paid_reward,
+			new_reserve,
+		));
+
+		paid_reward
+	}
+
 	fn upgrade_order_info_data_v2() -> Weight {
 		log::info!("Start upgrading order info data v2");
 		let mut num_order_queue_classes = 0;
@@ -1142,3 +1372,14 @@ impl<T: Config> Pallet<T> {
 		0
 	}
 }
+
+impl<T: Config> MetaverseStakingTrait<Balance> for Pallet<T> {
+	fn update_staking_reward(round: RoundIndex, total_reward: Balance) -> DispatchResult {
+		let paid_reward = Self::smooth_staking_reward(round, total_reward);
+		T::MetaverseStakingHandler::update_staking_reward(round, paid_reward)
+	}
+
+	fn get_total_staked(round: RoundIndex) -> Balance {
+		T::MetaverseStakingHandler::get_total_staked(round)
+	}
+}
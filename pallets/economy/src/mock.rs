@@ -5,6 +5,7 @@ use frame_support::{construct_runtime, ord_parameter_types, parameter_types, Pal
 use frame_system::EnsureSignedBy;
 use orml_traits::parameter_type_with_key;
 use sp_core::H256;
+use sp_runtime::traits::{IdentifyAccount, Lazy, Verify};
 use sp_runtime::{testing::Header, traits::IdentityLookup, Perbill};
 
 use auction_manager::*;
@@ -58,6 +59,7 @@ pub const STAKE_BELOW_MINIMUM_BALANCE: Balance = 1;
 pub const STAKE_EXCESS_BALANCE: Balance = 10 * DOLLARS;
 pub const UNSTAKE_AMOUNT: Balance = 10;
 pub const CURRENT_ROUND: RoundIndex = 1;
+pub const TOTAL_METAVERSE_STAKED: Balance = 1_000_000;
 pub const FREE_BALANCE: Balance = 9010;
 
 // Configure a mock runtime to test the pallet.
@@ -138,6 +140,18 @@ impl Estate<u128> for EstateHandler {
 		Ok(true)
 	}
 
+	fn check_estate_ownership(_owner: &u128, _estate_id: EstateId) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
+
+	fn check_landunit_ownership(
+		_owner: &u128,
+		_metaverse_id: primitives::MetaverseId,
+		_coordinate: (i32, i32),
+	) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
+
 	fn get_total_land_units() -> u64 {
 		10
 	}
@@ -145,6 +159,13 @@ impl Estate<u128> for EstateHandler {
 	fn get_total_undeploy_land_units() -> u64 {
 		10
 	}
+	fn check_adjacent_landunit_owner(
+		_who: &u128,
+		_metaverse_id: primitives::MetaverseId,
+		_coordinate: (i32, i32),
+	) -> bool {
+		false
+	}
 }
 
 pub struct MetaverseStakingHandler;
@@ -153,6 +174,10 @@ impl MetaverseStakingTrait<u128> for MetaverseStakingHandler {
 	fn update_staking_reward(round: RoundIndex, total_reward: u128) -> sp_runtime::DispatchResult {
 		Ok(())
 	}
+
+	fn get_total_staked(round: RoundIndex) -> u128 {
+		TOTAL_METAVERSE_STAKED
+	}
 }
 
 impl pallet_mining::Config for Runtime {
@@ -163,6 +188,7 @@ impl pallet_mining::Config for Runtime {
 	type EstateHandler = EstateHandler;
 	type AdminOrigin = EnsureSignedBy<One, AccountId>;
 	type MetaverseStakingHandler = MetaverseStakingHandler;
+	type QuestHandler = ();
 	type WeightInfo = ();
 }
 
@@ -170,6 +196,7 @@ ord_parameter_types! {
 	pub const One: AccountId = 1;
 	pub const Two: AccountId = 2;
 	pub const PowerAmountPerBlock: u32 = 10;
+	pub const BlocksPerYear: u32 = 200;
 }
 impl Config for Runtime {
 	type Event = Event;
@@ -181,7 +208,10 @@ impl Config for Runtime {
 	type MiningCurrencyId = MiningCurrencyId;
 	type MinimumStake = MinimumStake;
 	type PowerAmountPerBlock = PowerAmountPerBlock;
+	type MetaverseStakingHandler = MetaverseStakingHandler;
+	type BlocksPerYear = BlocksPerYear;
 	type WeightInfo = ();
+	type MiningBurnRecorder = Mining;
 }
 
 parameter_type_with_key! {
@@ -218,6 +248,8 @@ impl currencies::Config for Runtime {
 	type MultiSocialCurrency = OrmlTokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type GetNativeCurrencyId = NativeCurrencyId;
+	type TreasuryAccount = TreasuryModuleAccount;
+	type UpdateOrigin = frame_system::EnsureRoot<AccountId>;
 }
 
 pub struct MockAuctionManager;
@@ -299,6 +331,10 @@ parameter_types! {
 	pub MaxBatchTransfer: u32 = 3;
 	pub MaxBatchMinting: u32 = 2000;
 	pub MaxMetadata: u32 = 10;
+	pub MaxRarityAttributes: u32 = 5;
+	pub MaxTypedAttributes: u32 = 2;
+	pub MaxNestingDepth: u32 = 2;
+	pub GenerativeRevealDelay: BlockNumber = 2;
 }
 
 impl pallet_nft::Config for Runtime {
@@ -315,6 +351,51 @@ impl pallet_nft::Config for Runtime {
 	type Treasury = MetaverseNetworkTreasuryPalletId;
 	type AssetMintingFee = AssetMintingFee;
 	type ClassMintingFee = ClassMintingFee;
+	type ActivityRecorder = ();
+	type MaxRarityAttributes = MaxRarityAttributes;
+	type MaxTypedAttributes = MaxTypedAttributes;
+	type MaxNestingDepth = MaxNestingDepth;
+	type OffchainSignature = MockSignature;
+	type OffchainPublic = MockPublic;
+	type Randomness = MockRandomness;
+	type GenerativeRevealDelay = GenerativeRevealDelay;
+	type OnNftTransfer = ();
+}
+
+/// Randomness of the mock, derived from the subject and block number
+pub struct MockRandomness;
+
+impl frame_support::traits::Randomness<H256, BlockNumber> for MockRandomness {
+	fn random(subject: &[u8]) -> (H256, BlockNumber) {
+		let block_number = System::block_number();
+		(
+			<sp_runtime::traits::BlakeTwo256 as sp_runtime::traits::Hash>::hash_of(&(subject, block_number)),
+			block_number,
+		)
+	}
+}
+
+/// Signature of a mock account, valid for the message it was created with
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MockSignature(pub AccountId, pub Vec<u8>);
+
+impl Verify for MockSignature {
+	type Signer = MockPublic;
+
+	fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &AccountId) -> bool {
+		self.0 == *signer && self.1 == msg.get()
+	}
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MockPublic(pub AccountId);
+
+impl IdentifyAccount for MockPublic {
+	type AccountId = AccountId;
+
+	fn into_account(self) -> AccountId {
+		self.0
+	}
 }
 
 parameter_types! {
@@ -694,6 +694,7 @@ fn execute_buy_power_order_should_work() {
 
 			let remaining_amount: mock::Balance = (ALICE_MINING_BALANCE - bit_amount).into();
 			assert_eq!(OrmlTokens::free_balance(mining_currency_id, &ALICE), remaining_amount);
+			assert_eq!(Mining::total_burned(), bit_amount);
 		});
 }
 
@@ -1437,3 +1438,127 @@ fn generate_power_from_generator_should_work() {
 			);
 		});
 }
+
+#[test]
+fn set_reward_smoothing_config_should_fail_with_invalid_band() {
+	ExtBuilder::default().build().execute_with(|| {
+		let config = RewardSmoothingInfo {
+			reserve_ratio: Perbill::from_percent(10),
+			min_apy: Perbill::from_percent(50),
+			max_apy: Perbill::from_percent(10),
+		};
+
+		assert_noop!(
+			EconomyModule::set_reward_smoothing_config(Origin::signed(ALICE), Some(config.clone())),
+			BadOrigin
+		);
+		assert_noop!(
+			EconomyModule::set_reward_smoothing_config(Origin::root(), Some(config)),
+			Error::<Runtime>::InvalidApyBand
+		);
+	});
+}
+
+#[test]
+fn staking_reward_should_pass_through_without_smoothing_config() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(EconomyModule::smooth_staking_reward(CURRENT_ROUND, 40_000), 40_000);
+		assert_eq!(EconomyModule::smoothing_reserve(), 0);
+		assert_eq!(EconomyModule::round_reward_smoothing(CURRENT_ROUND), None);
+	});
+}
+
+#[test]
+fn staking_reward_smoothing_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Band is 10_000 - 50_000 per round with 1_000_000 staked over 10 rounds of 20 blocks per year
+		assert_ok!(EconomyModule::set_reward_smoothing_config(
+			Origin::root(),
+			Some(RewardSmoothingInfo {
+				reserve_ratio: Perbill::from_percent(10),
+				min_apy: Perbill::from_percent(10),
+				max_apy: Perbill::from_percent(50),
+			})
+		));
+
+		// Reserve share is diverted
+		assert_ok!(EconomyModule::update_staking_reward(1, 40_000));
+		assert_eq!(
+			last_event(),
+			Event::Economy(crate::Event::StakingRewardSmoothed(1, 40_000, 36_000, 4_000))
+		);
+		assert_eq!(EconomyModule::round_reward_smoothing(1), Some((4_000, 0)));
+
+		// Everything above the band is diverted
+		assert_ok!(EconomyModule::update_staking_reward(2, 60_000));
+		assert_eq!(EconomyModule::round_reward_smoothing(2), Some((10_000, 0)));
+		assert_eq!(EconomyModule::smoothing_reserve(), 14_000);
+
+		// Shortfall is topped up from the reserve
+		assert_ok!(EconomyModule::update_staking_reward(3, 5_000));
+		assert_eq!(
+			last_event(),
+			Event::Economy(crate::Event::StakingRewardSmoothed(3, 5_000, 10_000, 9_000))
+		);
+		assert_eq!(EconomyModule::round_reward_smoothing(3), Some((0, 5_000)));
+	});
+}
+
+#[test]
+fn stake_nft_should_generate_power() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(Origin::signed(ALICE), DISTRIBUTOR_COLLECTION_ID, DISTRIBUTOR_CLASS_ID);
+
+		assert_noop!(
+			EconomyModule::stake_nft(Origin::signed(ALICE), DISTRIBUTOR_NFT_ASSET_ID),
+			Error::<Runtime>::NftClassNotPowerGenerating
+		);
+		assert_noop!(
+			EconomyModule::set_nft_power_rate(Origin::signed(ALICE), DISTRIBUTOR_CLASS_ID, Some(10)),
+			BadOrigin
+		);
+		assert_ok!(EconomyModule::set_nft_power_rate(
+			Origin::root(),
+			DISTRIBUTOR_CLASS_ID,
+			Some(10)
+		));
+
+		assert_noop!(
+			EconomyModule::stake_nft(Origin::signed(BOB), DISTRIBUTOR_NFT_ASSET_ID),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(EconomyModule::stake_nft(
+			Origin::signed(ALICE),
+			DISTRIBUTOR_NFT_ASSET_ID
+		));
+		assert_eq!(
+			last_event(),
+			Event::Economy(crate::Event::NftStakedForPower(ALICE, DISTRIBUTOR_NFT_ASSET_ID))
+		);
+
+		// Staked NFT is locked
+		assert_noop!(
+			NFTModule::transfer(Origin::signed(ALICE), BOB, DISTRIBUTOR_NFT_ASSET_ID),
+			pallet_nft::Error::<Runtime>::NftIsLocked
+		);
+
+		run_to_block(11);
+
+		let power_balance = EconomyModule::get_power_balance(ALICE);
+		assert_ok!(EconomyModule::unstake_nft(
+			Origin::signed(ALICE),
+			DISTRIBUTOR_NFT_ASSET_ID
+		));
+		assert_eq!(
+			last_event(),
+			Event::Economy(crate::Event::NftUnstakedFromPower(ALICE, DISTRIBUTOR_NFT_ASSET_ID, 100))
+		);
+		assert_eq!(EconomyModule::get_power_balance(ALICE), power_balance + 100);
+		assert_eq!(EconomyModule::get_staked_nft(DISTRIBUTOR_NFT_ASSET_ID), None);
+		assert_ok!(NFTModule::transfer(
+			Origin::signed(ALICE),
+			BOB,
+			DISTRIBUTOR_NFT_ASSET_ID
+		));
+	});
+}
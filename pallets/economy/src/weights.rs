@@ -49,6 +49,7 @@ pub trait WeightInfo {
     fn stake() -> Weight;
     fn unstake() -> Weight;
     fn withdraw_unreserved() -> Weight;
+    fn set_reward_smoothing_config() -> Weight;
 }
 
 /// Weights for economy using the for collator node and recommended hardware.
@@ -70,6 +71,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn withdraw_unreserved() -> Weight {
         (21_000_000 as Weight).saturating_add(T::DbWeight::get().reads(3 as Weight)).saturating_add(T::DbWeight::get().writes(2 as Weight))
     }
+    fn set_reward_smoothing_config() -> Weight {
+        (9_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
 }
 
 // For backwards compatibility and tests
@@ -89,4 +93,7 @@ impl WeightInfo for () {
     fn withdraw_unreserved() -> Weight {
         (21_000_000 as Weight).saturating_add(RocksDbWeight::get().reads(3 as Weight)).saturating_add(RocksDbWeight::get().writes(2 as Weight))
     }
+    fn set_reward_smoothing_config() -> Weight {
+        (9_000_000 as Weight).saturating_add(RocksDbWeight::get().writes(1 as Weight))
+    }
 }
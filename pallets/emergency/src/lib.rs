@@ -26,6 +26,8 @@ use frame_support::{
 };
 use frame_system::pallet_prelude::*;
 use sp_runtime::DispatchResult;
+
+use core_primitives::InvariantMonitor;
 use sp_std::{prelude::*, vec::Vec};
 
 pub use module::*;
@@ -40,6 +42,12 @@ pub mod module {
 
 		/// The origin which may set filter.
 		type EmergencyOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Invariants checked at block finalization.
+		type InvariantMonitor: InvariantMonitor;
+
+		/// Calls stopped while safe mode is enabled.
+		type SafeModeCalls: Contains<Self::Call>;
 	}
 
 	#[pallet::error]
@@ -48,6 +56,8 @@ pub mod module {
 		CannotStopEmergencyCall,
 		/// invalid character encoding
 		InvalidPalletAndFunction,
+		/// Safe mode is not enabled
+		SafeModeNotEnabled,
 	}
 
 	#[pallet::event]
@@ -63,6 +73,10 @@ pub mod module {
 			pallet_name_bytes: Vec<u8>,
 			function_name_bytes: Vec<u8>,
 		},
+		/// Safe mode enabled after an invariant violation
+		SafeModeEnabled { invariant: Vec<u8> },
+		/// Safe mode cleared by governance
+		SafeModeCleared,
 	}
 
 	/// The paused transaction map
@@ -72,12 +86,29 @@ pub mod module {
 	#[pallet::getter(fn emergency_stopped_pallets)]
 	pub type EmergencyStoppedPallets<T: Config> = StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), (), OptionQuery>;
 
+	/// The violated invariant which enabled safe mode
+	///
+	/// SafeMode: Option<InvariantNameBytes>
+	#[pallet::storage]
+	#[pallet::getter(fn safe_mode)]
+	pub type SafeMode<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_finalize(_now: T::BlockNumber) {
+			// Monitors are checked even in safe mode so one-off violations are consumed
+			if let Some(invariant) = T::InvariantMonitor::violated_invariant() {
+				if !SafeMode::<T>::exists() {
+					SafeMode::<T>::put(invariant.clone());
+					Self::deposit_event(Event::SafeModeEnabled { invariant });
+				}
+			}
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -118,6 +149,16 @@ pub mod module {
 			};
 			Ok(())
 		}
+
+		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		#[transactional]
+		pub fn clear_safe_mode(origin: OriginFor<T>) -> DispatchResult {
+			T::EmergencyOrigin::ensure_origin(origin)?;
+			ensure!(SafeMode::<T>::take().is_some(), Error::<T>::SafeModeNotEnabled);
+
+			Self::deposit_event(Event::SafeModeCleared);
+			Ok(())
+		}
 	}
 }
 
@@ -134,5 +175,6 @@ where
 		} = call.get_call_metadata();
 
 		EmergencyStoppedPallets::<T>::contains_key((pallet_name.as_bytes(), function_name.as_bytes()))
+			|| (SafeMode::<T>::exists() && T::SafeModeCalls::contains(call))
 	}
 }
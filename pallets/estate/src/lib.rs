@@ -18,24 +18,37 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::pallet_prelude::*;
-use frame_support::{dispatch::DispatchResult, ensure, traits::Get, PalletId};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	traits::{Get, GetStorageVersion},
+	transactional, PalletId,
+};
 use frame_system::pallet_prelude::*;
 use frame_system::{ensure_root, ensure_signed};
+use orml_traits::MultiCurrency;
 use scale_info::TypeInfo;
+use sp_io::KillStorageResult;
 use sp_runtime::{
-	traits::{AccountIdConversion, One, Saturating},
-	DispatchError,
+	traits::{AccountIdConversion, One, SaturatedConversion, Saturating, Zero},
+	DispatchError, Perbill,
 };
-use sp_std::vec::Vec;
+use sp_std::{collections::btree_map::BTreeMap, convert::TryInto, vec::Vec};
 
-use auction_manager::{Auction, CheckAuctionItemHandler};
+use auction_manager::{Auction, CheckAuctionItemHandler, ListingLevel};
 use core_primitives::*;
 pub use pallet::*;
-use primitives::estate::EstateInfo;
+use primitives::estate::{
+	adjacent_coordinates, CoOwnershipAction, CoOwnershipProposal, EstateAttributeValue, EstateAttributes,
+	EstateBeneficiaryInfo, EstateCoOwnershipInfo, EstateInfo, EstateLoanInfo, EstateLoanRequest,
+	EstateProvenanceRecord, LandBuyerGate, LandGiftCard, LandGiftCardPool, LandGiftCardPoolId, LandTaxInfo, LeaseInfo,
+	LeaseOffer, MapBound,
+};
+use primitives::telemetry::{ActivityCounter, ActivityRecorder};
 use primitives::{
-	estate::Estate, estate::LandUnitStatus, estate::OwnerId, Attributes, ClassId, EstateId, ItemId, MetaverseId,
-	NftMetadata, TokenId, UndeployedLandBlock, UndeployedLandBlockId, UndeployedLandBlockType, ESTATE_CLASS_ID,
-	LAND_CLASS_ID,
+	estate::Estate, estate::LandUnitStatus, estate::OwnerId, Attributes, Balance, ClassId, EstateId, FungibleTokenId,
+	ItemId, MetaverseId, NftMetadata, TokenId, UndeployedLandBlock, UndeployedLandBlockId, UndeployedLandBlockType,
+	ESTATE_CLASS_ID, LAND_CLASS_ID,
 };
 pub use rate::{MintingRateInfo, Range};
 pub use weights::WeightInfo;
@@ -52,9 +65,13 @@ mod tests;
 
 pub mod weights;
 
+/// Max stale per-extrinsic transfer records cleared in one block, records left over are ignored as
+/// they carry the block they were written in
+pub const MAX_TRANSFER_RECORDS_CLEARED_PER_BLOCK: u32 = 1_000;
+
 #[frame_support::pallet]
 pub mod pallet {
-	use frame_support::traits::{Currency, Imbalance, ReservableCurrency};
+	use frame_support::traits::{BalanceStatus, Currency, ExistenceRequirement, Imbalance, ReservableCurrency};
 	use sp_runtime::traits::{CheckedAdd, CheckedSub, Zero};
 
 	use primitives::estate::EstateInfo;
@@ -65,8 +82,12 @@ pub mod pallet {
 
 	use super::*;
 
+	/// Estates have a land tax activity record from storage version 1
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 
@@ -95,6 +116,53 @@ pub mod pallet {
 		type RewardPaymentDelay: Get<u32>;
 		/// NFT Trait required for land and estate tokenization
 		type NFTTokenizationSource: NFTTrait<Self::AccountId, BalanceOf<Self>, ClassId = ClassId, TokenId = TokenId>;
+		/// Maximum length of estate metadata, attribute keys and text attribute values
+		#[pallet::constant]
+		type MaxEstateMetadata: Get<u32>;
+		/// Maximum number of attributes per estate
+		#[pallet::constant]
+		type MaxEstateAttributes: Get<u32>;
+		/// Origin which allows to force transfer assets on court orders
+		type ForceTransferOrigin: EnsureOrigin<Self::Origin>;
+		/// Whether force transfer is available, disabled for public runtimes
+		#[pallet::constant]
+		type ForceTransferEnabled: Get<bool>;
+		/// Maximum number of co-owners of an estate
+		#[pallet::constant]
+		type MaxCoOwners: Get<u32>;
+		/// Number of blocks a co-ownership proposal stays pending before governance can break the
+		/// deadlock
+		#[pallet::constant]
+		type CoOwnershipDeadlockPeriod: Get<Self::BlockNumber>;
+		/// Maximum number of provenance records kept per estate, oldest records are pruned first
+		#[pallet::constant]
+		type MaxEstateProvenance: Get<u32>;
+		/// Multi currency used to burn mining resource and pay for land gift cards
+		type FungibleTokenCurrency: MultiCurrency<
+			Self::AccountId,
+			CurrencyId = FungibleTokenId,
+			Balance = BalanceOf<Self>,
+		>;
+		/// Mining resource token burned when upgrading land units
+		#[pallet::constant]
+		type MiningResourceId: Get<FungibleTokenId>;
+		/// Highest level a land unit can be upgraded to
+		#[pallet::constant]
+		type MaxLandUnitLevel: Get<u32>;
+		/// Stablecoin land gift cards are sold in
+		#[pallet::constant]
+		type GiftCardCurrencyId: Get<FungibleTokenId>;
+		/// Maximum number of land blocks a gift card pool can hold
+		#[pallet::constant]
+		type MaxGiftCardPoolSize: Get<u32>;
+		/// Chain activity telemetry counting deployed land units
+		type ActivityRecorder: ActivityRecorder;
+		/// Maximum number of expired leases ended per block, leftovers are ended in the next
+		/// blocks
+		#[pallet::constant]
+		type MaxLeaseExpiriesPerBlock: Get<u32>;
+		/// Records mining resource burned by land unit upgrades
+		type MiningBurnRecorder: MiningBurnRecorder;
 	}
 
 	type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -104,6 +172,11 @@ pub mod pallet {
 	#[pallet::getter(fn get_max_bounds)]
 	pub type MaxBounds<T: Config> = StorageMap<_, Blake2_128Concat, MetaverseId, (i32, i32), ValueQuery>;
 
+	/// Get map dimensions, takes precedence over max bound
+	#[pallet::storage]
+	#[pallet::getter(fn get_map_bounds)]
+	pub type MapBounds<T: Config> = StorageMap<_, Blake2_128Concat, MetaverseId, MapBound, OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn all_land_units_count)]
 	pub(super) type AllLandUnitsCount<T: Config> = StorageValue<_, u64, ValueQuery>;
@@ -141,6 +214,11 @@ pub mod pallet {
 	pub type EstateOwner<T: Config> =
 		StorageMap<_, Twox64Concat, EstateId, OwnerId<T::AccountId, TokenId>, OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn get_estate_by_token)]
+	/// Estate represented by each estate token
+	pub type EstateTokens<T: Config> = StorageMap<_, Twox64Concat, TokenId, EstateId, OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn next_undeployed_land_block_id)]
 	pub(super) type NextUndeployedLandBlockId<T: Config> = StorageValue<_, UndeployedLandBlockId, ValueQuery>;
@@ -199,6 +277,213 @@ pub mod pallet {
 	pub type EstateStake<T: Config> =
 		StorageDoubleMap<_, Twox64Concat, EstateId, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn get_land_tax)]
+	/// Idle land tax configuration of each metaverse
+	pub type LandTax<T: Config> = StorageMap<_, Twox64Concat, MetaverseId, LandTaxInfo<BalanceOf<T>>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_estate_last_activity)]
+	/// Last block at which the estate was updated or settled its land tax
+	pub type EstateLastActivity<T: Config> = StorageMap<_, Twox64Concat, EstateId, T::BlockNumber, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_land_tax_exemption)]
+	/// Estates which are exempted from idle land tax
+	pub type LandTaxExemption<T: Config> = StorageMap<_, Twox64Concat, EstateId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_estate_beneficiary)]
+	/// Beneficiary designated by the estate owner
+	pub type EstateBeneficiaries<T: Config> =
+		StorageMap<_, Twox64Concat, EstateId, EstateBeneficiaryInfo<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_last_heartbeat)]
+	/// Last block at which the estate owner proved activity
+	pub type LastHeartbeat<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, T::BlockNumber, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_estate_metadata)]
+	/// Estate metadata URI or CID
+	pub type EstateMetadata<T: Config> = StorageMap<_, Twox64Concat, EstateId, NftMetadata, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_estate_attributes)]
+	/// Typed estate attributes such as name, description and terrain
+	pub type EstateAttributesOf<T: Config> = StorageMap<_, Twox64Concat, EstateId, EstateAttributes, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn is_estate_metadata_frozen)]
+	/// Estates which metadata and attributes can no longer be updated
+	pub type FrozenEstateMetadata<T: Config> = StorageMap<_, Twox64Concat, EstateId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_estate_co_ownership)]
+	/// Co-owners and approval threshold of jointly owned estates
+	pub type EstateCoOwnership<T: Config> =
+		StorageMap<_, Twox64Concat, EstateId, EstateCoOwnershipInfo<T::AccountId>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_co_ownership_proposal)]
+	/// Pending action of jointly owned estate awaiting co-owner approvals
+	pub type CoOwnershipProposals<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		EstateId,
+		CoOwnershipProposal<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_co_ownership_opening_balance)]
+	/// Balance of the estate account when the co-ownership started, only the balance above it is
+	/// split among co-owners
+	pub type CoOwnershipOpeningBalance<T: Config> = StorageMap<_, Twox64Concat, EstateId, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_lease_offer)]
+	/// Lease terms posted on the lease board, any account can accept them
+	pub type LeaseOffers<T: Config> =
+		StorageMap<_, Twox64Concat, EstateId, LeaseOffer<T::AccountId, BalanceOf<T>, T::BlockNumber>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_estate_lease)]
+	/// Active estate leases
+	pub type EstateLeases<T: Config> =
+		StorageMap<_, Twox64Concat, EstateId, LeaseInfo<T::AccountId, BalanceOf<T>, T::BlockNumber>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn lease_expiries)]
+	/// Index leases by the block at which the tenant is evicted
+	pub type LeaseExpiries<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, EstateId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn lease_expiry_cursor)]
+	/// Earliest eviction block that may still have leases waiting to be ended
+	pub(super) type LeaseExpiryCursor<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn land_deployed_by)]
+	/// Accounts that have deployed at least one land block, used by the onboarding quests
+	pub type LandDeployedAccounts<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_loan_request)]
+	/// Loan requests waiting to be funded, the estate is locked as collateral while requested
+	pub type LoanRequests<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		EstateId,
+		EstateLoanRequest<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_estate_loan)]
+	/// Active loans collateralized by estates
+	pub type EstateLoans<T: Config> =
+		StorageMap<_, Twox64Concat, EstateId, EstateLoanInfo<T::AccountId, BalanceOf<T>, T::BlockNumber>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn deployment_whitelist_enabled)]
+	/// Metaverses where only approved deployers can deploy undeployed land blocks
+	pub type DeploymentWhitelistEnabled<T: Config> = StorageMap<_, Twox64Concat, MetaverseId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn land_deployers)]
+	/// Accounts approved to deploy land blocks in whitelisted metaverses
+	pub type LandDeployers<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, MetaverseId, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn land_buyer_gate)]
+	/// Requirement buyers have to meet to purchase land in the metaverse
+	pub type LandBuyerGates<T: Config> = StorageMap<_, Twox64Concat, MetaverseId, LandBuyerGate, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn land_buyers)]
+	/// Accounts approved to purchase land in metaverses gated by allowlist
+	pub type LandBuyers<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, MetaverseId, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_estate_provenance)]
+	/// Recent owners of each estate with their acquisition price and block, oldest first
+	pub type EstateProvenance<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		EstateId,
+		Vec<EstateProvenanceRecord<T::AccountId, Balance, T::BlockNumber>>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_estate_transferred_in_extrinsic)]
+	/// Block and index of the extrinsic which last transferred each estate, a bounded number of
+	/// stale records is cleared at the start of every block
+	pub type EstateTransferredInExtrinsic<T: Config> =
+		StorageMap<_, Twox64Concat, EstateId, (T::BlockNumber, u32), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_land_unit_upgrade_cost)]
+	/// Amount of mining resource burned to upgrade a land unit to each level
+	pub type LandUnitUpgradeCosts<T: Config> = StorageMap<_, Twox64Concat, u32, BalanceOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_land_unit_level)]
+	/// Current level of each upgraded land unit, land units are level 0 until first upgraded
+	pub type LandUnitLevels<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, MetaverseId, Twox64Concat, (i32, i32), u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_frozen_estate)]
+	/// Disputed estates frozen by governance, frozen estates can not be transferred, dissolved,
+	/// listed or leased
+	pub type FrozenEstates<T: Config> = StorageMap<_, Twox64Concat, EstateId, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_estate_royalty)]
+	/// Royalty recipient and share of the price enforced on marketplace sales of the estate
+	pub type EstateRoyalties<T: Config> = StorageMap<_, Twox64Concat, EstateId, (T::AccountId, Perbill), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_land_gift_card_pool_id)]
+	pub type NextLandGiftCardPoolId<T: Config> = StorageValue<_, LandGiftCardPoolId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_land_gift_card_pool)]
+	/// Pools of undeployed land blocks pre-funded by sponsors to back land gift cards
+	pub type LandGiftCardPools<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		LandGiftCardPoolId,
+		LandGiftCardPool<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_land_gift_card)]
+	/// Sold land gift cards by their NFT which are not redeemed or refunded yet
+	pub type LandGiftCards<T: Config> =
+		StorageMap<_, Twox64Concat, (ClassId, TokenId), LandGiftCard<T::BlockNumber>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn metaverse_land_unit_count)]
+	/// Number of land units deployed in each metaverse, kept in step with `LandUnits`
+	pub type MetaverseLandUnitCount<T: Config> = StorageMap<_, Twox64Concat, MetaverseId, u64, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn staker_estate_stake)]
+	/// Total stake of each staker over all estates, kept in step with `EstateStake`
+	pub type StakerEstateStake<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn sunset_lease_cursor)]
+	/// Metaverses entering sunset with the next estate id to check for leases to wind down
+	pub(super) type SunsetLeaseCursors<T: Config> = StorageMap<_, Twox64Concat, MetaverseId, EstateId, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig {
 		pub minting_rate_config: MintingRateInfo,
@@ -247,6 +532,10 @@ pub mod pallet {
 		NewEstateMinted(EstateId, OwnerId<T::AccountId, TokenId>, MetaverseId, Vec<(i32, i32)>),
 		/// Metaverse Id, Min and Max Coordinate
 		MaxBoundSet(MetaverseId, (i32, i32)),
+		/// Metaverse Id, Map Bound
+		MapBoundSet(MetaverseId, MapBound),
+		/// Metaverse Id, Map Bound
+		MapBoundExtended(MetaverseId, MapBound),
 		/// From Account Id, Metaverse Id, Undeployed Land Block Id, Coordinates
 		LandBlockDeployed(T::AccountId, MetaverseId, UndeployedLandBlockId, Vec<(i32, i32)>),
 		/// Beneficial Account Id, Undeployed Land Block Id
@@ -284,6 +573,110 @@ pub mod pallet {
 		EstateStakeLeft(OwnerId<T::AccountId, TokenId>, EstateId),
 		/// Account Id, Balance
 		StakingRewarded(T::AccountId, BalanceOf<T>),
+		/// Metaverse Id, Land Tax Info
+		LandTaxUpdated(MetaverseId, Option<LandTaxInfo<BalanceOf<T>>>),
+		/// Estate Id, Is Exempted
+		LandTaxExemptionUpdated(EstateId, bool),
+		/// Estate Id, Payer Account Id, Amount
+		LandTaxPaid(EstateId, T::AccountId, BalanceOf<T>),
+		/// Estate Id, Owner Account Id, Beneficiary Account Id
+		EstateBeneficiarySet(EstateId, T::AccountId, Option<T::AccountId>),
+		/// Account Id, Block Number
+		HeartbeatReceived(T::AccountId, T::BlockNumber),
+		/// Estate Id, From Account Id, Beneficiary Account Id
+		EstateInherited(EstateId, T::AccountId, T::AccountId),
+		/// Estate Id, Metadata
+		EstateMetadataUpdated(EstateId, NftMetadata),
+		/// Estate Id, Attribute Key, Attribute Value
+		EstateAttributeUpdated(EstateId, Vec<u8>, Option<EstateAttributeValue>),
+		/// Estate Id
+		EstateMetadataFrozen(EstateId),
+		/// Item Id, From Account Id, To Account Id, Justification Hash
+		AssetForceTransferred(ItemId, T::AccountId, T::AccountId, T::Hash),
+		/// Estate Id, Co-owners, Threshold
+		EstateCoOwnershipSet(EstateId, Vec<T::AccountId>, u32),
+		/// Estate Id, Proposer Account Id, Action
+		CoOwnershipActionProposed(
+			EstateId,
+			T::AccountId,
+			CoOwnershipAction<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+		),
+		/// Estate Id, Co-owner Account Id
+		CoOwnershipActionApproved(EstateId, T::AccountId),
+		/// Estate Id, Action, Approving Co-owner Account Ids
+		CoOwnershipActionExecuted(
+			EstateId,
+			CoOwnershipAction<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+			Vec<T::AccountId>,
+		),
+		/// Estate Id, Is Executed
+		CoOwnershipDeadlockResolved(EstateId, bool),
+		/// Estate Id
+		EstateCoOwnershipEnded(EstateId),
+		/// Estate Id, Landlord Account Id, Rent Per Block, Duration
+		LeaseOfferPosted(EstateId, T::AccountId, BalanceOf<T>, T::BlockNumber),
+		/// Estate Id
+		LeaseOfferCancelled(EstateId),
+		/// Estate Id, Landlord Account Id, Tenant Account Id, Deposit
+		EstateLeased(EstateId, T::AccountId, T::AccountId, BalanceOf<T>),
+		/// Estate Id, Tenant Account Id, Amount
+		LeaseDepositToppedUp(EstateId, T::AccountId, BalanceOf<T>),
+		/// Estate Id, Landlord Account Id, Amount
+		RentCollected(EstateId, T::AccountId, BalanceOf<T>),
+		/// Estate Id, Tenant Account Id
+		LeaseEnded(EstateId, T::AccountId),
+		/// Estate Id, Borrower Account Id, Principal, Repayment
+		EstateLoanRequested(EstateId, T::AccountId, BalanceOf<T>, BalanceOf<T>),
+		/// Estate Id
+		EstateLoanRequestCancelled(EstateId),
+		/// Estate Id, Borrower Account Id, Lender Account Id, Repayment Deadline
+		EstateLoanFunded(EstateId, T::AccountId, T::AccountId, T::BlockNumber),
+		/// Estate Id, Payer Account Id, Amount
+		EstateLoanRepaid(EstateId, T::AccountId, BalanceOf<T>),
+		/// Estate Id, Borrower Account Id, Lender Account Id
+		EstateLoanLiquidated(EstateId, T::AccountId, T::AccountId),
+		/// Metaverse Id, Is Enabled
+		DeploymentWhitelistUpdated(MetaverseId, bool),
+		/// Metaverse Id, Deployer Account Id
+		LandDeployerAdded(MetaverseId, T::AccountId),
+		/// Metaverse Id, Deployer Account Id
+		LandDeployerRemoved(MetaverseId, T::AccountId),
+		/// Metaverse Id, Land Buyer Gate
+		LandBuyerGateUpdated(MetaverseId, Option<LandBuyerGate>),
+		/// Metaverse Id, Buyer Account Id
+		LandBuyerAdded(MetaverseId, T::AccountId),
+		/// Metaverse Id, Buyer Account Id
+		LandBuyerRemoved(MetaverseId, T::AccountId),
+		/// Level, Upgrade Cost
+		LandUnitUpgradeCostUpdated(u32, BalanceOf<T>),
+		/// Account Id, Metaverse Id, Coordinate, New Level, Burned Amount
+		LandUnitUpgraded(T::AccountId, MetaverseId, (i32, i32), u32, BalanceOf<T>),
+		/// Estate Id
+		EstateFrozen(EstateId),
+		/// Estate Id
+		EstateUnfrozen(EstateId),
+		/// Estate Id, Royalty Recipient and Share
+		EstateRoyaltyUpdated(EstateId, Option<(T::AccountId, Perbill)>),
+		/// Pool Id, Sponsor Account Id, Metaverse Id, Number Of Land Blocks
+		LandGiftCardPoolCreated(LandGiftCardPoolId, T::AccountId, MetaverseId, u32),
+		/// Pool Id, Sponsor Account Id, Number Of Returned Land Blocks
+		LandGiftCardPoolClosed(LandGiftCardPoolId, T::AccountId, u32),
+		/// Pool Id, Buyer Account Id, Recipient Account Id, Gift Card, Price
+		LandGiftCardPurchased(
+			LandGiftCardPoolId,
+			T::AccountId,
+			T::AccountId,
+			(ClassId, TokenId),
+			BalanceOf<T>,
+		),
+		/// Gift Card, Holder Account Id, Metaverse Id, Undeployed Land Block Id
+		LandGiftCardRedeemed((ClassId, TokenId), T::AccountId, MetaverseId, UndeployedLandBlockId),
+		/// Gift Card, Sponsor Account Id, Undeployed Land Block Id
+		LandGiftCardRefunded((ClassId, TokenId), T::AccountId, UndeployedLandBlockId),
+		/// Account Id, Metaverse Id, Number Of Land Units, Undeployed Land Block Id
+		SunsetLandUnitsReverted(T::AccountId, MetaverseId, u32, UndeployedLandBlockId),
+		/// Estate Id, Proposer Account Id
+		CoOwnershipActionCancelled(EstateId, T::AccountId),
 	}
 
 	#[pallet::error]
@@ -322,6 +715,153 @@ pub mod pallet {
 		Overflow,
 		EstateStakeAlreadyLeft,
 		AccountHasNoStake,
+		// Estate has unpaid land tax
+		LandTaxOutstanding,
+		// Estate has no land tax due
+		NoLandTaxDue,
+		// Estate has no designated beneficiary
+		NoEstateBeneficiary,
+		// Inactivity period can not be zero
+		InvalidInactivityPeriod,
+		// Estate owner is still active
+		EstateOwnerStillActive,
+		// Exceed maximum length of estate metadata
+		ExceedMaximumMetadataLength,
+		// Exceed maximum number of estate attributes
+		ExceedMaximumAttributes,
+		// Estate metadata is frozen
+		EstateMetadataFrozen,
+		// Force transfer is disabled in this runtime
+		ForceTransferDisabled,
+		// Asset type can not be force transferred
+		InvalidForceTransferAsset,
+		// Co-owners are duplicated or out of allowed range
+		InvalidCoOwners,
+		// Threshold is zero or above the number of co-owners
+		InvalidCoOwnershipThreshold,
+		// Estate is not jointly owned
+		EstateIsNotCoOwned,
+		// Account is not co-owner of the estate
+		NotCoOwner,
+		// Estate already has a pending co-ownership proposal
+		CoOwnershipProposalPending,
+		// Estate has no pending co-ownership proposal
+		NoCoOwnershipProposal,
+		// Co-owner already approved the proposal
+		AlreadyApprovedCoOwnershipProposal,
+		// Co-ownership proposal has not been pending long enough
+		CoOwnershipDeadlockPeriodNotPassed,
+		// Minimum coordinate of the map is above the maximum
+		InvalidMapBound,
+		// New map bound does not contain the current map
+		MapBoundCanOnlyBeExtended,
+		// Same coordinate is minted more than once
+		DuplicateLandUnitCoordinate,
+		// Rent, duration or minimum deposit is invalid
+		InvalidLeaseTerms,
+		// Estate has no lease offer
+		LeaseOfferNotFound,
+		// Estate is leased
+		EstateIsLeased,
+		// Estate is not leased
+		EstateNotLeased,
+		// Deposit is below minimum deposit of the lease offer
+		LeaseDepositTooLow,
+		// Owner can not lease own estate
+		CannotLeaseOwnEstate,
+		// Principal, repayment or duration is invalid
+		InvalidLoanTerms,
+		// Estate is locked as loan collateral
+		EstateIsCollateralized,
+		// Estate has no loan request
+		LoanRequestNotFound,
+		// Estate has no active loan
+		EstateLoanNotFound,
+		// Borrower can not fund own loan
+		CannotLendToSelf,
+		// Loan repayment deadline has not passed
+		LoanRepaymentDeadlineNotPassed,
+		// Account is not approved to deploy land in the metaverse
+		NotWhitelistedDeployer,
+		// Account is already approved to deploy land in the metaverse
+		LandDeployerAlreadyExists,
+		// Account is not approved to deploy land in the metaverse
+		LandDeployerNotFound,
+		// Account does not meet the land buyer gate of the metaverse
+		LandBuyerNotAllowed,
+		// Account is already approved to buy land in the metaverse
+		LandBuyerAlreadyExists,
+		// Account is not approved to buy land in the metaverse
+		LandBuyerNotFound,
+		// Estate has already been transferred in this extrinsic
+		EstateAlreadyTransferredInExtrinsic,
+		// Land unit level is invalid
+		InvalidLandUnitLevel,
+		// Land unit has reached the maximum level
+		LandUnitMaxLevelReached,
+		// No upgrade cost is configured for the next land unit level
+		LandUnitUpgradeCostNotSet,
+		// Estate is frozen by governance
+		EstateIsFrozen,
+		/// Estate royalty exceeds the 25% maximum
+		EstateRoyaltyExceedLimit,
+		// Estate is not frozen
+		EstateIsNotFrozen,
+		// Gift card pool has no land blocks or too many land blocks
+		InvalidGiftCardPoolSize,
+		// Gift card validity period is invalid
+		InvalidGiftCardValidity,
+		// No available gift card pool id
+		NoAvailableGiftCardPoolId,
+		// Gift card pool does not exist
+		GiftCardPoolNotFound,
+		// Gift card pool has no land blocks left
+		GiftCardPoolSoldOut,
+		// Gift card does not exist or is already redeemed
+		GiftCardNotFound,
+		// Gift card has already been issued
+		GiftCardAlreadyExists,
+		// Gift card has expired
+		GiftCardExpired,
+		// Gift card has not expired yet
+		GiftCardNotExpired,
+		// Metaverse is in sunset and does not accept new land
+		MetaverseIsInSunset,
+		// Metaverse is not in sunset
+		MetaverseNotInSunset,
+		// Loan request terms differ from the terms the lender agreed to
+		LoanTermsMismatch,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let cleared_records =
+				match EstateTransferredInExtrinsic::<T>::remove_all(Some(MAX_TRANSFER_RECORDS_CLEARED_PER_BLOCK)) {
+					KillStorageResult::AllRemoved(cleared) | KillStorageResult::SomeRemaining(cleared) => cleared,
+				};
+			T::DbWeight::get()
+				.writes(cleared_records as Weight)
+				.saturating_add(Self::end_expired_leases(now))
+				.saturating_add(Self::wind_down_sunset_leases())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Self::on_chain_storage_version();
+			if on_chain_version < 3 {
+				let mut weight: Weight = 0;
+				if on_chain_version < 1 {
+					weight = weight.saturating_add(Self::upgrade_estate_last_activity_v1());
+				}
+				if on_chain_version < 2 {
+					weight = weight.saturating_add(Self::upgrade_estate_tokens_v2());
+				}
+				weight = weight.saturating_add(Self::upgrade_estate_stake_totals_v3());
+				STORAGE_VERSION.put::<Pallet<T>>();
+				return weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+			}
+			T::DbWeight::get().reads(1)
+		}
 	}
 
 	#[pallet::call]
@@ -335,12 +875,59 @@ pub mod pallet {
 			ensure_root(origin)?;
 
 			MaxBounds::<T>::insert(metaverse_id, new_bound);
+			MapBounds::<T>::insert(
+				metaverse_id,
+				MapBound {
+					min: (new_bound.0, new_bound.0),
+					max: (new_bound.1, new_bound.1),
+				},
+			);
 
 			Self::deposit_event(Event::<T>::MaxBoundSet(metaverse_id, new_bound));
 
 			Ok(().into())
 		}
 
+		/// Set map dimensions of the metaverse, land units can only be minted within the map
+		#[pallet::weight(T::WeightInfo::set_map_bounds())]
+		pub fn set_map_bounds(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			map_bound: MapBound,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ensure!(map_bound.is_valid(), Error::<T>::InvalidMapBound);
+
+			MapBounds::<T>::insert(metaverse_id, map_bound);
+
+			Self::deposit_event(Event::<T>::MapBoundSet(metaverse_id, map_bound));
+
+			Ok(().into())
+		}
+
+		/// Governance extends the map of the metaverse, existing land units stay within the map
+		#[pallet::weight(T::WeightInfo::extend_map_bounds())]
+		pub fn extend_map_bounds(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			map_bound: MapBound,
+		) -> DispatchResultWithPostInfo {
+			T::CouncilOrigin::ensure_origin(origin)?;
+			ensure!(map_bound.is_valid(), Error::<T>::InvalidMapBound);
+
+			let current_bound = Self::map_bound(metaverse_id).ok_or(Error::<T>::NoMaxBoundSet)?;
+			ensure!(
+				map_bound.contains_bound(&current_bound),
+				Error::<T>::MapBoundCanOnlyBeExtended
+			);
+
+			MapBounds::<T>::insert(metaverse_id, map_bound);
+
+			Self::deposit_event(Event::<T>::MapBoundExtended(metaverse_id, map_bound));
+
+			Ok(().into())
+		}
+
 		#[pallet::weight(T::WeightInfo::mint_land())]
 		pub fn mint_land(
 			origin: OriginFor<T>,
@@ -351,6 +938,8 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
 
+			Self::ensure_land_coordinates_available(metaverse_id, &[coordinate])?;
+
 			// Mint land unit
 			let owner = Self::mint_land_unit(
 				metaverse_id,
@@ -378,6 +967,8 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
 
+			Self::ensure_land_coordinates_available(metaverse_id, &coordinates)?;
+
 			// Mint land units
 			for coordinate in coordinates.clone() {
 				Self::mint_land_unit(
@@ -409,6 +1000,7 @@ pub mod pallet {
 			coordinate: (i32, i32),
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
 
 			ensure!(
 				!T::AuctionHandler::check_item_in_auction(ItemId::LandUnit(coordinate, metaverse_id)),
@@ -430,6 +1022,8 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
 
+			Self::ensure_land_coordinates_available(metaverse_id, &coordinates)?;
+
 			// Generate new estate id
 			let new_estate_id = Self::get_new_estate_id()?;
 
@@ -512,12 +1106,15 @@ pub mod pallet {
 			estate_id: EstateId,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
 
 			ensure!(
 				!T::AuctionHandler::check_item_in_auction(ItemId::Estate(estate_id)),
 				Error::<T>::EstateAlreadyInAuction
 			);
 
+			Self::ensure_land_tax_settled(estate_id)?;
+
 			Self::do_transfer_estate(estate_id, &who, &to)?;
 
 			Ok(().into())
@@ -534,65 +1131,12 @@ pub mod pallet {
 			are_tokenized: bool,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
 
-			UndeployedLandBlocks::<T>::try_mutate_exists(
-				&undeployed_land_block_id,
-				|undeployed_land_block| -> DispatchResultWithPostInfo {
-					let mut undeployed_land_block_record = undeployed_land_block
-						.as_mut()
-						.ok_or(Error::<T>::UndeployedLandBlockNotFound)?;
-
-					ensure!(
-						undeployed_land_block_record.owner == who.clone(),
-						Error::<T>::NoPermission
-					);
-
-					ensure!(
-						undeployed_land_block_record.is_frozen == false,
-						Error::<T>::UndeployedLandBlockFreezed
-					);
-
-					let land_units_to_mint = coordinates.len() as u32;
-					ensure!(
-						undeployed_land_block_record.number_land_units > land_units_to_mint,
-						Error::<T>::UndeployedLandBlockDoesNotHaveEnoughLandUnits
-					);
-
-					// Mint land units
-					for coordinate in coordinates.clone() {
-						Self::mint_land_unit(
-							metaverse_id,
-							who.clone(),
-							coordinate,
-							LandUnitStatus::NonExisting,
-							are_tokenized,
-						)?;
-					}
-
-					// Update total land count
-					Self::set_total_land_unit(coordinates.len() as u64, false)?;
-
-					// Update undeployed land block
-					if undeployed_land_block_record.number_land_units == land_units_to_mint {
-						Self::do_burn_undeployed_land_block(undeployed_land_block_id)?;
-					} else {
-						undeployed_land_block_record.number_land_units = undeployed_land_block_record
-							.number_land_units
-							.checked_sub(land_units_to_mint)
-							.ok_or("Overflow deduct land units from undeployed land block")?;
-					}
-					Self::set_total_undeployed_land_unit(land_units_to_mint as u64, true)?;
-
-					Self::deposit_event(Event::<T>::LandBlockDeployed(
-						who.clone(),
-						metaverse_id,
-						undeployed_land_block_id,
-						coordinates,
-					));
+			Self::do_deploy_land_block(&who, undeployed_land_block_id, metaverse_id, coordinates, are_tokenized)?;
+			LandDeployedAccounts::<T>::insert(&who, ());
 
-					Ok(().into())
-				},
-			)
+			Ok(().into())
 		}
 
 		/// Sudo issues new raw land block
@@ -666,6 +1210,7 @@ pub mod pallet {
 			undeployed_land_block_id: UndeployedLandBlockId,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
 
 			Self::do_transfer_undeployed_land_block(&who, &to, undeployed_land_block_id)?;
 
@@ -693,6 +1238,7 @@ pub mod pallet {
 			undeployed_land_block_id: UndeployedLandBlockId,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
 
 			UndeployedLandBlocks::<T>::try_mutate_exists(
 				&undeployed_land_block_id,
@@ -731,6 +1277,7 @@ pub mod pallet {
 			undeployed_land_block_id: UndeployedLandBlockId,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
 
 			UndeployedLandBlocks::<T>::try_mutate_exists(
 				&undeployed_land_block_id,
@@ -764,6 +1311,7 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::dissolve_estate())]
 		pub fn dissolve_estate(origin: OriginFor<T>, estate_id: EstateId) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
 
 			ensure!(
 				!T::AuctionHandler::check_item_in_auction(ItemId::Estate(estate_id)),
@@ -771,6 +1319,14 @@ pub mod pallet {
 			);
 			let estate_info = Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
 
+			Self::ensure_land_tax_settled(estate_id)?;
+			ensure!(!Self::is_frozen(estate_id), Error::<T>::EstateIsFrozen);
+			ensure!(!EstateLeases::<T>::contains_key(estate_id), Error::<T>::EstateIsLeased);
+			ensure!(
+				!Self::is_estate_collateralized(estate_id),
+				Error::<T>::EstateIsCollateralized
+			);
+
 			EstateOwner::<T>::try_mutate_exists(&estate_id, |estate_owner| {
 				//ensure there is record of the estate owner with estate id and account id
 
@@ -783,6 +1339,7 @@ pub mod pallet {
 				match estate_owner_value {
 					OwnerId::Token(t) => {
 						T::NFTTokenizationSource::burn_nft(&who, &(ESTATE_CLASS_ID, t));
+						EstateTokens::<T>::remove(t);
 						*estate_owner = None;
 					}
 					OwnerId::Account(ref a) => {
@@ -792,6 +1349,14 @@ pub mod pallet {
 
 				// Remove estate
 				Estates::<T>::remove(&estate_id);
+				EstateLastActivity::<T>::remove(&estate_id);
+				LandTaxExemption::<T>::remove(&estate_id);
+				EstateBeneficiaries::<T>::remove(&estate_id);
+				EstateMetadata::<T>::remove(&estate_id);
+				EstateAttributesOf::<T>::remove(&estate_id);
+				FrozenEstateMetadata::<T>::remove(&estate_id);
+				EstateProvenance::<T>::remove(&estate_id);
+				LeaseOffers::<T>::remove(&estate_id);
 
 				// Update total estates
 				let total_estates_count = Self::all_estates_count();
@@ -830,6 +1395,7 @@ pub mod pallet {
 			land_units: Vec<(i32, i32)>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
 
 			ensure!(
 				!T::AuctionHandler::check_item_in_auction(ItemId::Estate(estate_id)),
@@ -845,6 +1411,8 @@ pub mod pallet {
 				Error::<T>::NoPermission
 			);
 
+			Self::ensure_land_tax_settled(estate_id)?;
+
 			// Check land unit ownership
 			for land_unit in land_units.clone() {
 				ensure!(
@@ -882,6 +1450,8 @@ pub mod pallet {
 					);
 				}
 
+				Self::record_estate_activity(estate_id);
+
 				Self::deposit_event(Event::<T>::LandUnitAdded(
 					estate_id.clone(),
 					estate_owner_value.clone(),
@@ -900,11 +1470,16 @@ pub mod pallet {
 			land_units: Vec<(i32, i32)>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
 
 			ensure!(
 				!T::AuctionHandler::check_item_in_auction(ItemId::Estate(estate_id)),
 				Error::<T>::EstateAlreadyInAuction
 			);
+			ensure!(
+				!Self::is_estate_collateralized(estate_id),
+				Error::<T>::EstateIsCollateralized
+			);
 
 			let estate_info: EstateInfo = Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
 
@@ -915,6 +1490,8 @@ pub mod pallet {
 				Error::<T>::NoPermission
 			);
 
+			Self::ensure_land_tax_settled(estate_id)?;
+
 			// Mutate estates
 			Estates::<T>::try_mutate_exists(&estate_id, |maybe_estate_info| {
 				let mut mut_estate_info = maybe_estate_info.as_mut().ok_or(Error::<T>::EstateDoesNotExist)?;
@@ -936,6 +1513,8 @@ pub mod pallet {
 					);
 				}
 
+				Self::record_estate_activity(estate_id);
+
 				Self::deposit_event(Event::<T>::LandUnitsRemoved(
 					estate_id.clone(),
 					estate_owner_value.clone(),
@@ -950,6 +1529,7 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::bond_more())]
 		pub fn bond_more(origin: OriginFor<T>, estate_id: EstateId, more: BalanceOf<T>) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
 
 			Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
 
@@ -976,6 +1556,7 @@ pub mod pallet {
 			T::Currency::reserve(&who, more)?;
 
 			<EstateStake<T>>::insert(estate_id, &who, total);
+			Self::increase_staker_estate_stake(&who, more);
 
 			// Update TotalStake
 			let new_total_staked = <TotalStake<T>>::get().saturating_add(more);
@@ -990,6 +1571,7 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::bond_less())]
 		pub fn bond_less(origin: OriginFor<T>, estate_id: EstateId, less: BalanceOf<T>) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
 
 			Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
 
@@ -1016,6 +1598,7 @@ pub mod pallet {
 			T::Currency::unreserve(&who, less);
 
 			<EstateStake<T>>::insert(estate_id, &who, remaining);
+			Self::decrease_staker_estate_stake(&who, less);
 
 			// Update TotalStake
 			let new_total_staked = <TotalStake<T>>::get().saturating_sub(less);
@@ -1030,6 +1613,7 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::leave_staking())]
 		pub fn leave_staking(origin: OriginFor<T>, estate_id: EstateId) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
 			let estate_owner_value = Self::get_estate_owner(&estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
 			ensure!(
 				Self::check_if_land_or_estate_owner(&who, &estate_owner_value, true),
@@ -1054,84 +1638,1980 @@ pub mod pallet {
 
 			Ok(().into())
 		}
-	}
-
-	impl<T: Config> Pallet<T> {
-		fn pay_stakers(next: RoundIndex) {
-			// payout is next - duration rounds ago => next - duration > 0 else return early
-			let duration = T::RewardPaymentDelay::get();
-			if next <= duration {
-				return;
-			}
-			let round_to_payout = next - duration;
 
-			// issue BIT for rewards distribution
-			let total_staked = <Staked<T>>::get(round_to_payout);
-			let total_issuance = Self::compute_issuance(total_staked);
+		/// Set or remove idle land tax of a metaverse
+		#[pallet::weight(T::WeightInfo::set_land_tax())]
+		pub fn set_land_tax(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			land_tax: Option<LandTaxInfo<BalanceOf<T>>>,
+		) -> DispatchResultWithPostInfo {
+			T::CouncilOrigin::ensure_origin(origin)?;
 
-			let mut left_issuance = total_issuance;
+			match land_tax.clone() {
+				Some(tax) => LandTax::<T>::insert(metaverse_id, tax),
+				None => LandTax::<T>::remove(metaverse_id),
+			}
 
-			// a local fn to transfer rewards to the account specified
-			let mint = |amt: BalanceOf<T>, to: T::AccountId| {
-				if let Ok(imb) = T::Currency::deposit_into_existing(&to, amt) {
-					Self::deposit_event(Event::StakingRewarded(to.clone(), imb.peek()));
-				}
-			};
+			Self::deposit_event(Event::<T>::LandTaxUpdated(metaverse_id, land_tax));
 
-			for (estate_id, stake_snapshot) in <AtStake<T>>::drain_prefix(round_to_payout) {
-				for Bond { staker: owner, amount } in stake_snapshot.stakers {
-					// TODO: TBD on the rewards amount
-					let amount_due = amount;
-					mint(1u32.into(), owner);
-				}
-			}
+			Ok(().into())
 		}
 
-		/// Clear exit queue. return stake to account
-		fn clear_exit_queue(now: RoundIndex) {
-			for (account_id, estate_id, val) in <ExitQueue<T>>::drain() {
-				let staked_amount = <EstateStake<T>>::get(estate_id, &account_id);
+		/// Exempt estate from idle land tax or remove its exemption
+		#[pallet::weight(T::WeightInfo::set_land_tax_exemption())]
+		pub fn set_land_tax_exemption(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			is_exempted: bool,
+		) -> DispatchResultWithPostInfo {
+			T::CouncilOrigin::ensure_origin(origin)?;
 
-				// return stake to account
-				T::Currency::unreserve(&account_id, staked_amount);
+			ensure!(Estates::<T>::contains_key(estate_id), Error::<T>::EstateDoesNotExist);
 
-				<EstateStake<T>>::remove(estate_id, &account_id);
+			if is_exempted {
+				LandTaxExemption::<T>::insert(estate_id, ());
+			} else {
+				LandTaxExemption::<T>::remove(estate_id);
+				// Exempted period does not count as idle time
+				Self::record_estate_activity(estate_id);
 			}
-		}
 
-		fn update_stake_snapshot(next: RoundIndex) -> BalanceOf<T> {
-			let mut total = BalanceOf::<T>::zero();
+			Self::deposit_event(Event::<T>::LandTaxExemptionUpdated(estate_id, is_exempted));
 
-			for estate_id in <Estates<T>>::iter_keys() {
-				let mut total_bond = BalanceOf::<T>::zero();
+			Ok(().into())
+		}
+
+		/// Pay outstanding idle land tax of the estate to the metaverse treasury
+		#[pallet::weight(T::WeightInfo::pay_land_tax())]
+		pub fn pay_land_tax(origin: OriginFor<T>, estate_id: EstateId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
+
+			let estate_info: EstateInfo = Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+
+			// Check estate ownership, co-owners can pay land tax of jointly owned estate
+			let estate_owner_value = Self::get_estate_owner(&estate_id).ok_or(Error::<T>::NoPermission)?;
+			ensure!(
+				Self::check_if_land_or_estate_owner(&who, &estate_owner_value, true)
+					|| Self::get_estate_co_ownership(estate_id).map_or(false, |info| info.co_owners.contains(&who)),
+				Error::<T>::NoPermission
+			);
+
+			let tax_due = Self::land_tax_due(estate_id);
+			ensure!(!tax_due.is_zero(), Error::<T>::NoLandTaxDue);
+
+			T::Currency::transfer(
+				&who,
+				&T::MetaverseInfoSource::get_metaverse_treasury(estate_info.metaverse_id),
+				tax_due,
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			// Paying tax settles the taxed rounds without granting a new grace period,
+			// the estate keeps accruing tax every round until it has real activity.
+			if let Some(land_tax) = LandTax::<T>::get(estate_info.metaverse_id) {
+				let grace_period = Self::round_length().saturating_mul(land_tax.idle_rounds.into());
+				let now = <frame_system::Pallet<T>>::block_number();
+				EstateLastActivity::<T>::insert(estate_id, now.saturating_sub(grace_period));
+			}
+
+			Self::deposit_event(Event::<T>::LandTaxPaid(estate_id, who, tax_due));
+
+			Ok(().into())
+		}
+
+		/// Designate or remove the beneficiary who can claim the estate after the owner has been
+		/// inactive for `inactivity_period` blocks
+		#[pallet::weight(T::WeightInfo::set_estate_beneficiary())]
+		pub fn set_estate_beneficiary(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			beneficiary: Option<T::AccountId>,
+			inactivity_period: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			// Check estate ownership
+			let estate_owner_value = Self::get_estate_owner(&estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+			ensure!(
+				Self::check_if_land_or_estate_owner(&who, &estate_owner_value, true),
+				Error::<T>::NoPermission
+			);
+
+			match beneficiary.clone() {
+				Some(beneficiary) => {
+					ensure!(!inactivity_period.is_zero(), Error::<T>::InvalidInactivityPeriod);
+					EstateBeneficiaries::<T>::insert(
+						estate_id,
+						EstateBeneficiaryInfo {
+							owner: who.clone(),
+							beneficiary,
+							inactivity_period,
+						},
+					);
+				}
+				None => EstateBeneficiaries::<T>::remove(estate_id),
+			}
+
+			Self::record_heartbeat(&who);
+
+			Self::deposit_event(Event::<T>::EstateBeneficiarySet(estate_id, who, beneficiary));
+
+			Ok(().into())
+		}
+
+		/// Prove that the estate owner is still active
+		#[pallet::weight(T::WeightInfo::ping())]
+		pub fn ping(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::record_heartbeat(&who);
+
+			Self::deposit_event(Event::<T>::HeartbeatReceived(
+				who,
+				<frame_system::Pallet<T>>::block_number(),
+			));
+
+			Ok(().into())
+		}
+
+		/// Beneficiary claims the estate of an inactive owner
+		#[pallet::weight(T::WeightInfo::claim_inherited_estate())]
+		pub fn claim_inherited_estate(origin: OriginFor<T>, estate_id: EstateId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let beneficiary_info = EstateBeneficiaries::<T>::get(estate_id).ok_or(Error::<T>::NoEstateBeneficiary)?;
+			ensure!(beneficiary_info.beneficiary == who, Error::<T>::NoPermission);
+
+			ensure!(
+				!T::AuctionHandler::check_item_in_auction(ItemId::Estate(estate_id)),
+				Error::<T>::EstateAlreadyInAuction
+			);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let inactive_since = LastHeartbeat::<T>::get(&beneficiary_info.owner);
+			ensure!(
+				now.saturating_sub(inactive_since) >= beneficiary_info.inactivity_period,
+				Error::<T>::EstateOwnerStillActive
+			);
+
+			Self::ensure_land_tax_settled(estate_id)?;
+
+			// Ownership is checked against the owner who designated the beneficiary
+			Self::do_transfer_estate(estate_id, &beneficiary_info.owner, &who)?;
+
+			Self::deposit_event(Event::<T>::EstateInherited(estate_id, beneficiary_info.owner, who));
+
+			Ok(().into())
+		}
+
+		/// Set metadata URI or CID of the estate
+		#[pallet::weight(T::WeightInfo::set_estate_metadata())]
+		pub fn set_estate_metadata(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			metadata: NftMetadata,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
+
+			Self::ensure_estate_metadata_updatable(&who, estate_id)?;
+			ensure!(
+				metadata.len() as u32 <= T::MaxEstateMetadata::get(),
+				Error::<T>::ExceedMaximumMetadataLength
+			);
+
+			EstateMetadata::<T>::insert(estate_id, metadata.clone());
+
+			Self::deposit_event(Event::<T>::EstateMetadataUpdated(estate_id, metadata));
+
+			Ok(().into())
+		}
+
+		/// Set or remove an attribute of the estate
+		#[pallet::weight(T::WeightInfo::set_estate_attribute())]
+		pub fn set_estate_attribute(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			key: Vec<u8>,
+			value: Option<EstateAttributeValue>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
+
+			Self::ensure_estate_metadata_updatable(&who, estate_id)?;
+			ensure!(
+				key.len() as u32 <= T::MaxEstateMetadata::get(),
+				Error::<T>::ExceedMaximumMetadataLength
+			);
+			if let Some(EstateAttributeValue::Text(text)) = &value {
+				ensure!(
+					text.len() as u32 <= T::MaxEstateMetadata::get(),
+					Error::<T>::ExceedMaximumMetadataLength
+				);
+			}
+
+			EstateAttributesOf::<T>::try_mutate(estate_id, |attributes| -> DispatchResult {
+				match value.clone() {
+					Some(value) => {
+						attributes.insert(key.clone(), value);
+						ensure!(
+							attributes.len() as u32 <= T::MaxEstateAttributes::get(),
+							Error::<T>::ExceedMaximumAttributes
+						);
+					}
+					None => {
+						attributes.remove(&key);
+					}
+				}
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::EstateAttributeUpdated(estate_id, key, value));
+
+			Ok(().into())
+		}
+
+		/// Permanently freeze metadata and attributes of the estate
+		#[pallet::weight(T::WeightInfo::freeze_estate_metadata())]
+		pub fn freeze_estate_metadata(origin: OriginFor<T>, estate_id: EstateId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
+
+			Self::ensure_estate_metadata_updatable(&who, estate_id)?;
+
+			FrozenEstateMetadata::<T>::insert(estate_id, ());
+
+			Self::deposit_event(Event::<T>::EstateMetadataFrozen(estate_id));
+
+			Ok(().into())
+		}
+
+		/// Force transfer NFT, estate or land unit on a court order, the justification hash references
+		/// the off-chain order and is kept in the audit event
+		#[pallet::weight(T::WeightInfo::force_transfer_asset())]
+		pub fn force_transfer_asset(
+			origin: OriginFor<T>,
+			asset: ItemId,
+			from: T::AccountId,
+			to: T::AccountId,
+			justification: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			T::ForceTransferOrigin::ensure_origin(origin)?;
+			ensure!(T::ForceTransferEnabled::get(), Error::<T>::ForceTransferDisabled);
+
+			ensure!(
+				!T::AuctionHandler::check_item_in_auction(asset),
+				Error::<T>::EstateAlreadyInAuction
+			);
+
+			match asset {
+				ItemId::NFT(class_id, token_id) => {
+					ensure!(
+						T::NFTTokenizationSource::check_ownership(&from, &(class_id, token_id))?,
+						Error::<T>::NoPermission
+					);
+					T::NFTTokenizationSource::transfer_nft(&from, &to, &(class_id, token_id))?;
+				}
+				ItemId::Estate(estate_id) => {
+					Self::do_transfer_estate(estate_id, &from, &to)?;
+				}
+				ItemId::LandUnit(coordinate, metaverse_id) => {
+					Self::do_transfer_landunit(coordinate, &from, &to, metaverse_id)?;
+				}
+				_ => return Err(Error::<T>::InvalidForceTransferAsset.into()),
+			}
+
+			Self::deposit_event(Event::<T>::AssetForceTransferred(asset, from, to, justification));
+
+			Ok(().into())
+		}
+
+		/// Convert the estate into jointly owned estate, the estate is then held by the estate
+		/// account and transfers, listings, leases and land deployments require approval of
+		/// `threshold` co-owners
+		#[pallet::weight(T::WeightInfo::set_estate_co_owners())]
+		#[transactional]
+		pub fn set_estate_co_owners(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			co_owners: Vec<T::AccountId>,
+			threshold: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
+
+			ensure!(
+				!T::AuctionHandler::check_item_in_auction(ItemId::Estate(estate_id)),
+				Error::<T>::EstateAlreadyInAuction
+			);
+			Self::ensure_land_tax_settled(estate_id)?;
+
+			ensure!(
+				co_owners.len() >= 2 && co_owners.len() as u32 <= T::MaxCoOwners::get(),
+				Error::<T>::InvalidCoOwners
+			);
+			for (index, co_owner) in co_owners.iter().enumerate() {
+				ensure!(!co_owners[..index].contains(co_owner), Error::<T>::InvalidCoOwners);
+			}
+			ensure!(
+				threshold > 0 && threshold as usize <= co_owners.len(),
+				Error::<T>::InvalidCoOwnershipThreshold
+			);
+
+			// Ownership is checked on transfer
+			let estate_account_id: T::AccountId = T::LandTreasury::get().into_sub_account(estate_id);
+			Self::do_transfer_estate(estate_id, &who, &estate_account_id)?;
+
+			EstateCoOwnership::<T>::insert(
+				estate_id,
+				EstateCoOwnershipInfo {
+					co_owners: co_owners.clone(),
+					threshold,
+				},
+			);
+			CoOwnershipOpeningBalance::<T>::insert(estate_id, T::Currency::free_balance(&estate_account_id));
+
+			Self::deposit_event(Event::<T>::EstateCoOwnershipSet(estate_id, co_owners, threshold));
+
+			Ok(().into())
+		}
+
+		/// Co-owner proposes an action on jointly owned estate, the proposal counts as approval
+		#[pallet::weight(T::WeightInfo::propose_co_ownership_action())]
+		#[transactional]
+		pub fn propose_co_ownership_action(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			action: CoOwnershipAction<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
+
+			let co_ownership = Self::get_estate_co_ownership(estate_id).ok_or(Error::<T>::EstateIsNotCoOwned)?;
+			ensure!(co_ownership.co_owners.contains(&who), Error::<T>::NotCoOwner);
+			ensure!(
+				!CoOwnershipProposals::<T>::contains_key(estate_id),
+				Error::<T>::CoOwnershipProposalPending
+			);
+
+			CoOwnershipProposals::<T>::insert(
+				estate_id,
+				CoOwnershipProposal {
+					action: action.clone(),
+					approvals: sp_std::vec![who.clone()],
+					proposed_at: <frame_system::Pallet<T>>::block_number(),
+				},
+			);
+
+			Self::deposit_event(Event::<T>::CoOwnershipActionProposed(estate_id, who, action));
+
+			Self::try_execute_co_ownership_proposal(estate_id, co_ownership.threshold)?;
+
+			Ok(().into())
+		}
+
+		/// Co-owner approves pending action, the action is executed once the threshold is reached
+		#[pallet::weight(T::WeightInfo::approve_co_ownership_action())]
+		#[transactional]
+		pub fn approve_co_ownership_action(origin: OriginFor<T>, estate_id: EstateId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
+
+			let co_ownership = Self::get_estate_co_ownership(estate_id).ok_or(Error::<T>::EstateIsNotCoOwned)?;
+			ensure!(co_ownership.co_owners.contains(&who), Error::<T>::NotCoOwner);
+
+			CoOwnershipProposals::<T>::try_mutate(estate_id, |maybe_proposal| -> DispatchResult {
+				let proposal = maybe_proposal.as_mut().ok_or(Error::<T>::NoCoOwnershipProposal)?;
+				ensure!(
+					!proposal.approvals.contains(&who),
+					Error::<T>::AlreadyApprovedCoOwnershipProposal
+				);
+				proposal.approvals.push(who.clone());
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::CoOwnershipActionApproved(estate_id, who));
+
+			Self::try_execute_co_ownership_proposal(estate_id, co_ownership.threshold)?;
+
+			Ok(().into())
+		}
+
+		/// Governance breaks deadlock of co-owners by executing or discarding a proposal which has
+		/// been pending longer than the deadlock period
+		#[pallet::weight(T::WeightInfo::resolve_co_ownership_deadlock())]
+		#[transactional]
+		pub fn resolve_co_ownership_deadlock(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			execute: bool,
+		) -> DispatchResultWithPostInfo {
+			T::CouncilOrigin::ensure_origin(origin)?;
+
+			let proposal = Self::get_co_ownership_proposal(estate_id).ok_or(Error::<T>::NoCoOwnershipProposal)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				now.saturating_sub(proposal.proposed_at) >= T::CoOwnershipDeadlockPeriod::get(),
+				Error::<T>::CoOwnershipDeadlockPeriodNotPassed
+			);
+
+			CoOwnershipProposals::<T>::remove(estate_id);
+			if execute {
+				Self::do_execute_co_ownership_action(estate_id, proposal.action, proposal.approvals)?;
+			}
+
+			Self::deposit_event(Event::<T>::CoOwnershipDeadlockResolved(estate_id, execute));
+
+			Ok(().into())
+		}
+
+		/// Post lease terms of the estate on the lease board
+		#[pallet::weight(T::WeightInfo::post_lease_offer())]
+		pub fn post_lease_offer(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			rent_per_block: BalanceOf<T>,
+			duration: T::BlockNumber,
+			min_deposit: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
+
+			let estate_owner_value = Self::get_estate_owner(&estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+			ensure!(
+				Self::check_if_land_or_estate_owner(&who, &estate_owner_value, true),
+				Error::<T>::NoPermission
+			);
+
+			Self::do_post_lease_offer(&who, estate_id, rent_per_block, duration, min_deposit)?;
+
+			Ok(().into())
+		}
+
+		/// Remove lease terms of the estate from the lease board
+		#[pallet::weight(T::WeightInfo::cancel_lease_offer())]
+		pub fn cancel_lease_offer(origin: OriginFor<T>, estate_id: EstateId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
+
+			let lease_offer = Self::get_lease_offer(estate_id).ok_or(Error::<T>::LeaseOfferNotFound)?;
+			ensure!(lease_offer.landlord == who, Error::<T>::NoPermission);
+
+			LeaseOffers::<T>::remove(estate_id);
+
+			Self::deposit_event(Event::<T>::LeaseOfferCancelled(estate_id));
+
+			Ok(().into())
+		}
+
+		/// Accept lease terms on the lease board by locking deposit, rent is streamed to the
+		/// landlord every block and the tenant is evicted once the deposit is exhausted
+		#[pallet::weight(T::WeightInfo::accept_lease_offer())]
+		#[transactional]
+		pub fn accept_lease_offer(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			deposit: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let lease_offer = LeaseOffers::<T>::take(estate_id).ok_or(Error::<T>::LeaseOfferNotFound)?;
+			let estate_info = Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+			ensure!(
+				!T::MetaverseInfoSource::is_metaverse_sunset(estate_info.metaverse_id),
+				Error::<T>::MetaverseIsInSunset
+			);
+			ensure!(!Self::is_frozen(estate_id), Error::<T>::EstateIsFrozen);
+			ensure!(lease_offer.landlord != who, Error::<T>::CannotLeaseOwnEstate);
+			ensure!(deposit >= lease_offer.min_deposit, Error::<T>::LeaseDepositTooLow);
+
+			T::Currency::reserve(&who, deposit)?;
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let lease = LeaseInfo {
+				landlord: lease_offer.landlord.clone(),
+				tenant: who.clone(),
+				rent_per_block: lease_offer.rent_per_block,
+				deposit,
+				last_collected: now,
+				end_block: now.saturating_add(lease_offer.duration),
+			};
+			LeaseExpiries::<T>::insert(Self::lease_eviction_block(&lease), estate_id, ());
+			EstateLeases::<T>::insert(estate_id, lease);
+
+			Self::deposit_event(Event::<T>::EstateLeased(estate_id, lease_offer.landlord, who, deposit));
+
+			Ok(().into())
+		}
+
+		/// Tenant locks more deposit to postpone eviction
+		#[pallet::weight(T::WeightInfo::top_up_lease_deposit())]
+		#[transactional]
+		pub fn top_up_lease_deposit(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			EstateLeases::<T>::try_mutate(estate_id, |maybe_lease| -> DispatchResult {
+				let lease = maybe_lease.as_mut().ok_or(Error::<T>::EstateNotLeased)?;
+				ensure!(lease.tenant == who, Error::<T>::NoPermission);
+
+				T::Currency::reserve(&who, amount)?;
+
+				LeaseExpiries::<T>::remove(Self::lease_eviction_block(lease), estate_id);
+				lease.deposit = lease.deposit.saturating_add(amount);
+				LeaseExpiries::<T>::insert(Self::lease_eviction_block(lease), estate_id, ());
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::LeaseDepositToppedUp(estate_id, who, amount));
+
+			Ok(().into())
+		}
+
+		/// Transfer rent accrued since the last collection from the tenant deposit to the landlord
+		#[pallet::weight(T::WeightInfo::collect_rent())]
+		pub fn collect_rent(origin: OriginFor<T>, estate_id: EstateId) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			EstateLeases::<T>::try_mutate(estate_id, |maybe_lease| -> DispatchResult {
+				let lease = maybe_lease.as_mut().ok_or(Error::<T>::EstateNotLeased)?;
+				Self::do_collect_rent(estate_id, lease);
+				Ok(())
+			})?;
+
+			Ok(().into())
+		}
+
+		/// Lock the estate as collateral and request a loan from a specific lender, or from any
+		/// lender such as a lending pool account when no lender is set
+		#[pallet::weight(T::WeightInfo::request_estate_loan())]
+		#[transactional]
+		pub fn request_estate_loan(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			lender: Option<T::AccountId>,
+			principal: BalanceOf<T>,
+			repayment: BalanceOf<T>,
+			duration: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
+
+			let estate_owner_value = Self::get_estate_owner(&estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+			ensure!(
+				Self::check_if_land_or_estate_owner(&who, &estate_owner_value, true),
+				Error::<T>::NoPermission
+			);
+			ensure!(
+				!T::AuctionHandler::check_item_in_auction(ItemId::Estate(estate_id)),
+				Error::<T>::EstateAlreadyInAuction
+			);
+			ensure!(
+				!Self::is_estate_collateralized(estate_id),
+				Error::<T>::EstateIsCollateralized
+			);
+			ensure!(lender.as_ref() != Some(&who), Error::<T>::CannotLendToSelf);
+			ensure!(
+				!principal.is_zero() && repayment >= principal && !duration.is_zero(),
+				Error::<T>::InvalidLoanTerms
+			);
+
+			LoanRequests::<T>::insert(
+				estate_id,
+				EstateLoanRequest {
+					borrower: who.clone(),
+					lender,
+					principal,
+					repayment,
+					duration,
+				},
+			);
+			Self::update_estate_token_lock(estate_id)?;
+
+			Self::deposit_event(Event::<T>::EstateLoanRequested(estate_id, who, principal, repayment));
+
+			Ok(().into())
+		}
+
+		/// Cancel loan request which has not been funded and unlock the estate
+		#[pallet::weight(T::WeightInfo::cancel_estate_loan_request())]
+		#[transactional]
+		pub fn cancel_estate_loan_request(origin: OriginFor<T>, estate_id: EstateId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
+
+			let loan_request = Self::get_loan_request(estate_id).ok_or(Error::<T>::LoanRequestNotFound)?;
+			ensure!(loan_request.borrower == who, Error::<T>::NoPermission);
+
+			LoanRequests::<T>::remove(estate_id);
+			Self::update_estate_token_lock(estate_id)?;
+
+			Self::deposit_event(Event::<T>::EstateLoanRequestCancelled(estate_id));
+
+			Ok(().into())
+		}
+
+		/// Fund loan request, the principal is transferred to the borrower and the estate stays
+		/// locked until the loan is repaid or liquidated. The lender states the terms they agree to,
+		/// so a request changed by the borrower in the meantime is not funded
+		#[pallet::weight(T::WeightInfo::fund_estate_loan())]
+		#[transactional]
+		pub fn fund_estate_loan(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			principal: BalanceOf<T>,
+			repayment: BalanceOf<T>,
+			duration: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let loan_request = LoanRequests::<T>::take(estate_id).ok_or(Error::<T>::LoanRequestNotFound)?;
+			ensure!(loan_request.borrower != who, Error::<T>::CannotLendToSelf);
+			if let Some(lender) = &loan_request.lender {
+				ensure!(*lender == who, Error::<T>::NoPermission);
+			}
+			ensure!(
+				loan_request.principal == principal
+					&& loan_request.repayment == repayment
+					&& loan_request.duration == duration,
+				Error::<T>::LoanTermsMismatch
+			);
+
+			T::Currency::transfer(
+				&who,
+				&loan_request.borrower,
+				loan_request.principal,
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			let deadline = <frame_system::Pallet<T>>::block_number().saturating_add(loan_request.duration);
+			EstateLoans::<T>::insert(
+				estate_id,
+				EstateLoanInfo {
+					borrower: loan_request.borrower.clone(),
+					lender: who.clone(),
+					repayment: loan_request.repayment,
+					deadline,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::EstateLoanFunded(
+				estate_id,
+				loan_request.borrower,
+				who,
+				deadline,
+			));
+
+			Ok(().into())
+		}
+
+		/// Repay the loan to the lender and unlock the estate, any account can repay on behalf of
+		/// the borrower
+		#[pallet::weight(T::WeightInfo::repay_estate_loan())]
+		#[transactional]
+		pub fn repay_estate_loan(origin: OriginFor<T>, estate_id: EstateId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
+
+			let estate_loan = EstateLoans::<T>::take(estate_id).ok_or(Error::<T>::EstateLoanNotFound)?;
+
+			T::Currency::transfer(
+				&who,
+				&estate_loan.lender,
+				estate_loan.repayment,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			Self::update_estate_token_lock(estate_id)?;
+
+			Self::deposit_event(Event::<T>::EstateLoanRepaid(estate_id, who, estate_loan.repayment));
+
+			Ok(().into())
+		}
+
+		/// Lender takes over the estate once the repayment deadline has passed
+		#[pallet::weight(T::WeightInfo::liquidate_estate_loan())]
+		#[transactional]
+		pub fn liquidate_estate_loan(origin: OriginFor<T>, estate_id: EstateId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let estate_loan = EstateLoans::<T>::take(estate_id).ok_or(Error::<T>::EstateLoanNotFound)?;
+			ensure!(estate_loan.lender == who, Error::<T>::NoPermission);
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() > estate_loan.deadline,
+				Error::<T>::LoanRepaymentDeadlineNotPassed
+			);
+
+			Self::update_estate_token_lock(estate_id)?;
+			Self::do_transfer_estate(estate_id, &estate_loan.borrower, &who)?;
+
+			Self::deposit_event(Event::<T>::EstateLoanLiquidated(estate_id, estate_loan.borrower, who));
+
+			Ok(().into())
+		}
+
+		/// Metaverse owner turns whitelist mode for land deployment on or off
+		#[pallet::weight(T::WeightInfo::set_deployment_whitelist())]
+		pub fn set_deployment_whitelist(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			enabled: bool,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				T::MetaverseInfoSource::check_ownership(&who, &metaverse_id),
+				Error::<T>::NoPermission
+			);
+
+			if enabled {
+				DeploymentWhitelistEnabled::<T>::insert(metaverse_id, ());
+			} else {
+				DeploymentWhitelistEnabled::<T>::remove(metaverse_id);
+			}
+
+			Self::deposit_event(Event::<T>::DeploymentWhitelistUpdated(metaverse_id, enabled));
+
+			Ok(().into())
+		}
+
+		/// Metaverse owner approves account to deploy land blocks in the metaverse
+		#[pallet::weight(T::WeightInfo::add_deployer())]
+		pub fn add_deployer(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			deployer: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				T::MetaverseInfoSource::check_ownership(&who, &metaverse_id),
+				Error::<T>::NoPermission
+			);
+			ensure!(
+				!LandDeployers::<T>::contains_key(metaverse_id, &deployer),
+				Error::<T>::LandDeployerAlreadyExists
+			);
+
+			LandDeployers::<T>::insert(metaverse_id, &deployer, ());
+
+			Self::deposit_event(Event::<T>::LandDeployerAdded(metaverse_id, deployer));
+
+			Ok(().into())
+		}
+
+		/// Metaverse owner revokes land deployment approval of account
+		#[pallet::weight(T::WeightInfo::remove_deployer())]
+		pub fn remove_deployer(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			deployer: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				T::MetaverseInfoSource::check_ownership(&who, &metaverse_id),
+				Error::<T>::NoPermission
+			);
+			ensure!(
+				LandDeployers::<T>::contains_key(metaverse_id, &deployer),
+				Error::<T>::LandDeployerNotFound
+			);
+
+			LandDeployers::<T>::remove(metaverse_id, &deployer);
+
+			Self::deposit_event(Event::<T>::LandDeployerRemoved(metaverse_id, deployer));
+
+			Ok(().into())
+		}
+
+		/// Metaverse owner sets the requirement buyers have to meet to purchase land in the
+		/// metaverse, none lifts the gate
+		#[pallet::weight(T::WeightInfo::set_land_buyer_gate())]
+		pub fn set_land_buyer_gate(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			gate: Option<LandBuyerGate>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				T::MetaverseInfoSource::check_ownership(&who, &metaverse_id),
+				Error::<T>::NoPermission
+			);
+
+			match &gate {
+				Some(gate) => LandBuyerGates::<T>::insert(metaverse_id, gate),
+				None => LandBuyerGates::<T>::remove(metaverse_id),
+			}
+
+			Self::deposit_event(Event::<T>::LandBuyerGateUpdated(metaverse_id, gate));
+
+			Ok(().into())
+		}
+
+		/// Metaverse owner approves account to buy land in the metaverse
+		#[pallet::weight(T::WeightInfo::add_land_buyer())]
+		pub fn add_land_buyer(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			buyer: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				T::MetaverseInfoSource::check_ownership(&who, &metaverse_id),
+				Error::<T>::NoPermission
+			);
+			ensure!(
+				!LandBuyers::<T>::contains_key(metaverse_id, &buyer),
+				Error::<T>::LandBuyerAlreadyExists
+			);
+
+			LandBuyers::<T>::insert(metaverse_id, &buyer, ());
+
+			Self::deposit_event(Event::<T>::LandBuyerAdded(metaverse_id, buyer));
+
+			Ok(().into())
+		}
+
+		/// Metaverse owner revokes land purchase approval of account
+		#[pallet::weight(T::WeightInfo::remove_land_buyer())]
+		pub fn remove_land_buyer(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			buyer: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				T::MetaverseInfoSource::check_ownership(&who, &metaverse_id),
+				Error::<T>::NoPermission
+			);
+			ensure!(
+				LandBuyers::<T>::contains_key(metaverse_id, &buyer),
+				Error::<T>::LandBuyerNotFound
+			);
+
+			LandBuyers::<T>::remove(metaverse_id, &buyer);
+
+			Self::deposit_event(Event::<T>::LandBuyerRemoved(metaverse_id, buyer));
+
+			Ok(().into())
+		}
+
+		/// Council sets the amount of mining resource burned to upgrade a land unit to a level
+		#[pallet::weight(T::WeightInfo::set_land_unit_upgrade_cost())]
+		pub fn set_land_unit_upgrade_cost(
+			origin: OriginFor<T>,
+			level: u32,
+			cost: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::CouncilOrigin::ensure_origin(origin)?;
+			ensure!(
+				level > 0 && level <= T::MaxLandUnitLevel::get(),
+				Error::<T>::InvalidLandUnitLevel
+			);
+
+			LandUnitUpgradeCosts::<T>::insert(level, cost);
+
+			Self::deposit_event(Event::<T>::LandUnitUpgradeCostUpdated(level, cost));
+
+			Ok(().into())
+		}
+
+		/// Land unit owner burns mining resource to raise the level of the land unit
+		#[pallet::weight(T::WeightInfo::upgrade_land_unit())]
+		#[transactional]
+		pub fn upgrade_land_unit(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			coordinate: (i32, i32),
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
+
+			let land_unit_owner =
+				Self::get_land_units(metaverse_id, coordinate).ok_or(Error::<T>::LandUnitDoesNotExist)?;
+			// Land units of an estate are held by the estate account and upgraded by the estate owner
+			let is_estate_owner = Self::get_land_unit_estate(metaverse_id, coordinate, &land_unit_owner)
+				.map_or(false, |estate_id| {
+					Self::check_estate_ownership(&who, estate_id).unwrap_or(false)
+				});
+			ensure!(
+				is_estate_owner || Self::check_if_land_or_estate_owner(&who, &land_unit_owner, false),
+				Error::<T>::NoPermission
+			);
+
+			let next_level = Self::get_land_unit_level(metaverse_id, coordinate).saturating_add(1);
+			ensure!(
+				next_level <= T::MaxLandUnitLevel::get(),
+				Error::<T>::LandUnitMaxLevelReached
+			);
+
+			let cost = Self::get_land_unit_upgrade_cost(next_level).ok_or(Error::<T>::LandUnitUpgradeCostNotSet)?;
+			T::FungibleTokenCurrency::withdraw(T::MiningResourceId::get(), &who, cost)?;
+			T::MiningBurnRecorder::record_mining_burn(cost.saturated_into());
+
+			LandUnitLevels::<T>::insert(metaverse_id, coordinate, next_level);
+
+			Self::deposit_event(Event::<T>::LandUnitUpgraded(
+				who,
+				metaverse_id,
+				coordinate,
+				next_level,
+				cost,
+			));
+
+			Ok(().into())
+		}
+
+		/// Council freezes a disputed estate, blocking transfers, listings and leases until it is
+		/// unfrozen
+		#[pallet::weight(T::WeightInfo::freeze_estate())]
+		#[transactional]
+		pub fn freeze_estate(origin: OriginFor<T>, estate_id: EstateId) -> DispatchResultWithPostInfo {
+			T::CouncilOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				!T::AuctionHandler::check_item_in_auction(ItemId::Estate(estate_id)),
+				Error::<T>::EstateAlreadyInAuction
+			);
+			let estate_owner = Self::get_estate_owner(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+			ensure!(!FrozenEstates::<T>::contains_key(estate_id), Error::<T>::EstateIsFrozen);
+
+			if let OwnerId::Token(token_id) = estate_owner {
+				ensure!(
+					!T::AuctionHandler::check_item_in_auction(ItemId::NFT(ESTATE_CLASS_ID, token_id)),
+					Error::<T>::EstateAlreadyInAuction
+				);
+				T::NFTTokenizationSource::set_freeze_nft(&(ESTATE_CLASS_ID, token_id), true)?;
+			}
+			FrozenEstates::<T>::insert(estate_id, ());
+			LeaseOffers::<T>::remove(estate_id);
+
+			Self::deposit_event(Event::<T>::EstateFrozen(estate_id));
+
+			Ok(().into())
+		}
+
+		/// Council unfreezes an estate once the dispute is resolved
+		#[pallet::weight(T::WeightInfo::unfreeze_estate())]
+		#[transactional]
+		pub fn unfreeze_estate(origin: OriginFor<T>, estate_id: EstateId) -> DispatchResultWithPostInfo {
+			T::CouncilOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				FrozenEstates::<T>::contains_key(estate_id),
+				Error::<T>::EstateIsNotFrozen
+			);
+
+			FrozenEstates::<T>::remove(estate_id);
+			if let Some(OwnerId::Token(token_id)) = Self::get_estate_owner(estate_id) {
+				T::NFTTokenizationSource::set_freeze_nft(&(ESTATE_CLASS_ID, token_id), false)?;
+			}
+
+			Self::deposit_event(Event::<T>::EstateUnfrozen(estate_id));
+
+			Ok(().into())
+		}
+
+		/// Council sets the royalty paid to a recipient on marketplace sales of an estate, or
+		/// removes it if none
+		#[pallet::weight(T::WeightInfo::set_estate_royalty())]
+		pub fn set_estate_royalty(
+			origin: OriginFor<T>,
+			estate_id: EstateId,
+			royalty: Option<(T::AccountId, Perbill)>,
+		) -> DispatchResultWithPostInfo {
+			T::CouncilOrigin::ensure_origin(origin)?;
+
+			ensure!(Estates::<T>::contains_key(estate_id), Error::<T>::EstateDoesNotExist);
+			if let Some((_, share)) = royalty {
+				ensure!(
+					share <= Perbill::from_percent(25u32),
+					Error::<T>::EstateRoyaltyExceedLimit
+				);
+			}
+
+			EstateRoyalties::<T>::set(estate_id, royalty.clone());
+			Self::deposit_event(Event::<T>::EstateRoyaltyUpdated(estate_id, royalty));
+
+			Ok(().into())
+		}
+
+		/// Sponsor pre-funds a pool of undeployed land blocks for a metaverse, gift cards for the
+		/// land blocks are minted in the sponsor's NFT class and sold at a fixed stablecoin price
+		#[pallet::weight(T::WeightInfo::create_land_gift_card_pool() * land_block_ids.len().max(1) as u64)]
+		#[transactional]
+		pub fn create_land_gift_card_pool(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			card_class_id: ClassId,
+			land_block_ids: Vec<UndeployedLandBlockId>,
+			price: BalanceOf<T>,
+			validity: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				!land_block_ids.is_empty() && land_block_ids.len() as u32 <= T::MaxGiftCardPoolSize::get(),
+				Error::<T>::InvalidGiftCardPoolSize
+			);
+			ensure!(!validity.is_zero(), Error::<T>::InvalidGiftCardValidity);
+
+			let pool_id = NextLandGiftCardPoolId::<T>::try_mutate(|id| -> Result<LandGiftCardPoolId, DispatchError> {
+				let current_id = *id;
+				*id = id
+					.checked_add(One::one())
+					.ok_or(Error::<T>::NoAvailableGiftCardPoolId)?;
+				Ok(current_id)
+			})?;
+
+			let pool_account = Self::land_gift_card_pool_account(pool_id);
+			for land_block_id in land_block_ids.iter() {
+				Self::do_transfer_undeployed_land_block(&who, &pool_account, *land_block_id)?;
+			}
+
+			let number_of_land_blocks = land_block_ids.len() as u32;
+			LandGiftCardPools::<T>::insert(
+				pool_id,
+				LandGiftCardPool {
+					sponsor: who.clone(),
+					metaverse_id,
+					card_class_id,
+					price,
+					validity,
+					available_land_blocks: land_block_ids,
+					outstanding_cards: 0,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::LandGiftCardPoolCreated(
+				pool_id,
+				who,
+				metaverse_id,
+				number_of_land_blocks,
+			));
+
+			Ok(().into())
+		}
+
+		/// Sponsor takes back the land blocks of the pool which are not reserved by gift cards,
+		/// the pool is removed once all sold gift cards are redeemed or refunded
+		#[pallet::weight(T::WeightInfo::close_land_gift_card_pool())]
+		#[transactional]
+		pub fn close_land_gift_card_pool(
+			origin: OriginFor<T>,
+			pool_id: LandGiftCardPoolId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let mut pool = Self::get_land_gift_card_pool(pool_id).ok_or(Error::<T>::GiftCardPoolNotFound)?;
+			ensure!(pool.sponsor == who, Error::<T>::NoPermission);
+
+			let pool_account = Self::land_gift_card_pool_account(pool_id);
+			let returned_land_blocks = pool.available_land_blocks.len() as u32;
+			for land_block_id in pool.available_land_blocks.drain(..) {
+				Self::do_transfer_undeployed_land_block(&pool_account, &who, land_block_id)?;
+			}
+
+			if pool.outstanding_cards == 0 {
+				LandGiftCardPools::<T>::remove(pool_id);
+			} else {
+				LandGiftCardPools::<T>::insert(pool_id, pool);
+			}
+
+			Self::deposit_event(Event::<T>::LandGiftCardPoolClosed(pool_id, who, returned_land_blocks));
+
+			Ok(().into())
+		}
+
+		/// Buy a land gift card from the pool for the recipient, the price is paid to the sponsor
+		/// in stablecoin and a land block of the pool is reserved for the gift card
+		#[pallet::weight(T::WeightInfo::buy_land_gift_card())]
+		#[transactional]
+		pub fn buy_land_gift_card(
+			origin: OriginFor<T>,
+			pool_id: LandGiftCardPoolId,
+			recipient: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let mut pool = Self::get_land_gift_card_pool(pool_id).ok_or(Error::<T>::GiftCardPoolNotFound)?;
+			ensure!(
+				Self::is_allowed_to_buy_land(&recipient, pool.metaverse_id),
+				Error::<T>::LandBuyerNotAllowed
+			);
+			let land_block_id = pool
+				.available_land_blocks
+				.pop()
+				.ok_or(Error::<T>::GiftCardPoolSoldOut)?;
+
+			T::FungibleTokenCurrency::transfer(T::GiftCardCurrencyId::get(), &who, &pool.sponsor, pool.price)?;
+
+			let metadata: NftMetadata = pool_id.encode();
+			let token_id =
+				T::NFTTokenizationSource::mint_token(&pool.sponsor, pool.card_class_id, metadata, Default::default())?;
+			let gift_card = (pool.card_class_id, token_id);
+			ensure!(
+				!LandGiftCards::<T>::contains_key(gift_card),
+				Error::<T>::GiftCardAlreadyExists
+			);
+			if recipient != pool.sponsor {
+				T::NFTTokenizationSource::transfer_nft(&pool.sponsor, &recipient, &gift_card)?;
+			}
+
+			let expires_at = <frame_system::Pallet<T>>::block_number().saturating_add(pool.validity);
+			LandGiftCards::<T>::insert(
+				gift_card,
+				LandGiftCard {
+					pool_id,
+					land_block_id,
+					expires_at,
+				},
+			);
+			pool.outstanding_cards = pool.outstanding_cards.saturating_add(1);
+			let price = pool.price;
+			LandGiftCardPools::<T>::insert(pool_id, pool);
+
+			Self::deposit_event(Event::<T>::LandGiftCardPurchased(
+				pool_id, who, recipient, gift_card, price,
+			));
+
+			Ok(().into())
+		}
+
+		/// Gift card holder redeems the gift card for its reserved undeployed land block, which
+		/// can then be deployed in the metaverse of the pool
+		#[pallet::weight(T::WeightInfo::redeem_land_gift_card())]
+		#[transactional]
+		pub fn redeem_land_gift_card(
+			origin: OriginFor<T>,
+			gift_card: (ClassId, TokenId),
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let card = Self::get_land_gift_card(gift_card).ok_or(Error::<T>::GiftCardNotFound)?;
+			ensure!(
+				T::NFTTokenizationSource::check_ownership(&who, &gift_card)?,
+				Error::<T>::NoPermission
+			);
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() <= card.expires_at,
+				Error::<T>::GiftCardExpired
+			);
+
+			T::NFTTokenizationSource::burn_nft(&who, &gift_card)?;
+			let metaverse_id = Self::settle_land_gift_card(gift_card, &card, &who)?;
+
+			Self::deposit_event(Event::<T>::LandGiftCardRedeemed(
+				gift_card,
+				who,
+				metaverse_id,
+				card.land_block_id,
+			));
+
+			Ok(().into())
+		}
+
+		/// Return the land block reserved by an expired gift card to the pool sponsor
+		#[pallet::weight(T::WeightInfo::refund_expired_land_gift_card())]
+		#[transactional]
+		pub fn refund_expired_land_gift_card(
+			origin: OriginFor<T>,
+			gift_card: (ClassId, TokenId),
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let card = Self::get_land_gift_card(gift_card).ok_or(Error::<T>::GiftCardNotFound)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() > card.expires_at,
+				Error::<T>::GiftCardNotExpired
+			);
+
+			let pool = Self::get_land_gift_card_pool(card.pool_id).ok_or(Error::<T>::GiftCardPoolNotFound)?;
+			Self::settle_land_gift_card(gift_card, &card, &pool.sponsor)?;
+
+			Self::deposit_event(Event::<T>::LandGiftCardRefunded(
+				gift_card,
+				pool.sponsor,
+				card.land_block_id,
+			));
+
+			Ok(().into())
+		}
+
+		/// Land unit owner reverts land units of a metaverse in sunset to a transferable undeployed
+		/// land block. Land units of an estate can be reverted once the estate is dissolved.
+		#[pallet::weight(T::WeightInfo::revert_sunset_land_units().saturating_mul(coordinates.len() as u64))]
+		#[transactional]
+		pub fn revert_sunset_land_units(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			coordinates: Vec<(i32, i32)>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::MetaverseInfoSource::is_metaverse_sunset(metaverse_id),
+				Error::<T>::MetaverseNotInSunset
+			);
+			ensure!(!coordinates.is_empty(), Error::<T>::LandUnitDoesNotExist);
+
+			for coordinate in coordinates.iter() {
+				ensure!(
+					!T::AuctionHandler::check_item_in_auction(ItemId::LandUnit(*coordinate, metaverse_id)),
+					Error::<T>::LandUnitAlreadyInAuction
+				);
+				let owner = LandUnits::<T>::take(metaverse_id, coordinate).ok_or(Error::<T>::LandUnitDoesNotExist)?;
+				Self::decrease_metaverse_land_unit_count(metaverse_id);
+				ensure!(
+					Self::check_if_land_or_estate_owner(&who, &owner, false),
+					Error::<T>::NoPermission
+				);
+				if let OwnerId::Token(token_id) = owner {
+					T::NFTTokenizationSource::burn_nft(&who, &(LAND_CLASS_ID, token_id))?;
+				}
+				LandUnitLevels::<T>::remove(metaverse_id, coordinate);
+			}
+
+			let number_land_units = coordinates.len() as u32;
+			Self::set_total_land_unit(number_land_units as u64, true)?;
+			let undeployed_land_block_ids = Self::do_issue_undeployed_land_blocks(
+				&who,
+				1,
+				number_land_units,
+				UndeployedLandBlockType::Transferable,
+			)?;
+
+			Self::deposit_event(Event::<T>::SunsetLandUnitsReverted(
+				who,
+				metaverse_id,
+				number_land_units,
+				undeployed_land_block_ids[0],
+			));
+
+			Ok(().into())
+		}
+
+		/// Co-owner who proposed the pending action cancels it
+		#[pallet::weight(T::WeightInfo::cancel_co_ownership_action())]
+		pub fn cancel_co_ownership_action(origin: OriginFor<T>, estate_id: EstateId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::refresh_heartbeat(&who);
+
+			let proposal = Self::get_co_ownership_proposal(estate_id).ok_or(Error::<T>::NoCoOwnershipProposal)?;
+			// The proposer is the first approval of the proposal
+			ensure!(proposal.approvals.first() == Some(&who), Error::<T>::NoPermission);
+
+			CoOwnershipProposals::<T>::remove(estate_id);
+			Self::deposit_event(Event::<T>::CoOwnershipActionCancelled(estate_id, who));
+
+			Ok(().into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn pay_stakers(next: RoundIndex) {
+			// payout is next - duration rounds ago => next - duration > 0 else return early
+			let duration = T::RewardPaymentDelay::get();
+			if next <= duration {
+				return;
+			}
+			let round_to_payout = next - duration;
+
+			// issue BIT for rewards distribution
+			let total_staked = <Staked<T>>::get(round_to_payout);
+			let total_issuance = Self::compute_issuance(total_staked);
+
+			let mut left_issuance = total_issuance;
+
+			// a local fn to transfer rewards to the account specified
+			let mint = |amt: BalanceOf<T>, to: T::AccountId| {
+				if let Ok(imb) = T::Currency::deposit_into_existing(&to, amt) {
+					Self::deposit_event(Event::StakingRewarded(to.clone(), imb.peek()));
+				}
+			};
+
+			for (estate_id, stake_snapshot) in <AtStake<T>>::drain_prefix(round_to_payout) {
+				for Bond { staker: owner, amount } in stake_snapshot.stakers {
+					// TODO: TBD on the rewards amount
+					let amount_due = amount;
+					mint(1u32.into(), owner);
+				}
+			}
+		}
+
+		/// Clear exit queue. return stake to account
+		fn clear_exit_queue(now: RoundIndex) {
+			for (account_id, estate_id, val) in <ExitQueue<T>>::drain() {
+				let staked_amount = <EstateStake<T>>::get(estate_id, &account_id);
+
+				// return stake to account
+				T::Currency::unreserve(&account_id, staked_amount);
+
+				<EstateStake<T>>::remove(estate_id, &account_id);
+				Self::decrease_staker_estate_stake(&account_id, staked_amount);
+			}
+		}
+
+		fn update_stake_snapshot(next: RoundIndex) -> BalanceOf<T> {
+			let mut total = BalanceOf::<T>::zero();
+
+			for estate_id in <Estates<T>>::iter_keys() {
+				let mut total_bond = BalanceOf::<T>::zero();
 				let mut stakers: Vec<Bond<T::AccountId, BalanceOf<T>>> = Vec::new();
 
-				for (account_id, amount) in <EstateStake<T>>::iter_prefix(estate_id) {
-					stakers.push(Bond {
-						staker: account_id.clone(),
-						amount,
-					});
+				for (account_id, amount) in <EstateStake<T>>::iter_prefix(estate_id) {
+					stakers.push(Bond {
+						staker: account_id.clone(),
+						amount,
+					});
+
+					total += amount;
+					total_bond += amount;
+				}
+				if stakers.len() > 0 {
+					<AtStake<T>>::insert(next, estate_id, StakeSnapshot { stakers, total_bond });
+				}
+			}
+
+			<TotalStake<T>>::put(total);
+			total
+		}
+
+		fn compute_issuance(staked: BalanceOf<T>) -> BalanceOf<T> {
+			//TODO: need to decide on how much BIT need to be issued per session
+			staked.saturating_add(staked)
+		}
+
+		/// Idle land tax that the estate owes to its metaverse treasury. Estates listed on the
+		/// marketplace do not build up tax, listing requires the tax to be settled.
+		pub fn land_tax_due(estate_id: EstateId) -> BalanceOf<T> {
+			if LandTaxExemption::<T>::contains_key(estate_id)
+				|| T::AuctionHandler::check_item_in_auction(ItemId::Estate(estate_id))
+			{
+				return Zero::zero();
+			}
+
+			let estate_info = match Estates::<T>::get(estate_id) {
+				Some(estate_info) => estate_info,
+				None => return Zero::zero(),
+			};
+
+			let land_tax = match LandTax::<T>::get(estate_info.metaverse_id) {
+				Some(land_tax) => land_tax,
+				None => return Zero::zero(),
+			};
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let idle_blocks = now.saturating_sub(EstateLastActivity::<T>::get(estate_id));
+			let idle_rounds: u32 = (idle_blocks / Self::round_length()).saturated_into();
+			let taxable_rounds = idle_rounds.saturating_sub(land_tax.idle_rounds);
+
+			land_tax
+				.tax_per_land_unit
+				.saturating_mul((estate_info.land_units.len() as u32).into())
+				.saturating_mul(taxable_rounds.into())
+		}
+
+		pub(crate) fn ensure_land_tax_settled(estate_id: EstateId) -> DispatchResult {
+			ensure!(Self::land_tax_due(estate_id).is_zero(), Error::<T>::LandTaxOutstanding);
+			Ok(())
+		}
+
+		pub(crate) fn record_estate_activity(estate_id: EstateId) {
+			EstateLastActivity::<T>::insert(estate_id, <frame_system::Pallet<T>>::block_number());
+		}
+
+		/// Record the upgrade block as last activity of estates created before idle land tax, so
+		/// they do not owe tax back to genesis
+		pub fn upgrade_estate_last_activity_v1() -> Weight {
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut num_estates = 0u64;
+			let mut num_backfilled = 0u64;
+
+			for estate_id in Estates::<T>::iter_keys() {
+				num_estates += 1;
+				if !EstateLastActivity::<T>::contains_key(estate_id) {
+					EstateLastActivity::<T>::insert(estate_id, now);
+					num_backfilled += 1;
+				}
+			}
+
+			T::DbWeight::get().reads_writes(num_estates.saturating_mul(2), num_backfilled)
+		}
+
+		/// Index estates tokenized before estate tokens were indexed
+		pub fn upgrade_estate_tokens_v2() -> Weight {
+			let mut num_estates = 0u64;
+			let mut num_indexed = 0u64;
+
+			for (estate_id, owner) in EstateOwner::<T>::iter() {
+				num_estates += 1;
+				if let OwnerId::Token(token_id) = owner {
+					EstateTokens::<T>::insert(token_id, estate_id);
+					num_indexed += 1;
+				}
+			}
+
+			T::DbWeight::get().reads_writes(num_estates, num_indexed)
+		}
+
+		/// Count land units of each metaverse and total estate stake of each staker deployed
+		/// before the totals were kept
+		pub fn upgrade_estate_stake_totals_v3() -> Weight {
+			let mut num_land_units = 0u64;
+			let mut land_unit_counts: BTreeMap<MetaverseId, u64> = BTreeMap::new();
+			for (metaverse_id, _) in LandUnits::<T>::iter_keys() {
+				num_land_units += 1;
+				let count = land_unit_counts.entry(metaverse_id).or_default();
+				*count = count.saturating_add(1);
+			}
+
+			let mut num_stakes = 0u64;
+			let mut staker_stakes: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
+			for (_, staker, amount) in EstateStake::<T>::iter() {
+				num_stakes += 1;
+				let stake = staker_stakes.entry(staker).or_default();
+				*stake = stake.saturating_add(amount);
+			}
+
+			let num_writes = (land_unit_counts.len() + staker_stakes.len()) as u64;
+			for (metaverse_id, count) in land_unit_counts {
+				MetaverseLandUnitCount::<T>::insert(metaverse_id, count);
+			}
+			for (staker, stake) in staker_stakes {
+				if !stake.is_zero() {
+					StakerEstateStake::<T>::insert(staker, stake);
+				}
+			}
+
+			T::DbWeight::get().reads_writes(num_land_units.saturating_add(num_stakes), num_writes)
+		}
+
+		/// Deploy land units of undeployed land block owned by `who` to the metaverse
+		pub(crate) fn do_deploy_land_block(
+			who: &T::AccountId,
+			undeployed_land_block_id: UndeployedLandBlockId,
+			metaverse_id: MetaverseId,
+			coordinates: Vec<(i32, i32)>,
+			are_tokenized: bool,
+		) -> DispatchResult {
+			UndeployedLandBlocks::<T>::try_mutate_exists(
+				&undeployed_land_block_id,
+				|undeployed_land_block| -> DispatchResult {
+					let mut undeployed_land_block_record = undeployed_land_block
+						.as_mut()
+						.ok_or(Error::<T>::UndeployedLandBlockNotFound)?;
+
+					ensure!(
+						undeployed_land_block_record.owner == who.clone(),
+						Error::<T>::NoPermission
+					);
+
+					ensure!(
+						undeployed_land_block_record.is_frozen == false,
+						Error::<T>::UndeployedLandBlockFreezed
+					);
+
+					ensure!(
+						!T::MetaverseInfoSource::is_metaverse_sunset(metaverse_id),
+						Error::<T>::MetaverseIsInSunset
+					);
+
+					let land_units_to_mint = coordinates.len() as u32;
+					ensure!(
+						undeployed_land_block_record.number_land_units > land_units_to_mint,
+						Error::<T>::UndeployedLandBlockDoesNotHaveEnoughLandUnits
+					);
+
+					ensure!(
+						Self::is_allowed_to_deploy(who, metaverse_id),
+						Error::<T>::NotWhitelistedDeployer
+					);
+
+					Self::ensure_land_coordinates_available(metaverse_id, &coordinates)?;
+
+					// Mint land units
+					for coordinate in coordinates.clone() {
+						Self::mint_land_unit(
+							metaverse_id,
+							who.clone(),
+							coordinate,
+							LandUnitStatus::NonExisting,
+							are_tokenized,
+						)?;
+					}
+
+					// Update total land count
+					Self::set_total_land_unit(coordinates.len() as u64, false)?;
 
-					total += amount;
-					total_bond += amount;
+					// Update undeployed land block
+					if undeployed_land_block_record.number_land_units == land_units_to_mint {
+						Self::do_burn_undeployed_land_block(undeployed_land_block_id)?;
+					} else {
+						undeployed_land_block_record.number_land_units = undeployed_land_block_record
+							.number_land_units
+							.checked_sub(land_units_to_mint)
+							.ok_or("Overflow deduct land units from undeployed land block")?;
+					}
+					Self::set_total_undeployed_land_unit(land_units_to_mint as u64, true)?;
+					T::MetaverseInfoSource::record_metaverse_activity(metaverse_id);
+
+					Self::deposit_event(Event::<T>::LandBlockDeployed(
+						who.clone(),
+						metaverse_id,
+						undeployed_land_block_id,
+						coordinates,
+					));
+
+					Ok(())
+				},
+			)
+		}
+
+		/// Post lease offer of the estate on the lease board with `landlord` receiving the rent
+		pub(crate) fn do_post_lease_offer(
+			landlord: &T::AccountId,
+			estate_id: EstateId,
+			rent_per_block: BalanceOf<T>,
+			duration: T::BlockNumber,
+			min_deposit: BalanceOf<T>,
+		) -> DispatchResult {
+			ensure!(Estates::<T>::contains_key(estate_id), Error::<T>::EstateDoesNotExist);
+			ensure!(!Self::is_frozen(estate_id), Error::<T>::EstateIsFrozen);
+			ensure!(!EstateLeases::<T>::contains_key(estate_id), Error::<T>::EstateIsLeased);
+			ensure!(
+				!rent_per_block.is_zero() && !duration.is_zero() && min_deposit >= rent_per_block,
+				Error::<T>::InvalidLeaseTerms
+			);
+
+			LeaseOffers::<T>::insert(
+				estate_id,
+				LeaseOffer {
+					landlord: landlord.clone(),
+					rent_per_block,
+					duration,
+					min_deposit,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::LeaseOfferPosted(
+				estate_id,
+				landlord.clone(),
+				rent_per_block,
+				duration,
+			));
+
+			Ok(())
+		}
+
+		fn try_execute_co_ownership_proposal(estate_id: EstateId, threshold: u32) -> DispatchResult {
+			if let Some(proposal) = CoOwnershipProposals::<T>::get(estate_id) {
+				if proposal.approvals.len() as u32 >= threshold {
+					CoOwnershipProposals::<T>::remove(estate_id);
+					Self::do_execute_co_ownership_action(estate_id, proposal.action, proposal.approvals)?;
 				}
-				if stakers.len() > 0 {
-					<AtStake<T>>::insert(next, estate_id, StakeSnapshot { stakers, total_bond });
+			}
+			Ok(())
+		}
+
+		fn do_execute_co_ownership_action(
+			estate_id: EstateId,
+			action: CoOwnershipAction<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+			approvals: Vec<T::AccountId>,
+		) -> DispatchResult {
+			let estate_account_id: T::AccountId = T::LandTreasury::get().into_sub_account(estate_id);
+
+			match action.clone() {
+				CoOwnershipAction::Transfer(to) => {
+					ensure!(
+						!T::AuctionHandler::check_item_in_auction(ItemId::Estate(estate_id)),
+						Error::<T>::EstateAlreadyInAuction
+					);
+					Self::ensure_land_tax_settled(estate_id)?;
+					Self::do_transfer_estate(estate_id, &estate_account_id, &to)?;
+				}
+				CoOwnershipAction::List(auction_type, initial_amount, end_time) => {
+					let now = <frame_system::Pallet<T>>::block_number();
+					let initial_amount = TryInto::<u128>::try_into(initial_amount)
+						.ok()
+						.and_then(|amount| amount.try_into().ok())
+						.ok_or(Error::<T>::Overflow)?;
+					T::AuctionHandler::create_auction(
+						auction_type,
+						ItemId::Estate(estate_id),
+						Some(end_time),
+						estate_account_id,
+						initial_amount,
+						now,
+						ListingLevel::Global,
+					)?;
+				}
+				CoOwnershipAction::PostLeaseOffer(rent_per_block, duration, min_deposit) => {
+					Self::do_post_lease_offer(&estate_account_id, estate_id, rent_per_block, duration, min_deposit)?;
+				}
+				CoOwnershipAction::CancelLeaseOffer => {
+					ensure!(
+						LeaseOffers::<T>::contains_key(estate_id),
+						Error::<T>::LeaseOfferNotFound
+					);
+					LeaseOffers::<T>::remove(estate_id);
+					Self::deposit_event(Event::<T>::LeaseOfferCancelled(estate_id));
+				}
+				CoOwnershipAction::DeployLandBlock(undeployed_land_block_id, coordinates) => {
+					// Land units are deployed to the estate account and joined to the estate
+					let mut estate_info = Estates::<T>::get(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+					Self::do_deploy_land_block(
+						&estate_account_id,
+						undeployed_land_block_id,
+						estate_info.metaverse_id,
+						coordinates.clone(),
+						false,
+					)?;
+					estate_info.land_units.extend(coordinates);
+					Estates::<T>::insert(estate_id, estate_info);
+					Self::record_estate_activity(estate_id);
+				}
+				_ => {
+					EstateCoOwnership::<T>::try_mutate(estate_id, |maybe_co_ownership| -> DispatchResult {
+						let co_ownership = maybe_co_ownership.as_mut().ok_or(Error::<T>::EstateIsNotCoOwned)?;
+						match action.clone() {
+							CoOwnershipAction::AddCoOwner(co_owner) => {
+								ensure!(
+									!co_ownership.co_owners.contains(&co_owner)
+										&& (co_ownership.co_owners.len() as u32) < T::MaxCoOwners::get(),
+									Error::<T>::InvalidCoOwners
+								);
+								co_ownership.co_owners.push(co_owner);
+							}
+							CoOwnershipAction::RemoveCoOwner(co_owner) => {
+								ensure!(
+									co_ownership.co_owners.contains(&co_owner) && co_ownership.co_owners.len() > 2,
+									Error::<T>::InvalidCoOwners
+								);
+								co_ownership.co_owners.retain(|a| *a != co_owner);
+							}
+							CoOwnershipAction::SetThreshold(threshold) => {
+								co_ownership.threshold = threshold;
+							}
+							_ => {}
+						}
+						ensure!(
+							co_ownership.threshold > 0
+								&& co_ownership.threshold as usize <= co_ownership.co_owners.len(),
+							Error::<T>::InvalidCoOwnershipThreshold
+						);
+						Ok(())
+					})?;
 				}
 			}
 
-			<TotalStake<T>>::put(total);
-			total
+			Self::deposit_event(Event::<T>::CoOwnershipActionExecuted(estate_id, action, approvals));
+			Ok(())
 		}
 
-		fn compute_issuance(staked: BalanceOf<T>) -> BalanceOf<T> {
-			//TODO: need to decide on how much BIT need to be issued per session
-			staked.saturating_add(staked)
+		/// Split funds the estate account received during the co-ownership, including sale
+		/// proceeds, equally among co-owners once jointly owned estate leaves the estate account
+		pub(crate) fn end_estate_co_ownership(estate_id: EstateId) -> DispatchResult {
+			if let Some(co_ownership) = EstateCoOwnership::<T>::take(estate_id) {
+				CoOwnershipProposals::<T>::remove(estate_id);
+				let opening_balance = CoOwnershipOpeningBalance::<T>::take(estate_id);
+
+				let estate_account_id: T::AccountId = T::LandTreasury::get().into_sub_account(estate_id);
+				let share = T::Currency::free_balance(&estate_account_id).saturating_sub(opening_balance)
+					/ (co_ownership.co_owners.len() as u32).into();
+				if !share.is_zero() {
+					for co_owner in co_ownership.co_owners.iter() {
+						T::Currency::transfer(&estate_account_id, co_owner, share, ExistenceRequirement::AllowDeath)?;
+					}
+				}
+
+				Self::deposit_event(Event::<T>::EstateCoOwnershipEnded(estate_id));
+			}
+			Ok(())
+		}
+
+		/// Block at which the deposit is exhausted or the lease term ends
+		pub(crate) fn lease_eviction_block(
+			lease: &LeaseInfo<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+		) -> T::BlockNumber {
+			let covered_blocks: u128 = (lease.deposit / lease.rent_per_block).saturated_into();
+			lease
+				.last_collected
+				.saturating_add(covered_blocks.saturated_into())
+				.min(lease.end_block)
+		}
+
+		/// Stream rent accrued since the last collection from the tenant deposit to the landlord
+		pub(crate) fn do_collect_rent(
+			estate_id: EstateId,
+			lease: &mut LeaseInfo<T::AccountId, BalanceOf<T>, T::BlockNumber>,
+		) {
+			let now = <frame_system::Pallet<T>>::block_number().min(lease.end_block);
+			let elapsed: u128 = now.saturating_sub(lease.last_collected).saturated_into();
+			let rent = lease
+				.rent_per_block
+				.saturating_mul(elapsed.saturated_into())
+				.min(lease.deposit);
+			lease.last_collected = now;
+			if rent.is_zero() {
+				return;
+			}
+
+			// Any amount which could not be moved stays reserved on the tenant
+			let unpaid = T::Currency::repatriate_reserved(&lease.tenant, &lease.landlord, rent, BalanceStatus::Free)
+				.unwrap_or(rent);
+			let paid = rent.saturating_sub(unpaid);
+			lease.deposit = lease.deposit.saturating_sub(rent);
+
+			Self::deposit_event(Event::<T>::RentCollected(estate_id, lease.landlord.clone(), paid));
+		}
+
+		/// End leases evicted up to the current block in eviction block order, at most
+		/// MaxLeaseExpiriesPerBlock per block. Leases left over are ended in the next blocks.
+		fn end_expired_leases(now: T::BlockNumber) -> Weight {
+			let max_expiries = T::MaxLeaseExpiriesPerBlock::get();
+			let mut cursor = Self::lease_expiry_cursor().unwrap_or(now);
+			let mut ended_leases: u32 = 0;
+			let mut visited_blocks: u32 = 0;
+
+			while cursor <= now && ended_leases < max_expiries && visited_blocks < max_expiries {
+				visited_blocks = visited_blocks.saturating_add(1);
+				let due: Vec<EstateId> = LeaseExpiries::<T>::iter_prefix(&cursor)
+					.map(|(estate_id, _)| estate_id)
+					.take(max_expiries.saturating_sub(ended_leases) as usize)
+					.collect();
+				for estate_id in due {
+					LeaseExpiries::<T>::remove(&cursor, estate_id);
+					Self::end_lease(estate_id);
+					ended_leases = ended_leases.saturating_add(1);
+				}
+				if LeaseExpiries::<T>::iter_prefix(&cursor).next().is_some() {
+					break;
+				}
+				cursor = cursor.saturating_add(One::one());
+			}
+			LeaseExpiryCursor::<T>::put(cursor);
+
+			let ended_leases = ended_leases as Weight;
+			let visited_blocks = visited_blocks as Weight;
+			T::DbWeight::get().reads_writes(
+				ended_leases
+					.saturating_mul(3)
+					.saturating_add(visited_blocks.saturating_mul(2))
+					.saturating_add(2),
+				ended_leases.saturating_mul(4).saturating_add(2),
+			)
+		}
+
+		/// Wind down the leases and lease offers of estates in a metaverse entering sunset, checking
+		/// at most MaxLeaseExpiriesPerBlock estates per block. Estates left over are checked in the
+		/// next blocks.
+		fn wind_down_sunset_leases() -> Weight {
+			let (metaverse_id, start) = match SunsetLeaseCursors::<T>::iter().next() {
+				Some(cursor) => cursor,
+				None => return T::DbWeight::get().reads(1),
+			};
+			let next_estate_id = NextEstateId::<T>::get();
+			let end = start
+				.saturating_add(T::MaxLeaseExpiriesPerBlock::get() as EstateId)
+				.min(next_estate_id);
+
+			let mut ended_leases: Weight = 0;
+			for estate_id in start..end {
+				if Estates::<T>::get(estate_id).map_or(false, |estate| estate.metaverse_id == metaverse_id) {
+					LeaseOffers::<T>::remove(estate_id);
+					if let Some(lease) = EstateLeases::<T>::get(estate_id) {
+						LeaseExpiries::<T>::remove(Self::lease_eviction_block(&lease), estate_id);
+						Self::end_lease(estate_id);
+						ended_leases = ended_leases.saturating_add(1);
+					}
+				}
+			}
+			if end < next_estate_id {
+				SunsetLeaseCursors::<T>::insert(metaverse_id, end);
+			} else {
+				SunsetLeaseCursors::<T>::remove(metaverse_id);
+			}
+
+			let checked_estates = end.saturating_sub(start) as Weight;
+			T::DbWeight::get().reads_writes(
+				checked_estates
+					.saturating_mul(2)
+					.saturating_add(ended_leases.saturating_mul(3))
+					.saturating_add(2),
+				checked_estates
+					.saturating_add(ended_leases.saturating_mul(4))
+					.saturating_add(1),
+			)
+		}
+
+		pub(crate) fn end_lease(estate_id: EstateId) {
+			if let Some(mut lease) = EstateLeases::<T>::take(estate_id) {
+				Self::do_collect_rent(estate_id, &mut lease);
+				T::Currency::unreserve(&lease.tenant, lease.deposit);
+
+				Self::deposit_event(Event::<T>::LeaseEnded(estate_id, lease.tenant));
+			}
+		}
+
+		/// Check if account can deploy land blocks in the metaverse, the metaverse owner and its
+		/// builders are always allowed
+		pub fn is_allowed_to_deploy(who: &T::AccountId, metaverse_id: MetaverseId) -> bool {
+			!DeploymentWhitelistEnabled::<T>::contains_key(metaverse_id)
+				|| LandDeployers::<T>::contains_key(metaverse_id, who)
+				|| T::MetaverseInfoSource::has_metaverse_role(who, &metaverse_id, MetaverseRole::Builder)
+		}
+
+		/// Check if account meets the land buyer gate of the metaverse, the metaverse owner is
+		/// always allowed
+		pub fn is_allowed_to_buy_land(who: &T::AccountId, metaverse_id: MetaverseId) -> bool {
+			match Self::land_buyer_gate(metaverse_id) {
+				None => true,
+				Some(LandBuyerGate::Allowlist) => {
+					LandBuyers::<T>::contains_key(metaverse_id, who)
+						|| T::MetaverseInfoSource::check_ownership(who, &metaverse_id)
+				}
+				Some(LandBuyerGate::NftHolders(class_id)) => {
+					T::NFTTokenizationSource::holds_class_token(who, &class_id)
+						|| T::MetaverseInfoSource::check_ownership(who, &metaverse_id)
+				}
+			}
+		}
+
+		/// Total stake of the account over all estates
+		pub fn get_account_estate_stake(who: &T::AccountId) -> BalanceOf<T> {
+			Self::staker_estate_stake(who).unwrap_or_default()
+		}
+
+		/// Append new owner to the estate provenance history, pruning the oldest records once the
+		/// history is full
+		pub(crate) fn record_estate_provenance(estate_id: EstateId, owner: &T::AccountId) {
+			EstateProvenance::<T>::mutate(estate_id, |provenance| {
+				provenance.push(EstateProvenanceRecord {
+					owner: owner.clone(),
+					price: None,
+					acquired_at: <frame_system::Pallet<T>>::block_number(),
+				});
+				let max_records = T::MaxEstateProvenance::get() as usize;
+				if provenance.len() > max_records {
+					let excess = provenance.len() - max_records;
+					provenance.drain(..excess);
+				}
+			});
+		}
+
+		/// Reject a second transfer of the same estate within one extrinsic. Transfers made outside
+		/// of extrinsics are not restricted.
+		pub(crate) fn ensure_estate_not_transferred_in_extrinsic(estate_id: EstateId) -> DispatchResult {
+			if let Some(extrinsic_index) = <frame_system::Pallet<T>>::extrinsic_index() {
+				let now = <frame_system::Pallet<T>>::block_number();
+				ensure!(
+					Self::get_estate_transferred_in_extrinsic(estate_id) != Some((now, extrinsic_index)),
+					Error::<T>::EstateAlreadyTransferredInExtrinsic
+				);
+			}
+			Ok(())
+		}
+
+		pub(crate) fn note_estate_transfer_in_extrinsic(estate_id: EstateId) {
+			if let Some(extrinsic_index) = <frame_system::Pallet<T>>::extrinsic_index() {
+				let now = <frame_system::Pallet<T>>::block_number();
+				EstateTransferredInExtrinsic::<T>::insert(estate_id, (now, extrinsic_index));
+			}
+		}
+
+		/// Check if the estate is locked by a loan request or an active loan
+		pub(crate) fn is_estate_collateralized(estate_id: EstateId) -> bool {
+			LoanRequests::<T>::contains_key(estate_id) || EstateLoans::<T>::contains_key(estate_id)
+		}
+
+		/// Lock the estate token while the estate is collateralized so it can not be moved through
+		/// the NFT pallet, unlock it once the loan is settled. Freezes are kept apart by the NFT
+		/// pallet.
+		pub(crate) fn update_estate_token_lock(estate_id: EstateId) -> DispatchResult {
+			if let Some(OwnerId::Token(token_id)) = Self::get_estate_owner(estate_id) {
+				let is_locked = Self::is_estate_collateralized(estate_id);
+				T::NFTTokenizationSource::set_lock_nft(&(ESTATE_CLASS_ID, token_id), is_locked)?;
+			}
+			Ok(())
+		}
+
+		/// Check if the estate is frozen by governance
+		pub(crate) fn is_frozen(estate_id: EstateId) -> bool {
+			FrozenEstates::<T>::contains_key(estate_id)
+		}
+
+		/// Account holding the undeployed land blocks of a gift card pool
+		pub fn land_gift_card_pool_account(pool_id: LandGiftCardPoolId) -> T::AccountId {
+			T::LandTreasury::get().into_sub_account((b"gift", pool_id))
+		}
+
+		/// Release the land block reserved by the gift card to the account and remove the gift
+		/// card, returns the metaverse of the pool
+		fn settle_land_gift_card(
+			gift_card: (ClassId, TokenId),
+			card: &LandGiftCard<T::BlockNumber>,
+			to: &T::AccountId,
+		) -> Result<MetaverseId, DispatchError> {
+			let pool_account = Self::land_gift_card_pool_account(card.pool_id);
+			Self::do_transfer_undeployed_land_block(&pool_account, to, card.land_block_id)?;
+			LandGiftCards::<T>::remove(gift_card);
+
+			LandGiftCardPools::<T>::try_mutate_exists(
+				card.pool_id,
+				|maybe_pool| -> Result<MetaverseId, DispatchError> {
+					let pool = maybe_pool.as_mut().ok_or(Error::<T>::GiftCardPoolNotFound)?;
+					let metaverse_id = pool.metaverse_id;
+					pool.outstanding_cards = pool.outstanding_cards.saturating_sub(1);
+					// Pool closed by the sponsor is removed with its last gift card
+					if pool.outstanding_cards == 0 && pool.available_land_blocks.is_empty() {
+						*maybe_pool = None;
+					}
+					Ok(metaverse_id)
+				},
+			)
+		}
+
+		fn record_heartbeat(who: &T::AccountId) {
+			LastHeartbeat::<T>::insert(who, <frame_system::Pallet<T>>::block_number());
+		}
+
+		/// Refresh the heartbeat of an account which designated an estate beneficiary, any estate
+		/// action signed by the owner proves they are still active
+		pub(crate) fn refresh_heartbeat(who: &T::AccountId) {
+			if LastHeartbeat::<T>::contains_key(who) {
+				Self::record_heartbeat(who);
+			}
+		}
+
+		fn ensure_estate_metadata_updatable(who: &T::AccountId, estate_id: EstateId) -> DispatchResult {
+			let estate_owner_value = Self::get_estate_owner(&estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+			ensure!(
+				Self::check_if_land_or_estate_owner(who, &estate_owner_value, true),
+				Error::<T>::NoPermission
+			);
+			ensure!(
+				!FrozenEstateMetadata::<T>::contains_key(estate_id),
+				Error::<T>::EstateMetadataFrozen
+			);
+			Ok(())
+		}
+
+		fn round_length() -> T::BlockNumber {
+			let round_length: u32 = Round::<T>::get().length.max(One::one());
+			round_length.into()
 		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Check if the account has deployed a land block
+	pub fn has_deployed_land(who: &T::AccountId) -> bool {
+		LandDeployedAccounts::<T>::contains_key(who)
+	}
+
 	fn get_new_estate_id() -> Result<EstateId, DispatchError> {
 		let estate_id = NextEstateId::<T>::try_mutate(|id| -> Result<EstateId, DispatchError> {
 			let current_id = *id;
@@ -1149,16 +3629,10 @@ impl<T: Config> Pallet<T> {
 		is_tokenized: bool,
 	) -> Result<OwnerId<T::AccountId, TokenId>, DispatchError> {
 		// Ensure the max bound is set for the bit country
-		ensure!(MaxBounds::<T>::contains_key(metaverse_id), Error::<T>::NoMaxBoundSet);
-
-		let max_bound = MaxBounds::<T>::get(metaverse_id);
+		let map_bound = Self::map_bound(metaverse_id).ok_or(Error::<T>::NoMaxBoundSet)?;
 
 		// Check whether the coordinate is within the bound
-		ensure!(
-			(coordinate.0 >= max_bound.0 && max_bound.1 >= coordinate.0)
-				&& (coordinate.1 >= max_bound.0 && max_bound.1 >= coordinate.1),
-			Error::<T>::LandUnitIsOutOfBound
-		);
+		ensure!(map_bound.contains(coordinate), Error::<T>::LandUnitIsOutOfBound);
 		let mut owner = OwnerId::Account(beneficiary.clone());
 
 		match land_unit_status {
@@ -1216,10 +3690,73 @@ impl<T: Config> Pallet<T> {
 				}
 			}
 		}
+		if !LandUnits::<T>::contains_key(metaverse_id, coordinate) {
+			MetaverseLandUnitCount::<T>::mutate(metaverse_id, |count| {
+				*count = Some(count.unwrap_or_default().saturating_add(1))
+			});
+		}
 		LandUnits::<T>::insert(metaverse_id, coordinate, owner.clone());
 		Ok(owner)
 	}
 
+	/// Remove a land unit from the land unit count of the metaverse
+	fn decrease_metaverse_land_unit_count(metaverse_id: MetaverseId) {
+		MetaverseLandUnitCount::<T>::mutate_exists(metaverse_id, |count| {
+			*count = count
+				.map(|count| count.saturating_sub(1))
+				.filter(|count| !count.is_zero())
+		});
+	}
+
+	/// Add estate stake to the total stake of the staker
+	fn increase_staker_estate_stake(who: &T::AccountId, amount: BalanceOf<T>) {
+		StakerEstateStake::<T>::mutate(who, |stake| {
+			*stake = Some(stake.unwrap_or_default().saturating_add(amount))
+		});
+	}
+
+	/// Remove estate stake from the total stake of the staker
+	fn decrease_staker_estate_stake(who: &T::AccountId, amount: BalanceOf<T>) {
+		StakerEstateStake::<T>::mutate_exists(who, |stake| {
+			*stake = stake
+				.map(|stake| stake.saturating_sub(amount))
+				.filter(|stake| !stake.is_zero())
+		});
+	}
+
+	/// Map dimensions of the metaverse, falls back to max bound applied to both axes
+	pub fn map_bound(metaverse_id: MetaverseId) -> Option<MapBound> {
+		MapBounds::<T>::get(metaverse_id).or_else(|| {
+			if MaxBounds::<T>::contains_key(metaverse_id) {
+				let max_bound = MaxBounds::<T>::get(metaverse_id);
+				Some(MapBound {
+					min: (max_bound.0, max_bound.0),
+					max: (max_bound.1, max_bound.1),
+				})
+			} else {
+				None
+			}
+		})
+	}
+
+	/// Validate all coordinates before minting so a batch is never partially minted
+	fn ensure_land_coordinates_available(metaverse_id: MetaverseId, coordinates: &[(i32, i32)]) -> DispatchResult {
+		let map_bound = Self::map_bound(metaverse_id).ok_or(Error::<T>::NoMaxBoundSet)?;
+
+		for (index, coordinate) in coordinates.iter().enumerate() {
+			ensure!(map_bound.contains(*coordinate), Error::<T>::LandUnitIsOutOfBound);
+			ensure!(
+				!coordinates[..index].contains(coordinate),
+				Error::<T>::DuplicateLandUnitCoordinate
+			);
+			ensure!(
+				!LandUnits::<T>::contains_key(metaverse_id, coordinate),
+				Error::<T>::LandUnitIsNotAvailable
+			);
+		}
+		Ok(())
+	}
+
 	fn update_estate_information(
 		new_estate_id: EstateId,
 		metaverse_id: MetaverseId,
@@ -1250,12 +3787,16 @@ impl<T: Config> Pallet<T> {
 				token_properties.1,
 			)?;
 			owner = OwnerId::Token(asset_id);
+			EstateTokens::<T>::insert(asset_id, new_estate_id);
 		}
 
 		Estates::<T>::insert(new_estate_id, estate_info);
 
 		EstateOwner::<T>::insert(new_estate_id, owner.clone());
 
+		Self::record_estate_activity(new_estate_id);
+		Self::record_estate_provenance(new_estate_id, beneficiary);
+
 		Self::deposit_event(Event::<T>::NewEstateMinted(
 			new_estate_id.clone(),
 			owner.clone(),
@@ -1400,6 +3941,17 @@ impl<T: Config> Pallet<T> {
 		Ok(undeployed_land_block_ids)
 	}
 
+	/// Minted land units sharing an edge with the given coordinate
+	pub fn get_adjacent_land_units(
+		metaverse_id: MetaverseId,
+		coordinate: (i32, i32),
+	) -> Vec<((i32, i32), OwnerId<T::AccountId, TokenId>)> {
+		adjacent_coordinates(coordinate)
+			.into_iter()
+			.filter_map(|adjacent| LandUnits::<T>::get(metaverse_id, adjacent).map(|owner| (adjacent, owner)))
+			.collect()
+	}
+
 	fn do_transfer_estate(
 		estate_id: EstateId,
 		from: &T::AccountId,
@@ -1408,18 +3960,45 @@ impl<T: Config> Pallet<T> {
 		EstateOwner::<T>::try_mutate_exists(&estate_id, |estate_owner| -> Result<EstateId, DispatchError> {
 			//ensure there is record of the estate owner with estate id and account id
 			ensure!(from != to, Error::<T>::AlreadyOwnTheEstate);
+			ensure!(
+				!Self::is_estate_collateralized(estate_id),
+				Error::<T>::EstateIsCollateralized
+			);
+			ensure!(!Self::is_frozen(estate_id), Error::<T>::EstateIsFrozen);
+			Self::ensure_estate_not_transferred_in_extrinsic(estate_id)?;
 			let estate_owner_value = Self::get_estate_owner(&estate_id).ok_or(Error::<T>::NoPermission)?;
 			ensure!(
 				Self::check_if_land_or_estate_owner(from, &estate_owner_value, true),
 				Error::<T>::NoPermission
 			);
 
+			// Provenance of tokenized estates is recorded when the NFT pallet moves the token
 			match estate_owner_value {
 				OwnerId::Token(t) => {
 					T::NFTTokenizationSource::transfer_nft(from, to, &(ESTATE_CLASS_ID, t));
 				}
-				OwnerId::Account(a) => *estate_owner = Some(OwnerId::Account(to.clone())),
+				OwnerId::Account(a) => {
+					*estate_owner = Some(OwnerId::Account(to.clone()));
+					Self::record_estate_provenance(estate_id, to);
+				}
+			}
+
+			Self::record_estate_activity(estate_id);
+			Self::note_estate_transfer_in_extrinsic(estate_id);
+			// Designation does not carry over to the new owner
+			EstateBeneficiaries::<T>::remove(estate_id);
+			let estate_account_id: T::AccountId = T::LandTreasury::get().into_sub_account(estate_id);
+			if *from == estate_account_id {
+				Self::end_estate_co_ownership(estate_id)?;
 			}
+			// Lease carries over, rent accrued so far belongs to the previous owner
+			LeaseOffers::<T>::remove(estate_id);
+			EstateLeases::<T>::mutate(estate_id, |maybe_lease| {
+				if let Some(lease) = maybe_lease {
+					Self::do_collect_rent(estate_id, lease);
+					lease.landlord = to.clone();
+				}
+			});
 
 			Self::deposit_event(Event::<T>::TransferredEstate(
 				estate_id.clone(),
@@ -1503,6 +4082,7 @@ impl<T: Config> Pallet<T> {
 				.checked_add(total)
 				.ok_or("Overflow adding new count to total lands")?;
 			AllLandUnitsCount::<T>::put(new_total_land_units_count);
+			T::ActivityRecorder::record_activity(ActivityCounter::LandUnitsDeployed, total.saturated_into());
 		}
 		Ok(())
 	}
@@ -1547,6 +4127,27 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Estate holding a land unit through the estate account, if any
+	fn get_land_unit_estate(
+		metaverse_id: MetaverseId,
+		coordinate: (i32, i32),
+		owner_id: &OwnerId<T::AccountId, TokenId>,
+	) -> Option<EstateId> {
+		let account = match owner_id {
+			OwnerId::Account(account) => account,
+			OwnerId::Token(_) => return None,
+		};
+		let (land_treasury, estate_id) = PalletId::try_from_sub_account::<EstateId>(account)?;
+		if land_treasury != T::LandTreasury::get() {
+			return None;
+		}
+		Self::get_estates(estate_id)
+			.filter(|estate_info| {
+				estate_info.metaverse_id == metaverse_id && estate_info.land_units.contains(&coordinate)
+			})
+			.map(|_| estate_id)
+	}
+
 	fn check_if_land_or_estate_owner(
 		who: &T::AccountId,
 		owner_id: &OwnerId<T::AccountId, TokenId>,
@@ -1565,6 +4166,25 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> OnNftTransfer<T::AccountId, ClassId, TokenId> for Pallet<T> {
+	fn on_nft_transfer(_from: &T::AccountId, to: &T::AccountId, nft: &(ClassId, TokenId)) {
+		if nft.0 != ESTATE_CLASS_ID {
+			return;
+		}
+		if let Some(estate_id) = Self::get_estate_by_token(nft.1) {
+			Self::record_estate_provenance(estate_id, to);
+		}
+	}
+}
+
+impl<T: Config> MetaverseSunsetHandler for Pallet<T> {
+	fn on_metaverse_sunset(metaverse_id: MetaverseId) {
+		// Leases are settled and the tenant deposits are released in bounded batches from the
+		// next block
+		SunsetLeaseCursors::<T>::insert(metaverse_id, EstateId::zero());
+	}
+}
+
 impl<T: Config> MetaverseLandTrait<T::AccountId> for Pallet<T> {
 	fn get_user_land_units(who: &T::AccountId, metaverse_id: &MetaverseId) -> Vec<(i32, i32)> {
 		// Check land units owner.
@@ -1602,6 +4222,10 @@ impl<T: Config> MetaverseLandTrait<T::AccountId> for Pallet<T> {
 	fn is_user_own_metaverse_land(who: &T::AccountId, metaverse_id: &MetaverseId) -> bool {
 		Self::get_user_land_units(&who, metaverse_id).len() > 0
 	}
+
+	fn get_metaverse_land_units_count(metaverse_id: &MetaverseId) -> u64 {
+		Self::metaverse_land_unit_count(metaverse_id).unwrap_or_default()
+	}
 }
 
 impl<T: Config> UndeployedLandBlocksTrait<T::AccountId> for Pallet<T> {
@@ -1681,6 +4305,62 @@ impl<T: Config> Estate<T::AccountId> for Pallet<T> {
 		Ok(LandUnits::<T>::contains_key(metaverse_id, coordinate))
 	}
 
+	fn record_estate_sale(estate_id: EstateId, price: Balance) {
+		EstateProvenance::<T>::mutate(estate_id, |provenance| {
+			if let Some(record) = provenance.last_mut() {
+				record.price = Some(price);
+			}
+		});
+	}
+
+	fn get_land_unit_level(metaverse_id: MetaverseId, coordinate: (i32, i32)) -> u32 {
+		LandUnitLevels::<T>::get(metaverse_id, coordinate)
+	}
+
+	fn is_estate_frozen(estate_id: EstateId) -> bool {
+		Self::is_frozen(estate_id)
+	}
+
+	fn get_estate_royalty(estate_id: EstateId) -> Option<(T::AccountId, Perbill)> {
+		EstateRoyalties::<T>::get(estate_id)
+	}
+
+	fn get_estate_metaverse(estate_id: EstateId) -> Option<MetaverseId> {
+		Estates::<T>::get(estate_id).map(|estate_info| estate_info.metaverse_id)
+	}
+
+	fn record_owner_heartbeat(who: &T::AccountId) {
+		Self::refresh_heartbeat(who)
+	}
+
+	fn is_allowed_to_buy_land(who: &T::AccountId, metaverse_id: MetaverseId) -> bool {
+		Self::is_allowed_to_buy_land(who, metaverse_id)
+	}
+
+	fn is_land_tax_settled(estate_id: EstateId) -> bool {
+		Self::land_tax_due(estate_id).is_zero()
+	}
+
+	fn record_estate_delisted(estate_id: EstateId) {
+		if Estates::<T>::contains_key(estate_id) {
+			Self::record_estate_activity(estate_id);
+		}
+	}
+
+	fn check_estate_ownership(owner: &T::AccountId, estate_id: EstateId) -> Result<bool, DispatchError> {
+		let estate_owner = Self::get_estate_owner(estate_id).ok_or(Error::<T>::EstateDoesNotExist)?;
+		Ok(Self::check_if_land_or_estate_owner(owner, &estate_owner, true))
+	}
+
+	fn check_landunit_ownership(
+		owner: &T::AccountId,
+		metaverse_id: MetaverseId,
+		coordinate: (i32, i32),
+	) -> Result<bool, DispatchError> {
+		let land_unit_owner = Self::get_land_units(metaverse_id, coordinate).ok_or(Error::<T>::LandUnitDoesNotExist)?;
+		Ok(Self::check_if_land_or_estate_owner(owner, &land_unit_owner, false))
+	}
+
 	fn get_total_land_units() -> u64 {
 		AllLandUnitsCount::<T>::get()
 	}
@@ -1688,4 +4368,37 @@ impl<T: Config> Estate<T::AccountId> for Pallet<T> {
 	fn get_total_undeploy_land_units() -> u64 {
 		TotalUndeployedLandUnit::<T>::get()
 	}
+
+	fn check_adjacent_landunit_owner(who: &T::AccountId, metaverse_id: MetaverseId, coordinate: (i32, i32)) -> bool {
+		Self::get_adjacent_land_units(metaverse_id, coordinate)
+			.iter()
+			.any(|(_, owner)| Self::check_if_land_or_estate_owner(who, owner, false))
+	}
+
+	fn has_adjacent_landunits(seller: &T::AccountId, metaverse_id: MetaverseId, coordinate: (i32, i32)) -> bool {
+		Self::get_adjacent_land_units(metaverse_id, coordinate)
+			.iter()
+			.any(|(_, owner)| !Self::check_if_land_or_estate_owner(seller, owner, false))
+	}
+
+	fn get_estate_stakes(start_after: Option<T::AccountId>, limit: u32) -> Vec<(T::AccountId, Balance)> {
+		let stakes = match start_after {
+			Some(staker) => StakerEstateStake::<T>::iter_from(StakerEstateStake::<T>::hashed_key_for(staker)),
+			None => StakerEstateStake::<T>::iter(),
+		};
+		stakes
+			.take(limit as usize)
+			.map(|(staker, stake)| (staker, stake.saturated_into()))
+			.collect()
+	}
+
+	fn get_metaverse_land_units(start_after: Option<MetaverseId>, limit: u32) -> Vec<(MetaverseId, u64)> {
+		let land_units = match start_after {
+			Some(metaverse_id) => {
+				MetaverseLandUnitCount::<T>::iter_from(MetaverseLandUnitCount::<T>::hashed_key_for(metaverse_id))
+			}
+			None => MetaverseLandUnitCount::<T>::iter(),
+		};
+		land_units.take(limit as usize).collect()
+	}
 }
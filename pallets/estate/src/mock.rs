@@ -1,15 +1,17 @@
 #![cfg(test)]
 
 use frame_support::{construct_runtime, ord_parameter_types, parameter_types, PalletId};
-use frame_system::EnsureSignedBy;
+use frame_system::{EnsureRoot, EnsureSignedBy};
+use orml_traits::parameter_type_with_key;
 use sp_core::H256;
 use sp_runtime::{testing::Header, traits::IdentityLookup, DispatchError, Perbill};
-use sp_std::collections::btree_map::BTreeMap;
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 use sp_std::default::Default;
+use std::cell::RefCell;
 
 use auction_manager::{Auction, AuctionInfo, AuctionType, CheckAuctionItemHandler, ListingLevel};
 use core_primitives::{CollectionType, NftClassData, TokenType};
-use primitives::{AssetId, Attributes, ClassId, FungibleTokenId, GroupCollectionId, NftMetadata, TokenId};
+use primitives::{Amount, AssetId, Attributes, ClassId, FungibleTokenId, GroupCollectionId, NftMetadata, TokenId};
 
 use crate as estate;
 
@@ -25,10 +27,12 @@ pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 5;
 pub const BENEFICIARY_ID: AccountId = 99;
 pub const CLASS_FUND_ID: AccountId = 123;
+pub const METAVERSE_TREASURY_ID: AccountId = 88;
 pub const METAVERSE_ID: MetaverseId = 0;
 pub const DOLLARS: Balance = 1_000_000_000_000_000_000;
 pub const ALICE_METAVERSE_ID: MetaverseId = 1;
 pub const BOB_METAVERSE_ID: MetaverseId = 2;
+pub const SUNSET_METAVERSE_ID: MetaverseId = 3;
 pub const MAX_BOUND: (i32, i32) = (-100, 100);
 pub const COORDINATE_IN_1: (i32, i32) = (-10, 10);
 pub const COORDINATE_IN_2: (i32, i32) = (-5, 5);
@@ -54,6 +58,32 @@ pub const OWNER_ID_ALICE: OwnerId<AccountId, TokenId> = OwnerId::Account(ALICE);
 pub const OWNER_LAND_ASSET_ID: OwnerId<AccountId, TokenId> = OwnerId::Token(ASSET_ID_1);
 pub const OWNER_ESTATE_ASSET_ID: OwnerId<AccountId, TokenId> = OwnerId::Token(ASSET_ID_2);
 
+thread_local! {
+	static LOCKED_NFTS: RefCell<BTreeSet<(ClassId, TokenId)>> = RefCell::new(BTreeSet::new());
+	static MINING_BURNED: RefCell<Balance> = RefCell::new(0);
+	static FROZEN_NFTS: RefCell<BTreeSet<(ClassId, TokenId)>> = RefCell::new(BTreeSet::new());
+}
+
+pub fn is_nft_frozen(nft: &(ClassId, TokenId)) -> bool {
+	FROZEN_NFTS.with(|frozen_nfts| frozen_nfts.borrow().contains(nft))
+}
+
+pub fn is_nft_locked(nft: &(ClassId, TokenId)) -> bool {
+	LOCKED_NFTS.with(|locked_nfts| locked_nfts.borrow().contains(nft))
+}
+
+pub fn mining_burned() -> Balance {
+	MINING_BURNED.with(|burned| *burned.borrow())
+}
+
+pub struct MockMiningBurnRecorder;
+
+impl MiningBurnRecorder for MockMiningBurnRecorder {
+	fn record_mining_burn(amount: Balance) {
+		MINING_BURNED.with(|burned| *burned.borrow_mut() += amount);
+	}
+}
+
 ord_parameter_types! {
 	pub const One: AccountId = ALICE;
 }
@@ -110,6 +140,24 @@ impl pallet_balances::Config for Runtime {
 	type ReserveIdentifier = ();
 }
 
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: FungibleTokenId| -> Balance {
+		Default::default()
+	};
+}
+
+impl orml_tokens::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = FungibleTokenId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+	type MaxLocks = ();
+	type DustRemovalWhitelist = frame_support::traits::Nothing;
+}
+
 // pub type AdaptedBasicCurrency =
 // currencies::BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
 
@@ -150,6 +198,14 @@ impl MetaverseTrait<AccountId> for MetaverseInfoSource {
 	fn get_metaverse_estate_class(metaverse_id: MetaverseId) -> ClassId {
 		16u32
 	}
+
+	fn get_metaverse_treasury(metaverse_id: MetaverseId) -> AccountId {
+		METAVERSE_TREASURY_ID
+	}
+
+	fn is_metaverse_sunset(metaverse_id: MetaverseId) -> bool {
+		metaverse_id == SUNSET_METAVERSE_ID
+	}
 }
 
 pub struct MockAuctionManager;
@@ -323,6 +379,7 @@ impl NFTTrait<AccountId, Balance> for MockNFTHandler {
 	}
 
 	fn transfer_nft(from: &AccountId, to: &AccountId, nft: &(Self::ClassId, Self::TokenId)) -> DispatchResult {
+		<EstateModule as OnNftTransfer<AccountId, ClassId, TokenId>>::on_nft_transfer(from, to, nft);
 		Ok(())
 	}
 
@@ -337,6 +394,28 @@ impl NFTTrait<AccountId, Balance> for MockNFTHandler {
 		Ok(true)
 	}
 
+	fn set_lock_nft(nft: &(Self::ClassId, Self::TokenId), is_locked: bool) -> DispatchResult {
+		LOCKED_NFTS.with(|locked_nfts| {
+			if is_locked {
+				locked_nfts.borrow_mut().insert(*nft);
+			} else {
+				locked_nfts.borrow_mut().remove(nft);
+			}
+		});
+		Ok(())
+	}
+
+	fn set_freeze_nft(nft: &(Self::ClassId, Self::TokenId), is_frozen: bool) -> DispatchResult {
+		FROZEN_NFTS.with(|frozen_nfts| {
+			if is_frozen {
+				frozen_nfts.borrow_mut().insert(*nft);
+			} else {
+				frozen_nfts.borrow_mut().remove(nft);
+			}
+		});
+		Ok(())
+	}
+
 	fn get_class_fund(class_id: &Self::ClassId) -> AccountId {
 		CLASS_FUND_ID
 	}
@@ -359,6 +438,16 @@ parameter_types! {
 	pub const MinimumStake: Balance = 200;
 	/// Reward payments are delayed by 2 hours (2 * 300 * block_time)
 	pub const RewardPaymentDelay: u32 = 2;
+	pub const MaxEstateMetadata: u32 = 32;
+	pub const MaxEstateAttributes: u32 = 2;
+	pub const ForceTransferEnabled: bool = true;
+	pub const MaxCoOwners: u32 = 3;
+	pub const CoOwnershipDeadlockPeriod: u64 = 10;
+	pub const MaxEstateProvenance: u32 = 3;
+	pub const MaxLandUnitLevel: u32 = 2;
+	pub const GiftCardCurrencyId: FungibleTokenId = FungibleTokenId::Stable(0);
+	pub const MaxGiftCardPoolSize: u32 = 3;
+	pub const MaxLeaseExpiriesPerBlock: u32 = 2;
 }
 
 impl Config for Runtime {
@@ -374,6 +463,21 @@ impl Config for Runtime {
 	type MinimumStake = MinimumStake;
 	type RewardPaymentDelay = RewardPaymentDelay;
 	type NFTTokenizationSource = MockNFTHandler;
+	type MaxEstateMetadata = MaxEstateMetadata;
+	type MaxEstateAttributes = MaxEstateAttributes;
+	type ForceTransferOrigin = EnsureRoot<AccountId>;
+	type ForceTransferEnabled = ForceTransferEnabled;
+	type MaxCoOwners = MaxCoOwners;
+	type CoOwnershipDeadlockPeriod = CoOwnershipDeadlockPeriod;
+	type MaxEstateProvenance = MaxEstateProvenance;
+	type FungibleTokenCurrency = Tokens;
+	type MiningResourceId = MiningCurrencyId;
+	type MaxLandUnitLevel = MaxLandUnitLevel;
+	type GiftCardCurrencyId = GiftCardCurrencyId;
+	type MaxGiftCardPoolSize = MaxGiftCardPoolSize;
+	type ActivityRecorder = ();
+	type MaxLeaseExpiriesPerBlock = MaxLeaseExpiriesPerBlock;
+	type MiningBurnRecorder = MockMiningBurnRecorder;
 }
 
 construct_runtime!(
@@ -384,6 +488,7 @@ construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Tokens: orml_tokens::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Estate: estate:: {Pallet, Call, Storage, Event<T>},
 	}
 );
@@ -413,6 +518,15 @@ impl ExtBuilder {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: vec![
+				(ALICE, MiningCurrencyId::get(), 1000),
+				(BOB, GiftCardCurrencyId::get(), 1000),
+			],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
 		let mut ext = sp_io::TestExternalities::new(t);
 		ext.execute_with(|| System::set_block_number(1));
 		ext
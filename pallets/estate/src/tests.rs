@@ -18,6 +18,7 @@
 #![cfg(test)]
 
 use frame_support::{assert_err, assert_noop, assert_ok};
+use sp_core::H256;
 use sp_runtime::traits::BadOrigin;
 
 use mock::{Event, *};
@@ -1774,6 +1775,7 @@ fn deploy_undeployed_land_block_should_work() {
 			}
 		}
 
+		assert!(!EstateModule::has_deployed_land(&BOB));
 		assert_ok!(EstateModule::deploy_land_block(
 			Origin::signed(BOB),
 			undeployed_land_block_id,
@@ -1781,6 +1783,7 @@ fn deploy_undeployed_land_block_should_work() {
 			vec![COORDINATE_IN_1, COORDINATE_IN_2],
 			false
 		));
+		assert!(EstateModule::has_deployed_land(&BOB));
 
 		assert_eq!(
 			last_event(),
@@ -2248,6 +2251,103 @@ fn bond_more_should_work() {
 	});
 }
 
+#[test]
+fn get_account_estate_stake_should_sum_stakes_over_estates() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1],
+			false
+		));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_2],
+			false
+		));
+
+		assert_ok!(EstateModule::bond_more(
+			Origin::signed(BENEFICIARY_ID),
+			0,
+			BOND_AMOUNT_1
+		));
+		assert_ok!(EstateModule::bond_more(
+			Origin::signed(BENEFICIARY_ID),
+			1,
+			BOND_AMOUNT_2
+		));
+
+		assert_eq!(
+			EstateModule::get_account_estate_stake(&BENEFICIARY_ID),
+			BOND_AMOUNT_1 + BOND_AMOUNT_2
+		);
+		assert_eq!(EstateModule::get_account_estate_stake(&BOB), 0);
+	});
+}
+
+#[test]
+fn estate_stakes_and_land_units_should_be_paged_from_totals() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::set_max_bounds(
+			Origin::root(),
+			BOB_METAVERSE_ID,
+			MAX_BOUND
+		));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+		assert_ok!(EstateModule::mint_land(
+			Origin::root(),
+			BENEFICIARY_ID,
+			BOB_METAVERSE_ID,
+			COORDINATE_IN_1,
+			false
+		));
+
+		assert_ok!(EstateModule::bond_more(
+			Origin::signed(BENEFICIARY_ID),
+			0,
+			BOND_AMOUNT_2
+		));
+		assert_ok!(EstateModule::bond_less(
+			Origin::signed(BENEFICIARY_ID),
+			0,
+			BOND_AMOUNT_1
+		));
+
+		assert_eq!(
+			EstateModule::staker_estate_stake(BENEFICIARY_ID),
+			Some(BOND_AMOUNT_2 - BOND_AMOUNT_1)
+		);
+		assert_eq!(
+			<EstateModule as Estate<AccountId>>::get_estate_stakes(None, 10),
+			vec![(BENEFICIARY_ID, BOND_AMOUNT_2 - BOND_AMOUNT_1)]
+		);
+		assert_eq!(
+			<EstateModule as Estate<AccountId>>::get_estate_stakes(Some(BENEFICIARY_ID), 10),
+			vec![]
+		);
+
+		assert_eq!(EstateModule::get_metaverse_land_units_count(&METAVERSE_ID), 2);
+		let first_page = <EstateModule as Estate<AccountId>>::get_metaverse_land_units(None, 1);
+		assert_eq!(first_page.len(), 1);
+		let second_page = <EstateModule as Estate<AccountId>>::get_metaverse_land_units(Some(first_page[0].0), 1);
+		assert_eq!(second_page.len(), 1);
+		let mut land_units = [first_page, second_page].concat();
+		land_units.sort();
+		assert_eq!(land_units, vec![(METAVERSE_ID, 2), (BOB_METAVERSE_ID, 1)]);
+	});
+}
+
 #[test]
 fn bond_more_should_work_with_more_than_one_operation() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -2568,3 +2668,2238 @@ fn bond_more_should_reject_stake_has_already_left() {
 		);
 	});
 }
+
+#[test]
+fn set_land_tax_should_reject_non_council() {
+	ExtBuilder::default().build().execute_with(|| {
+		let land_tax = LandTaxInfo {
+			tax_per_land_unit: 10,
+			idle_rounds: 2,
+		};
+		assert_noop!(
+			EstateModule::set_land_tax(Origin::signed(BOB), METAVERSE_ID, Some(land_tax)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn idle_estate_should_accrue_land_tax() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		let land_tax = LandTaxInfo {
+			tax_per_land_unit: 10,
+			idle_rounds: 2,
+		};
+		assert_ok!(EstateModule::set_land_tax(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			Some(land_tax.clone())
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::LandTaxUpdated(METAVERSE_ID, Some(land_tax)))
+		);
+
+		// Still in the grace period
+		System::set_block_number(41);
+		assert_eq!(EstateModule::land_tax_due(estate_id), 0);
+
+		// 4 idle rounds, 2 taxable rounds for 2 land units
+		System::set_block_number(81);
+		assert_eq!(EstateModule::land_tax_due(estate_id), 40);
+
+		assert_noop!(
+			EstateModule::transfer_estate(Origin::signed(BENEFICIARY_ID), ALICE, estate_id),
+			Error::<Runtime>::LandTaxOutstanding
+		);
+		assert!(!<EstateModule as Estate<AccountId>>::is_land_tax_settled(estate_id));
+	});
+}
+
+#[test]
+fn listed_estate_should_not_accrue_land_tax() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+		assert_ok!(EstateModule::set_land_tax(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			Some(LandTaxInfo {
+				tax_per_land_unit: 10,
+				idle_rounds: 2,
+			})
+		));
+
+		System::set_block_number(81);
+		assert_eq!(EstateModule::land_tax_due(0), 40);
+
+		// Time spent on the marketplace does not count as idle time
+
+		<EstateModule as Estate<AccountId>>::record_estate_delisted(0);
+		assert_eq!(EstateModule::land_tax_due(0), 0);
+		assert!(<EstateModule as Estate<AccountId>>::is_land_tax_settled(0));
+	});
+}
+
+#[test]
+fn upgrade_estate_last_activity_v1_should_backfill_existing_estates() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+		assert_ok!(EstateModule::set_land_tax(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			Some(LandTaxInfo {
+				tax_per_land_unit: 10,
+				idle_rounds: 2,
+			})
+		));
+
+		// Estate created before idle land tax existed
+		EstateLastActivity::<Runtime>::remove(0);
+		System::set_block_number(81);
+		assert_eq!(EstateModule::land_tax_due(0), 40);
+
+		EstateModule::upgrade_estate_last_activity_v1();
+		assert_eq!(EstateModule::get_estate_last_activity(0), 81);
+		assert_eq!(EstateModule::land_tax_due(0), 0);
+	});
+}
+
+#[test]
+fn upgrade_estate_tokens_v2_should_index_tokenized_estates() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			true
+		));
+
+		// Estate tokenized before estate tokens were indexed
+		EstateTokens::<Runtime>::remove(ASSET_ID_2);
+
+		EstateModule::upgrade_estate_tokens_v2();
+		assert_eq!(EstateModule::get_estate_by_token(ASSET_ID_2), Some(0));
+	});
+}
+
+#[test]
+fn pay_land_tax_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		assert_ok!(EstateModule::set_land_tax(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			Some(LandTaxInfo {
+				tax_per_land_unit: 10,
+				idle_rounds: 2,
+			})
+		));
+
+		assert_noop!(
+			EstateModule::pay_land_tax(Origin::signed(BENEFICIARY_ID), estate_id),
+			Error::<Runtime>::NoLandTaxDue
+		);
+
+		System::set_block_number(81);
+		assert_noop!(
+			EstateModule::pay_land_tax(Origin::signed(BOB), estate_id),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(EstateModule::pay_land_tax(Origin::signed(BENEFICIARY_ID), estate_id));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::LandTaxPaid(estate_id, BENEFICIARY_ID, 40))
+		);
+		assert_eq!(Balances::free_balance(METAVERSE_TREASURY_ID), 40);
+		assert_eq!(EstateModule::land_tax_due(estate_id), 0);
+
+		// Paid estate keeps accruing tax while idle
+		System::set_block_number(101);
+		assert_eq!(EstateModule::land_tax_due(estate_id), 20);
+	});
+}
+
+#[test]
+fn land_tax_exempted_estate_should_not_accrue_tax() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		assert_ok!(EstateModule::set_land_tax(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			Some(LandTaxInfo {
+				tax_per_land_unit: 10,
+				idle_rounds: 2,
+			})
+		));
+		assert_ok!(EstateModule::set_land_tax_exemption(
+			Origin::signed(ALICE),
+			estate_id,
+			true
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::LandTaxExemptionUpdated(estate_id, true))
+		);
+
+		System::set_block_number(81);
+		assert_eq!(EstateModule::land_tax_due(estate_id), 0);
+		assert_ok!(EstateModule::transfer_estate(
+			Origin::signed(BENEFICIARY_ID),
+			ALICE,
+			estate_id
+		));
+	});
+}
+
+#[test]
+fn set_estate_beneficiary_should_reject_non_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		assert_noop!(
+			EstateModule::set_estate_beneficiary(Origin::signed(BOB), 0, Some(BOB), 10),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			EstateModule::set_estate_beneficiary(Origin::signed(BENEFICIARY_ID), 0, Some(BOB), 0),
+			Error::<Runtime>::InvalidInactivityPeriod
+		);
+	});
+}
+
+#[test]
+fn claim_inherited_estate_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		assert_ok!(EstateModule::set_estate_beneficiary(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			Some(BOB),
+			10
+		));
+		assert_eq!(
+			EstateModule::get_estate_beneficiary(estate_id),
+			Some(EstateBeneficiaryInfo {
+				owner: BENEFICIARY_ID,
+				beneficiary: BOB,
+				inactivity_period: 10,
+			})
+		);
+
+		assert_noop!(
+			EstateModule::claim_inherited_estate(Origin::signed(ALICE), estate_id),
+			Error::<Runtime>::NoPermission
+		);
+
+		// Owner heartbeat resets the inactivity period
+		System::set_block_number(8);
+		assert_ok!(EstateModule::ping(Origin::signed(BENEFICIARY_ID)));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::HeartbeatReceived(BENEFICIARY_ID, 8))
+		);
+
+		System::set_block_number(15);
+		assert_noop!(
+			EstateModule::claim_inherited_estate(Origin::signed(BOB), estate_id),
+			Error::<Runtime>::EstateOwnerStillActive
+		);
+
+		System::set_block_number(18);
+		assert_ok!(EstateModule::claim_inherited_estate(Origin::signed(BOB), estate_id));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::EstateInherited(estate_id, BENEFICIARY_ID, BOB))
+		);
+		assert_eq!(EstateModule::get_estate_owner(estate_id), Some(OwnerId::Account(BOB)));
+		assert_eq!(EstateModule::get_estate_beneficiary(estate_id), None);
+	});
+}
+
+#[test]
+fn owner_estate_actions_should_refresh_heartbeat() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		assert_ok!(EstateModule::set_estate_metadata(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			vec![1]
+		));
+		// Heartbeat is only tracked once a beneficiary is designated
+		assert!(!LastHeartbeat::<Runtime>::contains_key(BENEFICIARY_ID));
+
+		assert_ok!(EstateModule::set_estate_beneficiary(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			Some(BOB),
+			10
+		));
+
+		System::set_block_number(8);
+		assert_ok!(EstateModule::set_estate_metadata(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			vec![2]
+		));
+		assert_eq!(EstateModule::get_last_heartbeat(BENEFICIARY_ID), 8);
+
+		System::set_block_number(15);
+		assert_noop!(
+			EstateModule::claim_inherited_estate(Origin::signed(BOB), estate_id),
+			Error::<Runtime>::EstateOwnerStillActive
+		);
+	});
+}
+
+#[test]
+fn transfer_estate_should_clear_beneficiary() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		assert_ok!(EstateModule::set_estate_beneficiary(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			Some(BOB),
+			10
+		));
+		assert_ok!(EstateModule::transfer_estate(
+			Origin::signed(BENEFICIARY_ID),
+			ALICE,
+			estate_id
+		));
+
+		System::set_block_number(100);
+		assert_noop!(
+			EstateModule::claim_inherited_estate(Origin::signed(BOB), estate_id),
+			Error::<Runtime>::NoEstateBeneficiary
+		);
+	});
+}
+
+#[test]
+fn set_estate_metadata_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		assert_noop!(
+			EstateModule::set_estate_metadata(Origin::signed(BOB), estate_id, b"ipfs://estate".to_vec()),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			EstateModule::set_estate_metadata(Origin::signed(BENEFICIARY_ID), estate_id, vec![1u8; 33]),
+			Error::<Runtime>::ExceedMaximumMetadataLength
+		);
+
+		assert_ok!(EstateModule::set_estate_metadata(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			b"ipfs://estate".to_vec()
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::EstateMetadataUpdated(
+				estate_id,
+				b"ipfs://estate".to_vec()
+			))
+		);
+		assert_eq!(
+			EstateModule::get_estate_metadata(estate_id),
+			Some(b"ipfs://estate".to_vec())
+		);
+	});
+}
+
+#[test]
+fn set_estate_attribute_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		assert_ok!(EstateModule::set_estate_attribute(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			b"terrain".to_vec(),
+			Some(EstateAttributeValue::Text(b"desert".to_vec()))
+		));
+		assert_ok!(EstateModule::set_estate_attribute(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			b"elevation".to_vec(),
+			Some(EstateAttributeValue::Integer(-12))
+		));
+		assert_noop!(
+			EstateModule::set_estate_attribute(
+				Origin::signed(BENEFICIARY_ID),
+				estate_id,
+				b"waterfront".to_vec(),
+				Some(EstateAttributeValue::Bool(true))
+			),
+			Error::<Runtime>::ExceedMaximumAttributes
+		);
+
+		assert_ok!(EstateModule::set_estate_attribute(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			b"elevation".to_vec(),
+			None
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::EstateAttributeUpdated(
+				estate_id,
+				b"elevation".to_vec(),
+				None
+			))
+		);
+
+		let attributes = EstateModule::get_estate_attributes(estate_id);
+		assert_eq!(attributes.len(), 1);
+		assert_eq!(
+			attributes.get(&b"terrain".to_vec()),
+			Some(&EstateAttributeValue::Text(b"desert".to_vec()))
+		);
+	});
+}
+
+#[test]
+fn frozen_estate_metadata_should_reject_updates() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		assert_ok!(EstateModule::freeze_estate_metadata(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::EstateMetadataFrozen(estate_id))
+		);
+
+		assert_noop!(
+			EstateModule::set_estate_metadata(Origin::signed(BENEFICIARY_ID), estate_id, b"ipfs://estate".to_vec()),
+			Error::<Runtime>::EstateMetadataFrozen
+		);
+		assert_noop!(
+			EstateModule::set_estate_attribute(
+				Origin::signed(BENEFICIARY_ID),
+				estate_id,
+				b"terrain".to_vec(),
+				Some(EstateAttributeValue::Bool(true))
+			),
+			Error::<Runtime>::EstateMetadataFrozen
+		);
+	});
+}
+
+#[test]
+fn force_transfer_asset_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		let justification = H256::repeat_byte(1);
+		assert_noop!(
+			EstateModule::force_transfer_asset(
+				Origin::signed(ALICE),
+				ItemId::Estate(estate_id),
+				BENEFICIARY_ID,
+				BOB,
+				justification
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			EstateModule::force_transfer_asset(
+				Origin::root(),
+				ItemId::Country(METAVERSE_ID),
+				BENEFICIARY_ID,
+				BOB,
+				justification
+			),
+			Error::<Runtime>::InvalidForceTransferAsset
+		);
+
+		assert_ok!(EstateModule::force_transfer_asset(
+			Origin::root(),
+			ItemId::Estate(estate_id),
+			BENEFICIARY_ID,
+			BOB,
+			justification
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::AssetForceTransferred(
+				ItemId::Estate(estate_id),
+				BENEFICIARY_ID,
+				BOB,
+				justification
+			))
+		);
+		assert_eq!(EstateModule::get_estate_owner(estate_id), Some(OwnerId::Account(BOB)));
+	});
+}
+
+#[test]
+fn get_adjacent_land_units_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_lands(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![(0, 0), (0, 1), (1, 1)],
+			false
+		));
+
+		assert_eq!(
+			EstateModule::get_adjacent_land_units(METAVERSE_ID, (0, 0)),
+			vec![((0, 1), OWNER_ACCOUNT_ID)]
+		);
+		assert!(EstateModule::check_adjacent_landunit_owner(
+			&BENEFICIARY_ID,
+			METAVERSE_ID,
+			(1, 0)
+		));
+		assert!(!EstateModule::check_adjacent_landunit_owner(&BOB, METAVERSE_ID, (1, 0)));
+		assert!(!EstateModule::check_adjacent_landunit_owner(
+			&BENEFICIARY_ID,
+			METAVERSE_ID,
+			(5, 5)
+		));
+
+		// Land of the seller does not count as a neighbour
+		assert!(EstateModule::has_adjacent_landunits(&BOB, METAVERSE_ID, (1, 0)));
+		assert!(!EstateModule::has_adjacent_landunits(
+			&BENEFICIARY_ID,
+			METAVERSE_ID,
+			(1, 0)
+		));
+		assert!(!EstateModule::has_adjacent_landunits(&BOB, METAVERSE_ID, (5, 5)));
+	});
+}
+
+#[test]
+fn set_estate_co_owners_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		assert_noop!(
+			EstateModule::set_estate_co_owners(Origin::signed(BENEFICIARY_ID), estate_id, vec![BENEFICIARY_ID], 1),
+			Error::<Runtime>::InvalidCoOwners
+		);
+		assert_noop!(
+			EstateModule::set_estate_co_owners(
+				Origin::signed(BENEFICIARY_ID),
+				estate_id,
+				vec![BENEFICIARY_ID, ALICE, BENEFICIARY_ID],
+				2
+			),
+			Error::<Runtime>::InvalidCoOwners
+		);
+		assert_noop!(
+			EstateModule::set_estate_co_owners(
+				Origin::signed(BENEFICIARY_ID),
+				estate_id,
+				vec![BENEFICIARY_ID, ALICE, BOB, METAVERSE_TREASURY_ID],
+				2
+			),
+			Error::<Runtime>::InvalidCoOwners
+		);
+		assert_noop!(
+			EstateModule::set_estate_co_owners(
+				Origin::signed(BENEFICIARY_ID),
+				estate_id,
+				vec![BENEFICIARY_ID, ALICE],
+				3
+			),
+			Error::<Runtime>::InvalidCoOwnershipThreshold
+		);
+		assert_noop!(
+			EstateModule::set_estate_co_owners(Origin::signed(BOB), estate_id, vec![BOB, ALICE], 2),
+			Error::<Runtime>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn co_owned_estate_transfer_should_require_threshold() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		let estate_account_id: AccountId = LandTreasuryPalletId::get().into_sub_account(estate_id);
+		assert_ok!(EstateModule::set_estate_co_owners(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			vec![BENEFICIARY_ID, ALICE, BOB],
+			2
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::EstateCoOwnershipSet(
+				estate_id,
+				vec![BENEFICIARY_ID, ALICE, BOB],
+				2
+			))
+		);
+		assert_eq!(
+			EstateModule::get_estate_owner(estate_id),
+			Some(OwnerId::Account(estate_account_id))
+		);
+
+		// Single co-owner can no longer transfer the estate
+		assert_noop!(
+			EstateModule::transfer_estate(Origin::signed(BENEFICIARY_ID), BOB, estate_id),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(EstateModule::propose_co_ownership_action(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			CoOwnershipAction::Transfer(BOB)
+		));
+		assert_noop!(
+			EstateModule::approve_co_ownership_action(Origin::signed(BENEFICIARY_ID), estate_id),
+			Error::<Runtime>::AlreadyApprovedCoOwnershipProposal
+		);
+		assert_noop!(
+			EstateModule::approve_co_ownership_action(Origin::signed(METAVERSE_TREASURY_ID), estate_id),
+			Error::<Runtime>::NotCoOwner
+		);
+
+		// Sale proceeds held by the estate account are split among co-owners
+		assert_ok!(Balances::transfer(
+			Origin::signed(BENEFICIARY_ID),
+			estate_account_id,
+			300
+		));
+		assert_ok!(EstateModule::approve_co_ownership_action(
+			Origin::signed(ALICE),
+			estate_id
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::CoOwnershipActionExecuted(
+				estate_id,
+				CoOwnershipAction::Transfer(BOB),
+				vec![BENEFICIARY_ID, ALICE]
+			))
+		);
+		assert_eq!(EstateModule::get_estate_owner(estate_id), Some(OwnerId::Account(BOB)));
+		assert_eq!(EstateModule::get_estate_co_ownership(estate_id), None);
+		assert_eq!(EstateModule::get_co_ownership_proposal(estate_id), None);
+		assert_eq!(Balances::free_balance(ALICE), 100100);
+		assert_eq!(Balances::free_balance(BOB), 100100);
+	});
+}
+
+#[test]
+fn resolve_co_ownership_deadlock_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		assert_ok!(EstateModule::set_estate_co_owners(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			vec![BENEFICIARY_ID, BOB],
+			2
+		));
+		assert_ok!(EstateModule::propose_co_ownership_action(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			CoOwnershipAction::AddCoOwner(METAVERSE_TREASURY_ID)
+		));
+		assert_noop!(
+			EstateModule::propose_co_ownership_action(
+				Origin::signed(BOB),
+				estate_id,
+				CoOwnershipAction::SetThreshold(1)
+			),
+			Error::<Runtime>::CoOwnershipProposalPending
+		);
+
+		assert_noop!(
+			EstateModule::resolve_co_ownership_deadlock(Origin::signed(BOB), estate_id, true),
+			BadOrigin
+		);
+		assert_noop!(
+			EstateModule::resolve_co_ownership_deadlock(Origin::signed(ALICE), estate_id, true),
+			Error::<Runtime>::CoOwnershipDeadlockPeriodNotPassed
+		);
+
+		System::set_block_number(11);
+		assert_ok!(EstateModule::resolve_co_ownership_deadlock(
+			Origin::signed(ALICE),
+			estate_id,
+			true
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::CoOwnershipDeadlockResolved(estate_id, true))
+		);
+		assert_eq!(
+			EstateModule::get_estate_co_ownership(estate_id),
+			Some(EstateCoOwnershipInfo {
+				co_owners: vec![BENEFICIARY_ID, BOB, METAVERSE_TREASURY_ID],
+				threshold: 2,
+			})
+		);
+		assert_eq!(EstateModule::get_co_ownership_proposal(estate_id), None);
+	});
+}
+
+#[test]
+fn cancel_co_ownership_action_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		assert_ok!(EstateModule::set_estate_co_owners(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			vec![BENEFICIARY_ID, BOB],
+			2
+		));
+		assert_noop!(
+			EstateModule::cancel_co_ownership_action(Origin::signed(BENEFICIARY_ID), estate_id),
+			Error::<Runtime>::NoCoOwnershipProposal
+		);
+		assert_ok!(EstateModule::propose_co_ownership_action(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			CoOwnershipAction::Transfer(BOB)
+		));
+
+		// Only the proposer can cancel
+		assert_noop!(
+			EstateModule::cancel_co_ownership_action(Origin::signed(BOB), estate_id),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(EstateModule::cancel_co_ownership_action(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::CoOwnershipActionCancelled(estate_id, BENEFICIARY_ID))
+		);
+		assert_eq!(EstateModule::get_co_ownership_proposal(estate_id), None);
+
+		// New action can be proposed once the pending one is cancelled
+		assert_ok!(EstateModule::propose_co_ownership_action(
+			Origin::signed(BOB),
+			estate_id,
+			CoOwnershipAction::SetThreshold(1)
+		));
+	});
+}
+
+#[test]
+fn ending_co_ownership_should_only_split_co_ownership_funds() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		let estate_account_id: AccountId = LandTreasuryPalletId::get().into_sub_account(estate_id);
+		// Funds held by the estate account before the co-ownership are not split
+		assert_ok!(Balances::transfer(
+			Origin::signed(BENEFICIARY_ID),
+			estate_account_id,
+			60
+		));
+		assert_ok!(EstateModule::set_estate_co_owners(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			vec![BENEFICIARY_ID, ALICE, BOB],
+			2
+		));
+		assert_eq!(EstateModule::get_co_ownership_opening_balance(estate_id), 60);
+
+		assert_ok!(Balances::transfer(
+			Origin::signed(BENEFICIARY_ID),
+			estate_account_id,
+			300
+		));
+		assert_ok!(EstateModule::propose_co_ownership_action(
+			Origin::signed(ALICE),
+			estate_id,
+			CoOwnershipAction::Transfer(BOB)
+		));
+		assert_ok!(EstateModule::approve_co_ownership_action(
+			Origin::signed(BOB),
+			estate_id
+		));
+
+		assert_eq!(Balances::free_balance(ALICE), 100100);
+		assert_eq!(Balances::free_balance(BOB), 100100);
+		assert_eq!(Balances::free_balance(estate_account_id), 60);
+		assert_eq!(EstateModule::get_co_ownership_opening_balance(estate_id), 0);
+	});
+}
+
+#[test]
+fn mint_land_should_respect_map_bounds() {
+	ExtBuilder::default().build().execute_with(|| {
+		let map_bound = MapBound {
+			min: (-10, 0),
+			max: (10, 5),
+		};
+		assert_noop!(
+			EstateModule::set_map_bounds(
+				Origin::root(),
+				METAVERSE_ID,
+				MapBound {
+					min: (10, 0),
+					max: (-10, 5)
+				}
+			),
+			Error::<Runtime>::InvalidMapBound
+		);
+		assert_ok!(EstateModule::set_map_bounds(Origin::root(), METAVERSE_ID, map_bound));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::MapBoundSet(METAVERSE_ID, map_bound))
+		);
+
+		assert_noop!(
+			EstateModule::mint_land(Origin::root(), BENEFICIARY_ID, METAVERSE_ID, (0, 6), false),
+			Error::<Runtime>::LandUnitIsOutOfBound
+		);
+		assert_ok!(EstateModule::mint_land(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			(10, 5),
+			false
+		));
+
+		let extended_bound = MapBound {
+			min: (-10, 0),
+			max: (10, 10),
+		};
+		assert_noop!(
+			EstateModule::extend_map_bounds(Origin::signed(BOB), METAVERSE_ID, extended_bound),
+			BadOrigin
+		);
+		assert_noop!(
+			EstateModule::extend_map_bounds(
+				Origin::signed(ALICE),
+				METAVERSE_ID,
+				MapBound {
+					min: (-5, 0),
+					max: (10, 10)
+				}
+			),
+			Error::<Runtime>::MapBoundCanOnlyBeExtended
+		);
+		assert_ok!(EstateModule::extend_map_bounds(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			extended_bound
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::MapBoundExtended(METAVERSE_ID, extended_bound))
+		);
+		assert_ok!(EstateModule::mint_land(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			(0, 6),
+			false
+		));
+	});
+}
+
+#[test]
+fn mint_lands_should_reject_duplicate_coordinates() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+
+		assert_noop!(
+			EstateModule::mint_lands(
+				Origin::root(),
+				BENEFICIARY_ID,
+				METAVERSE_ID,
+				vec![COORDINATE_IN_1, COORDINATE_IN_2, COORDINATE_IN_1],
+				false
+			),
+			Error::<Runtime>::DuplicateLandUnitCoordinate
+		);
+		assert_noop!(
+			EstateModule::mint_lands(
+				Origin::root(),
+				BENEFICIARY_ID,
+				METAVERSE_ID,
+				vec![COORDINATE_IN_1, COORDINATE_OUT],
+				false
+			),
+			Error::<Runtime>::LandUnitIsOutOfBound
+		);
+		assert_eq!(EstateModule::get_land_units(METAVERSE_ID, COORDINATE_IN_1), None);
+	});
+}
+
+#[test]
+fn accept_lease_offer_should_stream_rent_and_evict() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		assert_noop!(
+			EstateModule::post_lease_offer(Origin::signed(BOB), estate_id, 10, 100, 50),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			EstateModule::post_lease_offer(Origin::signed(BENEFICIARY_ID), estate_id, 10, 100, 5),
+			Error::<Runtime>::InvalidLeaseTerms
+		);
+		assert_ok!(EstateModule::post_lease_offer(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			10,
+			100,
+			50
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::LeaseOfferPosted(estate_id, BENEFICIARY_ID, 10, 100))
+		);
+
+		assert_noop!(
+			EstateModule::accept_lease_offer(Origin::signed(BENEFICIARY_ID), estate_id, 50),
+			Error::<Runtime>::CannotLeaseOwnEstate
+		);
+		assert_noop!(
+			EstateModule::accept_lease_offer(Origin::signed(BOB), estate_id, 40),
+			Error::<Runtime>::LeaseDepositTooLow
+		);
+		assert_ok!(EstateModule::accept_lease_offer(Origin::signed(BOB), estate_id, 50));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::EstateLeased(estate_id, BENEFICIARY_ID, BOB, 50))
+		);
+		assert_eq!(EstateModule::get_lease_offer(estate_id), None);
+		assert_eq!(Balances::reserved_balance(BOB), 50);
+		// Deposit covers 5 blocks of rent
+		assert_eq!(EstateModule::lease_expiries(6, estate_id), Some(()));
+
+		System::set_block_number(3);
+		assert_ok!(EstateModule::collect_rent(Origin::signed(ALICE), estate_id));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::RentCollected(estate_id, BENEFICIARY_ID, 20))
+		);
+		assert_eq!(Balances::reserved_balance(BOB), 30);
+		assert_noop!(
+			EstateModule::dissolve_estate(Origin::signed(BENEFICIARY_ID), estate_id),
+			Error::<Runtime>::EstateIsLeased
+		);
+
+		System::set_block_number(6);
+		EstateModule::on_initialize(6);
+		assert_eq!(last_event(), Event::Estate(crate::Event::LeaseEnded(estate_id, BOB)));
+		assert_eq!(EstateModule::get_estate_lease(estate_id), None);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::free_balance(BOB), 99950);
+		assert_eq!(Balances::free_balance(BENEFICIARY_ID), 1000050);
+	});
+}
+
+#[test]
+fn top_up_lease_deposit_should_postpone_eviction() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		assert_ok!(EstateModule::post_lease_offer(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			10,
+			100,
+			50
+		));
+		assert_ok!(EstateModule::cancel_lease_offer(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id
+		));
+		assert_noop!(
+			EstateModule::accept_lease_offer(Origin::signed(BOB), estate_id, 50),
+			Error::<Runtime>::LeaseOfferNotFound
+		);
+
+		assert_ok!(EstateModule::post_lease_offer(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			10,
+			100,
+			50
+		));
+		assert_ok!(EstateModule::accept_lease_offer(Origin::signed(BOB), estate_id, 50));
+		assert_noop!(
+			EstateModule::top_up_lease_deposit(Origin::signed(ALICE), estate_id, 50),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(EstateModule::top_up_lease_deposit(Origin::signed(BOB), estate_id, 50));
+		assert_eq!(EstateModule::lease_expiries(6, estate_id), None);
+		assert_eq!(EstateModule::lease_expiries(11, estate_id), Some(()));
+
+		EstateModule::on_initialize(6);
+		assert!(EstateModule::get_estate_lease(estate_id).is_some());
+	});
+}
+
+#[test]
+fn estate_loan_should_lock_estate_token_until_settled() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			true
+		));
+
+		let estate_id: u64 = 0;
+		let estate_token = (ESTATE_CLASS_ID, ASSET_ID_2);
+		assert_ok!(EstateModule::request_estate_loan(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			Some(BOB),
+			100,
+			150,
+			10
+		));
+		assert!(is_nft_locked(&estate_token));
+		assert_ok!(EstateModule::cancel_estate_loan_request(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id
+		));
+		assert!(!is_nft_locked(&estate_token));
+
+		assert_ok!(EstateModule::request_estate_loan(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			Some(BOB),
+			100,
+			150,
+			10
+		));
+		assert_ok!(EstateModule::fund_estate_loan(
+			Origin::signed(BOB),
+			estate_id,
+			100,
+			150,
+			10
+		));
+		assert!(is_nft_locked(&estate_token));
+		assert_ok!(EstateModule::repay_estate_loan(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id
+		));
+		assert!(!is_nft_locked(&estate_token));
+
+		assert_ok!(EstateModule::request_estate_loan(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			Some(BOB),
+			100,
+			150,
+			10
+		));
+		assert_ok!(EstateModule::fund_estate_loan(
+			Origin::signed(BOB),
+			estate_id,
+			100,
+			150,
+			10
+		));
+		System::set_block_number(12);
+		assert_ok!(EstateModule::liquidate_estate_loan(Origin::signed(BOB), estate_id));
+		assert!(!is_nft_locked(&estate_token));
+	});
+}
+
+#[test]
+fn dissolve_estate_should_remove_lease_offer() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		assert_ok!(EstateModule::post_lease_offer(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			10,
+			100,
+			50
+		));
+		assert_ok!(EstateModule::dissolve_estate(Origin::signed(BENEFICIARY_ID), estate_id));
+		assert_eq!(EstateModule::get_lease_offer(estate_id), None);
+		assert_noop!(
+			EstateModule::accept_lease_offer(Origin::signed(BOB), estate_id, 50),
+			Error::<Runtime>::LeaseOfferNotFound
+		);
+		assert_noop!(
+			EstateModule::post_lease_offer(Origin::signed(BENEFICIARY_ID), estate_id, 10, 100, 50),
+			Error::<Runtime>::EstateDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn expired_leases_should_end_in_bounded_batches() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		for (estate_id, coordinate) in vec![(0, 0), (1, 1), (2, 2)].into_iter().enumerate() {
+			assert_ok!(EstateModule::mint_estate(
+				Origin::root(),
+				BENEFICIARY_ID,
+				METAVERSE_ID,
+				vec![coordinate],
+				false
+			));
+			assert_ok!(EstateModule::post_lease_offer(
+				Origin::signed(BENEFICIARY_ID),
+				estate_id as u64,
+				10,
+				100,
+				50
+			));
+			assert_ok!(EstateModule::accept_lease_offer(
+				Origin::signed(BOB),
+				estate_id as u64,
+				50
+			));
+		}
+
+		// At most MaxLeaseExpiriesPerBlock leases are ended per block
+		System::set_block_number(6);
+		EstateModule::on_initialize(6);
+		let active_leases = (0..3u64)
+			.filter(|estate_id| EstateModule::get_estate_lease(estate_id).is_some())
+			.count();
+		assert_eq!(active_leases, 1);
+		assert_eq!(EstateModule::lease_expiry_cursor(), Some(6));
+
+		System::set_block_number(7);
+		EstateModule::on_initialize(7);
+		let active_leases = (0..3u64)
+			.filter(|estate_id| EstateModule::get_estate_lease(estate_id).is_some())
+			.count();
+		assert_eq!(active_leases, 0);
+		assert_eq!(EstateModule::lease_expiry_cursor(), Some(8));
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+	});
+}
+
+#[test]
+fn sunset_leases_should_wind_down_in_bounded_batches() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		for (estate_id, coordinate) in vec![(0, 0), (1, 1), (2, 2)].into_iter().enumerate() {
+			assert_ok!(EstateModule::mint_estate(
+				Origin::root(),
+				BENEFICIARY_ID,
+				METAVERSE_ID,
+				vec![coordinate],
+				false
+			));
+			assert_ok!(EstateModule::post_lease_offer(
+				Origin::signed(BENEFICIARY_ID),
+				estate_id as u64,
+				10,
+				100,
+				50
+			));
+		}
+		assert_ok!(EstateModule::accept_lease_offer(Origin::signed(BOB), 2, 50));
+
+		EstateModule::on_metaverse_sunset(METAVERSE_ID);
+		assert_eq!(EstateModule::sunset_lease_cursor(METAVERSE_ID), Some(0));
+
+		// At most MaxLeaseExpiriesPerBlock estates are checked per block
+		EstateModule::on_initialize(2);
+		assert_eq!(EstateModule::get_lease_offer(0), None);
+		assert_eq!(EstateModule::get_lease_offer(1), None);
+		assert!(EstateModule::get_estate_lease(2).is_some());
+		assert_eq!(EstateModule::sunset_lease_cursor(METAVERSE_ID), Some(2));
+
+		EstateModule::on_initialize(3);
+		assert_eq!(EstateModule::get_estate_lease(2), None);
+		assert_eq!(EstateModule::sunset_lease_cursor(METAVERSE_ID), None);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+	});
+}
+
+#[test]
+fn estate_loan_should_lock_estate_until_repaid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		assert_noop!(
+			EstateModule::request_estate_loan(Origin::signed(BENEFICIARY_ID), estate_id, Some(BOB), 1000, 900, 10),
+			Error::<Runtime>::InvalidLoanTerms
+		);
+		assert_ok!(EstateModule::request_estate_loan(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			Some(BOB),
+			1000,
+			1100,
+			10
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::EstateLoanRequested(estate_id, BENEFICIARY_ID, 1000, 1100))
+		);
+		assert_noop!(
+			EstateModule::transfer_estate(Origin::signed(BENEFICIARY_ID), ALICE, estate_id),
+			Error::<Runtime>::EstateIsCollateralized
+		);
+		assert_noop!(
+			EstateModule::fund_estate_loan(Origin::signed(ALICE), estate_id, 1000, 1100, 10),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(EstateModule::fund_estate_loan(
+			Origin::signed(BOB),
+			estate_id,
+			1000,
+			1100,
+			10
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::EstateLoanFunded(estate_id, BENEFICIARY_ID, BOB, 11))
+		);
+		assert_eq!(EstateModule::get_loan_request(estate_id), None);
+		assert_eq!(Balances::free_balance(BOB), 99000);
+		assert_eq!(Balances::free_balance(BENEFICIARY_ID), 1001000);
+		assert_noop!(
+			EstateModule::dissolve_estate(Origin::signed(BENEFICIARY_ID), estate_id),
+			Error::<Runtime>::EstateIsCollateralized
+		);
+		assert_noop!(
+			EstateModule::liquidate_estate_loan(Origin::signed(BOB), estate_id),
+			Error::<Runtime>::LoanRepaymentDeadlineNotPassed
+		);
+
+		assert_ok!(EstateModule::repay_estate_loan(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::EstateLoanRepaid(estate_id, BENEFICIARY_ID, 1100))
+		);
+		assert_eq!(EstateModule::get_estate_loan(estate_id), None);
+		assert_eq!(Balances::free_balance(BOB), 100100);
+		assert_ok!(EstateModule::transfer_estate(
+			Origin::signed(BENEFICIARY_ID),
+			ALICE,
+			estate_id
+		));
+	});
+}
+
+#[test]
+fn fund_estate_loan_should_reject_changed_terms() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		assert_ok!(EstateModule::request_estate_loan(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			None,
+			1000,
+			1100,
+			10
+		));
+
+		// Borrower replaces the request before the lender's call is included
+		assert_ok!(EstateModule::cancel_estate_loan_request(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id
+		));
+		assert_ok!(EstateModule::request_estate_loan(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			None,
+			1000,
+			1001,
+			1
+		));
+		assert_noop!(
+			EstateModule::fund_estate_loan(Origin::signed(ALICE), estate_id, 1000, 1100, 10),
+			Error::<Runtime>::LoanTermsMismatch
+		);
+		assert_eq!(EstateModule::get_estate_loan(estate_id), None);
+		assert_ok!(EstateModule::fund_estate_loan(
+			Origin::signed(ALICE),
+			estate_id,
+			1000,
+			1001,
+			1
+		));
+	});
+}
+
+#[test]
+fn liquidate_estate_loan_should_transfer_estate_to_lender() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		assert_ok!(EstateModule::request_estate_loan(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			None,
+			1000,
+			1100,
+			10
+		));
+		assert_noop!(
+			EstateModule::fund_estate_loan(Origin::signed(BENEFICIARY_ID), estate_id, 1000, 1100, 10),
+			Error::<Runtime>::CannotLendToSelf
+		);
+		assert_ok!(EstateModule::fund_estate_loan(
+			Origin::signed(ALICE),
+			estate_id,
+			1000,
+			1100,
+			10
+		));
+
+		System::set_block_number(12);
+		assert_noop!(
+			EstateModule::liquidate_estate_loan(Origin::signed(BOB), estate_id),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(EstateModule::liquidate_estate_loan(Origin::signed(ALICE), estate_id));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::EstateLoanLiquidated(estate_id, BENEFICIARY_ID, ALICE))
+		);
+		assert_eq!(EstateModule::get_estate_owner(estate_id), Some(OwnerId::Account(ALICE)));
+		assert_eq!(EstateModule::get_estate_loan(estate_id), None);
+	});
+}
+
+#[test]
+fn deploy_land_block_should_respect_deployment_whitelist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(
+			Origin::root(),
+			ALICE_METAVERSE_ID,
+			MAX_BOUND
+		));
+		assert_ok!(EstateModule::issue_undeployed_land_blocks(
+			Origin::root(),
+			BOB,
+			1,
+			100,
+			UndeployedLandBlockType::BoundToAddress
+		));
+		let undeployed_land_block_id: UndeployedLandBlockId = 0;
+
+		assert_noop!(
+			EstateModule::set_deployment_whitelist(Origin::signed(BOB), ALICE_METAVERSE_ID, true),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(EstateModule::set_deployment_whitelist(
+			Origin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			true
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::DeploymentWhitelistUpdated(ALICE_METAVERSE_ID, true))
+		);
+
+		assert_noop!(
+			EstateModule::deploy_land_block(
+				Origin::signed(BOB),
+				undeployed_land_block_id,
+				ALICE_METAVERSE_ID,
+				vec![COORDINATE_IN_1],
+				false
+			),
+			Error::<Runtime>::NotWhitelistedDeployer
+		);
+
+		assert_noop!(
+			EstateModule::add_deployer(Origin::signed(BOB), ALICE_METAVERSE_ID, BOB),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(EstateModule::add_deployer(
+			Origin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			BOB
+		));
+		assert_noop!(
+			EstateModule::add_deployer(Origin::signed(ALICE), ALICE_METAVERSE_ID, BOB),
+			Error::<Runtime>::LandDeployerAlreadyExists
+		);
+		assert_ok!(EstateModule::deploy_land_block(
+			Origin::signed(BOB),
+			undeployed_land_block_id,
+			ALICE_METAVERSE_ID,
+			vec![COORDINATE_IN_1],
+			false
+		));
+
+		assert_ok!(EstateModule::remove_deployer(
+			Origin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			BOB
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::LandDeployerRemoved(ALICE_METAVERSE_ID, BOB))
+		);
+		assert_noop!(
+			EstateModule::deploy_land_block(
+				Origin::signed(BOB),
+				undeployed_land_block_id,
+				ALICE_METAVERSE_ID,
+				vec![COORDINATE_IN_2],
+				false
+			),
+			Error::<Runtime>::NotWhitelistedDeployer
+		);
+	});
+}
+
+#[test]
+fn estate_provenance_should_record_owners_and_prune_oldest() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		assert_eq!(
+			EstateModule::get_estate_provenance(estate_id),
+			vec![EstateProvenanceRecord {
+				owner: BENEFICIARY_ID,
+				price: None,
+				acquired_at: 1,
+			}]
+		);
+
+		System::set_block_number(5);
+		assert_ok!(EstateModule::transfer_estate(
+			Origin::signed(BENEFICIARY_ID),
+			ALICE,
+			estate_id
+		));
+		<EstateModule as Estate<AccountId>>::record_estate_sale(estate_id, 500);
+		assert_ok!(EstateModule::transfer_estate(Origin::signed(ALICE), BOB, estate_id));
+		assert_ok!(EstateModule::transfer_estate(Origin::signed(BOB), ALICE, estate_id));
+
+		// Max provenance is 3 in the mock, minting record is pruned first
+		let provenance = EstateModule::get_estate_provenance(estate_id);
+		assert_eq!(provenance.len(), 3);
+		assert_eq!(
+			provenance[0],
+			EstateProvenanceRecord {
+				owner: ALICE,
+				price: Some(500),
+				acquired_at: 5,
+			}
+		);
+		assert_eq!(provenance[1].owner, BOB);
+		assert_eq!(provenance[2].owner, ALICE);
+		assert_eq!(provenance[2].price, None);
+	});
+}
+
+#[test]
+fn estate_token_transfer_should_record_provenance() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			true
+		));
+
+		let estate_id: u64 = 0;
+		assert_eq!(EstateModule::get_estate_by_token(ASSET_ID_2), Some(estate_id));
+
+		// Estate token moved directly through the NFT pallet
+		System::set_block_number(5);
+		<EstateModule as OnNftTransfer<AccountId, ClassId, TokenId>>::on_nft_transfer(
+			&BENEFICIARY_ID,
+			&ALICE,
+			&(ESTATE_CLASS_ID, ASSET_ID_2),
+		);
+		// Tokens of other classes are ignored
+		<EstateModule as OnNftTransfer<AccountId, ClassId, TokenId>>::on_nft_transfer(
+			&ALICE,
+			&BOB,
+			&(LAND_CLASS_ID, ASSET_ID_2),
+		);
+
+		let provenance = EstateModule::get_estate_provenance(estate_id);
+		assert_eq!(provenance.len(), 2);
+		assert_eq!(
+			provenance[1],
+			EstateProvenanceRecord {
+				owner: ALICE,
+				price: None,
+				acquired_at: 5,
+			}
+		);
+
+		// Transfer through the estate pallet records the new owner once
+		assert_ok!(EstateModule::transfer_estate(
+			Origin::signed(BENEFICIARY_ID),
+			BOB,
+			estate_id
+		));
+		let provenance = EstateModule::get_estate_provenance(estate_id);
+		assert_eq!(provenance.len(), 3);
+		assert_eq!(provenance[2].owner, BOB);
+
+		assert_ok!(EstateModule::dissolve_estate(Origin::signed(BENEFICIARY_ID), estate_id));
+		assert_eq!(EstateModule::get_estate_by_token(ASSET_ID_2), None);
+	});
+}
+
+#[test]
+fn transfer_same_estate_twice_in_extrinsic_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		System::set_extrinsic_index(1);
+		assert_ok!(EstateModule::transfer_estate(
+			Origin::signed(BENEFICIARY_ID),
+			ALICE,
+			estate_id
+		));
+		assert_noop!(
+			EstateModule::transfer_estate(Origin::signed(ALICE), BOB, estate_id),
+			Error::<Runtime>::EstateAlreadyTransferredInExtrinsic
+		);
+
+		// Records left over from an earlier block do not restrict the same extrinsic index
+		System::set_block_number(2);
+		assert_ok!(EstateModule::transfer_estate(Origin::signed(ALICE), BOB, estate_id));
+
+		System::set_extrinsic_index(2);
+		assert_ok!(EstateModule::transfer_estate(Origin::signed(BOB), ALICE, estate_id));
+	});
+}
+
+#[test]
+fn co_owned_estate_should_lease_and_deploy_with_threshold() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		let estate_account_id: AccountId = LandTreasuryPalletId::get().into_sub_account(estate_id);
+		assert_ok!(EstateModule::set_estate_co_owners(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			vec![BENEFICIARY_ID, ALICE, BOB],
+			2
+		));
+
+		// Single co-owner can not post lease offer on behalf of the co-owners
+		assert_noop!(
+			EstateModule::post_lease_offer(Origin::signed(BENEFICIARY_ID), estate_id, 10, 100, 100),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(EstateModule::propose_co_ownership_action(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			CoOwnershipAction::PostLeaseOffer(10, 100, 100)
+		));
+		assert_eq!(EstateModule::get_lease_offer(estate_id), None);
+		assert_ok!(EstateModule::approve_co_ownership_action(
+			Origin::signed(BOB),
+			estate_id
+		));
+		assert_eq!(
+			EstateModule::get_lease_offer(estate_id).map(|offer| offer.landlord),
+			Some(estate_account_id)
+		);
+
+		// Land block held by the estate account is deployed into the estate
+		assert_ok!(EstateModule::issue_undeployed_land_blocks(
+			Origin::root(),
+			estate_account_id,
+			1,
+			20,
+			UndeployedLandBlockType::BoundToAddress
+		));
+		assert_ok!(EstateModule::propose_co_ownership_action(
+			Origin::signed(ALICE),
+			estate_id,
+			CoOwnershipAction::DeployLandBlock(0, vec![COORDINATE_IN_2])
+		));
+		assert_ok!(EstateModule::approve_co_ownership_action(
+			Origin::signed(BOB),
+			estate_id
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::CoOwnershipActionExecuted(
+				estate_id,
+				CoOwnershipAction::DeployLandBlock(0, vec![COORDINATE_IN_2]),
+				vec![ALICE, BOB]
+			))
+		);
+		assert_eq!(
+			EstateModule::get_estates(estate_id).map(|estate| estate.land_units),
+			Some(vec![COORDINATE_IN_1, COORDINATE_IN_2])
+		);
+		assert_eq!(
+			EstateModule::get_land_units(METAVERSE_ID, COORDINATE_IN_2),
+			Some(OwnerId::Account(estate_account_id))
+		);
+	});
+}
+
+#[test]
+fn upgrade_land_unit_should_burn_mining_resource() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_land(
+			Origin::root(),
+			ALICE,
+			METAVERSE_ID,
+			COORDINATE_IN_1,
+			false
+		));
+
+		assert_noop!(
+			EstateModule::upgrade_land_unit(Origin::signed(ALICE), METAVERSE_ID, COORDINATE_IN_1),
+			Error::<Runtime>::LandUnitUpgradeCostNotSet
+		);
+		assert_noop!(
+			EstateModule::set_land_unit_upgrade_cost(Origin::signed(BOB), 1, 100),
+			BadOrigin
+		);
+		assert_noop!(
+			EstateModule::set_land_unit_upgrade_cost(Origin::signed(ALICE), 3, 100),
+			Error::<Runtime>::InvalidLandUnitLevel
+		);
+		assert_ok!(EstateModule::set_land_unit_upgrade_cost(Origin::signed(ALICE), 1, 100));
+		assert_ok!(EstateModule::set_land_unit_upgrade_cost(Origin::signed(ALICE), 2, 2000));
+
+		assert_noop!(
+			EstateModule::upgrade_land_unit(Origin::signed(BOB), METAVERSE_ID, COORDINATE_IN_1),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(EstateModule::upgrade_land_unit(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			COORDINATE_IN_1
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::LandUnitUpgraded(
+				ALICE,
+				METAVERSE_ID,
+				COORDINATE_IN_1,
+				1,
+				100
+			))
+		);
+		assert_eq!(Tokens::free_balance(MiningCurrencyId::get(), &ALICE), 900);
+		assert_eq!(mining_burned(), 100);
+		assert_eq!(
+			<EstateModule as Estate<AccountId>>::get_land_unit_level(METAVERSE_ID, COORDINATE_IN_1),
+			1
+		);
+
+		// Not enough mining resource for the next level
+		assert!(EstateModule::upgrade_land_unit(Origin::signed(ALICE), METAVERSE_ID, COORDINATE_IN_1).is_err());
+		assert_eq!(EstateModule::get_land_unit_level(METAVERSE_ID, COORDINATE_IN_1), 1);
+	});
+}
+
+#[test]
+fn estate_owner_should_upgrade_estate_land_unit() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			ALICE,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1],
+			false
+		));
+		assert_ok!(EstateModule::set_land_unit_upgrade_cost(Origin::signed(ALICE), 1, 100));
+
+		assert_noop!(
+			EstateModule::upgrade_land_unit(Origin::signed(BOB), METAVERSE_ID, COORDINATE_IN_1),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(EstateModule::upgrade_land_unit(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			COORDINATE_IN_1
+		));
+		assert_eq!(EstateModule::get_land_unit_level(METAVERSE_ID, COORDINATE_IN_1), 1);
+		assert_eq!(mining_burned(), 100);
+	});
+}
+
+#[test]
+fn frozen_estate_should_not_be_transferred_or_leased() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		assert_noop!(EstateModule::freeze_estate(Origin::signed(BOB), estate_id), BadOrigin);
+		assert_noop!(
+			EstateModule::unfreeze_estate(Origin::signed(ALICE), estate_id),
+			Error::<Runtime>::EstateIsNotFrozen
+		);
+		assert_noop!(
+			EstateModule::freeze_estate(Origin::signed(ALICE), ESTATE_IN_AUCTION),
+			Error::<Runtime>::EstateAlreadyInAuction
+		);
+
+		assert_ok!(EstateModule::freeze_estate(Origin::signed(ALICE), estate_id));
+		assert_eq!(last_event(), Event::Estate(crate::Event::EstateFrozen(estate_id)));
+		assert_eq!(<EstateModule as Estate<AccountId>>::is_estate_frozen(estate_id), true);
+		assert_noop!(
+			EstateModule::freeze_estate(Origin::signed(ALICE), estate_id),
+			Error::<Runtime>::EstateIsFrozen
+		);
+
+		assert_noop!(
+			EstateModule::transfer_estate(Origin::signed(BENEFICIARY_ID), BOB, estate_id),
+			Error::<Runtime>::EstateIsFrozen
+		);
+		assert_noop!(
+			EstateModule::post_lease_offer(Origin::signed(BENEFICIARY_ID), estate_id, 10, 100, 1000),
+			Error::<Runtime>::EstateIsFrozen
+		);
+		assert_noop!(
+			EstateModule::dissolve_estate(Origin::signed(BENEFICIARY_ID), estate_id),
+			Error::<Runtime>::EstateIsFrozen
+		);
+
+		assert_ok!(EstateModule::unfreeze_estate(Origin::signed(ALICE), estate_id));
+		assert_eq!(last_event(), Event::Estate(crate::Event::EstateUnfrozen(estate_id)));
+		assert_ok!(EstateModule::transfer_estate(
+			Origin::signed(BENEFICIARY_ID),
+			BOB,
+			estate_id
+		));
+	});
+}
+
+#[test]
+fn frozen_tokenized_estate_should_stay_frozen_when_loan_settles() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			true
+		));
+
+		let estate_id: u64 = 0;
+		let estate_token = (ESTATE_CLASS_ID, ASSET_ID_2);
+		assert_ok!(EstateModule::request_estate_loan(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id,
+			Some(BOB),
+			100,
+			150,
+			10
+		));
+		assert_ok!(EstateModule::freeze_estate(Origin::signed(ALICE), estate_id));
+		assert!(is_nft_frozen(&estate_token));
+
+		// Settling the loan releases its lock but not the freeze
+		assert_ok!(EstateModule::cancel_estate_loan_request(
+			Origin::signed(BENEFICIARY_ID),
+			estate_id
+		));
+		assert!(!is_nft_locked(&estate_token));
+		assert!(is_nft_frozen(&estate_token));
+
+		assert_ok!(EstateModule::unfreeze_estate(Origin::signed(ALICE), estate_id));
+		assert!(!is_nft_frozen(&estate_token));
+	});
+}
+
+#[test]
+fn set_estate_royalty_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(Origin::root(), METAVERSE_ID, MAX_BOUND));
+		assert_ok!(EstateModule::mint_estate(
+			Origin::root(),
+			BENEFICIARY_ID,
+			METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+
+		let estate_id: u64 = 0;
+		let royalty = Some((BOB, Perbill::from_percent(5)));
+		assert_noop!(
+			EstateModule::set_estate_royalty(Origin::signed(BOB), estate_id, royalty.clone()),
+			BadOrigin
+		);
+		assert_noop!(
+			EstateModule::set_estate_royalty(Origin::signed(ALICE), estate_id, Some((BOB, Perbill::from_percent(30)))),
+			Error::<Runtime>::EstateRoyaltyExceedLimit
+		);
+
+		assert_ok!(EstateModule::set_estate_royalty(
+			Origin::signed(ALICE),
+			estate_id,
+			royalty.clone()
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::EstateRoyaltyUpdated(estate_id, royalty.clone()))
+		);
+		assert_eq!(
+			<EstateModule as Estate<AccountId>>::get_estate_royalty(estate_id),
+			royalty
+		);
+
+		assert_ok!(EstateModule::set_estate_royalty(Origin::signed(ALICE), estate_id, None));
+		assert_eq!(<EstateModule as Estate<AccountId>>::get_estate_royalty(estate_id), None);
+	});
+}
+
+#[test]
+fn land_gift_card_should_be_redeemed_or_refunded() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::issue_undeployed_land_blocks(
+			Origin::root(),
+			ALICE,
+			2,
+			20,
+			UndeployedLandBlockType::Transferable
+		));
+		assert_ok!(EstateModule::create_land_gift_card_pool(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			ASSET_CLASS_ID,
+			vec![0, 1],
+			100,
+			10
+		));
+		let pool_account = EstateModule::land_gift_card_pool_account(0);
+		assert_eq!(EstateModule::get_undeployed_land_block_owner(pool_account, 0), Some(()));
+
+		let gift_card = (ASSET_CLASS_ID, 1);
+		assert_ok!(EstateModule::buy_land_gift_card(Origin::signed(BOB), 0, ALICE));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::LandGiftCardPurchased(0, BOB, ALICE, gift_card, 100))
+		);
+		assert_eq!(Tokens::free_balance(GiftCardCurrencyId::get(), &BOB), 900);
+		assert_eq!(Tokens::free_balance(GiftCardCurrencyId::get(), &ALICE), 100);
+
+		assert_noop!(
+			EstateModule::redeem_land_gift_card(Origin::signed(BOB), gift_card),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(EstateModule::redeem_land_gift_card(Origin::signed(ALICE), gift_card));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::LandGiftCardRedeemed(gift_card, ALICE, METAVERSE_ID, 1))
+		);
+		assert_eq!(EstateModule::get_undeployed_land_block_owner(ALICE, 1), Some(()));
+
+		assert_ok!(EstateModule::buy_land_gift_card(Origin::signed(BOB), 0, ALICE));
+		assert_noop!(
+			EstateModule::buy_land_gift_card(Origin::signed(BOB), 0, ALICE),
+			Error::<Runtime>::GiftCardPoolSoldOut
+		);
+		assert_noop!(
+			EstateModule::refund_expired_land_gift_card(Origin::signed(BOB), gift_card),
+			Error::<Runtime>::GiftCardNotExpired
+		);
+
+		System::set_block_number(12);
+		assert_noop!(
+			EstateModule::redeem_land_gift_card(Origin::signed(ALICE), gift_card),
+			Error::<Runtime>::GiftCardExpired
+		);
+		assert_ok!(EstateModule::refund_expired_land_gift_card(
+			Origin::signed(BOB),
+			gift_card
+		));
+		assert_eq!(EstateModule::get_undeployed_land_block_owner(ALICE, 0), Some(()));
+		assert_eq!(EstateModule::get_land_gift_card_pool(0), None);
+	});
+}
+
+#[test]
+fn land_buyer_gate_should_restrict_land_purchases() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::issue_undeployed_land_blocks(
+			Origin::root(),
+			ALICE,
+			1,
+			20,
+			UndeployedLandBlockType::Transferable
+		));
+		assert_ok!(EstateModule::create_land_gift_card_pool(
+			Origin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			ASSET_CLASS_ID,
+			vec![0],
+			100,
+			10
+		));
+
+		assert_noop!(
+			EstateModule::set_land_buyer_gate(Origin::signed(BOB), ALICE_METAVERSE_ID, Some(LandBuyerGate::Allowlist)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(EstateModule::set_land_buyer_gate(
+			Origin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			Some(LandBuyerGate::Allowlist)
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::LandBuyerGateUpdated(
+				ALICE_METAVERSE_ID,
+				Some(LandBuyerGate::Allowlist)
+			))
+		);
+		assert!(EstateModule::is_allowed_to_buy_land(&ALICE, ALICE_METAVERSE_ID));
+		assert!(EstateModule::is_allowed_to_buy_land(&BOB, BOB_METAVERSE_ID));
+
+		assert_noop!(
+			EstateModule::buy_land_gift_card(Origin::signed(BOB), 0, BENEFICIARY_ID),
+			Error::<Runtime>::LandBuyerNotAllowed
+		);
+
+		assert_ok!(EstateModule::add_land_buyer(
+			Origin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			BENEFICIARY_ID
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::LandBuyerAdded(ALICE_METAVERSE_ID, BENEFICIARY_ID))
+		);
+		assert_noop!(
+			EstateModule::add_land_buyer(Origin::signed(ALICE), ALICE_METAVERSE_ID, BENEFICIARY_ID),
+			Error::<Runtime>::LandBuyerAlreadyExists
+		);
+		assert_ok!(EstateModule::buy_land_gift_card(Origin::signed(BOB), 0, BENEFICIARY_ID));
+
+		assert_ok!(EstateModule::set_land_buyer_gate(
+			Origin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			Some(LandBuyerGate::NftHolders(ASSET_CLASS_ID))
+		));
+		assert!(!EstateModule::is_allowed_to_buy_land(
+			&BENEFICIARY_ID,
+			ALICE_METAVERSE_ID
+		));
+		assert!(EstateModule::is_allowed_to_buy_land(&ALICE, ALICE_METAVERSE_ID));
+
+		assert_ok!(EstateModule::remove_land_buyer(
+			Origin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			BENEFICIARY_ID
+		));
+		assert_noop!(
+			EstateModule::remove_land_buyer(Origin::signed(ALICE), ALICE_METAVERSE_ID, BENEFICIARY_ID),
+			Error::<Runtime>::LandBuyerNotFound
+		);
+
+		assert_ok!(EstateModule::set_land_buyer_gate(
+			Origin::signed(ALICE),
+			ALICE_METAVERSE_ID,
+			None
+		));
+		assert_eq!(EstateModule::land_buyer_gate(ALICE_METAVERSE_ID), None);
+		assert!(EstateModule::is_allowed_to_buy_land(
+			&BENEFICIARY_ID,
+			ALICE_METAVERSE_ID
+		));
+	});
+}
+
+#[test]
+fn revert_sunset_land_units_should_issue_transferable_land_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(EstateModule::set_max_bounds(
+			Origin::root(),
+			SUNSET_METAVERSE_ID,
+			MAX_BOUND
+		));
+		assert_ok!(EstateModule::mint_lands(
+			Origin::root(),
+			BENEFICIARY_ID,
+			SUNSET_METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2],
+			false
+		));
+		assert_eq!(EstateModule::all_land_units_count(), 2);
+
+		assert_noop!(
+			EstateModule::revert_sunset_land_units(Origin::signed(BENEFICIARY_ID), METAVERSE_ID, vec![COORDINATE_IN_1]),
+			Error::<Runtime>::MetaverseNotInSunset
+		);
+		assert_noop!(
+			EstateModule::revert_sunset_land_units(Origin::signed(BOB), SUNSET_METAVERSE_ID, vec![COORDINATE_IN_1]),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(EstateModule::revert_sunset_land_units(
+			Origin::signed(BENEFICIARY_ID),
+			SUNSET_METAVERSE_ID,
+			vec![COORDINATE_IN_1, COORDINATE_IN_2]
+		));
+		assert_eq!(
+			last_event(),
+			Event::Estate(crate::Event::SunsetLandUnitsReverted(
+				BENEFICIARY_ID,
+				SUNSET_METAVERSE_ID,
+				2,
+				0
+			))
+		);
+		assert_eq!(EstateModule::get_land_units(SUNSET_METAVERSE_ID, COORDINATE_IN_1), None);
+		assert_eq!(EstateModule::all_land_units_count(), 0);
+
+		let undeployed_land_block = EstateModule::get_undeployed_land_block(0).unwrap();
+		assert_eq!(undeployed_land_block.owner, BENEFICIARY_ID);
+		assert_eq!(undeployed_land_block.number_land_units, 2);
+		assert_eq!(
+			undeployed_land_block.undeployed_land_block_type,
+			UndeployedLandBlockType::Transferable
+		);
+	});
+}
@@ -43,7 +43,7 @@ use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use sp_std::marker::PhantomData;
 
 /// Weight functions needed for estate.
-pub trait WeightInfo {	fn set_max_bounds() -> Weight;	fn mint_land() -> Weight;	fn mint_lands() -> Weight;	fn transfer_land() -> Weight;	fn mint_estate() -> Weight;	fn dissolve_estate() -> Weight;	fn add_land_unit_to_estate() -> Weight;	fn remove_land_unit_from_estate() -> Weight;	fn create_estate() -> Weight;	fn transfer_estate() -> Weight;	fn issue_undeployed_land_blocks() -> Weight;	fn freeze_undeployed_land_blocks() -> Weight;	fn unfreeze_undeployed_land_blocks() -> Weight;	fn burn_undeployed_land_blocks() -> Weight;	fn approve_undeployed_land_blocks() -> Weight;	fn unapprove_undeployed_land_blocks() -> Weight;	fn transfer_undeployed_land_blocks() -> Weight;	fn deploy_land_block() -> Weight;	fn active_issue_undeploy_land_block() -> Weight;	fn bond_more() -> Weight;	fn bond_less() -> Weight;	fn leave_staking() -> Weight;}
+pub trait WeightInfo {	fn set_max_bounds() -> Weight;	fn mint_land() -> Weight;	fn mint_lands() -> Weight;	fn transfer_land() -> Weight;	fn mint_estate() -> Weight;	fn dissolve_estate() -> Weight;	fn add_land_unit_to_estate() -> Weight;	fn remove_land_unit_from_estate() -> Weight;	fn create_estate() -> Weight;	fn transfer_estate() -> Weight;	fn issue_undeployed_land_blocks() -> Weight;	fn freeze_undeployed_land_blocks() -> Weight;	fn unfreeze_undeployed_land_blocks() -> Weight;	fn burn_undeployed_land_blocks() -> Weight;	fn approve_undeployed_land_blocks() -> Weight;	fn unapprove_undeployed_land_blocks() -> Weight;	fn transfer_undeployed_land_blocks() -> Weight;	fn deploy_land_block() -> Weight;	fn active_issue_undeploy_land_block() -> Weight;	fn bond_more() -> Weight;	fn bond_less() -> Weight;	fn leave_staking() -> Weight;	fn set_land_tax() -> Weight;	fn set_land_tax_exemption() -> Weight;	fn pay_land_tax() -> Weight;	fn set_estate_beneficiary() -> Weight;	fn ping() -> Weight;	fn claim_inherited_estate() -> Weight;	fn set_estate_metadata() -> Weight;	fn set_estate_attribute() -> Weight;	fn freeze_estate_metadata() -> Weight;	fn force_transfer_asset() -> Weight;	fn set_estate_co_owners() -> Weight;	fn propose_co_ownership_action() -> Weight;	fn approve_co_ownership_action() -> Weight;	fn resolve_co_ownership_deadlock() -> Weight;	fn cancel_co_ownership_action() -> Weight;	fn set_map_bounds() -> Weight;	fn extend_map_bounds() -> Weight;	fn post_lease_offer() -> Weight;	fn cancel_lease_offer() -> Weight;	fn accept_lease_offer() -> Weight;	fn top_up_lease_deposit() -> Weight;	fn collect_rent() -> Weight;	fn request_estate_loan() -> Weight;	fn cancel_estate_loan_request() -> Weight;	fn fund_estate_loan() -> Weight;	fn repay_estate_loan() -> Weight;	fn liquidate_estate_loan() -> Weight;	fn set_deployment_whitelist() -> Weight;	fn add_deployer() -> Weight;	fn remove_deployer() -> Weight;	fn set_land_unit_upgrade_cost() -> Weight;	fn upgrade_land_unit() -> Weight;	fn freeze_estate() -> Weight;	fn unfreeze_estate() -> Weight;	fn create_land_gift_card_pool() -> Weight;	fn close_land_gift_card_pool() -> Weight;	fn buy_land_gift_card() -> Weight;	fn redeem_land_gift_card() -> Weight;	fn refund_expired_land_gift_card() -> Weight;	fn revert_sunset_land_units() -> Weight;	fn set_estate_royalty() -> Weight;	fn set_land_buyer_gate() -> Weight;	fn add_land_buyer() -> Weight;	fn remove_land_buyer() -> Weight;}
 
 /// Weights for estate using the for collator node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -69,7 +69,51 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {	fn set_max_bou
 		(900_000 as Weight)	}	fn bond_more() -> Weight {
 		(53_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(6 as Weight))			.saturating_add(T::DbWeight::get().writes(3 as Weight))	}	fn bond_less() -> Weight {
 		(56_500_000 as Weight)			.saturating_add(T::DbWeight::get().reads(6 as Weight))			.saturating_add(T::DbWeight::get().writes(3 as Weight))	}	fn leave_staking() -> Weight {
-		(36_900_000 as Weight)			.saturating_add(T::DbWeight::get().reads(4 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}}
+		(36_900_000 as Weight)			.saturating_add(T::DbWeight::get().reads(4 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn set_land_tax() -> Weight {
+		(17_800_000 as Weight)			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn set_land_tax_exemption() -> Weight {
+		(19_200_000 as Weight)			.saturating_add(T::DbWeight::get().reads(1 as Weight))			.saturating_add(T::DbWeight::get().writes(2 as Weight))	}	fn pay_land_tax() -> Weight {
+		(48_300_000 as Weight)			.saturating_add(T::DbWeight::get().reads(5 as Weight))			.saturating_add(T::DbWeight::get().writes(3 as Weight))	}	fn set_estate_beneficiary() -> Weight {
+		(21_400_000 as Weight)			.saturating_add(T::DbWeight::get().reads(1 as Weight))			.saturating_add(T::DbWeight::get().writes(2 as Weight))	}	fn ping() -> Weight {
+		(12_600_000 as Weight)			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn claim_inherited_estate() -> Weight {
+		(47_900_000 as Weight)			.saturating_add(T::DbWeight::get().reads(6 as Weight))			.saturating_add(T::DbWeight::get().writes(4 as Weight))	}	fn set_estate_metadata() -> Weight {
+		(20_100_000 as Weight)			.saturating_add(T::DbWeight::get().reads(2 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn set_estate_attribute() -> Weight {
+		(23_500_000 as Weight)			.saturating_add(T::DbWeight::get().reads(3 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn freeze_estate_metadata() -> Weight {
+		(18_700_000 as Weight)			.saturating_add(T::DbWeight::get().reads(2 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn force_transfer_asset() -> Weight {
+		(45_600_000 as Weight)			.saturating_add(T::DbWeight::get().reads(5 as Weight))			.saturating_add(T::DbWeight::get().writes(3 as Weight))	}	fn set_estate_co_owners() -> Weight {
+		(42_300_000 as Weight)			.saturating_add(T::DbWeight::get().reads(5 as Weight))			.saturating_add(T::DbWeight::get().writes(3 as Weight))	}	fn propose_co_ownership_action() -> Weight {
+		(24_800_000 as Weight)			.saturating_add(T::DbWeight::get().reads(2 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn approve_co_ownership_action() -> Weight {
+		(24_100_000 as Weight)			.saturating_add(T::DbWeight::get().reads(3 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn resolve_co_ownership_deadlock() -> Weight {
+		(21_600_000 as Weight)			.saturating_add(T::DbWeight::get().reads(1 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn cancel_co_ownership_action() -> Weight {
+		(18_900_000 as Weight)			.saturating_add(T::DbWeight::get().reads(2 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn set_map_bounds() -> Weight {
+		(15_200_000 as Weight)			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn extend_map_bounds() -> Weight {
+		(19_400_000 as Weight)			.saturating_add(T::DbWeight::get().reads(2 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn post_lease_offer() -> Weight {
+		(21_300_000 as Weight)			.saturating_add(T::DbWeight::get().reads(2 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn cancel_lease_offer() -> Weight {
+		(17_800_000 as Weight)			.saturating_add(T::DbWeight::get().reads(1 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn accept_lease_offer() -> Weight {
+		(46_900_000 as Weight)			.saturating_add(T::DbWeight::get().reads(2 as Weight))			.saturating_add(T::DbWeight::get().writes(4 as Weight))	}	fn top_up_lease_deposit() -> Weight {
+		(38_200_000 as Weight)			.saturating_add(T::DbWeight::get().reads(2 as Weight))			.saturating_add(T::DbWeight::get().writes(4 as Weight))	}	fn collect_rent() -> Weight {
+		(36_500_000 as Weight)			.saturating_add(T::DbWeight::get().reads(2 as Weight))			.saturating_add(T::DbWeight::get().writes(3 as Weight))	}	fn request_estate_loan() -> Weight {
+		(27_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(5 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn cancel_estate_loan_request() -> Weight {
+		(20_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(1 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn fund_estate_loan() -> Weight {
+		(52_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(3 as Weight))			.saturating_add(T::DbWeight::get().writes(4 as Weight))	}	fn repay_estate_loan() -> Weight {
+		(50_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(3 as Weight))			.saturating_add(T::DbWeight::get().writes(3 as Weight))	}	fn liquidate_estate_loan() -> Weight {
+		(60_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(4 as Weight))			.saturating_add(T::DbWeight::get().writes(4 as Weight))	}	fn set_deployment_whitelist() -> Weight {
+		(21_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(1 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn add_deployer() -> Weight {
+		(23_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(2 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn remove_deployer() -> Weight {
+		(23_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(2 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn set_land_unit_upgrade_cost() -> Weight {
+		(18_000_000 as Weight)			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn upgrade_land_unit() -> Weight {
+		(45_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(4 as Weight))			.saturating_add(T::DbWeight::get().writes(3 as Weight))	}	fn freeze_estate() -> Weight {
+		(24_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(2 as Weight))			.saturating_add(T::DbWeight::get().writes(3 as Weight))	}	fn unfreeze_estate() -> Weight {
+		(22_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(2 as Weight))			.saturating_add(T::DbWeight::get().writes(2 as Weight))	}	fn create_land_gift_card_pool() -> Weight {
+		(30_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(3 as Weight))			.saturating_add(T::DbWeight::get().writes(3 as Weight))	}	fn close_land_gift_card_pool() -> Weight {
+		(30_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(3 as Weight))			.saturating_add(T::DbWeight::get().writes(3 as Weight))	}	fn buy_land_gift_card() -> Weight {
+		(70_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(6 as Weight))			.saturating_add(T::DbWeight::get().writes(7 as Weight))	}	fn redeem_land_gift_card() -> Weight {
+		(55_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(6 as Weight))			.saturating_add(T::DbWeight::get().writes(6 as Weight))	}	fn refund_expired_land_gift_card() -> Weight {
+		(40_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(5 as Weight))			.saturating_add(T::DbWeight::get().writes(5 as Weight))	}	fn revert_sunset_land_units() -> Weight {
+		(60_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(6 as Weight))			.saturating_add(T::DbWeight::get().writes(6 as Weight))	}	fn set_estate_royalty() -> Weight {
+		(17_800_000 as Weight)			.saturating_add(T::DbWeight::get().reads(1 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn set_land_buyer_gate() -> Weight {
+		(21_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(1 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn add_land_buyer() -> Weight {
+		(23_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(2 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn remove_land_buyer() -> Weight {
+		(23_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(2 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}}
 
 // For backwards compatibility and tests
 impl WeightInfo for () {	fn set_max_bounds() -> Weight {
@@ -94,4 +138,48 @@ impl WeightInfo for () {	fn set_max_bounds() -> Weight {
 		(900_000 as Weight)	}	fn bond_more() -> Weight {
 		(53_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(6 as Weight))			.saturating_add(RocksDbWeight::get().writes(3 as Weight))	}	fn bond_less() -> Weight {
 		(56_500_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(6 as Weight))			.saturating_add(RocksDbWeight::get().writes(3 as Weight))	}	fn leave_staking() -> Weight {
-		(36_900_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(4 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}}
+		(36_900_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(4 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn set_land_tax() -> Weight {
+		(17_800_000 as Weight)			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn set_land_tax_exemption() -> Weight {
+		(19_200_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(1 as Weight))			.saturating_add(RocksDbWeight::get().writes(2 as Weight))	}	fn pay_land_tax() -> Weight {
+		(48_300_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(5 as Weight))			.saturating_add(RocksDbWeight::get().writes(3 as Weight))	}	fn set_estate_beneficiary() -> Weight {
+		(21_400_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(1 as Weight))			.saturating_add(RocksDbWeight::get().writes(2 as Weight))	}	fn ping() -> Weight {
+		(12_600_000 as Weight)			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn claim_inherited_estate() -> Weight {
+		(47_900_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(6 as Weight))			.saturating_add(RocksDbWeight::get().writes(4 as Weight))	}	fn set_estate_metadata() -> Weight {
+		(20_100_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(2 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn set_estate_attribute() -> Weight {
+		(23_500_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(3 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn freeze_estate_metadata() -> Weight {
+		(18_700_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(2 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn force_transfer_asset() -> Weight {
+		(45_600_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(5 as Weight))			.saturating_add(RocksDbWeight::get().writes(3 as Weight))	}	fn set_estate_co_owners() -> Weight {
+		(42_300_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(5 as Weight))			.saturating_add(RocksDbWeight::get().writes(3 as Weight))	}	fn propose_co_ownership_action() -> Weight {
+		(24_800_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(2 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn approve_co_ownership_action() -> Weight {
+		(24_100_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(3 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn resolve_co_ownership_deadlock() -> Weight {
+		(21_600_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(1 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn cancel_co_ownership_action() -> Weight {
+		(18_900_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(2 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn set_map_bounds() -> Weight {
+		(15_200_000 as Weight)			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn extend_map_bounds() -> Weight {
+		(19_400_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(2 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn post_lease_offer() -> Weight {
+		(21_300_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(2 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn cancel_lease_offer() -> Weight {
+		(17_800_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(1 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn accept_lease_offer() -> Weight {
+		(46_900_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(2 as Weight))			.saturating_add(RocksDbWeight::get().writes(4 as Weight))	}	fn top_up_lease_deposit() -> Weight {
+		(38_200_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(2 as Weight))			.saturating_add(RocksDbWeight::get().writes(4 as Weight))	}	fn collect_rent() -> Weight {
+		(36_500_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(2 as Weight))			.saturating_add(RocksDbWeight::get().writes(3 as Weight))	}	fn request_estate_loan() -> Weight {
+		(27_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(5 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn cancel_estate_loan_request() -> Weight {
+		(20_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(1 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn fund_estate_loan() -> Weight {
+		(52_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(3 as Weight))			.saturating_add(RocksDbWeight::get().writes(4 as Weight))	}	fn repay_estate_loan() -> Weight {
+		(50_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(3 as Weight))			.saturating_add(RocksDbWeight::get().writes(3 as Weight))	}	fn liquidate_estate_loan() -> Weight {
+		(60_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(4 as Weight))			.saturating_add(RocksDbWeight::get().writes(4 as Weight))	}	fn set_deployment_whitelist() -> Weight {
+		(21_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(1 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn add_deployer() -> Weight {
+		(23_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(2 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn remove_deployer() -> Weight {
+		(23_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(2 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn set_land_unit_upgrade_cost() -> Weight {
+		(18_000_000 as Weight)			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn upgrade_land_unit() -> Weight {
+		(45_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(4 as Weight))			.saturating_add(RocksDbWeight::get().writes(3 as Weight))	}	fn freeze_estate() -> Weight {
+		(24_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(2 as Weight))			.saturating_add(RocksDbWeight::get().writes(3 as Weight))	}	fn unfreeze_estate() -> Weight {
+		(22_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(2 as Weight))			.saturating_add(RocksDbWeight::get().writes(2 as Weight))	}	fn create_land_gift_card_pool() -> Weight {
+		(30_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(3 as Weight))			.saturating_add(RocksDbWeight::get().writes(3 as Weight))	}	fn close_land_gift_card_pool() -> Weight {
+		(30_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(3 as Weight))			.saturating_add(RocksDbWeight::get().writes(3 as Weight))	}	fn buy_land_gift_card() -> Weight {
+		(70_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(6 as Weight))			.saturating_add(RocksDbWeight::get().writes(7 as Weight))	}	fn redeem_land_gift_card() -> Weight {
+		(55_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(6 as Weight))			.saturating_add(RocksDbWeight::get().writes(6 as Weight))	}	fn refund_expired_land_gift_card() -> Weight {
+		(40_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(5 as Weight))			.saturating_add(RocksDbWeight::get().writes(5 as Weight))	}	fn revert_sunset_land_units() -> Weight {
+		(60_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(6 as Weight))			.saturating_add(RocksDbWeight::get().writes(6 as Weight))	}	fn set_estate_royalty() -> Weight {
+		(17_800_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(1 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn set_land_buyer_gate() -> Weight {
+		(21_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(1 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn add_land_buyer() -> Weight {
+		(23_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(2 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn remove_land_buyer() -> Weight {
+		(23_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(2 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}}
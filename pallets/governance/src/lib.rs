@@ -33,6 +33,7 @@ use frame_support::{
 	},
 };
 use sp_runtime::traits::{Dispatchable, Hash, Saturating, Zero};
+use sp_runtime::SaturatedConversion;
 use sp_std::prelude::*;
 
 use metaverse_primitive::MetaverseTrait;
@@ -49,6 +50,36 @@ mod tests;
 
 const GOVERNANCE_ID: LockIdentifier = *b"bcgovern";
 
+/// Restricts the calls a metaverse referendum can enact to the ones affecting that metaverse
+pub trait MetaverseProposalScope<Call> {
+	fn is_in_scope(call: &Call, metaverse_id: MetaverseId) -> bool;
+}
+
+/// Origin of calls enacted by a passed referendum of a metaverse
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo)]
+pub enum RawOrigin {
+	/// Referendum of the metaverse
+	Metaverse(MetaverseId),
+}
+
+/// Ensure the origin is a referendum of a metaverse, succeeding with the metaverse id
+pub struct EnsureMetaverse;
+
+impl<O: Into<Result<RawOrigin, O>> + From<RawOrigin>> EnsureOrigin<O> for EnsureMetaverse {
+	type Success = MetaverseId;
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().map(|o| match o {
+			RawOrigin::Metaverse(metaverse_id) => metaverse_id,
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> O {
+		O::from(RawOrigin::Metaverse(Default::default()))
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{dispatch::DispatchResultWithPostInfo, traits::EnsureOrigin, Parameter};
@@ -104,7 +135,9 @@ pub mod pallet {
 		type MetaverseLandInfo: MetaverseLandTrait<Self::AccountId>;
 
 		/// Overarching type of all pallets origins.
-		type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+		type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>
+			+ From<crate::RawOrigin>
+			+ Into<<Self as frame_system::Config>::Origin>;
 
 		type Proposal: Parameter + Dispatchable<Origin = Self::Origin> + From<Call<Self>>;
 
@@ -113,12 +146,20 @@ pub mod pallet {
 		type Scheduler: ScheduleNamed<Self::BlockNumber, Self::Proposal, Self::PalletsOrigin>;
 		/// Metaverse Council which collective of members
 		type MetaverseCouncil: EnsureOrigin<Self::Origin>;
+		/// Calls a referendum of a metaverse is allowed to enact
+		type ProposalScope: MetaverseProposalScope<Self::Proposal>;
+		/// Origin of a passed referendum of a metaverse
+		type MetaverseGovernanceOrigin: EnsureOrigin<Self::Origin, Success = MetaverseId>;
 	}
 
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 
+	/// Proposals are enacted with the origin of the metaverse whose referendum passed them
+	#[pallet::origin]
+	pub type Origin = crate::RawOrigin;
+
 	#[pallet::storage]
 	#[pallet::getter(fn preimages)]
 	pub type Preimages<T: Config> = StorageDoubleMap<
@@ -181,6 +222,33 @@ pub mod pallet {
 	pub type VotingOf<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, VotingRecord<BalanceOf<T>, T::BlockNumber>, ValueQuery>;
 
+	/// Votes cast before votes were land weighted were counted once
+	#[pallet::type_value]
+	pub fn DefaultVoteLandWeight() -> u32 {
+		1
+	}
+
+	/// Land units of the voter in the metaverse weighting its vote on a referendum
+	#[pallet::storage]
+	#[pallet::getter(fn vote_land_weight)]
+	pub type VoteLandWeights<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ReferendumId,
+		Twox64Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+		DefaultVoteLandWeight,
+	>;
+
+	/// Voter each land unit weighted a referendum vote of, so land changing hands during the
+	/// referendum cannot weight another vote
+	#[pallet::storage]
+	#[pallet::getter(fn land_unit_voter)]
+	pub type VotedLandUnits<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ReferendumId, Twox64Concat, (i32, i32), T::AccountId, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -239,22 +307,31 @@ pub mod pallet {
 		ProposalMissing,
 		WrongUpperBound,
 		NoneWaiting,
+		/// Land units of the voter already weighted a vote on the referendum
+		LandAlreadyVoted,
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Metaverse owner can update referendum parameters
+		/// Metaverse owner or a referendum of the metaverse can update referendum parameters
 		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
 		pub fn update_referendum_parameters(
 			origin: OriginFor<T>,
 			metaverse_id: MetaverseId,
 			new_referendum_parameters: ReferendumParameters<T::BlockNumber>,
 		) -> DispatchResultWithPostInfo {
-			let from = ensure_signed(origin)?;
-			ensure!(
-				T::MetaverseInfo::check_ownership(&from, &metaverse_id),
-				Error::<T>::AccountIsNotMetaverseOwner
-			);
+			match T::MetaverseGovernanceOrigin::try_origin(origin) {
+				Ok(origin_metaverse_id) => {
+					ensure!(origin_metaverse_id == metaverse_id, Error::<T>::InsufficientPrivileges)
+				}
+				Err(origin) => {
+					let from = ensure_signed(origin)?;
+					ensure!(
+						T::MetaverseInfo::check_ownership(&from, &metaverse_id),
+						Error::<T>::AccountIsNotMetaverseOwner
+					);
+				}
+			}
 			<ReferendumParametersOf<T>>::remove(metaverse_id);
 			<ReferendumParametersOf<T>>::insert(metaverse_id, new_referendum_parameters);
 			Self::deposit_event(Event::ReferendumParametersUpdated(metaverse_id));
@@ -315,6 +392,8 @@ pub mod pallet {
 						T::Slash::on_unbalanced(T::Currency::slash_reserved(&provider, deposit).0);
 						Self::deposit_event(Event::<T>::ProposalRefused(metaverse_id, preimage_hash));
 						Err(Error::<T>::PreimageInvalid.into())
+					} else if !T::ProposalScope::is_in_scope(&proposal, metaverse_id) {
+						Err(Error::<T>::PreimageCallsOutOfScope.into())
 					} else {
 						let launch_block = Self::get_proposal_launch_block(metaverse_id)?;
 						let proposal_info = ProposalInfo {
@@ -432,6 +511,8 @@ pub mod pallet {
 		}
 
 		/// Vote for local metaverse proposal
+		/// The vote is weighted by the number of land units the voter holds in the metaverse that
+		/// have not weighted another vote on the referendum
 		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
 		pub fn try_vote(
 			origin: OriginFor<T>,
@@ -441,10 +522,14 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let from = ensure_signed(origin)?;
 			let mut status = Self::referendum_status(metaverse, referendum)?;
-			ensure!(
-				T::MetaverseLandInfo::is_user_own_metaverse_land(&from, &status.metaverse),
-				Error::<T>::AccountIsNotMetaverseMember
-			);
+			let owned_land_units = T::MetaverseLandInfo::get_user_land_units(&from, &status.metaverse);
+			ensure!(!owned_land_units.is_empty(), Error::<T>::AccountIsNotMetaverseMember);
+			let voting_land_units: Vec<(i32, i32)> = owned_land_units
+				.into_iter()
+				.filter(|land_unit| !VotedLandUnits::<T>::contains_key(referendum, land_unit))
+				.collect();
+			ensure!(!voting_land_units.is_empty(), Error::<T>::LandAlreadyVoted);
+			let land_units = voting_land_units.len() as u32;
 			ensure!(
 				vote.balance <= T::Currency::free_balance(&from),
 				Error::<T>::InsufficientBalance
@@ -460,12 +545,19 @@ pub mod pallet {
 							metaverse,
 							referendum,
 							|referendum_info| -> DispatchResultWithPostInfo {
-								status.tally.add(vote.clone()).ok_or(Error::<T>::TallyOverflow)?;
+								status
+									.tally
+									.add(Self::land_weighted_vote(vote.clone(), land_units))
+									.ok_or(Error::<T>::TallyOverflow)?;
 								*referendum_info = Some(ReferendumInfo::Ongoing(status));
 
 								Ok(().into())
 							},
 						);
+						VoteLandWeights::<T>::insert(referendum, &from, land_units);
+						for land_unit in voting_land_units.iter() {
+							VotedLandUnits::<T>::insert(referendum, land_unit, &from);
+						}
 						T::Currency::extend_lock(GOVERNANCE_ID, &from, vote.balance, WithdrawReasons::TRANSFER);
 						Self::deposit_event(Event::VoteRecorded(from, referendum, vote.aye));
 						Ok(().into())
@@ -487,9 +579,19 @@ pub mod pallet {
 				match votes.binary_search_by_key(&referendum, |i| i.0) {
 					Ok(i) => {
 						let vote = votes.remove(i).1;
+						let land_units = VoteLandWeights::<T>::take(referendum, &from);
 						match info {
 							Some(ReferendumInfo::Ongoing(mut status)) => {
-								status.tally.remove(vote).ok_or(Error::<T>::TallyOverflow)?;
+								status
+									.tally
+									.remove(Self::land_weighted_vote(vote, land_units))
+									.ok_or(Error::<T>::TallyOverflow)?;
+								// Land units still held by the voter can weight a new vote
+								for land_unit in T::MetaverseLandInfo::get_user_land_units(&from, &metaverse) {
+									if Self::land_unit_voter(referendum, land_unit).as_ref() == Some(&from) {
+										VotedLandUnits::<T>::remove(referendum, land_unit);
+									}
+								}
 								ReferendumInfoOf::<T>::insert(&metaverse, &referendum, ReferendumInfo::Ongoing(status));
 								Self::deposit_event(Event::VoteRemoved(from, referendum));
 							}
@@ -616,6 +718,8 @@ impl<T: Config> Pallet<T> {
 				T::Slash::on_unbalanced(T::Currency::slash_reserved(&who, deposit).0);
 				Self::deposit_event(Event::<T>::ProposalRefused(metaverse_id, preimage_hash));
 				Err(Error::<T>::PreimageInvalid.into())
+			} else if !T::ProposalScope::is_in_scope(&proposal, metaverse_id) {
+				Err(Error::<T>::PreimageCallsOutOfScope.into())
 			} else {
 				T::Currency::reserve(&who, deposit)?;
 
@@ -789,6 +893,14 @@ impl<T: Config> Pallet<T> {
 		Self::ensure_ongoing(info.into())
 	}
 
+	/// Vote counted with its balance multiplied by the land units the voter holds in the metaverse
+	fn land_weighted_vote(vote: Vote<BalanceOf<T>>, land_units: u32) -> Vote<BalanceOf<T>> {
+		Vote {
+			balance: vote.balance.saturating_mul(land_units.into()),
+			..vote
+		}
+	}
+
 	/// Ok if the given referendum is active, Err otherwise
 	fn ensure_ongoing(
 		r: ReferendumInfo<T::BlockNumber, BalanceOf<T>, T::Hash>,
@@ -804,11 +916,12 @@ impl<T: Config> Pallet<T> {
 		referendum_id: ReferendumId,
 		referendum_status: ReferendumStatus<T::BlockNumber, BalanceOf<T>, T::Hash>,
 	) -> DispatchResult {
-		// Check if referendum passes
-		let total_issuance = T::Currency::total_issuance();
+		// Check if referendum passes, the electorate is weighted by the land units of the metaverse
+		let metaverse_land_units = T::MetaverseLandInfo::get_metaverse_land_units_count(&metaverse_id).max(1);
+		let electorate = T::Currency::total_issuance().saturating_mul(metaverse_land_units.saturated_into());
 		let is_referendum_approved = referendum_status
 			.threshold
-			.is_referendum_approved(referendum_status.tally.clone(), total_issuance);
+			.is_referendum_approved(referendum_status.tally.clone(), electorate);
 
 		// Update referendum info
 		<ReferendumInfoOf<T>>::try_mutate(metaverse_id, referendum_id, |referendum_info| -> DispatchResult {
@@ -882,7 +995,8 @@ impl<T: Config> Pallet<T> {
 		{
 			if let Ok(proposal) = T::Proposal::decode(&mut &data[..]) {
 				let proposal_type = T::ProposalType::default();
-				if !proposal_type.filter(&proposal) {
+				// Referendum of a metaverse can only enact calls affecting that metaverse
+				if !proposal_type.filter(&proposal) || !T::ProposalScope::is_in_scope(&proposal, metaverse_id) {
 					T::Slash::on_unbalanced(T::Currency::slash_reserved(&provider, deposit).0);
 					Self::deposit_event(Event::<T>::PreimageInvalid(metaverse_id, proposal_hash, referendum_id));
 					Err(Error::<T>::PreimageInvalid.into())
@@ -890,7 +1004,7 @@ impl<T: Config> Pallet<T> {
 					T::Currency::unreserve(&provider, deposit);
 					Self::deposit_event(Event::<T>::PreimageUsed(metaverse_id, proposal_hash, provider, deposit));
 					let result = proposal
-						.dispatch(frame_system::RawOrigin::Root.into())
+						.dispatch(T::PalletsOrigin::from(crate::RawOrigin::Metaverse(metaverse_id)).into())
 						.map(|_| ())
 						.map_err(|e| e.error);
 
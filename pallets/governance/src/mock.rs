@@ -18,8 +18,9 @@ use sp_std::collections::btree_map::BTreeMap;
 
 use metaverse_primitive::{
 	Attributes, CollectionType, MetaverseInfo as MetaversePrimitiveInfo, MetaverseLandTrait, MetaverseTrait, NFTTrait,
-	NftClassData, NftMetadata, TokenType,
+	NftClassData, NftMetadata, RoundTrait, TokenType,
 };
+use primitives::staking::RoundInfo;
 use primitives::{Amount, ClassId, FungibleTokenId, GroupCollectionId, TokenId};
 
 use crate as governance;
@@ -39,6 +40,7 @@ pub type BlockNumber = u64;
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
 pub const ALICE_COUNTRY_ID: CountryId = 1;
 pub const BOB_COUNTRY_ID: CountryId = 2;
 pub const PROPOSAL_BLOCK: BlockNumber = 12;
@@ -161,13 +163,23 @@ impl MetaverseTrait<AccountId> for MetaverseInfo {
 	fn get_metaverse_estate_class(metaverse_id: MetaverseId) -> ClassId {
 		16u32
 	}
+
+	fn get_metaverse_treasury(metaverse_id: MetaverseId) -> AccountId {
+		100
+	}
 }
 
 pub struct MetaverseLandInfo {}
 
 impl MetaverseLandTrait<AccountId> for MetaverseLandInfo {
-	fn get_user_land_units(_who: &u64, _metaverse_id: &u64) -> Vec<(i32, i32)> {
-		Vec::default()
+	fn get_user_land_units(who: &u64, metaverse_id: &u64) -> Vec<(i32, i32)> {
+		match (*metaverse_id, *who) {
+			(ALICE_COUNTRY_ID, ALICE) | (BOB_COUNTRY_ID, ALICE) => vec![(0, 0)],
+			(BOB_COUNTRY_ID, BOB) => vec![(1, 0), (1, 1), (1, 2)],
+			// Land unit of Alice changing hands
+			(BOB_COUNTRY_ID, CHARLIE) => vec![(0, 0)],
+			_ => Vec::default(),
+		}
 	}
 
 	fn is_user_own_metaverse_land(who: &u64, metaverse_id: &u64) -> bool {
@@ -177,6 +189,14 @@ impl MetaverseLandTrait<AccountId> for MetaverseLandInfo {
 			_ => false,
 		}
 	}
+
+	fn get_metaverse_land_units_count(metaverse_id: &u64) -> u64 {
+		match *metaverse_id {
+			ALICE_COUNTRY_ID => 1,
+			BOB_COUNTRY_ID => 4,
+			_ => 0,
+		}
+	}
 }
 
 parameter_types! {
@@ -202,6 +222,14 @@ fn test_attributes(x: u8) -> Attributes {
 	attr
 }
 
+pub struct RoundHandler;
+
+impl RoundTrait<BlockNumber> for RoundHandler {
+	fn get_current_round_info() -> RoundInfo<BlockNumber> {
+		RoundInfo::new(System::block_number() as u32, System::block_number(), 1)
+	}
+}
+
 pub struct MockNFTHandler;
 
 impl NFTTrait<AccountId, Balance> for MockNFTHandler {
@@ -322,6 +350,13 @@ parameter_types! {
 	pub const MaxTokenMetadata: u32 = 1024;
 	pub const MinContribution: Balance = 1;
 	pub const MaxNumberOfStakersPerMetaverse: u32 = 512;
+	pub MaxStakingBoost: Perbill = Perbill::from_percent(100);
+	pub const MaxActiveStakingBoosts: u32 = 3;
+	pub const SunsetPeriod: BlockNumber = 10;
+	pub const BackingUnbondingPeriod: BlockNumber = 5;
+	pub const MaxBackersPerMetaverse: u32 = 512;
+	pub const StaleMetaverseRounds: u32 = 10;
+	pub StaleMetaverseSlash: Perbill = Perbill::from_percent(50);
 }
 
 impl pallet_metaverse::Config for Runtime {
@@ -332,11 +367,21 @@ impl pallet_metaverse::Config for Runtime {
 	type MaxMetaverseMetadata = MaxTokenMetadata;
 	type MinContribution = MinContribution;
 	type MetaverseCouncil = EnsureSignedBy<One, AccountId>;
+	type MetaverseGovernanceOrigin = governance::EnsureMetaverse;
 	type MetaverseRegistrationDeposit = MinContribution;
 	type MinStakingAmount = MinContribution;
 	type MaxNumberOfStakersPerMetaverse = MaxNumberOfStakersPerMetaverse;
 	type WeightInfo = ();
 	type NFTHandler = MockNFTHandler;
+	type MaxStakingBoost = MaxStakingBoost;
+	type MaxActiveStakingBoosts = MaxActiveStakingBoosts;
+	type SunsetPeriod = SunsetPeriod;
+	type SunsetHandler = ();
+	type BackingUnbondingPeriod = BackingUnbondingPeriod;
+	type MaxBackersPerMetaverse = MaxBackersPerMetaverse;
+	type RoundHandler = RoundHandler;
+	type StaleMetaverseRounds = StaleMetaverseRounds;
+	type StaleMetaverseSlash = StaleMetaverseSlash;
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo)]
@@ -363,6 +408,19 @@ impl InstanceFilter<Call> for ProposalType {
 	}
 }
 
+pub struct ProposalScope;
+
+impl MetaverseProposalScope<Call> for ProposalScope {
+	fn is_in_scope(call: &Call, metaverse_id: MetaverseId) -> bool {
+		match call {
+			Call::Metaverse(pallet_metaverse::Call::spend_from_treasury { metaverse_id: id, .. }) => {
+				*id == metaverse_id
+			}
+			_ => true,
+		}
+	}
+}
+
 impl Config for Runtime {
 	type DefaultVotingPeriod = DefaultVotingPeriod;
 	type DefaultEnactmentPeriod = DefaultEnactmentPeriod;
@@ -382,6 +440,8 @@ impl Config for Runtime {
 	type MetaverseLandInfo = MetaverseLandInfo;
 	type MetaverseCouncil = EnsureSignedBy<One, AccountId>;
 	type ProposalType = ProposalType;
+	type ProposalScope = ProposalScope;
+	type MetaverseGovernanceOrigin = governance::EnsureMetaverse;
 }
 
 parameter_type_with_key! {
@@ -419,6 +479,8 @@ impl currencies::Config for Runtime {
 	type MultiSocialCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type GetNativeCurrencyId = NativeCurrencyId;
+	type TreasuryAccount = TreasuryModuleAccount;
+	type UpdateOrigin = EnsureRoot<AccountId>;
 }
 
 pub type GovernanceModule = Pallet<Runtime>;
@@ -435,7 +497,7 @@ construct_runtime!(
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Scheduler: pallet_scheduler::{Pallet, Call, Storage, Event<T>},
-		Governance: governance::{Pallet, Call ,Storage, Event<T>},
+		Governance: governance::{Pallet, Call ,Storage, Event<T>, Origin},
 		Currencies: currencies::{ Pallet, Storage, Call, Event<T>},
 		Tokens: orml_tokens::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Metaverse: pallet_metaverse::{Pallet, Call ,Storage, Event<T>}
@@ -508,6 +570,20 @@ pub fn set_balance_proposal_hash(value: u64) -> H256 {
 	BlakeTwo256::hash(&set_balance_proposal(value)[..])
 }
 
+pub fn spend_from_treasury_proposal(metaverse_id: MetaverseId) -> Vec<u8> {
+	Call::Metaverse(pallet_metaverse::Call::spend_from_treasury {
+		metaverse_id,
+		currency_id: FungibleTokenId::NativeToken(0),
+		beneficiary: BOB,
+		amount: 100,
+	})
+	.encode()
+}
+
+pub fn spend_from_treasury_proposal_hash(metaverse_id: MetaverseId) -> H256 {
+	BlakeTwo256::hash(&spend_from_treasury_proposal(metaverse_id)[..])
+}
+
 pub fn set_freeze_metaverse_proposal_hash(value: u64) -> H256 {
 	BlakeTwo256::hash(&set_freeze_metaverse_proposal(value)[..])
 }
@@ -560,6 +636,17 @@ pub fn add_metaverse_preimage(hash: H256) {
 	Preimages::<Runtime>::insert(BOB_COUNTRY_ID, hash, preimage_status);
 }
 
+pub fn add_spend_from_treasury_preimage(hash: H256) {
+	let preimage_status = PreimageStatus::Available {
+		data: spend_from_treasury_proposal(BOB_COUNTRY_ID),
+		provider: ALICE,
+		deposit: 200,
+		since: 1,
+		expiry: Some(150),
+	};
+	Preimages::<Runtime>::insert(BOB_COUNTRY_ID, hash, preimage_status);
+}
+
 pub fn add_out_of_scope_proposal(preimage_hash: H256) {
 	let proposal_info = ProposalInfo {
 		proposed_by: ALICE,
@@ -22,6 +22,29 @@ fn update_country_referendum_parameters_work() {
 	});
 }
 
+#[test]
+fn update_country_referendum_parameters_by_country_referendum_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			GovernanceModule::update_referendum_parameters(
+				Origin::from(crate::RawOrigin::Metaverse(ALICE_COUNTRY_ID)),
+				BOB_COUNTRY_ID,
+				REFERENDUM_PARAMETERS
+			),
+			Error::<Runtime>::InsufficientPrivileges
+		);
+		assert_ok!(GovernanceModule::update_referendum_parameters(
+			Origin::from(crate::RawOrigin::Metaverse(BOB_COUNTRY_ID)),
+			BOB_COUNTRY_ID,
+			REFERENDUM_PARAMETERS
+		));
+		assert_eq!(
+			GovernanceModule::referendum_parameters(BOB_COUNTRY_ID),
+			Some(REFERENDUM_PARAMETERS)
+		);
+	});
+}
+
 #[test]
 fn update_country_referendum_parameters_when_not_country_owner_does_not_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -636,6 +659,30 @@ fn referendum_proposal_is_enacted() {
 	});
 }
 
+#[test]
+fn referendum_proposal_is_enacted_with_metaverse_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		let hash = spend_from_treasury_proposal_hash(BOB_COUNTRY_ID);
+		add_spend_from_treasury_preimage(hash);
+		let treasury = <Metaverse as MetaverseTrait<AccountId>>::get_metaverse_treasury(BOB_COUNTRY_ID);
+		let _ = Balances::deposit_creating(&treasury, 1000);
+		let bob_balance = Balances::free_balance(&BOB);
+
+		assert_ok!(GovernanceModule::enact_proposal(
+			Origin::root(),
+			0,
+			BOB_COUNTRY_ID,
+			0,
+			hash.clone()
+		));
+		assert!(System::events().iter().any(
+			|record| record.event == Event::Governance(crate::Event::PreimageEnacted(BOB_COUNTRY_ID, hash, Ok(())))
+		));
+		assert_eq!(Balances::free_balance(&BOB), bob_balance + 100);
+		assert_eq!(Balances::free_balance(&treasury), 900);
+	});
+}
+
 #[test]
 fn referendum_proposal_rejected_as_out_of_scope() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -658,6 +705,133 @@ fn referendum_proposal_rejected_as_out_of_scope() {
 	});
 }
 
+#[test]
+fn note_preimage_for_other_metaverse_does_not_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		assert_noop!(
+			GovernanceModule::note_preimage(
+				origin.clone(),
+				BOB_COUNTRY_ID,
+				spend_from_treasury_proposal(ALICE_COUNTRY_ID)
+			),
+			Error::<Runtime>::PreimageCallsOutOfScope
+		);
+		assert_ok!(GovernanceModule::note_preimage(
+			origin.clone(),
+			BOB_COUNTRY_ID,
+			spend_from_treasury_proposal(BOB_COUNTRY_ID)
+		));
+	});
+}
+
+#[test]
+fn land_weighted_vote_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		let hash = set_freeze_metaverse_proposal_hash(1);
+		add_freeze_metaverse_preimage(hash);
+		assert_ok!(GovernanceModule::propose(
+			origin.clone(),
+			BOB_COUNTRY_ID,
+			600,
+			hash.clone(),
+			PROPOSAL_DESCRIPTION.to_vec()
+		));
+		run_to_block(16);
+		assert_ok!(GovernanceModule::try_vote(
+			origin.clone(),
+			BOB_COUNTRY_ID,
+			0,
+			VOTE_AGAINST
+		));
+		assert_ok!(GovernanceModule::try_vote(
+			Origin::signed(BOB),
+			BOB_COUNTRY_ID,
+			0,
+			VOTE_FOR
+		));
+		assert_eq!(GovernanceModule::vote_land_weight(0, ALICE), 1);
+		assert_eq!(GovernanceModule::vote_land_weight(0, BOB), 3);
+		run_to_block(117);
+		assert_eq!(
+			GovernanceModule::referendum_info(BOB_COUNTRY_ID, 0),
+			Some(ReferendumInfo::Finished {
+				passed: true,
+				end: 116,
+				title: PROPOSAL_DESCRIPTION.to_vec()
+			})
+		);
+	});
+}
+
+#[test]
+fn land_unit_weights_one_vote_per_referendum() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		let hash = set_freeze_metaverse_proposal_hash(1);
+		add_freeze_metaverse_preimage(hash);
+		assert_ok!(GovernanceModule::propose(
+			origin.clone(),
+			BOB_COUNTRY_ID,
+			600,
+			hash.clone(),
+			PROPOSAL_DESCRIPTION.to_vec()
+		));
+		run_to_block(16);
+		let _ = Balances::deposit_creating(&CHARLIE, 100);
+
+		assert_ok!(GovernanceModule::try_vote(
+			origin.clone(),
+			BOB_COUNTRY_ID,
+			0,
+			VOTE_AGAINST
+		));
+		assert_eq!(GovernanceModule::land_unit_voter(0, (0, 0)), Some(ALICE));
+		// Land unit already weighted the vote of Alice
+		assert_noop!(
+			GovernanceModule::try_vote(Origin::signed(CHARLIE), BOB_COUNTRY_ID, 0, VOTE_FOR),
+			Error::<Runtime>::LandAlreadyVoted
+		);
+
+		assert_ok!(GovernanceModule::try_remove_vote(origin, 0, BOB_COUNTRY_ID));
+		assert_eq!(GovernanceModule::land_unit_voter(0, (0, 0)), None);
+		assert_ok!(GovernanceModule::try_vote(
+			Origin::signed(CHARLIE),
+			BOB_COUNTRY_ID,
+			0,
+			VOTE_FOR
+		));
+	});
+}
+
+#[test]
+fn removing_vote_cast_before_land_weighting_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		let hash = set_freeze_metaverse_proposal_hash(1);
+		add_freeze_metaverse_preimage(hash);
+		assert_ok!(GovernanceModule::propose(
+			origin.clone(),
+			BOB_COUNTRY_ID,
+			600,
+			hash.clone(),
+			PROPOSAL_DESCRIPTION.to_vec()
+		));
+		run_to_block(16);
+		assert_ok!(GovernanceModule::try_vote(origin.clone(), BOB_COUNTRY_ID, 0, VOTE_FOR));
+		// Votes cast before the upgrade have no land weight and were counted once
+		VoteLandWeights::<Runtime>::remove(0, ALICE);
+		assert_eq!(GovernanceModule::vote_land_weight(0, ALICE), 1);
+
+		assert_ok!(GovernanceModule::try_remove_vote(origin, 0, BOB_COUNTRY_ID));
+		match GovernanceModule::referendum_info(BOB_COUNTRY_ID, 0) {
+			Some(ReferendumInfo::Ongoing(status)) => assert_eq!(status.tally.ayes, 0),
+			_ => panic!("Referendum should be ongoing"),
+		}
+	});
+}
+
 #[test]
 fn unlocking_balance_after_removing_vote_works() {
 	ExtBuilder::default().build().execute_with(|| {
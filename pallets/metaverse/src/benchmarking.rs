@@ -57,18 +57,26 @@ fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
 	caller
 }
 
+fn metaverse_metadata() -> MetaverseMetadata {
+	MetaverseMetadata {
+		version: METAVERSE_METADATA_VERSION,
+		name: b"Metaverse".to_vec().try_into().unwrap(),
+		..Default::default()
+	}
+}
+
 benchmarks! {
 	// create_metaverse
 	create_metaverse{
 		let caller = funded_account::<T>("caller", 0);
-	}: _(RawOrigin::Signed(caller.clone()), vec![1])
+	}: _(RawOrigin::Signed(caller.clone()), metaverse_metadata())
 	verify {
 		let metaverse = crate::Pallet::<T>::get_metaverse(0);
 		match metaverse {
 			Some(a) => {
 				assert_eq!(a.owner, caller.clone());
 				assert_eq!(a.is_frozen, false);
-				assert_eq!(a.metadata, vec![1]);
+				assert_eq!(a.metadata, metaverse_metadata());
 			}
 			_ => {
 				// Should fail test
@@ -82,7 +90,7 @@ benchmarks! {
 		let caller = funded_account::<T>("caller", 0);
 		let target = funded_account::<T>("target", 0);
 
-		crate::Pallet::<T>::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1]);
+		crate::Pallet::<T>::create_metaverse(RawOrigin::Signed(caller.clone()).into(), metaverse_metadata());
 	}: _(RawOrigin::Signed(caller.clone()), target.clone(), 0)
 	verify {
 		let metaverse = crate::Pallet::<T>::get_metaverse(0);
@@ -90,7 +98,7 @@ benchmarks! {
 			Some(a) => {
 				assert_eq!(a.owner, target.clone());
 				assert_eq!(a.is_frozen, false);
-				assert_eq!(a.metadata, vec![1]);
+				assert_eq!(a.metadata, metaverse_metadata());
 			}
 			_ => {
 				// Should fail test
@@ -104,7 +112,7 @@ benchmarks! {
 		let caller = funded_account::<T>("caller", 0);
 		let target = funded_account::<T>("target", 0);
 
-		crate::Pallet::<T>::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1]);
+		crate::Pallet::<T>::create_metaverse(RawOrigin::Signed(caller.clone()).into(), metaverse_metadata());
 	}: _(RawOrigin::Root, 0)
 	verify {
 		let metaverse = crate::Pallet::<T>::get_metaverse(0);
@@ -124,7 +132,7 @@ benchmarks! {
 		let caller = funded_account::<T>("caller", 0);
 		let target = funded_account::<T>("target", 0);
 
-		crate::Pallet::<T>::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1]);
+		crate::Pallet::<T>::create_metaverse(RawOrigin::Signed(caller.clone()).into(), metaverse_metadata());
 		crate::Pallet::<T>::freeze_metaverse(RawOrigin::Root.into(), 0);
 	}: _(RawOrigin::Root, 0)
 	verify {
@@ -146,7 +154,7 @@ benchmarks! {
 		let caller = funded_account::<T>("caller", 0);
 		let target = funded_account::<T>("target", 0);
 
-		crate::Pallet::<T>::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1]);
+		crate::Pallet::<T>::create_metaverse(RawOrigin::Signed(caller.clone()).into(), metaverse_metadata());
 		crate::Pallet::<T>::freeze_metaverse(RawOrigin::Root.into(), 0);
 	}: _(RawOrigin::Root, 0)
 	verify {
@@ -158,7 +166,7 @@ benchmarks! {
 		let caller = funded_account::<T>("caller", 0);
 		let target = funded_account::<T>("target", 0);
 
-		crate::Pallet::<T>::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1]);
+		crate::Pallet::<T>::create_metaverse(RawOrigin::Signed(caller.clone()).into(), metaverse_metadata());
 	}: _(RawOrigin::Signed(caller.clone()), 0)
 	verify {
 		let metaverse = crate::Pallet::<T>::get_registered_metaverse(0);
@@ -179,7 +187,7 @@ benchmarks! {
 		let target = funded_account::<T>("target", 0);
 		let amount = <<T as Config>::MinStakingAmount as Get<BalanceOf<T>>>::get();
 
-		crate::Pallet::<T>::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1]);
+		crate::Pallet::<T>::create_metaverse(RawOrigin::Signed(caller.clone()).into(), metaverse_metadata());
 		crate::Pallet::<T>::register_metaverse(RawOrigin::Signed(caller.clone()).into(), 0);
 	}: _(RawOrigin::Signed(caller.clone()), 0, (amount+1u32.into()).into())
 	verify {
@@ -195,7 +203,7 @@ benchmarks! {
 		let amount = <<T as Config>::MinStakingAmount as Get<BalanceOf<T>>>::get();
 
 
-		crate::Pallet::<T>::create_metaverse(RawOrigin::Signed(caller.clone()).into(), vec![1]);
+		crate::Pallet::<T>::create_metaverse(RawOrigin::Signed(caller.clone()).into(), metaverse_metadata());
 		crate::Pallet::<T>::register_metaverse(RawOrigin::Signed(caller.clone()).into(), 0);
 		crate::Pallet::<T>::stake(RawOrigin::Signed(caller.clone()).into(), 0, (amount+1u32.into()).into());
 	}: _(RawOrigin::Signed(caller.clone()), 0, 1u32.into())
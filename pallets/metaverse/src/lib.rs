@@ -18,12 +18,12 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode, HasCompact};
-use frame_support::traits::{LockIdentifier, WithdrawReasons};
+use frame_support::traits::{GetStorageVersion, LockIdentifier, StorageVersion, WithdrawReasons};
 use frame_support::{
 	ensure,
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement, LockableCurrency, ReservableCurrency},
-	PalletId,
+	traits::{Currency, ExistenceRequirement, Imbalance, LockableCurrency, ReservableCurrency},
+	transactional, PalletId,
 };
 use frame_system::{ensure_signed, pallet_prelude::*};
 use orml_traits::MultiCurrency;
@@ -37,7 +37,7 @@ use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 use core_primitives::*;
 use core_primitives::{MetaverseInfo, MetaverseTrait};
 pub use pallet::*;
-use primitives::staking::MetaverseStakingTrait;
+use primitives::staking::{MetaverseBackingTrait, MetaverseStakingTrait};
 use primitives::{ClassId, FungibleTokenId, MetaverseId, RoundIndex, TokenId};
 pub use weights::WeightInfo;
 
@@ -63,6 +63,55 @@ pub struct MetaverseStakingSnapshot<Balance> {
 
 const LOCK_STAKING: LockIdentifier = *b"stakelok";
 
+const LOCK_BACKING: LockIdentifier = *b"mvbackng";
+
+/// The maximum number of unbonding chunks an account can have.
+pub const MAX_UNBONDING_CHUNKS: usize = 16;
+
+/// Metaverse backing stake waiting for the end of its unbonding period
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct UnbondingChunk<Balance, BlockNumber> {
+	/// Metaverse the stake was backing
+	pub metaverse_id: MetaverseId,
+	/// Unbonding amount
+	pub amount: Balance,
+	/// Block from which the amount can be withdrawn
+	pub unlock_at: BlockNumber,
+}
+
+/// Reward weight boost granted by locking an NFT of a boost class
+#[derive(PartialEq, Eq, Clone, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct StakingBoostInfo<BlockNumber> {
+	/// Extra reward weight on top of the staked amount
+	pub multiplier: Perbill,
+	/// Number of blocks the boost stays active after being locked
+	pub duration: BlockNumber,
+}
+
+/// Boost NFT locked against a metaverse staking position
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ActiveStakingBoost<BlockNumber> {
+	/// Locked boost NFT
+	pub nft: (ClassId, TokenId),
+	/// Extra reward weight on top of the staked amount
+	pub multiplier: Perbill,
+	/// Block at which the boost expires and the NFT is unlocked
+	pub expires_at: BlockNumber,
+	/// First staking round the boost counts towards, earlier rounds are paid without it
+	pub from_round: RoundIndex,
+}
+
+/// Tombstone of a decommissioned metaverse, retired metaverse ids are never reused
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct RetiredMetaverseInfo<AccountId, BlockNumber> {
+	/// Owner of the metaverse when it was retired
+	pub owner: AccountId,
+	/// Local token of the metaverse, still redeemable against the remaining treasury
+	pub currency_id: FungibleTokenId,
+	/// Block at which the metaverse was retired
+	pub retired_at: BlockNumber,
+}
+
 /// Storing the reward detail of metaverse that store the list of stakers for each metaverse
 /// This will be used to reward metaverse owner and the stakers.
 #[derive(Clone, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
@@ -86,8 +135,13 @@ pub mod pallet {
 
 	use super::*;
 
+	/// Metaverse metadata follows the versioned schema from storage version 1, activity and
+	/// registration deposits are recorded for every metaverse from storage version 2
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 
@@ -114,6 +168,8 @@ pub mod pallet {
 		type MinContribution: Get<BalanceOf<Self>>;
 		/// Origin to add new metaverse
 		type MetaverseCouncil: EnsureOrigin<Self::Origin>;
+		/// Origin of a passed referendum of a metaverse
+		type MetaverseGovernanceOrigin: EnsureOrigin<Self::Origin, Success = MetaverseId>;
 		/// Mininum deposit for registering a metaverse
 		type MetaverseRegistrationDeposit: Get<BalanceOf<Self>>;
 		/// Mininum staking amount
@@ -124,6 +180,31 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 		/// NFT handler required for minting classes for lands and estates when creating a metaverse
 		type NFTHandler: NFTTrait<Self::AccountId, BalanceOf<Self>, ClassId = ClassId, TokenId = TokenId>;
+		/// Maximum extra reward weight a staker can get from stacked boosts
+		#[pallet::constant]
+		type MaxStakingBoost: Get<Perbill>;
+		/// Maximum number of boosts active on a staking position at the same time
+		#[pallet::constant]
+		type MaxActiveStakingBoosts: Get<u32>;
+		/// Number of blocks between the start of a metaverse sunset and its retirement
+		#[pallet::constant]
+		type SunsetPeriod: Get<Self::BlockNumber>;
+		/// Handler winding down metaverse activity when a sunset starts
+		type SunsetHandler: MetaverseSunsetHandler;
+		/// Number of blocks metaverse backing stake stays locked after being unbonded
+		#[pallet::constant]
+		type BackingUnbondingPeriod: Get<Self::BlockNumber>;
+		/// Maximum number of accounts backing a metaverse, every backer is paid in the mining payout
+		#[pallet::constant]
+		type MaxBackersPerMetaverse: Get<u32>;
+		/// Source of the current mining round
+		type RoundHandler: RoundTrait<Self::BlockNumber>;
+		/// Number of rounds without activity after which a metaverse can be flagged as stale
+		#[pallet::constant]
+		type StaleMetaverseRounds: Get<RoundIndex>;
+		/// Part of the registration deposit slashed when a metaverse is flagged as stale
+		#[pallet::constant]
+		type StaleMetaverseSlash: Get<Perbill>;
 	}
 
 	#[pallet::storage]
@@ -160,6 +241,12 @@ pub mod pallet {
 	pub(crate) type RegisteredMetaverse<T: Config> =
 		StorageMap<_, Blake2_128Concat, MetaverseId, T::AccountId, OptionQuery>;
 
+	/// Deposit reserved by the registrant of a metaverse registered for staking
+	#[pallet::storage]
+	#[pallet::getter(fn get_metaverse_registration_deposit)]
+	pub type MetaverseRegistrationDeposits<T: Config> =
+		StorageMap<_, Twox64Concat, MetaverseId, BalanceOf<T>, ValueQuery>;
+
 	/// Metaverse Staking snapshot per staking round
 	#[pallet::storage]
 	#[pallet::getter(fn get_metaverse_staking_snapshots)]
@@ -183,6 +270,96 @@ pub mod pallet {
 	#[pallet::getter(fn staking_info)]
 	pub(crate) type StakingInfo<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
+	/// NFT classes approved as staking boosts with their multiplier and duration
+	#[pallet::storage]
+	#[pallet::getter(fn get_staking_boost_class)]
+	pub type StakingBoostClasses<T: Config> =
+		StorageMap<_, Twox64Concat, ClassId, StakingBoostInfo<T::BlockNumber>, OptionQuery>;
+
+	/// Boosts locked against the staking position of each staker per metaverse
+	#[pallet::storage]
+	#[pallet::getter(fn get_active_staking_boosts)]
+	pub type ActiveStakingBoosts<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		MetaverseId,
+		Blake2_128Concat,
+		T::AccountId,
+		Vec<ActiveStakingBoost<T::BlockNumber>>,
+		ValueQuery,
+	>;
+
+	/// Boost NFTs expiring at each block with the staking position they are locked against
+	#[pallet::storage]
+	#[pallet::getter(fn get_staking_boost_expiries)]
+	pub type StakingBoostExpiries<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		Twox64Concat,
+		(ClassId, TokenId),
+		(MetaverseId, T::AccountId),
+		OptionQuery,
+	>;
+
+	/// Metaverses in sunset with the block from which they can be retired
+	#[pallet::storage]
+	#[pallet::getter(fn get_metaverse_sunset)]
+	pub type MetaverseSunsets<T: Config> = StorageMap<_, Twox64Concat, MetaverseId, T::BlockNumber, OptionQuery>;
+
+	/// Tombstones of retired metaverses
+	#[pallet::storage]
+	#[pallet::getter(fn get_retired_metaverse)]
+	pub type RetiredMetaverses<T: Config> =
+		StorageMap<_, Twox64Concat, MetaverseId, RetiredMetaverseInfo<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	/// Stake backing a metaverse per account, sharing the metaverse mining reward
+	#[pallet::storage]
+	#[pallet::getter(fn get_metaverse_backing)]
+	pub type MetaverseBackings<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, MetaverseId, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// Number of accounts backing a metaverse
+	#[pallet::storage]
+	#[pallet::getter(fn get_metaverse_backer_count)]
+	pub type MetaverseBackerCount<T: Config> = StorageMap<_, Twox64Concat, MetaverseId, u32, ValueQuery>;
+
+	/// Unbonding metaverse backing stake of an account
+	#[pallet::storage]
+	#[pallet::getter(fn get_backing_unbonding)]
+	pub type BackingUnbonding<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Vec<UnbondingChunk<BalanceOf<T>, T::BlockNumber>>, ValueQuery>;
+
+	/// Total backing and unbonding stake locked for an account
+	#[pallet::storage]
+	#[pallet::getter(fn get_backing_locked)]
+	pub type BackingLocked<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// Roles granted by metaverse owners to other accounts
+	#[pallet::storage]
+	#[pallet::getter(fn get_metaverse_role)]
+	pub type MetaverseRoles<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		MetaverseId,
+		Blake2_128Concat,
+		(MetaverseRole, T::AccountId),
+		(),
+		OptionQuery,
+	>;
+
+	/// Legacy metadata bytes of metaverses created before the versioned metadata schema that do
+	/// not fit the description content identifier, kept until the owner updates the metadata
+	#[pallet::storage]
+	#[pallet::getter(fn get_legacy_metaverse_metadata)]
+	pub type LegacyMetaverseMetadata<T: Config> =
+		StorageMap<_, Twox64Concat, MetaverseId, BoundedVec<u8, T::MaxMetaverseMetadata>, OptionQuery>;
+
+	/// Last mining round with activity in the metaverse
+	#[pallet::storage]
+	#[pallet::getter(fn get_metaverse_last_active_round)]
+	pub type MetaverseLastActiveRound<T: Config> = StorageMap<_, Twox64Concat, MetaverseId, RoundIndex, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -196,6 +373,21 @@ pub mod pallet {
 		MetaverseStaked(T::AccountId, MetaverseId, BalanceOf<T>),
 		MetaverseUnstaked(T::AccountId, MetaverseId, BalanceOf<T>),
 		MetaverseStakingRewarded(T::AccountId, MetaverseId, RoundIndex, BalanceOf<T>),
+		StakingBoostClassUpdated(ClassId, Option<StakingBoostInfo<T::BlockNumber>>),
+		StakingBoostLocked(T::AccountId, MetaverseId, (ClassId, TokenId), T::BlockNumber),
+		StakingBoostExpired(T::AccountId, MetaverseId, (ClassId, TokenId)),
+		MetaverseSunsetInitiated(MetaverseId, T::BlockNumber),
+		MetaverseRetired(MetaverseId, T::AccountId),
+		MetaverseTreasuryFunded(MetaverseId, T::AccountId, BalanceOf<T>),
+		MetaverseTreasurySpent(MetaverseId, FungibleTokenId, T::AccountId, BalanceOf<T>),
+		MetaverseBacked(T::AccountId, MetaverseId, BalanceOf<T>),
+		MetaverseBackingUnbonded(T::AccountId, MetaverseId, BalanceOf<T>, T::BlockNumber),
+		MetaverseBackingWithdrawn(T::AccountId, BalanceOf<T>),
+		MetaverseRoleGranted(MetaverseId, MetaverseRole, T::AccountId),
+		MetaverseRoleRevoked(MetaverseId, MetaverseRole, T::AccountId),
+		MetaverseFlaggedStale(MetaverseId, BalanceOf<T>),
+		MetaverseMetadataUpdated(MetaverseId),
+		StakingBoostReleased(T::AccountId, MetaverseId, (ClassId, TokenId)),
 	}
 
 	#[pallet::error]
@@ -234,6 +426,44 @@ pub mod pallet {
 		MetaverseStakingAlreadyPaid,
 		/// Metaverse has no stake
 		MetaverseHasNoStake,
+		/// NFT class is not a staking boost
+		StakingBoostClassNotFound,
+		/// Boost multiplier exceeds the maximum or duration is zero
+		InvalidStakingBoost,
+		/// Staker has no stake in the metaverse to boost
+		NoStakeToBoost,
+		/// Boost of the same class is already active on the staking position
+		StakingBoostClassAlreadyActive,
+		/// Staking position already has the maximum number of active boosts
+		TooManyStakingBoosts,
+		/// Stacked boosts would exceed the maximum reward weight
+		StakingBoostLimitExceeded,
+		/// Metaverse sunset has already been initiated
+		MetaverseAlreadyInSunset,
+		/// Metaverse is not in sunset
+		MetaverseNotInSunset,
+		/// Metaverse sunset period is not over yet
+		MetaverseSunsetNotOver,
+		/// Metaverse is in sunset and does not accept new activity
+		MetaverseIsInSunset,
+		/// Backing amount exceeds the stake backing the metaverse
+		InsufficientBackingToUnbond,
+		/// This account has too many unbonding chunks
+		TooManyUnbondingChunks,
+		/// No unbonded stake is ready to withdraw
+		NoUnbondedBacking,
+		/// Account already holds the role in the metaverse
+		MetaverseRoleAlreadyGranted,
+		/// Account does not hold the role in the metaverse
+		MetaverseRoleNotFound,
+		/// Metaverse had activity within the stale period
+		MetaverseIsNotStale,
+		/// Metadata does not follow the current schema version
+		UnsupportedMetadataVersion,
+		/// Boost NFT is locked, listed or otherwise not transferable
+		StakingBoostNftNotAvailable,
+		/// Maximum amount of allowed backers per metaverse
+		MaximumAmountOfBackersPerMetaverse,
 	}
 
 	#[pallet::call]
@@ -242,10 +472,7 @@ pub mod pallet {
 		pub fn create_metaverse(origin: OriginFor<T>, metadata: MetaverseMetadata) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			ensure!(
-				metadata.len() as u32 <= T::MaxMetaverseMetadata::get(),
-				Error::<T>::MaxMetadataExceeded
-			);
+			Self::ensure_valid_metadata(&metadata)?;
 
 			ensure!(
 				T::Currency::free_balance(&who) >= T::MinContribution::get(),
@@ -297,6 +524,8 @@ pub mod pallet {
 
 					*metaverse_by_owner = None;
 					MetaverseOwner::<T>::insert(to.clone(), metaverse_id.clone(), ());
+					// Roles were granted by the previous owner and do not carry over
+					MetaverseRoles::<T>::remove_prefix(metaverse_id, None);
 
 					Metaverses::<T>::try_mutate_exists(&metaverse_id, |metaverse| -> DispatchResultWithPostInfo {
 						let mut metaverse_record = metaverse.as_mut().ok_or(Error::<T>::NoPermission)?;
@@ -329,6 +558,10 @@ pub mod pallet {
 		pub fn unfreeze_metaverse(origin: OriginFor<T>, metaverse_id: MetaverseId) -> DispatchResultWithPostInfo {
 			// Only Council can freeze a metaverse
 			T::MetaverseCouncil::ensure_origin(origin)?;
+			ensure!(
+				!MetaverseSunsets::<T>::contains_key(metaverse_id),
+				Error::<T>::MetaverseIsInSunset
+			);
 
 			Metaverses::<T>::try_mutate(metaverse_id, |maybe_metaverse| {
 				let metaverse_info = maybe_metaverse.as_mut().ok_or(Error::<T>::MetaverseInfoNotFound)?;
@@ -352,10 +585,89 @@ pub mod pallet {
 
 			MetaverseOwner::<T>::remove(metaverse_info.owner, &metaverse_id);
 			Metaverses::<T>::remove(&metaverse_id);
+			LegacyMetaverseMetadata::<T>::remove(metaverse_id);
+			MetaverseRoles::<T>::remove_prefix(metaverse_id, None);
 			Self::deposit_event(Event::<T>::MetaverseDestroyed(metaverse_id));
 			Ok(().into())
 		}
 
+		/// Start decommissioning an abandoned metaverse. The metaverse is frozen, leases on its
+		/// estates are wound down and its local token becomes redeemable against the remaining
+		/// treasury. It can be retired once `SunsetPeriod` is over.
+		#[pallet::weight(T::WeightInfo::initiate_metaverse_sunset())]
+		pub fn initiate_metaverse_sunset(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+		) -> DispatchResultWithPostInfo {
+			T::MetaverseCouncil::ensure_origin(origin)?;
+
+			Self::do_initiate_sunset(metaverse_id)?;
+
+			Ok(().into())
+		}
+
+		/// Flag a metaverse without activity for `StaleMetaverseRounds` rounds as abandoned. Part
+		/// of its registration deposit is slashed to the network treasury, the rest is returned to
+		/// the registrant, the metaverse is deregistered from staking and enters sunset, releasing
+		/// its continuum spots.
+		#[pallet::weight(T::WeightInfo::flag_stale_metaverse())]
+		pub fn flag_stale_metaverse(origin: OriginFor<T>, metaverse_id: MetaverseId) -> DispatchResultWithPostInfo {
+			T::MetaverseCouncil::ensure_origin(origin)?;
+
+			let current_round = T::RoundHandler::get_current_round_info().current;
+			ensure!(
+				current_round
+					>= Self::get_metaverse_last_active_round(metaverse_id)
+						.saturating_add(T::StaleMetaverseRounds::get()),
+				Error::<T>::MetaverseIsNotStale
+			);
+
+			Self::do_initiate_sunset(metaverse_id)?;
+
+			let mut slashed = BalanceOf::<T>::zero();
+			if let Some(registrant) = RegisteredMetaverse::<T>::take(metaverse_id) {
+				let deposit = MetaverseRegistrationDeposits::<T>::take(metaverse_id);
+				let (imbalance, _) =
+					T::Currency::slash_reserved(&registrant, T::StaleMetaverseSlash::get().mul_floor(deposit));
+				slashed = imbalance.peek();
+				T::Currency::resolve_creating(&Self::account_id(), imbalance);
+				T::Currency::unreserve(&registrant, deposit.saturating_sub(slashed));
+			}
+
+			Self::deposit_event(Event::<T>::MetaverseFlaggedStale(metaverse_id, slashed));
+
+			Ok(().into())
+		}
+
+		/// Retire a metaverse once its sunset period is over, leaving a tombstone record behind.
+		/// Stakers can still withdraw their stake from a retired metaverse.
+		#[pallet::weight(T::WeightInfo::retire_metaverse())]
+		pub fn retire_metaverse(origin: OriginFor<T>, metaverse_id: MetaverseId) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let retire_at = Self::get_metaverse_sunset(metaverse_id).ok_or(Error::<T>::MetaverseNotInSunset)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(now >= retire_at, Error::<T>::MetaverseSunsetNotOver);
+
+			let metaverse_info = Metaverses::<T>::take(metaverse_id).ok_or(Error::<T>::MetaverseInfoNotFound)?;
+			MetaverseOwner::<T>::remove(&metaverse_info.owner, &metaverse_id);
+			MetaverseSunsets::<T>::remove(metaverse_id);
+			LegacyMetaverseMetadata::<T>::remove(metaverse_id);
+			MetaverseRoles::<T>::remove_prefix(metaverse_id, None);
+			RetiredMetaverses::<T>::insert(
+				metaverse_id,
+				RetiredMetaverseInfo {
+					owner: metaverse_info.owner.clone(),
+					currency_id: metaverse_info.currency_id,
+					retired_at: now,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::MetaverseRetired(metaverse_id, metaverse_info.owner));
+
+			Ok(().into())
+		}
+
 		/// Register metaverse for staking
 		/// only metaverse owner can register for staking
 		#[pallet::weight(T::WeightInfo::register_metaverse())]
@@ -370,9 +682,16 @@ pub mod pallet {
 				Error::<T>::AlreadyRegisteredForStaking
 			);
 
-			T::Currency::reserve(&who, T::MetaverseRegistrationDeposit::get())?;
+			ensure!(
+				!Self::is_metaverse_sunset(metaverse_id),
+				Error::<T>::MetaverseIsInSunset
+			);
+
+			let deposit = T::MetaverseRegistrationDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
 
 			RegisteredMetaverse::<T>::insert(metaverse_id.clone(), who.clone());
+			MetaverseRegistrationDeposits::<T>::insert(metaverse_id, deposit);
 
 			Self::deposit_event(Event::<T>::NewMetaverseRegisteredForStaking(metaverse_id, who));
 
@@ -394,6 +713,10 @@ pub mod pallet {
 				RegisteredMetaverse::<T>::contains_key(&metaverse_id),
 				Error::<T>::NotRegisteredForStaking
 			);
+			ensure!(
+				!Self::is_metaverse_sunset(metaverse_id),
+				Error::<T>::MetaverseIsInSunset
+			);
 
 			// Get the staking ledger or create an entry if it doesn't exist.
 			let mut staking_info = Self::staking_info(&who);
@@ -402,7 +725,9 @@ pub mod pallet {
 			let free_balance = T::Currency::free_balance(&who).saturating_sub(T::MinStakingAmount::get());
 
 			// Remove already locked funds from the free balance
-			let available_balance = free_balance.saturating_sub(staking_info);
+			let available_balance = free_balance
+				.saturating_sub(staking_info)
+				.saturating_sub(Self::get_backing_locked(&who));
 			let stake_amount = value.min(available_balance);
 			ensure!(stake_amount > Zero::zero(), Error::<T>::NotEnoughBalanceToStake);
 
@@ -484,9 +809,10 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			// Check that metaverse is registered for staking.
+			// Check that metaverse is registered for staking, stakers of a metaverse deregistered
+			// when flagged stale can still withdraw.
 			ensure!(
-				RegisteredMetaverse::<T>::contains_key(&metaverse_id),
+				RegisteredMetaverse::<T>::contains_key(&metaverse_id) || Self::is_metaverse_sunset(metaverse_id),
 				Error::<T>::NotRegisteredForStaking
 			);
 
@@ -509,8 +835,9 @@ pub mod pallet {
 
 			let remaining = staked_amount.saturating_sub(value);
 			let amount_to_unstake = if remaining < T::MinStakingAmount::get() {
-				// Remaining amount below minimum, remove all staked amount
+				// Remaining amount below minimum, remove all staked amount along with its boosts
 				metaverse_stake_per_round.stakers.remove(&who);
+				Self::release_staking_boosts(metaverse_id, &who);
 				staked_amount
 			} else {
 				metaverse_stake_per_round.stakers.insert(who.clone(), remaining);
@@ -576,8 +903,22 @@ pub mod pallet {
 
 			let mut total_rewward_per_metaverse: BalanceOf<T> = Default::default();
 
+			// Rewards are shared by staked amount weighted with the active staking boosts
+			let total_reward_weight = metaverse_stake_per_round.stakers.iter().fold(
+				BalanceOf::<T>::zero(),
+				|total, (staker, staked_amount)| {
+					total.saturating_add(Self::staking_reward_weight(
+						&metaverse_id,
+						staker,
+						*staked_amount,
+						round,
+					))
+				},
+			);
+
 			for (staker, staked_amount) in &metaverse_stake_per_round.stakers {
-				let ratio = Perbill::from_rational(*staked_amount, metaverse_stake_per_round.total);
+				let reward_weight = Self::staking_reward_weight(&metaverse_id, staker, *staked_amount, round);
+				let ratio = Perbill::from_rational(reward_weight, total_reward_weight);
 				let staking_reward = ratio * metaverse_staking_snapshot.rewards;
 
 				let balance_staking_reward = TryInto::<BalanceOf<T>>::try_into(staking_reward).unwrap_or_default();
@@ -599,10 +940,394 @@ pub mod pallet {
 			<MetaverseRoundStake<T>>::insert(&metaverse_id, round, metaverse_stake_per_round);
 			Ok(().into())
 		}
+
+		/// Approve NFT class as staking boost or remove the approval
+		/// Only metaverse council can update boost classes
+		#[pallet::weight(T::WeightInfo::set_staking_boost_class())]
+		pub fn set_staking_boost_class(
+			origin: OriginFor<T>,
+			class_id: ClassId,
+			boost: Option<StakingBoostInfo<T::BlockNumber>>,
+		) -> DispatchResultWithPostInfo {
+			T::MetaverseCouncil::ensure_origin(origin)?;
+
+			match boost.clone() {
+				Some(boost) => {
+					ensure!(
+						boost.multiplier <= T::MaxStakingBoost::get() && !boost.duration.is_zero(),
+						Error::<T>::InvalidStakingBoost
+					);
+					StakingBoostClasses::<T>::insert(class_id, boost);
+				}
+				None => StakingBoostClasses::<T>::remove(class_id),
+			}
+
+			Self::deposit_event(Event::<T>::StakingBoostClassUpdated(class_id, boost));
+
+			Ok(().into())
+		}
+
+		/// Lock boost NFT against the staking position of the origin in the metaverse
+		/// The NFT stays with the origin and is unlocked once the boost expires or the stake is
+		/// withdrawn
+		#[pallet::weight(T::WeightInfo::lock_staking_boost())]
+		#[transactional]
+		pub fn lock_staking_boost(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			nft: (ClassId, TokenId),
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let boost = Self::get_staking_boost_class(nft.0).ok_or(Error::<T>::StakingBoostClassNotFound)?;
+			ensure!(T::NFTHandler::check_ownership(&who, &nft)?, Error::<T>::NoPermission);
+			ensure!(
+				T::NFTHandler::is_transferable(&nft)?,
+				Error::<T>::StakingBoostNftNotAvailable
+			);
+
+			let current_staking_round: RoundInfo<T::BlockNumber> = Self::staking_round();
+			let has_stake = Self::get_metaverse_stake_per_round(&metaverse_id, current_staking_round.current)
+				.map_or(false, |stake_per_round| stake_per_round.stakers.contains_key(&who));
+			ensure!(has_stake, Error::<T>::NoStakeToBoost);
+
+			let expires_at = <frame_system::Pallet<T>>::block_number().saturating_add(boost.duration);
+			ActiveStakingBoosts::<T>::try_mutate(metaverse_id, &who, |active_boosts| -> DispatchResult {
+				ensure!(
+					(active_boosts.len() as u32) < T::MaxActiveStakingBoosts::get(),
+					Error::<T>::TooManyStakingBoosts
+				);
+				ensure!(
+					!active_boosts.iter().any(|active_boost| active_boost.nft.0 == nft.0),
+					Error::<T>::StakingBoostClassAlreadyActive
+				);
+				let total_boost = active_boosts.iter().fold(boost.multiplier, |total, active_boost| {
+					total.saturating_add(active_boost.multiplier)
+				});
+				ensure!(
+					total_boost <= T::MaxStakingBoost::get(),
+					Error::<T>::StakingBoostLimitExceeded
+				);
+
+				active_boosts.push(ActiveStakingBoost {
+					nft,
+					multiplier: boost.multiplier,
+					expires_at,
+					from_round: current_staking_round.current,
+				});
+				Ok(())
+			})?;
+
+			T::NFTHandler::set_lock_nft(&nft, true)?;
+			StakingBoostExpiries::<T>::insert(expires_at, nft, (metaverse_id, who.clone()));
+
+			Self::deposit_event(Event::<T>::StakingBoostLocked(who, metaverse_id, nft, expires_at));
+
+			Ok(().into())
+		}
+
+		/// Lock balance of the origin account to back a metaverse and share in its mining reward
+		/// Backing stake applies immediately and is withdrawn through an unbonding period
+		#[pallet::weight(T::WeightInfo::stake())]
+		pub fn stake_to_metaverse(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				Metaverses::<T>::contains_key(metaverse_id),
+				Error::<T>::MetaverseInfoNotFound
+			);
+			ensure!(
+				!Self::is_metaverse_sunset(metaverse_id),
+				Error::<T>::MetaverseIsInSunset
+			);
+
+			let is_new_backer = !MetaverseBackings::<T>::contains_key(metaverse_id, &who);
+			ensure!(
+				!is_new_backer || Self::get_metaverse_backer_count(metaverse_id) < T::MaxBackersPerMetaverse::get(),
+				Error::<T>::MaximumAmountOfBackersPerMetaverse
+			);
+			let backing = Self::get_metaverse_backing(metaverse_id, &who).saturating_add(amount);
+			ensure!(
+				backing >= T::MinStakingAmount::get(),
+				Error::<T>::MinimumStakingAmountRequired
+			);
+
+			// Balance locked for metaverse staking cannot back a metaverse as well
+			let locked = Self::get_backing_locked(&who).saturating_add(amount);
+			ensure!(
+				T::Currency::free_balance(&who) >= locked.saturating_add(Self::staking_info(&who)),
+				Error::<T>::NotEnoughBalanceToStake
+			);
+
+			MetaverseBackings::<T>::insert(metaverse_id, &who, backing);
+			if is_new_backer {
+				MetaverseBackerCount::<T>::mutate(metaverse_id, |count| *count = count.saturating_add(1));
+			}
+			Self::update_backing_lock(&who, locked);
+
+			Self::deposit_event(Event::<T>::MetaverseBacked(who, metaverse_id, amount));
+			Ok(().into())
+		}
+
+		/// Stop backing a metaverse with the given amount of stake
+		/// The amount stays locked until `BackingUnbondingPeriod` is over
+		#[pallet::weight(T::WeightInfo::unstake_and_withdraw())]
+		pub fn unbond_from_metaverse(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let backing = Self::get_metaverse_backing(metaverse_id, &who);
+			ensure!(
+				!amount.is_zero() && amount <= backing,
+				Error::<T>::InsufficientBackingToUnbond
+			);
+
+			// Remaining backing below minimum is unbonded entirely
+			let remaining = backing.saturating_sub(amount);
+			let amount = if remaining < T::MinStakingAmount::get() {
+				MetaverseBackings::<T>::remove(metaverse_id, &who);
+				MetaverseBackerCount::<T>::mutate(metaverse_id, |count| *count = count.saturating_sub(1));
+				backing
+			} else {
+				MetaverseBackings::<T>::insert(metaverse_id, &who, remaining);
+				amount
+			};
+
+			let unlock_at = <frame_system::Pallet<T>>::block_number().saturating_add(T::BackingUnbondingPeriod::get());
+			BackingUnbonding::<T>::try_mutate(&who, |chunks| -> DispatchResult {
+				ensure!(chunks.len() < MAX_UNBONDING_CHUNKS, Error::<T>::TooManyUnbondingChunks);
+				chunks.push(UnbondingChunk {
+					metaverse_id,
+					amount,
+					unlock_at,
+				});
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::MetaverseBackingUnbonded(
+				who,
+				metaverse_id,
+				amount,
+				unlock_at,
+			));
+			Ok(().into())
+		}
+
+		/// Unlock backing stake of the origin account whose unbonding period is over
+		#[pallet::weight(T::WeightInfo::unstake_and_withdraw())]
+		pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut withdrawn: BalanceOf<T> = Zero::zero();
+			BackingUnbonding::<T>::mutate_exists(&who, |maybe_chunks| {
+				if let Some(chunks) = maybe_chunks {
+					chunks.retain(|chunk| {
+						if chunk.unlock_at <= now {
+							withdrawn = withdrawn.saturating_add(chunk.amount);
+							false
+						} else {
+							true
+						}
+					});
+					if chunks.is_empty() {
+						*maybe_chunks = None;
+					}
+				}
+			});
+			ensure!(!withdrawn.is_zero(), Error::<T>::NoUnbondedBacking);
+
+			Self::update_backing_lock(&who, Self::get_backing_locked(&who).saturating_sub(withdrawn));
+
+			Self::deposit_event(Event::<T>::MetaverseBackingWithdrawn(who, withdrawn));
+			Ok(().into())
+		}
+
+		/// Transfer native balance from the origin into the treasury of a metaverse
+		#[pallet::weight(T::WeightInfo::fund_metaverse_treasury())]
+		pub fn fund_metaverse_treasury(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				Metaverses::<T>::contains_key(metaverse_id),
+				Error::<T>::MetaverseInfoNotFound
+			);
+
+			T::Currency::transfer(
+				&who,
+				&Self::get_metaverse_treasury(metaverse_id),
+				amount,
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			Self::deposit_event(Event::<T>::MetaverseTreasuryFunded(metaverse_id, who, amount));
+
+			Ok(().into())
+		}
+
+		/// Spend native or fungible token balance from the treasury of a metaverse
+		/// Only metaverse owner, its treasurers, its governance or council can spend, the owner and
+		/// treasurers cannot spend once the metaverse is in sunset as the remaining treasury backs the
+		/// local token
+		#[pallet::weight(T::WeightInfo::spend_from_treasury())]
+		pub fn spend_from_treasury(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			currency_id: FungibleTokenId,
+			beneficiary: T::AccountId,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			match T::MetaverseGovernanceOrigin::try_origin(origin) {
+				Ok(origin_metaverse_id) => ensure!(origin_metaverse_id == metaverse_id, Error::<T>::NoPermission),
+				Err(origin) => {
+					if let Err(origin) = T::MetaverseCouncil::try_origin(origin) {
+						let who = ensure_signed(origin)?;
+						ensure!(
+							Self::has_metaverse_role(&who, &metaverse_id, MetaverseRole::Treasurer),
+							Error::<T>::NoPermission
+						);
+						ensure!(
+							!Self::is_metaverse_sunset(metaverse_id),
+							Error::<T>::MetaverseIsInSunset
+						);
+					}
+				}
+			}
+
+			T::MultiCurrency::transfer(
+				currency_id,
+				&Self::get_metaverse_treasury(metaverse_id),
+				&beneficiary,
+				amount,
+			)?;
+
+			Self::record_metaverse_activity(metaverse_id);
+			Self::deposit_event(Event::<T>::MetaverseTreasurySpent(
+				metaverse_id,
+				currency_id,
+				beneficiary,
+				amount,
+			));
+
+			Ok(().into())
+		}
+
+		/// Metaverse owner grants a moderator, builder or treasurer role of the metaverse to an
+		/// account
+		#[pallet::weight(T::WeightInfo::grant_metaverse_role())]
+		pub fn grant_metaverse_role(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			role: MetaverseRole,
+			account: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::check_ownership(&who, &metaverse_id), Error::<T>::NoPermission);
+			ensure!(
+				!MetaverseRoles::<T>::contains_key(metaverse_id, (role, &account)),
+				Error::<T>::MetaverseRoleAlreadyGranted
+			);
+
+			MetaverseRoles::<T>::insert(metaverse_id, (role, &account), ());
+			Self::record_metaverse_activity(metaverse_id);
+
+			Self::deposit_event(Event::<T>::MetaverseRoleGranted(metaverse_id, role, account));
+
+			Ok(().into())
+		}
+
+		/// Metaverse owner revokes a role of the metaverse from an account
+		#[pallet::weight(T::WeightInfo::revoke_metaverse_role())]
+		pub fn revoke_metaverse_role(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			role: MetaverseRole,
+			account: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::check_ownership(&who, &metaverse_id), Error::<T>::NoPermission);
+			ensure!(
+				MetaverseRoles::<T>::contains_key(metaverse_id, (role, &account)),
+				Error::<T>::MetaverseRoleNotFound
+			);
+
+			MetaverseRoles::<T>::remove(metaverse_id, (role, &account));
+			Self::record_metaverse_activity(metaverse_id);
+
+			Self::deposit_event(Event::<T>::MetaverseRoleRevoked(metaverse_id, role, account));
+
+			Ok(().into())
+		}
+
+		/// Metaverse owner or a referendum of the metaverse replaces the metadata of the metaverse
+		#[pallet::weight(T::WeightInfo::update_metaverse_metadata())]
+		pub fn update_metaverse_metadata(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			metadata: MetaverseMetadata,
+		) -> DispatchResultWithPostInfo {
+			match T::MetaverseGovernanceOrigin::try_origin(origin) {
+				Ok(origin_metaverse_id) => ensure!(origin_metaverse_id == metaverse_id, Error::<T>::NoPermission),
+				Err(origin) => {
+					let who = ensure_signed(origin)?;
+					ensure!(Self::check_ownership(&who, &metaverse_id), Error::<T>::NoPermission);
+				}
+			}
+			Self::ensure_valid_metadata(&metadata)?;
+
+			Metaverses::<T>::try_mutate(metaverse_id, |maybe_metaverse| -> DispatchResult {
+				let metaverse_info = maybe_metaverse.as_mut().ok_or(Error::<T>::MetaverseInfoNotFound)?;
+				metaverse_info.metadata = metadata;
+				Ok(())
+			})?;
+			LegacyMetaverseMetadata::<T>::remove(metaverse_id);
+			Self::record_metaverse_activity(metaverse_id);
+
+			Self::deposit_event(Event::<T>::MetaverseMetadataUpdated(metaverse_id));
+
+			Ok(().into())
+		}
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let mut expired_boosts: u64 = 0;
+			for (nft, (metaverse_id, staker)) in StakingBoostExpiries::<T>::drain_prefix(now) {
+				Self::expire_staking_boost(metaverse_id, &staker, nft);
+				expired_boosts = expired_boosts.saturating_add(1);
+			}
+			T::DbWeight::get().reads_writes(
+				expired_boosts.saturating_mul(2).saturating_add(1),
+				expired_boosts.saturating_mul(3),
+			)
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Self::on_chain_storage_version();
+			if on_chain_version < 2 {
+				let mut weight: Weight = 0;
+				if on_chain_version < 1 {
+					weight = weight.saturating_add(Self::upgrade_metaverse_metadata_v1());
+				}
+				weight = weight.saturating_add(Self::upgrade_metaverse_activity_v2());
+				STORAGE_VERSION.put::<Pallet<T>>();
+				return weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+			}
+			T::DbWeight::get().reads(1)
+		}
+	}
 }
 
 impl<T: Config> Pallet<T> {
@@ -621,10 +1346,131 @@ impl<T: Config> Pallet<T> {
 		};
 
 		Metaverses::<T>::insert(metaverse_id, metaverse_info);
+		Self::record_metaverse_activity(metaverse_id);
 
 		Ok(metaverse_id)
 	}
 
+	fn ensure_valid_metadata(metadata: &MetaverseMetadata) -> DispatchResult {
+		ensure!(
+			metadata.version == METAVERSE_METADATA_VERSION,
+			Error::<T>::UnsupportedMetadataVersion
+		);
+		ensure!(
+			metadata.encoded_size() as u32 <= T::MaxMetaverseMetadata::get(),
+			Error::<T>::MaxMetadataExceeded
+		);
+		Ok(())
+	}
+
+	/// Migrate the opaque metadata bytes of existing metaverses to the versioned schema. The
+	/// legacy bytes are kept as the description content identifier when they fit, otherwise they
+	/// are kept in `LegacyMetaverseMetadata`. Owners are expected to update the rest of the
+	/// metadata.
+	pub fn upgrade_metaverse_metadata_v1() -> Weight {
+		log::info!("Start upgrading metaverse metadata v1");
+		let max_metadata = T::MaxMetaverseMetadata::get() as usize;
+		let mut num_metaverses = 0u64;
+		let mut num_legacy_blobs = 0u64;
+		let mut num_truncated_blobs = 0u64;
+
+		Metaverses::<T>::translate(|metaverse_id, legacy: MetaverseInfoV0<T::AccountId>| {
+			num_metaverses += 1;
+			let content_id: Result<ContentId, _> = legacy.metadata.clone().try_into();
+			let description = match content_id {
+				Ok(content_id) => content_id,
+				Err(_) => {
+					let mut blob = legacy.metadata;
+					if blob.len() > max_metadata {
+						log::warn!("Legacy metadata of metaverse {:?} truncated", metaverse_id);
+						blob.truncate(max_metadata);
+						num_truncated_blobs += 1;
+					}
+					let blob: Result<BoundedVec<u8, T::MaxMetaverseMetadata>, _> = blob.try_into();
+					if let Ok(blob) = blob {
+						LegacyMetaverseMetadata::<T>::insert(metaverse_id, blob);
+					}
+					num_legacy_blobs += 1;
+					Default::default()
+				}
+			};
+			let metadata = MetaverseMetadata {
+				version: LEGACY_METAVERSE_METADATA_VERSION,
+				description,
+				..Default::default()
+			};
+			Some(MetaverseInfo {
+				owner: legacy.owner,
+				metadata,
+				currency_id: legacy.currency_id,
+				is_frozen: legacy.is_frozen,
+			})
+		});
+
+		log::info!(
+			"Metaverses upgraded: {}, legacy metadata kept: {}, truncated: {}",
+			num_metaverses,
+			num_legacy_blobs,
+			num_truncated_blobs
+		);
+		T::DbWeight::get().reads_writes(num_metaverses, num_metaverses.saturating_add(num_legacy_blobs))
+	}
+
+	/// Record the current round as the last active round of metaverses created before activity
+	/// was tracked, so they are not flagged stale straight away, and record the deposit reserved
+	/// by the registrants of metaverses registered for staking.
+	pub fn upgrade_metaverse_activity_v2() -> Weight {
+		let current_round = T::RoundHandler::get_current_round_info().current;
+		let mut num_metaverses = 0u64;
+		let mut num_recorded = 0u64;
+		for metaverse_id in Metaverses::<T>::iter_keys() {
+			num_metaverses += 1;
+			if !MetaverseLastActiveRound::<T>::contains_key(metaverse_id) {
+				MetaverseLastActiveRound::<T>::insert(metaverse_id, current_round);
+				num_recorded += 1;
+			}
+		}
+
+		// Registrations before the upgrade reserved the deposit configured at the time
+		let deposit = T::MetaverseRegistrationDeposit::get();
+		let mut num_registered = 0u64;
+		for metaverse_id in RegisteredMetaverse::<T>::iter_keys() {
+			num_registered += 1;
+			if !MetaverseRegistrationDeposits::<T>::contains_key(metaverse_id) {
+				MetaverseRegistrationDeposits::<T>::insert(metaverse_id, deposit);
+			}
+		}
+
+		T::DbWeight::get().reads_writes(
+			num_metaverses
+				.saturating_mul(2)
+				.saturating_add(num_registered.saturating_mul(2)),
+			num_recorded.saturating_add(num_registered),
+		)
+	}
+
+	fn do_initiate_sunset(metaverse_id: MetaverseId) -> DispatchResult {
+		ensure!(
+			!MetaverseSunsets::<T>::contains_key(metaverse_id),
+			Error::<T>::MetaverseAlreadyInSunset
+		);
+
+		Metaverses::<T>::try_mutate(metaverse_id, |maybe_metaverse| -> DispatchResult {
+			let metaverse_info = maybe_metaverse.as_mut().ok_or(Error::<T>::MetaverseInfoNotFound)?;
+			metaverse_info.is_frozen = true;
+			Ok(())
+		})?;
+
+		let retire_at = <frame_system::Pallet<T>>::block_number().saturating_add(T::SunsetPeriod::get());
+		MetaverseSunsets::<T>::insert(metaverse_id, retire_at);
+
+		T::SunsetHandler::on_metaverse_sunset(metaverse_id);
+
+		Self::deposit_event(Event::<T>::MetaverseSunsetInitiated(metaverse_id, retire_at));
+
+		Ok(())
+	}
+
 	/// The account ID of the treasury pot.
 	///
 	/// This actually does computation. If you need to keep using it, then make sure you cache the
@@ -633,6 +1479,55 @@ impl<T: Config> Pallet<T> {
 		T::MetaverseTreasury::get().into_account()
 	}
 
+	/// Staked amount of a round weighted by the boosts of the staker active in that round, capped
+	/// at the maximum boost
+	pub fn staking_reward_weight(
+		metaverse_id: &MetaverseId,
+		staker: &T::AccountId,
+		staked_amount: BalanceOf<T>,
+		round: RoundIndex,
+	) -> BalanceOf<T> {
+		let total_boost = Self::get_active_staking_boosts(metaverse_id, staker)
+			.iter()
+			.filter(|active_boost| active_boost.from_round <= round)
+			.fold(Perbill::zero(), |total, active_boost| {
+				total.saturating_add(active_boost.multiplier)
+			})
+			.min(T::MaxStakingBoost::get());
+
+		staked_amount.saturating_add(total_boost * staked_amount)
+	}
+
+	/// Remove expired boost from the staking position and unlock its NFT
+	fn expire_staking_boost(metaverse_id: MetaverseId, staker: &T::AccountId, nft: (ClassId, TokenId)) {
+		ActiveStakingBoosts::<T>::mutate_exists(metaverse_id, staker, |maybe_active_boosts| {
+			if let Some(active_boosts) = maybe_active_boosts {
+				active_boosts.retain(|active_boost| active_boost.nft != nft);
+				if active_boosts.is_empty() {
+					*maybe_active_boosts = None;
+				}
+			}
+		});
+
+		let _ = T::NFTHandler::set_lock_nft(&nft, false);
+
+		Self::deposit_event(Event::<T>::StakingBoostExpired(staker.clone(), metaverse_id, nft));
+	}
+
+	/// Remove all boosts from a withdrawn staking position and unlock their NFTs
+	fn release_staking_boosts(metaverse_id: MetaverseId, staker: &T::AccountId) {
+		for active_boost in ActiveStakingBoosts::<T>::take(metaverse_id, staker) {
+			StakingBoostExpiries::<T>::remove(active_boost.expires_at, active_boost.nft);
+			let _ = T::NFTHandler::set_lock_nft(&active_boost.nft, false);
+
+			Self::deposit_event(Event::<T>::StakingBoostReleased(
+				staker.clone(),
+				metaverse_id,
+				active_boost.nft,
+			));
+		}
+	}
+
 	/// Update staking info of origin
 	fn update_staking_info(who: &T::AccountId, staking_info: BalanceOf<T>) {
 		if staking_info.is_zero() {
@@ -644,6 +1539,17 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Update backing and unbonding stake locked for an account
+	fn update_backing_lock(who: &T::AccountId, locked: BalanceOf<T>) {
+		if locked.is_zero() {
+			BackingLocked::<T>::remove(who);
+			T::Currency::remove_lock(LOCK_BACKING, who);
+		} else {
+			T::Currency::set_lock(LOCK_BACKING, who, locked, WithdrawReasons::all());
+			BackingLocked::<T>::insert(who, locked);
+		}
+	}
+
 	fn mint_metaverse_land_class(sender: &T::AccountId, metaverse_id: MetaverseId) {
 		// Pre-mint class for lands
 		let mut land_class_attributes = Attributes::new();
@@ -719,6 +1625,22 @@ impl<T: Config> MetaverseTrait<T::AccountId> for Pallet<T> {
 	fn get_metaverse_estate_class(metaverse_id: MetaverseId) -> ClassId {
 		return TryInto::<ClassId>::try_into(16u32).unwrap_or_default();
 	}
+
+	fn get_metaverse_treasury(metaverse_id: MetaverseId) -> T::AccountId {
+		T::MetaverseTreasury::get().into_sub_account(metaverse_id)
+	}
+
+	fn is_metaverse_sunset(metaverse_id: MetaverseId) -> bool {
+		MetaverseSunsets::<T>::contains_key(metaverse_id) || RetiredMetaverses::<T>::contains_key(metaverse_id)
+	}
+
+	fn has_metaverse_role(who: &T::AccountId, metaverse_id: &MetaverseId, role: MetaverseRole) -> bool {
+		Self::check_ownership(who, metaverse_id) || MetaverseRoles::<T>::contains_key(metaverse_id, (role, who))
+	}
+
+	fn record_metaverse_activity(metaverse_id: MetaverseId) {
+		MetaverseLastActiveRound::<T>::insert(metaverse_id, T::RoundHandler::get_current_round_info().current);
+	}
 }
 
 impl<T: Config> MetaverseStakingTrait<BalanceOf<T>> for Pallet<T> {
@@ -732,4 +1654,16 @@ impl<T: Config> MetaverseStakingTrait<BalanceOf<T>> for Pallet<T> {
 
 		Ok(())
 	}
+
+	fn get_total_staked(round: RoundIndex) -> BalanceOf<T> {
+		MetaverseStakingSnapshots::<T>::get(round)
+			.map(|snapshot| snapshot.staked)
+			.unwrap_or_default()
+	}
+}
+
+impl<T: Config> MetaverseBackingTrait<T::AccountId, BalanceOf<T>> for Pallet<T> {
+	fn get_metaverse_backers(metaverse_id: MetaverseId) -> Vec<(T::AccountId, BalanceOf<T>)> {
+		MetaverseBackings::<T>::iter_prefix(metaverse_id).collect()
+	}
 }
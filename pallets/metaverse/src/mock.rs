@@ -1,5 +1,8 @@
 #![cfg(test)]
 
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+
 use frame_support::traits::Nothing;
 use frame_support::{construct_runtime, ord_parameter_types, parameter_types, PalletId};
 use frame_system::EnsureSignedBy;
@@ -24,6 +27,7 @@ pub const BOB: AccountId = 2;
 pub const FREEDY: AccountId = 3;
 pub const METAVERSE_ID: MetaverseId = 0;
 pub const COUNTRY_ID_NOT_EXIST: MetaverseId = 1;
+pub const NATIVE: FungibleTokenId = FungibleTokenId::NativeToken(0);
 
 pub const CLASS_FUND_ID: AccountId = 123;
 pub const BENEFICIARY_ID: AccountId = 99;
@@ -35,6 +39,14 @@ pub const ASSET_COLLECTION_ID: GroupCollectionId = 7;
 
 pub const DOLLARS: Balance = 1_000_000_000_000_000_000;
 
+thread_local! {
+	static LOCKED_NFTS: RefCell<BTreeSet<(ClassId, TokenId)>> = RefCell::new(BTreeSet::new());
+}
+
+pub fn is_nft_locked(nft: &(ClassId, TokenId)) -> bool {
+	LOCKED_NFTS.with(|locked_nfts| locked_nfts.borrow().contains(nft))
+}
+
 // Configure a mock runtime to test the pallet.
 
 parameter_types! {
@@ -92,6 +104,14 @@ parameter_types! {
 	pub const MinContribution: Balance = 1;
 	pub const MinStakingAmount: Balance = 100;
 	pub const MaxNumberOfStakersPerMetaverse: u32 = 1;
+	pub MaxStakingBoost: Perbill = Perbill::from_percent(50);
+	pub const MaxActiveStakingBoosts: u32 = 2;
+	pub const SunsetPeriod: BlockNumber = 10;
+	pub const BackingUnbondingPeriod: BlockNumber = 5;
+	pub const MaxBackersPerMetaverse: u32 = 1;
+	pub const StaleMetaverseRounds: RoundIndex = 10;
+	pub StaleMetaverseSlash: Perbill = Perbill::from_percent(100);
+	pub static RegistrationDeposit: Balance = 1;
 }
 
 fn test_attributes(x: u8) -> Attributes {
@@ -100,6 +120,22 @@ fn test_attributes(x: u8) -> Attributes {
 	attr
 }
 
+pub fn metaverse_metadata() -> MetaverseMetadata {
+	MetaverseMetadata {
+		version: METAVERSE_METADATA_VERSION,
+		name: b"Metaverse".to_vec().try_into().unwrap(),
+		..Default::default()
+	}
+}
+
+pub struct MockRoundHandler;
+
+impl RoundTrait<BlockNumber> for MockRoundHandler {
+	fn get_current_round_info() -> RoundInfo<BlockNumber> {
+		RoundInfo::new(System::block_number() as RoundIndex, System::block_number(), 1)
+	}
+}
+
 pub struct MockNFTHandler;
 
 impl NFTTrait<AccountId, Balance> for MockNFTHandler {
@@ -195,7 +231,18 @@ impl NFTTrait<AccountId, Balance> for MockNFTHandler {
 		Ok(())
 	}
 	fn is_transferable(nft: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError> {
-		Ok(true)
+		Ok(!is_nft_locked(nft))
+	}
+
+	fn set_lock_nft(nft: &(Self::ClassId, Self::TokenId), is_locked: bool) -> DispatchResult {
+		LOCKED_NFTS.with(|locked_nfts| {
+			if is_locked {
+				locked_nfts.borrow_mut().insert(*nft);
+			} else {
+				locked_nfts.borrow_mut().remove(nft);
+			}
+		});
+		Ok(())
 	}
 
 	fn get_class_fund(class_id: &Self::ClassId) -> AccountId {
@@ -228,11 +275,21 @@ impl Config for Runtime {
 	type MaxMetaverseMetadata = MaxTokenMetadata;
 	type MinContribution = MinContribution;
 	type MetaverseCouncil = EnsureSignedBy<One, AccountId>;
-	type MetaverseRegistrationDeposit = MinContribution;
+	type MetaverseGovernanceOrigin = frame_system::EnsureNever<MetaverseId>;
+	type MetaverseRegistrationDeposit = RegistrationDeposit;
 	type MinStakingAmount = MinStakingAmount;
 	type MaxNumberOfStakersPerMetaverse = MaxNumberOfStakersPerMetaverse;
 	type WeightInfo = ();
 	type NFTHandler = MockNFTHandler;
+	type MaxStakingBoost = MaxStakingBoost;
+	type MaxActiveStakingBoosts = MaxActiveStakingBoosts;
+	type SunsetPeriod = SunsetPeriod;
+	type SunsetHandler = ();
+	type BackingUnbondingPeriod = BackingUnbondingPeriod;
+	type MaxBackersPerMetaverse = MaxBackersPerMetaverse;
+	type RoundHandler = MockRoundHandler;
+	type StaleMetaverseRounds = StaleMetaverseRounds;
+	type StaleMetaverseSlash = StaleMetaverseSlash;
 }
 
 parameter_type_with_key! {
@@ -270,6 +327,8 @@ impl currencies::Config for Runtime {
 	type MultiSocialCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type GetNativeCurrencyId = NativeCurrencyId;
+	type TreasuryAccount = TreasuryModuleAccount;
+	type UpdateOrigin = frame_system::EnsureRoot<AccountId>;
 }
 
 pub type MetaverseModule = Pallet<Runtime>;
@@ -28,12 +28,15 @@ use super::*;
 #[test]
 fn create_metaverse_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 		assert_eq!(
 			MetaverseModule::get_metaverse(&METAVERSE_ID),
 			Some(MetaverseInfo {
 				owner: ALICE,
-				metadata: vec![1],
+				metadata: metaverse_metadata(),
 				currency_id: FungibleTokenId::NativeToken(0),
 				is_frozen: false,
 			})
@@ -46,7 +49,10 @@ fn create_metaverse_should_work() {
 #[test]
 fn transfer_metaverse_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 		assert_ok!(MetaverseModule::transfer_metaverse(
 			Origin::signed(ALICE),
 			BOB,
@@ -68,7 +74,10 @@ fn transfer_metaverse_should_work() {
 #[test]
 fn transfer_metaverse_should_fail() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 		assert_noop!(
 			MetaverseModule::transfer_metaverse(Origin::signed(BOB), ALICE, METAVERSE_ID),
 			Error::<Runtime>::NoPermission
@@ -79,7 +88,10 @@ fn transfer_metaverse_should_fail() {
 #[test]
 fn freeze_metaverse_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 		assert_ok!(MetaverseModule::freeze_metaverse(Origin::signed(ALICE), METAVERSE_ID));
 		let event = Event::Metaverse(crate::Event::MetaverseFreezed(METAVERSE_ID));
 		assert_eq!(last_event(), event);
@@ -89,7 +101,10 @@ fn freeze_metaverse_should_work() {
 #[test]
 fn freeze_metaverse_should_fail() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 		//Country owner tries to freeze their own metaverse
 		assert_noop!(
 			MetaverseModule::freeze_metaverse(Origin::signed(BOB), METAVERSE_ID),
@@ -101,7 +116,10 @@ fn freeze_metaverse_should_fail() {
 #[test]
 fn unfreeze_metaverse_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 		assert_ok!(MetaverseModule::freeze_metaverse(Origin::signed(ALICE), METAVERSE_ID));
 		let event = Event::Metaverse(crate::Event::MetaverseFreezed(METAVERSE_ID));
 		assert_eq!(last_event(), event);
@@ -114,7 +132,10 @@ fn unfreeze_metaverse_should_work() {
 #[test]
 fn destroy_metaverse_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 		assert_ok!(MetaverseModule::freeze_metaverse(Origin::signed(ALICE), METAVERSE_ID));
 		assert_ok!(MetaverseModule::destroy_metaverse(Origin::signed(ALICE), METAVERSE_ID));
 		let event = Event::Metaverse(crate::Event::MetaverseDestroyed(METAVERSE_ID));
@@ -126,7 +147,10 @@ fn destroy_metaverse_should_work() {
 #[test]
 fn destroy_metaverse_without_root_should_fail() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 		assert_noop!(
 			MetaverseModule::destroy_metaverse(Origin::signed(2), METAVERSE_ID),
 			BadOrigin
@@ -137,7 +161,10 @@ fn destroy_metaverse_without_root_should_fail() {
 #[test]
 fn destroy_metaverse_with_no_id_should_fail() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 		assert_noop!(
 			MetaverseModule::destroy_metaverse(Origin::signed(ALICE), COUNTRY_ID_NOT_EXIST),
 			Error::<Runtime>::MetaverseInfoNotFound
@@ -148,7 +175,10 @@ fn destroy_metaverse_with_no_id_should_fail() {
 #[test]
 fn register_metaverse_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
 		let event = Event::Metaverse(crate::Event::NewMetaverseRegisteredForStaking(METAVERSE_ID, ALICE));
 		assert_eq!(last_event(), event);
@@ -160,7 +190,10 @@ fn register_metaverse_should_work() {
 #[test]
 fn register_metaverse_should_fail_no_permission() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 
 		assert_noop!(
 			MetaverseModule::register_metaverse(Origin::signed(BOB), METAVERSE_ID),
@@ -172,7 +205,10 @@ fn register_metaverse_should_fail_no_permission() {
 #[test]
 fn register_metaverse_should_fail_already_registered() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 
 		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
 
@@ -189,7 +225,10 @@ fn register_metaverse_should_fail_already_registered() {
 #[test]
 fn stake_should_fail_not_registered() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 		assert_noop!(
 			MetaverseModule::stake(Origin::signed(ALICE), METAVERSE_ID, 100),
 			Error::<Runtime>::NotRegisteredForStaking
@@ -200,7 +239,10 @@ fn stake_should_fail_not_registered() {
 #[test]
 fn stake_should_fail_not_enough_balance() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
 		assert_noop!(
 			MetaverseModule::stake(Origin::signed(FREEDY), METAVERSE_ID, 10000),
@@ -212,7 +254,10 @@ fn stake_should_fail_not_enough_balance() {
 #[test]
 fn stake_should_fail_min_staking_required() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
 
 		assert_err!(
@@ -225,7 +270,10 @@ fn stake_should_fail_min_staking_required() {
 #[test]
 fn stake_should_fail_max_stakers() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
 		assert_ok!(MetaverseModule::stake(Origin::signed(ALICE), METAVERSE_ID, 10000));
 
@@ -239,7 +287,10 @@ fn stake_should_fail_max_stakers() {
 #[test]
 fn stake_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
 		assert_ok!(MetaverseModule::stake(Origin::signed(ALICE), METAVERSE_ID, 100000));
 
@@ -263,7 +314,10 @@ fn stake_should_work() {
 #[test]
 fn stake_should_work_with_min_value() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
 		assert_ok!(MetaverseModule::stake(Origin::signed(BOB), METAVERSE_ID, 50000));
 
@@ -277,7 +331,10 @@ fn stake_should_work_with_min_value() {
 #[test]
 fn unstake_should_fail_not_registered() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 		assert_noop!(
 			MetaverseModule::unstake_and_withdraw(Origin::signed(ALICE), METAVERSE_ID, 100),
 			Error::<Runtime>::NotRegisteredForStaking
@@ -288,7 +345,10 @@ fn unstake_should_fail_not_registered() {
 #[test]
 fn unstake_should_fail_no_staking_info() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
 
 		assert_noop!(
@@ -301,7 +361,10 @@ fn unstake_should_fail_no_staking_info() {
 #[test]
 fn unstake_should_fail_no_permission() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
 		assert_ok!(MetaverseModule::stake(Origin::signed(ALICE), METAVERSE_ID, 10000));
 
@@ -315,7 +378,10 @@ fn unstake_should_fail_no_permission() {
 #[test]
 fn unstake_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_ok!(MetaverseModule::create_metaverse(Origin::signed(ALICE), vec![1]));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
 		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
 		assert_ok!(MetaverseModule::stake(Origin::signed(ALICE), METAVERSE_ID, 10000));
 
@@ -337,3 +403,733 @@ fn unstake_should_work() {
 		assert_eq!(*(metaverse_stake_per_round.stakers.entry(ALICE).or_default()), 9900u64);
 	})
 }
+
+#[test]
+fn set_staking_boost_class_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let boost = StakingBoostInfo {
+			multiplier: Perbill::from_percent(20),
+			duration: 10,
+		};
+		assert_noop!(
+			MetaverseModule::set_staking_boost_class(Origin::signed(BOB), 10, Some(boost)),
+			BadOrigin
+		);
+
+		let boost_above_limit = StakingBoostInfo {
+			multiplier: Perbill::from_percent(60),
+			duration: 10,
+		};
+		assert_noop!(
+			MetaverseModule::set_staking_boost_class(Origin::signed(ALICE), 10, Some(boost_above_limit)),
+			Error::<Runtime>::InvalidStakingBoost
+		);
+	})
+}
+
+#[test]
+fn lock_staking_boost_should_work_and_expire() {
+	ExtBuilder::default().build().execute_with(|| {
+		Round::<Runtime>::put(RoundInfo::new(2, 1, 10));
+		assert_ok!(MetaverseModule::set_staking_boost_class(
+			Origin::signed(ALICE),
+			10,
+			Some(StakingBoostInfo {
+				multiplier: Perbill::from_percent(20),
+				duration: 10,
+			})
+		));
+		assert_ok!(MetaverseModule::set_staking_boost_class(
+			Origin::signed(ALICE),
+			11,
+			Some(StakingBoostInfo {
+				multiplier: Perbill::from_percent(40),
+				duration: 20,
+			})
+		));
+
+		assert_noop!(
+			MetaverseModule::lock_staking_boost(Origin::signed(ALICE), METAVERSE_ID, (10, 1)),
+			Error::<Runtime>::NoStakeToBoost
+		);
+
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
+		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
+		assert_ok!(MetaverseModule::stake(Origin::signed(ALICE), METAVERSE_ID, 1000));
+
+		assert_noop!(
+			MetaverseModule::lock_staking_boost(Origin::signed(ALICE), METAVERSE_ID, (10, 2)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(MetaverseModule::lock_staking_boost(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			(10, 1)
+		));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::StakingBoostLocked(ALICE, METAVERSE_ID, (10, 1), 11))
+		);
+		assert!(is_nft_locked(&(10, 1)));
+		assert_eq!(
+			MetaverseModule::staking_reward_weight(&METAVERSE_ID, &ALICE, 1000, 2),
+			1200
+		);
+		// Rounds before the boost was locked are not boosted
+		assert_eq!(
+			MetaverseModule::staking_reward_weight(&METAVERSE_ID, &ALICE, 1000, 1),
+			1000
+		);
+
+		// Boosts of the same class do not stack and stacked boosts are capped
+		assert_noop!(
+			MetaverseModule::lock_staking_boost(Origin::signed(ALICE), METAVERSE_ID, (10, 3)),
+			Error::<Runtime>::StakingBoostClassAlreadyActive
+		);
+		assert_noop!(
+			MetaverseModule::lock_staking_boost(Origin::signed(ALICE), METAVERSE_ID, (11, 3)),
+			Error::<Runtime>::StakingBoostLimitExceeded
+		);
+
+		MetaverseModule::on_initialize(11);
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::StakingBoostExpired(ALICE, METAVERSE_ID, (10, 1)))
+		);
+		assert!(MetaverseModule::get_active_staking_boosts(METAVERSE_ID, ALICE).is_empty());
+		assert!(!is_nft_locked(&(10, 1)));
+		assert_eq!(
+			MetaverseModule::staking_reward_weight(&METAVERSE_ID, &ALICE, 1000, 2),
+			1000
+		);
+	})
+}
+
+#[test]
+fn unstake_should_release_staking_boosts() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::set_staking_boost_class(
+			Origin::signed(ALICE),
+			10,
+			Some(StakingBoostInfo {
+				multiplier: Perbill::from_percent(20),
+				duration: 10,
+			})
+		));
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
+		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
+		assert_ok!(MetaverseModule::stake(Origin::signed(ALICE), METAVERSE_ID, 1000));
+		assert_ok!(MetaverseModule::lock_staking_boost(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			(10, 1)
+		));
+
+		// Locked boost NFTs cannot be locked again
+		assert_noop!(
+			MetaverseModule::lock_staking_boost(Origin::signed(ALICE), METAVERSE_ID, (10, 1)),
+			Error::<Runtime>::StakingBoostNftNotAvailable
+		);
+
+		assert_ok!(MetaverseModule::unstake_and_withdraw(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			1000
+		));
+		assert!(MetaverseModule::get_active_staking_boosts(METAVERSE_ID, ALICE).is_empty());
+		assert_eq!(MetaverseModule::get_staking_boost_expiries(11, (10, 1)), None);
+		assert!(!is_nft_locked(&(10, 1)));
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event
+				== Event::Metaverse(crate::Event::StakingBoostReleased(ALICE, METAVERSE_ID, (10, 1)))));
+	})
+}
+
+#[test]
+fn metaverse_sunset_should_retire_metaverse() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
+		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(ALICE), METAVERSE_ID));
+		assert_ok!(MetaverseModule::stake(Origin::signed(ALICE), METAVERSE_ID, 10000));
+
+		assert_noop!(
+			MetaverseModule::initiate_metaverse_sunset(Origin::signed(BOB), METAVERSE_ID),
+			BadOrigin
+		);
+		assert_noop!(
+			MetaverseModule::retire_metaverse(Origin::signed(BOB), METAVERSE_ID),
+			Error::<Runtime>::MetaverseNotInSunset
+		);
+		assert_ok!(MetaverseModule::initiate_metaverse_sunset(
+			Origin::signed(ALICE),
+			METAVERSE_ID
+		));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::MetaverseSunsetInitiated(METAVERSE_ID, 11))
+		);
+		assert!(MetaverseModule::get_metaverse(&METAVERSE_ID).unwrap().is_frozen);
+		assert!(MetaverseModule::is_metaverse_sunset(METAVERSE_ID));
+
+		// No new activity while the metaverse is winding down
+		assert_noop!(
+			MetaverseModule::initiate_metaverse_sunset(Origin::signed(ALICE), METAVERSE_ID),
+			Error::<Runtime>::MetaverseAlreadyInSunset
+		);
+		assert_noop!(
+			MetaverseModule::unfreeze_metaverse(Origin::signed(ALICE), METAVERSE_ID),
+			Error::<Runtime>::MetaverseIsInSunset
+		);
+		assert_noop!(
+			MetaverseModule::stake(Origin::signed(ALICE), METAVERSE_ID, 100),
+			Error::<Runtime>::MetaverseIsInSunset
+		);
+		assert_noop!(
+			MetaverseModule::retire_metaverse(Origin::signed(BOB), METAVERSE_ID),
+			Error::<Runtime>::MetaverseSunsetNotOver
+		);
+
+		System::set_block_number(11);
+		assert_ok!(MetaverseModule::retire_metaverse(Origin::signed(BOB), METAVERSE_ID));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::MetaverseRetired(METAVERSE_ID, ALICE))
+		);
+		assert_eq!(MetaverseModule::get_metaverse(&METAVERSE_ID), None);
+		assert_eq!(MetaverseModule::get_metaverse_owner(ALICE, METAVERSE_ID), None);
+		assert_eq!(
+			MetaverseModule::get_retired_metaverse(METAVERSE_ID),
+			Some(RetiredMetaverseInfo {
+				owner: ALICE,
+				currency_id: FungibleTokenId::NativeToken(0),
+				retired_at: 11,
+			})
+		);
+		assert!(MetaverseModule::is_metaverse_sunset(METAVERSE_ID));
+
+		// Stakers can still withdraw from the retired metaverse
+		assert_ok!(MetaverseModule::unstake_and_withdraw(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			100
+		));
+	})
+}
+
+#[test]
+fn metaverse_treasury_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(BOB),
+			metaverse_metadata()
+		));
+		let treasury = MetaverseModule::get_metaverse_treasury(METAVERSE_ID);
+
+		assert_noop!(
+			MetaverseModule::fund_metaverse_treasury(Origin::signed(ALICE), COUNTRY_ID_NOT_EXIST, 100),
+			Error::<Runtime>::MetaverseInfoNotFound
+		);
+		assert_ok!(MetaverseModule::fund_metaverse_treasury(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			1000
+		));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::MetaverseTreasuryFunded(METAVERSE_ID, ALICE, 1000))
+		);
+		assert_eq!(Balances::free_balance(treasury), 1000);
+
+		// Only the metaverse owner or council can spend
+		assert_noop!(
+			MetaverseModule::spend_from_treasury(Origin::signed(FREEDY), METAVERSE_ID, NATIVE, FREEDY, 100),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(MetaverseModule::spend_from_treasury(
+			Origin::signed(BOB),
+			METAVERSE_ID,
+			NATIVE,
+			FREEDY,
+			300
+		));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::MetaverseTreasurySpent(METAVERSE_ID, NATIVE, FREEDY, 300))
+		);
+		assert_eq!(Balances::free_balance(FREEDY), 300);
+
+		// Fungible tokens held by the treasury are spent through the multi currency
+		let mining_resource = FungibleTokenId::MiningResource(0);
+		assert_ok!(Currencies::deposit(mining_resource, &treasury, 500));
+		assert_ok!(MetaverseModule::spend_from_treasury(
+			Origin::signed(BOB),
+			METAVERSE_ID,
+			mining_resource,
+			FREEDY,
+			200
+		));
+		assert_eq!(Currencies::free_balance(mining_resource, &FREEDY), 200);
+		assert_eq!(Currencies::free_balance(mining_resource, &treasury), 300);
+
+		// Owner can no longer spend once the metaverse is in sunset
+		assert_ok!(MetaverseModule::initiate_metaverse_sunset(
+			Origin::signed(ALICE),
+			METAVERSE_ID
+		));
+		assert_noop!(
+			MetaverseModule::spend_from_treasury(Origin::signed(BOB), METAVERSE_ID, NATIVE, BOB, 100),
+			Error::<Runtime>::MetaverseIsInSunset
+		);
+		assert_ok!(MetaverseModule::spend_from_treasury(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			NATIVE,
+			FREEDY,
+			700
+		));
+		assert_eq!(Balances::free_balance(treasury), 0);
+	})
+}
+
+#[test]
+fn metaverse_backing_with_unbonding_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
+
+		assert_noop!(
+			MetaverseModule::stake_to_metaverse(Origin::signed(BOB), COUNTRY_ID_NOT_EXIST, 1000),
+			Error::<Runtime>::MetaverseInfoNotFound
+		);
+		assert_noop!(
+			MetaverseModule::stake_to_metaverse(Origin::signed(BOB), METAVERSE_ID, 50),
+			Error::<Runtime>::MinimumStakingAmountRequired
+		);
+		assert_noop!(
+			MetaverseModule::stake_to_metaverse(Origin::signed(BOB), METAVERSE_ID, 20001),
+			Error::<Runtime>::NotEnoughBalanceToStake
+		);
+		assert_ok!(MetaverseModule::stake_to_metaverse(
+			Origin::signed(BOB),
+			METAVERSE_ID,
+			1000
+		));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::MetaverseBacked(BOB, METAVERSE_ID, 1000))
+		);
+		assert_eq!(MetaverseModule::get_metaverse_backers(METAVERSE_ID), vec![(BOB, 1000)]);
+		assert_eq!(Balances::usable_balance(BOB), 19000);
+
+		// Unbonded stake no longer backs the metaverse but stays locked
+		assert_noop!(
+			MetaverseModule::unbond_from_metaverse(Origin::signed(BOB), METAVERSE_ID, 1001),
+			Error::<Runtime>::InsufficientBackingToUnbond
+		);
+		assert_ok!(MetaverseModule::unbond_from_metaverse(
+			Origin::signed(BOB),
+			METAVERSE_ID,
+			400
+		));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::MetaverseBackingUnbonded(BOB, METAVERSE_ID, 400, 6))
+		);
+		assert_eq!(MetaverseModule::get_metaverse_backing(METAVERSE_ID, BOB), 600);
+		assert_eq!(Balances::usable_balance(BOB), 19000);
+		assert_noop!(
+			MetaverseModule::withdraw_unbonded(Origin::signed(BOB)),
+			Error::<Runtime>::NoUnbondedBacking
+		);
+
+		System::set_block_number(6);
+		assert_ok!(MetaverseModule::withdraw_unbonded(Origin::signed(BOB)));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::MetaverseBackingWithdrawn(BOB, 400))
+		);
+		assert_eq!(MetaverseModule::get_backing_locked(BOB), 600);
+		assert_eq!(Balances::usable_balance(BOB), 19400);
+	})
+}
+
+#[test]
+fn stake_to_metaverse_should_respect_max_backers() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
+		assert_ok!(MetaverseModule::stake_to_metaverse(
+			Origin::signed(BOB),
+			METAVERSE_ID,
+			1000
+		));
+		assert_eq!(MetaverseModule::get_metaverse_backer_count(METAVERSE_ID), 1);
+
+		assert_noop!(
+			MetaverseModule::stake_to_metaverse(Origin::signed(ALICE), METAVERSE_ID, 1000),
+			Error::<Runtime>::MaximumAmountOfBackersPerMetaverse
+		);
+		// Existing backers can still add stake
+		assert_ok!(MetaverseModule::stake_to_metaverse(
+			Origin::signed(BOB),
+			METAVERSE_ID,
+			500
+		));
+		assert_eq!(MetaverseModule::get_metaverse_backer_count(METAVERSE_ID), 1);
+
+		// Backer leaving frees a place
+		assert_ok!(MetaverseModule::unbond_from_metaverse(
+			Origin::signed(BOB),
+			METAVERSE_ID,
+			1500
+		));
+		assert_eq!(MetaverseModule::get_metaverse_backer_count(METAVERSE_ID), 0);
+		assert_ok!(MetaverseModule::stake_to_metaverse(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			1000
+		));
+		assert_eq!(
+			MetaverseModule::get_metaverse_backers(METAVERSE_ID),
+			vec![(ALICE, 1000)]
+		);
+	})
+}
+
+#[test]
+fn metaverse_role_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(BOB),
+			metaverse_metadata()
+		));
+		assert_ok!(MetaverseModule::fund_metaverse_treasury(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			1000
+		));
+
+		assert_noop!(
+			MetaverseModule::grant_metaverse_role(
+				Origin::signed(FREEDY),
+				METAVERSE_ID,
+				MetaverseRole::Treasurer,
+				FREEDY
+			),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(MetaverseModule::grant_metaverse_role(
+			Origin::signed(BOB),
+			METAVERSE_ID,
+			MetaverseRole::Treasurer,
+			FREEDY
+		));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::MetaverseRoleGranted(
+				METAVERSE_ID,
+				MetaverseRole::Treasurer,
+				FREEDY
+			))
+		);
+		assert_noop!(
+			MetaverseModule::grant_metaverse_role(Origin::signed(BOB), METAVERSE_ID, MetaverseRole::Treasurer, FREEDY),
+			Error::<Runtime>::MetaverseRoleAlreadyGranted
+		);
+		assert!(MetaverseModule::has_metaverse_role(
+			&FREEDY,
+			&METAVERSE_ID,
+			MetaverseRole::Treasurer
+		));
+		assert!(!MetaverseModule::has_metaverse_role(
+			&FREEDY,
+			&METAVERSE_ID,
+			MetaverseRole::Builder
+		));
+		assert!(MetaverseModule::has_metaverse_role(
+			&BOB,
+			&METAVERSE_ID,
+			MetaverseRole::Builder
+		));
+
+		// Treasurer can spend from the metaverse treasury
+		assert_ok!(MetaverseModule::spend_from_treasury(
+			Origin::signed(FREEDY),
+			METAVERSE_ID,
+			NATIVE,
+			FREEDY,
+			300
+		));
+		assert_eq!(Balances::free_balance(FREEDY), 300);
+
+		assert_ok!(MetaverseModule::revoke_metaverse_role(
+			Origin::signed(BOB),
+			METAVERSE_ID,
+			MetaverseRole::Treasurer,
+			FREEDY
+		));
+		assert_noop!(
+			MetaverseModule::revoke_metaverse_role(Origin::signed(BOB), METAVERSE_ID, MetaverseRole::Treasurer, FREEDY),
+			Error::<Runtime>::MetaverseRoleNotFound
+		);
+		assert_noop!(
+			MetaverseModule::spend_from_treasury(Origin::signed(FREEDY), METAVERSE_ID, NATIVE, FREEDY, 100),
+			Error::<Runtime>::NoPermission
+		);
+	})
+}
+
+#[test]
+fn transfer_metaverse_should_clear_roles() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(BOB),
+			metaverse_metadata()
+		));
+		assert_ok!(MetaverseModule::grant_metaverse_role(
+			Origin::signed(BOB),
+			METAVERSE_ID,
+			MetaverseRole::Treasurer,
+			FREEDY
+		));
+		assert!(MetaverseModule::has_metaverse_role(
+			&FREEDY,
+			&METAVERSE_ID,
+			MetaverseRole::Treasurer
+		));
+
+		assert_ok!(MetaverseModule::transfer_metaverse(
+			Origin::signed(BOB),
+			ALICE,
+			METAVERSE_ID
+		));
+		assert!(!MetaverseModule::has_metaverse_role(
+			&FREEDY,
+			&METAVERSE_ID,
+			MetaverseRole::Treasurer
+		));
+		assert!(!MetaverseModule::has_metaverse_role(
+			&BOB,
+			&METAVERSE_ID,
+			MetaverseRole::Treasurer
+		));
+		assert!(MetaverseModule::has_metaverse_role(
+			&ALICE,
+			&METAVERSE_ID,
+			MetaverseRole::Treasurer
+		));
+	})
+}
+
+#[test]
+fn flag_stale_metaverse_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(BOB),
+			metaverse_metadata()
+		));
+		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(BOB), METAVERSE_ID));
+		assert_eq!(Balances::reserved_balance(BOB), 1);
+
+		System::set_block_number(8);
+		assert_ok!(MetaverseModule::grant_metaverse_role(
+			Origin::signed(BOB),
+			METAVERSE_ID,
+			MetaverseRole::Builder,
+			FREEDY
+		));
+		assert_eq!(MetaverseModule::get_metaverse_last_active_round(METAVERSE_ID), 8);
+
+		System::set_block_number(11);
+		assert_noop!(
+			MetaverseModule::flag_stale_metaverse(Origin::signed(BOB), METAVERSE_ID),
+			BadOrigin
+		);
+		assert_noop!(
+			MetaverseModule::flag_stale_metaverse(Origin::signed(ALICE), METAVERSE_ID),
+			Error::<Runtime>::MetaverseIsNotStale
+		);
+
+		System::set_block_number(18);
+		assert_ok!(MetaverseModule::flag_stale_metaverse(
+			Origin::signed(ALICE),
+			METAVERSE_ID
+		));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::MetaverseFlaggedStale(METAVERSE_ID, 1))
+		);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(MetaverseModule::get_metaverse_sunset(METAVERSE_ID), Some(28));
+		assert_eq!(MetaverseModule::get_registered_metaverse(METAVERSE_ID), None);
+		assert_eq!(MetaverseModule::get_metaverse_registration_deposit(METAVERSE_ID), 0);
+		assert_noop!(
+			MetaverseModule::flag_stale_metaverse(Origin::signed(ALICE), METAVERSE_ID),
+			Error::<Runtime>::MetaverseAlreadyInSunset
+		);
+		assert_noop!(
+			MetaverseModule::register_metaverse(Origin::signed(BOB), METAVERSE_ID),
+			Error::<Runtime>::MetaverseIsInSunset
+		);
+	})
+}
+
+#[test]
+fn flag_stale_metaverse_should_release_reserved_deposit() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(BOB),
+			metaverse_metadata()
+		));
+		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(BOB), METAVERSE_ID));
+		assert_ok!(Balances::reserve(&BOB, 100));
+		assert_eq!(Balances::reserved_balance(BOB), 101);
+
+		// A deposit change after registration does not touch the other reserves of the registrant
+		RegistrationDeposit::set(10);
+		System::set_block_number(11);
+		assert_ok!(MetaverseModule::flag_stale_metaverse(
+			Origin::signed(ALICE),
+			METAVERSE_ID
+		));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::MetaverseFlaggedStale(METAVERSE_ID, 1))
+		);
+		assert_eq!(Balances::reserved_balance(BOB), 100);
+	})
+}
+
+#[test]
+fn upgrade_metaverse_activity_v2_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(BOB),
+			metaverse_metadata()
+		));
+		assert_ok!(MetaverseModule::register_metaverse(Origin::signed(BOB), METAVERSE_ID));
+		MetaverseLastActiveRound::<Runtime>::remove(METAVERSE_ID);
+		MetaverseRegistrationDeposits::<Runtime>::remove(METAVERSE_ID);
+
+		System::set_block_number(20);
+		MetaverseModule::upgrade_metaverse_activity_v2();
+		assert_eq!(MetaverseModule::get_metaverse_last_active_round(METAVERSE_ID), 20);
+		assert_eq!(MetaverseModule::get_metaverse_registration_deposit(METAVERSE_ID), 1);
+
+		System::set_block_number(25);
+		assert_noop!(
+			MetaverseModule::flag_stale_metaverse(Origin::signed(ALICE), METAVERSE_ID),
+			Error::<Runtime>::MetaverseIsNotStale
+		);
+	})
+}
+
+#[test]
+fn update_metaverse_metadata_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MetaverseModule::create_metaverse(
+			Origin::signed(ALICE),
+			metaverse_metadata()
+		));
+
+		let metadata = MetaverseMetadata {
+			description: b"bafkreidescription".to_vec().try_into().unwrap(),
+			external_url: b"https://bit.country".to_vec().try_into().unwrap(),
+			..metaverse_metadata()
+		};
+		assert_noop!(
+			MetaverseModule::update_metaverse_metadata(Origin::signed(BOB), METAVERSE_ID, metadata.clone()),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			MetaverseModule::update_metaverse_metadata(
+				Origin::signed(ALICE),
+				METAVERSE_ID,
+				MetaverseMetadata {
+					version: LEGACY_METAVERSE_METADATA_VERSION,
+					..metadata.clone()
+				}
+			),
+			Error::<Runtime>::UnsupportedMetadataVersion
+		);
+		assert_ok!(MetaverseModule::update_metaverse_metadata(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			metadata.clone()
+		));
+		assert_eq!(
+			last_event(),
+			Event::Metaverse(crate::Event::MetaverseMetadataUpdated(METAVERSE_ID))
+		);
+		assert_eq!(MetaverseModule::get_metaverse(METAVERSE_ID).unwrap().metadata, metadata);
+	})
+}
+
+#[test]
+fn upgrade_metaverse_metadata_v1_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let legacy = MetaverseInfoV0 {
+			owner: ALICE,
+			metadata: b"bafkreilegacy".to_vec(),
+			currency_id: FungibleTokenId::NativeToken(0),
+			is_frozen: false,
+		};
+		frame_support::storage::unhashed::put(&Metaverses::<Runtime>::hashed_key_for(METAVERSE_ID), &legacy);
+
+		MetaverseModule::upgrade_metaverse_metadata_v1();
+
+		let metadata = MetaverseModule::get_metaverse(METAVERSE_ID).unwrap().metadata;
+		assert_eq!(metadata.version, LEGACY_METAVERSE_METADATA_VERSION);
+		assert_eq!(metadata.description.to_vec(), b"bafkreilegacy".to_vec());
+		assert!(metadata.name.is_empty());
+		assert_eq!(MetaverseModule::get_legacy_metaverse_metadata(METAVERSE_ID), None);
+	})
+}
+
+#[test]
+fn upgrade_metaverse_metadata_v1_should_keep_legacy_blob() {
+	ExtBuilder::default().build().execute_with(|| {
+		let blob = br#"{"name":"Bit Country Pioneer","description":"The first metaverse created on the Pioneer network, home of the early builders and explorers of the continuum","image":"ipfs://bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku","external_url":"https://pioneer.bit.country"}"#.to_vec();
+		assert!(blob.len() > 64);
+		let legacy = MetaverseInfoV0 {
+			owner: BOB,
+			metadata: blob.clone(),
+			currency_id: FungibleTokenId::NativeToken(0),
+			is_frozen: false,
+		};
+		frame_support::storage::unhashed::put(&Metaverses::<Runtime>::hashed_key_for(METAVERSE_ID), &legacy);
+		MetaverseOwner::<Runtime>::insert(BOB, METAVERSE_ID, ());
+
+		MetaverseModule::upgrade_metaverse_metadata_v1();
+
+		let metadata = MetaverseModule::get_metaverse(METAVERSE_ID).unwrap().metadata;
+		assert_eq!(metadata.version, LEGACY_METAVERSE_METADATA_VERSION);
+		assert!(metadata.description.is_empty());
+		assert_eq!(
+			MetaverseModule::get_legacy_metaverse_metadata(METAVERSE_ID).map(|blob| blob.to_vec()),
+			Some(blob)
+		);
+
+		// The legacy blob is dropped once the owner moves to the versioned schema
+		assert_ok!(MetaverseModule::update_metaverse_metadata(
+			Origin::signed(BOB),
+			METAVERSE_ID,
+			metaverse_metadata()
+		));
+		assert_eq!(MetaverseModule::get_legacy_metaverse_metadata(METAVERSE_ID), None);
+	})
+}
@@ -63,6 +63,12 @@ fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
 	caller
 }
 
+/// Roll the mining round over `rounds` times without authoring the round blocks, for benchmarks
+/// which need rewards of past rounds in place
+pub fn advance_mining_rounds<T: Config>(rounds: u32) {
+	crate::Pallet::<T>::do_advance_rounds(rounds);
+}
+
 benchmarks! {
 	// add minting origin
 	add_minting_origin {
@@ -152,5 +158,24 @@ benchmarks! {
 		assert_eq!(T::MiningCurrency::free_balance(FungibleTokenId::MiningResource(0), &origin.clone()), 900);
 		assert_eq!(T::MiningCurrency::free_balance(FungibleTokenId::MiningResource(0), &dest.clone()), 1100);
 	}
+
+	// set mining boost class with the boost classes full but one
+	set_mining_boost_class {
+		let max_classes = T::MaxMiningBoostClasses::get();
+		for class_id in 1..max_classes {
+			crate::Pallet::<T>::set_mining_boost_class(T::AdminOrigin::successful_origin(), class_id, Some(200));
+		}
+	}: _<T::Origin>(T::AdminOrigin::successful_origin(), 0, Some(200))
+	verify {
+		assert_eq!(crate::Pallet::<T>::mining_boost_of_class(0), Some(200));
+	}
+
+	// force advance round
+	force_advance_round {
+		let round_before = crate::Pallet::<T>::round().current;
+	}: _(RawOrigin::Root, 10)
+	verify {
+		assert_eq!(crate::Pallet::<T>::round().current, round_before + 10);
+	}
 }
 impl_benchmark_test_suite!(Pallet, crate::benchmarking::tests::new_test_ext(), crate::mock::Test);
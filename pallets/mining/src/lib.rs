@@ -36,16 +36,19 @@ use orml_traits::{
 };
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{AccountIdConversion, AtLeast32Bit, One, StaticLookup, Zero},
-	DispatchError,
+	traits::{AccountIdConversion, AtLeast32Bit, One, Saturating, StaticLookup, Zero},
+	DispatchError, Perbill,
 };
 use sp_std::vec::Vec;
 
 use auction_manager::SwapManager;
 use core_primitives::*;
 pub use pallet::*;
-use primitives::staking::RoundInfo;
-use primitives::{Balance, CurrencyId, FungibleTokenId, MetaverseId};
+use primitives::staking::{RoundInfo, RoundIssuanceSummary};
+use primitives::{
+	Balance, ClassId, CurrencyId, FungibleTokenId, MetaverseId, OnboardingQuest, OnboardingQuestTrait, RoundIndex,
+	VestingSchedule,
+};
 pub use weights::WeightInfo;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -58,6 +61,13 @@ mod mining;
 #[cfg(test)]
 mod tests;
 
+/// Whether `force_advance_round` is enabled in this build
+const ROUND_FAST_FORWARD_ENABLED: bool = cfg!(any(
+	test,
+	feature = "round-fast-forward",
+	feature = "runtime-benchmarks"
+));
+
 /// A wrapper for a token name.
 pub type TokenName = Vec<u8>;
 
@@ -73,7 +83,62 @@ pub struct Token<Balance> {
 /// The maximum number of vesting schedules an account can have.
 pub const MAX_VESTINGS: usize = 20;
 
-pub const VESTING_LOCK_ID: LockIdentifier = *b"bcstvest";
+/// Lock of vested mining rewards, distinct from the local token vesting lock of tokenization
+pub const MINING_VESTING_LOCK_ID: LockIdentifier = *b"bcmnvest";
+
+/// Mining power multiplier of metaverses without a governance set weight
+pub const DEFAULT_MINING_WEIGHT_PERCENT: u32 = 100;
+
+/// Share of the mined rewards of a round locked under a linear vesting schedule
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MiningRewardVesting<BlockNumber> {
+	/// Share of each payout which is locked
+	pub share: Perbill,
+	/// Number of blocks between vests
+	pub period: BlockNumber,
+	/// Number of vests
+	pub period_count: u32,
+}
+
+/// Step of a round mining allocation payout, with the last staker or metaverse handled
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum MiningPayoutStage<AccountId> {
+	/// Summing boosted estate stakes
+	SumStakes(Option<AccountId>),
+	/// Summing weighted land units of metaverses
+	SumLandPower(Option<MetaverseId>),
+	/// Paying estate stakers
+	PayStakers(Option<AccountId>),
+	/// Paying metaverse treasuries and backers
+	PayMetaverses(Option<MetaverseId>),
+}
+
+/// Round mining allocation payout spread over several blocks
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MiningPayout<AccountId, BlockNumber> {
+	/// Round being paid out
+	pub round: RoundIndex,
+	/// Mining allocation of the round
+	pub mining_allocation: Balance,
+	/// Share of the allocation paid to estate stakers
+	pub land_allocation: Balance,
+	/// Share of the allocation paid to metaverses
+	pub metaverse_allocation: Balance,
+	/// Vesting applied to the rewards of the round
+	pub vesting: Option<MiningRewardVesting<BlockNumber>>,
+	/// Mining resource minted during the round outside of the payout
+	pub round_minted: Balance,
+	/// Sum of the boosted estate stakes
+	pub total_stake: Balance,
+	/// Sum of the weighted land units
+	pub total_land_power: u128,
+	/// Amount paid to estate stakers so far
+	pub land_paid: Balance,
+	/// Amount paid to metaverses so far
+	pub metaverse_paid: Balance,
+	/// Next step of the payout
+	pub stage: MiningPayoutStage<AccountId>,
+}
 
 pub mod weights;
 
@@ -87,10 +152,13 @@ pub mod pallet {
 
 	use primitives::dex::Price;
 	use primitives::estate::Estate;
-	use primitives::staking::{MetaverseStakingTrait, RoundInfo};
-	use primitives::{FungibleTokenId, RoundIndex, TokenId, VestingSchedule};
+	use primitives::staking::{MetaverseBackingTrait, MetaverseStakingTrait, RoundInfo};
+	use primitives::{ClassId, FungibleTokenId, RoundIndex, TokenId, VestingSchedule};
 
-	use crate::mining::round_issuance_range;
+	use crate::mining::{
+		decayed_annual_rate, halving_epoch_at, is_halving_block, is_valid_round_length, next_halving_block_at,
+		round_issuance_range, rounds_per_year_of,
+	};
 
 	use super::*;
 
@@ -100,6 +168,8 @@ pub mod pallet {
 	pub struct Pallet<T>(PhantomData<T>);
 
 	pub(crate) type VestingScheduleOf<T> = VestingSchedule<<T as frame_system::Config>::BlockNumber, Balance>;
+	pub(crate) type MiningPayoutOf<T> =
+		MiningPayout<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber>;
 	pub type ScheduledItem<T> = (
 		<T as frame_system::Config>::AccountId,
 		<T as frame_system::Config>::BlockNumber,
@@ -111,7 +181,8 @@ pub mod pallet {
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
-		type MiningCurrency: MultiCurrencyExtended<Self::AccountId, CurrencyId = FungibleTokenId, Balance = Balance>;
+		type MiningCurrency: MultiCurrencyExtended<Self::AccountId, CurrencyId = FungibleTokenId, Balance = Balance>
+			+ MultiLockableCurrency<Self::AccountId, CurrencyId = FungibleTokenId>;
 		#[pallet::constant]
 		type BitMiningTreasury: Get<PalletId>;
 		type BitMiningResourceId: Get<FungibleTokenId>;
@@ -119,6 +190,41 @@ pub mod pallet {
 		type EstateHandler: Estate<Self::AccountId>;
 		type AdminOrigin: EnsureOrigin<Self::Origin, Success = Self::AccountId>;
 		type MetaverseStakingHandler: MetaverseStakingTrait<Balance>;
+		/// On-chain treasury receiving the mining allocation that could not be distributed
+		type TreasuryAccount: Get<Self::AccountId>;
+		/// Onboarding quest verification used by the starter mining resource faucet
+		type QuestHandler: OnboardingQuestTrait<Self::AccountId>;
+		/// Metaverse treasuries receiving the metaverse share of the mining allocation
+		type MetaverseInfoSource: MetaverseTrait<Self::AccountId>;
+		/// Accounts backing a metaverse, sharing its part of the metaverse allocation
+		type MetaverseBackingHandler: MetaverseBackingTrait<Self::AccountId, Balance>;
+		/// Share of the mining reward of a metaverse paid to its backers, the rest goes to the
+		/// metaverse treasury
+		#[pallet::constant]
+		type MetaverseBackerRewardShare: Get<Perbill>;
+		/// Share of the max round issuance minted when land participation is furthest from ideal
+		#[pallet::constant]
+		type MinimumIssuanceShare: Get<Perbill>;
+		/// Share of deployed land units at which round issuance reaches its max
+		#[pallet::constant]
+		type IdealLandParticipation: Get<Perbill>;
+		/// Number of blocks in a halving epoch, the minting rate halves at every epoch start. Zero
+		/// disables halving
+		#[pallet::constant]
+		type HalvingPeriod: Get<Self::BlockNumber>;
+		/// Origin allowed to pause and resume mining resource issuance
+		type IssuancePauseOrigin: EnsureOrigin<Self::Origin>;
+		/// Number of past rounds kept in the round issuance history
+		#[pallet::constant]
+		type RoundHistoryDepth: Get<u32>;
+		/// NFT ownership source of mining boost classes
+		type NFTHandler: NFTTrait<Self::AccountId, Balance, ClassId = ClassId, TokenId = TokenId>;
+		/// Maximum number of estate stakers and metaverses handled by the round payout in a block
+		#[pallet::constant]
+		type MaxMiningPayoutsPerBlock: Get<u32>;
+		/// Maximum number of NFT classes granting a mining boost
+		#[pallet::constant]
+		type MaxMiningBoostClasses: Get<u32>;
 		// Weight implementation for mining extrinsics
 		type WeightInfo: WeightInfo;
 	}
@@ -133,21 +239,113 @@ pub mod pallet {
 	/// Current round index and next round scheduled transition
 	pub type Round<T: Config> = StorageValue<_, RoundInfo<T::BlockNumber>, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn pending_round_length)]
+	/// Round length applied when the current round ends
+	pub type PendingRoundLength<T: Config> = StorageValue<_, u32, OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn mining_ratio_config)]
 	/// Mining resource issuance ratio config
 	pub type MiningConfig<T: Config> = StorageValue<_, MiningResourceRateInfo, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn annual_rate_decay)]
+	/// Share of the annual inflation rate removed over a year, applied proportionally every round
+	pub type AnnualRateDecay<T: Config> = StorageValue<_, Perbill, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn land_allocation_share)]
+	/// Share of the round mining allocation paid to estate stakers, the rest goes to metaverse treasuries
+	pub type LandAllocationShare<T: Config> = StorageValue<_, Perbill, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn metaverse_mining_weight)]
+	/// Mining power multiplier of metaverse land in percent, metaverses without entry count at 100
+	pub type MetaverseMiningWeights<T: Config> = StorageMap<_, Twox64Concat, MetaverseId, u32, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn mining_boost_classes)]
+	/// NFT classes with the land staking reward multiplier in percent granted to holders of a
+	/// token of the class
+	pub type MiningBoostClasses<T: Config> =
+		StorageValue<_, BoundedVec<(ClassId, u32), T::MaxMiningBoostClasses>, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn current_mining_resource_allocation)]
 	/// Mining resource issuance ratio config
 	pub type CurrentMiningResourceAllocation<T: Config> = StorageValue<_, MiningRange<Balance>, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn total_burned)]
+	/// Total mining resource permanently removed from supply by burns
+	pub type TotalBurned<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn round_minted)]
+	/// Mining resource minted by minting origins during the current round
+	pub type RoundMinted<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn mining_paused)]
 	/// Mining resource issuance ratio config
 	pub type MiningPaused<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn mining_issuance_paused)]
+	/// Round issuance is zero and allocation payouts are skipped while paused
+	pub type MiningIssuancePaused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn quest_reward)]
+	/// Starter mining resource reward of each onboarding quest
+	pub type QuestRewards<T: Config> = StorageMap<_, Twox64Concat, OnboardingQuest, Balance, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn starter_faucet_cap)]
+	/// Maximum mining resource the starter faucet can pay out in total
+	pub type StarterFaucetCap<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn starter_faucet_distributed)]
+	/// Total mining resource paid out by the starter faucet
+	pub type StarterFaucetDistributed<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn claimed_quest_reward)]
+	/// Onboarding quest rewards already claimed by each account
+	pub type ClaimedQuestRewards<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, OnboardingQuest, (), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn round_reward_vesting)]
+	/// Vesting applied to the mined rewards paid out at the end of each round
+	pub type RoundRewardVesting<T: Config> =
+		StorageMap<_, Twox64Concat, RoundIndex, MiningRewardVesting<T::BlockNumber>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn mining_vesting)]
+	/// Vesting schedules of locked mined rewards of each account
+	pub type MiningVesting<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Vec<VestingScheduleOf<T>>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn round_issuance_summary)]
+	/// Issuance summary of the most recent rounds, rounds older than the history depth are pruned
+	pub type RoundIssuanceHistory<T: Config> =
+		StorageMap<_, Twox64Concat, RoundIndex, RoundIssuanceSummary<Balance>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_mining_payout)]
+	/// Round mining allocation payout still in progress
+	pub type PendingMiningPayout<T: Config> = StorageValue<_, MiningPayoutOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn carried_mining_allocation)]
+	/// Mining allocation of rounds ended while issuance was paused, paid out with the first round
+	/// ending after issuance resumes
+	pub type CarriedMiningAllocation<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -178,6 +376,55 @@ pub mod pallet {
 		MiningRoundPaused(T::BlockNumber, RoundIndex),
 		/// Mining round rotation is unpaused
 		MiningRoundUnPaused(T::BlockNumber, RoundIndex),
+		/// Onboarding quest reward updated [quest, amount]
+		QuestRewardSet(OnboardingQuest, Balance),
+		/// Starter faucet cap updated [cap]
+		StarterFaucetCapSet(Balance),
+		/// Onboarding quest reward claimed [who, quest, amount]
+		QuestRewardClaimed(T::AccountId, OnboardingQuest, Balance),
+		/// Annual inflation rate decay updated [decay]
+		AnnualRateDecaySet(Perbill),
+		/// Annual inflation rate decayed at new round [round, rate]
+		MiningRateDecayed(RoundIndex, Perbill),
+		/// Land allocation share updated [share]
+		LandAllocationShareSet(Perbill),
+		/// Estate staker paid from round land allocation [round, who, amount]
+		EstateStakerRewarded(RoundIndex, T::AccountId, Balance),
+		/// Metaverse treasury paid from round metaverse allocation [round, metaverse_id, amount]
+		MetaverseTreasuryRewarded(RoundIndex, MetaverseId, Balance),
+		/// Metaverse backer paid from round metaverse allocation [round, metaverse_id, who, amount]
+		MetaverseBackerRewarded(RoundIndex, MetaverseId, T::AccountId, Balance),
+		/// Round mining allocation paid out [round, land_allocation, metaverse_allocation]
+		MiningAllocationDistributed(RoundIndex, Balance, Balance),
+		/// Round length change scheduled for the next round [length]
+		RoundLengthScheduled(u32),
+		/// Round length changed at round boundary [round, old_length, new_length, rounds_per_year]
+		RoundLengthTransitioned(RoundIndex, u32, u32, u32),
+		/// Mining power multiplier of metaverse land updated [metaverse_id, weight_percent]
+		MetaverseMiningWeightSet(MetaverseId, u32),
+		/// Mining resource issuance paused [block_number]
+		MiningIssuancePaused(T::BlockNumber),
+		/// Mining resource issuance resumed [block_number]
+		MiningIssuanceResumed(T::BlockNumber),
+		/// Minting rate halved at the start of new halving epoch [epoch, next_halving_block]
+		HalvingEpochStarted(u32, T::BlockNumber),
+		/// Vesting of mined rewards of a round updated [round, vesting]
+		RoundRewardVestingSet(RoundIndex, Option<MiningRewardVesting<T::BlockNumber>>),
+		/// Part of a mined reward locked under vesting schedule [who, schedule]
+		MiningRewardVested(T::AccountId, VestingScheduleOf<T>),
+		/// Vested mined rewards claimed [who, still_locked_amount]
+		MiningVestingClaimed(T::AccountId, Balance),
+		/// Mining rounds fast-forwarded [rounds, current_round]
+		MiningRoundsForceAdvanced(u32, RoundIndex),
+		/// Land staking reward multiplier of NFT class holders updated [class_id, boost_percent]
+		MiningBoostClassSet(ClassId, Option<u32>),
+		/// Mining allocation left undistributed minted to the treasury [round, amount]
+		UnallocatedIssuanceToTreasury(RoundIndex, Balance),
+		/// Mining resource burned by holder [who, amount, total_burned]
+		MiningResourceBurnedBy(T::AccountId, Balance, Balance),
+		/// Mining allocation of a round ended while issuance was paused carried over [round,
+		/// carried_allocation]
+		MiningAllocationCarriedOver(RoundIndex, Balance),
 	}
 
 	#[pallet::error]
@@ -204,6 +451,32 @@ pub mod pallet {
 		MiningRoundAlreadyPaused,
 		/// Mining round is not paused
 		MiningRoundIsNotPaused,
+		/// Onboarding quest has no reward
+		QuestRewardNotSet,
+		/// Onboarding quest is not completed
+		QuestNotCompleted,
+		/// Onboarding quest reward already claimed
+		QuestRewardAlreadyClaimed,
+		/// Starter faucet cap exceeded
+		StarterFaucetCapExceeded,
+		/// Round length must be non-zero and fit within a year
+		InvalidRoundLength,
+		/// Mining resource issuance already paused
+		MiningIssuanceAlreadyPaused,
+		/// Mining resource issuance is not paused
+		MiningIssuanceIsNotPaused,
+		/// Vesting period and number of vests must be non-zero
+		InvalidRewardVesting,
+		/// Mined rewards of the round are already paid out
+		RoundAlreadyPaidOut,
+		/// Staking and mining reward shares must add up to the whole round issuance
+		InvalidMiningResourceRateInfo,
+		/// Round fast-forward is only available in test and benchmark builds
+		RoundFastForwardDisabled,
+		/// Mining boost multiplier must be at least 100 percent
+		InvalidMiningBoost,
+		/// Maximum number of mining boost classes reached
+		TooManyMiningBoostClasses,
 	}
 
 	#[pallet::call]
@@ -269,10 +542,11 @@ pub mod pallet {
 		pub fn update_round_length(origin: OriginFor<T>, length: T::BlockNumber) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
 
-			let mut current_round = Round::<T>::get();
-			ensure!(length >= Zero::zero(), Error::<T>::AmountZero);
+			let length_in_blocks = length.saturated_into::<u32>();
+			ensure!(is_valid_round_length(length_in_blocks), Error::<T>::InvalidRoundLength);
 
-			current_round.length = length.saturated_into::<u32>();
+			let mut current_round = Round::<T>::get();
+			current_round.length = length_in_blocks;
 
 			Round::<T>::put(current_round);
 
@@ -291,6 +565,7 @@ pub mod pallet {
 			let round = <Round<T>>::get();
 			let current_block = <system::Pallet<T>>::block_number();
 			ensure!(!round.should_update(current_block), Error::<T>::RoundUpdateIsOnProgress);
+			ensure!(config.is_valid(), Error::<T>::InvalidMiningResourceRateInfo);
 
 			MiningConfig::<T>::put(config.clone());
 
@@ -328,25 +603,247 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Pause mining resource issuance, new rounds issue nothing and pay out no allocation until
+		/// resumed
+		#[pallet::weight(< T as pallet::Config >::WeightInfo::pause_mining_issuance())]
+		pub fn pause_mining_issuance(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			T::IssuancePauseOrigin::ensure_origin(origin)?;
+			ensure!(!Self::mining_issuance_paused(), Error::<T>::MiningIssuanceAlreadyPaused);
+
+			MiningIssuancePaused::<T>::put(true);
+			Self::deposit_event(Event::<T>::MiningIssuancePaused(<system::Pallet<T>>::block_number()));
+
+			Ok(().into())
+		}
+
+		/// Resume mining resource issuance from the next round
+		#[pallet::weight(< T as pallet::Config >::WeightInfo::resume_mining_issuance())]
+		pub fn resume_mining_issuance(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			T::IssuancePauseOrigin::ensure_origin(origin)?;
+			ensure!(Self::mining_issuance_paused(), Error::<T>::MiningIssuanceIsNotPaused);
+
+			MiningIssuancePaused::<T>::kill();
+			Self::deposit_event(Event::<T>::MiningIssuanceResumed(<system::Pallet<T>>::block_number()));
+
+			Ok(().into())
+		}
+
+		/// Set starter mining resource reward of onboarding quest, zero amount removes the reward
+		#[pallet::weight(< T as pallet::Config >::WeightInfo::set_quest_reward())]
+		pub fn set_quest_reward(
+			origin: OriginFor<T>,
+			quest: OnboardingQuest,
+			amount: Balance,
+		) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			if amount.is_zero() {
+				QuestRewards::<T>::remove(quest);
+			} else {
+				QuestRewards::<T>::insert(quest, amount);
+			}
+
+			Self::deposit_event(Event::<T>::QuestRewardSet(quest, amount));
+
+			Ok(().into())
+		}
+
+		/// Set maximum total mining resource the starter faucet can pay out
+		#[pallet::weight(< T as pallet::Config >::WeightInfo::set_starter_faucet_cap())]
+		pub fn set_starter_faucet_cap(origin: OriginFor<T>, cap: Balance) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			StarterFaucetCap::<T>::put(cap);
+
+			Self::deposit_event(Event::<T>::StarterFaucetCapSet(cap));
+
+			Ok(().into())
+		}
+
+		/// Set share of the annual inflation rate removed over a year, zero keeps the rate flat
+		#[pallet::weight(< T as pallet::Config >::WeightInfo::set_annual_rate_decay())]
+		pub fn set_annual_rate_decay(origin: OriginFor<T>, decay: Perbill) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			AnnualRateDecay::<T>::put(decay);
+
+			Self::deposit_event(Event::<T>::AnnualRateDecaySet(decay));
+
+			Ok(().into())
+		}
+
+		/// Set share of the round mining allocation paid to estate stakers
+		#[pallet::weight(< T as pallet::Config >::WeightInfo::set_land_allocation_share())]
+		pub fn set_land_allocation_share(origin: OriginFor<T>, share: Perbill) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			LandAllocationShare::<T>::put(share);
+
+			Self::deposit_event(Event::<T>::LandAllocationShareSet(share));
+
+			Ok(().into())
+		}
+
+		/// Set mining power multiplier in percent of the land in a metaverse, 100 resets it to
+		/// the default weight
+		#[pallet::weight(< T as pallet::Config >::WeightInfo::set_metaverse_mining_weight())]
+		pub fn set_metaverse_mining_weight(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			weight_percent: u32,
+		) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			if weight_percent == DEFAULT_MINING_WEIGHT_PERCENT {
+				MetaverseMiningWeights::<T>::remove(metaverse_id);
+			} else {
+				MetaverseMiningWeights::<T>::insert(metaverse_id, weight_percent);
+			}
+
+			Self::deposit_event(Event::<T>::MetaverseMiningWeightSet(metaverse_id, weight_percent));
+
+			Ok(().into())
+		}
+
+		/// Set the land staking reward multiplier in percent granted to holders of a token of the
+		/// NFT class, `None` removes the boost
+		#[pallet::weight(< T as pallet::Config >::WeightInfo::set_mining_boost_class())]
+		pub fn set_mining_boost_class(
+			origin: OriginFor<T>,
+			class_id: ClassId,
+			boost_percent: Option<u32>,
+		) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			MiningBoostClasses::<T>::try_mutate(|boost_classes| -> DispatchResult {
+				boost_classes.retain(|(boosted_class_id, _)| *boosted_class_id != class_id);
+				if let Some(boost) = boost_percent {
+					ensure!(boost >= DEFAULT_MINING_WEIGHT_PERCENT, Error::<T>::InvalidMiningBoost);
+					boost_classes
+						.try_push((class_id, boost))
+						.map_err(|_| Error::<T>::TooManyMiningBoostClasses)?;
+				}
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::MiningBoostClassSet(class_id, boost_percent));
+
+			Ok(().into())
+		}
+
+		/// Schedule a new round length, applied when the current round ends
+		#[pallet::weight(< T as pallet::Config >::WeightInfo::schedule_round_length())]
+		pub fn schedule_round_length(origin: OriginFor<T>, length: u32) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(is_valid_round_length(length), Error::<T>::InvalidRoundLength);
+
+			PendingRoundLength::<T>::put(length);
+
+			Self::deposit_event(Event::<T>::RoundLengthScheduled(length));
+
+			Ok(().into())
+		}
+
+		/// Burn mining resource of the caller, permanently reducing the supply
+		#[pallet::weight(< T as pallet::Config >::WeightInfo::burn_mining_resource())]
+		pub fn burn_mining_resource(origin: OriginFor<T>, amount: Balance) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+
+			T::MiningCurrency::withdraw(Self::bit_mining_resource_currency_id(), &who, amount)?;
+			let total_burned = Self::record_burned(amount);
+
+			Self::deposit_event(Event::<T>::MiningResourceBurnedBy(who, amount, total_burned));
+
+			Ok(().into())
+		}
+
+		/// Lock a share of the mined rewards paid out at the end of a round under a linear vesting
+		/// schedule, none pays them out fully liquid
+		#[pallet::weight(< T as pallet::Config >::WeightInfo::set_round_reward_vesting())]
+		pub fn set_round_reward_vesting(
+			origin: OriginFor<T>,
+			round: RoundIndex,
+			vesting: Option<MiningRewardVesting<T::BlockNumber>>,
+		) -> DispatchResultWithPostInfo {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(round >= Self::round().current, Error::<T>::RoundAlreadyPaidOut);
+
+			match vesting {
+				Some(info) => {
+					ensure!(
+						!info.period.is_zero() && !info.period_count.is_zero(),
+						Error::<T>::InvalidRewardVesting
+					);
+					RoundRewardVesting::<T>::insert(round, info);
+				}
+				None => RoundRewardVesting::<T>::remove(round),
+			}
+
+			Self::deposit_event(Event::<T>::RoundRewardVestingSet(round, vesting));
+
+			Ok(().into())
+		}
+
+		/// Unlock mined rewards of the caller which finished vesting
+		#[pallet::weight(< T as pallet::Config >::WeightInfo::claim_mining_vesting())]
+		pub fn claim_mining_vesting(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let locked = Self::update_mining_vesting(&who, Self::mining_vesting(&who));
+
+			Self::deposit_event(Event::<T>::MiningVestingClaimed(who, locked));
+
+			Ok(().into())
+		}
+
+		/// Claim starter mining resource from mining treasury for completed onboarding quest
+		#[pallet::weight(< T as pallet::Config >::WeightInfo::claim_quest_reward())]
+		pub fn claim_quest_reward(origin: OriginFor<T>, quest: OnboardingQuest) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::do_claim_quest_reward(who, quest)?;
+
+			Ok(().into())
+		}
+
+		/// Roll the mining round over `rounds` times at the current block, paying out every
+		/// skipped round as if its boundary was reached. Only enabled with the `round-fast-forward`
+		/// feature so test and benchmark runtimes don't need to author the empty blocks.
+		#[pallet::weight(100_000u64.saturating_mul(*rounds as u64))]
+		pub fn force_advance_round(origin: OriginFor<T>, rounds: u32) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ensure!(ROUND_FAST_FORWARD_ENABLED, Error::<T>::RoundFastForwardDisabled);
+			ensure!(!rounds.is_zero(), Error::<T>::AmountZero);
+
+			Self::do_advance_rounds(rounds);
+
+			Self::deposit_event(Event::<T>::MiningRoundsForceAdvanced(rounds, Self::round().current));
+
+			Ok(().into())
+		}
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
 		fn on_initialize(n: T::BlockNumber) -> Weight {
-			let mut round = <Round<T>>::get();
-			if round.should_update(n) {
-				// mutate round
-				let allocation_range = round_issuance_range::<T>(<MiningConfig<T>>::get());
-				T::MetaverseStakingHandler::update_staking_reward(round.current, allocation_range.staking_allocation);
-
-				round.update(n);
-				Round::<T>::put(round);
-				CurrentMiningResourceAllocation::<T>::put(allocation_range);
-				Self::deposit_event(Event::NewMiningRound(round.current, allocation_range));
-				0
-			} else {
-				0
+			if is_halving_block::<T>(n) {
+				if let Some(next_halving_block) = next_halving_block_at::<T>(n) {
+					Self::deposit_event(Event::HalvingEpochStarted(halving_epoch_at::<T>(n), next_halving_block));
+				}
 			}
+
+			// Continue the payout of the previous round, the round only rolls over once it is done
+			let payout_limit = T::MaxMiningPayoutsPerBlock::get();
+			let (mut weight, processed) = Self::process_mining_payout(payout_limit);
+
+			let round = <Round<T>>::get();
+			weight = weight.saturating_add(T::DbWeight::get().reads(1));
+			if round.should_update(n) && !PendingMiningPayout::<T>::exists() {
+				weight = weight.saturating_add(Self::start_new_round(n, round, payout_limit.saturating_sub(processed)));
+			}
+			weight
 		}
 	}
 }
@@ -365,6 +862,16 @@ impl<T: Config> Pallet<T> {
 		minting_origin == Some(())
 	}
 
+	/// Halving epoch of the current block
+	pub fn current_halving_epoch() -> u32 {
+		mining::halving_epoch_at::<T>(<system::Pallet<T>>::block_number())
+	}
+
+	/// Block at which the minting rate halves next, none when halving is disabled
+	pub fn next_halving_block() -> Option<T::BlockNumber> {
+		mining::next_halving_block_at::<T>(<system::Pallet<T>>::block_number())
+	}
+
 	pub fn ensure_admin(o: T::Origin) -> DispatchResult {
 		T::AdminOrigin::try_origin(o).map(|_| ()).or_else(ensure_root)?;
 		Ok(())
@@ -380,6 +887,7 @@ impl<T: Config> Pallet<T> {
 		let mining_treasury = Self::bit_mining_resource_account_id();
 		//Deposit Bit mining to mining treasury
 		T::MiningCurrency::deposit(Self::bit_mining_resource_currency_id(), &to, amount)?;
+		RoundMinted::<T>::mutate(|minted| *minted = minted.saturating_add(amount));
 
 		Self::deposit_event(Event::<T>::MiningResourceMintedTo(to, amount));
 
@@ -399,12 +907,469 @@ impl<T: Config> Pallet<T> {
 		);
 		//Deposit Bit mining to mining treasury
 		T::MiningCurrency::slash(Self::bit_mining_resource_currency_id(), &from, amount);
+		Self::record_burned(amount);
 
 		Self::deposit_event(Event::<T>::MiningResourceBurnFrom(from, amount));
 
 		Ok(())
 	}
 
+	/// Start paying out the round mining allocation to estate stakers by stake and metaverse
+	/// treasuries by land count weighted by the metaverse mining power multiplier. The payout
+	/// continues over the following blocks when it does not fit in `limit` recipients, returns the
+	/// weight used and the number of recipients processed
+	fn begin_mining_payout(round: RoundIndex, allocation: MiningRange<Balance>, limit: u32) -> (Weight, u32) {
+		let mining_allocation = allocation
+			.mining_allocation
+			.saturating_add(Self::carried_mining_allocation());
+		if Self::mining_issuance_paused() {
+			if !mining_allocation.is_zero() {
+				CarriedMiningAllocation::<T>::put(mining_allocation);
+				Self::deposit_event(Event::MiningAllocationCarriedOver(round, mining_allocation));
+			}
+			Self::record_round_issuance(round, Self::round_minted(), Zero::zero(), Zero::zero(), Zero::zero());
+			return (T::DbWeight::get().reads_writes(4, 3), 0);
+		}
+		if mining_allocation.is_zero() {
+			Self::record_round_issuance(round, Self::round_minted(), Zero::zero(), Zero::zero(), Zero::zero());
+			return (T::DbWeight::get().reads_writes(4, 2), 0);
+		}
+		CarriedMiningAllocation::<T>::kill();
+		let land_allocation = Self::land_allocation_share() * mining_allocation;
+		PendingMiningPayout::<T>::put(MiningPayout {
+			round,
+			mining_allocation,
+			land_allocation,
+			metaverse_allocation: mining_allocation.saturating_sub(land_allocation),
+			vesting: RoundRewardVesting::<T>::take(round),
+			round_minted: Self::round_minted(),
+			total_stake: Zero::zero(),
+			total_land_power: 0,
+			land_paid: Zero::zero(),
+			metaverse_paid: Zero::zero(),
+			stage: MiningPayoutStage::SumStakes(None),
+		});
+
+		let (weight, processed) = Self::process_mining_payout(limit);
+		(weight.saturating_add(T::DbWeight::get().reads_writes(5, 3)), processed)
+	}
+
+	/// Continue the pending mining payout with at most `limit` stakers or metaverses. Totals are
+	/// summed in a first pass and rewards paid in a second one, rewards are capped at what is left
+	/// of the allocation in case stakes changed in between. Returns the weight used and the number
+	/// of recipients processed
+	fn process_mining_payout(limit: u32) -> (Weight, u32) {
+		let mut payout = match PendingMiningPayout::<T>::get() {
+			Some(payout) => payout,
+			None => return (T::DbWeight::get().reads(1), 0),
+		};
+		let db_weight = T::DbWeight::get();
+		let boost_classes = MiningBoostClasses::<T>::get();
+		let boost_reads = boost_classes.len() as Weight;
+		let currency_id = Self::bit_mining_resource_currency_id();
+		let mut weight = db_weight.reads_writes(2, 1);
+		let mut processed: u32 = 0;
+
+		loop {
+			let remaining = limit.saturating_sub(processed);
+			if remaining.is_zero() {
+				break;
+			}
+			match payout.stage.clone() {
+				MiningPayoutStage::SumStakes(cursor) => {
+					let stakes = T::EstateHandler::get_estate_stakes(cursor, remaining);
+					let count = stakes.len() as u32;
+					for (staker, stake) in stakes.iter() {
+						payout.total_stake =
+							payout
+								.total_stake
+								.saturating_add(Self::boosted_stake(&boost_classes, staker, *stake));
+					}
+					weight = weight.saturating_add(db_weight.reads((count as Weight).saturating_mul(1 + boost_reads)));
+					processed = processed.saturating_add(count);
+					payout.stage = match stakes.last() {
+						Some((staker, _)) if count == remaining => MiningPayoutStage::SumStakes(Some(staker.clone())),
+						_ => MiningPayoutStage::SumLandPower(None),
+					};
+				}
+				MiningPayoutStage::SumLandPower(cursor) => {
+					let land_units = T::EstateHandler::get_metaverse_land_units(cursor, remaining);
+					let count = land_units.len() as u32;
+					for (metaverse_id, land_unit_count) in land_units.iter() {
+						payout.total_land_power = payout
+							.total_land_power
+							.saturating_add(Self::metaverse_land_power(*metaverse_id, *land_unit_count));
+					}
+					weight = weight.saturating_add(db_weight.reads((count as Weight).saturating_mul(4)));
+					processed = processed.saturating_add(count);
+					payout.stage = match land_units.last() {
+						Some((metaverse_id, _)) if count == remaining => {
+							MiningPayoutStage::SumLandPower(Some(*metaverse_id))
+						}
+						_ => MiningPayoutStage::PayStakers(None),
+					};
+				}
+				MiningPayoutStage::PayStakers(cursor) => {
+					let stakes = if payout.total_stake.is_zero() {
+						Vec::new()
+					} else {
+						T::EstateHandler::get_estate_stakes(cursor, remaining)
+					};
+					let count = stakes.len() as u32;
+					for (staker, stake) in stakes.iter() {
+						let stake = Self::boosted_stake(&boost_classes, staker, *stake);
+						let reward = (Perbill::from_rational(stake, payout.total_stake) * payout.land_allocation)
+							.min(payout.land_allocation.saturating_sub(payout.land_paid));
+						if !reward.is_zero() && T::MiningCurrency::deposit(currency_id, staker, reward).is_ok() {
+							payout.land_paid = payout.land_paid.saturating_add(reward);
+							Self::vest_mining_reward(staker, reward, payout.vesting);
+							Self::deposit_event(Event::EstateStakerRewarded(payout.round, staker.clone(), reward));
+							weight = weight.saturating_add(Self::mining_reward_weight());
+						}
+					}
+					weight = weight.saturating_add(db_weight.reads((count as Weight).saturating_mul(1 + boost_reads)));
+					processed = processed.saturating_add(count);
+					payout.stage = match stakes.last() {
+						Some((staker, _)) if count == remaining => MiningPayoutStage::PayStakers(Some(staker.clone())),
+						_ => MiningPayoutStage::PayMetaverses(None),
+					};
+				}
+				MiningPayoutStage::PayMetaverses(cursor) => {
+					let land_units = if payout.total_land_power == 0 {
+						Vec::new()
+					} else {
+						T::EstateHandler::get_metaverse_land_units(cursor, remaining)
+					};
+					let count = land_units.len() as u32;
+					for (metaverse_id, land_unit_count) in land_units.iter() {
+						let power = Self::metaverse_land_power(*metaverse_id, *land_unit_count);
+						if power == 0 {
+							continue;
+						}
+						let reward = (Perbill::from_rational(power, payout.total_land_power)
+							* payout.metaverse_allocation)
+							.min(payout.metaverse_allocation.saturating_sub(payout.metaverse_paid));
+						let (backers_paid, backers_weight) =
+							Self::reward_metaverse_backers(payout.round, *metaverse_id, reward, payout.vesting);
+						payout.metaverse_paid = payout.metaverse_paid.saturating_add(backers_paid);
+						weight = weight.saturating_add(backers_weight);
+
+						let treasury_reward = reward.saturating_sub(backers_paid);
+						let treasury = T::MetaverseInfoSource::get_metaverse_treasury(*metaverse_id);
+						if !treasury_reward.is_zero()
+							&& T::MiningCurrency::deposit(currency_id, &treasury, treasury_reward).is_ok()
+						{
+							payout.metaverse_paid = payout.metaverse_paid.saturating_add(treasury_reward);
+							Self::vest_mining_reward(&treasury, treasury_reward, payout.vesting);
+							Self::deposit_event(Event::MetaverseTreasuryRewarded(
+								payout.round,
+								*metaverse_id,
+								treasury_reward,
+							));
+							weight = weight.saturating_add(Self::mining_reward_weight());
+						}
+					}
+					weight = weight.saturating_add(db_weight.reads((count as Weight).saturating_mul(5)));
+					processed = processed.saturating_add(count);
+					match land_units.last() {
+						Some((metaverse_id, _)) if count == remaining => {
+							payout.stage = MiningPayoutStage::PayMetaverses(Some(*metaverse_id));
+						}
+						_ => {
+							Self::finish_mining_payout(payout);
+							return (
+								weight
+									.saturating_add(Self::mining_reward_weight())
+									.saturating_add(db_weight.reads_writes(2, 2)),
+								processed,
+							);
+						}
+					}
+				}
+			}
+		}
+
+		PendingMiningPayout::<T>::put(payout);
+		(weight, processed)
+	}
+
+	/// Mint the allocation left of a completed payout to the treasury and record the round issuance
+	fn finish_mining_payout(payout: MiningPayoutOf<T>) {
+		PendingMiningPayout::<T>::kill();
+		Self::deposit_event(Event::MiningAllocationDistributed(
+			payout.round,
+			payout.land_paid,
+			payout.metaverse_paid,
+		));
+
+		// Allocation without recipients, rounding dust and failed deposits go to the treasury
+		let mut treasury_paid: Balance = Zero::zero();
+		let unallocated = payout
+			.mining_allocation
+			.saturating_sub(payout.land_paid)
+			.saturating_sub(payout.metaverse_paid);
+		if !unallocated.is_zero()
+			&& T::MiningCurrency::deposit(
+				Self::bit_mining_resource_currency_id(),
+				&T::TreasuryAccount::get(),
+				unallocated,
+			)
+			.is_ok()
+		{
+			treasury_paid = unallocated;
+			Self::deposit_event(Event::UnallocatedIssuanceToTreasury(payout.round, unallocated));
+		}
+
+		Self::record_round_issuance(
+			payout.round,
+			payout.round_minted,
+			payout.land_paid,
+			payout.metaverse_paid,
+			treasury_paid,
+		);
+	}
+
+	/// Weight of minting a reward and locking its vesting share
+	fn mining_reward_weight() -> Weight {
+		T::DbWeight::get().reads_writes(3, 4)
+	}
+
+	/// Estate stake weighted by the mining boost of NFTs held by the staker
+	fn boosted_stake(boost_classes: &[(ClassId, u32)], staker: &T::AccountId, stake: Balance) -> Balance {
+		let boost = boost_classes
+			.iter()
+			.filter(|(class_id, _)| T::NFTHandler::holds_class_token(staker, class_id))
+			.map(|(_, boost)| *boost)
+			.max()
+			.unwrap_or(DEFAULT_MINING_WEIGHT_PERCENT);
+		stake.saturating_mul(boost as Balance) / DEFAULT_MINING_WEIGHT_PERCENT as Balance
+	}
+
+	/// Land units weighted by the mining power multiplier of their metaverse, land of a metaverse
+	/// in sunset or retired does not mine
+	fn metaverse_land_power(metaverse_id: MetaverseId, land_unit_count: u64) -> u128 {
+		if T::MetaverseInfoSource::is_metaverse_sunset(metaverse_id) {
+			return 0;
+		}
+		let weight = Self::metaverse_mining_weight(metaverse_id).unwrap_or(DEFAULT_MINING_WEIGHT_PERCENT);
+		(land_unit_count as u128).saturating_mul(weight as u128)
+	}
+
+	/// Pay the backer share of the mining reward of a metaverse to its backers by backed stake,
+	/// returning the amount paid and the weight used. The metaverse pallet caps the backers of a
+	/// metaverse at `MaxBackersPerMetaverse`
+	fn reward_metaverse_backers(
+		round: RoundIndex,
+		metaverse_id: MetaverseId,
+		reward: Balance,
+		vesting: Option<MiningRewardVesting<T::BlockNumber>>,
+	) -> (Balance, Weight) {
+		let backers = T::MetaverseBackingHandler::get_metaverse_backers(metaverse_id);
+		let mut weight = T::DbWeight::get().reads(backers.len() as Weight);
+		let total_backing = backers
+			.iter()
+			.fold(Balance::zero(), |total, (_, backing)| total.saturating_add(*backing));
+		if total_backing.is_zero() {
+			return (Zero::zero(), weight);
+		}
+
+		let backers_reward = T::MetaverseBackerRewardShare::get() * reward;
+		let currency_id = Self::bit_mining_resource_currency_id();
+		let mut backers_paid: Balance = Zero::zero();
+		for (backer, backing) in backers {
+			let backer_reward = Perbill::from_rational(backing, total_backing) * backers_reward;
+			if !backer_reward.is_zero() && T::MiningCurrency::deposit(currency_id, &backer, backer_reward).is_ok() {
+				backers_paid = backers_paid.saturating_add(backer_reward);
+				Self::vest_mining_reward(&backer, backer_reward, vesting);
+				Self::deposit_event(Event::MetaverseBackerRewarded(
+					round,
+					metaverse_id,
+					backer,
+					backer_reward,
+				));
+				weight = weight.saturating_add(Self::mining_reward_weight());
+			}
+		}
+		(backers_paid, weight)
+	}
+
+	/// Highest land staking reward multiplier in percent of the NFT classes held by the staker
+	pub fn staker_mining_boost(who: &T::AccountId) -> u32 {
+		MiningBoostClasses::<T>::get()
+			.iter()
+			.filter(|(class_id, _)| T::NFTHandler::holds_class_token(who, class_id))
+			.map(|(_, boost)| *boost)
+			.max()
+			.unwrap_or(DEFAULT_MINING_WEIGHT_PERCENT)
+	}
+
+	/// Land staking reward multiplier in percent granted to holders of a token of the NFT class
+	pub fn mining_boost_of_class(class_id: ClassId) -> Option<u32> {
+		MiningBoostClasses::<T>::get()
+			.iter()
+			.find(|(boosted_class_id, _)| *boosted_class_id == class_id)
+			.map(|(_, boost)| *boost)
+	}
+
+	/// Start paying out the ending round with at most `payout_limit` recipients and roll the
+	/// mining round over at block `n`, returns the weight used
+	fn start_new_round(n: T::BlockNumber, mut round: RoundInfo<T::BlockNumber>, payout_limit: u32) -> Weight {
+		// Pay out the mining allocation of the ending round
+		let (payout_weight, _) =
+			Self::begin_mining_payout(round.current, Self::current_mining_resource_allocation(), payout_limit);
+
+		// Apply scheduled round length so the new round issuance uses it
+		let old_length = round.length;
+		let pending_length = PendingRoundLength::<T>::take();
+		if let Some(length) = pending_length {
+			round.length = length;
+			Round::<T>::put(round);
+		}
+
+		// mutate round
+		let mut config = <MiningConfig<T>>::get();
+		let allocation_range = round_issuance_range::<T>(config.clone(), halving_epoch_at::<T>(n));
+		T::MetaverseStakingHandler::update_staking_reward(round.current, allocation_range.staking_allocation);
+
+		round.update(n);
+		Round::<T>::put(round);
+		CurrentMiningResourceAllocation::<T>::put(allocation_range);
+		RoundMinted::<T>::kill();
+		Self::deposit_event(Event::NewMiningRound(round.current, allocation_range));
+		if let Some(length) = pending_length {
+			Self::deposit_event(Event::RoundLengthTransitioned(
+				round.current,
+				old_length,
+				length,
+				rounds_per_year_of(length),
+			));
+		}
+
+		// Decay the annual rate used for the next round issuance
+		let decayed_rate = decayed_annual_rate::<T>(config.rate, AnnualRateDecay::<T>::get());
+		if decayed_rate != config.rate {
+			config.set_rate(decayed_rate);
+			MiningConfig::<T>::put(config);
+			Self::deposit_event(Event::MiningRateDecayed(round.current, decayed_rate));
+		}
+
+		payout_weight.saturating_add(T::DbWeight::get().reads_writes(8, 6))
+	}
+
+	/// Roll the mining round over `rounds` times at the current block, paying out each round in
+	/// full
+	pub(crate) fn do_advance_rounds(rounds: u32) {
+		let now = <system::Pallet<T>>::block_number();
+		for _ in 0..rounds {
+			Self::process_mining_payout(u32::MAX);
+			Self::start_new_round(now, <Round<T>>::get(), u32::MAX);
+		}
+	}
+
+	/// Store issuance summary of the ending round and prune the round falling out of the history
+	fn record_round_issuance(
+		round: RoundIndex,
+		round_minted: Balance,
+		land_paid: Balance,
+		metaverse_paid: Balance,
+		treasury_paid: Balance,
+	) {
+		let summary = RoundIssuanceSummary {
+			total_minted: round_minted
+				.saturating_add(land_paid)
+				.saturating_add(metaverse_paid)
+				.saturating_add(treasury_paid),
+			land_allocation: land_paid,
+			metaverse_allocation: metaverse_paid,
+			land_units: T::EstateHandler::get_total_land_units(),
+		};
+		RoundIssuanceHistory::<T>::insert(round, summary);
+
+		if let Some(pruned_round) = round.checked_sub(T::RoundHistoryDepth::get()) {
+			RoundIssuanceHistory::<T>::remove(pruned_round);
+		}
+	}
+
+	/// Issuance range the next round boundary would compute from the current land circulation,
+	/// mining config and scheduled round length
+	pub fn estimate_next_round_issuance() -> MiningRange<Balance> {
+		let round = Self::round();
+		let next_round_start = round.first.saturating_add(round.length.into());
+		let blocks_per_round = Self::pending_round_length().unwrap_or(round.length);
+		mining::round_issuance_range_of::<T>(
+			Self::mining_ratio_config(),
+			mining::halving_epoch_at::<T>(next_round_start),
+			blocks_per_round,
+		)
+	}
+
+	/// Issuance summaries of the rounds kept in the history, most recent first
+	pub fn round_issuance_history() -> Vec<(RoundIndex, RoundIssuanceSummary<Balance>)> {
+		let current_round = Self::round().current;
+		(1..=T::RoundHistoryDepth::get())
+			.filter_map(|ago| current_round.checked_sub(ago))
+			.filter_map(|round| Self::round_issuance_summary(round).map(|summary| (round, summary)))
+			.collect()
+	}
+
+	/// Lock the vesting share of a paid out reward. The reward stays liquid when the account has
+	/// too many schedules.
+	fn vest_mining_reward(who: &T::AccountId, reward: Balance, vesting: Option<MiningRewardVesting<T::BlockNumber>>) {
+		let vesting = match vesting {
+			Some(vesting) => vesting,
+			None => return,
+		};
+		let per_period = (vesting.share * reward) / vesting.period_count as Balance;
+		if per_period.is_zero() {
+			return;
+		}
+
+		let now = <system::Pallet<T>>::block_number();
+		let mut schedules = Self::mining_vesting(who);
+		schedules.retain(|schedule| !schedule.locked_amount(now).is_zero());
+		if schedules.len() >= MAX_VESTINGS {
+			return;
+		}
+
+		let schedule = VestingSchedule {
+			token: Self::bit_mining_resource_currency_id(),
+			start: now,
+			period: vesting.period,
+			period_count: vesting.period_count,
+			per_period,
+		};
+		schedules.push(schedule.clone());
+		Self::update_mining_vesting(who, schedules);
+
+		Self::deposit_event(Event::<T>::MiningRewardVested(who.clone(), schedule));
+	}
+
+	/// Drop finished vesting schedules and lock what is still vesting, returns the locked amount
+	fn update_mining_vesting(who: &T::AccountId, mut schedules: Vec<VestingScheduleOf<T>>) -> Balance {
+		let now = <system::Pallet<T>>::block_number();
+		schedules.retain(|schedule| !schedule.locked_amount(now).is_zero());
+		let locked = schedules.iter().fold(Balance::zero(), |total, schedule| {
+			total.saturating_add(schedule.locked_amount(now))
+		});
+
+		let currency_id = Self::bit_mining_resource_currency_id();
+		if locked.is_zero() {
+			let _ = T::MiningCurrency::remove_lock(MINING_VESTING_LOCK_ID, currency_id, who);
+			MiningVesting::<T>::remove(who);
+		} else {
+			let _ = T::MiningCurrency::set_lock(MINING_VESTING_LOCK_ID, currency_id, who, locked);
+			MiningVesting::<T>::insert(who, schedules);
+		}
+		locked
+	}
+
+	fn record_burned(amount: Balance) -> Balance {
+		TotalBurned::<T>::mutate(|total_burned| {
+			*total_burned = total_burned.saturating_add(amount);
+			*total_burned
+		})
+	}
+
 	fn do_deposit(who: T::AccountId, amount: Balance) -> DispatchResult {
 		if amount.is_zero() {
 			return Ok(());
@@ -443,6 +1408,41 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn do_claim_quest_reward(who: T::AccountId, quest: OnboardingQuest) -> DispatchResult {
+		ensure!(
+			!ClaimedQuestRewards::<T>::contains_key(&who, quest),
+			Error::<T>::QuestRewardAlreadyClaimed
+		);
+		let amount = Self::quest_reward(quest).ok_or(Error::<T>::QuestRewardNotSet)?;
+		ensure!(
+			T::QuestHandler::is_quest_completed(&who, quest),
+			Error::<T>::QuestNotCompleted
+		);
+
+		let distributed = StarterFaucetDistributed::<T>::get()
+			.checked_add(amount)
+			.ok_or(Error::<T>::StarterFaucetCapExceeded)?;
+		ensure!(
+			distributed <= StarterFaucetCap::<T>::get(),
+			Error::<T>::StarterFaucetCapExceeded
+		);
+
+		let mining_treasury = Self::bit_mining_resource_account_id();
+		ensure!(
+			T::MiningCurrency::free_balance(Self::bit_mining_resource_currency_id(), &mining_treasury) >= amount,
+			Error::<T>::BalanceLow
+		);
+
+		T::MiningCurrency::transfer(Self::bit_mining_resource_currency_id(), &mining_treasury, &who, amount)?;
+
+		ClaimedQuestRewards::<T>::insert(&who, quest, ());
+		StarterFaucetDistributed::<T>::put(distributed);
+
+		Self::deposit_event(Event::QuestRewardClaimed(who, quest, amount));
+
+		Ok(())
+	}
+
 	fn do_add_minting_origin(who: T::AccountId) -> DispatchResult {
 		ensure!(!Self::is_mining_origin(&who), Error::<T>::OriginsAlreadyExist);
 
@@ -465,3 +1465,20 @@ impl<T: Config> RoundTrait<T::BlockNumber> for Pallet<T> {
 		Round::<T>::get()
 	}
 }
+
+impl<T: Config> MiningBurnRecorder for Pallet<T> {
+	fn record_mining_burn(amount: Balance) {
+		Self::record_burned(amount);
+	}
+}
+
+impl<T: Config> InvariantMonitor for Pallet<T> {
+	fn violated_invariant() -> Option<Vec<u8>> {
+		// Rounds without an issuance allocation are not monitored
+		let max_issuance = Self::current_mining_resource_allocation().max;
+		if !max_issuance.is_zero() && Self::round_minted() > max_issuance {
+			return Some(b"MiningIssuanceExceedsMaxRange".to_vec());
+		}
+		None
+	}
+}
@@ -21,7 +21,7 @@ use orml_traits::MultiCurrency;
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use sp_runtime::traits::{Saturating, Zero};
+use sp_runtime::traits::{SaturatedConversion, Saturating, Zero};
 use sp_runtime::{ArithmeticError, Perbill, RuntimeDebug};
 
 use core_primitives::{MiningRange, MiningResourceRateInfo};
@@ -35,36 +35,128 @@ const SECONDS_PER_BLOCK: u32 = 12;
 const BLOCKS_PER_YEAR: u32 = SECONDS_PER_YEAR / SECONDS_PER_BLOCK;
 
 fn rounds_per_year<T: Config>() -> u32 {
-	let blocks_per_round = <Pallet<T>>::round().length;
+	rounds_per_year_of(<Pallet<T>>::round().length)
+}
+
+/// Number of rounds in a year for the given round length
+pub fn rounds_per_year_of(blocks_per_round: u32) -> u32 {
 	BLOCKS_PER_YEAR / blocks_per_round
 }
 
+/// Round length must fit at least once in a year for the issuance math to hold
+pub fn is_valid_round_length(blocks_per_round: u32) -> bool {
+	blocks_per_round > 0 && blocks_per_round <= BLOCKS_PER_YEAR
+}
+
 pub fn convert_annual_to_round<T: Config>(annual: Perbill) -> Perbill {
 	let rounds = rounds_per_year::<T>();
 	annual / rounds
 }
 
+/// Annual inflation rate for the next round after applying one round share of the annual decay
+pub fn decayed_annual_rate<T: Config>(annual_rate: Perbill, annual_decay: Perbill) -> Perbill {
+	let decay_per_round = convert_annual_to_round::<T>(annual_decay);
+	annual_rate.saturating_sub(decay_per_round * annual_rate)
+}
+
+/// Halving epoch at the given block, always 0 when halving is disabled
+pub fn halving_epoch_at<T: Config>(n: T::BlockNumber) -> u32 {
+	let period = T::HalvingPeriod::get();
+	if period.is_zero() {
+		return 0;
+	}
+	(n / period).saturated_into::<u32>()
+}
+
+/// First block of the halving epoch following the given block, none when halving is disabled
+pub fn next_halving_block_at<T: Config>(n: T::BlockNumber) -> Option<T::BlockNumber> {
+	let period = T::HalvingPeriod::get();
+	if period.is_zero() {
+		return None;
+	}
+	Some(n.saturating_sub(n % period).saturating_add(period))
+}
+
+/// Check if a new halving epoch starts at the given block
+pub fn is_halving_block<T: Config>(n: T::BlockNumber) -> bool {
+	let period = T::HalvingPeriod::get();
+	!period.is_zero() && !n.is_zero() && (n % period).is_zero()
+}
+
+/// Rate halved once for every halving epoch
+pub fn halved_rate(rate: Perbill, epoch: u32) -> Perbill {
+	Perbill::from_parts(rate.deconstruct().checked_shr(epoch).unwrap_or_default())
+}
+
+/// Share of land units deployed out of all deployed and undeployed land units
+pub fn land_participation<T: Config>() -> Perbill {
+	let deployed = T::EstateHandler::get_total_land_units();
+	let total = deployed.saturating_add(T::EstateHandler::get_total_undeploy_land_units());
+	if total.is_zero() {
+		return Perbill::zero();
+	}
+	Perbill::from_rational(deployed, total)
+}
+
+/// Issuance between min and max for the given participation, rising up to max at the ideal
+/// participation and falling back towards min above it, similar to NPoS inflation curve
+pub fn issuance_for_participation(min: Balance, max: Balance, participation: Perbill, ideal: Perbill) -> Balance {
+	let spread = max.saturating_sub(min);
+	if participation <= ideal {
+		if ideal.is_zero() {
+			return max;
+		}
+		min.saturating_add(Perbill::from_rational(participation.deconstruct(), ideal.deconstruct()) * spread)
+	} else {
+		let above_ideal = participation.saturating_sub(ideal);
+		let ideal_to_full = Perbill::one().saturating_sub(ideal);
+		max.saturating_sub(Perbill::from_rational(above_ideal.deconstruct(), ideal_to_full.deconstruct()) * spread)
+	}
+}
+
 /// Compute round issuance range from round inflation range and current total issuance
-pub fn round_issuance_range<T: Config>(config: MiningResourceRateInfo) -> MiningRange<Balance> {
+pub fn round_issuance_range<T: Config>(config: MiningResourceRateInfo, halving_epoch: u32) -> MiningRange<Balance> {
+	round_issuance_range_of::<T>(config, halving_epoch, <Pallet<T>>::round().length)
+}
+
+/// Compute round issuance range for rounds of the given length
+pub fn round_issuance_range_of<T: Config>(
+	config: MiningResourceRateInfo,
+	halving_epoch: u32,
+	blocks_per_round: u32,
+) -> MiningRange<Balance> {
+	// Nothing is issued while issuance is paused
+	if <Pallet<T>>::mining_issuance_paused() {
+		return MiningRange::default();
+	}
+
 	// Get total round per year
-	// Annual inflation rate
-	let annual_rate = config.rate;
-	// Get total token supply
+	// Annual inflation rate, halved once for every halving epoch
+	let annual_rate = halved_rate(config.rate, halving_epoch);
+	// Get total token supply, burned supply is already removed from total issuance so burns
+	// shrink every following round issuance
 	let total_circulation_supply = T::MiningCurrency::total_issuance(FungibleTokenId::MiningResource(0));
 	// Get rate per round
-	let rate_per_round = convert_annual_to_round::<T>(annual_rate);
-	// Calculate rate per round from total circulation supply
-	let issuance_per_round = rate_per_round * total_circulation_supply;
+	let rate_per_round = annual_rate / rounds_per_year_of(blocks_per_round);
+	// Annual rate gives the max issuance, min issuance is a share of it
+	let max_issuance = rate_per_round * total_circulation_supply;
+	let min_issuance = T::MinimumIssuanceShare::get() * max_issuance;
+	// Actual issuance follows land participation
+	let issuance_per_round = issuance_for_participation(
+		min_issuance,
+		max_issuance,
+		land_participation::<T>(),
+		T::IdealLandParticipation::get(),
+	);
 
 	let staking_allocation = config.staking_reward * issuance_per_round;
 
 	let mining_allocation = config.mining_reward * issuance_per_round;
 
-	// Return range - could implement more cases in the future.
 	MiningRange {
-		min: issuance_per_round.into(),
+		min: min_issuance.into(),
 		ideal: issuance_per_round.into(),
-		max: issuance_per_round.into(),
+		max: max_issuance.into(),
 		staking_allocation: staking_allocation.into(),
 		mining_allocation: mining_allocation.into(),
 	}
@@ -122,4 +214,31 @@ mod tests {
 		assert_eq!(round_issuance.staking_allocation, 200);
 		assert_eq!(round_issuance.mining_allocation, 800);
 	}
+
+	#[test]
+	fn rate_halves_every_epoch() {
+		let rate = Perbill::from_percent(10);
+
+		assert_eq!(halved_rate(rate, 0), rate);
+		assert_eq!(halved_rate(rate, 1), Perbill::from_percent(5));
+		assert_eq!(halved_rate(rate, 2), Perbill::from_parts(25_000_000));
+		assert_eq!(halved_rate(rate, 32), Perbill::zero());
+	}
+
+	#[test]
+	fn issuance_follows_land_participation() {
+		let ideal = Perbill::from_percent(50);
+
+		assert_eq!(issuance_for_participation(250, 1_000, Perbill::zero(), ideal), 250);
+		assert_eq!(
+			issuance_for_participation(250, 1_000, Perbill::from_percent(25), ideal),
+			625
+		);
+		assert_eq!(issuance_for_participation(250, 1_000, ideal, ideal), 1_000);
+		assert_eq!(
+			issuance_for_participation(250, 1_000, Perbill::from_percent(75), ideal),
+			625
+		);
+		assert_eq!(issuance_for_participation(250, 1_000, Perbill::one(), ideal), 250);
+	}
 }
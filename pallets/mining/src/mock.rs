@@ -14,7 +14,10 @@ use sp_runtime::{
 use primitives::estate::Estate;
 use primitives::staking::MetaverseStakingTrait;
 use primitives::FungibleTokenId::FungibleToken;
-use primitives::{Amount, CurrencyId, EstateId, FungibleTokenId, RoundIndex};
+use primitives::{
+	Amount, Attributes, ClassId, CurrencyId, EstateId, FungibleTokenId, GroupCollectionId, NftMetadata,
+	OnboardingQuest, OnboardingQuestTrait, RoundIndex, TokenId,
+};
 
 use crate as mining;
 use crate::{Config, Module};
@@ -34,6 +37,8 @@ pub const METAVERSE_ID: MetaverseId = 1;
 pub const COUNTRY_ID_NOT_EXIST: MetaverseId = 1;
 pub const NUUM: CurrencyId = 0;
 pub const COUNTRY_FUND: FungibleTokenId = FungibleTokenId::FungibleToken(1);
+pub const METAVERSE_TREASURY_ID: AccountId = 100;
+pub const BOOST_CLASS_ID: ClassId = 7;
 
 ord_parameter_types! {
 	pub const One: AccountId = ALICE;
@@ -99,6 +104,14 @@ parameter_types! {
 	pub const MetaverseTreasuryPalletId: PalletId = PalletId(*b"bit/trsy");
 	pub TreasuryModuleAccount: AccountId = MetaverseTreasuryPalletId::get().into_account();
 	pub const MiningTreasuryPalletId: PalletId = PalletId(*b"bit/fund");
+	pub const MinimumIssuanceShare: Perbill = Perbill::from_percent(25);
+	pub const IdealLandParticipation: Perbill = Perbill::from_percent(50);
+	pub const HalvingPeriod: BlockNumber = 100;
+	pub const RoundHistoryDepth: u32 = 2;
+	pub const MetaverseBackerRewardShare: Perbill = Perbill::from_percent(50);
+	pub static MaxMiningPayoutsPerBlock: u32 = 100;
+	pub const MaxMiningBoostClasses: u32 = 2;
+	pub static SunsetMetaverseId: MetaverseId = 0;
 }
 
 impl orml_tokens::Config for Runtime {
@@ -125,6 +138,8 @@ impl currencies::Config for Runtime {
 	type MultiSocialCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type TreasuryAccount = TreasuryModuleAccount;
+	type UpdateOrigin = EnsureRoot<AccountId>;
 }
 
 parameter_types! {
@@ -154,6 +169,18 @@ impl Estate<u128> for EstateHandler {
 		Ok(true)
 	}
 
+	fn check_estate_ownership(_owner: &u128, _estate_id: EstateId) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
+
+	fn check_landunit_ownership(
+		_owner: &u128,
+		_metaverse_id: primitives::MetaverseId,
+		_coordinate: (i32, i32),
+	) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
+
 	fn get_total_land_units() -> u64 {
 		10
 	}
@@ -161,6 +188,147 @@ impl Estate<u128> for EstateHandler {
 	fn get_total_undeploy_land_units() -> u64 {
 		10
 	}
+	fn check_adjacent_landunit_owner(
+		_who: &u128,
+		_metaverse_id: primitives::MetaverseId,
+		_coordinate: (i32, i32),
+	) -> bool {
+		false
+	}
+
+	fn get_estate_stakes(start_after: Option<u128>, limit: u32) -> Vec<(u128, Balance)> {
+		page_after(vec![(ALICE, 300), (BOB, 100)], start_after, limit)
+	}
+
+	fn get_metaverse_land_units(
+		start_after: Option<primitives::MetaverseId>,
+		limit: u32,
+	) -> Vec<(primitives::MetaverseId, u64)> {
+		page_after(vec![(METAVERSE_ID, 1), (2, 3)], start_after, limit)
+	}
+}
+
+/// Entries following `start_after`, at most `limit` of them
+fn page_after<K: PartialEq, V>(entries: Vec<(K, V)>, start_after: Option<K>, limit: u32) -> Vec<(K, V)> {
+	let skip = match start_after {
+		Some(key) => entries
+			.iter()
+			.position(|(k, _)| *k == key)
+			.map_or(entries.len(), |index| index + 1),
+		None => 0,
+	};
+	entries.into_iter().skip(skip).take(limit as usize).collect()
+}
+
+pub struct MockNFTHandler;
+
+impl NFTTrait<AccountId, Balance> for MockNFTHandler {
+	type TokenId = TokenId;
+	type ClassId = ClassId;
+
+	fn check_ownership(_who: &AccountId, _asset_id: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
+
+	fn check_nft_ownership(_who: &AccountId, _nft: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
+
+	fn get_nft_detail(_asset_id: (Self::ClassId, Self::TokenId)) -> Result<NftClassData<Balance>, DispatchError> {
+		Err(DispatchError::Other("No NFT"))
+	}
+
+	fn get_nft_group_collection(_nft_collection: &Self::ClassId) -> Result<GroupCollectionId, DispatchError> {
+		Ok(0)
+	}
+
+	fn check_collection_and_class(
+		_collection_id: GroupCollectionId,
+		_class_id: Self::ClassId,
+	) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
+
+	fn create_token_class(
+		_sender: &AccountId,
+		_metadata: NftMetadata,
+		_attributes: Attributes,
+		_collection_id: GroupCollectionId,
+		_token_type: TokenType,
+		_collection_type: CollectionType,
+		_royalty_fee: Perbill,
+	) -> Result<ClassId, DispatchError> {
+		Ok(0)
+	}
+
+	fn mint_token(
+		_sender: &AccountId,
+		_class_id: ClassId,
+		_metadata: NftMetadata,
+		_attributes: Attributes,
+	) -> Result<TokenId, DispatchError> {
+		Ok(0)
+	}
+
+	fn burn_nft(_account: &AccountId, _nft: &(Self::ClassId, Self::TokenId)) -> DispatchResult {
+		Ok(())
+	}
+
+	fn check_item_on_listing(_class_id: Self::ClassId, _token_id: Self::TokenId) -> Result<bool, DispatchError> {
+		Ok(false)
+	}
+
+	fn transfer_nft(_sender: &AccountId, _to: &AccountId, _nft: &(Self::ClassId, Self::TokenId)) -> DispatchResult {
+		Ok(())
+	}
+
+	fn is_transferable(_nft: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError> {
+		Ok(true)
+	}
+
+	fn get_class_fund(_class_id: &Self::ClassId) -> AccountId {
+		0
+	}
+
+	fn holds_class_token(who: &AccountId, class_id: &Self::ClassId) -> bool {
+		*who == BOB && *class_id == BOOST_CLASS_ID
+	}
+}
+
+pub struct MetaverseInfoSource;
+
+impl MetaverseTrait<AccountId> for MetaverseInfoSource {
+	fn check_ownership(_who: &AccountId, _metaverse_id: &MetaverseId) -> bool {
+		false
+	}
+
+	fn get_metaverse(_metaverse_id: MetaverseId) -> Option<MetaverseInfo<AccountId>> {
+		None
+	}
+
+	fn get_metaverse_token(_metaverse_id: MetaverseId) -> Option<FungibleTokenId> {
+		None
+	}
+
+	fn update_metaverse_token(_metaverse_id: MetaverseId, _currency_id: FungibleTokenId) -> Result<(), DispatchError> {
+		Ok(())
+	}
+
+	fn get_metaverse_land_class(_metaverse_id: MetaverseId) -> ClassId {
+		15u32
+	}
+
+	fn get_metaverse_estate_class(_metaverse_id: MetaverseId) -> ClassId {
+		16u32
+	}
+
+	fn get_metaverse_treasury(metaverse_id: MetaverseId) -> AccountId {
+		METAVERSE_TREASURY_ID + metaverse_id as AccountId
+	}
+
+	fn is_metaverse_sunset(metaverse_id: MetaverseId) -> bool {
+		metaverse_id == SunsetMetaverseId::get()
+	}
 }
 
 pub struct MetaverseStakingHandler;
@@ -169,6 +337,18 @@ impl MetaverseStakingTrait<u128> for MetaverseStakingHandler {
 	fn update_staking_reward(round: RoundIndex, total_reward: u128) -> sp_runtime::DispatchResult {
 		Ok(())
 	}
+
+	fn get_total_staked(round: RoundIndex) -> u128 {
+		0
+	}
+}
+
+pub struct QuestHandler;
+
+impl OnboardingQuestTrait<AccountId> for QuestHandler {
+	fn is_quest_completed(who: &AccountId, quest: OnboardingQuest) -> bool {
+		*who == ALICE && quest == OnboardingQuest::DeployFirstLand
+	}
 }
 
 impl Config for Runtime {
@@ -179,6 +359,19 @@ impl Config for Runtime {
 	type EstateHandler = EstateHandler;
 	type AdminOrigin = EnsureSignedBy<One, AccountId>;
 	type MetaverseStakingHandler = MetaverseStakingHandler;
+	type TreasuryAccount = TreasuryModuleAccount;
+	type QuestHandler = QuestHandler;
+	type MetaverseInfoSource = MetaverseInfoSource;
+	type MetaverseBackingHandler = ();
+	type MetaverseBackerRewardShare = MetaverseBackerRewardShare;
+	type MinimumIssuanceShare = MinimumIssuanceShare;
+	type IdealLandParticipation = IdealLandParticipation;
+	type HalvingPeriod = HalvingPeriod;
+	type IssuancePauseOrigin = EnsureSignedBy<One, AccountId>;
+	type RoundHistoryDepth = RoundHistoryDepth;
+	type NFTHandler = MockNFTHandler;
+	type MaxMiningPayoutsPerBlock = MaxMiningPayoutsPerBlock;
+	type MaxMiningBoostClasses = MaxMiningBoostClasses;
 	type WeightInfo = ();
 }
 
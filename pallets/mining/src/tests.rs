@@ -66,6 +66,28 @@ fn burn_mining_resource_should_work() {
 	});
 }
 
+#[test]
+fn burn_own_mining_resource_should_track_total_burned() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(setup_minting_resource());
+		assert_ok!(MiningModule::mint(Origin::signed(ALICE), BOB, 1000));
+		assert_ok!(MiningModule::burn(Origin::signed(ALICE), BOB, 100));
+		assert_eq!(MiningModule::total_burned(), 100);
+
+		assert_noop!(
+			MiningModule::burn_mining_resource(Origin::signed(BOB), 0),
+			Error::<Runtime>::AmountZero
+		);
+		assert_ok!(MiningModule::burn_mining_resource(Origin::signed(BOB), 400));
+		assert_eq!(get_mining_balance(), 500);
+		assert_eq!(MiningModule::total_burned(), 500);
+		assert_eq!(
+			last_event(),
+			mock::Event::MiningModule(crate::Event::MiningResourceBurnedBy(BOB, 400, 500))
+		);
+	});
+}
+
 #[test]
 fn withdraw_mining_resource_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -185,3 +207,705 @@ fn update_mining_config_should_work() {
 		assert_eq!(last_event(), event);
 	});
 }
+
+#[test]
+fn claim_quest_reward_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(setup_minting_resource());
+		let treasury_id = MiningModule::bit_mining_resource_account_id();
+		assert_ok!(MiningModule::mint(Origin::signed(ALICE), treasury_id, 1000));
+
+		assert_ok!(MiningModule::set_quest_reward(
+			Origin::signed(ALICE),
+			OnboardingQuest::DeployFirstLand,
+			100
+		));
+		assert_ok!(MiningModule::set_starter_faucet_cap(Origin::signed(ALICE), 150));
+
+		assert_ok!(MiningModule::claim_quest_reward(
+			Origin::signed(ALICE),
+			OnboardingQuest::DeployFirstLand
+		));
+
+		assert_eq!(get_mining_balance_of(&ALICE), 100);
+		assert_eq!(get_mining_balance_of(&treasury_id), 900);
+		assert_eq!(MiningModule::starter_faucet_distributed(), 100);
+
+		let event = mock::Event::MiningModule(crate::Event::QuestRewardClaimed(
+			ALICE,
+			OnboardingQuest::DeployFirstLand,
+			100,
+		));
+
+		assert_eq!(last_event(), event);
+	});
+}
+
+#[test]
+fn claim_quest_reward_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(setup_minting_resource());
+		let treasury_id = MiningModule::bit_mining_resource_account_id();
+		assert_ok!(MiningModule::mint(Origin::signed(ALICE), treasury_id, 1000));
+
+		assert_noop!(
+			MiningModule::set_quest_reward(Origin::signed(BOB), OnboardingQuest::DeployFirstLand, 100),
+			BadOrigin
+		);
+		assert_noop!(
+			MiningModule::claim_quest_reward(Origin::signed(ALICE), OnboardingQuest::DeployFirstLand),
+			Error::<Runtime>::QuestRewardNotSet
+		);
+
+		assert_ok!(MiningModule::set_quest_reward(
+			Origin::signed(ALICE),
+			OnboardingQuest::DeployFirstLand,
+			100
+		));
+		assert_ok!(MiningModule::set_quest_reward(
+			Origin::signed(ALICE),
+			OnboardingQuest::MintFirstNft,
+			100
+		));
+		assert_noop!(
+			MiningModule::claim_quest_reward(Origin::signed(ALICE), OnboardingQuest::DeployFirstLand),
+			Error::<Runtime>::StarterFaucetCapExceeded
+		);
+
+		assert_ok!(MiningModule::set_starter_faucet_cap(Origin::signed(ALICE), 150));
+		assert_noop!(
+			MiningModule::claim_quest_reward(Origin::signed(ALICE), OnboardingQuest::MintFirstNft),
+			Error::<Runtime>::QuestNotCompleted
+		);
+		assert_noop!(
+			MiningModule::claim_quest_reward(Origin::signed(BOB), OnboardingQuest::DeployFirstLand),
+			Error::<Runtime>::QuestNotCompleted
+		);
+
+		assert_ok!(MiningModule::claim_quest_reward(
+			Origin::signed(ALICE),
+			OnboardingQuest::DeployFirstLand
+		));
+		assert_noop!(
+			MiningModule::claim_quest_reward(Origin::signed(ALICE), OnboardingQuest::DeployFirstLand),
+			Error::<Runtime>::QuestRewardAlreadyClaimed
+		);
+	});
+}
+
+#[test]
+fn annual_rate_should_decay_every_round() {
+	ExtBuilder::default().build().execute_with(|| {
+		let mining_config = MiningResourceRateInfo {
+			rate: Perbill::from_percent(10),
+			staking_reward: Perbill::from_percent(30),
+			mining_reward: Perbill::from_percent(70),
+		};
+		assert_ok!(MiningModule::update_mining_issuance_config(
+			Origin::signed(ALICE),
+			mining_config.clone()
+		));
+
+		// Rate stays flat without decay
+		MiningModule::on_initialize(21);
+		assert_eq!(MiningModule::mining_ratio_config(), mining_config);
+
+		assert_noop!(
+			MiningModule::set_annual_rate_decay(Origin::signed(BOB), Perbill::from_percent(50)),
+			BadOrigin
+		);
+		assert_ok!(MiningModule::set_annual_rate_decay(
+			Origin::signed(ALICE),
+			Perbill::from_percent(50)
+		));
+		assert_eq!(MiningModule::annual_rate_decay(), Perbill::from_percent(50));
+
+		MiningModule::on_initialize(41);
+		let decayed_rate =
+			crate::mining::decayed_annual_rate::<Runtime>(Perbill::from_percent(10), Perbill::from_percent(50));
+		assert!(decayed_rate < Perbill::from_percent(10));
+		assert_eq!(MiningModule::mining_ratio_config().rate, decayed_rate);
+		assert_eq!(
+			last_event(),
+			Event::MiningModule(crate::Event::MiningRateDecayed(3, decayed_rate))
+		);
+	});
+}
+
+#[test]
+fn update_round_length_should_reject_invalid_length() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			MiningModule::update_round_length(Origin::root(), 0),
+			Error::<Runtime>::InvalidRoundLength
+		);
+		assert_ok!(MiningModule::update_round_length(Origin::root(), 10));
+		assert_eq!(MiningModule::round().length, 10);
+	});
+}
+
+#[test]
+fn scheduled_round_length_applies_at_next_round() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			MiningModule::schedule_round_length(Origin::signed(ALICE), 0),
+			Error::<Runtime>::InvalidRoundLength
+		);
+		assert_ok!(MiningModule::schedule_round_length(Origin::signed(ALICE), 10));
+		assert_eq!(MiningModule::pending_round_length(), Some(10));
+
+		// Current round keeps its length until the boundary
+		MiningModule::on_initialize(11);
+		assert_eq!(MiningModule::round(), RoundInfo::new(1, 1, 20));
+
+		MiningModule::on_initialize(21);
+		assert_eq!(MiningModule::round(), RoundInfo::new(2, 21, 10));
+		assert_eq!(MiningModule::pending_round_length(), None);
+		assert_eq!(
+			last_event(),
+			Event::MiningModule(crate::Event::RoundLengthTransitioned(
+				2,
+				20,
+				10,
+				crate::mining::rounds_per_year_of(10)
+			))
+		);
+
+		MiningModule::on_initialize(31);
+		assert_eq!(MiningModule::round().current, 3);
+	});
+}
+
+#[test]
+fn minting_above_round_allocation_should_violate_invariant() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(setup_minting_resource());
+		CurrentMiningResourceAllocation::<Runtime>::put(MiningRange {
+			min: 500,
+			ideal: 500,
+			max: 500,
+			staking_allocation: 0,
+			mining_allocation: 500,
+		});
+
+		assert_ok!(MiningModule::mint(Origin::signed(ALICE), BOB, 500));
+		assert_eq!(MiningModule::violated_invariant(), None);
+
+		assert_ok!(MiningModule::mint(Origin::signed(ALICE), BOB, 1));
+		assert_eq!(
+			MiningModule::violated_invariant(),
+			Some(b"MiningIssuanceExceedsMaxRange".to_vec())
+		);
+
+		// New round resets minted amount
+		MiningModule::on_initialize(21);
+		assert_eq!(MiningModule::round_minted(), 0);
+	});
+}
+
+#[test]
+fn mining_allocation_should_be_paid_at_round_end() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			MiningModule::set_land_allocation_share(Origin::signed(BOB), Perbill::from_percent(40)),
+			BadOrigin
+		);
+		assert_ok!(MiningModule::set_land_allocation_share(
+			Origin::signed(ALICE),
+			Perbill::from_percent(40)
+		));
+		CurrentMiningResourceAllocation::<Runtime>::put(MiningRange {
+			min: 1000,
+			ideal: 1000,
+			max: 1000,
+			staking_allocation: 0,
+			mining_allocation: 1000,
+		});
+
+		MiningModule::on_initialize(21);
+
+		// Land allocation split by estate stake
+		assert_eq!(get_mining_balance_of(&ALICE), 300);
+		assert_eq!(get_mining_balance_of(&BOB), 100);
+		// Metaverse allocation split by land units
+		assert_eq!(get_mining_balance_of(&(METAVERSE_TREASURY_ID + 1)), 150);
+		assert_eq!(get_mining_balance_of(&(METAVERSE_TREASURY_ID + 2)), 450);
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == Event::MiningModule(crate::Event::MiningAllocationDistributed(1, 400, 600))));
+	});
+}
+
+#[test]
+fn mining_allocation_should_be_paid_in_batches() {
+	ExtBuilder::default().build().execute_with(|| {
+		MaxMiningPayoutsPerBlock::set(3);
+		assert_ok!(MiningModule::set_land_allocation_share(
+			Origin::signed(ALICE),
+			Perbill::from_percent(40)
+		));
+		CurrentMiningResourceAllocation::<Runtime>::put(MiningRange {
+			min: 1000,
+			ideal: 1000,
+			max: 1000,
+			staking_allocation: 0,
+			mining_allocation: 1000,
+		});
+
+		// Stakes and first metaverse summed
+		assert!(MiningModule::on_initialize(21) > 0);
+		assert_eq!(MiningModule::round().current, 2);
+		assert!(MiningModule::pending_mining_payout().is_some());
+		assert_eq!(get_mining_balance_of(&ALICE), 0);
+
+		// Second metaverse summed and stakers paid
+		MiningModule::on_initialize(22);
+		assert_eq!(get_mining_balance_of(&ALICE), 300);
+		assert_eq!(get_mining_balance_of(&BOB), 100);
+		assert_eq!(get_mining_balance_of(&(METAVERSE_TREASURY_ID + 1)), 0);
+
+		// Metaverses paid and payout completed
+		MiningModule::on_initialize(23);
+		assert!(MiningModule::pending_mining_payout().is_none());
+		assert_eq!(get_mining_balance_of(&(METAVERSE_TREASURY_ID + 1)), 150);
+		assert_eq!(get_mining_balance_of(&(METAVERSE_TREASURY_ID + 2)), 450);
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == Event::MiningModule(crate::Event::MiningAllocationDistributed(1, 400, 600))));
+		assert_eq!(MiningModule::round_issuance_summary(1).unwrap().total_minted, 1000);
+	});
+}
+
+#[test]
+fn land_of_sunset_metaverse_should_not_mine() {
+	ExtBuilder::default().build().execute_with(|| {
+		SunsetMetaverseId::set(2);
+		assert_ok!(MiningModule::set_land_allocation_share(
+			Origin::signed(ALICE),
+			Perbill::from_percent(40)
+		));
+		CurrentMiningResourceAllocation::<Runtime>::put(MiningRange {
+			min: 1000,
+			ideal: 1000,
+			max: 1000,
+			staking_allocation: 0,
+			mining_allocation: 1000,
+		});
+
+		MiningModule::on_initialize(21);
+
+		// Metaverse allocation goes to the metaverse whose land is still active
+		assert_eq!(get_mining_balance_of(&(METAVERSE_TREASURY_ID + 1)), 600);
+		assert_eq!(get_mining_balance_of(&(METAVERSE_TREASURY_ID + 2)), 0);
+	});
+}
+
+#[test]
+fn metaverse_mining_weight_should_boost_metaverse_allocation() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			MiningModule::set_metaverse_mining_weight(Origin::signed(BOB), 1, 300),
+			BadOrigin
+		);
+		assert_ok!(MiningModule::set_metaverse_mining_weight(Origin::signed(ALICE), 1, 300));
+		assert_eq!(MiningModule::metaverse_mining_weight(1), Some(300));
+		CurrentMiningResourceAllocation::<Runtime>::put(MiningRange {
+			min: 1000,
+			ideal: 1000,
+			max: 1000,
+			staking_allocation: 0,
+			mining_allocation: 1000,
+		});
+
+		MiningModule::on_initialize(21);
+
+		// Metaverse 1 land counts three times, matching the land units of metaverse 2
+		assert_eq!(get_mining_balance_of(&(METAVERSE_TREASURY_ID + 1)), 500);
+		assert_eq!(get_mining_balance_of(&(METAVERSE_TREASURY_ID + 2)), 500);
+
+		assert_ok!(MiningModule::set_metaverse_mining_weight(Origin::signed(ALICE), 1, 100));
+		assert_eq!(MiningModule::metaverse_mining_weight(1), None);
+	});
+}
+
+#[test]
+fn minting_rate_should_halve_every_halving_epoch() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(setup_minting_resource());
+		assert_ok!(MiningModule::mint(Origin::signed(ALICE), BOB, 1_000_000_000_000_000));
+		assert_ok!(MiningModule::update_mining_issuance_config(
+			Origin::signed(ALICE),
+			MiningResourceRateInfo {
+				rate: Perbill::from_percent(10),
+				staking_reward: Perbill::from_percent(100),
+				mining_reward: Perbill::zero(),
+			}
+		));
+		assert_eq!(MiningModule::current_halving_epoch(), 0);
+		assert_eq!(MiningModule::next_halving_block(), Some(100));
+
+		MiningModule::on_initialize(21);
+		let first_epoch_issuance = MiningModule::current_mining_resource_allocation().max;
+		assert!(first_epoch_issuance > 0);
+
+		MiningModule::on_initialize(100);
+		assert_eq!(
+			MiningModule::current_mining_resource_allocation().max,
+			first_epoch_issuance / 2
+		);
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == Event::MiningModule(crate::Event::HalvingEpochStarted(1, 200))));
+
+		System::set_block_number(150);
+		assert_eq!(MiningModule::current_halving_epoch(), 1);
+		assert_eq!(MiningModule::next_halving_block(), Some(200));
+	});
+}
+
+#[test]
+fn paused_mining_issuance_should_issue_and_pay_nothing() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(MiningModule::pause_mining_issuance(Origin::signed(BOB)), BadOrigin);
+		assert_noop!(
+			MiningModule::resume_mining_issuance(Origin::signed(ALICE)),
+			Error::<Runtime>::MiningIssuanceIsNotPaused
+		);
+		assert_ok!(MiningModule::pause_mining_issuance(Origin::signed(ALICE)));
+		assert!(MiningModule::mining_issuance_paused());
+
+		assert_ok!(setup_minting_resource());
+		assert_ok!(MiningModule::mint(Origin::signed(ALICE), BOB, 1_000_000_000_000_000));
+		CurrentMiningResourceAllocation::<Runtime>::put(MiningRange {
+			min: 1000,
+			ideal: 1000,
+			max: 1000,
+			staking_allocation: 0,
+			mining_allocation: 1000,
+		});
+
+		MiningModule::on_initialize(21);
+
+		// Neither the ending round allocation is paid nor new issuance is computed
+		assert_eq!(get_mining_balance_of(&ALICE), 0);
+		assert_eq!(get_mining_balance_of(&(METAVERSE_TREASURY_ID + 1)), 0);
+		assert_eq!(
+			MiningModule::current_mining_resource_allocation(),
+			MiningRange::default()
+		);
+		// The ending round allocation is carried over to the first round after resuming
+		assert_eq!(MiningModule::carried_mining_allocation(), 1000);
+		assert_eq!(
+			last_event(),
+			Event::MiningModule(crate::Event::MiningAllocationCarriedOver(1, 1000))
+		);
+
+		assert_ok!(MiningModule::resume_mining_issuance(Origin::signed(ALICE)));
+		assert_ok!(MiningModule::update_mining_issuance_config(
+			Origin::signed(ALICE),
+			MiningResourceRateInfo {
+				rate: Perbill::from_percent(10),
+				staking_reward: Perbill::from_percent(20),
+				mining_reward: Perbill::from_percent(80),
+			}
+		));
+		MiningModule::on_initialize(41);
+		assert!(MiningModule::current_mining_resource_allocation().max > 0);
+		assert_eq!(MiningModule::carried_mining_allocation(), 0);
+		assert_eq!(get_mining_balance_of(&(METAVERSE_TREASURY_ID + 1)), 250);
+		assert_eq!(get_mining_balance_of(&(METAVERSE_TREASURY_ID + 2)), 750);
+	});
+}
+
+#[test]
+fn round_reward_vesting_should_lock_share_of_mined_rewards() {
+	ExtBuilder::default().build().execute_with(|| {
+		let vesting = MiningRewardVesting {
+			share: Perbill::from_percent(50),
+			period: 10,
+			period_count: 2,
+		};
+		assert_noop!(
+			MiningModule::set_round_reward_vesting(Origin::signed(BOB), 1, Some(vesting)),
+			BadOrigin
+		);
+		assert_noop!(
+			MiningModule::set_round_reward_vesting(
+				Origin::signed(ALICE),
+				1,
+				Some(MiningRewardVesting { period: 0, ..vesting })
+			),
+			Error::<Runtime>::InvalidRewardVesting
+		);
+		assert_ok!(MiningModule::set_round_reward_vesting(
+			Origin::signed(ALICE),
+			1,
+			Some(vesting)
+		));
+		assert_ok!(MiningModule::set_land_allocation_share(
+			Origin::signed(ALICE),
+			Perbill::from_percent(40)
+		));
+		CurrentMiningResourceAllocation::<Runtime>::put(MiningRange {
+			min: 1000,
+			ideal: 1000,
+			max: 1000,
+			staking_allocation: 0,
+			mining_allocation: 1000,
+		});
+
+		System::set_block_number(21);
+		MiningModule::on_initialize(21);
+		assert_eq!(MiningModule::round_reward_vesting(1), None);
+
+		// Half of the 300 reward is locked and released over two vests
+		assert_eq!(get_mining_balance_of(&ALICE), 300);
+		assert_eq!(MiningModule::mining_vesting(ALICE).len(), 1);
+		assert_eq!(
+			Tokens::locks(ALICE, MiningCurrencyId::get())
+				.iter()
+				.map(|lock| (lock.id, lock.amount))
+				.collect::<Vec<_>>(),
+			vec![(MINING_VESTING_LOCK_ID, 150)]
+		);
+		assert_noop!(
+			MiningModule::burn_mining_resource(Origin::signed(ALICE), 151),
+			orml_tokens::Error::<Runtime>::LiquidityRestrictions
+		);
+
+		System::set_block_number(31);
+		assert_ok!(MiningModule::claim_mining_vesting(Origin::signed(ALICE)));
+		assert_eq!(
+			last_event(),
+			Event::MiningModule(crate::Event::MiningVestingClaimed(ALICE, 75))
+		);
+
+		System::set_block_number(41);
+		assert_ok!(MiningModule::claim_mining_vesting(Origin::signed(ALICE)));
+		assert!(MiningModule::mining_vesting(ALICE).is_empty());
+		assert_ok!(MiningModule::burn_mining_resource(Origin::signed(ALICE), 300));
+	});
+}
+
+#[test]
+fn round_issuance_history_should_keep_most_recent_rounds() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MiningModule::set_land_allocation_share(
+			Origin::signed(ALICE),
+			Perbill::from_percent(40)
+		));
+		CurrentMiningResourceAllocation::<Runtime>::put(MiningRange {
+			min: 1000,
+			ideal: 1000,
+			max: 1000,
+			staking_allocation: 0,
+			mining_allocation: 1000,
+		});
+
+		MiningModule::on_initialize(21);
+		assert_eq!(
+			MiningModule::round_issuance_summary(1),
+			Some(RoundIssuanceSummary {
+				total_minted: 1000,
+				land_allocation: 400,
+				metaverse_allocation: 600,
+				land_units: 10,
+			})
+		);
+
+		MiningModule::on_initialize(41);
+		MiningModule::on_initialize(61);
+		// History depth is 2 so round 1 is pruned
+		assert_eq!(MiningModule::round_issuance_summary(1), None);
+		let history = MiningModule::round_issuance_history();
+		assert_eq!(
+			history.iter().map(|(round, _)| *round).collect::<Vec<RoundIndex>>(),
+			vec![3, 2]
+		);
+	});
+}
+
+#[test]
+fn estimate_next_round_issuance_should_match_round_boundary() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(setup_minting_resource());
+		assert_ok!(MiningModule::mint(Origin::signed(ALICE), BOB, 1_000_000_000));
+		assert_ok!(MiningModule::update_mining_issuance_config(
+			Origin::signed(ALICE),
+			MiningResourceRateInfo {
+				rate: Perbill::from_percent(10),
+				staking_reward: Perbill::from_percent(30),
+				mining_reward: Perbill::from_percent(70),
+			}
+		));
+		let current_length_estimate = MiningModule::estimate_next_round_issuance();
+		assert!(!current_length_estimate.max.is_zero());
+
+		// Scheduled round length is applied before the next round issuance is computed
+		assert_ok!(MiningModule::schedule_round_length(Origin::signed(ALICE), 10));
+		let estimate = MiningModule::estimate_next_round_issuance();
+		assert!(estimate.max < current_length_estimate.max);
+
+		MiningModule::on_initialize(21);
+		assert_eq!(MiningModule::current_mining_resource_allocation(), estimate);
+	});
+}
+
+#[test]
+fn update_mining_issuance_config_should_reject_invalid_reward_split() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			MiningModule::update_mining_issuance_config(
+				Origin::signed(ALICE),
+				MiningResourceRateInfo {
+					rate: Perbill::from_percent(10),
+					staking_reward: Perbill::from_percent(60),
+					mining_reward: Perbill::from_percent(60),
+				}
+			),
+			Error::<Runtime>::InvalidMiningResourceRateInfo
+		);
+		assert_noop!(
+			MiningModule::update_mining_issuance_config(
+				Origin::signed(ALICE),
+				MiningResourceRateInfo {
+					rate: Perbill::from_percent(10),
+					staking_reward: Perbill::from_percent(30),
+					mining_reward: Perbill::from_percent(60),
+				}
+			),
+			Error::<Runtime>::InvalidMiningResourceRateInfo
+		);
+		assert_ok!(MiningModule::update_mining_issuance_config(
+			Origin::signed(ALICE),
+			MiningResourceRateInfo {
+				rate: Perbill::from_percent(10),
+				staking_reward: Perbill::from_percent(40),
+				mining_reward: Perbill::from_percent(60),
+			}
+		));
+	})
+}
+
+#[test]
+fn force_advance_round_should_roll_rounds_at_current_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(5);
+		CurrentMiningResourceAllocation::<Runtime>::put(MiningRange {
+			min: 1000,
+			ideal: 1000,
+			max: 1000,
+			staking_allocation: 0,
+			mining_allocation: 1000,
+		});
+		assert_noop!(MiningModule::force_advance_round(Origin::signed(ALICE), 3), BadOrigin);
+
+		assert_ok!(MiningModule::force_advance_round(Origin::root(), 3));
+		assert_eq!(MiningModule::round().current, 4);
+		assert_eq!(MiningModule::round().first, 5);
+		assert_eq!(
+			MiningModule::round_issuance_history()
+				.iter()
+				.map(|(round, _)| *round)
+				.collect::<Vec<RoundIndex>>(),
+			vec![3, 2]
+		);
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == Event::MiningModule(crate::Event::MiningRoundsForceAdvanced(3, 4))));
+
+		// Next round boundary counts from the fast-forwarded block
+		MiningModule::on_initialize(24);
+		assert_eq!(MiningModule::round().current, 4);
+		MiningModule::on_initialize(25);
+		assert_eq!(MiningModule::round().current, 5);
+	});
+}
+
+#[test]
+fn mining_boost_nft_holders_should_earn_boosted_land_rewards() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			MiningModule::set_mining_boost_class(Origin::signed(ALICE), BOOST_CLASS_ID, Some(90)),
+			Error::<Runtime>::InvalidMiningBoost
+		);
+		assert_ok!(MiningModule::set_mining_boost_class(
+			Origin::signed(ALICE),
+			BOOST_CLASS_ID,
+			Some(200)
+		));
+		assert_eq!(MiningModule::staker_mining_boost(&BOB), 200);
+		assert_eq!(MiningModule::staker_mining_boost(&ALICE), 100);
+
+		// Boost classes are bounded, updating a boosted class does not take another entry
+		assert_ok!(MiningModule::set_mining_boost_class(
+			Origin::signed(ALICE),
+			100,
+			Some(150)
+		));
+		assert_ok!(MiningModule::set_mining_boost_class(
+			Origin::signed(ALICE),
+			BOOST_CLASS_ID,
+			Some(200)
+		));
+		assert_noop!(
+			MiningModule::set_mining_boost_class(Origin::signed(ALICE), 101, Some(150)),
+			Error::<Runtime>::TooManyMiningBoostClasses
+		);
+		assert_ok!(MiningModule::set_mining_boost_class(Origin::signed(ALICE), 100, None));
+		assert_eq!(MiningModule::mining_boost_of_class(100), None);
+		assert_eq!(MiningModule::mining_boost_of_class(BOOST_CLASS_ID), Some(200));
+
+		assert_ok!(MiningModule::set_land_allocation_share(
+			Origin::signed(ALICE),
+			Perbill::from_percent(40)
+		));
+		CurrentMiningResourceAllocation::<Runtime>::put(MiningRange {
+			min: 1000,
+			ideal: 1000,
+			max: 1000,
+			staking_allocation: 0,
+			mining_allocation: 1000,
+		});
+
+		MiningModule::on_initialize(21);
+
+		// BOB stake of 100 counts double against ALICE stake of 300
+		assert_eq!(get_mining_balance_of(&ALICE), 240);
+		assert_eq!(get_mining_balance_of(&BOB), 160);
+	});
+}
+
+#[test]
+fn undistributed_mining_allocation_should_go_to_treasury() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MiningModule::set_land_allocation_share(
+			Origin::signed(ALICE),
+			Perbill::from_percent(40)
+		));
+		// No metaverse land has mining power so the metaverse share has no recipient
+		assert_ok!(MiningModule::set_metaverse_mining_weight(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			0
+		));
+		assert_ok!(MiningModule::set_metaverse_mining_weight(Origin::signed(ALICE), 2, 0));
+		CurrentMiningResourceAllocation::<Runtime>::put(MiningRange {
+			min: 1000,
+			ideal: 1000,
+			max: 1000,
+			staking_allocation: 0,
+			mining_allocation: 1000,
+		});
+
+		MiningModule::on_initialize(21);
+
+		assert_eq!(get_mining_balance_of(&ALICE), 300);
+		assert_eq!(get_mining_balance_of(&BOB), 100);
+		assert_eq!(get_mining_balance_of(&TreasuryModuleAccount::get()), 600);
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == Event::MiningModule(crate::Event::UnallocatedIssuanceToTreasury(1, 600))));
+		assert_eq!(MiningModule::round_issuance_summary(1).unwrap().total_minted, 1000);
+	});
+}
@@ -43,7 +43,7 @@ use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use sp_std::marker::PhantomData;
 
 /// Weight functions needed for mining.
-pub trait WeightInfo {	fn add_minting_origin() -> Weight;	fn remove_minting_origin() -> Weight;	fn update_round_length() -> Weight;	fn update_mining_issuance_config() -> Weight;	fn mint() -> Weight;	fn burn() -> Weight;	fn deposit() -> Weight;	fn withdraw() -> Weight;}
+pub trait WeightInfo {	fn add_minting_origin() -> Weight;	fn remove_minting_origin() -> Weight;	fn update_round_length() -> Weight;	fn update_mining_issuance_config() -> Weight;	fn mint() -> Weight;	fn burn() -> Weight;	fn deposit() -> Weight;	fn withdraw() -> Weight;	fn set_quest_reward() -> Weight;	fn set_starter_faucet_cap() -> Weight;	fn claim_quest_reward() -> Weight;	fn set_annual_rate_decay() -> Weight;	fn schedule_round_length() -> Weight;	fn burn_mining_resource() -> Weight;	fn set_land_allocation_share() -> Weight;	fn set_metaverse_mining_weight() -> Weight;	fn pause_mining_issuance() -> Weight;	fn resume_mining_issuance() -> Weight;	fn set_round_reward_vesting() -> Weight;	fn claim_mining_vesting() -> Weight;	fn set_mining_boost_class() -> Weight;}
 
 /// Weights for mining using the for collator node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -55,7 +55,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {	fn add_minting
 		(61_900_000 as Weight)			.saturating_add(T::DbWeight::get().reads(3 as Weight))			.saturating_add(T::DbWeight::get().writes(2 as Weight))	}	fn burn() -> Weight {
 		(43_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(3 as Weight))			.saturating_add(T::DbWeight::get().writes(2 as Weight))	}	fn deposit() -> Weight {
 		(79_400_000 as Weight)			.saturating_add(T::DbWeight::get().reads(4 as Weight))			.saturating_add(T::DbWeight::get().writes(3 as Weight))	}	fn withdraw() -> Weight {
-		(62_400_000 as Weight)			.saturating_add(T::DbWeight::get().reads(4 as Weight))			.saturating_add(T::DbWeight::get().writes(2 as Weight))	}}
+		(62_400_000 as Weight)			.saturating_add(T::DbWeight::get().reads(4 as Weight))			.saturating_add(T::DbWeight::get().writes(2 as Weight))	}	fn set_quest_reward() -> Weight {
+		(18_000_000 as Weight)			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn set_starter_faucet_cap() -> Weight {
+		(17_000_000 as Weight)			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn claim_quest_reward() -> Weight {
+		(65_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(6 as Weight))			.saturating_add(T::DbWeight::get().writes(4 as Weight))	}	fn set_annual_rate_decay() -> Weight {
+		(18_000_000 as Weight)			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn schedule_round_length() -> Weight {
+		(18_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(1 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn burn_mining_resource() -> Weight {
+		(41_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(2 as Weight))			.saturating_add(T::DbWeight::get().writes(3 as Weight))	}	fn set_land_allocation_share() -> Weight {
+		(18_000_000 as Weight)			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn set_metaverse_mining_weight() -> Weight {
+		(18_000_000 as Weight)			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn pause_mining_issuance() -> Weight {
+		(16_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(1 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn resume_mining_issuance() -> Weight {
+		(16_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(1 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn set_round_reward_vesting() -> Weight {
+		(15_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(1 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn claim_mining_vesting() -> Weight {
+		(30_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(2 as Weight))			.saturating_add(T::DbWeight::get().writes(2 as Weight))	}	fn set_mining_boost_class() -> Weight {
+		(21_000_000 as Weight)			.saturating_add(T::DbWeight::get().reads(1 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}}
 
 // For backwards compatibility and tests
 impl WeightInfo for () {	fn add_minting_origin() -> Weight {
@@ -66,4 +79,17 @@ impl WeightInfo for () {	fn add_minting_origin() -> Weight {
 		(61_900_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(3 as Weight))			.saturating_add(RocksDbWeight::get().writes(2 as Weight))	}	fn burn() -> Weight {
 		(43_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(3 as Weight))			.saturating_add(RocksDbWeight::get().writes(2 as Weight))	}	fn deposit() -> Weight {
 		(79_400_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(4 as Weight))			.saturating_add(RocksDbWeight::get().writes(3 as Weight))	}	fn withdraw() -> Weight {
-		(62_400_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(4 as Weight))			.saturating_add(RocksDbWeight::get().writes(2 as Weight))	}}
+		(62_400_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(4 as Weight))			.saturating_add(RocksDbWeight::get().writes(2 as Weight))	}	fn set_quest_reward() -> Weight {
+		(18_000_000 as Weight)			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn set_starter_faucet_cap() -> Weight {
+		(17_000_000 as Weight)			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn claim_quest_reward() -> Weight {
+		(65_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(6 as Weight))			.saturating_add(RocksDbWeight::get().writes(4 as Weight))	}	fn set_annual_rate_decay() -> Weight {
+		(18_000_000 as Weight)			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn schedule_round_length() -> Weight {
+		(18_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(1 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn burn_mining_resource() -> Weight {
+		(41_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(2 as Weight))			.saturating_add(RocksDbWeight::get().writes(3 as Weight))	}	fn set_land_allocation_share() -> Weight {
+		(18_000_000 as Weight)			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn set_metaverse_mining_weight() -> Weight {
+		(18_000_000 as Weight)			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn pause_mining_issuance() -> Weight {
+		(16_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(1 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn resume_mining_issuance() -> Weight {
+		(16_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(1 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn set_round_reward_vesting() -> Weight {
+		(15_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(1 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn claim_mining_vesting() -> Weight {
+		(30_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(2 as Weight))			.saturating_add(RocksDbWeight::get().writes(2 as Weight))	}	fn set_mining_boost_class() -> Weight {
+		(21_000_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(1 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}}
@@ -95,17 +95,30 @@ benchmarks! {
 	}: _(RawOrigin::Signed(caller), target.clone(), (0u32.into(), 0u32.into()))
 
 	transfer_batch{
+		let i in 1 .. T::MaxBatchTransfer::get();
+
 		let caller = funded_account::<T>("caller", 0);
-		let target1 = funded_account::<T>("target1", 0);
-		let target2 = funded_account::<T>("target2", 0);
+		let target = funded_account::<T>("target", 0);
 		let initial_balance = dollar(1000);
 
 		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
 		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
 		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
-		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 3);
-		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 3);
-	}: _(RawOrigin::Signed(caller), vec![(target1.clone(), (0u32.into(), 0u32.into())), (target2.clone(), (0u32.into(), 1u32.into()))] )
+		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), i);
+		let tos = (0..i).map(|token| (target.clone(), (0u32.into(), token.into()))).collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(caller), tos)
+
+	mint_batch{
+		let i in 1 .. T::MaxBatchMinting::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+		let items = (0..i).map(|item| (vec![1], test_attributes((item % 100) as u8))).collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(caller), 0u32.into(), items)
 
 	sign_asset{
 		let caller = funded_account::<T>("caller", 0);
@@ -118,6 +131,47 @@ benchmarks! {
 		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 3);
 	}: _(RawOrigin::Signed(signer), (0u32.into(), 0u32.into()), 100u32.into() )
 
+	enable_metadata_updates{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+	}: _(RawOrigin::Signed(caller), 0u32.into())
+
+	update_class_metadata{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+		crate::Pallet::<T>::enable_metadata_updates(RawOrigin::Signed(caller.clone()).into(), 0u32.into());
+	}: _(RawOrigin::Signed(caller), 0u32.into(), vec![2])
+
+	update_token_metadata{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+		crate::Pallet::<T>::enable_metadata_updates(RawOrigin::Signed(caller.clone()).into(), 0u32.into());
+		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 3);
+	}: _(RawOrigin::Signed(caller), (0u32.into(), 0u32.into()), vec![2], test_attributes(2))
+
+	freeze_metadata{
+		let caller = funded_account::<T>("caller", 0);
+		let initial_balance = dollar(1000);
+
+		<T as pallet::Config>::Currency::make_free_balance_be(&caller, initial_balance.unique_saturated_into());
+		crate::Pallet::<T>::create_group(RawOrigin::Root.into(), vec![1], vec![1]);
+		crate::Pallet::<T>::create_class(RawOrigin::Signed(caller.clone()).into(), vec![1], test_attributes(1), 0u32.into(), TokenType::Transferable, CollectionType::Collectable, Perbill::from_percent(0u32));
+		crate::Pallet::<T>::enable_metadata_updates(RawOrigin::Signed(caller.clone()).into(), 0u32.into());
+		crate::Pallet::<T>::mint(RawOrigin::Signed(caller.clone()).into(), 0u32.into(), vec![1], test_attributes(1), 3);
+	}: _(RawOrigin::Signed(caller), 0u32.into(), Some(0u32.into()))
+
 }
 
 impl_benchmark_test_suite!(Pallet, crate::benchmarking::tests::new_test_ext(), crate::mock::Test);
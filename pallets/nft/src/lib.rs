@@ -34,28 +34,34 @@ use frame_support::{
 	pallet_prelude::*,
 	traits::{
 		schedule::{DispatchTime, Named as ScheduleNamed},
-		Currency, ExistenceRequirement, Get, LockIdentifier, ReservableCurrency,
+		Currency, ExistenceRequirement, Get, GetStorageVersion, LockIdentifier, Randomness, ReservableCurrency,
 	},
-	PalletId,
+	transactional, PalletId,
 };
 use frame_system::pallet_prelude::*;
-use orml_nft::{ClassInfo, ClassInfoOf, Classes, Pallet as NftModule};
+use orml_nft::{ClassInfo, ClassInfoOf, Classes, Pallet as NftModule, Tokens};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
+use sp_io::KillStorageResult;
 use sp_runtime::traits::Saturating;
 use sp_runtime::{
-	traits::{AccountIdConversion, Dispatchable, One},
-	DispatchError,
+	traits::{AccountIdConversion, Dispatchable, Hash as HashT, IdentifyAccount, One, Verify, Zero},
+	DispatchError, SaturatedConversion,
 };
 use sp_runtime::{Perbill, RuntimeDebug};
-use sp_std::vec::Vec;
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
+use sp_std::{vec, vec::Vec};
+use xcm::latest::MultiLocation;
 
 use auction_manager::{Auction, CheckAuctionItemHandler};
 pub use pallet::*;
-pub use primitive_traits::{Attributes, NFTTrait, NftClassData, NftGroupCollectionData, NftMetadata, TokenType};
+pub use primitive_traits::{
+	AttributeValue, Attributes, MintVoucher, NFTTrait, NftAirdrop, NftClassData, NftGroupCollectionData, NftMetadata,
+	OnNftTransfer, TokenType, WearableSlot,
+};
 use primitive_traits::{CollectionType, NftAssetData, NftClassDataV1};
+use primitives::telemetry::{ActivityCounter, ActivityRecorder};
 use primitives::{
 	AssetId, BlockNumber, ClassId, GroupCollectionId, Hash, ItemId, TokenId, ESTATE_CLASS_ID, LAND_CLASS_ID,
 };
@@ -69,9 +75,76 @@ mod mock;
 mod tests;
 
 pub mod weights;
+pub mod xcm_adapter;
 
 const TIMECAPSULE_ID: LockIdentifier = *b"bctimeca";
 
+/// Sub account of the pallet holding local NFTs while they are on other chains
+pub const XCM_RESERVE_SUB_ACCOUNT: [u8; 7] = *b"xcm/nft";
+
+/// Sub account of the pallet holding gifted NFTs until they are claimed or reclaimed
+pub const GIFT_ESCROW_SUB_ACCOUNT: [u8; 8] = *b"gift/nft";
+
+/// Max merkle proof length of an airdrop claim, enough for over a billion eligible accounts
+pub const MAX_AIRDROP_PROOF_LENGTH: usize = 32;
+
+/// Scale of token rarity scores, a trait carried by every token of the class scores this much
+pub const RARITY_SCORE_SCALE: u128 = 1_000_000;
+
+/// Max generative mints revealed in one block
+pub const MAX_GENERATIVE_REVEALS_PER_BLOCK: usize = 10;
+
+/// Randomness subject of generative mint trait rolls
+pub const GENERATIVE_MINT_SUBJECT: &[u8] = b"nft/generative";
+
+/// Max mint phases of a class
+pub const MAX_MINT_PHASES: usize = 10;
+
+/// Max stale per-extrinsic transfer records cleared in one block, records left over are ignored
+/// as they carry the block they were written in
+pub const MAX_TRANSFER_RECORDS_CLEARED_PER_BLOCK: u32 = 1_000;
+
+/// Public mint phase of a class, open from the start block until before the end block
+#[derive(codec::Encode, codec::Decode, Clone, frame_support::RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct MintPhase<BlockNumber, Balance> {
+	/// First block of the phase
+	pub start: BlockNumber,
+	/// Block the phase closes at
+	pub end: BlockNumber,
+	/// Price of each NFT paid to the class owner
+	pub price: Balance,
+	/// Max NFTs each account can mint in the phase
+	pub per_account_limit: u32,
+}
+
+/// Generative mint committed by a class issuer, the traits are rolled when it is revealed
+#[derive(codec::Encode, codec::Decode, Clone, frame_support::RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct GenerativeMintRequest<AccountId, ClassId, BlockNumber> {
+	/// Account minting the NFTs
+	pub issuer: AccountId,
+	/// Class of the NFTs
+	pub class_id: ClassId,
+	/// Metadata shared by the NFTs
+	pub metadata: NftMetadata,
+	/// Number of NFTs to mint
+	pub quantity: u32,
+	/// Traits of the class when the mint was requested, later changes do not apply to it
+	pub traits: Vec<(Vec<u8>, Vec<Vec<u8>>)>,
+	/// Block the mint was requested at, only randomness revealed after it is used
+	pub requested_at: BlockNumber,
+}
+
+/// Class privileges the owner can delegate to another account, the owner always holds them all
+#[derive(codec::Encode, codec::Decode, Clone, Copy, frame_support::RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub enum ClassRole {
+	/// Mints NFTs and publishes airdrops, minting fees are paid by the class owner
+	Issuer,
+	/// Updates class and NFT metadata and typed attributes
+	Admin,
+	/// Freezes class and NFT metadata
+	Freezer,
+}
+
 #[derive(codec::Encode, codec::Decode, Clone, frame_support::RuntimeDebug, PartialEq)]
 pub enum StorageVersion {
 	V0,
@@ -87,7 +160,10 @@ pub mod pallet {
 
 	use super::*;
 
+	const STORAGE_VERSION: frame_support::traits::StorageVersion = frame_support::traits::StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 
@@ -134,11 +210,39 @@ pub mod pallet {
 		/// Fungible token id for promotion incentive
 		#[pallet::constant]
 		type MiningResourceId: Get<FungibleTokenId>;
+		/// Chain activity telemetry counting minted NFTs
+		type ActivityRecorder: ActivityRecorder;
+		/// Max attribute keys of a token counted in the rarity index
+		#[pallet::constant]
+		type MaxRarityAttributes: Get<u32>;
+		/// Max typed attributes of an NFT
+		#[pallet::constant]
+		type MaxTypedAttributes: Get<u32>;
+		/// Max levels of NFTs nested below a top level NFT
+		#[pallet::constant]
+		type MaxNestingDepth: Get<u32>;
+		/// Signature of mint vouchers signed off-chain by class owners
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+		/// Public key of mint voucher signers
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+		/// Randomness source of generative mint trait rolls, a reveal waits until the source reports
+		/// randomness first known after the request block
+		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+		/// Blocks between a generative mint request and the reveal of its traits
+		#[pallet::constant]
+		type GenerativeRevealDelay: Get<Self::BlockNumber>;
+		/// Handler notified of every NFT ownership change, such as the estate provenance
+		type OnNftTransfer: OnNftTransfer<Self::AccountId, ClassIdOf<Self>, TokenIdOf<Self>>;
 	}
 
 	pub type ClassIdOf<T> = <T as orml_nft::Config>::ClassId;
 	pub type TokenIdOf<T> = <T as orml_nft::Config>::TokenId;
 	pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type GenerativeMintRequestOf<T> = GenerativeMintRequest<
+		<T as frame_system::Config>::AccountId,
+		ClassIdOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+	>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn get_asset)]
@@ -180,6 +284,228 @@ pub mod pallet {
 	#[pallet::getter(fn get_locked_collection)]
 	pub(super) type LockedCollection<T: Config> = StorageMap<_, Blake2_128Concat, ClassIdOf<T>, (), OptionQuery>;
 
+	/// Block and index of the extrinsic which last transferred each NFT, a bounded number of stale
+	/// records is cleared at the start of every block
+	#[pallet::storage]
+	#[pallet::getter(fn get_transferred_in_extrinsic)]
+	pub(super) type TransferredInExtrinsic<T: Config> =
+		StorageMap<_, Blake2_128Concat, (ClassIdOf<T>, TokenIdOf<T>), (T::BlockNumber, u32), OptionQuery>;
+
+	/// Individual NFTs locked by other pallets, e.g. tokenized estates backing a loan
+	#[pallet::storage]
+	#[pallet::getter(fn get_locked_nft)]
+	pub(super) type LockedNfts<T: Config> =
+		StorageMap<_, Blake2_128Concat, (ClassIdOf<T>, TokenIdOf<T>), (), OptionQuery>;
+
+	/// Individual NFTs frozen by governance, e.g. tokenized estates under dispute
+	#[pallet::storage]
+	#[pallet::getter(fn get_frozen_nft)]
+	pub(super) type FrozenNfts<T: Config> =
+		StorageMap<_, Blake2_128Concat, (ClassIdOf<T>, TokenIdOf<T>), (), OptionQuery>;
+
+	/// Royalty recipient and rate of individual NFTs set by the creator at mint, overriding the
+	/// class royalty at settlement
+	#[pallet::storage]
+	#[pallet::getter(fn token_royalty)]
+	pub(super) type TokenRoyalties<T: Config> =
+		StorageMap<_, Blake2_128Concat, (ClassIdOf<T>, TokenIdOf<T>), (T::AccountId, Perbill), OptionQuery>;
+
+	/// Mint vouchers already redeemed per class and voucher nonce
+	#[pallet::storage]
+	#[pallet::getter(fn is_voucher_redeemed)]
+	pub(super) type RedeemedVouchers<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ClassIdOf<T>, Twox64Concat, u64, (), OptionQuery>;
+
+	/// Renter holding the user role of an NFT and the block the rental expires at, the owner keeps
+	/// ownership of the NFT
+	#[pallet::storage]
+	#[pallet::getter(fn get_nft_user)]
+	pub(super) type NftUsers<T: Config> =
+		StorageMap<_, Blake2_128Concat, (ClassIdOf<T>, TokenIdOf<T>), (T::AccountId, T::BlockNumber), OptionQuery>;
+
+	/// Parent NFT owning each nested NFT
+	#[pallet::storage]
+	#[pallet::getter(fn get_nft_parent)]
+	pub(super) type NftParents<T: Config> =
+		StorageMap<_, Blake2_128Concat, (ClassIdOf<T>, TokenIdOf<T>), (ClassIdOf<T>, TokenIdOf<T>), OptionQuery>;
+
+	/// NFTs nested directly in each parent NFT, they move together with the parent
+	#[pallet::storage]
+	#[pallet::getter(fn get_nft_child)]
+	pub(super) type NftChildren<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(ClassIdOf<T>, TokenIdOf<T>),
+		Blake2_128Concat,
+		(ClassIdOf<T>, TokenIdOf<T>),
+		(),
+		OptionQuery,
+	>;
+
+	/// Classes whose metadata, and the metadata and attributes of all their NFTs, can no longer
+	/// be updated
+	#[pallet::storage]
+	#[pallet::getter(fn get_frozen_class)]
+	pub(super) type FrozenClasses<T: Config> = StorageMap<_, Blake2_128Concat, ClassIdOf<T>, (), OptionQuery>;
+
+	/// NFTs whose metadata and attributes can no longer be updated
+	#[pallet::storage]
+	#[pallet::getter(fn get_frozen_token)]
+	pub(super) type FrozenTokens<T: Config> =
+		StorageMap<_, Blake2_128Concat, (ClassIdOf<T>, TokenIdOf<T>), (), OptionQuery>;
+
+	/// Classes whose owner opted in to metadata updates before minting, the metadata of any other
+	/// class is frozen
+	#[pallet::storage]
+	#[pallet::getter(fn get_mutable_metadata_class)]
+	pub(super) type MutableMetadataClasses<T: Config> = StorageMap<_, Blake2_128Concat, ClassIdOf<T>, (), OptionQuery>;
+
+	/// Typed attributes of each NFT by attribute key, set by the class owner until the metadata is
+	/// frozen
+	#[pallet::storage]
+	#[pallet::getter(fn get_typed_attribute_value)]
+	pub(super) type TypedAttributes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(ClassIdOf<T>, TokenIdOf<T>),
+		Blake2_128Concat,
+		Vec<u8>,
+		AttributeValue,
+		OptionQuery,
+	>;
+
+	/// Airdrop currently published for each class
+	#[pallet::storage]
+	#[pallet::getter(fn get_airdrop)]
+	pub(super) type Airdrops<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClassIdOf<T>, NftAirdrop<T::Hash>, OptionQuery>;
+
+	/// Accounts which claimed their allocation of the airdrop with the merkle root
+	#[pallet::storage]
+	#[pallet::getter(fn is_airdrop_claimed)]
+	pub(super) type AirdropClaims<T: Config> =
+		StorageDoubleMap<_, Identity, T::Hash, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// Local class of the derivatives of each registered foreign NFT collection
+	#[pallet::storage]
+	#[pallet::getter(fn foreign_class)]
+	pub(super) type ForeignClasses<T: Config> =
+		StorageMap<_, Blake2_128Concat, MultiLocation, ClassIdOf<T>, OptionQuery>;
+
+	/// Foreign collection location of each derivative class
+	#[pallet::storage]
+	#[pallet::getter(fn foreign_class_location)]
+	pub(super) type ForeignClassLocations<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClassIdOf<T>, MultiLocation, OptionQuery>;
+
+	/// Derivative NFT minted for each foreign asset instance
+	#[pallet::storage]
+	#[pallet::getter(fn foreign_token)]
+	pub(super) type ForeignTokens<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ClassIdOf<T>, Blake2_128Concat, u128, TokenIdOf<T>, OptionQuery>;
+
+	/// Foreign asset instance of each derivative NFT
+	#[pallet::storage]
+	#[pallet::getter(fn foreign_token_instance)]
+	pub(super) type ForeignTokenInstances<T: Config> =
+		StorageMap<_, Blake2_128Concat, (ClassIdOf<T>, TokenIdOf<T>), u128, OptionQuery>;
+
+	/// Trait keys of generative classes with the values rolled from, repeated values are more
+	/// likely to be rolled
+	#[pallet::storage]
+	#[pallet::getter(fn generative_traits)]
+	pub(super) type GenerativeTraits<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClassIdOf<T>, Vec<(Vec<u8>, Vec<Vec<u8>>)>, OptionQuery>;
+
+	/// Generative mints revealed at each block
+	#[pallet::storage]
+	#[pallet::getter(fn generative_mint_queue)]
+	pub(super) type GenerativeMintQueue<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, Vec<GenerativeMintRequestOf<T>>, ValueQuery>;
+
+	/// Account approved to transfer each NFT on behalf of its owner, cleared when the NFT moves
+	#[pallet::storage]
+	#[pallet::getter(fn get_approved)]
+	pub(super) type TokenApprovals<T: Config> =
+		StorageMap<_, Blake2_128Concat, (ClassIdOf<T>, TokenIdOf<T>), T::AccountId, OptionQuery>;
+
+	/// Operators approved to transfer all NFTs of an owner
+	#[pallet::storage]
+	#[pallet::getter(fn get_operator_approval)]
+	pub(super) type OperatorApprovals<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// Hard cap on the number of NFTs ever minted in a class, burned NFTs still count towards it
+	#[pallet::storage]
+	#[pallet::getter(fn get_class_max_supply)]
+	pub(super) type ClassMaxSupply<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClassIdOf<T>, TokenIdOf<T>, OptionQuery>;
+
+	/// Public mint phases of each class
+	#[pallet::storage]
+	#[pallet::getter(fn get_mint_phases)]
+	pub(super) type MintPhases<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClassIdOf<T>, Vec<MintPhase<T::BlockNumber, BalanceOf<T>>>, ValueQuery>;
+
+	/// NFTs minted by each account in each mint phase of a class
+	#[pallet::storage]
+	#[pallet::getter(fn get_phase_mints)]
+	pub(super) type PhaseMints<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ClassIdOf<T>, Blake2_128Concat, (u32, T::AccountId), u32, ValueQuery>;
+
+	/// Wearable NFTs equipped on each avatar NFT by slot
+	#[pallet::storage]
+	#[pallet::getter(fn get_equipped_item)]
+	pub(super) type EquippedItems<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(ClassIdOf<T>, TokenIdOf<T>),
+		Twox64Concat,
+		WearableSlot,
+		(ClassIdOf<T>, TokenIdOf<T>),
+		OptionQuery,
+	>;
+
+	/// Slot of each equipped wearable NFT, the avatar is its nesting parent
+	#[pallet::storage]
+	#[pallet::getter(fn get_equipped_slot)]
+	pub(super) type EquippedSlots<T: Config> =
+		StorageMap<_, Blake2_128Concat, (ClassIdOf<T>, TokenIdOf<T>), WearableSlot, OptionQuery>;
+
+	/// Gifted NFTs held in escrow - sender, recipient and the last block the gift can be claimed
+	#[pallet::storage]
+	#[pallet::getter(fn get_nft_gift)]
+	pub(super) type NftGifts<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(ClassIdOf<T>, TokenIdOf<T>),
+		(T::AccountId, T::AccountId, T::BlockNumber),
+		OptionQuery,
+	>;
+
+	/// Accounts holding a delegated role of each class
+	#[pallet::storage]
+	#[pallet::getter(fn class_role)]
+	pub(super) type ClassRoles<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ClassIdOf<T>, Twox64Concat, ClassRole, T::AccountId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_attribute_frequency)]
+	/// Number of tokens of a class carrying each attribute key and value, used to rank rarity
+	pub(super) type AttributeFrequencies<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ClassIdOf<T>, Blake2_128Concat, (Vec<u8>, Vec<u8>), u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn nft_minted_by)]
+	/// Accounts that have minted at least one NFT, used by the onboarding quests
+	pub(super) type NftMintedAccounts<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, (), OptionQuery>;
+
+	/// Class and minting deposits reserved on each depositor. Deposits paid to the treasury
+	/// before deposits were reserved are not counted.
+	#[pallet::storage]
+	#[pallet::getter(fn reserved_deposit_of)]
+	pub(super) type ReservedDeposits<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig {}
 
@@ -270,6 +596,121 @@ pub mod pallet {
 		CollectionLocked(ClassIdOf<T>),
 		/// Collection is unlocked
 		CollectionUnlocked(ClassIdOf<T>),
+		/// Batch transfer stopped at the item index with the error, earlier items were
+		/// transferred
+		BatchTransferInterrupted(u32, DispatchError),
+		/// NFTs transferred in a batch - show the sender and each recipient with the asset
+		BatchTransferred(
+			<T as frame_system::Config>::AccountId,
+			Vec<(<T as frame_system::Config>::AccountId, (ClassIdOf<T>, TokenIdOf<T>))>,
+		),
+		/// Royalty override set on the minted NFTs - show the first and last asset, recipient and
+		/// royalty rate
+		TokenRoyaltySet(
+			(ClassIdOf<T>, TokenIdOf<T>),
+			(ClassIdOf<T>, TokenIdOf<T>),
+			<T as frame_system::Config>::AccountId,
+			Perbill,
+		),
+		/// Mint voucher redeemed - show the voucher nonce, buyer, minted asset and price paid
+		MintVoucherRedeemed(
+			u64,
+			<T as frame_system::Config>::AccountId,
+			(ClassIdOf<T>, TokenIdOf<T>),
+			BalanceOf<T>,
+		),
+		/// Soulbound NFT issued by the class owner - show the asset and recipient
+		SoulboundNftIssued((ClassIdOf<T>, TokenIdOf<T>), <T as frame_system::Config>::AccountId),
+		/// NFT nested in a parent NFT - show the child and parent asset
+		NftNested((ClassIdOf<T>, TokenIdOf<T>), (ClassIdOf<T>, TokenIdOf<T>)),
+		/// NFT taken out of its parent NFT - show the child and former parent asset
+		NftUnnested((ClassIdOf<T>, TokenIdOf<T>), (ClassIdOf<T>, TokenIdOf<T>)),
+		/// NFT rented out - show the asset, user and the block the rental expires at
+		NftUserSet(
+			(ClassIdOf<T>, TokenIdOf<T>),
+			<T as frame_system::Config>::AccountId,
+			T::BlockNumber,
+		),
+		/// Class metadata updated by the class owner
+		ClassMetadataUpdated(ClassIdOf<T>),
+		/// NFT metadata and attributes updated by the class owner
+		TokenMetadataUpdated((ClassIdOf<T>, TokenIdOf<T>)),
+		/// Typed attribute of an NFT set, or removed if the value is None
+		TypedAttributeSet((ClassIdOf<T>, TokenIdOf<T>), Vec<u8>, Option<AttributeValue>),
+		/// Metadata frozen forever - show the class and the NFT, None if the whole class is frozen
+		MetadataFrozen(ClassIdOf<T>, Option<TokenIdOf<T>>),
+		/// Airdrop published for the class with the merkle root
+		AirdropPublished(ClassIdOf<T>, T::Hash),
+		/// Airdrop allocation claimed - show the claimant, first and last asset minted
+		AirdropClaimed(
+			<T as frame_system::Config>::AccountId,
+			(ClassIdOf<T>, TokenIdOf<T>),
+			(ClassIdOf<T>, TokenIdOf<T>),
+		),
+		/// Foreign NFT collection mapped to the local derivative class, or unmapped if None
+		ForeignClassRegistered(MultiLocation, Option<ClassIdOf<T>>),
+		/// Empty NFT class destroyed and its deposit released to the owner
+		BurnedNftClass(ClassIdOf<T>),
+		/// Class role assigned to an account, or revoked if None
+		ClassRoleSet(ClassIdOf<T>, ClassRole, Option<<T as frame_system::Config>::AccountId>),
+		/// Generative traits of the class updated
+		GenerativeTraitsSet(ClassIdOf<T>),
+		/// Generative mint requested - show the issuer, class and reveal block
+		GenerativeMintRequested(<T as frame_system::Config>::AccountId, ClassIdOf<T>, T::BlockNumber),
+		/// Generative mint revealed - show the issuer, first and last asset minted
+		GenerativeMintRevealed(
+			<T as frame_system::Config>::AccountId,
+			(ClassIdOf<T>, TokenIdOf<T>),
+			(ClassIdOf<T>, TokenIdOf<T>),
+		),
+		/// Generative mint could not be revealed - show the issuer, class and error
+		GenerativeMintFailed(<T as frame_system::Config>::AccountId, ClassIdOf<T>, DispatchError),
+		/// NFT gifted into escrow - show the asset, sender, recipient and expiry block
+		NftGifted(
+			(ClassIdOf<T>, TokenIdOf<T>),
+			<T as frame_system::Config>::AccountId,
+			<T as frame_system::Config>::AccountId,
+			T::BlockNumber,
+		),
+		/// Gifted NFT claimed by the recipient
+		NftGiftClaimed((ClassIdOf<T>, TokenIdOf<T>), <T as frame_system::Config>::AccountId),
+		/// Expired gift reclaimed by the sender
+		NftGiftReclaimed((ClassIdOf<T>, TokenIdOf<T>), <T as frame_system::Config>::AccountId),
+		/// Wearable equipped - show the avatar, wearable and slot
+		ItemEquipped((ClassIdOf<T>, TokenIdOf<T>), (ClassIdOf<T>, TokenIdOf<T>), WearableSlot),
+		/// Wearable unequipped - show the avatar, wearable and slot
+		ItemUnequipped((ClassIdOf<T>, TokenIdOf<T>), (ClassIdOf<T>, TokenIdOf<T>), WearableSlot),
+		/// Account approved to transfer the NFT, or approval revoked if None - show the owner,
+		/// asset and approved account
+		Approval(
+			<T as frame_system::Config>::AccountId,
+			(ClassIdOf<T>, TokenIdOf<T>),
+			Option<<T as frame_system::Config>::AccountId>,
+		),
+		/// Operator approved or revoked for all NFTs of the owner - show the owner, operator and
+		/// approval
+		ApprovalForAll(
+			<T as frame_system::Config>::AccountId,
+			<T as frame_system::Config>::AccountId,
+			bool,
+		),
+		/// Externally originated class imported for the owner
+		ClassImported(ClassIdOf<T>, <T as frame_system::Config>::AccountId),
+		/// NFTs imported into the class - show the class and number of NFTs
+		TokensImported(ClassIdOf<T>, u32),
+		/// Max supply of the class set
+		MaxSupplySet(ClassIdOf<T>, TokenIdOf<T>),
+		/// Mint phases of the class replaced
+		MintPhasesSet(ClassIdOf<T>),
+		/// NFTs minted in a mint phase - show the minter, phase, first and last asset minted
+		PhaseMinted(
+			<T as frame_system::Config>::AccountId,
+			u32,
+			(ClassIdOf<T>, TokenIdOf<T>),
+			(ClassIdOf<T>, TokenIdOf<T>),
+		),
+		/// Class owner opted in to metadata updates of the class and its NFTs
+		MetadataUpdatesEnabled(ClassIdOf<T>),
 	}
 
 	#[pallet::error]
@@ -280,8 +721,10 @@ pub mod pallet {
 		AssetInfoNotFound,
 		/// Asset Id not found
 		AssetIdNotFound,
-		/// No permission
-		NoPermission,
+		/// Sender is not the owner of the asset
+		NotAssetOwner,
+		/// Sender is not the owner of the class, nor holds the class role required
+		NotClassOwner,
 		/// No available collection id
 		NoAvailableCollectionId,
 		/// Collection id does not exist
@@ -324,8 +767,97 @@ pub mod pallet {
 		CollectionIsLocked,
 		/// Collection is not locked
 		CollectionIsNotLocked,
-		/// NFT Royalty fee exceed 50%
+		/// NFT royalty fee exceeds the 25% maximum
 		RoyaltyFeeExceedLimit,
+		/// NFT has already been transferred in this extrinsic
+		AlreadyTransferredInExtrinsic,
+		/// NFT is locked
+		NftIsLocked,
+		/// Mint voucher is not signed by the class owner
+		InvalidVoucherSignature,
+		/// Mint voucher has already been redeemed
+		VoucherAlreadyRedeemed,
+		/// Rental expiry is not in the future
+		InvalidRentalExpiry,
+		/// NFT is rented out to another user
+		NftAlreadyRented,
+		/// Only classes bound to address can issue soulbound NFTs
+		OnlyForSoulboundClass,
+		/// NFT is nested in a parent NFT
+		NftIsNested,
+		/// NFT is not nested in a parent NFT
+		NftIsNotNested,
+		/// NFT still owns nested NFTs
+		NftHasChildren,
+		/// NFT cannot be nested in itself or one of its own children
+		InvalidNesting,
+		/// Nesting would exceed the maximum nesting depth
+		ExceedMaximumNestingDepth,
+		/// Top level NFT cannot hold more nested NFTs
+		ExceedMaximumNestedChildren,
+		/// Metadata is frozen and cannot be updated
+		MetadataIsFrozen,
+		/// Exceed maximum typed attributes of an NFT
+		ExceedMaximumTypedAttributes,
+		/// No airdrop is published for the class
+		AirdropNotFound,
+		/// Merkle proof does not match the airdrop root
+		InvalidAirdropProof,
+		/// Airdrop allocation has already been claimed
+		AirdropAlreadyClaimed,
+		/// Foreign collection or local class is already registered
+		ForeignClassAlreadyRegistered,
+		/// Foreign collection is not registered
+		ForeignClassNotRegistered,
+		/// Class still has tokens and cannot be destroyed
+		ClassIsNotEmpty,
+		/// Generative traits are empty or exceed the limits
+		InvalidGenerativeTraits,
+		/// Class has no generative traits
+		GenerativeTraitsNotSet,
+		/// Too many generative mints revealed in the same block, try again later
+		GenerativeRevealQueueFull,
+		/// Gift expiry is not in the future or the recipient is the sender
+		InvalidGift,
+		/// NFT is not held as a gift
+		GiftNotFound,
+		/// Sender is not the gift recipient, or the gift expired
+		CannotClaimGift,
+		/// Sender did not gift the NFT, or the gift has not expired yet
+		CannotReclaimGift,
+		/// Item is not of a wearable class, or the avatar is
+		InvalidWearable,
+		/// Avatar already has a wearable equipped in the slot
+		SlotAlreadyEquipped,
+		/// Avatar has no wearable equipped in the slot
+		SlotNotEquipped,
+		/// Wearable is equipped, unequip it from the avatar instead
+		ItemIsEquipped,
+		/// Sender is neither the owner of the NFT nor approved to transfer it
+		NotApproved,
+		/// Owner cannot approve itself
+		InvalidApproval,
+		/// Imported token ids are not ascending from the next token id of the class, or span more
+		/// than the max batch minting
+		InvalidImportedTokenIds,
+		/// Max supply is below the class supply or above the current max supply
+		InvalidMaxSupply,
+		/// Minting would exceed the max supply of the class
+		ExceedMaxSupply,
+		/// Mint phases are too many or end before they start
+		InvalidMintPhases,
+		/// No mint phase of the class is open
+		NoActiveMintPhase,
+		/// Minting would exceed the per account limit of the mint phase
+		ExceedMintPhaseLimit,
+		/// Mint voucher expiry block has passed
+		VoucherExpired,
+		/// Metadata updates can only be enabled before the first NFT of the class is minted
+		ClassAlreadyMinted,
+		/// Derivative of the foreign NFT is already minted
+		DerivativeAlreadyMinted,
+		/// Generative reveal delay must be at least one block
+		InvalidRevealDelay,
 	}
 
 	#[pallet::call]
@@ -385,7 +917,7 @@ pub mod pallet {
 			Ok(().into())
 		}
 
-		#[pallet::weight(< T as Config >::WeightInfo::mint() * * quantity as u64)]
+		#[pallet::weight(< T as Config >::WeightInfo::mint() * * quantity as u64 + Pallet::<T>::rarity_index_weight(* quantity))]
 		pub fn mint(
 			origin: OriginFor<T>,
 			class_id: ClassIdOf<T>,
@@ -409,7 +941,166 @@ pub mod pallet {
 			Ok(().into())
 		}
 
-		#[pallet::weight(T::WeightInfo::transfer())]
+		/// Class owner issues a soulbound NFT of a class bound to address, e.g. an achievement
+		/// badge or KYC attestation, to the recipient. The NFT can never be transferred or sold
+		/// afterwards.
+		#[pallet::weight(< T as Config >::WeightInfo::mint() + < T as Config >::WeightInfo::transfer() + Pallet::<T>::rarity_index_weight(1))]
+		#[transactional]
+		pub fn issue_soulbound_nft(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			recipient: T::AccountId,
+			metadata: NftMetadata,
+			attributes: Attributes,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(
+				!class_info.data.token_type.is_transferable(),
+				Error::<T>::OnlyForSoulboundClass
+			);
+
+			let minting_outcome = Self::do_mint_nfts(&sender, class_id, metadata, attributes, 1)?;
+			let asset_id = *minting_outcome.0.first().unwrap();
+			// Issuance is the only move a soulbound NFT makes, bypassing the token type check
+			Self::transfer_token(&sender, &recipient, asset_id)?;
+
+			Self::deposit_event(Event::<T>::NewNftMinted(
+				asset_id,
+				asset_id,
+				sender,
+				class_id,
+				1,
+				minting_outcome.1,
+			));
+			Self::deposit_event(Event::<T>::SoulboundNftIssued(asset_id, recipient));
+
+			Ok(().into())
+		}
+
+		/// Mint NFTs with their own metadata and attributes in one call, cheaper than minting them
+		/// one by one
+		#[pallet::weight(< T as Config >::WeightInfo::mint_batch(items.len() as u32) + Pallet::<T>::rarity_index_weight(items.len() as u32))]
+		pub fn mint_batch(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			items: Vec<(NftMetadata, Attributes)>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let quantity = items.len() as u32;
+
+			let minting_outcome = Self::do_mint_items(&sender, class_id, items)?;
+
+			Self::deposit_event(Event::<T>::NewNftMinted(
+				*minting_outcome.0.first().unwrap(),
+				*minting_outcome.0.last().unwrap(),
+				sender,
+				class_id,
+				quantity,
+				minting_outcome.1,
+			));
+
+			Ok(().into())
+		}
+
+		/// Mint NFTs with a royalty recipient and rate overriding the class royalty, for items
+		/// that need a different split than the collection default
+		#[pallet::weight(< T as Config >::WeightInfo::mint() * * quantity as u64 + T::DbWeight::get().writes(* quantity as u64) + Pallet::<T>::rarity_index_weight(* quantity))]
+		pub fn mint_with_royalty(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			metadata: NftMetadata,
+			attributes: Attributes,
+			quantity: u32,
+			royalty_recipient: T::AccountId,
+			royalty_fee: Perbill,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			ensure!(
+				royalty_fee <= Perbill::from_percent(25u32),
+				Error::<T>::RoyaltyFeeExceedLimit
+			);
+
+			let minting_outcome = Self::do_mint_nfts(&sender, class_id, metadata, attributes, quantity)?;
+			for asset_id in minting_outcome.0.iter() {
+				TokenRoyalties::<T>::insert(asset_id, (royalty_recipient.clone(), royalty_fee));
+			}
+
+			let first_asset = *minting_outcome.0.first().unwrap();
+			let last_asset = *minting_outcome.0.last().unwrap();
+			Self::deposit_event(Event::<T>::NewNftMinted(
+				first_asset,
+				last_asset,
+				sender,
+				class_id,
+				quantity,
+				minting_outcome.1,
+			));
+			Self::deposit_event(Event::<T>::TokenRoyaltySet(
+				first_asset,
+				last_asset,
+				royalty_recipient,
+				royalty_fee,
+			));
+
+			Ok(().into())
+		}
+
+		/// Redeem a mint voucher signed off-chain by the class owner. The buyer pays the voucher
+		/// price to the class owner and receives the NFT minted at purchase time.
+		#[pallet::weight(< T as Config >::WeightInfo::mint() + < T as Config >::WeightInfo::transfer() + Pallet::<T>::rarity_index_weight(1))]
+		#[transactional]
+		pub fn redeem_mint_voucher(
+			origin: OriginFor<T>,
+			voucher: MintVoucher<ClassIdOf<T>, T::AccountId, BalanceOf<T>, T::BlockNumber>,
+			signature: T::OffchainSignature,
+		) -> DispatchResultWithPostInfo {
+			let buyer = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(voucher.class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			let creator = class_info.owner;
+			ensure!(
+				signature.verify(&Self::mint_voucher_payload(&voucher)[..], &creator),
+				Error::<T>::InvalidVoucherSignature
+			);
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() <= voucher.expires_at,
+				Error::<T>::VoucherExpired
+			);
+			ensure!(
+				!RedeemedVouchers::<T>::contains_key(voucher.class_id, voucher.nonce),
+				Error::<T>::VoucherAlreadyRedeemed
+			);
+			if let Some((_, royalty_fee)) = voucher.royalty {
+				ensure!(
+					royalty_fee <= Perbill::from_percent(25u32),
+					Error::<T>::RoyaltyFeeExceedLimit
+				);
+			}
+
+			RedeemedVouchers::<T>::insert(voucher.class_id, voucher.nonce, ());
+			// The creator pays the minting fee out of the sale
+			<T as Config>::Currency::transfer(&buyer, &creator, voucher.price, ExistenceRequirement::KeepAlive)?;
+			let minting_outcome =
+				Self::do_mint_nfts(&creator, voucher.class_id, voucher.metadata, voucher.attributes, 1)?;
+			let asset_id = minting_outcome.0[0];
+			if let Some(royalty) = voucher.royalty {
+				TokenRoyalties::<T>::insert(asset_id, royalty);
+			}
+			Self::do_transfer(&creator, &buyer, asset_id)?;
+
+			Self::deposit_event(Event::<T>::MintVoucherRedeemed(
+				voucher.nonce,
+				buyer,
+				asset_id,
+				voucher.price,
+			));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::transfer().saturating_add(Pallet::<T>::nested_transfer_weight(&asset_id)))]
 		pub fn transfer(
 			origin: OriginFor<T>,
 			to: T::AccountId,
@@ -429,7 +1120,87 @@ pub mod pallet {
 			Ok(().into())
 		}
 
-		#[pallet::weight(T::WeightInfo::transfer_batch() * tos.len() as u64)]
+		/// Transfer an NFT of another owner who approved the sender for the NFT or for all of their
+		/// NFTs
+		#[pallet::weight(T::WeightInfo::transfer().saturating_add(Pallet::<T>::nested_transfer_weight(&asset_id)))]
+		pub fn transfer_from(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			to: T::AccountId,
+			asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				Self::is_approved_or_owner(&sender, &from, &asset_id),
+				Error::<T>::NotApproved
+			);
+			ensure!(
+				Self::check_item_on_listing(asset_id.0, asset_id.1)? == false,
+				Error::<T>::AssetAlreadyInAuction
+			);
+
+			let token_id = Self::do_transfer(&from, &to, asset_id)?;
+
+			Self::deposit_event(Event::<T>::TransferedNft(from, to, token_id, asset_id.clone()));
+
+			Ok(().into())
+		}
+
+		/// Approve an account to transfer the NFT without taking custody of it, or revoke the
+		/// approval when no account is given. The approval is cleared when the NFT moves.
+		#[pallet::weight(T::WeightInfo::sign_asset())]
+		pub fn approve(
+			origin: OriginFor<T>,
+			asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+			approved: Option<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				Self::check_nft_ownership(&sender, &asset_id)?,
+				Error::<T>::NotAssetOwner
+			);
+			match &approved {
+				Some(account) => {
+					ensure!(*account != sender, Error::<T>::InvalidApproval);
+					TokenApprovals::<T>::insert(asset_id, account);
+				}
+				None => TokenApprovals::<T>::remove(asset_id),
+			}
+
+			Self::deposit_event(Event::<T>::Approval(sender, asset_id, approved));
+
+			Ok(().into())
+		}
+
+		/// Approve or revoke an operator, e.g. a marketplace or game contract, to transfer all NFTs
+		/// of the sender
+		#[pallet::weight(T::WeightInfo::sign_asset())]
+		pub fn approve_for_all(
+			origin: OriginFor<T>,
+			operator: T::AccountId,
+			approved: bool,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(operator != sender, Error::<T>::InvalidApproval);
+			if approved {
+				OperatorApprovals::<T>::insert(&sender, &operator, ());
+			} else {
+				OperatorApprovals::<T>::remove(&sender, &operator);
+			}
+
+			Self::deposit_event(Event::<T>::ApprovalForAll(sender, operator, approved));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::transfer_batch(tos.len() as u32).saturating_add(
+			tos.iter()
+				.map(|(_, asset_id)| Pallet::<T>::nested_transfer_weight(asset_id))
+				.fold(0, |total: Weight, weight| total.saturating_add(weight))
+		))]
 		pub fn transfer_batch(
 			origin: OriginFor<T>,
 			tos: Vec<(T::AccountId, (ClassIdOf<T>, TokenIdOf<T>))>,
@@ -441,29 +1212,28 @@ pub mod pallet {
 				Error::<T>::ExceedMaximumBatchTransfer
 			);
 
-			for (_i, x) in tos.iter().enumerate() {
-				let item = &x;
-				let owner = &sender.clone();
-
-				let class_info = NftModule::<T>::classes((item.1).0).ok_or(Error::<T>::ClassIdNotFound)?;
-				let data = class_info.data;
-
-				match data.token_type {
-					TokenType::Transferable => {
-						let asset_info =
-							NftModule::<T>::tokens((item.1).0, (item.1).1).ok_or(Error::<T>::AssetInfoNotFound)?;
-						ensure!(owner.clone() == asset_info.owner, Error::<T>::NoPermission);
-
-						NftModule::<T>::transfer(&owner, &item.0, item.1)?;
-						Self::deposit_event(Event::<T>::TransferedNft(
-							owner.clone(),
-							item.0.clone(),
-							(item.1).1.clone(),
-							item.1.clone(),
-						));
+			let mut transferred = Vec::new();
+			let mut interrupted = None;
+			for (index, (to, asset_id)) in tos.iter().enumerate() {
+				match Self::do_transfer_batch_item(&sender, to, *asset_id) {
+					Ok(true) => transferred.push((to.clone(), *asset_id)),
+					Ok(false) => {}
+					Err(error) => {
+						// Nothing has been transferred yet, fail the whole call
+						if index == 0 {
+							return Err(error.into());
+						}
+						interrupted = Some((index as u32, error));
+						break;
 					}
-					_ => (),
-				};
+				}
+			}
+
+			if !transferred.is_empty() {
+				Self::deposit_event(Event::<T>::BatchTransferred(sender, transferred));
+			}
+			if let Some((index, error)) = interrupted {
+				Self::deposit_event(Event::<T>::BatchTransferInterrupted(index, error));
 			}
 
 			Ok(().into())
@@ -518,6 +1288,7 @@ pub mod pallet {
 		}
 
 		#[pallet::weight(T::WeightInfo::sign_asset())]
+		#[transactional]
 		pub fn burn(origin: OriginFor<T>, asset_id: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			Self::do_burn(&sender, &asset_id)?;
@@ -525,10 +1296,788 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		/// Destroy a class once all of its tokens are burned, releasing the class deposit back to
+		/// the owner
 		#[pallet::weight(T::WeightInfo::sign_asset())]
-		pub fn force_lock_collection(origin: OriginFor<T>, class_id: ClassIdOf<T>) -> DispatchResult {
-			ensure_root(origin)?;
-
+		#[transactional]
+		pub fn burn_class(origin: OriginFor<T>, class_id: ClassIdOf<T>) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			Self::do_burn_class(&sender, class_id)?;
+			Self::deposit_event(Event::<T>::BurnedNftClass(class_id));
+			Ok(().into())
+		}
+
+		/// Class owner assigns an issuer, admin or freezer role of the class to another account,
+		/// or revokes it when no account is given
+		#[pallet::weight(T::WeightInfo::sign_asset())]
+		pub fn set_class_role(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			role: ClassRole,
+			account: Option<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(sender == class_info.owner, Error::<T>::NotClassOwner);
+
+			match &account {
+				Some(account) => ClassRoles::<T>::insert(class_id, role, account),
+				None => ClassRoles::<T>::remove(class_id, role),
+			}
+
+			Self::deposit_event(Event::<T>::ClassRoleSet(class_id, role, account));
+
+			Ok(().into())
+		}
+
+		/// Class owner or admin sets the trait keys and values rolled by generative mints, or
+		/// removes them when empty
+		#[pallet::weight(T::WeightInfo::sign_asset())]
+		pub fn set_generative_traits(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			traits: Vec<(Vec<u8>, Vec<Vec<u8>>)>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			Self::ensure_class_role(&sender, &class_info.owner, class_id, ClassRole::Admin)?;
+			ensure!(
+				!FrozenClasses::<T>::contains_key(class_id),
+				Error::<T>::MetadataIsFrozen
+			);
+
+			if traits.is_empty() {
+				GenerativeTraits::<T>::remove(class_id);
+			} else {
+				let max_metadata = T::MaxMetadata::get() as usize;
+				let max_traits = T::MaxTypedAttributes::get() as usize;
+				ensure!(
+					traits.len() <= max_traits
+						&& traits.iter().all(|(key, values)| {
+							key.len() <= max_metadata
+								&& !values.is_empty() && values.len() <= max_traits
+								&& values.iter().all(|value| value.len() <= max_metadata)
+						}),
+					Error::<T>::InvalidGenerativeTraits
+				);
+				GenerativeTraits::<T>::insert(class_id, traits);
+			}
+
+			Self::deposit_event(Event::<T>::GenerativeTraitsSet(class_id));
+
+			Ok(().into())
+		}
+
+		/// Class owner or issuer commits to a generative mint. The traits of the NFTs are rolled
+		/// from the runtime randomness when the mint is revealed after `GenerativeRevealDelay`
+		/// blocks, so they cannot be known when the mint is requested.
+		#[pallet::weight(T::WeightInfo::sign_asset())]
+		pub fn request_generative_mint(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			metadata: NftMetadata,
+			quantity: u32,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			Self::ensure_class_role(&sender, &class_info.owner, class_id, ClassRole::Issuer)?;
+			let traits = GenerativeTraits::<T>::get(class_id).ok_or(Error::<T>::GenerativeTraitsNotSet)?;
+			ensure!(!Self::is_collection_locked(&class_id), Error::<T>::CollectionIsLocked);
+			ensure!(quantity >= 1, Error::<T>::InvalidQuantity);
+			ensure!(
+				quantity <= T::MaxBatchMinting::get(),
+				Error::<T>::ExceedMaximumBatchMinting
+			);
+			ensure!(
+				metadata.len() as u32 <= T::MaxMetadata::get(),
+				Error::<T>::ExceedMaximumMetadataLength
+			);
+
+			let reveal_delay = T::GenerativeRevealDelay::get();
+			ensure!(!reveal_delay.is_zero(), Error::<T>::InvalidRevealDelay);
+			let requested_at = <frame_system::Pallet<T>>::block_number();
+			let reveal_at = requested_at + reveal_delay;
+			GenerativeMintQueue::<T>::try_mutate(reveal_at, |requests| -> DispatchResult {
+				ensure!(
+					requests.len() < MAX_GENERATIVE_REVEALS_PER_BLOCK,
+					Error::<T>::GenerativeRevealQueueFull
+				);
+				requests.push(GenerativeMintRequest {
+					issuer: sender.clone(),
+					class_id,
+					metadata,
+					quantity,
+					traits,
+					requested_at,
+				});
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::GenerativeMintRequested(sender, class_id, reveal_at));
+
+			Ok(().into())
+		}
+
+		/// Gift the NFT to the recipient. The NFT is held in escrow until the recipient claims it,
+		/// or the sender reclaims it once the expiry block has passed.
+		#[pallet::weight(T::WeightInfo::transfer().saturating_add(Pallet::<T>::nested_transfer_weight(&asset_id)))]
+		pub fn gift_nft(
+			origin: OriginFor<T>,
+			asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+			recipient: T::AccountId,
+			expiry: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				sender != recipient && expiry > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidGift
+			);
+			ensure!(
+				Self::check_item_on_listing(asset_id.0, asset_id.1)? == false,
+				Error::<T>::AssetAlreadyInAuction
+			);
+
+			Self::do_transfer(&sender, &Self::gift_escrow_account(), asset_id)?;
+			NftGifts::<T>::insert(asset_id, (sender.clone(), recipient.clone(), expiry));
+
+			Self::deposit_event(Event::<T>::NftGifted(asset_id, sender, recipient, expiry));
+
+			Ok(().into())
+		}
+
+		/// Recipient claims a gifted NFT until the gift expires
+		#[pallet::weight(T::WeightInfo::transfer().saturating_add(Pallet::<T>::nested_transfer_weight(&asset_id)))]
+		pub fn claim_gift(origin: OriginFor<T>, asset_id: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let (_, recipient, expiry) = NftGifts::<T>::get(asset_id).ok_or(Error::<T>::GiftNotFound)?;
+			ensure!(
+				sender == recipient && <frame_system::Pallet<T>>::block_number() <= expiry,
+				Error::<T>::CannotClaimGift
+			);
+
+			Self::do_transfer(&Self::gift_escrow_account(), &sender, asset_id)?;
+			NftGifts::<T>::remove(asset_id);
+
+			Self::deposit_event(Event::<T>::NftGiftClaimed(asset_id, sender));
+
+			Ok(().into())
+		}
+
+		/// Sender reclaims a gifted NFT the recipient did not claim before the expiry
+		#[pallet::weight(T::WeightInfo::transfer().saturating_add(Pallet::<T>::nested_transfer_weight(&asset_id)))]
+		pub fn reclaim_gift(
+			origin: OriginFor<T>,
+			asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let (gifter, _, expiry) = NftGifts::<T>::get(asset_id).ok_or(Error::<T>::GiftNotFound)?;
+			ensure!(
+				sender == gifter && <frame_system::Pallet<T>>::block_number() > expiry,
+				Error::<T>::CannotReclaimGift
+			);
+
+			Self::do_transfer(&Self::gift_escrow_account(), &sender, asset_id)?;
+			NftGifts::<T>::remove(asset_id);
+
+			Self::deposit_event(Event::<T>::NftGiftReclaimed(asset_id, sender));
+
+			Ok(().into())
+		}
+
+		/// Nest an NFT in a parent NFT of the same owner, e.g. wearables in an avatar. The nested
+		/// NFT moves together with its parent until it is unnested.
+		#[pallet::weight(T::WeightInfo::transfer())]
+		pub fn nest_nft(
+			origin: OriginFor<T>,
+			asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+			parent_id: (ClassIdOf<T>, TokenIdOf<T>),
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			Self::do_nest(&sender, asset_id, parent_id)?;
+			Self::deposit_event(Event::<T>::NftNested(asset_id, parent_id));
+
+			Ok(().into())
+		}
+
+		/// Take a nested NFT out of its parent, the owner can then trade it on its own again
+		#[pallet::weight(T::WeightInfo::transfer())]
+		pub fn unnest_nft(origin: OriginFor<T>, asset_id: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(!EquippedSlots::<T>::contains_key(asset_id), Error::<T>::ItemIsEquipped);
+			let parent_id = Self::do_unnest(&sender, asset_id)?;
+			Self::deposit_event(Event::<T>::NftUnnested(asset_id, parent_id));
+
+			Ok(().into())
+		}
+
+		/// Equip a wearable NFT in a slot of an avatar NFT of the same owner. The wearable is
+		/// nested in the avatar, it moves with the avatar and cannot be transferred on its own
+		/// until unequipped.
+		#[pallet::weight(T::WeightInfo::transfer())]
+		pub fn equip_item(
+			origin: OriginFor<T>,
+			avatar_id: (ClassIdOf<T>, TokenIdOf<T>),
+			wearable_id: (ClassIdOf<T>, TokenIdOf<T>),
+			slot: WearableSlot,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let wearable_class = NftModule::<T>::classes(wearable_id.0).ok_or(Error::<T>::ClassIdNotFound)?;
+			let avatar_class = NftModule::<T>::classes(avatar_id.0).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(
+				wearable_class.data.collection_type.is_wearable() && !avatar_class.data.collection_type.is_wearable(),
+				Error::<T>::InvalidWearable
+			);
+			ensure!(
+				!EquippedItems::<T>::contains_key(avatar_id, slot),
+				Error::<T>::SlotAlreadyEquipped
+			);
+
+			Self::do_nest(&sender, wearable_id, avatar_id)?;
+			EquippedItems::<T>::insert(avatar_id, slot, wearable_id);
+			EquippedSlots::<T>::insert(wearable_id, slot);
+			Self::deposit_event(Event::<T>::ItemEquipped(avatar_id, wearable_id, slot));
+
+			Ok(().into())
+		}
+
+		/// Take the wearable NFT equipped in the slot off the avatar NFT
+		#[pallet::weight(T::WeightInfo::transfer())]
+		pub fn unequip_item(
+			origin: OriginFor<T>,
+			avatar_id: (ClassIdOf<T>, TokenIdOf<T>),
+			slot: WearableSlot,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let wearable_id = EquippedItems::<T>::get(avatar_id, slot).ok_or(Error::<T>::SlotNotEquipped)?;
+			Self::do_unnest(&sender, wearable_id)?;
+			EquippedItems::<T>::remove(avatar_id, slot);
+			EquippedSlots::<T>::remove(wearable_id);
+			Self::deposit_event(Event::<T>::ItemUnequipped(avatar_id, wearable_id, slot));
+
+			Ok(().into())
+		}
+
+		/// Import a collection migrated from another chain, e.g. Statemine uniques or RMRK, as a
+		/// local class of the owner. Source formats are converted off-chain, no class deposit is
+		/// taken.
+		#[pallet::weight(T::WeightInfo::create_class())]
+		pub fn import_class(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			collection_id: GroupCollectionId,
+			metadata: NftMetadata,
+			attributes: Attributes,
+			token_type: TokenType,
+			collection_type: CollectionType,
+			royalty_fee: Perbill,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			ensure!(
+				metadata.len() as u32 <= T::MaxMetadata::get(),
+				Error::<T>::ExceedMaximumMetadataLength
+			);
+			ensure!(
+				GroupCollections::<T>::contains_key(collection_id),
+				Error::<T>::CollectionDoesNotExist
+			);
+			ensure!(
+				royalty_fee <= Perbill::from_percent(25u32),
+				Error::<T>::RoyaltyFeeExceedLimit
+			);
+
+			let class_data = NftClassData {
+				deposit: Zero::zero(),
+				token_type,
+				collection_type,
+				attributes,
+				is_locked: false,
+				royalty_fee,
+			};
+			let class_id = NftModule::<T>::create_class(&owner, metadata, class_data)?;
+			ClassDataCollection::<T>::insert(class_id, collection_id);
+
+			Self::deposit_event(Event::<T>::ClassImported(class_id, owner));
+
+			Ok(().into())
+		}
+
+		/// Import NFTs of a migrated collection with their original token ids, owners, metadata
+		/// and attributes. Token ids must be ascending from the next token id of the class, gaps
+		/// left by NFTs burned on the source chain are skipped. No minting fee is taken.
+		#[pallet::weight(T::WeightInfo::mint_batch(tokens.len() as u32) + Pallet::<T>::rarity_index_weight(tokens.len() as u32))]
+		#[transactional]
+		pub fn import_tokens(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			tokens: Vec<(TokenIdOf<T>, T::AccountId, NftMetadata, Attributes)>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			let first_token_id = NftModule::<T>::next_token_id(class_id);
+			let last_token_id = tokens.last().map(|token| token.0).ok_or(Error::<T>::InvalidQuantity)?;
+			ensure!(
+				last_token_id >= first_token_id
+					&& last_token_id - first_token_id < T::MaxBatchMinting::get().into()
+					&& tokens.windows(2).all(|pair| pair[0].0 < pair[1].0),
+				Error::<T>::InvalidImportedTokenIds
+			);
+			// Placeholders of NFTs burned on the source chain count towards the supply cap
+			Self::ensure_within_max_supply(class_id, last_token_id - first_token_id + One::one())?;
+			ensure!(
+				tokens
+					.iter()
+					.all(|(_, _, metadata, _)| metadata.len() as u32 <= T::MaxMetadata::get()),
+				Error::<T>::ExceedMaximumMetadataLength
+			);
+
+			let quantity = tokens.len() as u32;
+			for (token_id, owner, metadata, attributes) in tokens {
+				// Burned placeholders keep the token ids of the source collection
+				while NftModule::<T>::next_token_id(class_id) < token_id {
+					let placeholder_data = NftAssetData {
+						deposit: Zero::zero(),
+						attributes: Default::default(),
+					};
+					let placeholder_id =
+						NftModule::<T>::mint(&class_info.owner, class_id, Default::default(), placeholder_data)?;
+					NftModule::<T>::burn(&class_info.owner, (class_id, placeholder_id))?;
+				}
+				ensure!(
+					NftModule::<T>::next_token_id(class_id) == token_id,
+					Error::<T>::InvalidImportedTokenIds
+				);
+
+				Self::update_attribute_frequencies(class_id, &attributes, 1, true);
+				let token_data = NftAssetData {
+					deposit: Zero::zero(),
+					attributes,
+				};
+				NftModule::<T>::mint(&owner, class_id, metadata, token_data)?;
+			}
+
+			Self::deposit_event(Event::<T>::TokensImported(class_id, quantity));
+
+			Ok(().into())
+		}
+
+		/// Class owner caps the supply of the class. The cap can be lowered down to the number of
+		/// NFTs minted in the class but never raised.
+		#[pallet::weight(T::WeightInfo::sign_asset())]
+		pub fn set_max_supply(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			max_supply: TokenIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(sender == class_info.owner, Error::<T>::NotClassOwner);
+			ensure!(
+				max_supply >= NftModule::<T>::next_token_id(class_id)
+					&& Self::get_class_max_supply(class_id).map_or(true, |current| max_supply <= current),
+				Error::<T>::InvalidMaxSupply
+			);
+
+			ClassMaxSupply::<T>::insert(class_id, max_supply);
+			Self::deposit_event(Event::<T>::MaxSupplySet(class_id, max_supply));
+
+			Ok(().into())
+		}
+
+		/// Class owner or issuer replaces the public mint phases of the class, resetting the NFTs
+		/// counted against the per account limits
+		#[pallet::weight(T::WeightInfo::sign_asset())]
+		pub fn set_mint_phases(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			phases: Vec<MintPhase<T::BlockNumber, BalanceOf<T>>>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			Self::ensure_class_role(&sender, &class_info.owner, class_id, ClassRole::Issuer)?;
+			ensure!(
+				phases.len() <= MAX_MINT_PHASES && phases.iter().all(|phase| phase.start < phase.end),
+				Error::<T>::InvalidMintPhases
+			);
+
+			PhaseMints::<T>::remove_prefix(class_id, None);
+			MintPhases::<T>::insert(class_id, phases);
+			Self::deposit_event(Event::<T>::MintPhasesSet(class_id));
+
+			Ok(().into())
+		}
+
+		/// Mint NFTs of the class in its open mint phase, paying the phase price to the class
+		/// owner. The NFTs are minted without metadata, which the class admin reveals later.
+		#[pallet::weight(T::WeightInfo::mint_batch(*quantity) + Pallet::<T>::rarity_index_weight(*quantity))]
+		#[transactional]
+		pub fn phase_mint(origin: OriginFor<T>, class_id: ClassIdOf<T>, quantity: u32) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let (phase_index, phase) = Self::get_mint_phases(class_id)
+				.into_iter()
+				.enumerate()
+				.find(|(_, phase)| phase.start <= now && now < phase.end)
+				.ok_or(Error::<T>::NoActiveMintPhase)?;
+			let phase_index = phase_index as u32;
+
+			let minted = Self::get_phase_mints(class_id, (phase_index, &sender)).saturating_add(quantity);
+			ensure!(minted <= phase.per_account_limit, Error::<T>::ExceedMintPhaseLimit);
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			let price = phase.price.saturating_mul(Into::<BalanceOf<T>>::into(quantity));
+			<T as Config>::Currency::transfer(&sender, &class_info.owner, price, ExistenceRequirement::KeepAlive)?;
+
+			let minting_outcome = Self::do_mint_nfts(
+				&class_info.owner,
+				class_id,
+				Default::default(),
+				Default::default(),
+				quantity,
+			)?;
+			for asset_id in minting_outcome.0.iter() {
+				Self::transfer_token(&class_info.owner, &sender, *asset_id)?;
+			}
+			PhaseMints::<T>::insert(class_id, (phase_index, &sender), minted);
+
+			Self::deposit_event(Event::<T>::PhaseMinted(
+				sender,
+				phase_index,
+				*minting_outcome.0.first().unwrap(),
+				*minting_outcome.0.last().unwrap(),
+			));
+
+			Ok(().into())
+		}
+
+		/// Rent the NFT out to the user until the expiry block. The owner keeps ownership, the user
+		/// role ends at expiry or when the NFT is transferred.
+		#[pallet::weight(T::WeightInfo::sign_asset())]
+		pub fn set_nft_user(
+			origin: OriginFor<T>,
+			asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+			user: T::AccountId,
+			expires: T::BlockNumber,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				Self::check_nft_ownership(&sender, &asset_id)?,
+				Error::<T>::NotAssetOwner
+			);
+			ensure!(!Self::is_nft_locked(&asset_id), Error::<T>::NftIsLocked);
+			ensure!(
+				expires > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidRentalExpiry
+			);
+			if let Some(current_user) = Self::current_user(&asset_id) {
+				ensure!(current_user == user, Error::<T>::NftAlreadyRented);
+			}
+
+			NftUsers::<T>::insert(asset_id, (user.clone(), expires));
+			Self::deposit_event(Event::<T>::NftUserSet(asset_id, user, expires));
+
+			Ok(().into())
+		}
+
+		/// Class owner opts in to metadata updates of the class and its NFTs. Only allowed before
+		/// the first NFT is minted, so holders always know whether their NFT can change.
+		#[pallet::weight(T::WeightInfo::enable_metadata_updates())]
+		pub fn enable_metadata_updates(origin: OriginFor<T>, class_id: ClassIdOf<T>) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(sender == class_info.owner, Error::<T>::NotClassOwner);
+			ensure!(
+				!FrozenClasses::<T>::contains_key(class_id),
+				Error::<T>::MetadataIsFrozen
+			);
+			ensure!(
+				NftModule::<T>::next_token_id(class_id).is_zero(),
+				Error::<T>::ClassAlreadyMinted
+			);
+
+			MutableMetadataClasses::<T>::insert(class_id, ());
+			Self::deposit_event(Event::<T>::MetadataUpdatesEnabled(class_id));
+
+			Ok(().into())
+		}
+
+		/// Class owner or admin updates the class metadata, until the class metadata is frozen
+		#[pallet::weight(T::WeightInfo::update_class_metadata())]
+		pub fn update_class_metadata(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			metadata: NftMetadata,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(!Self::is_class_metadata_frozen(&class_id), Error::<T>::MetadataIsFrozen);
+			ensure!(
+				metadata.len() as u32 <= T::MaxMetadata::get(),
+				Error::<T>::ExceedMaximumMetadataLength
+			);
+			Classes::<T>::try_mutate(class_id, |class_info| -> DispatchResult {
+				let class_info = class_info.as_mut().ok_or(Error::<T>::ClassIdNotFound)?;
+				Self::ensure_class_role(&sender, &class_info.owner, class_id, ClassRole::Admin)?;
+				class_info.metadata = metadata
+					.try_into()
+					.map_err(|_| Error::<T>::ExceedMaximumMetadataLength)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::ClassMetadataUpdated(class_id));
+
+			Ok(().into())
+		}
+
+		/// Class owner or admin updates the metadata and attributes of an NFT, until its metadata or
+		/// the class metadata is frozen
+		#[pallet::weight(T::WeightInfo::update_token_metadata() + Pallet::<T>::rarity_index_weight(2))]
+		pub fn update_token_metadata(
+			origin: OriginFor<T>,
+			asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+			metadata: NftMetadata,
+			attributes: Attributes,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(!Self::is_metadata_frozen(&asset_id), Error::<T>::MetadataIsFrozen);
+			ensure!(
+				metadata.len() as u32 <= T::MaxMetadata::get(),
+				Error::<T>::ExceedMaximumMetadataLength
+			);
+			let class_info = NftModule::<T>::classes(asset_id.0).ok_or(Error::<T>::ClassIdNotFound)?;
+			Self::ensure_class_role(&sender, &class_info.owner, asset_id.0, ClassRole::Admin)?;
+
+			Tokens::<T>::try_mutate(asset_id.0, asset_id.1, |token_info| -> DispatchResult {
+				let token_info = token_info.as_mut().ok_or(Error::<T>::AssetInfoNotFound)?;
+				token_info.metadata = metadata
+					.try_into()
+					.map_err(|_| Error::<T>::ExceedMaximumMetadataLength)?;
+				Self::update_attribute_frequencies(asset_id.0, &token_info.data.attributes, 1, false);
+				Self::update_attribute_frequencies(asset_id.0, &attributes, 1, true);
+				token_info.data.attributes = attributes;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::TokenMetadataUpdated(asset_id));
+
+			Ok(().into())
+		}
+
+		/// Class owner or admin sets a typed attribute of an NFT, or removes it when the value is
+		/// None, until the metadata is frozen
+		#[pallet::weight(T::WeightInfo::sign_asset())]
+		pub fn set_typed_attribute(
+			origin: OriginFor<T>,
+			asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+			key: Vec<u8>,
+			value: Option<AttributeValue>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(!Self::is_metadata_frozen(&asset_id), Error::<T>::MetadataIsFrozen);
+			let class_info = NftModule::<T>::classes(asset_id.0).ok_or(Error::<T>::ClassIdNotFound)?;
+			Self::ensure_class_role(&sender, &class_info.owner, asset_id.0, ClassRole::Admin)?;
+			ensure!(
+				Tokens::<T>::contains_key(asset_id.0, asset_id.1),
+				Error::<T>::AssetInfoNotFound
+			);
+			ensure!(
+				key.len() as u32 <= T::MaxMetadata::get(),
+				Error::<T>::ExceedMaximumMetadataLength
+			);
+
+			match &value {
+				Some(attribute_value) => {
+					if let AttributeValue::Text(text) = attribute_value {
+						ensure!(
+							text.len() as u32 <= T::MaxMetadata::get(),
+							Error::<T>::ExceedMaximumMetadataLength
+						);
+					}
+					if !TypedAttributes::<T>::contains_key(asset_id, &key) {
+						ensure!(
+							(TypedAttributes::<T>::iter_prefix(asset_id).count() as u32) < T::MaxTypedAttributes::get(),
+							Error::<T>::ExceedMaximumTypedAttributes
+						);
+					}
+					TypedAttributes::<T>::insert(asset_id, &key, attribute_value);
+				}
+				None => TypedAttributes::<T>::remove(asset_id, &key),
+			}
+
+			Self::deposit_event(Event::<T>::TypedAttributeSet(asset_id, key, value));
+
+			Ok(().into())
+		}
+
+		/// Class owner or freezer freezes the metadata of an NFT, or of the class and all its NFTs
+		/// when no token is given. Frozen metadata can never be updated again.
+		#[pallet::weight(T::WeightInfo::freeze_metadata())]
+		pub fn freeze_metadata(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			token_id: Option<TokenIdOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			Self::ensure_class_role(&sender, &class_info.owner, class_id, ClassRole::Freezer)?;
+
+			match token_id {
+				Some(token_id) => {
+					ensure!(
+						!Self::is_metadata_frozen(&(class_id, token_id)),
+						Error::<T>::MetadataIsFrozen
+					);
+					ensure!(
+						Tokens::<T>::contains_key(class_id, token_id),
+						Error::<T>::AssetInfoNotFound
+					);
+					FrozenTokens::<T>::insert((class_id, token_id), ());
+				}
+				None => {
+					ensure!(!Self::is_class_metadata_frozen(&class_id), Error::<T>::MetadataIsFrozen);
+					FrozenClasses::<T>::insert(class_id, ());
+				}
+			}
+
+			Self::deposit_event(Event::<T>::MetadataFrozen(class_id, token_id));
+
+			Ok(().into())
+		}
+
+		/// Map a foreign NFT collection to the local class minting its derivatives when they arrive
+		/// over XCM, or remove the mapping if the class is None
+		#[pallet::weight(T::WeightInfo::sign_asset())]
+		pub fn register_foreign_class(
+			origin: OriginFor<T>,
+			location: Box<MultiLocation>,
+			class_id: Option<ClassIdOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+
+			match class_id {
+				Some(class_id) => {
+					ensure!(NftModule::<T>::classes(class_id).is_some(), Error::<T>::ClassIdNotFound);
+					ensure!(
+						!ForeignClasses::<T>::contains_key(location.as_ref())
+							&& !ForeignClassLocations::<T>::contains_key(class_id),
+						Error::<T>::ForeignClassAlreadyRegistered
+					);
+					ForeignClasses::<T>::insert(location.as_ref(), class_id);
+					ForeignClassLocations::<T>::insert(class_id, location.as_ref());
+				}
+				None => {
+					let class_id =
+						ForeignClasses::<T>::take(location.as_ref()).ok_or(Error::<T>::ForeignClassNotRegistered)?;
+					ForeignClassLocations::<T>::remove(class_id);
+				}
+			}
+
+			Self::deposit_event(Event::<T>::ForeignClassRegistered(*location, class_id));
+
+			Ok(().into())
+		}
+
+		/// Class owner or issuer publishes an airdrop, replacing any previous one. The merkle root
+		/// commits to the (account, quantity) allocations, minting fees are paid by the class owner
+		/// as they are claimed.
+		#[pallet::weight(T::WeightInfo::sign_asset())]
+		pub fn publish_airdrop(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			merkle_root: T::Hash,
+			metadata: NftMetadata,
+			attributes: Attributes,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			Self::ensure_class_role(&sender, &class_info.owner, class_id, ClassRole::Issuer)?;
+			ensure!(
+				metadata.len() as u32 <= T::MaxMetadata::get(),
+				Error::<T>::ExceedMaximumMetadataLength
+			);
+
+			Airdrops::<T>::insert(
+				class_id,
+				NftAirdrop {
+					merkle_root,
+					metadata,
+					attributes,
+				},
+			);
+			Self::deposit_event(Event::<T>::AirdropPublished(class_id, merkle_root));
+
+			Ok(().into())
+		}
+
+		/// Claim the airdrop allocation of the sender, proving the (account, quantity) leaf is
+		/// under the airdrop merkle root
+		#[pallet::weight(< T as Config >::WeightInfo::mint_batch(* quantity) + < T as Config >::WeightInfo::transfer_batch(* quantity) + Pallet::<T>::rarity_index_weight(* quantity))]
+		#[transactional]
+		pub fn claim_airdrop(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			quantity: u32,
+			proof: Vec<T::Hash>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+
+			let airdrop = Self::get_airdrop(class_id).ok_or(Error::<T>::AirdropNotFound)?;
+			ensure!(
+				!AirdropClaims::<T>::contains_key(airdrop.merkle_root, &sender),
+				Error::<T>::AirdropAlreadyClaimed
+			);
+			ensure!(
+				proof.len() <= MAX_AIRDROP_PROOF_LENGTH
+					&& Self::airdrop_proof_root(&sender, quantity, &proof) == airdrop.merkle_root,
+				Error::<T>::InvalidAirdropProof
+			);
+
+			let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			let minting_outcome = Self::do_mint_nfts(
+				&class_info.owner,
+				class_id,
+				airdrop.metadata,
+				airdrop.attributes,
+				quantity,
+			)?;
+			for asset_id in minting_outcome.0.iter() {
+				Self::transfer_token(&class_info.owner, &sender, *asset_id)?;
+			}
+			AirdropClaims::<T>::insert(airdrop.merkle_root, &sender, ());
+
+			Self::deposit_event(Event::<T>::AirdropClaimed(
+				sender,
+				*minting_outcome.0.first().unwrap(),
+				*minting_outcome.0.last().unwrap(),
+			));
+
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::sign_asset())]
+		pub fn force_lock_collection(origin: OriginFor<T>, class_id: ClassIdOf<T>) -> DispatchResult {
+			ensure_root(origin)?;
+
 			ensure!(
 				!LockedCollection::<T>::contains_key(class_id),
 				Error::<T>::CollectionAlreadyLocked
@@ -556,7 +2105,7 @@ pub mod pallet {
 		}
 
 		/// Force NFT transfer which only triggered by governance
-		#[pallet::weight(T::WeightInfo::transfer())]
+		#[pallet::weight(T::WeightInfo::transfer().saturating_add(Pallet::<T>::nested_transfer_weight(&asset_id)))]
 		pub fn force_transfer(
 			origin: OriginFor<T>,
 			from: T::AccountId,
@@ -579,14 +2128,85 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let cleared_records =
+				match TransferredInExtrinsic::<T>::remove_all(Some(MAX_TRANSFER_RECORDS_CLEARED_PER_BLOCK)) {
+					KillStorageResult::AllRemoved(cleared) | KillStorageResult::SomeRemaining(cleared) => cleared,
+				};
+			let mut weight = T::DbWeight::get().writes((cleared_records as Weight).saturating_add(1));
+
+			for (index, request) in GenerativeMintQueue::<T>::take(now).into_iter().enumerate() {
+				let (seed, known_since) =
+					T::Randomness::random(&(GENERATIVE_MINT_SUBJECT, request.class_id, index as u32).encode());
+				if known_since <= request.requested_at {
+					// Randomness could be known when the mint was requested, reveal it next block
+					weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+					let issuer = request.issuer.clone();
+					let class_id = request.class_id;
+					let deferred = GenerativeMintQueue::<T>::try_mutate(now + One::one(), |requests| {
+						if requests.len() >= MAX_GENERATIVE_REVEALS_PER_BLOCK {
+							return Err(());
+						}
+						requests.push(request);
+						Ok(())
+					});
+					if deferred.is_err() {
+						Self::deposit_event(Event::<T>::GenerativeMintFailed(
+							issuer,
+							class_id,
+							Error::<T>::GenerativeRevealQueueFull.into(),
+						));
+					}
+					continue;
+				}
+
+				weight = weight.saturating_add(T::WeightInfo::mint_batch(request.quantity));
+				match Self::do_reveal_generative_mint(&request, seed) {
+					Ok((asset_ids, _)) => Self::deposit_event(Event::<T>::GenerativeMintRevealed(
+						request.issuer,
+						*asset_ids.first().unwrap(),
+						*asset_ids.last().unwrap(),
+					)),
+					Err(error) => Self::deposit_event(Event::<T>::GenerativeMintFailed(
+						request.issuer,
+						request.class_id,
+						error,
+					)),
+				}
+			}
+			weight.saturating_add(T::DbWeight::get().writes(1))
+		}
+
 		fn on_runtime_upgrade() -> Weight {
 			Self::upgrade_class_data_v2();
-			0
+			let on_chain_version = Self::on_chain_storage_version();
+			if on_chain_version < 1 {
+				let weight = Self::upgrade_rarity_index_v1();
+				STORAGE_VERSION.put::<Pallet<T>>();
+				return weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+			}
+			T::DbWeight::get().reads(1)
 		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Move the NFT to the new owner and notify the transfer handler
+	fn transfer_token(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+	) -> DispatchResult {
+		NftModule::<T>::transfer(from, to, asset_id)?;
+		T::OnNftTransfer::on_nft_transfer(from, to, &asset_id);
+		Ok(())
+	}
+
+	/// Check if the account has minted an NFT
+	pub fn has_minted_nft(who: &T::AccountId) -> bool {
+		NftMintedAccounts::<T>::contains_key(who)
+	}
+
 	pub fn is_promotion_enabled() -> bool {
 		Self::get_promotion_enabled()
 	}
@@ -595,6 +2215,11 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_sub_account(class_id)
 	}
 
+	/// Account holding gifted NFTs until they are claimed or reclaimed
+	pub fn gift_escrow_account() -> T::AccountId {
+		T::PalletId::get().into_sub_account(GIFT_ESCROW_SUB_ACCOUNT)
+	}
+
 	fn do_create_group_collection(name: Vec<u8>, properties: Vec<u8>) -> Result<GroupCollectionId, DispatchError> {
 		let next_group_collection_id =
 			NextGroupCollectionId::<T>::try_mutate(|collection_id| -> Result<GroupCollectionId, DispatchError> {
@@ -614,12 +2239,41 @@ impl<T: Config> Pallet<T> {
 		Ok(next_group_collection_id)
 	}
 
+	/// Transfer one item of a batch, returns false if the item was skipped
+	fn do_transfer_batch_item(
+		sender: &T::AccountId,
+		to: &T::AccountId,
+		asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+	) -> Result<bool, DispatchError> {
+		let class_info = NftModule::<T>::classes(asset_id.0).ok_or(Error::<T>::ClassIdNotFound)?;
+
+		// Non transferable assets are skipped
+		if let TokenType::Transferable = class_info.data.token_type {
+			let asset_info = NftModule::<T>::tokens(asset_id.0, asset_id.1).ok_or(Error::<T>::AssetInfoNotFound)?;
+			ensure!(*sender == asset_info.owner, Error::<T>::NotAssetOwner);
+			ensure!(!Self::is_nft_locked(&asset_id), Error::<T>::NftIsLocked);
+			ensure!(!NftParents::<T>::contains_key(asset_id), Error::<T>::NftIsNested);
+			Self::ensure_not_transferred_in_extrinsic(&asset_id)?;
+
+			Self::transfer_token(sender, to, asset_id)?;
+			NftUsers::<T>::remove(asset_id);
+			TokenApprovals::<T>::remove(asset_id);
+			Self::transfer_nested_children(sender, to, &asset_id)?;
+			Self::note_transfer_in_extrinsic(asset_id);
+			return Ok(true);
+		}
+
+		Ok(false)
+	}
+
+	#[transactional]
 	pub fn do_transfer(
 		sender: &T::AccountId,
 		to: &T::AccountId,
 		asset_id: (ClassIdOf<T>, TokenIdOf<T>),
 	) -> Result<<T as orml_nft::Config>::TokenId, DispatchError> {
 		ensure!(!Self::is_collection_locked(&asset_id.0), Error::<T>::CollectionIsLocked);
+		ensure!(!Self::is_nft_locked(&asset_id), Error::<T>::NftIsLocked);
 
 		let class_info = NftModule::<T>::classes(asset_id.0).ok_or(Error::<T>::ClassIdNotFound)?;
 		let data = class_info.data;
@@ -627,15 +2281,33 @@ impl<T: Config> Pallet<T> {
 		match data.token_type {
 			TokenType::Transferable => {
 				let check_ownership = Self::check_nft_ownership(&sender, &asset_id)?;
-				ensure!(check_ownership, Error::<T>::NoPermission);
+				ensure!(check_ownership, Error::<T>::NotAssetOwner);
+				Self::ensure_not_transferred_in_extrinsic(&asset_id)?;
 
-				NftModule::<T>::transfer(&sender, &to, asset_id.clone())?;
+				ensure!(!NftParents::<T>::contains_key(asset_id), Error::<T>::NftIsNested);
+
+				Self::transfer_token(&sender, &to, asset_id.clone())?;
+				NftUsers::<T>::remove(asset_id);
+				TokenApprovals::<T>::remove(asset_id);
+				Self::transfer_nested_children(&sender, &to, &asset_id)?;
+				Self::note_transfer_in_extrinsic(asset_id);
 				Ok(asset_id.1)
 			}
 			TokenType::BoundToAddress => Err(Error::<T>::NonTransferable.into()),
 		}
 	}
 
+	/// Whether the account is the owner, or is approved by the owner to transfer the NFT
+	pub fn is_approved_or_owner(
+		who: &T::AccountId,
+		owner: &T::AccountId,
+		asset_id: &(ClassIdOf<T>, TokenIdOf<T>),
+	) -> bool {
+		who == owner
+			|| OperatorApprovals::<T>::contains_key(owner, who)
+			|| TokenApprovals::<T>::get(asset_id).as_ref() == Some(who)
+	}
+
 	pub fn check_nft_ownership(
 		sender: &T::AccountId,
 		asset_id: &(ClassIdOf<T>, TokenIdOf<T>),
@@ -648,12 +2320,76 @@ impl<T: Config> Pallet<T> {
 		return Ok(false);
 	}
 
+	/// Reject a second transfer of the same NFT within one extrinsic. Transfers made outside of
+	/// extrinsics, e.g. in block hooks, are not restricted.
+	fn ensure_not_transferred_in_extrinsic(asset_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+		if let Some(extrinsic_index) = <frame_system::Pallet<T>>::extrinsic_index() {
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				Self::get_transferred_in_extrinsic(asset_id) != Some((now, extrinsic_index)),
+				Error::<T>::AlreadyTransferredInExtrinsic
+			);
+		}
+		Ok(())
+	}
+
+	fn note_transfer_in_extrinsic(asset_id: (ClassIdOf<T>, TokenIdOf<T>)) {
+		if let Some(extrinsic_index) = <frame_system::Pallet<T>>::extrinsic_index() {
+			let now = <frame_system::Pallet<T>>::block_number();
+			TransferredInExtrinsic::<T>::insert(asset_id, (now, extrinsic_index));
+		}
+	}
+
 	/// Check if the NFT collection is locked
 	pub fn is_collection_locked(class_id: &ClassIdOf<T>) -> bool {
 		let is_locked = LockedCollection::<T>::get(class_id).is_some();
 		return is_locked;
 	}
 
+	/// Current user of a rented NFT, None if it is not rented out or the rental has expired
+	pub fn current_user(asset_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> Option<T::AccountId> {
+		let (user, expires) = Self::get_nft_user(asset_id)?;
+		if <frame_system::Pallet<T>>::block_number() < expires {
+			Some(user)
+		} else {
+			None
+		}
+	}
+
+	/// Merkle root computed from the (account, quantity) airdrop leaf and its proof, pairs are
+	/// hashed in ascending order
+	pub fn airdrop_proof_root(who: &T::AccountId, quantity: u32, proof: &[T::Hash]) -> T::Hash {
+		proof
+			.iter()
+			.fold(T::Hashing::hash_of(&(who, quantity)), |node, sibling| {
+				if node <= *sibling {
+					T::Hashing::hash_of(&(node, sibling))
+				} else {
+					T::Hashing::hash_of(&(sibling, node))
+				}
+			})
+	}
+
+	/// Typed attributes of the NFT with their keys
+	pub fn typed_attributes(asset_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> Vec<(Vec<u8>, AttributeValue)> {
+		TypedAttributes::<T>::iter_prefix(asset_id).collect()
+	}
+
+	/// Check if the metadata of the NFT is frozen, either on its own or with its class
+	pub fn is_metadata_frozen(asset_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> bool {
+		Self::is_class_metadata_frozen(&asset_id.0) || FrozenTokens::<T>::contains_key(asset_id)
+	}
+
+	/// Check if the class metadata is frozen, classes which never opted in to updates are frozen
+	pub fn is_class_metadata_frozen(class_id: &ClassIdOf<T>) -> bool {
+		!MutableMetadataClasses::<T>::contains_key(class_id) || FrozenClasses::<T>::contains_key(class_id)
+	}
+
+	/// Check if the NFT is locked by another pallet or frozen by governance
+	pub fn is_nft_locked(asset_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> bool {
+		LockedNfts::<T>::contains_key(asset_id) || FrozenNfts::<T>::contains_key(asset_id)
+	}
+
 	/// Force transfer NFT only for governance override action
 	fn do_force_transfer(
 		sender: &T::AccountId,
@@ -662,10 +2398,161 @@ impl<T: Config> Pallet<T> {
 	) -> Result<<T as orml_nft::Config>::TokenId, DispatchError> {
 		ensure!(!Self::is_collection_locked(&asset_id.0), Error::<T>::CollectionIsLocked);
 
-		NftModule::<T>::transfer(&sender, &to, asset_id.clone())?;
+		// Governance may move a nested NFT on its own, detaching it from the parent
+		if let Some(parent_id) = NftParents::<T>::take(asset_id) {
+			NftChildren::<T>::remove(parent_id, asset_id);
+			if let Some(slot) = EquippedSlots::<T>::take(asset_id) {
+				EquippedItems::<T>::remove(parent_id, slot);
+			}
+		}
+		Self::transfer_token(&sender, &to, asset_id.clone())?;
+		NftUsers::<T>::remove(asset_id);
+		TokenApprovals::<T>::remove(asset_id);
+		Self::transfer_nested_children(&sender, &to, &asset_id)?;
 		Ok(asset_id.1)
 	}
 
+	/// Nest an NFT in a parent NFT of the same owner
+	fn do_nest(
+		sender: &T::AccountId,
+		asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+		parent_id: (ClassIdOf<T>, TokenIdOf<T>),
+	) -> DispatchResult {
+		ensure!(Self::check_nft_ownership(sender, &asset_id)?, Error::<T>::NotAssetOwner);
+		ensure!(
+			Self::check_nft_ownership(sender, &parent_id)?,
+			Error::<T>::NotAssetOwner
+		);
+		ensure!(!NftParents::<T>::contains_key(asset_id), Error::<T>::NftIsNested);
+		ensure!(Self::is_transferable(&asset_id)?, Error::<T>::NonTransferable);
+		ensure!(
+			asset_id != parent_id && !Self::is_nested_in(&parent_id, &asset_id),
+			Error::<T>::InvalidNesting
+		);
+		Self::ensure_root_not_listed(&parent_id)?;
+		ensure!(
+			Self::check_item_on_listing(asset_id.0, asset_id.1)? == false,
+			Error::<T>::AssetAlreadyInAuction
+		);
+		ensure!(
+			Self::nesting_depth(&parent_id) + 1 + Self::nested_height(&asset_id) <= T::MaxNestingDepth::get(),
+			Error::<T>::ExceedMaximumNestingDepth
+		);
+		// Everything nested under the top level NFT moves with it, bound the whole tree so the
+		// transfer weight stays bounded
+		let root_id = Self::nested_root(&parent_id);
+		ensure!(
+			Self::nested_descendant_count(&root_id)
+				.saturating_add(1)
+				.saturating_add(Self::nested_descendant_count(&asset_id))
+				<= T::MaxBatchTransfer::get(),
+			Error::<T>::ExceedMaximumNestedChildren
+		);
+
+		NftParents::<T>::insert(asset_id, parent_id);
+		NftChildren::<T>::insert(parent_id, asset_id, ());
+		Ok(())
+	}
+
+	/// Take a nested NFT out of its parent, returning the parent
+	fn do_unnest(
+		sender: &T::AccountId,
+		asset_id: (ClassIdOf<T>, TokenIdOf<T>),
+	) -> Result<(ClassIdOf<T>, TokenIdOf<T>), DispatchError> {
+		ensure!(Self::check_nft_ownership(sender, &asset_id)?, Error::<T>::NotAssetOwner);
+		let parent_id = Self::get_nft_parent(asset_id).ok_or(Error::<T>::NftIsNotNested)?;
+		Self::ensure_root_not_listed(&parent_id)?;
+
+		NftParents::<T>::remove(asset_id);
+		NftChildren::<T>::remove(parent_id, asset_id);
+		Ok(parent_id)
+	}
+
+	/// Wearables equipped on the avatar NFT by slot
+	pub fn equipped_items(
+		avatar_id: &(ClassIdOf<T>, TokenIdOf<T>),
+	) -> Vec<(WearableSlot, (ClassIdOf<T>, TokenIdOf<T>))> {
+		EquippedItems::<T>::iter_prefix(avatar_id).collect()
+	}
+
+	/// Weight of moving the NFTs nested in the parent along with it
+	pub fn nested_transfer_weight(parent_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> Weight {
+		T::WeightInfo::transfer().saturating_mul(Self::nested_descendant_count(parent_id) as Weight)
+	}
+
+	/// Number of NFTs nested in the NFT, directly or through other NFTs
+	fn nested_descendant_count(asset_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> u32 {
+		NftChildren::<T>::iter_key_prefix(asset_id)
+			.map(|child_id| Self::nested_descendant_count(&child_id).saturating_add(1))
+			.fold(0u32, |total, count| total.saturating_add(count))
+	}
+
+	/// Move the NFTs nested in the parent, and their own nested NFTs, to the new owner
+	fn transfer_nested_children(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		parent_id: &(ClassIdOf<T>, TokenIdOf<T>),
+	) -> DispatchResult {
+		for child_id in NftChildren::<T>::iter_key_prefix(parent_id) {
+			ensure!(!Self::is_nft_locked(&child_id), Error::<T>::NftIsLocked);
+			Self::transfer_token(from, to, child_id)?;
+			NftUsers::<T>::remove(child_id);
+			TokenApprovals::<T>::remove(child_id);
+			Self::transfer_nested_children(from, to, &child_id)?;
+		}
+		Ok(())
+	}
+
+	/// Number of parents above the NFT
+	fn nesting_depth(asset_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> u32 {
+		let mut depth = 0u32;
+		let mut current = *asset_id;
+		while let Some(parent_id) = Self::get_nft_parent(current) {
+			depth = depth.saturating_add(1);
+			current = parent_id;
+		}
+		depth
+	}
+
+	/// Number of nested levels below the NFT
+	fn nested_height(asset_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> u32 {
+		NftChildren::<T>::iter_key_prefix(asset_id)
+			.map(|child_id| Self::nested_height(&child_id).saturating_add(1))
+			.max()
+			.unwrap_or_default()
+	}
+
+	/// Check if the NFT is nested, directly or through other NFTs, in the ancestor
+	fn is_nested_in(asset_id: &(ClassIdOf<T>, TokenIdOf<T>), ancestor_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> bool {
+		let mut current = *asset_id;
+		while let Some(parent_id) = Self::get_nft_parent(current) {
+			if &parent_id == ancestor_id {
+				return true;
+			}
+			current = parent_id;
+		}
+		false
+	}
+
+	/// Top level NFT the NFT is nested in, the NFT itself if it is not nested
+	fn nested_root(asset_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> (ClassIdOf<T>, TokenIdOf<T>) {
+		let mut root_id = *asset_id;
+		while let Some(parent_id) = Self::get_nft_parent(root_id) {
+			root_id = parent_id;
+		}
+		root_id
+	}
+
+	/// Reject changes to the nested NFTs of a top level NFT which is listed for sale
+	fn ensure_root_not_listed(asset_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+		let root_id = Self::nested_root(asset_id);
+		ensure!(
+			Self::check_item_on_listing(root_id.0, root_id.1)? == false,
+			Error::<T>::AssetAlreadyInAuction
+		);
+		Ok(())
+	}
+
 	fn do_mint_nfts(
 		sender: &T::AccountId,
 		class_id: ClassIdOf<T>,
@@ -673,6 +2560,23 @@ impl<T: Config> Pallet<T> {
 		attributes: Attributes,
 		quantity: u32,
 	) -> Result<(Vec<(ClassIdOf<T>, TokenIdOf<T>)>, TokenIdOf<T>), DispatchError> {
+		ensure!(quantity >= 1, Error::<T>::InvalidQuantity);
+		ensure!(
+			quantity <= T::MaxBatchMinting::get(),
+			Error::<T>::ExceedMaximumBatchMinting
+		);
+
+		Self::do_mint_items(sender, class_id, vec![(metadata, attributes); quantity as usize])
+	}
+
+	/// Mint one NFT per metadata and attributes item to the class owner or issuer, charging the
+	/// class owner the minting fee for each
+	fn do_mint_items(
+		sender: &T::AccountId,
+		class_id: ClassIdOf<T>,
+		items: Vec<(NftMetadata, Attributes)>,
+	) -> Result<(Vec<(ClassIdOf<T>, TokenIdOf<T>)>, TokenIdOf<T>), DispatchError> {
+		let quantity = items.len() as u32;
 		ensure!(!Self::is_collection_locked(&class_id), Error::<T>::CollectionIsLocked);
 		ensure!(quantity >= 1, Error::<T>::InvalidQuantity);
 		ensure!(
@@ -680,33 +2584,75 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::ExceedMaximumBatchMinting
 		);
 		ensure!(
-			metadata.len() as u32 <= T::MaxMetadata::get(),
+			items
+				.iter()
+				.all(|(metadata, _)| metadata.len() as u32 <= T::MaxMetadata::get()),
 			Error::<T>::ExceedMaximumMetadataLength
 		);
 
 		let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
-		ensure!(sender.clone() == class_info.owner, Error::<T>::NoPermission);
-		let class_fund: T::AccountId = T::Treasury::get().into_account();
-		let deposit = T::AssetMintingFee::get().saturating_mul(Into::<BalanceOf<T>>::into(quantity));
-		<T as Config>::Currency::transfer(&sender, &class_fund, deposit, ExistenceRequirement::KeepAlive)?;
-
-		let new_nft_data = NftAssetData {
-			deposit,
-			attributes: attributes,
-		};
+		Self::ensure_class_role(sender, &class_info.owner, class_id, ClassRole::Issuer)?;
+		Self::ensure_within_max_supply(class_id, quantity.into())?;
+		let deposit = T::AssetMintingFee::get();
+		let total_deposit = deposit.saturating_mul(Into::<BalanceOf<T>>::into(quantity));
+		Self::hold_deposit(&class_info.owner, total_deposit)?;
 
 		let mut new_asset_ids: Vec<(ClassIdOf<T>, TokenIdOf<T>)> = Vec::new();
 		let mut last_token_id: TokenIdOf<T> = Default::default();
 
-		for _ in 0..quantity {
-			let token_id = NftModule::<T>::mint(&sender, class_id, metadata.clone(), new_nft_data.clone())?;
+		for (metadata, attributes) in items {
+			Self::update_attribute_frequencies(class_id, &attributes, 1, true);
+			let new_nft_data = NftAssetData { deposit, attributes };
+			let token_id = NftModule::<T>::mint(&sender, class_id, metadata, new_nft_data)?;
 			new_asset_ids.push((class_id, token_id));
 
 			last_token_id = token_id;
 		}
+		T::ActivityRecorder::record_activity(ActivityCounter::NftsMinted, quantity);
+		NftMintedAccounts::<T>::insert(sender, ());
 		Ok((new_asset_ids, last_token_id))
 	}
 
+	/// Reject minting the quantity when the NFTs ever minted in the class would exceed its max
+	/// supply. Token ids are never reused, so the next token id counts every NFT minted.
+	fn ensure_within_max_supply(class_id: ClassIdOf<T>, quantity: TokenIdOf<T>) -> DispatchResult {
+		if let Some(max_supply) = Self::get_class_max_supply(class_id) {
+			ensure!(
+				NftModule::<T>::next_token_id(class_id).saturating_add(quantity) <= max_supply,
+				Error::<T>::ExceedMaxSupply
+			);
+		}
+		Ok(())
+	}
+
+	/// Mint the derivative of a foreign NFT arriving over XCM in the class registered for its
+	/// collection. No minting fee is charged, the NFT is backed by its reserve chain.
+	fn do_mint_derivative(
+		who: &T::AccountId,
+		class_id: ClassIdOf<T>,
+		instance: u128,
+	) -> Result<TokenIdOf<T>, DispatchError> {
+		ensure!(!Self::is_collection_locked(&class_id), Error::<T>::CollectionIsLocked);
+		ensure!(
+			!ForeignTokens::<T>::contains_key(class_id, instance),
+			Error::<T>::DerivativeAlreadyMinted
+		);
+		ensure!(NftModule::<T>::classes(class_id).is_some(), Error::<T>::ClassIdNotFound);
+		Self::ensure_within_max_supply(class_id, One::one())?;
+
+		let attributes: Attributes = Default::default();
+		Self::update_attribute_frequencies(class_id, &attributes, 1, true);
+		let token_data = NftAssetData {
+			deposit: Default::default(),
+			attributes,
+		};
+		let token_id = NftModule::<T>::mint(who, class_id, Default::default(), token_data)?;
+		ForeignTokens::<T>::insert(class_id, instance, token_id);
+		ForeignTokenInstances::<T>::insert((class_id, token_id), instance);
+		T::ActivityRecorder::record_activity(ActivityCounter::NftsMinted, 1);
+		Ok(token_id)
+	}
+
 	fn do_create_class(
 		sender: &T::AccountId,
 		metadata: NftMetadata,
@@ -731,13 +2677,9 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::RoyaltyFeeExceedLimit
 		);
 
-		// Class fund
-		let class_fund: T::AccountId = T::Treasury::get().into_account();
-
 		// Secure deposit of token class owner
 		let class_deposit = T::ClassMintingFee::get();
-		// Transfer fund to pot
-		<T as Config>::Currency::transfer(&sender, &class_fund, class_deposit, ExistenceRequirement::KeepAlive)?;
+		Self::hold_deposit(&sender, class_deposit)?;
 
 		let class_data = NftClassData {
 			deposit: class_deposit,
@@ -754,10 +2696,174 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn do_burn(sender: &T::AccountId, asset_id: &(ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+		ensure!(!Self::is_nft_locked(asset_id), Error::<T>::NftIsLocked);
+		ensure!(!NftParents::<T>::contains_key(asset_id), Error::<T>::NftIsNested);
+		ensure!(
+			NftChildren::<T>::iter_prefix(asset_id).next().is_none(),
+			Error::<T>::NftHasChildren
+		);
+		let class_info = NftModule::<T>::classes(asset_id.0).ok_or(Error::<T>::ClassIdNotFound)?;
+		let token_info = NftModule::<T>::tokens(asset_id.0, asset_id.1).ok_or(Error::<T>::AssetInfoNotFound)?;
 		NftModule::<T>::burn(&sender, *asset_id)?;
+		// Tokens minted before deposits were recorded per token carry the deposit of the whole
+		// batch, never release more than the minting fee for them
+		let token_deposit = token_info.data.deposit.min(T::AssetMintingFee::get());
+		Self::release_deposit(&class_info.owner, token_deposit)?;
+		TokenRoyalties::<T>::remove(asset_id);
+		NftUsers::<T>::remove(asset_id);
+		TokenApprovals::<T>::remove(asset_id);
+		FrozenTokens::<T>::remove(asset_id);
+		TypedAttributes::<T>::remove_prefix(asset_id, None);
+		if let Some(instance) = ForeignTokenInstances::<T>::take(asset_id) {
+			ForeignTokens::<T>::remove(asset_id.0, instance);
+		}
+		Self::update_attribute_frequencies(asset_id.0, &token_info.data.attributes, 1, false);
+		Ok(())
+	}
+
+	fn do_burn_class(sender: &T::AccountId, class_id: ClassIdOf<T>) -> DispatchResult {
+		let class_info = NftModule::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+		ensure!(sender.clone() == class_info.owner, Error::<T>::NotClassOwner);
+		ensure!(class_info.total_issuance.is_zero(), Error::<T>::ClassIsNotEmpty);
+
+		NftModule::<T>::destroy_class(&sender, class_id)?;
+		ClassDataCollection::<T>::remove(class_id);
+		LockedCollection::<T>::remove(class_id);
+		FrozenClasses::<T>::remove(class_id);
+		MutableMetadataClasses::<T>::remove(class_id);
+		Airdrops::<T>::remove(class_id);
+		GenerativeTraits::<T>::remove(class_id);
+		ClassMaxSupply::<T>::remove(class_id);
+		MintPhases::<T>::remove(class_id);
+		PhaseMints::<T>::remove_prefix(class_id, None);
+		ClassRoles::<T>::remove_prefix(class_id, None);
+		AttributeFrequencies::<T>::remove_prefix(class_id, None);
+		if let Some(location) = ForeignClassLocations::<T>::take(class_id) {
+			ForeignClasses::<T>::remove(location);
+		}
+		Self::release_deposit(&class_info.owner, class_info.data.deposit)
+	}
+
+	/// Roll the traits snapshotted with a generative mint from the seed and mint the NFTs to the
+	/// issuer
+	#[transactional]
+	fn do_reveal_generative_mint(
+		request: &GenerativeMintRequestOf<T>,
+		seed: T::Hash,
+	) -> Result<(Vec<(ClassIdOf<T>, TokenIdOf<T>)>, TokenIdOf<T>), DispatchError> {
+		ensure!(!request.traits.is_empty(), Error::<T>::GenerativeTraitsNotSet);
+		let items = (0..request.quantity)
+			.map(|item| {
+				let attributes: Attributes = request
+					.traits
+					.iter()
+					.enumerate()
+					.map(|(trait_index, (key, values))| {
+						let roll = T::Hashing::hash_of(&(seed, item, trait_index as u32));
+						let roll = u32::decode(&mut roll.as_ref()).unwrap_or_default() as usize;
+						(key.clone(), values[roll % values.len()].clone())
+					})
+					.collect();
+				(request.metadata.clone(), attributes)
+			})
+			.collect();
+
+		Self::do_mint_items(&request.issuer, request.class_id, items)
+	}
+
+	/// Ensure the account is the class owner or holds the class role
+	fn ensure_class_role(
+		who: &T::AccountId,
+		owner: &T::AccountId,
+		class_id: ClassIdOf<T>,
+		role: ClassRole,
+	) -> DispatchResult {
+		ensure!(
+			who == owner || ClassRoles::<T>::get(class_id, role).as_ref() == Some(who),
+			Error::<T>::NotClassOwner
+		);
+		Ok(())
+	}
+
+	/// Reserve a class or minting deposit on the depositor
+	fn hold_deposit(depositor: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+		<T as Config>::Currency::reserve(depositor, amount)?;
+		ReservedDeposits::<T>::mutate(depositor, |reserved| *reserved = reserved.saturating_add(amount));
 		Ok(())
 	}
 
+	/// Return a class or minting deposit to the depositor. Deposits paid before deposits were
+	/// reserved are still held by the treasury and are paid back from it.
+	fn release_deposit(depositor: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+		let reserved = Self::reserved_deposit_of(depositor);
+		let from_reserve = amount.min(reserved);
+		if !from_reserve.is_zero() {
+			<T as Config>::Currency::unreserve(depositor, from_reserve);
+			ReservedDeposits::<T>::mutate_exists(depositor, |reserved| {
+				let remaining = reserved.unwrap_or_default().saturating_sub(from_reserve);
+				*reserved = if remaining.is_zero() { None } else { Some(remaining) };
+			});
+		}
+
+		let from_treasury = amount.saturating_sub(from_reserve);
+		if from_treasury.is_zero() {
+			return Ok(());
+		}
+		let class_fund: T::AccountId = T::Treasury::get().into_account();
+		<T as Config>::Currency::transfer(&class_fund, depositor, from_treasury, ExistenceRequirement::AllowDeath)
+	}
+
+	/// Count tokens carrying the attributes in the rarity index, only the first
+	/// `MaxRarityAttributes` keys are counted
+	fn update_attribute_frequencies(class_id: ClassIdOf<T>, attributes: &Attributes, count: u32, increase: bool) {
+		for (key, value) in attributes.iter().take(T::MaxRarityAttributes::get() as usize) {
+			AttributeFrequencies::<T>::mutate_exists(class_id, (key.clone(), value.clone()), |frequency| {
+				let current = frequency.unwrap_or_default();
+				let updated = if increase {
+					current.saturating_add(count)
+				} else {
+					current.saturating_sub(count)
+				};
+				*frequency = if updated == 0 { None } else { Some(updated) };
+			});
+		}
+	}
+
+	/// Number of tokens of the class carrying each attribute key and value
+	pub fn class_attribute_frequencies(class_id: ClassIdOf<T>) -> Vec<(Vec<u8>, Vec<u8>, u32)> {
+		AttributeFrequencies::<T>::iter_prefix(class_id)
+			.map(|((key, value), frequency)| (key, value, frequency))
+			.collect()
+	}
+
+	/// Rarity score of the token, the sum over its counted attributes of the class supply divided
+	/// by the number of tokens carrying the attribute. Higher scores are rarer.
+	pub fn token_rarity_score(asset_id: (ClassIdOf<T>, TokenIdOf<T>)) -> Option<u128> {
+		let class_info = NftModule::<T>::classes(asset_id.0)?;
+		let token_info = NftModule::<T>::tokens(asset_id.0, asset_id.1)?;
+		let supply: u128 = class_info.total_issuance.saturated_into();
+
+		let score = token_info
+			.data
+			.attributes
+			.iter()
+			.take(T::MaxRarityAttributes::get() as usize)
+			.fold(0u128, |score, (key, value)| {
+				let frequency = Self::get_attribute_frequency(asset_id.0, (key.clone(), value.clone())) as u128;
+				if frequency == 0 {
+					return score;
+				}
+				score.saturating_add(supply.saturating_mul(RARITY_SCORE_SCALE) / frequency)
+			});
+		Some(score)
+	}
+
 	pub fn upgrade_class_data_v2() -> Weight {
 		log::info!("Start upgrading nft class data v2");
 		let mut num_nft_classes = 0;
@@ -797,6 +2903,48 @@ impl<T: Config> Pallet<T> {
 		log::info!("Classes upgraded: {}", num_nft_classes);
 		0
 	}
+
+	/// Count the attributes of tokens minted before the rarity index was kept, so burning them
+	/// and the class supply stay consistent with the index
+	pub fn upgrade_rarity_index_v1() -> Weight {
+		log::info!("Start backfilling nft rarity index v1");
+		let mut num_tokens: u64 = 0;
+		let mut num_frequencies: u64 = 0;
+
+		// Start from an empty index so the backfill counts every token exactly once
+		let _ = AttributeFrequencies::<T>::remove_all(None);
+		for (class_id, _token_id, token_info) in Tokens::<T>::iter() {
+			num_tokens += 1;
+			num_frequencies += token_info
+				.data
+				.attributes
+				.len()
+				.min(T::MaxRarityAttributes::get() as usize) as u64;
+			Self::update_attribute_frequencies(class_id, &token_info.data.attributes, 1, true);
+		}
+
+		log::info!("Tokens indexed: {}", num_tokens);
+		T::DbWeight::get().reads_writes(
+			num_tokens.saturating_add(num_frequencies),
+			num_frequencies.saturating_add(1),
+		)
+	}
+
+	/// Weight of counting the attributes of `tokens` tokens in the rarity index
+	pub fn rarity_index_weight(tokens: u32) -> Weight {
+		T::DbWeight::get()
+			.reads_writes(1, 1)
+			.saturating_mul(T::MaxRarityAttributes::get().saturating_mul(tokens) as Weight)
+	}
+
+	/// Payload the class owner signs for a mint voucher, the genesis hash binds the voucher to
+	/// this chain
+	pub fn mint_voucher_payload(
+		voucher: &MintVoucher<ClassIdOf<T>, T::AccountId, BalanceOf<T>, T::BlockNumber>,
+	) -> Vec<u8> {
+		let genesis_hash = <frame_system::Pallet<T>>::block_hash(T::BlockNumber::zero());
+		(genesis_hash, voucher).encode()
+	}
 }
 
 impl<T: Config> NFTTrait<T::AccountId, BalanceOf<T>> for Pallet<T> {
@@ -898,10 +3046,54 @@ impl<T: Config> NFTTrait<T::AccountId, BalanceOf<T>> for Pallet<T> {
 	fn is_transferable(nft: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError> {
 		let class_info = NftModule::<T>::classes(nft.0).ok_or(Error::<T>::ClassIdNotFound)?;
 		let data = class_info.data;
-		Ok(data.token_type.is_transferable())
+		Ok(data.token_type.is_transferable() && !Self::is_nft_locked(nft) && !NftParents::<T>::contains_key(nft))
 	}
 
 	fn get_class_fund(class_id: &Self::ClassId) -> T::AccountId {
 		T::PalletId::get().into_sub_account(class_id)
 	}
+
+	fn set_lock_nft(nft: &(Self::ClassId, Self::TokenId), is_locked: bool) -> DispatchResult {
+		ensure!(
+			NftModule::<T>::tokens(nft.0, nft.1).is_some(),
+			Error::<T>::AssetInfoNotFound
+		);
+
+		if is_locked {
+			LockedNfts::<T>::insert(nft, ());
+		} else {
+			LockedNfts::<T>::remove(nft);
+		}
+
+		Ok(())
+	}
+
+	fn set_freeze_nft(nft: &(Self::ClassId, Self::TokenId), is_frozen: bool) -> DispatchResult {
+		ensure!(
+			NftModule::<T>::tokens(nft.0, nft.1).is_some(),
+			Error::<T>::AssetInfoNotFound
+		);
+
+		if is_frozen {
+			FrozenNfts::<T>::insert(nft, ());
+		} else {
+			FrozenNfts::<T>::remove(nft);
+		}
+
+		Ok(())
+	}
+
+	fn holds_class_token(who: &T::AccountId, class_id: &Self::ClassId) -> bool {
+		orml_nft::TokensByOwner::<T>::iter_prefix((who.clone(), *class_id))
+			.next()
+			.is_some()
+	}
+
+	fn get_token_royalty(nft: &(Self::ClassId, Self::TokenId)) -> Option<(T::AccountId, Perbill)> {
+		TokenRoyalties::<T>::get(nft)
+	}
+
+	fn get_typed_attribute(nft: &(Self::ClassId, Self::TokenId), key: &[u8]) -> Option<AttributeValue> {
+		TypedAttributes::<T>::get(nft, key)
+	}
 }
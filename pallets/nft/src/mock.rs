@@ -7,7 +7,8 @@ use frame_system::{EnsureRoot, EnsureSignedBy};
 use orml_traits::parameter_type_with_key;
 use sp_core::H256;
 use sp_runtime::testing::Header;
-use sp_runtime::traits::IdentityLookup;
+use sp_runtime::traits::{IdentityLookup, Lazy};
+use xcm::latest::prelude::*;
 
 use auction_manager::{Auction, AuctionInfo, AuctionType, ListingLevel};
 pub use primitive_traits::{CollectionType, NftAssetData, NftClassData};
@@ -167,6 +168,10 @@ parameter_types! {
 	pub MaxBatchTransfer: u32 = 3;
 	pub MaxBatchMinting: u32 = 10;
 	pub MaxMetadata: u32 = 10;
+	pub MaxRarityAttributes: u32 = 5;
+	pub MaxTypedAttributes: u32 = 2;
+	pub MaxNestingDepth: u32 = 2;
+	pub static GenerativeRevealDelay: BlockNumber = 2;
 	pub const MetaverseTreasuryPalletId: PalletId = PalletId(*b"bit/trsy");
 	pub TreasuryModuleAccount: AccountId = MetaverseTreasuryPalletId::get().into_account();
 }
@@ -195,6 +200,8 @@ impl currencies::Config for Runtime {
 	type MultiSocialCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type GetNativeCurrencyId = NativeCurrencyId;
+	type TreasuryAccount = TreasuryModuleAccount;
+	type UpdateOrigin = EnsureRoot<AccountId>;
 }
 
 parameter_types! {
@@ -234,8 +241,82 @@ impl Config for Runtime {
 	type MiningResourceId = MiningCurrencyId;
 	type AssetMintingFee = AssetMintingFee;
 	type ClassMintingFee = ClassMintingFee;
+	type ActivityRecorder = ();
+	type MaxRarityAttributes = MaxRarityAttributes;
+	type MaxTypedAttributes = MaxTypedAttributes;
+	type MaxNestingDepth = MaxNestingDepth;
+	type OffchainSignature = MockSignature;
+	type OffchainPublic = MockPublic;
+	type Randomness = MockRandomness;
+	type GenerativeRevealDelay = GenerativeRevealDelay;
+	type OnNftTransfer = ();
 }
 
+/// Randomness of the mock, derived from the subject and block number
+pub struct MockRandomness;
+
+impl frame_support::traits::Randomness<H256, BlockNumber> for MockRandomness {
+	fn random(subject: &[u8]) -> (H256, BlockNumber) {
+		let block_number = System::block_number();
+		(
+			<sp_runtime::traits::BlakeTwo256 as sp_runtime::traits::Hash>::hash_of(&(subject, block_number)),
+			block_number,
+		)
+	}
+}
+
+/// Signature of a mock account, valid for the message it was created with
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MockSignature(pub AccountId, pub Vec<u8>);
+
+impl Verify for MockSignature {
+	type Signer = MockPublic;
+
+	fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &AccountId) -> bool {
+		self.0 == *signer && self.1 == msg.get()
+	}
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MockPublic(pub AccountId);
+
+impl IdentifyAccount for MockPublic {
+	type AccountId = AccountId;
+
+	fn into_account(self) -> AccountId {
+		self.0
+	}
+}
+
+parameter_types! {
+	pub NftPalletLocation: MultiLocation = MultiLocation::new(1, X2(Parachain(2000), PalletInstance(10)));
+}
+
+/// Converts `AccountIndex64` locations into mock accounts
+pub struct MockLocationToAccountId;
+
+impl xcm_executor::traits::Convert<MultiLocation, AccountId> for MockLocationToAccountId {
+	fn convert(location: MultiLocation) -> Result<AccountId, MultiLocation> {
+		match location {
+			MultiLocation {
+				parents: 0,
+				interior: X1(AccountIndex64 { index, .. }),
+			} => Ok(index.into()),
+			_ => Err(location),
+		}
+	}
+}
+
+pub fn account_location(who: AccountId) -> MultiLocation {
+	X1(AccountIndex64 {
+		network: NetworkId::Any,
+		index: who as u64,
+	})
+	.into()
+}
+
+pub type NftXcmAdapter = crate::xcm_adapter::NonFungibleAdapter<Runtime, MockLocationToAccountId, NftPalletLocation>;
+
 parameter_types! {
 	pub MaxClassMetadata: u32 = 1024;
 	pub MaxTokenMetadata: u32 = 1024;
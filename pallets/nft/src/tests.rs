@@ -1,8 +1,10 @@
 use frame_support::{assert_noop, assert_ok};
 use orml_nft::Pallet as NftModule;
 use orml_traits::MultiCurrency;
-use sp_runtime::traits::BadOrigin;
+use sp_runtime::traits::{BadOrigin, BlakeTwo256, Hash as HashT};
 use sp_std::default::Default;
+use xcm::latest::prelude::*;
+use xcm_executor::traits::TransactAsset;
 
 use mock::*;
 use primitives::{Balance, FungibleTokenId};
@@ -18,6 +20,10 @@ fn free_native_balance(who: AccountId) -> Balance {
 	<Runtime as Config>::Currency::free_balance(who)
 }
 
+fn reserved_native_balance(who: AccountId) -> Balance {
+	<Runtime as Config>::Currency::reserved_balance(who)
+}
+
 fn class_id_account() -> AccountId {
 	<Runtime as Config>::Treasury::get().into_account()
 }
@@ -46,6 +52,21 @@ fn init_test_nft(owner: Origin) {
 	assert_ok!(Nft::mint(owner.clone(), CLASS_ID, vec![1], test_attributes(1), 1));
 }
 
+fn init_mutable_test_nft(owner: Origin) {
+	assert_ok!(Nft::create_group(Origin::root(), vec![1], vec![1],));
+	assert_ok!(Nft::create_class(
+		owner.clone(),
+		vec![1],
+		test_attributes(1),
+		COLLECTION_ID,
+		TokenType::Transferable,
+		CollectionType::Collectable,
+		Perbill::from_percent(0u32)
+	));
+	assert_ok!(Nft::enable_metadata_updates(owner.clone(), CLASS_ID));
+	assert_ok!(Nft::mint(owner.clone(), CLASS_ID, vec![1], test_attributes(1), 1));
+}
+
 fn init_bound_to_address_nft(owner: Origin) {
 	assert_ok!(Nft::create_group(Origin::root(), vec![1], vec![1],));
 	assert_ok!(Nft::create_class(
@@ -147,7 +168,8 @@ fn create_class_should_work() {
 		let event = mock::Event::Nft(crate::Event::NewNftClassCreated(ALICE, CLASS_ID));
 		assert_eq!(last_event(), event);
 
-		assert_eq!(free_native_balance(class_id_account()), class_deposit);
+		assert_eq!(reserved_native_balance(ALICE), class_deposit);
+		assert_eq!(Nft::reserved_deposit_of(ALICE), class_deposit);
 	});
 }
 
@@ -156,9 +178,11 @@ fn mint_asset_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
 		let origin = Origin::signed(ALICE);
 		assert_ok!(Nft::enable_promotion(Origin::root(), true));
+		assert!(!Nft::has_minted_nft(&ALICE));
 		init_test_nft(origin.clone());
+		assert!(Nft::has_minted_nft(&ALICE));
 
-		assert_eq!(free_native_balance(class_id_account()), 3);
+		assert_eq!(reserved_native_balance(ALICE), 3);
 		assert_eq!(OrmlNft::tokens_by_owner((ALICE, 0, 0)), ());
 
 		let event = mock::Event::Nft(crate::Event::NewNftMinted((0, 0), (0, 0), ALICE, CLASS_ID, 1, 0));
@@ -225,7 +249,7 @@ fn mint_asset_should_fail() {
 		);
 		assert_noop!(
 			Nft::mint(invalid_owner.clone(), CLASS_ID, vec![1], test_attributes(1), 1),
-			Error::<Runtime>::NoPermission
+			Error::<Runtime>::NotClassOwner
 		);
 	})
 }
@@ -274,6 +298,175 @@ fn burn_nft_should_work() {
 	})
 }
 
+#[test]
+fn burn_class_should_release_deposits() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 1));
+		assert_eq!(free_native_balance(ALICE), 99996);
+		assert_eq!(reserved_native_balance(ALICE), 4);
+
+		// Token deposits go back to the class owner
+		assert_ok!(Nft::burn(origin.clone(), (CLASS_ID, 0)));
+		assert_eq!(free_native_balance(ALICE), 99997);
+		assert_eq!(NftModule::<Runtime>::classes(CLASS_ID).unwrap().total_issuance, 1);
+
+		assert_noop!(
+			Nft::burn_class(origin.clone(), CLASS_ID),
+			Error::<Runtime>::ClassIsNotEmpty
+		);
+		assert_noop!(
+			Nft::burn_class(Origin::signed(BOB), CLASS_ID),
+			Error::<Runtime>::NotClassOwner
+		);
+
+		assert_ok!(Nft::burn(origin.clone(), (CLASS_ID, 1)));
+		assert_ok!(Nft::burn_class(origin, CLASS_ID));
+		assert_eq!(last_event(), mock::Event::Nft(crate::Event::BurnedNftClass(CLASS_ID)));
+		assert_eq!(NftModule::<Runtime>::classes(CLASS_ID), None);
+		assert_eq!(Nft::get_class_collection(CLASS_ID), 0);
+		assert_eq!(free_native_balance(ALICE), 100000);
+		assert_eq!(reserved_native_balance(ALICE), 0);
+		assert_eq!(Nft::reserved_deposit_of(ALICE), 0);
+	})
+}
+
+#[test]
+fn burn_should_release_deposits_paid_to_treasury_before_reserving() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		// Deposits paid to the treasury before they were reserved on the depositor
+		<Runtime as Config>::Currency::unreserve(&ALICE, 3);
+		assert_ok!(<Runtime as Config>::Currency::transfer(
+			&ALICE,
+			&class_id_account(),
+			3,
+			ExistenceRequirement::KeepAlive
+		));
+		ReservedDeposits::<Runtime>::remove(ALICE);
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 1));
+		assert_eq!(reserved_native_balance(ALICE), 1);
+
+		// The reserved deposit is released first, then the treasury pays back the rest
+		assert_ok!(Nft::burn(origin.clone(), (CLASS_ID, 0)));
+		assert_ok!(Nft::burn(origin.clone(), (CLASS_ID, 1)));
+		assert_ok!(Nft::burn_class(origin, CLASS_ID));
+		assert_eq!(free_native_balance(ALICE), 100000);
+		assert_eq!(reserved_native_balance(ALICE), 0);
+		assert_eq!(free_native_balance(class_id_account()), 0);
+	})
+}
+
+#[test]
+fn approved_operators_should_transfer_on_behalf_of_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 1));
+
+		assert_noop!(
+			Nft::transfer_from(Origin::signed(BOB), ALICE, BOB, (CLASS_ID, 0)),
+			Error::<Runtime>::NotApproved
+		);
+		assert_noop!(
+			Nft::approve(Origin::signed(BOB), (CLASS_ID, 0), Some(BOB)),
+			Error::<Runtime>::NotAssetOwner
+		);
+
+		// Single token approval is cleared once the token moves
+		assert_ok!(Nft::approve(origin.clone(), (CLASS_ID, 0), Some(BOB)));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::Approval(ALICE, (CLASS_ID, 0), Some(BOB)))
+		);
+		assert_noop!(
+			Nft::transfer_from(Origin::signed(BOB), ALICE, BOB, (CLASS_ID, 1)),
+			Error::<Runtime>::NotApproved
+		);
+		assert_ok!(Nft::transfer_from(Origin::signed(BOB), ALICE, 3, (CLASS_ID, 0)));
+		assert_eq!(Nft::check_nft_ownership(&3, &(CLASS_ID, 0)), Ok(true));
+		assert_eq!(Nft::get_approved((CLASS_ID, 0)), None);
+
+		// Operator approval covers every token of the owner until revoked
+		assert_ok!(Nft::approve_for_all(origin.clone(), BOB, true));
+		assert_ok!(Nft::transfer_from(Origin::signed(BOB), ALICE, BOB, (CLASS_ID, 1)));
+		assert_eq!(Nft::check_nft_ownership(&BOB, &(CLASS_ID, 1)), Ok(true));
+		assert_ok!(Nft::approve_for_all(origin, BOB, false));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::ApprovalForAll(ALICE, BOB, false))
+		);
+		assert_eq!(Nft::get_operator_approval(ALICE, BOB), None);
+	})
+}
+
+#[test]
+fn import_collection_should_preserve_token_ids() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Nft::create_group(Origin::root(), vec![1], vec![1]));
+		assert_noop!(
+			Nft::import_class(
+				Origin::signed(ALICE),
+				BOB,
+				COLLECTION_ID,
+				vec![1],
+				test_attributes(1),
+				TokenType::Transferable,
+				CollectionType::Collectable,
+				Perbill::from_percent(0u32)
+			),
+			BadOrigin
+		);
+		assert_ok!(Nft::import_class(
+			Origin::root(),
+			BOB,
+			COLLECTION_ID,
+			vec![1],
+			test_attributes(1),
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			Perbill::from_percent(0u32)
+		));
+		assert_eq!(last_event(), Event::Nft(crate::Event::ClassImported(CLASS_ID, BOB)));
+		assert_eq!(NftModule::<Runtime>::classes(CLASS_ID).unwrap().owner, BOB);
+
+		// Token 1 was burned on the source chain
+		assert_ok!(Nft::import_tokens(
+			Origin::root(),
+			CLASS_ID,
+			vec![
+				(0, ALICE, vec![1], test_attributes(1)),
+				(2, BOB, vec![2], test_attributes(2))
+			]
+		));
+		assert_eq!(NftModule::<Runtime>::tokens(CLASS_ID, 0).unwrap().owner, ALICE);
+		assert_eq!(NftModule::<Runtime>::tokens(CLASS_ID, 1), None);
+		assert_eq!(NftModule::<Runtime>::tokens(CLASS_ID, 2).unwrap().owner, BOB);
+		assert_eq!(NftModule::<Runtime>::classes(CLASS_ID).unwrap().total_issuance, 2);
+		assert_eq!(last_event(), Event::Nft(crate::Event::TokensImported(CLASS_ID, 2)));
+
+		assert_noop!(
+			Nft::import_tokens(Origin::root(), CLASS_ID, vec![(1, ALICE, vec![1], test_attributes(1))]),
+			Error::<Runtime>::InvalidImportedTokenIds
+		);
+
+		// Burned placeholders count towards the supply cap
+		assert_ok!(Nft::set_max_supply(Origin::signed(BOB), CLASS_ID, 4));
+		assert_noop!(
+			Nft::import_tokens(Origin::root(), CLASS_ID, vec![(4, ALICE, vec![1], test_attributes(1))]),
+			Error::<Runtime>::ExceedMaxSupply
+		);
+		assert_ok!(Nft::import_tokens(
+			Origin::root(),
+			CLASS_ID,
+			vec![(3, ALICE, vec![1], test_attributes(1))]
+		));
+	})
+}
+
 #[test]
 fn transfer_batch_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -290,7 +483,10 @@ fn transfer_batch_should_work() {
 		));
 		assert_ok!(Nft::mint(origin.clone(), 1, vec![1], test_attributes(1), 4));
 		assert_ok!(Nft::transfer_batch(origin, vec![(BOB, (1, 0)), (BOB, (1, 1))]));
-		let event = mock::Event::Nft(crate::Event::TransferedNft(ALICE, BOB, 1, (1, 1)));
+		let event = mock::Event::Nft(crate::Event::BatchTransferred(
+			ALICE,
+			vec![(BOB, (1, 0)), (BOB, (1, 1))],
+		));
 		assert_eq!(last_event(), event);
 	})
 }
@@ -339,6 +535,27 @@ fn transfer_batch_should_fail() {
 	})
 }
 
+#[test]
+fn transfer_batch_should_report_interrupted_index() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::mint(origin.clone(), 0, vec![1], test_attributes(1), 1));
+		assert_ok!(Nft::transfer_batch(
+			origin,
+			vec![(BOB, (0, 0)), (BOB, (0, 5)), (BOB, (0, 1))]
+		));
+
+		let event = mock::Event::Nft(crate::Event::BatchTransferInterrupted(
+			1,
+			Error::<Runtime>::AssetInfoNotFound.into(),
+		));
+		assert_eq!(last_event(), event);
+		assert_eq!(Nft::check_nft_ownership(&BOB, &(0, 0)), Ok(true));
+		assert_eq!(Nft::check_nft_ownership(&ALICE, &(0, 1)), Ok(true));
+	})
+}
+
 #[test]
 fn do_create_group_collection_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -362,7 +579,7 @@ fn do_transfer_should_fail() {
 
 		init_test_nft(origin.clone());
 
-		assert_noop!(Nft::do_transfer(&BOB, &ALICE, (0, 0)), Error::<Runtime>::NoPermission);
+		assert_noop!(Nft::do_transfer(&BOB, &ALICE, (0, 0)), Error::<Runtime>::NotAssetOwner);
 
 		assert_ok!(Nft::create_class(
 			origin.clone(),
@@ -401,3 +618,948 @@ fn do_check_nft_ownership_should_fail() {
 		);
 	})
 }
+
+#[test]
+fn transfer_same_nft_twice_in_extrinsic_should_fail() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		System::set_extrinsic_index(1);
+		assert_ok!(Nft::transfer(origin.clone(), BOB, (0, 0)));
+		assert_noop!(
+			Nft::transfer(Origin::signed(BOB), ALICE, (0, 0)),
+			Error::<Runtime>::AlreadyTransferredInExtrinsic
+		);
+
+		// Records left over from an earlier block do not restrict the same extrinsic index
+		System::set_block_number(2);
+		assert_ok!(Nft::transfer(Origin::signed(BOB), ALICE, (0, 0)));
+
+		System::set_extrinsic_index(2);
+		assert_ok!(Nft::transfer(origin, BOB, (0, 0)));
+	})
+}
+
+#[test]
+fn attribute_frequencies_should_rank_token_rarity() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(2), 3));
+
+		assert_eq!(Nft::get_attribute_frequency(CLASS_ID, (vec![1, 6], vec![1, 11])), 1);
+		assert_eq!(Nft::get_attribute_frequency(CLASS_ID, (vec![2, 7], vec![2, 12])), 3);
+		assert_eq!(Nft::token_rarity_score((CLASS_ID, 0)), Some(4 * RARITY_SCORE_SCALE));
+		assert_eq!(Nft::token_rarity_score((CLASS_ID, 1)), Some(4 * RARITY_SCORE_SCALE / 3));
+
+		assert_ok!(Nft::burn(origin, (CLASS_ID, 0)));
+		assert_eq!(
+			Nft::class_attribute_frequencies(CLASS_ID),
+			vec![(vec![2, 7], vec![2, 12], 3)]
+		);
+		assert_eq!(Nft::token_rarity_score((CLASS_ID, 1)), Some(RARITY_SCORE_SCALE));
+		assert_eq!(Nft::token_rarity_score((CLASS_ID, 0)), None);
+	})
+}
+
+#[test]
+fn rarity_index_upgrade_should_backfill_existing_tokens() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(2), 3));
+		let frequencies = Nft::class_attribute_frequencies(CLASS_ID);
+
+		// Tokens minted before the index was kept are not counted
+		AttributeFrequencies::<Runtime>::remove_prefix(CLASS_ID, None);
+		assert!(Nft::class_attribute_frequencies(CLASS_ID).is_empty());
+
+		Nft::upgrade_rarity_index_v1();
+		assert_eq!(Nft::class_attribute_frequencies(CLASS_ID), frequencies);
+		assert_eq!(Nft::token_rarity_score((CLASS_ID, 0)), Some(4 * RARITY_SCORE_SCALE));
+
+		assert_ok!(Nft::burn(origin, (CLASS_ID, 0)));
+		assert_eq!(
+			Nft::class_attribute_frequencies(CLASS_ID),
+			vec![(vec![2, 7], vec![2, 12], 3)]
+		);
+	})
+}
+
+#[test]
+fn mint_with_royalty_should_override_class_royalty() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_noop!(
+			Nft::mint_with_royalty(
+				origin.clone(),
+				CLASS_ID,
+				vec![1],
+				test_attributes(1),
+				1,
+				BOB,
+				Perbill::from_percent(26)
+			),
+			Error::<Runtime>::RoyaltyFeeExceedLimit
+		);
+		assert_ok!(Nft::mint_with_royalty(
+			origin.clone(),
+			CLASS_ID,
+			vec![1],
+			test_attributes(1),
+			2,
+			BOB,
+			Perbill::from_percent(5)
+		));
+
+		assert_eq!(Nft::token_royalty((CLASS_ID, 0)), None);
+		assert_eq!(Nft::token_royalty((CLASS_ID, 2)), Some((BOB, Perbill::from_percent(5))));
+
+		assert_ok!(Nft::burn(origin, (CLASS_ID, 1)));
+		assert_eq!(Nft::token_royalty((CLASS_ID, 1)), None);
+	})
+}
+
+#[test]
+fn redeem_mint_voucher_should_mint_to_buyer() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(Origin::signed(ALICE));
+		assert_ok!(Balances::transfer(Origin::signed(ALICE), BOB, 1000));
+		let voucher = MintVoucher {
+			class_id: CLASS_ID,
+			nonce: 1,
+			metadata: vec![2],
+			attributes: test_attributes(2),
+			price: 100,
+			royalty: Some((BOB, Perbill::from_percent(5))),
+			expires_at: 10,
+		};
+
+		assert_noop!(
+			Nft::redeem_mint_voucher(
+				Origin::signed(BOB),
+				voucher.clone(),
+				MockSignature(BOB, Nft::mint_voucher_payload(&voucher))
+			),
+			Error::<Runtime>::InvalidVoucherSignature
+		);
+		// Signature without the genesis hash is not accepted
+		assert_noop!(
+			Nft::redeem_mint_voucher(
+				Origin::signed(BOB),
+				voucher.clone(),
+				MockSignature(ALICE, voucher.encode())
+			),
+			Error::<Runtime>::InvalidVoucherSignature
+		);
+
+		let alice_balance = free_native_balance(ALICE);
+		let signature = MockSignature(ALICE, Nft::mint_voucher_payload(&voucher));
+		assert_ok!(Nft::redeem_mint_voucher(
+			Origin::signed(BOB),
+			voucher.clone(),
+			signature.clone()
+		));
+		assert_eq!(Nft::check_nft_ownership(&BOB, &(CLASS_ID, 1)), Ok(true));
+		assert_eq!(Nft::token_royalty((CLASS_ID, 1)), Some((BOB, Perbill::from_percent(5))));
+		// Creator receives the price less the minting fee
+		assert_eq!(free_native_balance(ALICE), alice_balance + 99);
+		assert_eq!(free_native_balance(BOB), 900);
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::MintVoucherRedeemed(1, BOB, (CLASS_ID, 1), 100))
+		);
+
+		assert_noop!(
+			Nft::redeem_mint_voucher(Origin::signed(BOB), voucher, signature),
+			Error::<Runtime>::VoucherAlreadyRedeemed
+		);
+	})
+}
+
+#[test]
+fn redeem_mint_voucher_should_reject_expired_voucher() {
+	ExtBuilder::default().build().execute_with(|| {
+		init_test_nft(Origin::signed(ALICE));
+		assert_ok!(Balances::transfer(Origin::signed(ALICE), BOB, 1000));
+		let voucher = MintVoucher {
+			class_id: CLASS_ID,
+			nonce: 1,
+			metadata: vec![2],
+			attributes: test_attributes(2),
+			price: 100,
+			royalty: None,
+			expires_at: 10,
+		};
+		let signature = MockSignature(ALICE, Nft::mint_voucher_payload(&voucher));
+
+		System::set_block_number(11);
+		assert_noop!(
+			Nft::redeem_mint_voucher(Origin::signed(BOB), voucher, signature),
+			Error::<Runtime>::VoucherExpired
+		);
+	})
+}
+
+#[test]
+fn set_nft_user_should_expire_and_clear_on_transfer() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		assert_noop!(
+			Nft::set_nft_user(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), BOB, 10),
+			Error::<Runtime>::NotAssetOwner
+		);
+		assert_noop!(
+			Nft::set_nft_user(origin.clone(), (CLASS_ID, TOKEN_ID), BOB, 1),
+			Error::<Runtime>::InvalidRentalExpiry
+		);
+
+		assert_ok!(Nft::set_nft_user(origin.clone(), (CLASS_ID, TOKEN_ID), BOB, 10));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::NftUserSet((CLASS_ID, TOKEN_ID), BOB, 10))
+		);
+		assert_eq!(Nft::current_user(&(CLASS_ID, TOKEN_ID)), Some(BOB));
+		assert_eq!(Nft::check_nft_ownership(&ALICE, &(CLASS_ID, TOKEN_ID)), Ok(true));
+		assert_noop!(
+			Nft::set_nft_user(origin.clone(), (CLASS_ID, TOKEN_ID), ALICE, 20),
+			Error::<Runtime>::NftAlreadyRented
+		);
+
+		System::set_block_number(10);
+		assert_eq!(Nft::current_user(&(CLASS_ID, TOKEN_ID)), None);
+
+		assert_ok!(Nft::set_nft_user(origin.clone(), (CLASS_ID, TOKEN_ID), BOB, 20));
+		assert_ok!(Nft::transfer(origin, BOB, (CLASS_ID, TOKEN_ID)));
+		assert_eq!(Nft::current_user(&(CLASS_ID, TOKEN_ID)), None);
+	})
+}
+
+#[test]
+fn issue_soulbound_nft_should_bind_to_recipient() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::create_class(
+			origin.clone(),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::BoundToAddress,
+			CollectionType::Collectable,
+			Perbill::from_percent(0u32)
+		));
+
+		assert_noop!(
+			Nft::issue_soulbound_nft(origin.clone(), CLASS_ID, BOB, vec![1], test_attributes(1)),
+			Error::<Runtime>::OnlyForSoulboundClass
+		);
+		assert_noop!(
+			Nft::issue_soulbound_nft(Origin::signed(BOB), 1, BOB, vec![1], test_attributes(1)),
+			Error::<Runtime>::NotClassOwner
+		);
+
+		assert_ok!(Nft::issue_soulbound_nft(origin, 1, BOB, vec![1], test_attributes(1)));
+		assert_eq!(Nft::check_nft_ownership(&BOB, &(1, 0)), Ok(true));
+		assert_eq!(last_event(), Event::Nft(crate::Event::SoulboundNftIssued((1, 0), BOB)));
+
+		assert_noop!(
+			Nft::transfer(Origin::signed(BOB), ALICE, (1, 0)),
+			Error::<Runtime>::NonTransferable
+		);
+	})
+}
+
+#[test]
+fn nested_nfts_should_move_with_parent() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 3));
+
+		assert_ok!(Nft::nest_nft(origin.clone(), (CLASS_ID, 1), (CLASS_ID, 0)));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::NftNested((CLASS_ID, 1), (CLASS_ID, 0)))
+		);
+		assert_ok!(Nft::nest_nft(origin.clone(), (CLASS_ID, 2), (CLASS_ID, 1)));
+
+		assert_noop!(
+			Nft::nest_nft(origin.clone(), (CLASS_ID, 0), (CLASS_ID, 2)),
+			Error::<Runtime>::InvalidNesting
+		);
+		assert_noop!(
+			Nft::nest_nft(origin.clone(), (CLASS_ID, 3), (CLASS_ID, 2)),
+			Error::<Runtime>::ExceedMaximumNestingDepth
+		);
+		assert_noop!(
+			Nft::transfer(origin.clone(), BOB, (CLASS_ID, 1)),
+			Error::<Runtime>::NftIsNested
+		);
+		assert_noop!(
+			Nft::burn(origin.clone(), (CLASS_ID, 0)),
+			Error::<Runtime>::NftHasChildren
+		);
+
+		assert_ok!(Nft::transfer(origin, BOB, (CLASS_ID, 0)));
+		assert_eq!(Nft::check_nft_ownership(&BOB, &(CLASS_ID, 1)), Ok(true));
+		assert_eq!(Nft::check_nft_ownership(&BOB, &(CLASS_ID, 2)), Ok(true));
+
+		assert_ok!(Nft::unnest_nft(Origin::signed(BOB), (CLASS_ID, 2)));
+		assert_eq!(Nft::get_nft_parent((CLASS_ID, 2)), None);
+		assert_ok!(Nft::transfer(Origin::signed(BOB), ALICE, (CLASS_ID, 2)));
+		assert_eq!(Nft::check_nft_ownership(&ALICE, &(CLASS_ID, 2)), Ok(true));
+	})
+}
+
+#[test]
+fn nested_nft_tree_should_be_bounded_and_respect_child_locks() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 4));
+
+		assert_ok!(Nft::nest_nft(origin.clone(), (CLASS_ID, 1), (CLASS_ID, 0)));
+		assert_ok!(Nft::nest_nft(origin.clone(), (CLASS_ID, 2), (CLASS_ID, 0)));
+		assert_ok!(Nft::nest_nft(origin.clone(), (CLASS_ID, 3), (CLASS_ID, 1)));
+		// The whole tree under the top level NFT counts towards the limit
+		assert_noop!(
+			Nft::nest_nft(origin.clone(), (CLASS_ID, 4), (CLASS_ID, 2)),
+			Error::<Runtime>::ExceedMaximumNestedChildren
+		);
+		assert_eq!(
+			Nft::nested_transfer_weight(&(CLASS_ID, 0)),
+			<Runtime as Config>::WeightInfo::transfer() * 3
+		);
+
+		// A locked nested NFT keeps the parent from moving
+		assert_ok!(Nft::set_lock_nft(&(CLASS_ID, 3), true));
+		assert_noop!(
+			Nft::transfer(origin.clone(), BOB, (CLASS_ID, 0)),
+			Error::<Runtime>::NftIsLocked
+		);
+		assert_ok!(Nft::set_lock_nft(&(CLASS_ID, 3), false));
+		assert_ok!(Nft::transfer(origin, BOB, (CLASS_ID, 0)));
+		assert_eq!(Nft::check_nft_ownership(&BOB, &(CLASS_ID, 3)), Ok(true));
+	})
+}
+
+#[test]
+fn equip_item_should_bind_wearable_to_avatar() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::create_class(
+			origin.clone(),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Wearable,
+			Perbill::from_percent(0u32)
+		));
+		assert_ok!(Nft::mint(origin.clone(), 1, vec![1], test_attributes(1), 2));
+		let avatar = (CLASS_ID, TOKEN_ID);
+
+		assert_noop!(
+			Nft::equip_item(origin.clone(), (1, 0), avatar, WearableSlot::Head),
+			Error::<Runtime>::InvalidWearable
+		);
+		assert_ok!(Nft::equip_item(origin.clone(), avatar, (1, 0), WearableSlot::Head));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::ItemEquipped(avatar, (1, 0), WearableSlot::Head))
+		);
+		assert_noop!(
+			Nft::equip_item(origin.clone(), avatar, (1, 1), WearableSlot::Head),
+			Error::<Runtime>::SlotAlreadyEquipped
+		);
+		assert_noop!(
+			Nft::transfer(origin.clone(), BOB, (1, 0)),
+			Error::<Runtime>::NftIsNested
+		);
+		assert_noop!(
+			Nft::unnest_nft(origin.clone(), (1, 0)),
+			Error::<Runtime>::ItemIsEquipped
+		);
+		assert_eq!(Nft::equipped_items(&avatar), vec![(WearableSlot::Head, (1, 0))]);
+
+		// Equipped wearables move with the avatar
+		assert_ok!(Nft::transfer(origin, BOB, avatar));
+		assert_eq!(Nft::check_nft_ownership(&BOB, &(1, 0)), Ok(true));
+
+		assert_ok!(Nft::unequip_item(Origin::signed(BOB), avatar, WearableSlot::Head));
+		assert!(Nft::equipped_items(&avatar).is_empty());
+		assert_ok!(Nft::transfer(Origin::signed(BOB), ALICE, (1, 0)));
+	})
+}
+
+#[test]
+fn mint_phases_should_respect_supply_cap_and_limits() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		let _ = <Runtime as Config>::Currency::deposit_creating(&BOB, 100);
+
+		assert_noop!(
+			Nft::set_max_supply(origin.clone(), CLASS_ID, 0),
+			Error::<Runtime>::InvalidMaxSupply
+		);
+		assert_ok!(Nft::set_max_supply(origin.clone(), CLASS_ID, 3));
+		assert_noop!(
+			Nft::set_max_supply(origin.clone(), CLASS_ID, 4),
+			Error::<Runtime>::InvalidMaxSupply
+		);
+
+		assert_noop!(
+			Nft::phase_mint(Origin::signed(BOB), CLASS_ID, 1),
+			Error::<Runtime>::NoActiveMintPhase
+		);
+		let phase = MintPhase {
+			start: 1,
+			end: 10,
+			price: 5,
+			per_account_limit: 1,
+		};
+		assert_ok!(Nft::set_mint_phases(origin.clone(), CLASS_ID, vec![phase]));
+
+		let alice_balance = free_native_balance(ALICE);
+		assert_ok!(Nft::phase_mint(Origin::signed(BOB), CLASS_ID, 1));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::PhaseMinted(BOB, 0, (CLASS_ID, 1), (CLASS_ID, 1)))
+		);
+		assert_eq!(NftModule::<Runtime>::tokens(CLASS_ID, 1).unwrap().owner, BOB);
+		// Class owner receives the phase price and pays the minting fee
+		assert_eq!(free_native_balance(ALICE), alice_balance + 5 - 1);
+		assert_eq!(free_native_balance(BOB), 95);
+		assert_noop!(
+			Nft::phase_mint(Origin::signed(BOB), CLASS_ID, 1),
+			Error::<Runtime>::ExceedMintPhaseLimit
+		);
+
+		assert_noop!(
+			Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 2),
+			Error::<Runtime>::ExceedMaxSupply
+		);
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 1));
+
+		// Burning does not free up supply, the cap counts every NFT ever minted
+		assert_ok!(Nft::burn(origin.clone(), (CLASS_ID, 2)));
+		assert_noop!(
+			Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 1),
+			Error::<Runtime>::ExceedMaxSupply
+		);
+		assert_noop!(
+			Nft::set_max_supply(origin, CLASS_ID, 2),
+			Error::<Runtime>::InvalidMaxSupply
+		);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Nft::phase_mint(Origin::signed(BOB), CLASS_ID, 1),
+			Error::<Runtime>::NoActiveMintPhase
+		);
+	})
+}
+
+#[test]
+fn mint_batch_should_mint_items_with_own_metadata() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		let alice_balance = free_native_balance(ALICE);
+
+		assert_noop!(
+			Nft::mint_batch(origin.clone(), CLASS_ID, vec![]),
+			Error::<Runtime>::InvalidQuantity
+		);
+		assert_noop!(
+			Nft::mint_batch(origin.clone(), CLASS_ID, vec![(vec![1; 11], test_attributes(1))]),
+			Error::<Runtime>::ExceedMaximumMetadataLength
+		);
+
+		assert_ok!(Nft::mint_batch(
+			origin,
+			CLASS_ID,
+			vec![(vec![2], test_attributes(2)), (vec![3], test_attributes(3))]
+		));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::NewNftMinted(
+				(CLASS_ID, 1),
+				(CLASS_ID, 2),
+				ALICE,
+				CLASS_ID,
+				2,
+				2
+			))
+		);
+		assert_eq!(Nft::check_nft_ownership(&ALICE, &(CLASS_ID, 2)), Ok(true));
+		assert_eq!(Nft::get_attribute_frequency(CLASS_ID, (vec![3, 8], vec![3, 13])), 1);
+		assert_eq!(free_native_balance(ALICE), alice_balance - 2);
+	})
+}
+
+#[test]
+fn freeze_metadata_should_reject_updates() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_mutable_test_nft(origin.clone());
+		assert_ok!(Nft::mint(origin.clone(), CLASS_ID, vec![1], test_attributes(1), 1));
+
+		assert_noop!(
+			Nft::update_token_metadata(Origin::signed(BOB), (CLASS_ID, 0), vec![2], test_attributes(2)),
+			Error::<Runtime>::NotClassOwner
+		);
+		assert_ok!(Nft::update_token_metadata(
+			origin.clone(),
+			(CLASS_ID, 0),
+			vec![2],
+			test_attributes(2)
+		));
+		assert_eq!(Nft::get_attribute_frequency(CLASS_ID, (vec![1, 6], vec![1, 11])), 1);
+		assert_eq!(Nft::get_attribute_frequency(CLASS_ID, (vec![2, 7], vec![2, 12])), 1);
+
+		assert_ok!(Nft::freeze_metadata(origin.clone(), CLASS_ID, Some(0)));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::MetadataFrozen(CLASS_ID, Some(0)))
+		);
+		assert!(Nft::is_metadata_frozen(&(CLASS_ID, 0)));
+		assert!(!Nft::is_metadata_frozen(&(CLASS_ID, 1)));
+		assert_noop!(
+			Nft::update_token_metadata(origin.clone(), (CLASS_ID, 0), vec![3], test_attributes(3)),
+			Error::<Runtime>::MetadataIsFrozen
+		);
+
+		assert_ok!(Nft::update_class_metadata(origin.clone(), CLASS_ID, vec![2]));
+		assert_ok!(Nft::freeze_metadata(origin.clone(), CLASS_ID, None));
+		assert!(Nft::is_metadata_frozen(&(CLASS_ID, 1)));
+		assert_noop!(
+			Nft::update_class_metadata(origin.clone(), CLASS_ID, vec![3]),
+			Error::<Runtime>::MetadataIsFrozen
+		);
+		assert_noop!(
+			Nft::update_token_metadata(origin, (CLASS_ID, 1), vec![3], test_attributes(3)),
+			Error::<Runtime>::MetadataIsFrozen
+		);
+	})
+}
+
+#[test]
+fn metadata_updates_should_be_opt_in_before_minting() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		// Classes which never opted in keep their metadata frozen
+		assert!(Nft::is_metadata_frozen(&(CLASS_ID, 0)));
+		assert_noop!(
+			Nft::update_class_metadata(origin.clone(), CLASS_ID, vec![2]),
+			Error::<Runtime>::MetadataIsFrozen
+		);
+		assert_noop!(
+			Nft::update_token_metadata(origin.clone(), (CLASS_ID, 0), vec![2], test_attributes(2)),
+			Error::<Runtime>::MetadataIsFrozen
+		);
+		// Holders already own NFTs of the class, it cannot become mutable any more
+		assert_noop!(
+			Nft::enable_metadata_updates(origin.clone(), CLASS_ID),
+			Error::<Runtime>::ClassAlreadyMinted
+		);
+
+		assert_ok!(Nft::create_class(
+			origin.clone(),
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			Perbill::from_percent(0u32)
+		));
+		assert_noop!(
+			Nft::enable_metadata_updates(Origin::signed(BOB), CLASS_ID + 1),
+			Error::<Runtime>::NotClassOwner
+		);
+		assert_ok!(Nft::enable_metadata_updates(origin.clone(), CLASS_ID + 1));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::MetadataUpdatesEnabled(CLASS_ID + 1))
+		);
+		assert_ok!(Nft::update_class_metadata(origin, CLASS_ID + 1, vec![2]));
+	})
+}
+
+#[test]
+fn gift_nft_should_be_claimed_or_reclaimed() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		assert_noop!(
+			Nft::gift_nft(origin.clone(), (CLASS_ID, TOKEN_ID), BOB, 1),
+			Error::<Runtime>::InvalidGift
+		);
+		assert_ok!(Nft::gift_nft(origin.clone(), (CLASS_ID, TOKEN_ID), BOB, 10));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::NftGifted((CLASS_ID, TOKEN_ID), ALICE, BOB, 10))
+		);
+		let escrow = Nft::gift_escrow_account();
+		assert_eq!(NftModule::<Runtime>::tokens(CLASS_ID, TOKEN_ID).unwrap().owner, escrow);
+
+		assert_noop!(
+			Nft::reclaim_gift(origin.clone(), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::CannotReclaimGift
+		);
+		assert_noop!(
+			Nft::claim_gift(origin.clone(), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::CannotClaimGift
+		);
+		assert_ok!(Nft::claim_gift(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)));
+		assert_eq!(NftModule::<Runtime>::tokens(CLASS_ID, TOKEN_ID).unwrap().owner, BOB);
+		assert_eq!(Nft::get_nft_gift((CLASS_ID, TOKEN_ID)), None);
+
+		// Unclaimed gift goes back to the sender after expiry
+		assert_ok!(Nft::gift_nft(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), ALICE, 5));
+		System::set_block_number(6);
+		assert_noop!(
+			Nft::claim_gift(origin, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::CannotClaimGift
+		);
+		assert_ok!(Nft::reclaim_gift(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::NftGiftReclaimed((CLASS_ID, TOKEN_ID), BOB))
+		);
+		assert_eq!(NftModule::<Runtime>::tokens(CLASS_ID, TOKEN_ID).unwrap().owner, BOB);
+	})
+}
+
+#[test]
+fn generative_mint_should_roll_traits_on_reveal() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		assert_noop!(
+			Nft::request_generative_mint(origin.clone(), CLASS_ID, vec![1], 2),
+			Error::<Runtime>::GenerativeTraitsNotSet
+		);
+		let colours = vec![b"red".to_vec(), b"blue".to_vec()];
+		assert_noop!(
+			Nft::set_generative_traits(Origin::signed(BOB), CLASS_ID, vec![(b"hat".to_vec(), colours.clone())]),
+			Error::<Runtime>::NotClassOwner
+		);
+		assert_noop!(
+			Nft::set_generative_traits(origin.clone(), CLASS_ID, vec![(b"hat".to_vec(), vec![])]),
+			Error::<Runtime>::InvalidGenerativeTraits
+		);
+		assert_ok!(Nft::set_generative_traits(
+			origin.clone(),
+			CLASS_ID,
+			vec![(b"hat".to_vec(), colours.clone())]
+		));
+
+		assert_ok!(Nft::request_generative_mint(origin, CLASS_ID, vec![1], 2));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::GenerativeMintRequested(ALICE, CLASS_ID, 3))
+		);
+		// Nothing is minted until the reveal block
+		assert_eq!(NftModule::<Runtime>::tokens(CLASS_ID, 1), None);
+
+		System::set_block_number(3);
+		Nft::on_initialize(3);
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::GenerativeMintRevealed(
+				ALICE,
+				(CLASS_ID, 1),
+				(CLASS_ID, 2)
+			))
+		);
+		for token_id in 1..=2 {
+			let token_info = NftModule::<Runtime>::tokens(CLASS_ID, token_id).unwrap();
+			assert_eq!(token_info.owner, ALICE);
+			assert!(colours.contains(token_info.data.attributes.get(&b"hat".to_vec()).unwrap()));
+		}
+		assert!(Nft::generative_mint_queue(3).is_empty());
+	})
+}
+
+#[test]
+fn generative_mint_should_roll_traits_set_when_requested() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		let colours = vec![b"red".to_vec(), b"blue".to_vec()];
+		assert_ok!(Nft::set_generative_traits(
+			origin.clone(),
+			CLASS_ID,
+			vec![(b"hat".to_vec(), colours.clone())]
+		));
+		assert_ok!(Nft::request_generative_mint(origin.clone(), CLASS_ID, vec![1], 2));
+		assert_eq!(
+			Nft::generative_mint_queue(3)[0].traits,
+			vec![(b"hat".to_vec(), colours.clone())]
+		);
+
+		// Traits changed after the request do not apply to it
+		assert_ok!(Nft::set_generative_traits(
+			origin,
+			CLASS_ID,
+			vec![(b"hat".to_vec(), vec![b"gold".to_vec()])]
+		));
+
+		System::set_block_number(3);
+		Nft::on_initialize(3);
+		for token_id in 1..=2 {
+			let token_info = NftModule::<Runtime>::tokens(CLASS_ID, token_id).unwrap();
+			assert!(colours.contains(token_info.data.attributes.get(&b"hat".to_vec()).unwrap()));
+		}
+	})
+}
+
+#[test]
+fn generative_mint_should_reject_zero_reveal_delay() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::set_generative_traits(
+			origin.clone(),
+			CLASS_ID,
+			vec![(b"hat".to_vec(), vec![b"red".to_vec()])]
+		));
+
+		GenerativeRevealDelay::set(0);
+		assert_noop!(
+			Nft::request_generative_mint(origin, CLASS_ID, vec![1], 2),
+			Error::<Runtime>::InvalidRevealDelay
+		);
+		GenerativeRevealDelay::set(2);
+	})
+}
+
+#[test]
+fn class_roles_should_grant_delegated_privileges() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_mutable_test_nft(origin.clone());
+
+		assert_noop!(
+			Nft::set_class_role(Origin::signed(BOB), CLASS_ID, ClassRole::Issuer, Some(BOB)),
+			Error::<Runtime>::NotClassOwner
+		);
+		assert_noop!(
+			Nft::mint(Origin::signed(BOB), CLASS_ID, vec![1], test_attributes(1), 1),
+			Error::<Runtime>::NotClassOwner
+		);
+
+		assert_ok!(Nft::set_class_role(
+			origin.clone(),
+			CLASS_ID,
+			ClassRole::Issuer,
+			Some(BOB)
+		));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::ClassRoleSet(CLASS_ID, ClassRole::Issuer, Some(BOB)))
+		);
+
+		// Issuer mints without funds, the class owner pays the minting fee
+		let alice_balance = free_native_balance(ALICE);
+		assert_ok!(Nft::mint(Origin::signed(BOB), CLASS_ID, vec![1], test_attributes(1), 1));
+		assert_eq!(NftModule::<Runtime>::tokens(CLASS_ID, 1).unwrap().owner, BOB);
+		assert_eq!(free_native_balance(ALICE), alice_balance - 1);
+
+		// Issuer holds no admin or freezer privileges
+		assert_noop!(
+			Nft::update_class_metadata(Origin::signed(BOB), CLASS_ID, vec![2]),
+			Error::<Runtime>::NotClassOwner
+		);
+		assert_noop!(
+			Nft::freeze_metadata(Origin::signed(BOB), CLASS_ID, None),
+			Error::<Runtime>::NotClassOwner
+		);
+
+		assert_ok!(Nft::set_class_role(
+			origin.clone(),
+			CLASS_ID,
+			ClassRole::Admin,
+			Some(BOB)
+		));
+		assert_ok!(Nft::update_class_metadata(Origin::signed(BOB), CLASS_ID, vec![2]));
+
+		assert_ok!(Nft::set_class_role(origin, CLASS_ID, ClassRole::Issuer, None));
+		assert_eq!(Nft::class_role(CLASS_ID, ClassRole::Issuer), None);
+		assert_noop!(
+			Nft::mint(Origin::signed(BOB), CLASS_ID, vec![1], test_attributes(1), 1),
+			Error::<Runtime>::NotClassOwner
+		);
+	})
+}
+
+#[test]
+fn set_typed_attribute_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_mutable_test_nft(origin.clone());
+		let asset_id = (CLASS_ID, TOKEN_ID);
+
+		assert_noop!(
+			Nft::set_typed_attribute(Origin::signed(BOB), asset_id, vec![1], Some(AttributeValue::U32(1))),
+			Error::<Runtime>::NotClassOwner
+		);
+		assert_ok!(Nft::set_typed_attribute(
+			origin.clone(),
+			asset_id,
+			vec![1],
+			Some(AttributeValue::U32(7))
+		));
+		assert_ok!(Nft::set_typed_attribute(
+			origin.clone(),
+			asset_id,
+			vec![2],
+			Some(AttributeValue::Bool(true))
+		));
+		assert_noop!(
+			Nft::set_typed_attribute(origin.clone(), asset_id, vec![3], Some(AttributeValue::U128(1))),
+			Error::<Runtime>::ExceedMaximumTypedAttributes
+		);
+		assert_eq!(
+			<Nft as NFTTrait<AccountId, Balance>>::get_typed_attribute(&asset_id, &[1]),
+			Some(AttributeValue::U32(7))
+		);
+
+		assert_ok!(Nft::set_typed_attribute(origin.clone(), asset_id, vec![1], None));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::TypedAttributeSet(asset_id, vec![1], None))
+		);
+		assert_eq!(
+			Nft::typed_attributes(&asset_id),
+			vec![(vec![2], AttributeValue::Bool(true))]
+		);
+
+		assert_ok!(Nft::freeze_metadata(origin.clone(), CLASS_ID, Some(TOKEN_ID)));
+		assert_noop!(
+			Nft::set_typed_attribute(origin, asset_id, vec![2], Some(AttributeValue::Bool(false))),
+			Error::<Runtime>::MetadataIsFrozen
+		);
+	})
+}
+
+#[test]
+fn claim_airdrop_should_mint_allocation_with_valid_proof() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+
+		let bob_leaf = BlakeTwo256::hash_of(&(BOB, 2u32));
+		let other_leaf = BlakeTwo256::hash_of(&(3u128, 1u32));
+		let merkle_root = if bob_leaf <= other_leaf {
+			BlakeTwo256::hash_of(&(bob_leaf, other_leaf))
+		} else {
+			BlakeTwo256::hash_of(&(other_leaf, bob_leaf))
+		};
+
+		assert_noop!(
+			Nft::claim_airdrop(Origin::signed(BOB), CLASS_ID, 2, vec![other_leaf]),
+			Error::<Runtime>::AirdropNotFound
+		);
+		assert_ok!(Nft::publish_airdrop(
+			origin,
+			CLASS_ID,
+			merkle_root,
+			vec![2],
+			test_attributes(2)
+		));
+
+		assert_noop!(
+			Nft::claim_airdrop(Origin::signed(BOB), CLASS_ID, 3, vec![other_leaf]),
+			Error::<Runtime>::InvalidAirdropProof
+		);
+		let alice_balance = free_native_balance(ALICE);
+		assert_ok!(Nft::claim_airdrop(Origin::signed(BOB), CLASS_ID, 2, vec![other_leaf]));
+		assert_eq!(
+			last_event(),
+			Event::Nft(crate::Event::AirdropClaimed(BOB, (CLASS_ID, 1), (CLASS_ID, 2)))
+		);
+		assert_eq!(Nft::check_nft_ownership(&BOB, &(CLASS_ID, 1)), Ok(true));
+		assert_eq!(Nft::check_nft_ownership(&BOB, &(CLASS_ID, 2)), Ok(true));
+		// Class owner pays the minting fees
+		assert_eq!(free_native_balance(ALICE), alice_balance - 2);
+
+		assert_noop!(
+			Nft::claim_airdrop(Origin::signed(BOB), CLASS_ID, 2, vec![other_leaf]),
+			Error::<Runtime>::AirdropAlreadyClaimed
+		);
+	})
+}
+
+#[test]
+fn xcm_adapter_should_reserve_local_and_mint_foreign_nfts() {
+	ExtBuilder::default().build().execute_with(|| {
+		let origin = Origin::signed(ALICE);
+		init_test_nft(origin.clone());
+		assert_ok!(Nft::create_class(
+			origin,
+			vec![1],
+			test_attributes(1),
+			COLLECTION_ID,
+			TokenType::Transferable,
+			CollectionType::Collectable,
+			Perbill::from_percent(0u32)
+		));
+
+		// Local NFT leaves the chain and comes back to another account
+		let local_nft: MultiAsset = (
+			MultiLocation::new(0, X2(PalletInstance(10), GeneralIndex(CLASS_ID.into()))),
+			AssetInstance::Index(0),
+		)
+			.into();
+		assert_ok!(NftXcmAdapter::withdraw_asset(&local_nft, &account_location(ALICE)));
+		assert_eq!(Nft::check_nft_ownership(&ALICE, &(CLASS_ID, 0)), Ok(false));
+		assert_ok!(NftXcmAdapter::deposit_asset(&local_nft, &account_location(BOB)));
+		assert_eq!(Nft::check_nft_ownership(&BOB, &(CLASS_ID, 0)), Ok(true));
+
+		// Foreign NFT arrives as a derivative of the registered class
+		let collection = MultiLocation::new(1, X2(Parachain(3000), GeneralIndex(5)));
+		let foreign_nft: MultiAsset = (collection.clone(), AssetInstance::Index(7)).into();
+		assert_eq!(
+			NftXcmAdapter::deposit_asset(&foreign_nft, &account_location(BOB)),
+			Err(XcmError::AssetNotFound)
+		);
+		assert_noop!(
+			Nft::register_foreign_class(Origin::signed(ALICE), Box::new(collection.clone()), Some(1)),
+			BadOrigin
+		);
+		assert_ok!(Nft::register_foreign_class(
+			Origin::root(),
+			Box::new(collection.clone()),
+			Some(1)
+		));
+
+		assert_ok!(NftXcmAdapter::deposit_asset(&foreign_nft, &account_location(BOB)));
+		assert_eq!(Nft::foreign_token(1, 7), Some(0));
+		assert_eq!(Nft::check_nft_ownership(&BOB, &(1, 0)), Ok(true));
+		assert_eq!(
+			NftXcmAdapter::deposit_asset(&foreign_nft, &account_location(ALICE)),
+			Err(XcmError::FailedToTransactAsset("DerivativeMintFailed"))
+		);
+
+		assert_ok!(NftXcmAdapter::withdraw_asset(&foreign_nft, &account_location(BOB)));
+		assert_eq!(Nft::foreign_token(1, 7), None);
+		assert_eq!(
+			Nft::check_nft_ownership(&BOB, &(1, 0)),
+			Err(Error::<Runtime>::AssetInfoNotFound.into())
+		);
+	})
+}
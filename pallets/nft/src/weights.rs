@@ -43,7 +43,7 @@ use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use sp_std::marker::PhantomData;
 
 /// Weight functions needed for nft.
-pub trait WeightInfo {	fn create_group() -> Weight;	fn create_class() -> Weight;	fn mint() -> Weight;	fn transfer() -> Weight;	fn transfer_batch() -> Weight;	fn sign_asset() -> Weight;}
+pub trait WeightInfo {	fn create_group() -> Weight;	fn create_class() -> Weight;	fn mint() -> Weight;	fn transfer() -> Weight;	fn transfer_batch(i: u32, ) -> Weight;	fn mint_batch(i: u32, ) -> Weight;	fn sign_asset() -> Weight;	fn enable_metadata_updates() -> Weight;	fn update_class_metadata() -> Weight;	fn update_token_metadata() -> Weight;	fn freeze_metadata() -> Weight;}
 
 /// Weights for nft using the for collator node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -51,15 +51,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {	fn create_grou
 		(36_700_000 as Weight)			.saturating_add(T::DbWeight::get().reads(2 as Weight))			.saturating_add(T::DbWeight::get().writes(3 as Weight))	}	fn create_class() -> Weight {
 		(90_500_000 as Weight)			.saturating_add(T::DbWeight::get().reads(3 as Weight))			.saturating_add(T::DbWeight::get().writes(4 as Weight))	}	fn mint() -> Weight {
 		(153_400_000 as Weight)			.saturating_add(T::DbWeight::get().reads(6 as Weight))			.saturating_add(T::DbWeight::get().writes(11 as Weight))	}	fn transfer() -> Weight {
-		(89_400_000 as Weight)			.saturating_add(T::DbWeight::get().reads(6 as Weight))			.saturating_add(T::DbWeight::get().writes(5 as Weight))	}	fn transfer_batch() -> Weight {
-		(131_200_000 as Weight)			.saturating_add(T::DbWeight::get().reads(6 as Weight))			.saturating_add(T::DbWeight::get().writes(9 as Weight))	}	fn sign_asset() -> Weight {
-		(73_500_000 as Weight)			.saturating_add(T::DbWeight::get().reads(4 as Weight))			.saturating_add(T::DbWeight::get().writes(3 as Weight))	}}
+		(89_400_000 as Weight)			.saturating_add(T::DbWeight::get().reads(6 as Weight))			.saturating_add(T::DbWeight::get().writes(5 as Weight))	}	fn transfer_batch(i: u32, ) -> Weight {
+		(38_600_000 as Weight)			.saturating_add((52_700_000 as Weight).saturating_mul(i as Weight))			.saturating_add(T::DbWeight::get().reads(1 as Weight))			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(i as Weight)))			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(i as Weight)))	}	fn mint_batch(i: u32, ) -> Weight {
+		(97_300_000 as Weight)			.saturating_add((24_100_000 as Weight).saturating_mul(i as Weight))			.saturating_add(T::DbWeight::get().reads(5 as Weight))			.saturating_add(T::DbWeight::get().writes(4 as Weight))			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(i as Weight)))	}	fn sign_asset() -> Weight {
+		(73_500_000 as Weight)			.saturating_add(T::DbWeight::get().reads(4 as Weight))			.saturating_add(T::DbWeight::get().writes(3 as Weight))	}	fn enable_metadata_updates() -> Weight {
+		(31_200_000 as Weight)			.saturating_add(T::DbWeight::get().reads(3 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn update_class_metadata() -> Weight {
+		(38_900_000 as Weight)			.saturating_add(T::DbWeight::get().reads(4 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn update_token_metadata() -> Weight {
+		(52_400_000 as Weight)			.saturating_add(T::DbWeight::get().reads(5 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}	fn freeze_metadata() -> Weight {
+		(33_600_000 as Weight)			.saturating_add(T::DbWeight::get().reads(4 as Weight))			.saturating_add(T::DbWeight::get().writes(1 as Weight))	}}
 
 // For backwards compatibility and tests
 impl WeightInfo for () {	fn create_group() -> Weight {
 		(36_700_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(2 as Weight))			.saturating_add(RocksDbWeight::get().writes(3 as Weight))	}	fn create_class() -> Weight {
 		(90_500_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(3 as Weight))			.saturating_add(RocksDbWeight::get().writes(4 as Weight))	}	fn mint() -> Weight {
 		(153_400_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(6 as Weight))			.saturating_add(RocksDbWeight::get().writes(11 as Weight))	}	fn transfer() -> Weight {
-		(89_400_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(6 as Weight))			.saturating_add(RocksDbWeight::get().writes(5 as Weight))	}	fn transfer_batch() -> Weight {
-		(131_200_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(6 as Weight))			.saturating_add(RocksDbWeight::get().writes(9 as Weight))	}	fn sign_asset() -> Weight {
-		(73_500_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(4 as Weight))			.saturating_add(RocksDbWeight::get().writes(3 as Weight))	}}
+		(89_400_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(6 as Weight))			.saturating_add(RocksDbWeight::get().writes(5 as Weight))	}	fn transfer_batch(i: u32, ) -> Weight {
+		(38_600_000 as Weight)			.saturating_add((52_700_000 as Weight).saturating_mul(i as Weight))			.saturating_add(RocksDbWeight::get().reads(1 as Weight))			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(i as Weight)))			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(i as Weight)))	}	fn mint_batch(i: u32, ) -> Weight {
+		(97_300_000 as Weight)			.saturating_add((24_100_000 as Weight).saturating_mul(i as Weight))			.saturating_add(RocksDbWeight::get().reads(5 as Weight))			.saturating_add(RocksDbWeight::get().writes(4 as Weight))			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(i as Weight)))	}	fn sign_asset() -> Weight {
+		(73_500_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(4 as Weight))			.saturating_add(RocksDbWeight::get().writes(3 as Weight))	}	fn enable_metadata_updates() -> Weight {
+		(31_200_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(3 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn update_class_metadata() -> Weight {
+		(38_900_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(4 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn update_token_metadata() -> Weight {
+		(52_400_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(5 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}	fn freeze_metadata() -> Weight {
+		(33_600_000 as Weight)			.saturating_add(RocksDbWeight::get().reads(4 as Weight))			.saturating_add(RocksDbWeight::get().writes(1 as Weight))	}}
@@ -0,0 +1,164 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reserve-backed transfer of NFTs over XCM.
+//!
+//! NFTs of local classes are identified by `GeneralIndex(class_id)` under the NFT pallet location
+//! and `AssetInstance::Index(token_id)`. When they leave the chain they are held in the XCM
+//! reserve account until they come back. NFTs of foreign collections registered with
+//! `register_foreign_class` are minted as derivatives in the mapped local class on arrival and
+//! burned when they are sent back to their reserve chain. `ForeignNftReserve` only trusts the chain
+//! a registered collection lives on as its reserve.
+
+use frame_support::traits::Get;
+use sp_runtime::traits::AccountIdConversion;
+use sp_std::{convert::TryFrom, marker::PhantomData};
+use xcm::latest::prelude::*;
+use xcm_executor::{
+	traits::{Convert, FilterAssetLocation, TransactAsset},
+	Assets,
+};
+
+use crate::{ClassIdOf, Config, NFTTrait, Pallet, TokenIdOf, XCM_RESERVE_SUB_ACCOUNT};
+
+/// Reserve filter accepting NFTs of registered foreign collections only from the chain the
+/// collection lives on
+pub struct ForeignNftReserve<T>(PhantomData<T>);
+
+impl<T: Config> FilterAssetLocation for ForeignNftReserve<T> {
+	fn filter_asset_location(asset: &MultiAsset, origin: &MultiLocation) -> bool {
+		match asset {
+			MultiAsset {
+				id: Concrete(location),
+				fun: NonFungible(_),
+			} => Pallet::<T>::foreign_class(location).is_some() && collection_reserve(location).as_ref() == Some(origin),
+			_ => false,
+		}
+	}
+}
+
+/// Chain holding the NFTs of a foreign collection, a sibling parachain or the relay chain
+fn collection_reserve(location: &MultiLocation) -> Option<MultiLocation> {
+	match (location.parents, location.first_interior()) {
+		(1, Some(Parachain(para_id))) => Some(MultiLocation::new(1, X1(Parachain(*para_id)))),
+		(1, _) => Some(MultiLocation::parent()),
+		_ => None,
+	}
+}
+
+/// NFT identified by an XCM asset, either of a local class or a derivative of a foreign
+/// collection
+enum XcmNft<T: Config> {
+	Local(ClassIdOf<T>, TokenIdOf<T>),
+	Foreign(ClassIdOf<T>, u128),
+}
+
+/// Asset transactor moving NFTs in and out of the chain. `NftLocation` is the location of the NFT
+/// pallet as seen by sibling chains.
+pub struct NonFungibleAdapter<T, AccountIdConvert, NftLocation>(PhantomData<(T, AccountIdConvert, NftLocation)>);
+
+impl<T, AccountIdConvert, NftLocation> NonFungibleAdapter<T, AccountIdConvert, NftLocation>
+where
+	T: Config,
+	AccountIdConvert: Convert<MultiLocation, T::AccountId>,
+	NftLocation: Get<MultiLocation>,
+{
+	fn reserve_account() -> T::AccountId {
+		T::PalletId::get().into_sub_account(XCM_RESERVE_SUB_ACCOUNT)
+	}
+
+	fn local_class(location: &MultiLocation) -> Option<ClassIdOf<T>> {
+		let nft_location = NftLocation::get();
+		let class_junction = location.match_and_split(&nft_location).or_else(|| {
+			// Location relative to this chain
+			match (location.parents, &location.interior) {
+				(0, X2(pallet, class_junction)) if Some(pallet) == nft_location.last() => Some(class_junction),
+				_ => None,
+			}
+		})?;
+		match class_junction {
+			GeneralIndex(class_id) => ClassIdOf::<T>::try_from(*class_id).ok(),
+			_ => None,
+		}
+	}
+
+	fn match_nft(asset: &MultiAsset) -> Option<XcmNft<T>> {
+		match asset {
+			MultiAsset {
+				id: Concrete(location),
+				fun: NonFungible(AssetInstance::Index(instance)),
+			} => {
+				if let Some(class_id) = Self::local_class(location) {
+					let token_id = TokenIdOf::<T>::try_from(*instance).ok()?;
+					Some(XcmNft::Local(class_id, token_id))
+				} else {
+					Pallet::<T>::foreign_class(location).map(|class_id| XcmNft::Foreign(class_id, *instance))
+				}
+			}
+			_ => None,
+		}
+	}
+}
+
+impl<T, AccountIdConvert, NftLocation> TransactAsset for NonFungibleAdapter<T, AccountIdConvert, NftLocation>
+where
+	T: Config,
+	AccountIdConvert: Convert<MultiLocation, T::AccountId>,
+	NftLocation: Get<MultiLocation>,
+{
+	fn deposit_asset(what: &MultiAsset, who: &MultiLocation) -> XcmResult {
+		let nft = Self::match_nft(what).ok_or(XcmError::AssetNotFound)?;
+		let who = AccountIdConvert::convert_ref(who)
+			.map_err(|()| XcmError::FailedToTransactAsset("AccountIdConversionFailed"))?;
+
+		match nft {
+			XcmNft::Local(class_id, token_id) => {
+				let reserve_account = Self::reserve_account();
+				Pallet::<T>::transfer_token(&reserve_account, &who, (class_id, token_id))
+					.and_then(|_| Pallet::<T>::transfer_nested_children(&reserve_account, &who, &(class_id, token_id)))
+					.map_err(|_| XcmError::FailedToTransactAsset("NftNotInReserve"))?;
+			}
+			XcmNft::Foreign(class_id, instance) => {
+				Pallet::<T>::do_mint_derivative(&who, class_id, instance)
+					.map_err(|_| XcmError::FailedToTransactAsset("DerivativeMintFailed"))?;
+			}
+		}
+		Ok(())
+	}
+
+	fn withdraw_asset(what: &MultiAsset, who: &MultiLocation) -> Result<Assets, XcmError> {
+		let nft = Self::match_nft(what).ok_or(XcmError::AssetNotFound)?;
+		let who = AccountIdConvert::convert_ref(who)
+			.map_err(|()| XcmError::FailedToTransactAsset("AccountIdConversionFailed"))?;
+
+		match nft {
+			XcmNft::Local(class_id, token_id) => {
+				if Pallet::<T>::check_item_on_listing(class_id, token_id).unwrap_or(true) {
+					return Err(XcmError::FailedToTransactAsset("NftIsListed"));
+				}
+				Pallet::<T>::do_transfer(&who, &Self::reserve_account(), (class_id, token_id))
+					.map_err(|_| XcmError::FailedToTransactAsset("NftNotWithdrawable"))?;
+			}
+			XcmNft::Foreign(class_id, instance) => {
+				let token_id = Pallet::<T>::foreign_token(class_id, instance).ok_or(XcmError::AssetNotFound)?;
+				Pallet::<T>::do_burn(&who, &(class_id, token_id))
+					.map_err(|_| XcmError::FailedToTransactAsset("DerivativeBurnFailed"))?;
+			}
+		}
+		Ok(what.clone().into())
+	}
+}
@@ -0,0 +1,234 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	pallet_prelude::*,
+	storage::{with_transaction, TransactionOutcome},
+	traits::{Currency, ReservableCurrency},
+};
+use frame_system::pallet_prelude::*;
+use sp_runtime::traits::{Hash, Saturating, Zero};
+use sp_std::{boxed::Box, vec::Vec};
+
+use primitives::receipt::OperationReceipt;
+
+pub use module::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Calls that can be dispatched with a receipt.
+		type Call: Parameter
+			+ Dispatchable<Origin = Self::Origin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ From<frame_system::Call<Self>>;
+
+		/// Maximum length of a client correlation id.
+		#[pallet::constant]
+		type MaxCorrelationIdLength: Get<u32>;
+
+		/// Number of most recent receipts kept per account.
+		#[pallet::constant]
+		type MaxReceiptsPerAccount: Get<u32>;
+
+		/// Currency used to reserve the storage deposit of receipts.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Deposit reserved for every receipt slot an account occupies.
+		#[pallet::constant]
+		type ReceiptDeposit: Get<BalanceOf<Self>>;
+	}
+
+	/// Next receipt nonce of each account.
+	#[pallet::storage]
+	#[pallet::getter(fn receipt_nonce)]
+	pub type ReceiptNonce<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Ring buffer of recent receipts per account, keyed by slot.
+	#[pallet::storage]
+	#[pallet::getter(fn receipt_of)]
+	pub type Receipts<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		u32,
+		OperationReceipt<T::Hash, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// Storage deposit reserved by each account for its receipts.
+	#[pallet::storage]
+	#[pallet::getter(fn receipt_deposit_of)]
+	pub type ReceiptDeposits<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Receipt recorded for a dispatched call
+		ReceiptRecorded {
+			who: T::AccountId,
+			nonce: u32,
+			correlation_id: Vec<u8>,
+			outcome: DispatchResult,
+		},
+		/// Receipts of an account were cleared and their deposit released
+		ReceiptsCleared { who: T::AccountId, deposit: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Correlation id is empty
+		EmptyCorrelationId,
+		/// Correlation id exceeds the maximum length
+		CorrelationIdTooLong,
+	}
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Dispatch a call and record its outcome under the given correlation id. The receipt is
+		/// kept even if the call fails, in which case the changes of the call are reverted. Writing
+		/// to an empty receipt slot reserves the receipt deposit of the caller.
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(
+				dispatch_info.weight
+					.saturating_add(10_000)
+					.saturating_add(T::DbWeight::get().reads_writes(3, 4)),
+				dispatch_info.class,
+			)
+		})]
+		pub fn dispatch_with_receipt(
+			origin: OriginFor<T>,
+			correlation_id: Vec<u8>,
+			call: Box<<T as Config>::Call>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin.clone())?;
+			ensure!(!correlation_id.is_empty(), Error::<T>::EmptyCorrelationId);
+			ensure!(
+				correlation_id.len() as u32 <= T::MaxCorrelationIdLength::get(),
+				Error::<T>::CorrelationIdTooLong
+			);
+
+			Self::reserve_receipt_deposit(&who)?;
+
+			let call_hash = T::Hashing::hash_of(&call);
+			let outcome = with_transaction(|| {
+				let result = call.dispatch(origin).map(|_| ()).map_err(|e| e.error);
+				if result.is_ok() {
+					TransactionOutcome::Commit(result)
+				} else {
+					TransactionOutcome::Rollback(result)
+				}
+			});
+
+			Self::record_receipt(&who, correlation_id, call_hash, outcome);
+			Ok(().into())
+		}
+
+		/// Remove all receipts of the caller and release their deposit. The receipt nonce is kept
+		/// so later receipts do not reuse nonces.
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2, 2 + T::MaxReceiptsPerAccount::get() as u64))]
+		pub fn clear_receipts(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Receipts::<T>::remove_prefix(&who, None);
+			let deposit = ReceiptDeposits::<T>::take(&who);
+			T::Currency::unreserve(&who, deposit);
+
+			Self::deposit_event(Event::ReceiptsCleared { who, deposit });
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Reserve the receipt deposit if the next receipt of the account goes into an empty slot
+	fn reserve_receipt_deposit(who: &T::AccountId) -> DispatchResult {
+		let max_receipts = T::MaxReceiptsPerAccount::get();
+		if max_receipts == 0 || Receipts::<T>::contains_key(who, Self::receipt_nonce(who) % max_receipts) {
+			return Ok(());
+		}
+
+		let deposit = T::ReceiptDeposit::get();
+		if deposit.is_zero() {
+			return Ok(());
+		}
+		T::Currency::reserve(who, deposit)?;
+		ReceiptDeposits::<T>::mutate(who, |reserved| *reserved = reserved.saturating_add(deposit));
+		Ok(())
+	}
+
+	fn record_receipt(who: &T::AccountId, correlation_id: Vec<u8>, call_hash: T::Hash, outcome: DispatchResult) {
+		let max_receipts = T::MaxReceiptsPerAccount::get();
+		if max_receipts == 0 {
+			return;
+		}
+
+		let nonce = ReceiptNonce::<T>::mutate(who, |next| {
+			let nonce = *next;
+			*next = next.wrapping_add(1);
+			nonce
+		});
+		let receipt = OperationReceipt {
+			nonce,
+			correlation_id: correlation_id.clone(),
+			call_hash,
+			outcome,
+			block: <frame_system::Pallet<T>>::block_number(),
+		};
+		Receipts::<T>::insert(who, nonce % max_receipts, receipt);
+
+		Self::deposit_event(Event::ReceiptRecorded {
+			who: who.clone(),
+			nonce,
+			correlation_id,
+			outcome,
+		});
+	}
+
+	/// Receipts of the account kept in the ring buffer, oldest first
+	pub fn account_receipts(who: &T::AccountId) -> Vec<OperationReceipt<T::Hash, T::BlockNumber>> {
+		let mut receipts: Vec<OperationReceipt<T::Hash, T::BlockNumber>> =
+			Receipts::<T>::iter_prefix_values(who).collect();
+		receipts.sort_by_key(|receipt| receipt.nonce);
+		receipts
+	}
+}
@@ -0,0 +1,149 @@
+#![cfg(test)]
+
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{traits::BlakeTwo256, DispatchError};
+
+use mock::{Event, *};
+
+use super::*;
+
+fn remark_call() -> Box<Call> {
+	Box::new(Call::System(frame_system::Call::remark { remark: vec![1] }))
+}
+
+fn root_only_call() -> Box<Call> {
+	Box::new(Call::System(frame_system::Call::set_heap_pages { pages: 1 }))
+}
+
+#[test]
+fn dispatch_with_receipt_should_record_receipt_and_reserve_deposit() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ReceiptsModule::dispatch_with_receipt(
+			Origin::signed(ALICE),
+			b"order-1".to_vec(),
+			remark_call()
+		));
+
+		assert_eq!(
+			ReceiptsModule::account_receipts(&ALICE),
+			vec![OperationReceipt {
+				nonce: 0,
+				correlation_id: b"order-1".to_vec(),
+				call_hash: BlakeTwo256::hash_of(&remark_call()),
+				outcome: Ok(()),
+				block: 1,
+			}]
+		);
+		assert_eq!(ReceiptsModule::receipt_nonce(ALICE), 1);
+		assert_eq!(Balances::reserved_balance(ALICE), 10);
+		assert_eq!(ReceiptsModule::receipt_deposit_of(ALICE), 10);
+		assert_eq!(
+			last_event(),
+			Event::Receipts(crate::Event::ReceiptRecorded {
+				who: ALICE,
+				nonce: 0,
+				correlation_id: b"order-1".to_vec(),
+				outcome: Ok(()),
+			})
+		);
+	});
+}
+
+#[test]
+fn dispatch_with_receipt_should_keep_receipt_of_failed_call() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ReceiptsModule::dispatch_with_receipt(
+			Origin::signed(ALICE),
+			b"order-1".to_vec(),
+			root_only_call()
+		));
+
+		let receipts = ReceiptsModule::account_receipts(&ALICE);
+		assert_eq!(receipts.len(), 1);
+		assert_eq!(receipts[0].outcome, Err(DispatchError::BadOrigin));
+		assert_eq!(Balances::reserved_balance(ALICE), 10);
+	});
+}
+
+#[test]
+fn dispatch_with_receipt_should_reject_invalid_correlation_id() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ReceiptsModule::dispatch_with_receipt(Origin::signed(ALICE), vec![], remark_call()),
+			Error::<Runtime>::EmptyCorrelationId
+		);
+		assert_noop!(
+			ReceiptsModule::dispatch_with_receipt(Origin::signed(ALICE), b"order-123".to_vec(), remark_call()),
+			Error::<Runtime>::CorrelationIdTooLong
+		);
+	});
+}
+
+#[test]
+fn dispatch_with_receipt_should_fail_without_deposit() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ReceiptsModule::dispatch_with_receipt(Origin::signed(BOB), b"order-1".to_vec(), remark_call()),
+			pallet_balances::Error::<Runtime>::InsufficientBalance
+		);
+		assert_eq!(ReceiptsModule::account_receipts(&BOB), vec![]);
+	});
+}
+
+#[test]
+fn ring_buffer_should_reuse_slots_without_new_deposit() {
+	ExtBuilder::default().build().execute_with(|| {
+		for correlation_id in [b"order-1", b"order-2", b"order-3"] {
+			assert_ok!(ReceiptsModule::dispatch_with_receipt(
+				Origin::signed(ALICE),
+				correlation_id.to_vec(),
+				remark_call()
+			));
+		}
+
+		// Only the two most recent receipts are kept and each slot is paid for once
+		let receipts = ReceiptsModule::account_receipts(&ALICE);
+		assert_eq!(
+			receipts.iter().map(|receipt| receipt.nonce).collect::<Vec<_>>(),
+			vec![1, 2]
+		);
+		assert_eq!(receipts[1].correlation_id, b"order-3".to_vec());
+		assert_eq!(Balances::reserved_balance(ALICE), 20);
+		assert_eq!(ReceiptsModule::receipt_deposit_of(ALICE), 20);
+	});
+}
+
+#[test]
+fn clear_receipts_should_release_deposit_and_keep_nonce() {
+	ExtBuilder::default().build().execute_with(|| {
+		for correlation_id in [b"order-1", b"order-2"] {
+			assert_ok!(ReceiptsModule::dispatch_with_receipt(
+				Origin::signed(ALICE),
+				correlation_id.to_vec(),
+				remark_call()
+			));
+		}
+
+		assert_ok!(ReceiptsModule::clear_receipts(Origin::signed(ALICE)));
+		assert_eq!(ReceiptsModule::account_receipts(&ALICE), vec![]);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(ReceiptsModule::receipt_deposit_of(ALICE), 0);
+		assert_eq!(ReceiptsModule::receipt_nonce(ALICE), 2);
+		assert_eq!(
+			last_event(),
+			Event::Receipts(crate::Event::ReceiptsCleared {
+				who: ALICE,
+				deposit: 20
+			})
+		);
+
+		// Next receipt takes a new slot and reserves its deposit again
+		assert_ok!(ReceiptsModule::dispatch_with_receipt(
+			Origin::signed(ALICE),
+			b"order-3".to_vec(),
+			remark_call()
+		));
+		assert_eq!(ReceiptsModule::account_receipts(&ALICE)[0].nonce, 2);
+		assert_eq!(Balances::reserved_balance(ALICE), 10);
+	});
+}
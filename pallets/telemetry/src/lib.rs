@@ -0,0 +1,112 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use sp_runtime::traits::SaturatedConversion;
+use sp_std::vec::Vec;
+
+use primitives::telemetry::{ActivityCounter, ActivityCounters, ActivityRecorder};
+
+pub use module::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Number of recent blocks kept in the activity ring buffer.
+		#[pallet::constant]
+		type ActivityHistoryLength: Get<u32>;
+	}
+
+	/// Activity counted in the current block.
+	#[pallet::storage]
+	#[pallet::getter(fn current_activity)]
+	pub type CurrentActivity<T: Config> = StorageValue<_, ActivityCounters, ValueQuery>;
+
+	/// Ring buffer of recent block activity, keyed by slot.
+	#[pallet::storage]
+	#[pallet::getter(fn activity_history)]
+	pub type ActivityHistory<T: Config> =
+		StorageMap<_, Twox64Concat, u32, (T::BlockNumber, ActivityCounters), OptionQuery>;
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(_n: T::BlockNumber) -> Weight {
+			// Activity of the block is moved into the ring buffer on finalize
+			T::DbWeight::get().reads_writes(1, 2)
+		}
+
+		fn on_finalize(n: T::BlockNumber) {
+			let activity = CurrentActivity::<T>::take();
+			let history_length = T::ActivityHistoryLength::get();
+			if history_length == 0 {
+				return;
+			}
+			let slot = n.saturated_into::<u32>() % history_length;
+			ActivityHistory::<T>::insert(slot, (n, activity));
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Activity of the recent blocks kept in the ring buffer, oldest first
+	pub fn recent_activity() -> Vec<(T::BlockNumber, ActivityCounters)> {
+		let mut history: Vec<(T::BlockNumber, ActivityCounters)> = ActivityHistory::<T>::iter_values().collect();
+		history.sort_by_key(|(block, _)| *block);
+		history
+	}
+
+	/// Activity summed over the recent blocks kept in the ring buffer
+	pub fn recent_activity_totals() -> ActivityCounters {
+		ActivityHistory::<T>::iter_values().fold(ActivityCounters::default(), |mut totals, (_, activity)| {
+			totals.xcm_messages_processed = totals
+				.xcm_messages_processed
+				.saturating_add(activity.xcm_messages_processed);
+			totals.auctions_settled = totals.auctions_settled.saturating_add(activity.auctions_settled);
+			totals.nfts_minted = totals.nfts_minted.saturating_add(activity.nfts_minted);
+			totals.land_units_deployed = totals.land_units_deployed.saturating_add(activity.land_units_deployed);
+			totals
+		})
+	}
+}
+
+impl<T: Config> ActivityRecorder for Pallet<T> {
+	fn record_activity(counter: ActivityCounter, count: u32) {
+		if count == 0 {
+			return;
+		}
+		CurrentActivity::<T>::mutate(|activity| activity.record(counter, count));
+	}
+}
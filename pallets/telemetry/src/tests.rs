@@ -0,0 +1,45 @@
+#![cfg(test)]
+
+use mock::*;
+
+use super::*;
+
+fn counters(auctions_settled: u32, nfts_minted: u32) -> ActivityCounters {
+	ActivityCounters {
+		auctions_settled,
+		nfts_minted,
+		..Default::default()
+	}
+}
+
+#[test]
+fn recorded_activity_should_be_moved_into_ring_buffer_on_finalize() {
+	ExtBuilder::default().build().execute_with(|| {
+		TelemetryModule::record_activity(ActivityCounter::AuctionsSettled, 2);
+		TelemetryModule::record_activity(ActivityCounter::NftsMinted, 5);
+		TelemetryModule::record_activity(ActivityCounter::NftsMinted, 0);
+		assert_eq!(TelemetryModule::current_activity(), counters(2, 5));
+
+		next_block();
+
+		assert_eq!(TelemetryModule::current_activity(), ActivityCounters::default());
+		assert_eq!(TelemetryModule::recent_activity(), vec![(1, counters(2, 5))]);
+	});
+}
+
+#[test]
+fn ring_buffer_should_keep_most_recent_blocks_only() {
+	ExtBuilder::default().build().execute_with(|| {
+		for auctions_settled in 1..=4 {
+			TelemetryModule::record_activity(ActivityCounter::AuctionsSettled, auctions_settled);
+			next_block();
+		}
+
+		// History length of 3 drops block 1
+		assert_eq!(
+			TelemetryModule::recent_activity(),
+			vec![(2, counters(2, 0)), (3, counters(3, 0)), (4, counters(4, 0))]
+		);
+		assert_eq!(TelemetryModule::recent_activity_totals(), counters(9, 0));
+	});
+}
@@ -32,7 +32,7 @@ use frame_system::pallet_prelude::*;
 use orml_traits::{LockIdentifier, MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency};
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{AccountIdConversion, AtLeast32Bit, One, StaticLookup, Zero},
+	traits::{AccountIdConversion, AtLeast32Bit, One, Saturating, StaticLookup, Zero},
 	DispatchError,
 };
 use sp_runtime::{FixedPointNumber, SaturatedConversion};
@@ -43,7 +43,7 @@ use auction_manager::SwapManager;
 use core_primitives::*;
 pub use pallet::*;
 pub use pallet::*;
-use primitives::dex::Price;
+use primitives::dex::{Price, TradingPair};
 use primitives::{Balance, FungibleTokenId, MetaverseId, VestingSchedule};
 
 #[cfg(test)]
@@ -64,11 +64,58 @@ pub struct Token<Balance> {
 	pub total_supply: Balance,
 }
 
+/// Team or treasury allocation of a metaverse token which is vested at issuance time.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct TokenAllocation<AccountId, BlockNumber> {
+	/// Account receiving the allocation
+	pub beneficiary: AccountId,
+	/// Total allocated amount
+	pub amount: Balance,
+	/// Number of blocks after issuance before the first vest
+	pub cliff: BlockNumber,
+	/// Number of blocks between vest
+	pub period: BlockNumber,
+	/// Number of vest
+	pub period_count: u32,
+}
+
 /// The maximum number of vesting schedules an account can have.
 pub const MAX_VESTINGS: usize = 20;
 
 pub const VESTING_LOCK_ID: LockIdentifier = *b"bcstvest";
 
+pub const POOL_LIQUIDITY_LOCK_ID: LockIdentifier = *b"bcpoollk";
+
+/// Liquidity pool shares of a metaverse token locked in the metaverse treasury after issuance.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PoolLiquidityLock<AccountId, BlockNumber> {
+	/// Liquidity pool share currency
+	pub share_currency_id: FungibleTokenId,
+	/// Metaverse treasury holding the shares
+	pub holder: AccountId,
+	/// Locked share amount
+	pub amount: Balance,
+	/// Block after which the shares can be unlocked
+	pub unlock_at: BlockNumber,
+}
+
+/// The maximum length of a metaverse local token name.
+pub const MAX_LOCAL_TOKEN_NAME_LENGTH: usize = 32;
+
+/// The maximum length of a metaverse local token symbol.
+pub const MAX_LOCAL_TOKEN_SYMBOL_LENGTH: usize = 8;
+
+/// Fungible token local to a metaverse, created by its owner and minted by its governance.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct LocalTokenInfo {
+	/// Token name
+	pub name: TokenName,
+	/// Token symbol
+	pub symbol: Ticker,
+	/// Maximum total issuance of the token
+	pub max_supply: Balance,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use primitives::{FungibleTokenId, TokenId, VestingSchedule};
@@ -80,6 +127,8 @@ pub mod pallet {
 	pub struct Pallet<T>(PhantomData<T>);
 
 	pub(crate) type VestingScheduleOf<T> = VestingSchedule<<T as frame_system::Config>::BlockNumber, Balance>;
+	pub type TokenAllocationOf<T> =
+		TokenAllocation<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber>;
 	pub type ScheduledItem<T> = (
 		<T as frame_system::Config>::AccountId,
 		<T as frame_system::Config>::BlockNumber,
@@ -103,6 +152,11 @@ pub mod pallet {
 		type MinVestedTransfer: Get<Balance>;
 		/// Required origin for vested transfer.
 		type VestedTransferOrigin: EnsureOrigin<Self::Origin, Success = Self::AccountId>;
+		#[pallet::constant]
+		/// Number of blocks the liquidity pool shares created at token issuance stay locked.
+		type PoolLiquidityLockPeriod: Get<Self::BlockNumber>;
+		/// Origin of a passed referendum of a metaverse, minting and burning its local token.
+		type MetaverseGovernanceOrigin: EnsureOrigin<Self::Origin, Success = MetaverseId>;
 	}
 
 	#[pallet::storage]
@@ -130,6 +184,23 @@ pub mod pallet {
 	pub type VestingSchedules<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, Vec<VestingScheduleOf<T>>, ValueQuery>;
 
+	/// Vested allocations defined when the metaverse token was issued.
+	#[pallet::storage]
+	#[pallet::getter(fn token_allocations)]
+	pub type TokenAllocations<T: Config> =
+		StorageMap<_, Blake2_128Concat, FungibleTokenId, Vec<(T::AccountId, VestingScheduleOf<T>)>, ValueQuery>;
+
+	/// Liquidity pool shares locked at token issuance of a metaverse.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_liquidity_locks)]
+	pub type PoolLiquidityLocks<T: Config> =
+		StorageMap<_, Blake2_128Concat, MetaverseId, PoolLiquidityLock<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	/// Local fungible token of a metaverse, issued under `FungibleTokenId::MetaverseCurrency`.
+	#[pallet::storage]
+	#[pallet::getter(fn local_tokens)]
+	pub type LocalTokens<T: Config> = StorageMap<_, Twox64Concat, MetaverseId, LocalTokenInfo, OptionQuery>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Transfer amount should be non-zero
@@ -166,6 +237,20 @@ pub mod pallet {
 		InvalidVestingSchedule,
 		/// Invalid request
 		InvalidRequest,
+		/// Token allocations exceed the metaverse owner supply
+		TokenAllocationExceedsSupply,
+		/// Metaverse has no locked liquidity pool shares
+		PoolLiquidityLockNotFound,
+		/// Liquidity pool shares are still locked
+		PoolLiquidityStillLocked,
+		/// Metaverse is not in sunset
+		MetaverseNotInSunset,
+		/// Local token name or symbol is empty or too long, or supply cap is zero
+		InvalidLocalToken,
+		/// Metaverse has no local token
+		LocalTokenNotFound,
+		/// Minting would exceed the supply cap of the local token
+		LocalTokenSupplyCapExceeded,
 	}
 
 	#[pallet::call]
@@ -173,7 +258,16 @@ pub mod pallet {
 		/// Issue a new class of fungible assets for metaverse. There are, and will only ever be,
 		/// `total` such assets and they'll all belong to the `origin` initially. It will have an
 		/// identifier `TokenId` instance: this will be specified in the `Issued` event.
+		///
+		/// Team and treasury `allocations` are taken out of the metaverse owner supply and locked
+		/// with their own cliff and linear vesting schedule.
+		///
+		/// With non-zero `initial_backing` a swap pool against the native token is created from the
+		/// `initial_lp` share of the supply. Its shares are held by the metaverse treasury and locked
+		/// for `PoolLiquidityLockPeriod`. With zero backing no pool is created and the `initial_lp`
+		/// share stays in the metaverse fund.
 		#[pallet::weight(10_000)]
+		#[transactional]
 		pub fn mint_token(
 			origin: OriginFor<T>,
 			ticker: Ticker,
@@ -182,6 +276,7 @@ pub mod pallet {
 			initial_lp: (u32, u32),
 			initial_backing: Balance,
 			metaverse_owner: T::AccountId,
+			allocations: Vec<TokenAllocationOf<T>>,
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
 			ensure!(
@@ -200,6 +295,7 @@ pub mod pallet {
 				total_supply,
 				initial_lp,
 				initial_backing,
+				allocations,
 			)?;
 
 			Ok(().into())
@@ -258,6 +354,170 @@ pub mod pallet {
 			Self::deposit_event(Event::VestingSchedulesUpdated(currency_id, account));
 			Ok(().into())
 		}
+
+		/// Unlock the liquidity pool shares of the metaverse token once the lock period is over.
+		#[pallet::weight(10_000)]
+		pub fn unlock_pool_liquidity(origin: OriginFor<T>, metaverse_id: MetaverseId) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let lock = Self::pool_liquidity_locks(metaverse_id).ok_or(Error::<T>::PoolLiquidityLockNotFound)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() >= lock.unlock_at,
+				Error::<T>::PoolLiquidityStillLocked
+			);
+
+			T::MetaverseMultiCurrency::remove_lock(POOL_LIQUIDITY_LOCK_ID, lock.share_currency_id, &lock.holder)?;
+			PoolLiquidityLocks::<T>::remove(metaverse_id);
+
+			Self::deposit_event(Event::PoolLiquidityUnlocked(
+				metaverse_id,
+				lock.share_currency_id,
+				lock.holder,
+				lock.amount,
+			));
+			Ok(().into())
+		}
+
+		/// Redeem metaverse token of a metaverse in sunset for its pro-rata share of the native token
+		/// left in the metaverse treasury. Redeemed tokens are burned.
+		#[pallet::weight(10_000)]
+		#[transactional]
+		pub fn redeem_sunset_token(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			amount: Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				T::MetaverseInfoSource::is_metaverse_sunset(metaverse_id),
+				Error::<T>::MetaverseNotInSunset
+			);
+			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+
+			let metaverse_fund =
+				MetaverseTreasury::<T>::get(metaverse_id).ok_or(Error::<T>::MetaverseFundIsNotAvailable)?;
+			let currency_id = metaverse_fund.currency_id;
+			let total_issuance = T::MetaverseMultiCurrency::total_issuance(currency_id);
+			let treasury = T::MetaverseInfoSource::get_metaverse_treasury(metaverse_id);
+			let treasury_balance = T::MetaverseMultiCurrency::free_balance(FungibleTokenId::NativeToken(0), &treasury);
+			let native_amount = Price::checked_from_rational(amount, total_issuance)
+				.ok_or(Error::<T>::NumOverflow)?
+				.saturating_mul_int(treasury_balance);
+
+			T::MetaverseMultiCurrency::withdraw(currency_id, &who, amount)?;
+			T::MetaverseMultiCurrency::transfer(FungibleTokenId::NativeToken(0), &treasury, &who, native_amount)?;
+
+			Self::deposit_event(Event::SunsetTokenRedeemed(
+				metaverse_id,
+				currency_id,
+				who,
+				amount,
+				native_amount,
+			));
+			Ok(().into())
+		}
+
+		/// Create the local fungible token of a metaverse. Only the metaverse owner can create it
+		/// and a metaverse can have either a local token or a social token, never both. No supply
+		/// is issued until metaverse governance mints it.
+		#[pallet::weight(10_000)]
+		pub fn create_local_token(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			name: TokenName,
+			symbol: Ticker,
+			max_supply: Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				T::MetaverseInfoSource::check_ownership(&who, &metaverse_id),
+				Error::<T>::NoPermissionTokenIssuance
+			);
+			ensure!(
+				!LocalTokens::<T>::contains_key(metaverse_id) && !MetaverseTreasury::<T>::contains_key(metaverse_id),
+				Error::<T>::FungibleTokenAlreadyIssued
+			);
+			ensure!(
+				!name.is_empty()
+					&& name.len() <= MAX_LOCAL_TOKEN_NAME_LENGTH
+					&& !symbol.is_empty()
+					&& symbol.len() <= MAX_LOCAL_TOKEN_SYMBOL_LENGTH
+					&& !max_supply.is_zero(),
+				Error::<T>::InvalidLocalToken
+			);
+
+			let currency_id = FungibleTokenId::MetaverseCurrency(metaverse_id);
+			T::MetaverseInfoSource::update_metaverse_token(metaverse_id, currency_id)?;
+			LocalTokens::<T>::insert(
+				metaverse_id,
+				LocalTokenInfo {
+					name,
+					symbol,
+					max_supply,
+				},
+			);
+
+			Self::deposit_event(Event::LocalTokenCreated(metaverse_id, currency_id, who, max_supply));
+			Ok(().into())
+		}
+
+		/// Mint local token of a metaverse to the beneficiary, up to the supply cap.
+		/// Only metaverse governance can mint.
+		#[pallet::weight(10_000)]
+		pub fn mint_local_token(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			beneficiary: T::AccountId,
+			amount: Balance,
+		) -> DispatchResultWithPostInfo {
+			let origin_metaverse_id = T::MetaverseGovernanceOrigin::ensure_origin(origin)?;
+			ensure!(
+				origin_metaverse_id == metaverse_id,
+				Error::<T>::NoPermissionTokenIssuance
+			);
+			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+
+			let local_token = Self::local_tokens(metaverse_id).ok_or(Error::<T>::LocalTokenNotFound)?;
+			let currency_id = FungibleTokenId::MetaverseCurrency(metaverse_id);
+			let new_issuance = T::MetaverseMultiCurrency::total_issuance(currency_id)
+				.checked_add(amount)
+				.ok_or(Error::<T>::NumOverflow)?;
+			ensure!(
+				new_issuance <= local_token.max_supply,
+				Error::<T>::LocalTokenSupplyCapExceeded
+			);
+
+			T::MetaverseMultiCurrency::deposit(currency_id, &beneficiary, amount)?;
+
+			Self::deposit_event(Event::LocalTokenMinted(metaverse_id, beneficiary, amount));
+			Ok(().into())
+		}
+
+		/// Burn local token of a metaverse held by the metaverse treasury.
+		/// Only metaverse governance can burn.
+		#[pallet::weight(10_000)]
+		pub fn burn_local_token(
+			origin: OriginFor<T>,
+			metaverse_id: MetaverseId,
+			amount: Balance,
+		) -> DispatchResultWithPostInfo {
+			let origin_metaverse_id = T::MetaverseGovernanceOrigin::ensure_origin(origin)?;
+			ensure!(
+				origin_metaverse_id == metaverse_id,
+				Error::<T>::NoPermissionTokenIssuance
+			);
+			ensure!(!amount.is_zero(), Error::<T>::AmountZero);
+			ensure!(
+				LocalTokens::<T>::contains_key(metaverse_id),
+				Error::<T>::LocalTokenNotFound
+			);
+
+			let treasury = T::MetaverseInfoSource::get_metaverse_treasury(metaverse_id);
+			T::MetaverseMultiCurrency::withdraw(FungibleTokenId::MetaverseCurrency(metaverse_id), &treasury, amount)?;
+
+			Self::deposit_event(Event::LocalTokenBurned(metaverse_id, treasury, amount));
+			Ok(().into())
+		}
 	}
 
 	#[pallet::event]
@@ -275,6 +535,20 @@ pub mod pallet {
 		Claimed(FungibleTokenId, T::AccountId, Balance),
 		/// Updated vesting schedules. [token, who]
 		VestingSchedulesUpdated(FungibleTokenId, T::AccountId),
+		/// Liquidity pool shares locked in metaverse treasury. [metaverse_id, share_token, treasury,
+		/// amount, unlock_at]
+		PoolLiquidityLocked(MetaverseId, FungibleTokenId, T::AccountId, Balance, T::BlockNumber),
+		/// Liquidity pool shares unlocked. [metaverse_id, share_token, treasury, amount]
+		PoolLiquidityUnlocked(MetaverseId, FungibleTokenId, T::AccountId, Balance),
+		/// Metaverse token redeemed against the treasury of a metaverse in sunset. [metaverse_id,
+		/// token, who, redeemed_amount, native_amount]
+		SunsetTokenRedeemed(MetaverseId, FungibleTokenId, T::AccountId, Balance, Balance),
+		/// Local token of a metaverse created. [metaverse_id, token, owner, max_supply]
+		LocalTokenCreated(MetaverseId, FungibleTokenId, T::AccountId, Balance),
+		/// Local token of a metaverse minted. [metaverse_id, beneficiary, amount]
+		LocalTokenMinted(MetaverseId, T::AccountId, Balance),
+		/// Local token of a metaverse burned. [metaverse_id, treasury, amount]
+		LocalTokenBurned(MetaverseId, T::AccountId, Balance),
 	}
 
 	#[pallet::hooks]
@@ -289,6 +563,7 @@ impl<T: Config> Pallet<T> {
 		total_supply: Balance,
 		initial_lp: (u32, u32),
 		initial_backing: Balance,
+		allocations: Vec<TokenAllocationOf<T>>,
 	) -> DispatchResult {
 		let initial_pool_numerator = total_supply.saturating_mul(initial_lp.0.saturated_into());
 		let initial_pool_supply = initial_pool_numerator
@@ -297,8 +572,19 @@ impl<T: Config> Pallet<T> {
 		let initial_supply_ratio = Price::checked_from_rational(initial_pool_supply, total_supply).unwrap_or_default();
 		let supply_percent: u128 = initial_supply_ratio.saturating_mul_int(100.saturated_into());
 		ensure!(supply_percent >= 20u128, Error::<T>::InitialFungibleTokenSupplyIsTooLow);
+		ensure!(allocations.len() <= MAX_VESTINGS, Error::<T>::TooManyVestingSchedules);
 		// Remaining balance for metaverse owner
 		let owner_supply = total_supply.saturating_sub(initial_pool_supply);
+		let allocated_supply = allocations
+			.iter()
+			.try_fold::<_, _, Result<Balance, Error<T>>>(Zero::zero(), |acc_amount, allocation| {
+				acc_amount.checked_add(allocation.amount).ok_or(Error::<T>::NumOverflow)
+			})?;
+		ensure!(
+			allocated_supply <= owner_supply,
+			Error::<T>::TokenAllocationExceedsSupply
+		);
+		let owner_supply = owner_supply.saturating_sub(allocated_supply);
 		// Generate new TokenId
 		let currency_id = NextTokenId::<T>::mutate(|id| -> Result<FungibleTokenId, DispatchError> {
 			let current_id = *id;
@@ -333,13 +619,16 @@ impl<T: Config> Pallet<T> {
 		T::MetaverseMultiCurrency::transfer(FungibleTokenId::NativeToken(0), &who, &fund_id, initial_backing.clone())?;
 		T::MetaverseMultiCurrency::deposit(currency_id, &fund_id, total_supply.clone())?;
 		// Social currency should deposit to DEX pool instead, by calling provide LP function in DEX traits.
-		T::LiquidityPoolManager::add_liquidity(
-			&fund_id,
-			FungibleTokenId::NativeToken(0),
-			currency_id,
-			initial_backing,
-			initial_pool_supply,
-		)?;
+		if !initial_backing.is_zero() {
+			T::LiquidityPoolManager::add_liquidity(
+				&fund_id,
+				FungibleTokenId::NativeToken(0),
+				currency_id,
+				initial_backing,
+				initial_pool_supply,
+			)?;
+			Self::lock_pool_liquidity(metaverse_id, currency_id, &fund_id)?;
+		}
 
 		// The remaining token will be vested gradually 12 months.
 		let now = <frame_system::Pallet<T>>::block_number();
@@ -364,6 +653,35 @@ impl<T: Config> Pallet<T> {
 			vesting_schedule,
 		));
 
+		// Team and treasury allocations start vesting after their cliff
+		let mut vested_allocations: Vec<(T::AccountId, VestingScheduleOf<T>)> = Vec::new();
+		for allocation in allocations {
+			ensure!(!allocation.period_count.is_zero(), Error::<T>::ZeroVestingPeriodCount);
+			let allocation_schedule = VestingSchedule {
+				token: currency_id,
+				start: now.saturating_add(allocation.cliff),
+				period: allocation.period,
+				period_count: allocation.period_count,
+				// Remainder of the division stays in the metaverse fund
+				per_period: allocation.amount / allocation.period_count as Balance,
+			};
+
+			Self::do_vested_transfer(
+				&fund_id,
+				&allocation.beneficiary,
+				currency_id,
+				allocation_schedule.clone(),
+			)?;
+			Self::deposit_event(Event::VestingScheduleAdded(
+				currency_id,
+				fund_id.clone(),
+				allocation.beneficiary.clone(),
+				allocation_schedule.clone(),
+			));
+			vested_allocations.push((allocation.beneficiary, allocation_schedule));
+		}
+		TokenAllocations::<T>::insert(currency_id, vested_allocations);
+
 		let fund_address = Self::get_metaverse_fund_id(metaverse_id);
 		Self::deposit_event(Event::<T>::FungibleTokenIssued(
 			currency_id.clone(),
@@ -376,6 +694,44 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Move the liquidity pool shares of the new token to the metaverse treasury and lock them
+	fn lock_pool_liquidity(
+		metaverse_id: MetaverseId,
+		currency_id: FungibleTokenId,
+		fund_id: &T::AccountId,
+	) -> DispatchResult {
+		let share_currency_id = TradingPair::new(FungibleTokenId::NativeToken(0), currency_id)
+			.get_dex_share_social_currency_id()
+			.ok_or(Error::<T>::InvalidRequest)?;
+		let amount = T::MetaverseMultiCurrency::free_balance(share_currency_id, fund_id);
+		if amount.is_zero() {
+			return Ok(());
+		}
+
+		let holder = T::MetaverseInfoSource::get_metaverse_treasury(metaverse_id);
+		T::MetaverseMultiCurrency::transfer(share_currency_id, fund_id, &holder, amount)?;
+		T::MetaverseMultiCurrency::set_lock(POOL_LIQUIDITY_LOCK_ID, share_currency_id, &holder, amount)?;
+
+		let unlock_at = <frame_system::Pallet<T>>::block_number().saturating_add(T::PoolLiquidityLockPeriod::get());
+		PoolLiquidityLocks::<T>::insert(
+			metaverse_id,
+			PoolLiquidityLock {
+				share_currency_id,
+				holder: holder.clone(),
+				amount,
+				unlock_at,
+			},
+		);
+		Self::deposit_event(Event::PoolLiquidityLocked(
+			metaverse_id,
+			share_currency_id,
+			holder,
+			amount,
+			unlock_at,
+		));
+		Ok(())
+	}
+
 	fn transfer_from(
 		currency_id: FungibleTokenId,
 		from: &T::AccountId,
@@ -29,8 +29,10 @@ pub const ALICE: AccountId = 4;
 pub const BOB: AccountId = 5;
 pub const METAVERSE_ID: MetaverseId = 1;
 pub const METAVERSE_ID_NOT_EXIST: MetaverseId = 1;
+pub const METAVERSE_ID_IN_SUNSET: MetaverseId = 2;
 pub const NUUM: CurrencyId = 0;
 pub const METAVERSE_FUND: FungibleTokenId = FungibleTokenId::FungibleToken(1);
+pub const METAVERSE_GOVERNANCE: AccountId = 100;
 
 ord_parameter_types! {
 	pub const One: AccountId = ALICE;
@@ -142,6 +144,14 @@ impl MetaverseTrait<AccountId> for MetaverseInfoSource {
 	fn get_metaverse_estate_class(metaverse_id: MetaverseId) -> ClassId {
 		16u32
 	}
+
+	fn get_metaverse_treasury(metaverse_id: MetaverseId) -> AccountId {
+		100
+	}
+
+	fn is_metaverse_sunset(metaverse_id: MetaverseId) -> bool {
+		metaverse_id == METAVERSE_ID_IN_SUNSET
+	}
 }
 
 pub struct DEXManager {}
@@ -180,10 +190,13 @@ impl currencies::Config for Runtime {
 	type MultiSocialCurrency = Tokens;
 	type NativeCurrency = AdaptedBasicCurrency;
 	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type TreasuryAccount = TreasuryModuleAccount;
+	type UpdateOrigin = EnsureRoot<AccountId>;
 }
 
 parameter_types! {
 	pub const MinVestedTransfer: Balance = 100;
+	pub const PoolLiquidityLockPeriod: BlockNumber = 10;
 }
 
 impl Config for Runtime {
@@ -195,6 +208,29 @@ impl Config for Runtime {
 	type LiquidityPoolManager = SwapModule;
 	type MinVestedTransfer = MinVestedTransfer;
 	type VestedTransferOrigin = EnsureSignedBy<One, AccountId>;
+	type PoolLiquidityLockPeriod = PoolLiquidityLockPeriod;
+	type MetaverseGovernanceOrigin = EnsureMetaverseGovernance;
+}
+
+/// Signed origin of `METAVERSE_GOVERNANCE + metaverse_id` stands in for the referendum origin of
+/// the metaverse
+pub struct EnsureMetaverseGovernance;
+
+impl EnsureOrigin<Origin> for EnsureMetaverseGovernance {
+	type Success = MetaverseId;
+
+	fn try_origin(o: Origin) -> Result<MetaverseId, Origin> {
+		Into::<Result<frame_system::RawOrigin<AccountId>, Origin>>::into(o).and_then(|o| match o {
+			frame_system::RawOrigin::Signed(who) if who >= METAVERSE_GOVERNANCE => {
+				Ok((who - METAVERSE_GOVERNANCE) as MetaverseId)
+			}
+			r => Err(Origin::from(r)),
+		})
+	}
+}
+
+pub fn metaverse_governance(metaverse_id: MetaverseId) -> Origin {
+	Origin::signed(METAVERSE_GOVERNANCE + metaverse_id as AccountId)
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
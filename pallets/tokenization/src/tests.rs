@@ -35,7 +35,8 @@ fn mint_social_token_should_work() {
 			400,
 			(3, 10),
 			10,
-			ALICE
+			ALICE,
+			vec![]
 		));
 
 		assert_eq!(get_metaverse_fund_balance(), 400);
@@ -58,7 +59,7 @@ fn mint_social_token_should_fail_for_non_owner() {
 		let origin = Origin::root();
 
 		assert_noop!(
-			TokenizationModule::mint_token(origin, vec![1], METAVERSE_ID, 0, (3, 10), 10, BOB),
+			TokenizationModule::mint_token(origin, vec![1], METAVERSE_ID, 0, (3, 10), 10, BOB, vec![]),
 			Error::<Runtime>::NoPermissionTokenIssuance
 		);
 	});
@@ -75,11 +76,12 @@ fn mint_social_token_should_fail_if_already_exists() {
 			100,
 			(3, 10),
 			10,
-			ALICE
+			ALICE,
+			vec![]
 		));
 
 		assert_noop!(
-			TokenizationModule::mint_token(origin, vec![1], METAVERSE_ID, 100, (3, 10), 10, ALICE),
+			TokenizationModule::mint_token(origin, vec![1], METAVERSE_ID, 100, (3, 10), 10, ALICE, vec![]),
 			Error::<Runtime>::FungibleTokenAlreadyIssued
 		);
 	});
@@ -98,7 +100,8 @@ fn metaverse_treasury_pool_withdraw_should_work() {
 			400,
 			(3, 10),
 			10,
-			ALICE
+			ALICE,
+			vec![]
 		));
 		assert_ok!(Currencies::deposit(
 			METAVERSE_FUND,
@@ -127,7 +130,8 @@ fn metaverse_treasury_pool_withdraw_should_fail() {
 			400,
 			(3, 10),
 			10,
-			ALICE
+			ALICE,
+			vec![]
 		));
 		assert_eq!(get_metaverse_fund_balance(), 400);
 		assert_noop!(
@@ -149,7 +153,8 @@ fn metaverse_treasury_pool_transfer_should_work() {
 			400,
 			(3, 10),
 			10,
-			ALICE
+			ALICE,
+			vec![]
 		));
 		assert_eq!(get_metaverse_fund_balance(), 400);
 		assert_ok!(Currencies::deposit(
@@ -166,3 +171,278 @@ fn metaverse_treasury_pool_transfer_should_work() {
 		assert_eq!(Currencies::free_balance(METAVERSE_FUND, &ALICE), 380); // 120 has been vested
 	});
 }
+
+#[test]
+fn mint_social_token_with_team_allocation_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let allocation = TokenAllocation {
+			beneficiary: BOB,
+			amount: 120,
+			cliff: 10,
+			period: 10,
+			period_count: 2,
+		};
+		assert_ok!(TokenizationModule::mint_token(
+			Origin::root(),
+			vec![1],
+			METAVERSE_ID,
+			400,
+			(3, 10),
+			10,
+			ALICE,
+			vec![allocation]
+		));
+
+		let schedule = VestingSchedule {
+			token: METAVERSE_FUND,
+			start: 11,
+			period: 10,
+			period_count: 2,
+			per_period: 60,
+		};
+		assert_eq!(
+			TokenizationModule::token_allocations(METAVERSE_FUND),
+			vec![(BOB, schedule.clone())]
+		);
+		assert_eq!(TokenizationModule::vesting_schedules(BOB), vec![schedule]);
+		assert_eq!(Currencies::free_balance(METAVERSE_FUND, &BOB), 120);
+
+		// Nothing is claimable before the cliff ends
+		assert_ok!(TokenizationModule::claim(Origin::signed(BOB), METAVERSE_FUND));
+		assert_eq!(
+			last_event(),
+			mock::Event::TokenizationModule(crate::Event::Claimed(METAVERSE_FUND, BOB, 120))
+		);
+
+		System::set_block_number(21);
+		assert_ok!(TokenizationModule::claim(Origin::signed(BOB), METAVERSE_FUND));
+		assert_eq!(
+			last_event(),
+			mock::Event::TokenizationModule(crate::Event::Claimed(METAVERSE_FUND, BOB, 60))
+		);
+	});
+}
+
+#[test]
+fn mint_social_token_should_fail_if_allocation_exceeds_owner_supply() {
+	ExtBuilder::default().build().execute_with(|| {
+		let allocation = TokenAllocation {
+			beneficiary: BOB,
+			amount: 300,
+			cliff: 10,
+			period: 10,
+			period_count: 2,
+		};
+		assert_noop!(
+			TokenizationModule::mint_token(
+				Origin::root(),
+				vec![1],
+				METAVERSE_ID,
+				400,
+				(3, 10),
+				10,
+				ALICE,
+				vec![allocation]
+			),
+			Error::<Runtime>::TokenAllocationExceedsSupply
+		);
+	});
+}
+
+#[test]
+fn mint_social_token_should_lock_pool_liquidity_in_metaverse_treasury() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TokenizationModule::mint_token(
+			Origin::root(),
+			vec![1],
+			METAVERSE_ID,
+			400,
+			(3, 10),
+			10,
+			ALICE,
+			vec![]
+		));
+
+		let share_currency_id = FungibleTokenId::DEXShare(0, 1);
+		let lock = TokenizationModule::pool_liquidity_locks(METAVERSE_ID).unwrap();
+		assert_eq!(lock.share_currency_id, share_currency_id);
+		assert_eq!(lock.holder, 100);
+		assert_eq!(lock.unlock_at, 11);
+		assert!(lock.amount > 0);
+		assert_eq!(Currencies::free_balance(share_currency_id, &100), lock.amount);
+
+		assert!(SwapModule::remove_liquidity(
+			Origin::signed(100),
+			FungibleTokenId::NativeToken(0),
+			FungibleTokenId::FungibleToken(1),
+			lock.amount
+		)
+		.is_err());
+		assert_noop!(
+			TokenizationModule::unlock_pool_liquidity(Origin::signed(ALICE), METAVERSE_ID),
+			Error::<Runtime>::PoolLiquidityStillLocked
+		);
+
+		System::set_block_number(11);
+		assert_ok!(TokenizationModule::unlock_pool_liquidity(
+			Origin::signed(ALICE),
+			METAVERSE_ID
+		));
+		assert_eq!(TokenizationModule::pool_liquidity_locks(METAVERSE_ID), None);
+		assert_eq!(
+			last_event(),
+			Event::TokenizationModule(crate::Event::PoolLiquidityUnlocked(
+				METAVERSE_ID,
+				share_currency_id,
+				100,
+				lock.amount
+			))
+		);
+	});
+}
+
+#[test]
+fn redeem_sunset_token_should_pay_pro_rata_share_of_treasury() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(TokenizationModule::mint_token(
+			Origin::root(),
+			vec![1],
+			METAVERSE_ID_IN_SUNSET,
+			400,
+			(3, 10),
+			0,
+			ALICE,
+			vec![]
+		));
+		assert_ok!(Currencies::deposit(METAVERSE_FUND, &BOB, 100));
+		assert_ok!(Balances::transfer(Origin::signed(ALICE), 100, 1000));
+
+		assert_noop!(
+			TokenizationModule::redeem_sunset_token(Origin::signed(BOB), METAVERSE_ID, 100),
+			Error::<Runtime>::MetaverseNotInSunset
+		);
+		assert_noop!(
+			TokenizationModule::redeem_sunset_token(Origin::signed(BOB), METAVERSE_ID_IN_SUNSET, 0),
+			Error::<Runtime>::AmountZero
+		);
+
+		// 100 out of 500 tokens are redeemed for a fifth of the treasury
+		assert_ok!(TokenizationModule::redeem_sunset_token(
+			Origin::signed(BOB),
+			METAVERSE_ID_IN_SUNSET,
+			100
+		));
+		assert_eq!(
+			last_event(),
+			Event::TokenizationModule(crate::Event::SunsetTokenRedeemed(
+				METAVERSE_ID_IN_SUNSET,
+				METAVERSE_FUND,
+				BOB,
+				100,
+				200
+			))
+		);
+		assert_eq!(Currencies::free_balance(METAVERSE_FUND, &BOB), 0);
+		assert_eq!(Currencies::total_issuance(METAVERSE_FUND), 400);
+		assert_eq!(Balances::free_balance(BOB), 200);
+		assert_eq!(Balances::free_balance(100), 800);
+	});
+}
+
+#[test]
+fn metaverse_local_token_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let currency_id = FungibleTokenId::MetaverseCurrency(METAVERSE_ID);
+		let treasury = MetaverseInfoSource::get_metaverse_treasury(METAVERSE_ID);
+
+		assert_noop!(
+			TokenizationModule::create_local_token(
+				Origin::signed(BOB),
+				METAVERSE_ID,
+				b"Gold".to_vec(),
+				b"GLD".to_vec(),
+				1000
+			),
+			Error::<Runtime>::NoPermissionTokenIssuance
+		);
+		assert_noop!(
+			TokenizationModule::create_local_token(Origin::signed(ALICE), METAVERSE_ID, b"Gold".to_vec(), vec![], 1000),
+			Error::<Runtime>::InvalidLocalToken
+		);
+		assert_ok!(TokenizationModule::create_local_token(
+			Origin::signed(ALICE),
+			METAVERSE_ID,
+			b"Gold".to_vec(),
+			b"GLD".to_vec(),
+			1000
+		));
+		assert_eq!(
+			last_event(),
+			Event::TokenizationModule(crate::Event::LocalTokenCreated(METAVERSE_ID, currency_id, ALICE, 1000))
+		);
+		assert_noop!(
+			TokenizationModule::create_local_token(
+				Origin::signed(ALICE),
+				METAVERSE_ID,
+				b"Gold".to_vec(),
+				b"GLD".to_vec(),
+				1000
+			),
+			Error::<Runtime>::FungibleTokenAlreadyIssued
+		);
+
+		// Only governance of the metaverse can mint, up to the supply cap
+		assert_noop!(
+			TokenizationModule::mint_local_token(Origin::signed(ALICE), METAVERSE_ID, BOB, 100),
+			BadOrigin
+		);
+		assert_noop!(
+			TokenizationModule::mint_local_token(Origin::root(), METAVERSE_ID, BOB, 100),
+			BadOrigin
+		);
+		assert_noop!(
+			TokenizationModule::mint_local_token(metaverse_governance(METAVERSE_ID_IN_SUNSET), METAVERSE_ID, BOB, 100),
+			Error::<Runtime>::NoPermissionTokenIssuance
+		);
+		assert_ok!(TokenizationModule::mint_local_token(
+			metaverse_governance(METAVERSE_ID),
+			METAVERSE_ID,
+			BOB,
+			400
+		));
+		assert_ok!(TokenizationModule::mint_local_token(
+			metaverse_governance(METAVERSE_ID),
+			METAVERSE_ID,
+			treasury,
+			600
+		));
+		assert_eq!(
+			last_event(),
+			Event::TokenizationModule(crate::Event::LocalTokenMinted(METAVERSE_ID, treasury, 600))
+		);
+		assert_noop!(
+			TokenizationModule::mint_local_token(metaverse_governance(METAVERSE_ID), METAVERSE_ID, BOB, 1),
+			Error::<Runtime>::LocalTokenSupplyCapExceeded
+		);
+		assert_eq!(Currencies::free_balance(currency_id, &BOB), 400);
+
+		// Burning out of the treasury frees up supply
+		assert_ok!(TokenizationModule::burn_local_token(
+			metaverse_governance(METAVERSE_ID),
+			METAVERSE_ID,
+			200
+		));
+		assert_eq!(
+			last_event(),
+			Event::TokenizationModule(crate::Event::LocalTokenBurned(METAVERSE_ID, treasury, 200))
+		);
+		assert_eq!(Currencies::free_balance(currency_id, &treasury), 400);
+		assert_eq!(Currencies::total_issuance(currency_id), 800);
+		assert_ok!(TokenizationModule::mint_local_token(
+			metaverse_governance(METAVERSE_ID),
+			METAVERSE_ID,
+			BOB,
+			200
+		));
+	});
+}
@@ -0,0 +1,34 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+use primitives::estate::EstateProvenanceRecord;
+use primitives::{Balance, EstateId};
+
+sp_api::decl_runtime_apis! {
+	pub trait EstateApi<AccountId, BlockNumber> where
+		AccountId: Codec,
+		BlockNumber: Codec,
+	{
+		/// Recent owners of the estate with their acquisition price and block, oldest first
+		fn estate_provenance(estate_id: EstateId) -> Vec<EstateProvenanceRecord<AccountId, Balance, BlockNumber>>;
+	}
+}
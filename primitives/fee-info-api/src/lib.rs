@@ -0,0 +1,44 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::RuntimeDebug;
+
+/// Total cost of a call before signing
+#[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CallCostInfo<Balance> {
+	/// Transaction fee including weight, length and base fee
+	pub transaction_fee: Balance,
+	/// Deposits, fees and reserves the call takes from the signer on top of the transaction fee
+	pub deposit: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait FeeInfoApi<Balance, Call> where
+		Balance: Codec,
+		Call: Codec,
+	{
+		/// Estimate transaction fee and pallet specific deposits of the call
+		fn estimate_call_cost(call: Call) -> CallCostInfo<Balance>;
+	}
+}
@@ -0,0 +1,357 @@
+use codec::FullCodec;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{traits::AtLeast32BitUnsigned, DispatchError, DispatchResult, Perbill, RuntimeDebug};
+use sp_std::{
+	cmp::{Eq, PartialEq},
+	fmt::Debug,
+	vec::Vec,
+};
+
+use crate::{AuctionId, ClassId, EstateId, FungibleTokenId, ItemId, MetaverseId, TokenId, UndeployedLandBlockType};
+
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum Change<Value> {
+	/// No change.
+	NoChange,
+	/// Changed to new value.
+	NewValue(Value),
+}
+
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AuctionType {
+	Auction,
+	BuyNow,
+	/// Price descends from the listed amount to a floor, first buyer at the current price wins
+	Dutch,
+	/// Bidders commit to a hidden bid, then reveal it once commitments close
+	SealedBid,
+}
+
+/// Kind of item listed, listing constraints are configured per item type
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ListingItemType {
+	NFT,
+	Spot,
+	Estate,
+	LandUnit,
+	Bundle,
+}
+
+impl ListingItemType {
+	pub fn of(item_id: &ItemId) -> Option<Self> {
+		match item_id {
+			ItemId::NFT(_, _) => Some(ListingItemType::NFT),
+			ItemId::Spot(_, _) => Some(ListingItemType::Spot),
+			ItemId::Estate(_) => Some(ListingItemType::Estate),
+			ItemId::LandUnit(_, _) => Some(ListingItemType::LandUnit),
+			ItemId::Bundle(_) => Some(ListingItemType::Bundle),
+			_ => None,
+		}
+	}
+}
+
+/// Duration constraints applied to new listings of an item type
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ListingDurationLimits<BlockNumber> {
+	/// Minimum number of blocks a listing has to run
+	pub min_duration: BlockNumber,
+	/// Maximum number of blocks a listing can run, unlimited if none
+	pub max_duration: Option<BlockNumber>,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum ListingLevel<AccountId> {
+	// Accepted bidders
+	NetworkSpot(Vec<AccountId>),
+	Global,
+	Local(MetaverseId),
+}
+
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub struct AuctionItem<AccountId, BlockNumber, Balance> {
+	pub item_id: ItemId,
+	pub recipient: AccountId,
+	pub initial_amount: Balance,
+	/// Current amount for sale
+	pub amount: Balance,
+	/// Auction start time
+	pub start_time: BlockNumber,
+	pub end_time: BlockNumber,
+	pub auction_type: AuctionType,
+	pub listing_level: ListingLevel<AccountId>,
+	pub currency_id: FungibleTokenId,
+}
+
+/// Compact record of a settled auction kept until it is archived
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub struct AuctionSummary<AccountId, Balance> {
+	pub item_id: ItemId,
+	/// Winner of the auction, none if the auction ended without a sale
+	pub winner: Option<AccountId>,
+	/// Final price paid by the winner
+	pub price: Balance,
+	/// Royalty fee paid on top of the price
+	pub fees: Balance,
+}
+
+/// Estate bought with a down payment and installments, held by the marketplace until fully paid
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub struct InstallmentPurchase<AccountId, Balance, BlockNumber> {
+	pub buyer: AccountId,
+	pub seller: AccountId,
+	pub estate_id: EstateId,
+	/// Full listing price
+	pub price: Balance,
+	/// Amount escrowed by the buyer so far, including the down payment
+	pub paid: Balance,
+	/// Amount due every installment period, the last installment pays the remainder
+	pub installment: Balance,
+	/// Block by which the next installment must be paid
+	pub next_due: BlockNumber,
+}
+
+/// Summary of an active auction served to marketplace front ends
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub struct ActiveAuctionSummary<AccountId, Balance, BlockNumber> {
+	pub auction_id: AuctionId,
+	pub item_id: ItemId,
+	pub auction_type: AuctionType,
+	pub seller: AccountId,
+	/// Listing price
+	pub amount: Balance,
+	/// Current highest bidder and bid
+	pub bid: Option<(AccountId, Balance)>,
+	pub end: Option<BlockNumber>,
+	pub currency_id: FungibleTokenId,
+}
+
+/// Funds escrowed by a buyer against an item that is not listed
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub struct AssetOffer<Balance, BlockNumber> {
+	pub amount: Balance,
+	/// Last block the offer can be accepted
+	pub expiry: BlockNumber,
+}
+
+/// Minimum amount a new bid has to exceed the current highest bid by
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum BidIncrement<Balance> {
+	/// Fixed amount on top of the highest bid
+	Absolute(Balance),
+	/// Share of the highest bid
+	Percentage(Perbill),
+}
+
+/// Minimum bid increment of an auction, rising linearly from the start increment to the end
+/// increment over the auction period when an end increment is set
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct BidIncrementRule<Balance> {
+	pub start: BidIncrement<Balance>,
+	pub end: Option<BidIncrement<Balance>>,
+}
+
+/// Undeployed land block issued to the winner of a land block auction on settlement
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct LandBlockSale {
+	pub number_land_units: u32,
+	pub undeployed_land_block_type: UndeployedLandBlockType,
+}
+
+/// Barter trade of items and tokens proposed to a counterparty, settled atomically on acceptance
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub struct SwapProposal<AccountId, Balance, BlockNumber> {
+	pub proposer: AccountId,
+	pub counterparty: AccountId,
+	/// Items of the proposer given to the counterparty
+	pub offered_items: Vec<ItemId>,
+	/// Tokens escrowed by the proposer and paid to the counterparty
+	pub offered_amount: Balance,
+	/// Items of the counterparty given to the proposer
+	pub requested_items: Vec<ItemId>,
+	/// Tokens paid by the counterparty to the proposer
+	pub requested_amount: Balance,
+	/// Last block the swap can be accepted
+	pub expiry: BlockNumber,
+}
+
+/// Accounts allowed to bid on a gated auction
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum BidderAllowlist<AccountId> {
+	/// Explicit list of accounts
+	Accounts(Vec<AccountId>),
+	/// Holders of any token of the NFT class
+	NftHolders(ClassId),
+}
+
+/// Auction info.
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct AuctionInfo<AccountId, Balance, BlockNumber> {
+	/// Current bidder and bid price.
+	pub bid: Option<(AccountId, Balance)>,
+	/// Define which block this auction will be started.
+	pub start: BlockNumber,
+	/// Define which block this auction will be ended.
+	pub end: Option<BlockNumber>,
+}
+
+/// Abstraction over a simple auction system.
+pub trait Auction<AccountId, BlockNumber> {
+	/// The price to bid.
+	type Balance: AtLeast32BitUnsigned + FullCodec + Copy + Default + Debug;
+
+	/// The auction info of `id`
+	fn auction_info(id: AuctionId) -> Option<AuctionInfo<AccountId, Self::Balance, BlockNumber>>;
+	/// Update the auction info of `id` with `info`
+	fn update_auction(id: AuctionId, info: AuctionInfo<AccountId, Self::Balance, BlockNumber>) -> DispatchResult;
+	/// Create new auction with specific startblock and endblock, return the id
+	/// of the auction
+	fn new_auction(
+		recipient: AccountId,
+		initial_amount: Self::Balance,
+		start: BlockNumber,
+		end: Option<BlockNumber>,
+	) -> Result<AuctionId, DispatchError>;
+
+	fn create_auction(
+		auction_type: AuctionType,
+		item_id: ItemId,
+		end: Option<BlockNumber>,
+		recipient: AccountId,
+		initial_amount: Self::Balance,
+		start: BlockNumber,
+		listing_level: ListingLevel<AccountId>,
+	) -> Result<AuctionId, DispatchError>;
+
+	/// Remove auction by `id`
+	fn remove_auction(id: AuctionId, item_id: ItemId);
+
+	fn auction_bid_handler(
+		_now: BlockNumber,
+		id: AuctionId,
+		new_bid: (AccountId, Self::Balance),
+		last_bid: Option<(AccountId, Self::Balance)>,
+	) -> DispatchResult;
+
+	fn local_auction_bid_handler(
+		_now: BlockNumber,
+		id: AuctionId,
+		new_bid: (AccountId, Self::Balance),
+		last_bid: Option<(AccountId, Self::Balance)>,
+		social_currency_id: FungibleTokenId,
+	) -> DispatchResult;
+
+	fn collect_royalty_fee(
+		high_bid_price: &Self::Balance,
+		high_bidder: &AccountId,
+		asset_id: &(ClassId, TokenId),
+		social_currency_id: FungibleTokenId,
+	) -> DispatchResult;
+}
+
+pub trait CheckAuctionItemHandler {
+	fn check_item_in_auction(item_id: ItemId) -> bool;
+}
+
+/// The result of bid handling.
+pub struct OnNewBidResult<BlockNumber> {
+	/// Indicates if the bid was accepted
+	pub accept_bid: bool,
+	/// The auction end change.
+	pub auction_end_change: Change<Option<BlockNumber>>,
+}
+
+/// Hooks for auction to handle bids.
+pub trait AuctionHandler<AccountId, Balance, BlockNumber, AuctionId> {
+	/// Called when new bid is received.
+	/// The return value determines if the bid should be accepted and update
+	/// auction end time. Implementation should reserve money from current
+	/// winner and refund previous winner.
+	fn on_new_bid(
+		now: BlockNumber,
+		id: AuctionId,
+		new_bid: (AccountId, Balance),
+		last_bid: Option<(AccountId, Balance)>,
+	) -> OnNewBidResult<BlockNumber>;
+	/// End an auction with `winner`
+	fn on_auction_ended(id: AuctionId, winner: Option<(AccountId, Balance)>);
+}
+
+/// Swap manager to use swap functionality in different traits
+pub trait SwapManager<AccountId, CurrencyId, Balance> {
+	fn add_liquidity(
+		who: &AccountId,
+		token_id_a: FungibleTokenId,
+		token_id_b: FungibleTokenId,
+		max_amount_a: Balance,
+		max_amount_b: Balance,
+	) -> DispatchResult;
+}
+
+/// Partner marketplace identifier, the parachain id of the sibling chain
+pub type MarketplaceId = u32;
+
+/// Listing details mirrored to partner marketplaces
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub struct CrossListingV1<AccountId, Balance, BlockNumber> {
+	pub auction_id: AuctionId,
+	pub item_id: ItemId,
+	pub recipient: AccountId,
+	pub amount: Balance,
+	pub end_time: BlockNumber,
+	pub auction_type: AuctionType,
+}
+
+/// Versioned cross listing payload, partner marketplaces decode the payload by version
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub enum VersionedCrossListing<AccountId, Balance, BlockNumber> {
+	V1(CrossListingV1<AccountId, Balance, BlockNumber>),
+}
+
+/// Registration of a partner marketplace. Listings are delivered by calling `listing_call_index`
+/// on the partner chain with the encoded `VersionedCrossListing` as its only argument, the
+/// execution is paid with `execution_fee` of the relay chain token held by our sovereign account
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo)]
+pub struct PartnerMarketplaceInfo {
+	/// Pallet and call index of the listing call on the partner chain
+	pub listing_call_index: [u8; 2],
+	/// Weight the partner chain charges for the listing call
+	pub listing_call_weight: u64,
+	/// Relay chain token withdrawn from our sovereign account to buy execution
+	pub execution_fee: u128,
+}
+
+/// Notify partner marketplaces on sibling chains about new listings
+pub trait CrossListingHandler<AccountId, Balance, BlockNumber> {
+	fn notify_listing(
+		marketplace_id: MarketplaceId,
+		marketplace: &PartnerMarketplaceInfo,
+		listing: VersionedCrossListing<AccountId, Balance, BlockNumber>,
+	) -> DispatchResult;
+}
+
+impl<AccountId, Balance, BlockNumber> CrossListingHandler<AccountId, Balance, BlockNumber> for () {
+	fn notify_listing(
+		_marketplace_id: MarketplaceId,
+		_marketplace: &PartnerMarketplaceInfo,
+		_listing: VersionedCrossListing<AccountId, Balance, BlockNumber>,
+	) -> DispatchResult {
+		Ok(())
+	}
+}
@@ -4,8 +4,10 @@ use scale_info::{prelude::vec::Vec, TypeInfo};
 use serde::{Deserialize, Serialize};
 use sp_runtime::DispatchError;
 use sp_runtime::{Perbill, RuntimeDebug};
+use sp_std::collections::btree_map::BTreeMap;
 
-use crate::{EstateId, MetaverseId, TokenId};
+use crate::auction::AuctionType;
+use crate::{Balance, ClassId, EstateId, MetaverseId, TokenId, UndeployedLandBlockId};
 
 pub trait Estate<AccountId> {
 	fn transfer_estate(estate_id: EstateId, from: &AccountId, to: &AccountId) -> Result<EstateId, DispatchError>;
@@ -20,9 +22,83 @@ pub trait Estate<AccountId> {
 
 	fn check_landunit(metaverse_id: MetaverseId, coordinate: (i32, i32)) -> Result<bool, DispatchError>;
 
+	fn check_estate_ownership(owner: &AccountId, estate_id: EstateId) -> Result<bool, DispatchError>;
+
+	fn check_landunit_ownership(
+		owner: &AccountId,
+		metaverse_id: MetaverseId,
+		coordinate: (i32, i32),
+	) -> Result<bool, DispatchError>;
+
 	fn get_total_land_units() -> u64;
 
 	fn get_total_undeploy_land_units() -> u64;
+
+	fn check_adjacent_landunit_owner(who: &AccountId, metaverse_id: MetaverseId, coordinate: (i32, i32)) -> bool;
+
+	/// Check if any land unit sharing an edge with the coordinate is owned by an account other
+	/// than the seller
+	fn has_adjacent_landunits(_seller: &AccountId, _metaverse_id: MetaverseId, _coordinate: (i32, i32)) -> bool {
+		false
+	}
+
+	/// Record the price the estate was last sold for in its provenance history
+	fn record_estate_sale(_estate_id: EstateId, _price: Balance) {}
+
+	/// Level of the land unit raised by burning mining resource, 0 if never upgraded
+	fn get_land_unit_level(_metaverse_id: MetaverseId, _coordinate: (i32, i32)) -> u32 {
+		0
+	}
+
+	/// Check if the estate is frozen by governance
+	fn is_estate_frozen(_estate_id: EstateId) -> bool {
+		false
+	}
+
+	/// Total estate stake of at most `limit` stakers following `start_after`
+	fn get_estate_stakes(_start_after: Option<AccountId>, _limit: u32) -> Vec<(AccountId, Balance)> {
+		Vec::new()
+	}
+
+	/// Number of land units deployed in at most `limit` metaverses following `start_after`
+	fn get_metaverse_land_units(_start_after: Option<MetaverseId>, _limit: u32) -> Vec<(MetaverseId, u64)> {
+		Vec::new()
+	}
+
+	/// Royalty recipient and share of the price paid on estate sales
+	fn get_estate_royalty(_estate_id: EstateId) -> Option<(AccountId, Perbill)> {
+		None
+	}
+
+	/// Metaverse the estate is deployed in
+	fn get_estate_metaverse(_estate_id: EstateId) -> Option<MetaverseId> {
+		None
+	}
+
+	/// Record that the estate owner is still active for beneficiary inheritance
+	fn record_owner_heartbeat(_who: &AccountId) {}
+
+	/// Check if account meets the land buyer gate of the metaverse
+	fn is_allowed_to_buy_land(_who: &AccountId, _metaverse_id: MetaverseId) -> bool {
+		true
+	}
+
+	/// Check if the estate owes no idle land tax
+	fn is_land_tax_settled(_estate_id: EstateId) -> bool {
+		true
+	}
+
+	/// Record that the estate left the marketplace, the listed period does not count as idle time
+	fn record_estate_delisted(_estate_id: EstateId) {}
+}
+
+/// Coordinates sharing an edge with the given coordinate
+pub fn adjacent_coordinates(coordinate: (i32, i32)) -> Vec<(i32, i32)> {
+	let (x, y) = coordinate;
+	[(0, 1), (1, 0), (0, -1), (-1, 0)]
+		.iter()
+		.filter_map(|(dx, dy)| Some((x.checked_add(*dx)?, y.checked_add(*dy)?)))
+		.collect()
 }
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -45,3 +121,210 @@ pub enum LandUnitStatus<AccountId> {
 	NonExisting,
 	Existing(AccountId),
 }
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct LandTaxInfo<Balance> {
+	/// Tax charged per land unit for every round the estate stays idle
+	pub tax_per_land_unit: Balance,
+	/// Number of idle rounds before the tax starts to accrue
+	pub idle_rounds: u32,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct EstateBeneficiaryInfo<AccountId, BlockNumber> {
+	/// Owner who designated the beneficiary
+	pub owner: AccountId,
+	/// Account which can claim the estate
+	pub beneficiary: AccountId,
+	/// Number of blocks the owner needs to be inactive before the estate can be claimed
+	pub inactivity_period: BlockNumber,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum EstateAttributeValue {
+	Bool(bool),
+	Integer(i64),
+	Text(Vec<u8>),
+}
+
+/// Estate attributes keyed by attribute name
+pub type EstateAttributes = BTreeMap<Vec<u8>, EstateAttributeValue>;
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct EstateCoOwnershipInfo<AccountId> {
+	/// Accounts jointly owning the estate
+	pub co_owners: Vec<AccountId>,
+	/// Number of co-owner approvals required to execute an action
+	pub threshold: u32,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum CoOwnershipAction<AccountId, Balance, BlockNumber> {
+	/// Transfer the estate to a single owner and end the co-ownership
+	Transfer(AccountId),
+	/// List the estate in auction with initial amount and end block
+	List(AuctionType, Balance, BlockNumber),
+	AddCoOwner(AccountId),
+	RemoveCoOwner(AccountId),
+	SetThreshold(u32),
+	/// Post lease offer with rent per block, duration and minimum deposit, rent is paid to the
+	/// estate account
+	PostLeaseOffer(Balance, BlockNumber, Balance),
+	CancelLeaseOffer,
+	/// Deploy land units of undeployed land block held by the estate account into the estate
+	DeployLandBlock(UndeployedLandBlockId, Vec<(i32, i32)>),
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CoOwnershipProposal<AccountId, Balance, BlockNumber> {
+	/// Action executed once the threshold is reached
+	pub action: CoOwnershipAction<AccountId, Balance, BlockNumber>,
+	/// Co-owners who approved the action
+	pub approvals: Vec<AccountId>,
+	/// Block at which the action was proposed
+	pub proposed_at: BlockNumber,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct MapBound {
+	/// Minimum x and y coordinate
+	pub min: (i32, i32),
+	/// Maximum x and y coordinate
+	pub max: (i32, i32),
+}
+
+impl MapBound {
+	pub fn is_valid(&self) -> bool {
+		self.min.0 <= self.max.0 && self.min.1 <= self.max.1
+	}
+
+	/// Check if the coordinate is within the map
+	pub fn contains(&self, coordinate: (i32, i32)) -> bool {
+		coordinate.0 >= self.min.0
+			&& coordinate.0 <= self.max.0
+			&& coordinate.1 >= self.min.1
+			&& coordinate.1 <= self.max.1
+	}
+
+	/// Check if the whole other map is within this map
+	pub fn contains_bound(&self, other: &MapBound) -> bool {
+		self.contains(other.min) && self.contains(other.max)
+	}
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct LeaseOffer<AccountId, Balance, BlockNumber> {
+	/// Owner who posted the lease terms
+	pub landlord: AccountId,
+	/// Rent streamed to the landlord every block
+	pub rent_per_block: Balance,
+	/// Number of blocks the lease lasts
+	pub duration: BlockNumber,
+	/// Minimum deposit the tenant needs to lock
+	pub min_deposit: Balance,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct LeaseInfo<AccountId, Balance, BlockNumber> {
+	/// Account receiving the rent
+	pub landlord: AccountId,
+	/// Account leasing the estate
+	pub tenant: AccountId,
+	/// Rent streamed to the landlord every block
+	pub rent_per_block: Balance,
+	/// Remaining deposit reserved from the tenant
+	pub deposit: Balance,
+	/// Block up to which rent has been collected
+	pub last_collected: BlockNumber,
+	/// Block at which the lease ends
+	pub end_block: BlockNumber,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct EstateLoanRequest<AccountId, Balance, BlockNumber> {
+	/// Estate owner borrowing against the estate
+	pub borrower: AccountId,
+	/// Lender who can fund the loan, any account can fund it if not set
+	pub lender: Option<AccountId>,
+	/// Amount lent to the borrower
+	pub principal: Balance,
+	/// Amount the borrower has to repay
+	pub repayment: Balance,
+	/// Number of blocks the borrower has to repay after the loan is funded
+	pub duration: BlockNumber,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct EstateLoanInfo<AccountId, Balance, BlockNumber> {
+	/// Estate owner borrowing against the estate
+	pub borrower: AccountId,
+	/// Account which funded the loan
+	pub lender: AccountId,
+	/// Amount the borrower has to repay
+	pub repayment: Balance,
+	/// Block after which the lender can liquidate the estate
+	pub deadline: BlockNumber,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct EstateProvenanceRecord<AccountId, Balance, BlockNumber> {
+	/// Account which acquired the estate
+	pub owner: AccountId,
+	/// Sale price, none if the estate was minted or transferred without a sale
+	pub price: Option<Balance>,
+	/// Block at which the estate was acquired
+	pub acquired_at: BlockNumber,
+}
+
+/// Requirement a buyer has to meet to purchase land in a metaverse
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum LandBuyerGate {
+	/// Accounts approved by the metaverse owner
+	Allowlist,
+	/// Holders of any token of the NFT class
+	NftHolders(ClassId),
+}
+
+pub type LandGiftCardPoolId = u64;
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct LandGiftCardPool<AccountId, Balance, BlockNumber> {
+	/// Account which funded the pool with undeployed land blocks and receives the sales
+	pub sponsor: AccountId,
+	/// Metaverse the land blocks of the pool are meant for
+	pub metaverse_id: MetaverseId,
+	/// NFT class of the sponsor the gift cards are minted in
+	pub card_class_id: ClassId,
+	/// Fixed price of a gift card
+	pub price: Balance,
+	/// Number of blocks a gift card can be redeemed for after purchase
+	pub validity: BlockNumber,
+	/// Land blocks held by the pool which are not reserved by a gift card
+	pub available_land_blocks: Vec<UndeployedLandBlockId>,
+	/// Number of sold gift cards which are not redeemed or refunded yet
+	pub outstanding_cards: u32,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct LandGiftCard<BlockNumber> {
+	/// Pool the gift card was sold from
+	pub pool_id: LandGiftCardPoolId,
+	/// Land block reserved for the gift card holder
+	pub land_block_id: UndeployedLandBlockId,
+	/// Last block the gift card can be redeemed at
+	pub expires_at: BlockNumber,
+}
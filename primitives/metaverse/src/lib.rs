@@ -32,10 +32,27 @@ use sp_runtime::{
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::vec::Vec;
 
+pub mod auction;
 pub mod continuum;
 pub mod dex;
 pub mod estate;
+pub mod nft;
+pub mod ownership;
+pub mod receipt;
 pub mod staking;
+pub mod telemetry;
+
+/// Cross-runtime interfaces. Runtimes and external pallets should depend on a versioned path
+/// instead of concrete pallet crates, breaking changes are introduced as a new version.
+pub mod traits {
+	pub mod v1 {
+		pub use crate::auction::{Auction, AuctionHandler, CheckAuctionItemHandler, CrossListingHandler, SwapManager};
+		pub use crate::estate::Estate;
+		pub use crate::nft::NFTTrait;
+	}
+
+	pub use v1::*;
+}
 
 /// An index to a block.
 pub type BlockNumber = u32;
@@ -110,6 +127,10 @@ pub type RoundIndex = u32;
 pub type DomainId = u32;
 /// Element Id
 pub type ElementId = u32;
+/// Auction Bundle Id
+pub type BundleId = u64;
+/// Marketplace Swap Id
+pub type SwapId = u64;
 /// Mining Power Amount
 pub type PowerAmount = u64;
 /// Nonce
@@ -135,6 +156,7 @@ pub enum ItemId {
 	Block(u64),
 	Estate(EstateId),
 	LandUnit((i32, i32), MetaverseId),
+	Bundle(BundleId),
 }
 
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, MaxEncodedLen, PartialOrd, Ord, TypeInfo)]
@@ -146,6 +168,7 @@ pub enum FungibleTokenId {
 	MiningResource(TokenId),
 
 	Stable(TokenId), // kUSD
+	MetaverseCurrency(MetaverseId),
 }
 
 impl FungibleTokenId {
@@ -165,6 +188,10 @@ impl FungibleTokenId {
 		matches!(self, FungibleTokenId::MiningResource(_))
 	}
 
+	pub fn is_metaverse_currency(&self) -> bool {
+		matches!(self, FungibleTokenId::MetaverseCurrency(_))
+	}
+
 	pub fn split_dex_share_social_token_currency_id(&self) -> Option<(Self, Self)> {
 		match self {
 			FungibleTokenId::DEXShare(token_currency_id_0, token_currency_id_1) => Some((
@@ -314,6 +341,26 @@ pub struct UndeployedLandBlock<AccountId> {
 	pub is_frozen: bool,
 }
 
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum OnboardingQuest {
+	/// Deploy the first land block
+	DeployFirstLand,
+	/// Mint the first NFT
+	MintFirstNft,
+}
+
+pub trait OnboardingQuestTrait<AccountId> {
+	/// Check if the account has completed the onboarding quest
+	fn is_quest_completed(who: &AccountId, quest: OnboardingQuest) -> bool;
+}
+
+impl<AccountId> OnboardingQuestTrait<AccountId> for () {
+	fn is_quest_completed(_who: &AccountId, _quest: OnboardingQuest) -> bool {
+		false
+	}
+}
+
 // create_currency_id! {
 // Represent a Token symbol with 8 bit
 // Bit 8 : 0 for Pokladot Ecosystem, 1 for Kusama Ecosystem
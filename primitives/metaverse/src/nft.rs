@@ -0,0 +1,233 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{DispatchError, DispatchResult, Perbill, RuntimeDebug};
+use sp_std::vec::Vec;
+
+use crate::{Attributes, ClassId, GroupCollectionId, NftMetadata, TokenId};
+
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum TokenType {
+	Transferable,
+	BoundToAddress,
+}
+
+impl TokenType {
+	pub fn is_transferable(&self) -> bool {
+		match *self {
+			TokenType::Transferable => true,
+			_ => false,
+		}
+	}
+}
+
+impl Default for TokenType {
+	fn default() -> Self {
+		TokenType::Transferable
+	}
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum CollectionType {
+	Collectable,
+	Wearable,
+	Executable(Vec<u8>),
+}
+
+// Collection extension for fast retrieval
+impl CollectionType {
+	pub fn is_collectable(&self) -> bool {
+		match *self {
+			CollectionType::Collectable => true,
+			_ => false,
+		}
+	}
+
+	pub fn is_executable(&self) -> bool {
+		match *self {
+			CollectionType::Executable(_) => true,
+			_ => false,
+		}
+	}
+
+	pub fn is_wearable(&self) -> bool {
+		match *self {
+			CollectionType::Wearable => true,
+			_ => false,
+		}
+	}
+}
+
+impl Default for CollectionType {
+	fn default() -> Self {
+		CollectionType::Collectable
+	}
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct NftGroupCollectionData {
+	pub name: NftMetadata,
+	// Metadata from ipfs
+	pub properties: NftMetadata,
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct NftClassData<Balance> {
+	// Minimum balance to create a collection of Asset
+	pub deposit: Balance,
+	pub attributes: Attributes,
+	pub token_type: TokenType,
+	pub collection_type: CollectionType,
+	pub is_locked: bool,
+	pub royalty_fee: Perbill,
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct NftClassDataV1<Balance> {
+	// Minimum balance to create a collection of Asset
+	pub deposit: Balance,
+	pub attributes: Attributes,
+	pub token_type: TokenType,
+	pub collection_type: CollectionType,
+}
+
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct NftAssetData<Balance> {
+	// Deposit balance to create each token
+	pub deposit: Balance,
+	pub attributes: Attributes,
+}
+
+/// Mint voucher signed off-chain by the class owner, redeemable once by any buyer paying the price
+/// to mint the NFT at purchase time. The owner signs the voucher together with the genesis hash of
+/// the chain so a voucher can not be replayed on another chain
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct MintVoucher<ClassId, AccountId, Balance, BlockNumber> {
+	pub class_id: ClassId,
+	/// Unique per class, a voucher can not be redeemed twice
+	pub nonce: u64,
+	pub metadata: NftMetadata,
+	pub attributes: Attributes,
+	pub price: Balance,
+	/// Royalty recipient and rate overriding the class royalty
+	pub royalty: Option<(AccountId, Perbill)>,
+	/// Last block the voucher can be redeemed in
+	pub expires_at: BlockNumber,
+}
+
+/// NFT airdrop published by the class owner, eligible accounts claim their allocation with a proof
+/// of the (account, quantity) leaf under the merkle root
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct NftAirdrop<Hash> {
+	pub merkle_root: Hash,
+	/// Metadata and attributes of every NFT minted by a claim
+	pub metadata: NftMetadata,
+	pub attributes: Attributes,
+}
+
+/// Typed attribute value of an NFT kept on-chain, readable by other pallets without fetching the
+/// off-chain metadata
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AttributeValue {
+	U32(u32),
+	U128(u128),
+	Bool(bool),
+	/// Text, bounded by the NFT pallet max metadata length
+	Text(Vec<u8>),
+}
+
+/// Avatar slot a wearable NFT is equipped in
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum WearableSlot {
+	Head,
+	Body,
+	Legs,
+	Feet,
+	Hands,
+	Accessory,
+}
+
+pub trait NFTTrait<AccountId, Balance> {
+	/// Token identifier
+	type TokenId;
+	/// Token class identifier
+	type ClassId;
+	/// Check the ownership of this nft asset
+	fn check_ownership(who: &AccountId, asset_id: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError>;
+	/// Check the ownership of this nft tuple
+	fn check_nft_ownership(who: &AccountId, nft: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError>;
+	/// Get the detail of this nft
+	fn get_nft_detail(asset_id: (Self::ClassId, Self::TokenId)) -> Result<NftClassData<Balance>, DispatchError>;
+	/// Get the detail of this nft
+	fn get_nft_group_collection(nft_collection: &Self::ClassId) -> Result<GroupCollectionId, DispatchError>;
+	/// Check if collection and class exist
+	fn check_collection_and_class(
+		collection_id: GroupCollectionId,
+		class_id: Self::ClassId,
+	) -> Result<bool, DispatchError>;
+	/// Create NFT token class
+	fn create_token_class(
+		sender: &AccountId,
+		metadata: NftMetadata,
+		attributes: Attributes,
+		collection_id: GroupCollectionId,
+		token_type: TokenType,
+		collection_type: CollectionType,
+		royalty_fee: Perbill,
+	) -> Result<ClassId, DispatchError>;
+	/// Mint NFT token
+	fn mint_token(
+		sender: &AccountId,
+		class_id: ClassId,
+		metadata: NftMetadata,
+		attributes: Attributes,
+	) -> Result<TokenId, DispatchError>;
+	/// Burn nft
+	fn burn_nft(account: &AccountId, nft: &(Self::ClassId, Self::TokenId)) -> DispatchResult;
+	/// Check if item is on listing
+	fn check_item_on_listing(class_id: Self::ClassId, token_id: Self::TokenId) -> Result<bool, DispatchError>;
+	/// transfer nft
+	fn transfer_nft(sender: &AccountId, to: &AccountId, nft: &(Self::ClassId, Self::TokenId)) -> DispatchResult;
+	/// Is Nft transferable
+	fn is_transferable(nft: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError>;
+	/// Get collection account fund
+	fn get_class_fund(class_id: &Self::ClassId) -> AccountId;
+	/// Lock or unlock a single NFT, locked NFTs can not be transferred or listed
+	fn set_lock_nft(_nft: &(Self::ClassId, Self::TokenId), _is_locked: bool) -> DispatchResult {
+		Ok(())
+	}
+	/// Freeze or unfreeze a single NFT by governance, apart from locks so releasing a lock never
+	/// lifts a freeze
+	fn set_freeze_nft(_nft: &(Self::ClassId, Self::TokenId), _is_frozen: bool) -> DispatchResult {
+		Ok(())
+	}
+	/// Check if the account holds any token of the class
+	fn holds_class_token(_who: &AccountId, _class_id: &Self::ClassId) -> bool {
+		false
+	}
+	/// Royalty recipient and rate set on a single NFT, overriding the class royalty if any
+	fn get_token_royalty(_nft: &(Self::ClassId, Self::TokenId)) -> Option<(AccountId, Perbill)> {
+		None
+	}
+	/// Typed on-chain attribute of a single NFT
+	fn get_typed_attribute(_nft: &(Self::ClassId, Self::TokenId), _key: &[u8]) -> Option<AttributeValue> {
+		None
+	}
+}
+
+/// Handler notified when the NFT pallet moves an NFT to a new owner
+pub trait OnNftTransfer<AccountId, ClassId, TokenId> {
+	fn on_nft_transfer(from: &AccountId, to: &AccountId, nft: &(ClassId, TokenId));
+}
+
+impl<AccountId, ClassId, TokenId> OnNftTransfer<AccountId, ClassId, TokenId> for () {
+	fn on_nft_transfer(_from: &AccountId, _to: &AccountId, _nft: &(ClassId, TokenId)) {}
+}
@@ -0,0 +1,120 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Canonical challenge format used to prove ownership of on-chain items to off-chain services.
+use codec::Encode;
+use sp_runtime::traits::{IdentifyAccount, Verify};
+use sp_std::vec::Vec;
+
+use crate::ItemId;
+
+/// Domain prefix of every ownership proof message, versioned so the format can evolve
+pub const OWNERSHIP_PROOF_PREFIX: &[u8] = b"metaverse-network:ownership-proof:v2:";
+
+/// Wrapping added by browser wallet extensions when signing raw bytes
+const WALLET_WRAP_PREFIX: &[u8] = b"<Bytes>";
+const WALLET_WRAP_SUFFIX: &[u8] = b"</Bytes>";
+
+/// Message the owner signs: prefix followed by SCALE encoded (genesis hash, account, item,
+/// challenge). The genesis hash binds the proof to a single chain so it can not be replayed on
+/// another network sharing the account format
+pub fn ownership_proof_message<Hash: Encode, AccountId: Encode>(
+	genesis_hash: &Hash,
+	account: &AccountId,
+	item: &ItemId,
+	challenge: &[u8],
+) -> Vec<u8> {
+	let mut message = OWNERSHIP_PROOF_PREFIX.to_vec();
+	message.extend((genesis_hash, account, item, challenge).encode());
+	message
+}
+
+/// Check the signature over the ownership proof message, with or without the wallet wrapping
+pub fn verify_ownership_signature<S: Verify>(
+	message: &[u8],
+	signature: &S,
+	account: &<S::Signer as IdentifyAccount>::AccountId,
+) -> bool {
+	if signature.verify(message, account) {
+		return true;
+	}
+
+	let mut wrapped = WALLET_WRAP_PREFIX.to_vec();
+	wrapped.extend_from_slice(message);
+	wrapped.extend_from_slice(WALLET_WRAP_SUFFIX);
+	signature.verify(&wrapped[..], account)
+}
+
+#[cfg(test)]
+mod tests {
+	use sp_core::{sr25519, Pair, H256};
+	use sp_runtime::traits::IdentifyAccount;
+	use sp_runtime::{AccountId32, MultiSignature, MultiSigner};
+
+	use super::*;
+
+	const CHALLENGE: &[u8] = b"challenge";
+
+	fn owner() -> (sr25519::Pair, AccountId32) {
+		let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+		let account = MultiSigner::from(pair.public()).into_account();
+		(pair, account)
+	}
+
+	#[test]
+	fn ownership_proof_message_should_be_bound_to_chain() {
+		let (_, account) = owner();
+		let item = ItemId::Estate(0);
+
+		let message = ownership_proof_message(&H256::repeat_byte(1), &account, &item, CHALLENGE);
+		assert!(message.starts_with(OWNERSHIP_PROOF_PREFIX));
+		assert_ne!(
+			message,
+			ownership_proof_message(&H256::repeat_byte(2), &account, &item, CHALLENGE)
+		);
+		assert_ne!(
+			message,
+			ownership_proof_message(&H256::repeat_byte(1), &account, &ItemId::Estate(1), CHALLENGE)
+		);
+	}
+
+	#[test]
+	fn verify_ownership_signature_should_accept_raw_and_wallet_wrapped_signatures() {
+		let (pair, account) = owner();
+		let message = ownership_proof_message(&H256::repeat_byte(1), &account, &ItemId::Estate(0), CHALLENGE);
+
+		let signature = MultiSignature::from(pair.sign(&message));
+		assert!(verify_ownership_signature(&message, &signature, &account));
+
+		let mut wrapped = WALLET_WRAP_PREFIX.to_vec();
+		wrapped.extend_from_slice(&message);
+		wrapped.extend_from_slice(WALLET_WRAP_SUFFIX);
+		let wrapped_signature = MultiSignature::from(pair.sign(&wrapped));
+		assert!(verify_ownership_signature(&message, &wrapped_signature, &account));
+	}
+
+	#[test]
+	fn verify_ownership_signature_should_reject_proof_of_other_chain() {
+		let (pair, account) = owner();
+		let item = ItemId::Estate(0);
+		let other_chain_message = ownership_proof_message(&H256::repeat_byte(2), &account, &item, CHALLENGE);
+		let signature = MultiSignature::from(pair.sign(&other_chain_message));
+
+		let message = ownership_proof_message(&H256::repeat_byte(1), &account, &item, CHALLENGE);
+		assert!(!verify_ownership_signature(&message, &signature, &account));
+	}
+}
@@ -0,0 +1,37 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{DispatchResult, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// Receipt of a call submitted with a client correlation id, kept so off-chain backends can
+/// reconcile their submitted transactions without scanning blocks
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct OperationReceipt<Hash, BlockNumber> {
+	/// Per-account receipt nonce, increases with every receipt of the account
+	pub nonce: u32,
+	/// Client supplied correlation id
+	pub correlation_id: Vec<u8>,
+	/// Hash of the dispatched call
+	pub call_hash: Hash,
+	/// Outcome of the dispatched call
+	pub outcome: DispatchResult,
+	/// Block the call was dispatched in
+	pub block: BlockNumber,
+}
@@ -15,6 +15,19 @@ pub struct RoundInfo<BlockNumber> {
 	pub length: u32,
 }
 
+#[derive(Default, Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// Mining resource issued in a round, kept in the bounded round history
+pub struct RoundIssuanceSummary<Balance> {
+	/// Mining resource minted in the round, allocation payouts and mints of minting origins
+	pub total_minted: Balance,
+	/// Mining allocation paid to estate stakers
+	pub land_allocation: Balance,
+	/// Mining allocation paid to metaverse treasuries
+	pub metaverse_allocation: Balance,
+	/// Deployed land units at the end of the round
+	pub land_units: u64,
+}
+
 #[derive(Default, Encode, Decode, RuntimeDebug, TypeInfo)]
 /// Snapshot of estate state at the start of the round for which they are selected
 pub struct StakeSnapshot<AccountId, Balance> {
@@ -53,4 +66,17 @@ impl<B: Copy + sp_std::ops::Add<Output = B> + sp_std::ops::Sub<Output = B> + Fro
 
 pub trait MetaverseStakingTrait<Balance> {
 	fn update_staking_reward(round: RoundIndex, total_reward: Balance) -> DispatchResult;
+
+	fn get_total_staked(round: RoundIndex) -> Balance;
+}
+
+/// Accounts backing a metaverse with their stake, sharing the metaverse mining reward
+pub trait MetaverseBackingTrait<AccountId, Balance> {
+	fn get_metaverse_backers(metaverse_id: MetaverseId) -> Vec<(AccountId, Balance)>;
+}
+
+impl<AccountId, Balance> MetaverseBackingTrait<AccountId, Balance> for () {
+	fn get_metaverse_backers(_metaverse_id: MetaverseId) -> Vec<(AccountId, Balance)> {
+		Vec::new()
+	}
 }
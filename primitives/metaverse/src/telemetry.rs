@@ -0,0 +1,66 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::RuntimeDebug;
+
+/// Chain activity tracked by the telemetry counters
+#[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum ActivityCounter {
+	XcmMessagesProcessed,
+	AuctionsSettled,
+	NftsMinted,
+	LandUnitsDeployed,
+}
+
+/// Chain activity counted over a block
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Clone, Copy, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ActivityCounters {
+	/// Inbound XCM messages executed
+	pub xcm_messages_processed: u32,
+	/// Auctions and buy now listings finalized
+	pub auctions_settled: u32,
+	/// NFTs minted
+	pub nfts_minted: u32,
+	/// Land units minted into metaverses
+	pub land_units_deployed: u32,
+}
+
+impl ActivityCounters {
+	pub fn record(&mut self, counter: ActivityCounter, count: u32) {
+		let value = match counter {
+			ActivityCounter::XcmMessagesProcessed => &mut self.xcm_messages_processed,
+			ActivityCounter::AuctionsSettled => &mut self.auctions_settled,
+			ActivityCounter::NftsMinted => &mut self.nfts_minted,
+			ActivityCounter::LandUnitsDeployed => &mut self.land_units_deployed,
+		};
+		*value = value.saturating_add(count);
+	}
+}
+
+/// Record chain activity for the telemetry counters
+pub trait ActivityRecorder {
+	fn record_activity(counter: ActivityCounter, count: u32);
+}
+
+impl ActivityRecorder for () {
+	fn record_activity(_counter: ActivityCounter, _count: u32) {}
+}
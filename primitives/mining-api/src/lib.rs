@@ -0,0 +1,43 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+use core_primitives::MiningRange;
+use primitives::{staking::RoundIssuanceSummary, Balance, RoundIndex};
+
+sp_api::decl_runtime_apis! {
+	pub trait MiningApi<BlockNumber> where
+		BlockNumber: Codec,
+	{
+		/// Halving epoch of the current block
+		fn current_halving_epoch() -> u32;
+
+		/// Block at which the minting rate halves next, none when halving is disabled
+		fn next_halving_block() -> Option<BlockNumber>;
+
+		/// Issuance summaries of the rounds kept in the round history, most recent first
+		fn round_issuance_history() -> Vec<(RoundIndex, RoundIssuanceSummary<Balance>)>;
+
+		/// Issuance range the next round boundary would compute from the current land circulation
+		/// and mining config
+		fn estimate_next_round_issuance() -> MiningRange<Balance>;
+	}
+}
@@ -0,0 +1,48 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+use primitives::nft::{AttributeValue, WearableSlot};
+use primitives::{ClassId, TokenId};
+
+sp_api::decl_runtime_apis! {
+	pub trait NftApi<AccountId> where
+		AccountId: Codec,
+	{
+		/// Number of tokens of the class carrying each attribute key and value
+		fn class_attribute_frequencies(class_id: ClassId) -> Vec<(Vec<u8>, Vec<u8>, u32)>;
+
+		/// Rarity score of the token computed from the class attribute frequencies, higher is rarer
+		fn token_rarity_score(class_id: ClassId, token_id: TokenId) -> Option<u128>;
+
+		/// Current user of a rented token, None when it is not rented out or the rental expired
+		fn current_user(class_id: ClassId, token_id: TokenId) -> Option<AccountId>;
+
+		/// Whether the token metadata and attributes are frozen, on their own or with the class
+		fn is_metadata_frozen(class_id: ClassId, token_id: TokenId) -> bool;
+
+		/// Typed on-chain attributes of the token with their keys
+		fn typed_attributes(class_id: ClassId, token_id: TokenId) -> Vec<(Vec<u8>, AttributeValue)>;
+
+		/// Wearables equipped on the avatar token by slot
+		fn equipped_items(class_id: ClassId, token_id: TokenId) -> Vec<(WearableSlot, (ClassId, TokenId))>;
+	}
+}
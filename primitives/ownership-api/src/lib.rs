@@ -0,0 +1,36 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+use primitives::ItemId;
+
+sp_api::decl_runtime_apis! {
+	pub trait OwnershipApi<AccountId, Signature> where
+		AccountId: Codec,
+		Signature: Codec,
+	{
+		/// Canonical message the owner signs to prove ownership of the item for the given challenge
+		fn ownership_proof_message(account: AccountId, item: ItemId, challenge: Vec<u8>) -> Vec<u8>;
+
+		/// Check the account currently owns the item and signed the canonical message of the challenge
+		fn verify_ownership_proof(account: AccountId, item: ItemId, signature: Signature, challenge: Vec<u8>) -> bool;
+	}
+}
@@ -0,0 +1,54 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+use primitives::FungibleTokenId;
+
+/// Balance of an account in one currency split by what it is held for. A balance locked for
+/// more than one purpose is counted in each of its buckets
+#[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct BalanceBuckets<Balance> {
+	/// Free and reserved balance
+	pub total: Balance,
+	/// Free balance not frozen by any lock
+	pub liquid: Balance,
+	/// Reserved balance not held by staking
+	pub reserved: Balance,
+	/// Balance staked to the economy, estates and metaverses
+	pub staked: Balance,
+	/// Balance still locked by vesting schedules
+	pub vesting: Balance,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait ReservesApi<AccountId, Balance> where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Balances of each account in every currency it holds, native currency first
+		fn account_balances(accounts: Vec<AccountId>) -> Vec<(AccountId, Vec<(FungibleTokenId, BalanceBuckets<Balance>)>)>;
+	}
+}
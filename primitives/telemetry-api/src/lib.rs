@@ -0,0 +1,35 @@
+// This file is part of Bit.Country.
+
+// Copyright (C) 2020-2021 Bit.Country.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+use primitives::telemetry::ActivityCounters;
+
+sp_api::decl_runtime_apis! {
+	pub trait TelemetryApi<BlockNumber> where
+		BlockNumber: Codec,
+	{
+		/// Activity counted in each recent block kept by the telemetry ring buffer, oldest first
+		fn recent_activity() -> Vec<(BlockNumber, ActivityCounters)>;
+
+		/// Activity summed over the recent blocks kept by the telemetry ring buffer
+		fn recent_activity_totals() -> ActivityCounters;
+	}
+}
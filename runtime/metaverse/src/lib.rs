@@ -66,13 +66,16 @@ use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
 pub use constants::{currency::*, time::*};
-use core_primitives::{NftAssetData, NftClassData};
+use core_primitives::{MetaverseTrait, MiningRange, NFTTrait, NftAssetData, NftClassData};
 // External imports
 use currencies::BasicCurrencyAdapter;
 pub use estate::{MintingRateInfo, Range as MintingRange};
 //use pallet_evm::{EnsureAddressTruncated, HashedAddressMapping};
 use estate::weights::WeightInfo as EstateWeightInfo;
-use primitives::{Amount, Balance, BlockNumber, ClassId, FungibleTokenId, Moment, NftId, RoundIndex};
+use primitives::staking::RoundIssuanceSummary;
+use primitives::telemetry::ActivityCounters;
+use primitives::{Amount, Balance, BlockNumber, ClassId, FungibleTokenId, ItemId, Moment, NftId, RoundIndex};
+use reserves_runtime_api::BalanceBuckets;
 
 // primitives imports
 use crate::opaque::SessionKeys;
@@ -434,7 +437,7 @@ impl orml_tokens::Config for Runtime {
 	type CurrencyId = FungibleTokenId;
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
-	type OnDust = orml_tokens::TransferDust<Runtime, TreasuryModuleAccount>;
+	type OnDust = currencies::DustHandler<Runtime>;
 	type MaxLocks = MaxLocks;
 	type DustRemovalWhitelist = Nothing;
 }
@@ -448,6 +451,8 @@ impl currencies::Config for Runtime {
 	type MultiSocialCurrency = Tokens;
 	type NativeCurrency = BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
 	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type TreasuryAccount = TreasuryModuleAccount;
+	type UpdateOrigin = EnsureRootOrHalfMetaverseCouncil;
 }
 
 parameter_types! {
@@ -456,6 +461,11 @@ parameter_types! {
 	pub MaxBatchTransfer: u32 = 100;
 	pub MaxBatchMinting: u32 = 1000;
 	pub MaxNftMetadata: u32 = 1024;
+	pub MaxRarityAttributes: u32 = 16;
+	pub MaxTypedAttributes: u32 = 32;
+	pub MaxNestingDepth: u32 = 5;
+	// Past the 81 block window of the collective flip randomness
+	pub GenerativeRevealDelay: BlockNumber = 90;
 }
 
 impl nft::Config for Runtime {
@@ -472,6 +482,15 @@ impl nft::Config for Runtime {
 	type MiningResourceId = MiningResourceCurrencyId;
 	type AssetMintingFee = AssetMintingFee;
 	type ClassMintingFee = ClassMintingFee;
+	type ActivityRecorder = Telemetry;
+	type MaxRarityAttributes = MaxRarityAttributes;
+	type MaxTypedAttributes = MaxTypedAttributes;
+	type MaxNestingDepth = MaxNestingDepth;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type Randomness = RandomnessCollectiveFlip;
+	type GenerativeRevealDelay = GenerativeRevealDelay;
+	type OnNftTransfer = Estate;
 }
 
 parameter_types! {
@@ -492,6 +511,13 @@ parameter_types! {
 	pub MaxMetaverseMetadata: u32 = 1024;
 	pub MinContribution: Balance = 1 * DOLLARS;
 	pub MaxNumberOfStakerPerMetaverse: u32 = 512;
+	pub MaxStakingBoost: Perbill = Perbill::from_percent(100);
+	pub const MaxActiveStakingBoosts: u32 = 3;
+	pub const MetaverseSunsetPeriod: BlockNumber = 30 * DAYS;
+	pub const MetaverseBackingUnbondingPeriod: BlockNumber = 7 * DAYS;
+	pub const MaxBackersPerMetaverse: u32 = 512;
+	pub const StaleMetaverseRounds: RoundIndex = 90;
+	pub const StaleMetaverseSlash: Perbill = Perbill::from_percent(50);
 }
 
 impl metaverse::Config for Runtime {
@@ -501,12 +527,22 @@ impl metaverse::Config for Runtime {
 	type MaxMetaverseMetadata = MaxMetaverseMetadata;
 	type MinContribution = MinContribution;
 	type MetaverseCouncil = EnsureRootOrHalfMetaverseCouncil;
+	type MetaverseGovernanceOrigin = governance::EnsureMetaverse;
 	type WeightInfo = weights::module_metaverse::WeightInfo<Runtime>;
 	type MetaverseRegistrationDeposit = MinContribution;
 	type MinStakingAmount = MinContribution;
 	type MaxNumberOfStakersPerMetaverse = MaxNumberOfStakerPerMetaverse;
+	type MaxStakingBoost = MaxStakingBoost;
+	type MaxActiveStakingBoosts = MaxActiveStakingBoosts;
+	type SunsetPeriod = MetaverseSunsetPeriod;
+	type SunsetHandler = (Estate, Continuum);
 	type MultiCurrency = Currencies;
 	type NFTHandler = Nft;
+	type BackingUnbondingPeriod = MetaverseBackingUnbondingPeriod;
+	type MaxBackersPerMetaverse = MaxBackersPerMetaverse;
+	type RoundHandler = Mining;
+	type StaleMetaverseRounds = StaleMetaverseRounds;
+	type StaleMetaverseSlash = StaleMetaverseSlash;
 }
 
 parameter_types! {
@@ -515,6 +551,16 @@ parameter_types! {
 	pub const MinBlocksPerLandIssuanceRound: u32 = 20;
 	pub const MinimumStake: Balance = 100 * DOLLARS;
 	pub const RewardPaymentDelay: u32 = 1;
+	pub const MaxEstateAttributes: u32 = 32;
+	// Court ordered force transfer is only available for enterprise runtimes
+	pub const ForceTransferEnabled: bool = false;
+	pub const MaxCoOwners: u32 = 10;
+	pub const MaxEstateProvenance: u32 = 20;
+	pub const MaxLandUnitLevel: u32 = 10;
+	pub const GiftCardCurrencyId: FungibleTokenId = FungibleTokenId::Stable(0);
+	pub const MaxGiftCardPoolSize: u32 = 100;
+	pub const MaxLeaseExpiriesPerBlock: u32 = 50;
+	pub const CoOwnershipDeadlockPeriod: BlockNumber = 14 * DAYS; // Council can resolve stuck co-owner proposals
 }
 
 impl estate::Config for Runtime {
@@ -530,15 +576,53 @@ impl estate::Config for Runtime {
 	type MinimumStake = MinimumStake;
 	type RewardPaymentDelay = RewardPaymentDelay;
 	type NFTTokenizationSource = Nft;
+	type MaxEstateMetadata = MaxNftMetadata;
+	type MaxEstateAttributes = MaxEstateAttributes;
+	type ForceTransferOrigin = EnsureRoot<AccountId>;
+	type ForceTransferEnabled = ForceTransferEnabled;
+	type MaxCoOwners = MaxCoOwners;
+	type CoOwnershipDeadlockPeriod = CoOwnershipDeadlockPeriod;
+	type MaxEstateProvenance = MaxEstateProvenance;
+	type FungibleTokenCurrency = Currencies;
+	type MiningResourceId = MiningResourceCurrencyId;
+	type MaxLandUnitLevel = MaxLandUnitLevel;
+	type GiftCardCurrencyId = GiftCardCurrencyId;
+	type MaxGiftCardPoolSize = MaxGiftCardPoolSize;
+	type MaxLeaseExpiriesPerBlock = MaxLeaseExpiriesPerBlock;
+	type MiningBurnRecorder = Mining;
+	type ActivityRecorder = Telemetry;
 }
 
 parameter_types! {
 	pub const AuctionTimeToClose: u32 = 100; // Default 100800 Blocks
 	pub const ContinuumSessionDuration: BlockNumber = 100; // Default 43200 Blocks
 	pub const SpotAuctionChillingDuration: BlockNumber = 100; // Default 43200 Blocks
+	pub const ContinuumVoteLockingPeriod: BlockNumber = 100; // Default 43200 Blocks
+	pub const ContinuumStandardVoteWeight: Balance = 1 * DOLLARS;
+	pub const MaxSpotsReleasedPerBlock: u32 = 50;
 	pub const MinimumAuctionDuration: BlockNumber = 30; // Minimum duration is 300 blocks
-	pub const RoyaltyFee: u16 = 10; // Loyalty fee 0.1%
+	pub const NetworkFee: Perbill = Perbill::from_perthousand(1); // Network fee 0.1%
+	pub const MetaverseFeeShare: Perbill = Perbill::from_percent(50);
 	pub const MaxFinality: u32 = 100; // Maximum finalize auctions per block
+	pub const ExpansionRightPeriod: BlockNumber = 10; // Adjacent land owners bid first
+	pub const MaxBundleItems: u32 = 20; // Maximum items listed in a bundle auction
+	pub const ArchiveEraLength: BlockNumber = 7 * DAYS; // Settled auctions are archived per week
+	pub const AuctionRetentionPeriod: BlockNumber = 30 * DAYS; // Settled auctions are kept a month before pruning
+	pub const InstallmentDownPayment: Perbill = Perbill::from_percent(20);
+	pub const InstallmentPeriod: BlockNumber = 7 * DAYS;
+	pub const InstallmentCount: u32 = 12;
+	pub const InstallmentDefaultForfeit: Perbill = Perbill::from_percent(10);
+	pub const SealedBidDeposit: Balance = 1 * DOLLARS;
+	pub const SealedBidRevealPeriod: BlockNumber = 1 * DAYS;
+	pub const AntiSnipeThreshold: BlockNumber = 5 * MINUTES;
+	pub const AntiSnipeExtension: BlockNumber = 10 * MINUTES;
+	pub const MaxAuctionExtensions: u32 = 10;
+	pub const MaxOffersPerItem: u32 = 50;
+	pub const AuctionCancellationPenalty: Perbill = Perbill::from_percent(10);
+	pub const MaxSettlementsPerBlock: u32 = 100;
+	pub const MaxAuctionAllowlistSize: u32 = 1000;
+	pub const MaxRevealSaltLength: u32 = 64;
+	pub const DefaultBidIncrement: Perbill = Perbill::from_percent(5);
 }
 
 impl auction::Config for Runtime {
@@ -551,9 +635,37 @@ impl auction::Config for Runtime {
 	type MetaverseInfoSource = Metaverse;
 	type MinimumAuctionDuration = MinimumAuctionDuration;
 	type EstateHandler = Estate;
-	type RoyaltyFee = RoyaltyFee;
+	type NetworkFee = NetworkFee;
+	type NetworkFeeAccount = TreasuryModuleAccount;
+	type MetaverseFeeShare = MetaverseFeeShare;
 	type MaxFinality = MaxFinality;
 	type NFTHandler = Nft;
+	type ExpansionRightPeriod = ExpansionRightPeriod;
+	// Standalone chain has no partner marketplaces on sibling chains
+	type CrossListingHandler = ();
+	type CrossChainOrigin = frame_system::EnsureNever<(auction_manager::MarketplaceId, AccountId)>;
+	type MaxBundleItems = MaxBundleItems;
+	type ArchiveEraLength = ArchiveEraLength;
+	type AuctionRetentionPeriod = AuctionRetentionPeriod;
+	type InstallmentDownPayment = InstallmentDownPayment;
+	type InstallmentPeriod = InstallmentPeriod;
+	type InstallmentCount = InstallmentCount;
+	type InstallmentDefaultForfeit = InstallmentDefaultForfeit;
+	type SealedBidDeposit = SealedBidDeposit;
+	type SealedBidRevealPeriod = SealedBidRevealPeriod;
+	type AntiSnipeThreshold = AntiSnipeThreshold;
+	type AntiSnipeExtension = AntiSnipeExtension;
+	type MaxAuctionExtensions = MaxAuctionExtensions;
+	type MaxOffersPerItem = MaxOffersPerItem;
+	type AuctionCancellationPenalty = AuctionCancellationPenalty;
+	type MaxSettlementsPerBlock = MaxSettlementsPerBlock;
+	type MaxAuctionAllowlistSize = MaxAuctionAllowlistSize;
+	type UndeployedLandBlockHandler = Estate;
+	type LandSaleOrigin = pallet_collective::EnsureProportionMoreThan<_1, _2, AccountId, CouncilCollective>;
+	type LandSaleTreasury = TreasuryModuleAccount;
+	type MaxRevealSaltLength = MaxRevealSaltLength;
+	type DefaultBidIncrement = DefaultBidIncrement;
+	type ActivityRecorder = Telemetry;
 }
 
 impl continuum::Config for Runtime {
@@ -566,6 +678,13 @@ impl continuum::Config for Runtime {
 	type ContinuumTreasury = MetaverseNetworkTreasuryPalletId;
 	type Currency = Balances;
 	type MetaverseInfoSource = Metaverse;
+	type VoteLockingPeriod = ContinuumVoteLockingPeriod;
+	type StandardVoteWeight = ContinuumStandardVoteWeight;
+	type MaxSpotsReleasedPerBlock = MaxSpotsReleasedPerBlock;
+}
+
+parameter_types! {
+	pub const PoolLiquidityLockPeriod: BlockNumber = 180 * DAYS;
 }
 
 impl tokenization::Config for Runtime {
@@ -577,6 +696,8 @@ impl tokenization::Config for Runtime {
 	type LiquidityPoolManager = Swap;
 	type MinVestedTransfer = MinVestedTransfer;
 	type VestedTransferOrigin = EnsureRootOrMetaverseTreasury;
+	type PoolLiquidityLockPeriod = PoolLiquidityLockPeriod;
+	type MetaverseGovernanceOrigin = governance::EnsureMetaverse;
 }
 
 parameter_types! {
@@ -639,6 +760,25 @@ impl pallet_vesting::Config for Runtime {
 parameter_types! {
 	//Mining Resource Currency Id
 	pub const MiningResourceCurrencyId: FungibleTokenId = FungibleTokenId::MiningResource(0);
+	pub const MinimumIssuanceShare: Perbill = Perbill::from_percent(25);
+	pub const IdealLandParticipation: Perbill = Perbill::from_percent(50);
+	pub const HalvingPeriod: BlockNumber = 4 * 365 * DAYS;
+	pub const MiningRoundHistoryDepth: u32 = 1000;
+	pub const MetaverseBackerRewardShare: Perbill = Perbill::from_percent(50);
+	pub const MaxMiningPayoutsPerBlock: u32 = 200;
+	pub const MaxMiningBoostClasses: u32 = 20;
+}
+
+/// Onboarding quests completed through land deployment and NFT minting
+pub struct OnboardingQuests;
+
+impl primitives::OnboardingQuestTrait<AccountId> for OnboardingQuests {
+	fn is_quest_completed(who: &AccountId, quest: primitives::OnboardingQuest) -> bool {
+		match quest {
+			primitives::OnboardingQuest::DeployFirstLand => Estate::has_deployed_land(who),
+			primitives::OnboardingQuest::MintFirstNft => Nft::has_minted_nft(who),
+		}
+	}
 }
 
 impl mining::Config for Runtime {
@@ -648,10 +788,66 @@ impl mining::Config for Runtime {
 	type BitMiningResourceId = MiningResourceCurrencyId;
 	type EstateHandler = Estate;
 	type AdminOrigin = EnsureRootOrMetaverseTreasury;
-	type MetaverseStakingHandler = Metaverse;
+	// Staking rewards go through reward smoothing reserve before reaching metaverse staking
+	type MetaverseStakingHandler = Economy;
+	type TreasuryAccount = TreasuryModuleAccount;
+	type QuestHandler = OnboardingQuests;
+	type MetaverseInfoSource = Metaverse;
+	type MetaverseBackingHandler = Metaverse;
+	type MetaverseBackerRewardShare = MetaverseBackerRewardShare;
+	type MinimumIssuanceShare = MinimumIssuanceShare;
+	type IdealLandParticipation = IdealLandParticipation;
+	type HalvingPeriod = HalvingPeriod;
+	type IssuancePauseOrigin = EnsureRootOrHalfMetaverseCouncil;
+	type RoundHistoryDepth = MiningRoundHistoryDepth;
+	type NFTHandler = Nft;
+	type MaxMiningPayoutsPerBlock = MaxMiningPayoutsPerBlock;
+	type MaxMiningBoostClasses = MaxMiningBoostClasses;
 	type WeightInfo = (); //weights::module_mining::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const ActivityHistoryLength: u32 = 600;
+}
+
+impl telemetry::Config for Runtime {
+	type ActivityHistoryLength = ActivityHistoryLength;
+}
+
+parameter_types! {
+	pub const MaxCorrelationIdLength: u32 = 64;
+	pub const MaxReceiptsPerAccount: u32 = 100;
+	pub const ReceiptDeposit: Balance = deposit(1, 128);
+}
+
+impl receipts::Config for Runtime {
+	type Event = Event;
+	type Call = Call;
+	type MaxCorrelationIdLength = MaxCorrelationIdLength;
+	type MaxReceiptsPerAccount = MaxReceiptsPerAccount;
+	type Currency = Balances;
+	type ReceiptDeposit = ReceiptDeposit;
+}
+
+parameter_types! {
+	pub const BugBountyPotId: PalletId = PalletId(*b"bit/bbty");
+	pub const BugBountyPayoutPeriod: BlockNumber = 30 * DAYS;
+	pub const MaxBugBountyPayoutPerPeriod: Balance = 100_000 * DOLLARS;
+	pub const BugBountyRefillThreshold: Perbill = Perbill::from_percent(50);
+}
+
+impl bug_bounty::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type BountyPotId = BugBountyPotId;
+	type TreasuryAccount = TreasuryModuleAccount;
+	type GovernanceOrigin = EnsureRootOrHalfMetaverseCouncil;
+	type SecurityCouncilOrigin = EnsureRootOrHalfMetaverseCouncil;
+	type PayoutPeriod = BugBountyPayoutPeriod;
+	type MaxPayoutPerPeriod = MaxBugBountyPayoutPerPeriod;
+	type RefillThreshold = BugBountyRefillThreshold;
+}
+
 parameter_types! {
 	pub const Period: u32 = DAYS;
 	pub const Offset: u32 = 0;
@@ -830,7 +1026,13 @@ impl InstanceFilter<Call> for ProposalType {
 	fn filter(&self, c: &Call) -> bool {
 		match self {
 			ProposalType::Any => true,
-			ProposalType::JustMetaverse => matches!(c, Call::Metaverse(..)),
+			ProposalType::JustMetaverse => matches!(
+				c,
+				Call::Metaverse(..)
+					| Call::Governance(governance::Call::update_referendum_parameters { .. })
+					| Call::Tokenization(tokenization::Call::mint_local_token { .. })
+					| Call::Tokenization(tokenization::Call::burn_local_token { .. })
+			),
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -838,6 +1040,22 @@ impl InstanceFilter<Call> for ProposalType {
 	}
 }
 
+/// Local referendum can only enact calls that affect the metaverse it was raised in
+pub struct LocalProposalScope;
+
+impl governance::MetaverseProposalScope<Call> for LocalProposalScope {
+	fn is_in_scope(call: &Call, metaverse_id: primitives::MetaverseId) -> bool {
+		match call {
+			Call::Metaverse(metaverse::Call::spend_from_treasury { metaverse_id: id, .. })
+			| Call::Metaverse(metaverse::Call::update_metaverse_metadata { metaverse_id: id, .. })
+			| Call::Governance(governance::Call::update_referendum_parameters { metaverse_id: id, .. })
+			| Call::Tokenization(tokenization::Call::mint_local_token { metaverse_id: id, .. })
+			| Call::Tokenization(tokenization::Call::burn_local_token { metaverse_id: id, .. }) => *id == metaverse_id,
+			_ => false,
+		}
+	}
+}
+
 impl governance::Config for Runtime {
 	type Event = Event;
 	type DefaultPreimageByteDeposit = DefaultPreimageByteDeposit;
@@ -857,6 +1075,8 @@ impl governance::Config for Runtime {
 	type MetaverseLandInfo = Estate;
 	type MetaverseCouncil = EnsureRootOrMetaverseTreasury;
 	type ProposalType = ProposalType;
+	type ProposalScope = LocalProposalScope;
+	type MetaverseGovernanceOrigin = governance::EnsureMetaverse;
 }
 
 impl crowdloan::Config for Runtime {
@@ -869,6 +1089,7 @@ impl crowdloan::Config for Runtime {
 parameter_types! {
 	pub const MiningCurrencyId: FungibleTokenId = FungibleTokenId::MiningResource(0);
 	pub const PowerAmountPerBlock: u32 = 100;
+	pub const StakingBlocksPerYear: BlockNumber = 365 * DAYS;
 }
 
 impl economy::Config for Runtime {
@@ -881,12 +1102,25 @@ impl economy::Config for Runtime {
 	type NFTHandler = Nft;
 	type RoundHandler = Mining;
 	type PowerAmountPerBlock = PowerAmountPerBlock;
+	type MetaverseStakingHandler = Metaverse;
+	type BlocksPerYear = StakingBlocksPerYear;
 	type WeightInfo = weights::module_economy::WeightInfo<Runtime>;
+	type MiningBurnRecorder = Mining;
+}
+
+pub struct SafeModeCalls;
+impl Contains<Call> for SafeModeCalls {
+	fn contains(c: &Call) -> bool {
+		// Marketplace is stopped while safe mode is enabled
+		matches!(c, Call::Auction(..))
+	}
 }
 
 impl emergency::Config for Runtime {
 	type Event = Event;
 	type EmergencyOrigin = EnsureRootOrHalfMetaverseCouncil;
+	type InvariantMonitor = (Mining, Auction);
+	type SafeModeCalls = SafeModeCalls;
 }
 
 parameter_types! {
@@ -1074,11 +1308,14 @@ construct_runtime!(
 		Estate: estate::{Pallet, Call, Storage, Event<T>, Config},
 		Economy: economy::{Pallet, Call, Storage, Event<T>},
 		Emergency: emergency::{Pallet, Call, Storage, Event<T>},
+		Telemetry: telemetry::{Pallet, Storage},
+		Receipts: receipts::{Pallet, Call, Storage, Event<T>},
+		BugBounty: bug_bounty::{Pallet, Call, Storage, Event<T>},
 		RewardOracle: orml_oracle::<Instance1>::{Pallet, Storage, Call, Event<T>},
 		OracleMembership: pallet_membership::<Instance1>::{Pallet, Call, Storage, Event<T>, Config<T>},
 
 		// Governance
-		Governance: governance::{Pallet, Call ,Storage, Event<T>},
+		Governance: governance::{Pallet, Call ,Storage, Event<T>, Origin},
 		Democracy: pallet_democracy::{Pallet, Call, Storage, Config<T>, Event<T>},
 
 		// External consensus support
@@ -1182,6 +1419,70 @@ impl fp_self_contained::SelfContainedCall for Call {
 	}
 }
 
+/// Deposits, fees and reserves the call takes from the signer on top of the transaction fee
+fn call_deposit(call: &Call) -> Balance {
+	use frame_support::traits::Get;
+
+	match call {
+		Call::Nft(nft::Call::create_class { .. }) => <Runtime as nft::Config>::ClassMintingFee::get(),
+		Call::Nft(nft::Call::mint { quantity, .. }) => {
+			<Runtime as nft::Config>::AssetMintingFee::get().saturating_mul((*quantity).into())
+		}
+		Call::Metaverse(metaverse::Call::create_metaverse { .. }) => {
+			<Runtime as metaverse::Config>::MinContribution::get()
+		}
+		Call::Metaverse(metaverse::Call::register_metaverse { .. }) => {
+			<Runtime as metaverse::Config>::MetaverseRegistrationDeposit::get()
+		}
+		Call::Auction(auction::Call::bid { value, .. }) => *value,
+		Call::Estate(estate::Call::bond_more { more, .. }) => *more,
+		Call::Estate(estate::Call::accept_lease_offer { deposit, .. }) => *deposit,
+		Call::Estate(estate::Call::top_up_lease_deposit { amount, .. }) => *amount,
+		Call::Estate(estate::Call::fund_estate_loan { principal, .. }) => *principal,
+		Call::Estate(estate::Call::repay_estate_loan { estate_id }) => Estate::get_estate_loan(estate_id)
+			.map(|estate_loan| estate_loan.repayment)
+			.unwrap_or_default(),
+		_ => 0,
+	}
+}
+
+/// Balances of the account in every currency it holds, native currency first
+fn account_balance_buckets(who: &AccountId) -> Vec<(FungibleTokenId, BalanceBuckets<Balance>)> {
+	use frame_support::traits::{Currency, ReservableCurrency};
+
+	let free = Balances::free_balance(who);
+	let reserved = Balances::reserved_balance(who);
+	// Economy and estate stakes are reserved, metaverse stakes are locked
+	let staked_reserved = Economy::get_staking_info(who).saturating_add(Estate::get_account_estate_stake(who));
+	let staked_locked = Metaverse::staking_info(who);
+	let native = BalanceBuckets {
+		total: free.saturating_add(reserved),
+		liquid: Balances::usable_balance(who),
+		reserved: reserved.saturating_sub(staked_reserved),
+		staked: staked_reserved.saturating_add(staked_locked),
+		vesting: Vesting::vesting_balance(who).unwrap_or_default(),
+	};
+
+	let mut balances = vec![(GetNativeCurrencyId::get(), native)];
+	balances.extend(
+		orml_tokens::Accounts::<Runtime>::iter_prefix(who).map(|(currency_id, account)| {
+			let vesting = orml_tokens::Locks::<Runtime>::get(who, currency_id)
+				.iter()
+				.filter(|lock| lock.id == mining::VESTING_LOCK_ID)
+				.fold(0, |locked: Balance, lock| locked.max(lock.amount));
+			let buckets = BalanceBuckets {
+				total: account.free.saturating_add(account.reserved),
+				liquid: account.free.saturating_sub(account.frozen),
+				reserved: account.reserved,
+				staked: 0,
+				vesting,
+			};
+			(currency_id, buckets)
+		}),
+	);
+	balances
+}
+
 impl_runtime_apis! {
 	impl sp_api::Core<Block> for Runtime {
 		fn version() -> RuntimeVersion {
@@ -1461,6 +1762,146 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl fee_info_runtime_api::FeeInfoApi<Block, Balance, Call> for Runtime {
+		fn estimate_call_cost(call: Call) -> fee_info_runtime_api::CallCostInfo<Balance> {
+			use frame_support::weights::GetDispatchInfo;
+
+			let dispatch_info = call.get_dispatch_info();
+			let transaction_fee = TransactionPayment::compute_fee(call.encoded_size() as u32, &dispatch_info, 0);
+
+			fee_info_runtime_api::CallCostInfo {
+				transaction_fee,
+				deposit: call_deposit(&call),
+			}
+		}
+	}
+
+	impl estate_runtime_api::EstateApi<Block, AccountId, BlockNumber> for Runtime {
+		fn estate_provenance(
+			estate_id: primitives::EstateId,
+		) -> Vec<primitives::estate::EstateProvenanceRecord<AccountId, Balance, BlockNumber>> {
+			Estate::get_estate_provenance(estate_id)
+		}
+	}
+
+	impl auction_runtime_api::AuctionRuntimeApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn get_active_auctions(
+			start: primitives::AuctionId,
+			limit: u32,
+		) -> Vec<primitives::auction::ActiveAuctionSummary<AccountId, Balance, BlockNumber>> {
+			Auction::active_auctions(start, limit)
+		}
+	}
+
+	impl receipts_runtime_api::ReceiptsApi<Block, AccountId, Hash, BlockNumber> for Runtime {
+		fn account_receipts(who: AccountId) -> Vec<primitives::receipt::OperationReceipt<Hash, BlockNumber>> {
+			Receipts::account_receipts(&who)
+		}
+	}
+
+	impl telemetry_runtime_api::TelemetryApi<Block, BlockNumber> for Runtime {
+		fn recent_activity() -> Vec<(BlockNumber, ActivityCounters)> {
+			Telemetry::recent_activity()
+		}
+
+		fn recent_activity_totals() -> ActivityCounters {
+			Telemetry::recent_activity_totals()
+		}
+	}
+
+	impl reserves_runtime_api::ReservesApi<Block, AccountId, Balance> for Runtime {
+		fn account_balances(accounts: Vec<AccountId>) -> Vec<(AccountId, Vec<(FungibleTokenId, BalanceBuckets<Balance>)>)> {
+			accounts
+				.into_iter()
+				.map(|account| {
+					let balances = account_balance_buckets(&account);
+					(account, balances)
+				})
+				.collect()
+		}
+	}
+
+	impl nft_runtime_api::NftApi<Block, AccountId> for Runtime {
+		fn class_attribute_frequencies(class_id: ClassId) -> Vec<(Vec<u8>, Vec<u8>, u32)> {
+			Nft::class_attribute_frequencies(class_id)
+		}
+
+		fn token_rarity_score(class_id: ClassId, token_id: NftId) -> Option<u128> {
+			Nft::token_rarity_score((class_id, token_id))
+		}
+
+		fn current_user(class_id: ClassId, token_id: NftId) -> Option<AccountId> {
+			Nft::current_user(&(class_id, token_id))
+		}
+
+		fn is_metadata_frozen(class_id: ClassId, token_id: NftId) -> bool {
+			Nft::is_metadata_frozen(&(class_id, token_id))
+		}
+
+		fn typed_attributes(class_id: ClassId, token_id: NftId) -> Vec<(Vec<u8>, primitives::nft::AttributeValue)> {
+			Nft::typed_attributes(&(class_id, token_id))
+		}
+
+		fn equipped_items(class_id: ClassId, token_id: NftId) -> Vec<(primitives::nft::WearableSlot, (ClassId, NftId))> {
+			Nft::equipped_items(&(class_id, token_id))
+		}
+	}
+
+	impl mining_runtime_api::MiningApi<Block, BlockNumber> for Runtime {
+		fn current_halving_epoch() -> u32 {
+			Mining::current_halving_epoch()
+		}
+
+		fn next_halving_block() -> Option<BlockNumber> {
+			Mining::next_halving_block()
+		}
+
+		fn round_issuance_history() -> Vec<(RoundIndex, RoundIssuanceSummary<Balance>)> {
+			Mining::round_issuance_history()
+		}
+
+		fn estimate_next_round_issuance() -> MiningRange<Balance> {
+			Mining::estimate_next_round_issuance()
+		}
+	}
+
+	impl ownership_runtime_api::OwnershipApi<Block, AccountId, Signature> for Runtime {
+		fn ownership_proof_message(account: AccountId, item: ItemId, challenge: Vec<u8>) -> Vec<u8> {
+			primitives::ownership::ownership_proof_message(&System::block_hash(0), &account, &item, &challenge)
+		}
+
+		fn verify_ownership_proof(account: AccountId, item: ItemId, signature: Signature, challenge: Vec<u8>) -> bool {
+			let message =
+				primitives::ownership::ownership_proof_message(&System::block_hash(0), &account, &item, &challenge);
+			if !primitives::ownership::verify_ownership_signature(&message, &signature, &account) {
+				return false;
+			}
+
+			match item {
+				ItemId::NFT(class_id, token_id) => {
+					<Nft as NFTTrait<AccountId, Balance>>::check_nft_ownership(&account, &(class_id, token_id))
+						.unwrap_or(false)
+				}
+				ItemId::Estate(estate_id) => {
+					<Estate as primitives::estate::Estate<AccountId>>::check_estate_ownership(&account, estate_id)
+						.unwrap_or(false)
+				}
+				ItemId::LandUnit(coordinate, metaverse_id) => {
+					<Estate as primitives::estate::Estate<AccountId>>::check_landunit_ownership(
+						&account,
+						metaverse_id,
+						coordinate,
+					)
+					.unwrap_or(false)
+				}
+				ItemId::Country(metaverse_id) => {
+					<Metaverse as MetaverseTrait<AccountId>>::check_ownership(&account, &metaverse_id)
+				}
+				_ => false,
+			}
+		}
+	}
+
 	impl pallet_contracts_rpc_runtime_api::ContractsApi<Block, AccountId, Balance, BlockNumber, Hash> for Runtime
 	{
 		fn call(
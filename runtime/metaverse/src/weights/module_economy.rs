@@ -33,4 +33,8 @@ impl<T: frame_system::Config> economy::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+
+	fn set_reward_smoothing_config() -> Weight {
+		(9_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
@@ -58,11 +58,15 @@ use xcm_builder::{
 use xcm_executor::{Config, XcmExecutor};
 
 pub use constants::{currency::*, time::*};
-use core_primitives::{NftAssetData, NftClassData};
+use core_primitives::{MetaverseTrait, MiningRange, NFTTrait, NftAssetData, NftClassData};
 // External imports
 use currencies::BasicCurrencyAdapter;
 // XCM Imports
-use primitives::{Amount, ClassId, FungibleTokenId, NftId, TokenSymbol};
+use primitives::auction::MarketplaceId;
+use primitives::staking::RoundIssuanceSummary;
+use primitives::telemetry::{ActivityCounter, ActivityCounters, ActivityRecorder};
+use primitives::{Amount, ClassId, FungibleTokenId, ItemId, NftId, RoundIndex, TokenSymbol};
+use reserves_runtime_api::BalanceBuckets;
 
 use crate::constants::parachains;
 use crate::constants::xcm_fees::{ksm_per_second, native_per_second};
@@ -260,6 +264,30 @@ impl Contains<Call> for BaseFilter {
 	}
 }
 
+/// Calls are allowed unless stopped by the emergency pallet or paused in safe mode
+pub struct EmergencyCallFilter;
+
+impl Contains<Call> for EmergencyCallFilter {
+	fn contains(c: &Call) -> bool {
+		let is_core = matches!(
+			c,
+			// Calls from Sudo
+			Call::Sudo(..)
+			// Calls for runtime upgrade.
+			| Call::System(..)
+			| Call::Timestamp(..)
+			// Calls that are present in each block
+			| Call::ParachainSystem(..)
+		);
+
+		if is_core {
+			return true;
+		}
+
+		!emergency::EmergencyStoppedFilter::<Runtime>::contains(c)
+	}
+}
+
 parameter_types! {
 	pub const Version: RuntimeVersion = VERSION;
 
@@ -328,7 +356,7 @@ impl frame_system::Config for Runtime {
 	/// The weight of database operations that the runtime can invoke.
 	type DbWeight = RocksDbWeight;
 	/// The basic call filter to use in dispatchable.
-	type BaseCallFilter = Everything;
+	type BaseCallFilter = EmergencyCallFilter;
 	/// Weight information for the extrinsics of this pallet.
 	type SystemWeightInfo = ();
 	/// Block & extrinsics weights: base values and limits.
@@ -626,7 +654,7 @@ impl orml_tokens::Config for Runtime {
 	type CurrencyId = FungibleTokenId;
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
-	type OnDust = orml_tokens::TransferDust<Runtime, TreasuryModuleAccount>;
+	type OnDust = currencies::DustHandler<Runtime>;
 	type MaxLocks = MaxLocks;
 	type DustRemovalWhitelist = Nothing;
 }
@@ -637,6 +665,13 @@ parameter_types! {
 	// pub const RelayCurrencyId: CurrencyId = CurrencyId::Token(TokenSymbol::KSM);
 	pub SelfLocation: MultiLocation = MultiLocation::new(1, X1(Parachain(ParachainInfo::parachain_id().into())));
 	pub ParachainAccount: AccountId = ParachainInfo::parachain_id().into_account();
+	pub NftPalletLocation: MultiLocation = MultiLocation::new(
+		1,
+		X2(
+			Parachain(ParachainInfo::parachain_id().into()),
+			PalletInstance(<Nft as frame_support::traits::PalletInfoAccess>::index() as u8)
+		)
+	);
 }
 
 pub fn create_x2_parachain_multilocation(index: u16) -> MultiLocation {
@@ -700,6 +735,8 @@ impl currencies::Config for Runtime {
 	type MultiSocialCurrency = Tokens;
 	type NativeCurrency = BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
 	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type TreasuryAccount = TreasuryModuleAccount;
+	type UpdateOrigin = EnsureRootOrHalfCouncilCollective;
 }
 
 parameter_types! {
@@ -715,7 +752,36 @@ impl cumulus_pallet_parachain_system::Config for Runtime {
 	type OutboundXcmpMessageSource = XcmpQueue;
 	type XcmpMessageHandler = XcmpQueue;
 	type ReservedXcmpWeight = ReservedXcmpWeight;
-	type OnSystemEvent = ();
+	type OnSystemEvent = RelayChainRandomness;
+}
+
+frame_support::generate_storage_alias!(
+	RelayChainRandomness, RelayParentStorageRoot => Value<(Hash, BlockNumber)>
+);
+
+/// Randomness from the relay parent of each block. The relay parent storage root commits to the
+/// relay chain BABE VRF output, it is recorded with the validation data and cannot be known
+/// before the relay parent is built.
+pub struct RelayChainRandomness;
+
+impl cumulus_pallet_parachain_system::OnSystemEvent for RelayChainRandomness {
+	fn on_validation_data(data: &cumulus_primitives_core::PersistedValidationData) {
+		RelayParentStorageRoot::put((data.relay_parent_storage_root, System::block_number()));
+	}
+
+	fn on_validation_code_applied() {}
+}
+
+impl frame_support::traits::Randomness<Hash, BlockNumber> for RelayChainRandomness {
+	fn random(subject: &[u8]) -> (Hash, BlockNumber) {
+		match RelayParentStorageRoot::get() {
+			Some((storage_root, recorded_at)) => (
+				<BlakeTwo256 as sp_runtime::traits::Hash>::hash_of(&(subject, storage_root)),
+				recorded_at,
+			),
+			None => (Hash::default(), 0),
+		}
+	}
 }
 
 impl pallet_randomness_collective_flip::Config for Runtime {}
@@ -814,6 +880,10 @@ pub type LocalAssetTransactor = MultiCurrencyAdapter<
 	DepositToAlternative<TreasuryModuleAccount, Currencies, FungibleTokenId, AccountId, Balance>,
 >;
 
+/// Means for transacting NFTs, reserve-backed for our classes and derivatives for registered
+/// foreign collections.
+pub type NftAssetTransactor = nft::xcm_adapter::NonFungibleAdapter<Runtime, LocationToAccountId, NftPalletLocation>;
+
 /// This is the type we use to convert an (incoming) XCM origin into a local `Origin` instance,
 /// ready for dispatching a transaction with Xcm's `Transact`. There is an `OriginKind` which can
 /// biases the kind of local `Origin` it will become.
@@ -985,9 +1055,11 @@ impl xcm_executor::Config for XcmConfig {
 	type Call = Call;
 	type XcmSender = XcmRouter;
 	// How to withdraw and deposit an asset.
-	type AssetTransactor = LocalAssetTransactor;
+	// NFTs are matched first, the currency adapter would take any asset as an unknown token.
+	type AssetTransactor = (NftAssetTransactor, LocalAssetTransactor);
 	type OriginConverter = XcmOriginToTransactDispatchOrigin;
-	type IsReserve = NativeAsset;
+	// Foreign NFTs are only accepted from the chain of their registered collection.
+	type IsReserve = (NativeAsset, nft::xcm_adapter::ForeignNftReserve<Runtime>);
 	type IsTeleporter = ();
 	// Should be enough to allow teleportation of ROC
 	type LocationInverter = LocationInverter<Ancestry>;
@@ -1061,9 +1133,27 @@ pub type XcmOriginToCallOrigin = (
 	XcmPassthrough<Origin>,
 );
 
+/// Executes inbound XCM and counts processed messages for the chain activity telemetry
+pub struct CountingXcmExecutor<Executor>(sp_std::marker::PhantomData<Executor>);
+
+impl<Executor: ExecuteXcm<Call>> ExecuteXcm<Call> for CountingXcmExecutor<Executor> {
+	fn execute_xcm_in_credit(
+		origin: impl Into<MultiLocation>,
+		message: Xcm<Call>,
+		weight_limit: Weight,
+		weight_credit: Weight,
+	) -> Outcome {
+		let outcome = Executor::execute_xcm_in_credit(origin, message, weight_limit, weight_credit);
+		if !matches!(outcome, Outcome::Error(_)) {
+			Telemetry::record_activity(ActivityCounter::XcmMessagesProcessed, 1);
+		}
+		outcome
+	}
+}
+
 impl cumulus_pallet_xcmp_queue::Config for Runtime {
 	type Event = Event;
-	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmExecutor = CountingXcmExecutor<XcmExecutor<XcmConfig>>;
 	type ChannelInfo = ParachainSystem;
 	type VersionWrapper = PolkadotXcm;
 	type ExecuteOverweightOrigin = EnsureRoot<AccountId>;
@@ -1073,7 +1163,7 @@ impl cumulus_pallet_xcmp_queue::Config for Runtime {
 
 impl cumulus_pallet_dmp_queue::Config for Runtime {
 	type Event = Event;
-	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmExecutor = CountingXcmExecutor<XcmExecutor<XcmConfig>>;
 	type ExecuteOverweightOrigin = EnsureRoot<AccountId>;
 }
 
@@ -1237,6 +1327,25 @@ impl pallet_vesting::Config for Runtime {
 parameter_types! {
 	//Mining Resource Currency Id
 	pub const MiningResourceCurrencyId: FungibleTokenId = FungibleTokenId::MiningResource(0);
+	pub const MinimumIssuanceShare: Perbill = Perbill::from_percent(25);
+	pub const IdealLandParticipation: Perbill = Perbill::from_percent(50);
+	pub const HalvingPeriod: BlockNumber = 4 * 365 * DAYS;
+	pub const MiningRoundHistoryDepth: u32 = 1000;
+	pub const MetaverseBackerRewardShare: Perbill = Perbill::from_percent(50);
+	pub const MaxMiningPayoutsPerBlock: u32 = 200;
+	pub const MaxMiningBoostClasses: u32 = 20;
+}
+
+/// Onboarding quests completed through land deployment and NFT minting
+pub struct OnboardingQuests;
+
+impl primitives::OnboardingQuestTrait<AccountId> for OnboardingQuests {
+	fn is_quest_completed(who: &AccountId, quest: primitives::OnboardingQuest) -> bool {
+		match quest {
+			primitives::OnboardingQuest::DeployFirstLand => Estate::has_deployed_land(who),
+			primitives::OnboardingQuest::MintFirstNft => Nft::has_minted_nft(who),
+		}
+	}
 }
 
 impl mining::Config for Runtime {
@@ -1247,15 +1356,92 @@ impl mining::Config for Runtime {
 	type EstateHandler = Estate;
 	type AdminOrigin = EnsureRootOrMetaverseTreasury;
 	type MetaverseStakingHandler = Metaverse;
+	type TreasuryAccount = TreasuryModuleAccount;
+	type QuestHandler = OnboardingQuests;
+	type MetaverseInfoSource = Metaverse;
+	type MetaverseBackingHandler = Metaverse;
+	type MetaverseBackerRewardShare = MetaverseBackerRewardShare;
+	type MinimumIssuanceShare = MinimumIssuanceShare;
+	type IdealLandParticipation = IdealLandParticipation;
+	type HalvingPeriod = HalvingPeriod;
+	type IssuancePauseOrigin = EnsureRootOrHalfCouncilCollective;
+	type RoundHistoryDepth = MiningRoundHistoryDepth;
+	type NFTHandler = Nft;
+	type MaxMiningPayoutsPerBlock = MaxMiningPayoutsPerBlock;
+	type MaxMiningBoostClasses = MaxMiningBoostClasses;
 	type WeightInfo = (); //weights::module_mining::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub const ActivityHistoryLength: u32 = 600;
+}
+
+impl telemetry::Config for Runtime {
+	type ActivityHistoryLength = ActivityHistoryLength;
+}
+
+parameter_types! {
+	pub const MaxCorrelationIdLength: u32 = 64;
+	pub const MaxReceiptsPerAccount: u32 = 100;
+	pub const ReceiptDeposit: Balance = deposit(1, 128);
+}
+
+impl receipts::Config for Runtime {
+	type Event = Event;
+	type Call = Call;
+	type MaxCorrelationIdLength = MaxCorrelationIdLength;
+	type MaxReceiptsPerAccount = MaxReceiptsPerAccount;
+	type Currency = Balances;
+	type ReceiptDeposit = ReceiptDeposit;
+}
+
+parameter_types! {
+	pub const BugBountyPotId: PalletId = PalletId(*b"bit/bbty");
+	pub const BugBountyPayoutPeriod: BlockNumber = 30 * DAYS;
+	pub const MaxBugBountyPayoutPerPeriod: Balance = 100_000 * DOLLARS;
+	pub const BugBountyRefillThreshold: Perbill = Perbill::from_percent(50);
+}
+
+pub struct SafeModeCalls;
+impl Contains<Call> for SafeModeCalls {
+	fn contains(c: &Call) -> bool {
+		// Marketplace and XCM transfers are stopped while safe mode is enabled
+		matches!(
+			c,
+			Call::Auction(..) | Call::XTokens(..) | Call::PolkadotXcm(..) | Call::OrmlXcm(..)
+		)
+	}
+}
+
+impl emergency::Config for Runtime {
+	type Event = Event;
+	type EmergencyOrigin = EnsureRootOrHalfCouncilCollective;
+	type InvariantMonitor = (Mining, Auction);
+	type SafeModeCalls = SafeModeCalls;
+}
+
+impl bug_bounty::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type BountyPotId = BugBountyPotId;
+	type TreasuryAccount = TreasuryModuleAccount;
+	type GovernanceOrigin = EnsureRootOrHalfCouncilCollective;
+	type SecurityCouncilOrigin = EnsureRootOrHalfTechnicalCommittee;
+	type PayoutPeriod = BugBountyPayoutPeriod;
+	type MaxPayoutPerPeriod = MaxBugBountyPayoutPerPeriod;
+	type RefillThreshold = BugBountyRefillThreshold;
+}
+
 parameter_types! {
 	pub AssetMintingFee: Balance = 1 * DOLLARS;
 	pub ClassMintingFee: Balance = 2 * DOLLARS;
 	pub MaxBatchTransfer: u32 = 100;
 	pub MaxBatchMinting: u32 = 1000;
 	pub MaxNftMetadata: u32 = 1024;
+	pub MaxRarityAttributes: u32 = 16;
+	pub MaxTypedAttributes: u32 = 32;
+	pub MaxNestingDepth: u32 = 5;
+	pub GenerativeRevealDelay: BlockNumber = 10;
 }
 
 impl nft::Config for Runtime {
@@ -1272,6 +1458,15 @@ impl nft::Config for Runtime {
 	type MiningResourceId = MiningResourceCurrencyId;
 	type AssetMintingFee = AssetMintingFee;
 	type ClassMintingFee = ClassMintingFee;
+	type ActivityRecorder = Telemetry;
+	type MaxRarityAttributes = MaxRarityAttributes;
+	type MaxTypedAttributes = MaxTypedAttributes;
+	type MaxNestingDepth = MaxNestingDepth;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type Randomness = RelayChainRandomness;
+	type GenerativeRevealDelay = GenerativeRevealDelay;
+	type OnNftTransfer = Estate;
 }
 
 parameter_types! {
@@ -1292,6 +1487,13 @@ parameter_types! {
 	pub MaxMetaverseMetadata: u32 = 1024;
 	pub MinContribution: Balance = 1 * DOLLARS;
 	pub MaxNumberOfStakersPerMetaverse: u32 = 512;
+	pub MaxStakingBoost: Perbill = Perbill::from_percent(100);
+	pub const MaxActiveStakingBoosts: u32 = 3;
+	pub const MetaverseSunsetPeriod: BlockNumber = 30 * DAYS;
+	pub const MetaverseBackingUnbondingPeriod: BlockNumber = 7 * DAYS;
+	pub const MaxBackersPerMetaverse: u32 = 512;
+	pub const StaleMetaverseRounds: RoundIndex = 90;
+	pub const StaleMetaverseSlash: Perbill = Perbill::from_percent(50);
 }
 
 impl metaverse::Config for Runtime {
@@ -1301,12 +1503,22 @@ impl metaverse::Config for Runtime {
 	type MaxMetaverseMetadata = MaxMetaverseMetadata;
 	type MinContribution = MinContribution;
 	type MetaverseCouncil = EnsureRootOrMetaverseTreasury;
+	type MetaverseGovernanceOrigin = frame_system::EnsureNever<primitives::MetaverseId>;
 	type WeightInfo = weights::module_metaverse::WeightInfo<Runtime>;
 	type MetaverseRegistrationDeposit = MinContribution;
 	type MinStakingAmount = MinContribution;
 	type MaxNumberOfStakersPerMetaverse = MaxNumberOfStakersPerMetaverse;
+	type MaxStakingBoost = MaxStakingBoost;
+	type MaxActiveStakingBoosts = MaxActiveStakingBoosts;
+	type SunsetPeriod = MetaverseSunsetPeriod;
+	type SunsetHandler = (Estate, Continuum);
 	type MultiCurrency = Currencies;
 	type NFTHandler = Nft;
+	type BackingUnbondingPeriod = MetaverseBackingUnbondingPeriod;
+	type MaxBackersPerMetaverse = MaxBackersPerMetaverse;
+	type RoundHandler = Mining;
+	type StaleMetaverseRounds = StaleMetaverseRounds;
+	type StaleMetaverseSlash = StaleMetaverseSlash;
 }
 
 parameter_types! {
@@ -1315,6 +1527,16 @@ parameter_types! {
 	pub const MinBlocksPerLandIssuanceRound: u32 = 20;
 	pub const MinimumStake: Balance = 100 * DOLLARS;
 	pub const RewardPaymentDelay: u32 = 2;
+	pub const MaxEstateAttributes: u32 = 32;
+	// Court ordered force transfer is only available for enterprise runtimes
+	pub const ForceTransferEnabled: bool = false;
+	pub const MaxCoOwners: u32 = 10;
+	pub const MaxEstateProvenance: u32 = 20;
+	pub const MaxLandUnitLevel: u32 = 10;
+	pub const GiftCardCurrencyId: FungibleTokenId = FungibleTokenId::Stable(0);
+	pub const MaxGiftCardPoolSize: u32 = 100;
+	pub const MaxLeaseExpiriesPerBlock: u32 = 50;
+	pub const CoOwnershipDeadlockPeriod: BlockNumber = 14 * DAYS; // Council can resolve stuck co-owner proposals
 }
 
 impl estate::Config for Runtime {
@@ -1330,15 +1552,53 @@ impl estate::Config for Runtime {
 	type MinimumStake = MinimumStake;
 	type RewardPaymentDelay = RewardPaymentDelay;
 	type NFTTokenizationSource = Nft;
+	type MaxEstateMetadata = MaxNftMetadata;
+	type MaxEstateAttributes = MaxEstateAttributes;
+	type ForceTransferOrigin = EnsureRoot<AccountId>;
+	type ForceTransferEnabled = ForceTransferEnabled;
+	type MaxCoOwners = MaxCoOwners;
+	type CoOwnershipDeadlockPeriod = CoOwnershipDeadlockPeriod;
+	type MaxEstateProvenance = MaxEstateProvenance;
+	type FungibleTokenCurrency = Currencies;
+	type MiningResourceId = MiningResourceCurrencyId;
+	type MaxLandUnitLevel = MaxLandUnitLevel;
+	type GiftCardCurrencyId = GiftCardCurrencyId;
+	type MaxGiftCardPoolSize = MaxGiftCardPoolSize;
+	type MaxLeaseExpiriesPerBlock = MaxLeaseExpiriesPerBlock;
+	type MiningBurnRecorder = Mining;
+	type ActivityRecorder = Telemetry;
 }
 
 parameter_types! {
 	pub const AuctionTimeToClose: u32 = 100; // Default 100800 Blocks
 	pub const ContinuumSessionDuration: BlockNumber = 100; // Default 43200 Blocks
 	pub const SpotAuctionChillingDuration: BlockNumber = 100; // Default 43200 Blocks
+	pub const ContinuumVoteLockingPeriod: BlockNumber = 100; // Default 43200 Blocks
+	pub const ContinuumStandardVoteWeight: Balance = 1 * DOLLARS;
+	pub const MaxSpotsReleasedPerBlock: u32 = 50;
 	pub const MinimumAuctionDuration: BlockNumber = 30; // Minimum duration is 300 blocks
-	pub const RoyaltyFee: u16 = 10; // Loyalty fee 0.1%
+	pub const NetworkFee: Perbill = Perbill::from_perthousand(1); // Network fee 0.1%
+	pub const MetaverseFeeShare: Perbill = Perbill::from_percent(50);
 	pub const MaxFinality: u32 = 100; // Maximum finalize auctions per block
+	pub const ExpansionRightPeriod: BlockNumber = 10; // Adjacent land owners bid first
+	pub const MaxBundleItems: u32 = 20; // Maximum items listed in a bundle auction
+	pub const ArchiveEraLength: BlockNumber = 7 * DAYS; // Settled auctions are archived per week
+	pub const AuctionRetentionPeriod: BlockNumber = 30 * DAYS; // Settled auctions are kept a month before pruning
+	pub const InstallmentDownPayment: Perbill = Perbill::from_percent(20);
+	pub const InstallmentPeriod: BlockNumber = 7 * DAYS;
+	pub const InstallmentCount: u32 = 12;
+	pub const InstallmentDefaultForfeit: Perbill = Perbill::from_percent(10);
+	pub const SealedBidDeposit: Balance = 1 * DOLLARS;
+	pub const SealedBidRevealPeriod: BlockNumber = 1 * DAYS;
+	pub const AntiSnipeThreshold: BlockNumber = 5 * MINUTES;
+	pub const AntiSnipeExtension: BlockNumber = 10 * MINUTES;
+	pub const MaxAuctionExtensions: u32 = 10;
+	pub const MaxOffersPerItem: u32 = 50;
+	pub const AuctionCancellationPenalty: Perbill = Perbill::from_percent(10);
+	pub const MaxSettlementsPerBlock: u32 = 100;
+	pub const MaxAuctionAllowlistSize: u32 = 1000;
+	pub const MaxRevealSaltLength: u32 = 64;
+	pub const DefaultBidIncrement: Perbill = Perbill::from_percent(5);
 }
 
 impl auction::Config for Runtime {
@@ -1351,9 +1611,58 @@ impl auction::Config for Runtime {
 	type MetaverseInfoSource = Metaverse;
 	type MinimumAuctionDuration = MinimumAuctionDuration;
 	type EstateHandler = Estate;
-	type RoyaltyFee = RoyaltyFee;
+	type NetworkFee = NetworkFee;
+	type NetworkFeeAccount = TreasuryModuleAccount;
+	type MetaverseFeeShare = MetaverseFeeShare;
 	type MaxFinality = MaxFinality;
 	type NFTHandler = Nft;
+	type ExpansionRightPeriod = ExpansionRightPeriod;
+	type CrossListingHandler = auction::xcm_adapter::XcmCrossListingNotifier<XcmRouter, SelfLocation>;
+	type CrossChainOrigin = EnsureSiblingMarketplace;
+	type MaxBundleItems = MaxBundleItems;
+	type ArchiveEraLength = ArchiveEraLength;
+	type AuctionRetentionPeriod = AuctionRetentionPeriod;
+	type InstallmentDownPayment = InstallmentDownPayment;
+	type InstallmentPeriod = InstallmentPeriod;
+	type InstallmentCount = InstallmentCount;
+	type InstallmentDefaultForfeit = InstallmentDefaultForfeit;
+	type SealedBidDeposit = SealedBidDeposit;
+	type SealedBidRevealPeriod = SealedBidRevealPeriod;
+	type AntiSnipeThreshold = AntiSnipeThreshold;
+	type AntiSnipeExtension = AntiSnipeExtension;
+	type MaxAuctionExtensions = MaxAuctionExtensions;
+	type MaxOffersPerItem = MaxOffersPerItem;
+	type AuctionCancellationPenalty = AuctionCancellationPenalty;
+	type MaxSettlementsPerBlock = MaxSettlementsPerBlock;
+	type MaxAuctionAllowlistSize = MaxAuctionAllowlistSize;
+	type UndeployedLandBlockHandler = Estate;
+	type LandSaleOrigin = EnsureRoot<AccountId>;
+	type LandSaleTreasury = TreasuryModuleAccount;
+	type MaxRevealSaltLength = MaxRevealSaltLength;
+	type DefaultBidIncrement = DefaultBidIncrement;
+	type ActivityRecorder = Telemetry;
+}
+
+/// Partner marketplaces submit remote purchase intents from their sibling parachain origin, paid
+/// by the sovereign account of the sibling chain
+pub struct EnsureSiblingMarketplace;
+
+impl EnsureOrigin<Origin> for EnsureSiblingMarketplace {
+	type Success = (MarketplaceId, AccountId);
+
+	fn try_origin(o: Origin) -> Result<Self::Success, Origin> {
+		Into::<Result<cumulus_pallet_xcm::Origin, Origin>>::into(o).and_then(|o| match o {
+			cumulus_pallet_xcm::Origin::SiblingParachain(para_id) => {
+				Ok((para_id.into(), Sibling::from(para_id).into_account()))
+			}
+			r => Err(Origin::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn successful_origin() -> Origin {
+		Origin::from(cumulus_pallet_xcm::Origin::SiblingParachain(ParaId::from(2000)))
+	}
 }
 
 impl continuum::Config for Runtime {
@@ -1366,6 +1675,13 @@ impl continuum::Config for Runtime {
 	type ContinuumTreasury = MetaverseNetworkTreasuryPalletId;
 	type Currency = Balances;
 	type MetaverseInfoSource = Metaverse;
+	type VoteLockingPeriod = ContinuumVoteLockingPeriod;
+	type StandardVoteWeight = ContinuumStandardVoteWeight;
+	type MaxSpotsReleasedPerBlock = MaxSpotsReleasedPerBlock;
+}
+
+parameter_types! {
+	pub const PoolLiquidityLockPeriod: BlockNumber = 180 * DAYS;
 }
 
 impl tokenization::Config for Runtime {
@@ -1377,6 +1693,8 @@ impl tokenization::Config for Runtime {
 	type LiquidityPoolManager = Swap;
 	type MinVestedTransfer = MinVestedTransfer;
 	type VestedTransferOrigin = EnsureRootOrMetaverseTreasury;
+	type PoolLiquidityLockPeriod = PoolLiquidityLockPeriod;
+	type MetaverseGovernanceOrigin = frame_system::EnsureNever<primitives::MetaverseId>;
 }
 
 parameter_types! {
@@ -1466,6 +1784,7 @@ construct_runtime!(
 		Swap: swap:: {Pallet, Storage ,Event<T>} = 52,
 		Vesting: pallet_vesting::{Pallet, Call ,Storage, Event<T>} = 53,
 		Mining: mining:: {Pallet, Call ,Storage ,Event<T>} = 54,
+		Receipts: receipts::{Pallet, Call, Storage, Event<T>} = 59,
 
 		OrmlNFT: orml_nft::{Pallet, Storage} = 60,
 		Nft: nft::{Call, Pallet, Storage, Event<T>} = 61,
@@ -1473,12 +1792,109 @@ construct_runtime!(
 
 		Continuum: continuum::{Call, Pallet, Storage, Config<T>, Event<T>} = 63,
 		Estate: estate::{Call, Pallet, Storage, Event<T>, Config} = 64,
+		BugBounty: bug_bounty::{Pallet, Call, Storage, Event<T>} = 65,
+		Emergency: emergency::{Pallet, Call, Storage, Event<T>} = 66,
 
 		// Crowdloan
 		Crowdloan: crowdloan::{Pallet, Call, Storage, Event<T>} = 70,
+
+		// Declared last so its on_finalize runs after every other pallet has recorded activity
+		Telemetry: telemetry::{Pallet, Storage} = 58,
 	}
 );
 
+/// Deposits, fees and reserves the call takes from the signer on top of the transaction fee
+fn call_deposit(call: &Call) -> Balance {
+	match call {
+		Call::Nft(nft::Call::create_class { .. }) => <Runtime as nft::Config>::ClassMintingFee::get(),
+		Call::Nft(nft::Call::mint { quantity, .. }) => {
+			<Runtime as nft::Config>::AssetMintingFee::get().saturating_mul((*quantity).into())
+		}
+		Call::Metaverse(metaverse::Call::create_metaverse { .. }) => {
+			<Runtime as metaverse::Config>::MinContribution::get()
+		}
+		Call::Metaverse(metaverse::Call::register_metaverse { .. }) => {
+			<Runtime as metaverse::Config>::MetaverseRegistrationDeposit::get()
+		}
+		Call::Metaverse(metaverse::Call::fund_metaverse_treasury { amount, .. }) => *amount,
+		Call::Democracy(pallet_democracy::Call::propose { value, .. }) => *value,
+		Call::Democracy(pallet_democracy::Call::second { proposal, .. }) => Democracy::deposit_of(proposal)
+			.map(|(_, deposit)| deposit)
+			.unwrap_or_default(),
+		Call::Democracy(pallet_democracy::Call::note_preimage { encoded_proposal }) => {
+			<Runtime as pallet_democracy::Config>::PreimageByteDeposit::get()
+				.saturating_mul(encoded_proposal.len() as Balance)
+		}
+		Call::Auction(auction::Call::bid { value, .. }) => *value,
+		Call::Auction(auction::Call::buy_now { value, .. }) => *value,
+		Call::Auction(auction::Call::buy_now_with_installments { auction_id }) => Auction::get_auction_item(auction_id)
+			.map(|auction_item| <Runtime as auction::Config>::InstallmentDownPayment::get() * auction_item.amount)
+			.unwrap_or_default(),
+		Call::Auction(auction::Call::pay_installment { auction_id }) => Auction::installment_purchases(auction_id)
+			.map(|purchase| {
+				let remaining = purchase.price.saturating_sub(purchase.paid);
+				if purchase.installment.is_zero() {
+					remaining
+				} else {
+					purchase.installment.min(remaining)
+				}
+			})
+			.unwrap_or_default(),
+		Call::Auction(auction::Call::commit_sealed_bid { .. }) => <Runtime as auction::Config>::SealedBidDeposit::get(),
+		Call::Auction(auction::Call::make_offer { value, .. }) => *value,
+		Call::Auction(auction::Call::propose_swap { offered_amount, .. }) => *offered_amount,
+		Call::Auction(auction::Call::accept_swap { swap_id }) => Auction::swap_proposal(swap_id)
+			.map(|swap| swap.requested_amount)
+			.unwrap_or_default(),
+		Call::Estate(estate::Call::bond_more { more, .. }) => *more,
+		Call::Estate(estate::Call::pay_land_tax { estate_id }) => Estate::land_tax_due(*estate_id),
+		Call::Estate(estate::Call::accept_lease_offer { deposit, .. }) => *deposit,
+		Call::Estate(estate::Call::top_up_lease_deposit { amount, .. }) => *amount,
+		Call::Estate(estate::Call::fund_estate_loan { principal, .. }) => *principal,
+		Call::Estate(estate::Call::repay_estate_loan { estate_id }) => Estate::get_estate_loan(estate_id)
+			.map(|estate_loan| estate_loan.repayment)
+			.unwrap_or_default(),
+		_ => 0,
+	}
+}
+
+/// Balances of the account in every currency it holds, native currency first
+fn account_balance_buckets(who: &AccountId) -> Vec<(FungibleTokenId, BalanceBuckets<Balance>)> {
+	use frame_support::traits::{Currency, ReservableCurrency};
+
+	let free = Balances::free_balance(who);
+	let reserved = Balances::reserved_balance(who);
+	// Economy and estate stakes are reserved, metaverse stakes are locked
+	let staked_reserved = Estate::get_account_estate_stake(who);
+	let staked_locked = Metaverse::staking_info(who);
+	let native = BalanceBuckets {
+		total: free.saturating_add(reserved),
+		liquid: Balances::usable_balance(who),
+		reserved: reserved.saturating_sub(staked_reserved),
+		staked: staked_reserved.saturating_add(staked_locked),
+		vesting: Vesting::vesting_balance(who).unwrap_or_default(),
+	};
+
+	let mut balances = vec![(GetNativeCurrencyId::get(), native)];
+	balances.extend(
+		orml_tokens::Accounts::<Runtime>::iter_prefix(who).map(|(currency_id, account)| {
+			let vesting = orml_tokens::Locks::<Runtime>::get(who, currency_id)
+				.iter()
+				.filter(|lock| lock.id == mining::VESTING_LOCK_ID)
+				.fold(0, |locked: Balance, lock| locked.max(lock.amount));
+			let buckets = BalanceBuckets {
+				total: account.free.saturating_add(account.reserved),
+				liquid: account.free.saturating_sub(account.frozen),
+				reserved: account.reserved,
+				staked: 0,
+				vesting,
+			};
+			(currency_id, buckets)
+		}),
+	);
+	balances
+}
+
 impl_runtime_apis! {
 	impl sp_consensus_aura::AuraApi<Block, AuraId> for Runtime {
 		fn slot_duration() -> sp_consensus_aura::SlotDuration {
@@ -1580,6 +1996,146 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl fee_info_runtime_api::FeeInfoApi<Block, Balance, Call> for Runtime {
+		fn estimate_call_cost(call: Call) -> fee_info_runtime_api::CallCostInfo<Balance> {
+			use frame_support::weights::GetDispatchInfo;
+
+			let dispatch_info = call.get_dispatch_info();
+			let transaction_fee = TransactionPayment::compute_fee(call.encoded_size() as u32, &dispatch_info, 0);
+
+			fee_info_runtime_api::CallCostInfo {
+				transaction_fee,
+				deposit: call_deposit(&call),
+			}
+		}
+	}
+
+	impl estate_runtime_api::EstateApi<Block, AccountId, BlockNumber> for Runtime {
+		fn estate_provenance(
+			estate_id: primitives::EstateId,
+		) -> Vec<primitives::estate::EstateProvenanceRecord<AccountId, Balance, BlockNumber>> {
+			Estate::get_estate_provenance(estate_id)
+		}
+	}
+
+	impl auction_runtime_api::AuctionRuntimeApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn get_active_auctions(
+			start: primitives::AuctionId,
+			limit: u32,
+		) -> Vec<primitives::auction::ActiveAuctionSummary<AccountId, Balance, BlockNumber>> {
+			Auction::active_auctions(start, limit)
+		}
+	}
+
+	impl receipts_runtime_api::ReceiptsApi<Block, AccountId, Hash, BlockNumber> for Runtime {
+		fn account_receipts(who: AccountId) -> Vec<primitives::receipt::OperationReceipt<Hash, BlockNumber>> {
+			Receipts::account_receipts(&who)
+		}
+	}
+
+	impl telemetry_runtime_api::TelemetryApi<Block, BlockNumber> for Runtime {
+		fn recent_activity() -> Vec<(BlockNumber, ActivityCounters)> {
+			Telemetry::recent_activity()
+		}
+
+		fn recent_activity_totals() -> ActivityCounters {
+			Telemetry::recent_activity_totals()
+		}
+	}
+
+	impl reserves_runtime_api::ReservesApi<Block, AccountId, Balance> for Runtime {
+		fn account_balances(accounts: Vec<AccountId>) -> Vec<(AccountId, Vec<(FungibleTokenId, BalanceBuckets<Balance>)>)> {
+			accounts
+				.into_iter()
+				.map(|account| {
+					let balances = account_balance_buckets(&account);
+					(account, balances)
+				})
+				.collect()
+		}
+	}
+
+	impl nft_runtime_api::NftApi<Block, AccountId> for Runtime {
+		fn class_attribute_frequencies(class_id: ClassId) -> Vec<(Vec<u8>, Vec<u8>, u32)> {
+			Nft::class_attribute_frequencies(class_id)
+		}
+
+		fn token_rarity_score(class_id: ClassId, token_id: NftId) -> Option<u128> {
+			Nft::token_rarity_score((class_id, token_id))
+		}
+
+		fn current_user(class_id: ClassId, token_id: NftId) -> Option<AccountId> {
+			Nft::current_user(&(class_id, token_id))
+		}
+
+		fn is_metadata_frozen(class_id: ClassId, token_id: NftId) -> bool {
+			Nft::is_metadata_frozen(&(class_id, token_id))
+		}
+
+		fn typed_attributes(class_id: ClassId, token_id: NftId) -> Vec<(Vec<u8>, primitives::nft::AttributeValue)> {
+			Nft::typed_attributes(&(class_id, token_id))
+		}
+
+		fn equipped_items(class_id: ClassId, token_id: NftId) -> Vec<(primitives::nft::WearableSlot, (ClassId, NftId))> {
+			Nft::equipped_items(&(class_id, token_id))
+		}
+	}
+
+	impl mining_runtime_api::MiningApi<Block, BlockNumber> for Runtime {
+		fn current_halving_epoch() -> u32 {
+			Mining::current_halving_epoch()
+		}
+
+		fn next_halving_block() -> Option<BlockNumber> {
+			Mining::next_halving_block()
+		}
+
+		fn round_issuance_history() -> Vec<(RoundIndex, RoundIssuanceSummary<Balance>)> {
+			Mining::round_issuance_history()
+		}
+
+		fn estimate_next_round_issuance() -> MiningRange<Balance> {
+			Mining::estimate_next_round_issuance()
+		}
+	}
+
+	impl ownership_runtime_api::OwnershipApi<Block, AccountId, Signature> for Runtime {
+		fn ownership_proof_message(account: AccountId, item: ItemId, challenge: Vec<u8>) -> Vec<u8> {
+			primitives::ownership::ownership_proof_message(&System::block_hash(0), &account, &item, &challenge)
+		}
+
+		fn verify_ownership_proof(account: AccountId, item: ItemId, signature: Signature, challenge: Vec<u8>) -> bool {
+			let message =
+				primitives::ownership::ownership_proof_message(&System::block_hash(0), &account, &item, &challenge);
+			if !primitives::ownership::verify_ownership_signature(&message, &signature, &account) {
+				return false;
+			}
+
+			match item {
+				ItemId::NFT(class_id, token_id) => {
+					<Nft as NFTTrait<AccountId, Balance>>::check_nft_ownership(&account, &(class_id, token_id))
+						.unwrap_or(false)
+				}
+				ItemId::Estate(estate_id) => {
+					<Estate as primitives::estate::Estate<AccountId>>::check_estate_ownership(&account, estate_id)
+						.unwrap_or(false)
+				}
+				ItemId::LandUnit(coordinate, metaverse_id) => {
+					<Estate as primitives::estate::Estate<AccountId>>::check_landunit_ownership(
+						&account,
+						metaverse_id,
+						coordinate,
+					)
+					.unwrap_or(false)
+				}
+				ItemId::Country(metaverse_id) => {
+					<Metaverse as MetaverseTrait<AccountId>>::check_ownership(&account, &metaverse_id)
+				}
+				_ => false,
+			}
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
@@ -1676,3 +2232,29 @@ cumulus_pallet_parachain_system::register_validate_block! {
 	BlockExecutor = cumulus_pallet_aura_ext::BlockExecutor::<Runtime, Executive>,
 	CheckInherents = CheckInherents,
 }
+
+#[cfg(test)]
+mod tests {
+	use sp_std::any::type_name;
+
+	use super::*;
+
+	#[test]
+	fn telemetry_should_finalize_after_activity_recording_pallets() {
+		// Hooks run in the order pallets are declared, not by pallet index
+		let pallets = type_name::<AllPalletsWithSystem>();
+		let position = |pallet: &str| pallets.find(pallet).expect("pallet is declared in the runtime");
+
+		let telemetry = position(type_name::<Telemetry>());
+		for recorder in &[
+			type_name::<Nft>(),
+			type_name::<Auction>(),
+			type_name::<Estate>(),
+			type_name::<XcmpQueue>(),
+			type_name::<DmpQueue>(),
+		] {
+			assert!(position(recorder) < telemetry, "{} finalizes after telemetry", recorder);
+		}
+		assert_eq!(<Telemetry as frame_support::traits::PalletInfoAccess>::index(), 58);
+	}
+}
@@ -113,4 +113,136 @@ impl<T: frame_system::Config> estate::WeightInfo for WeightInfo<T> {
     fn leave_staking() -> Weight {
         (17_000_000 as Weight).saturating_add(T::DbWeight::get().reads(3 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
     }
+    fn set_land_tax() -> Weight {
+        (9_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn set_land_tax_exemption() -> Weight {
+        (10_000_000 as Weight).saturating_add(T::DbWeight::get().reads(1 as Weight)).saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn pay_land_tax() -> Weight {
+        (27_000_000 as Weight).saturating_add(T::DbWeight::get().reads(5 as Weight)).saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn set_estate_beneficiary() -> Weight {
+        (11_000_000 as Weight).saturating_add(T::DbWeight::get().reads(1 as Weight)).saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn ping() -> Weight {
+        (6_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn claim_inherited_estate() -> Weight {
+        (26_000_000 as Weight).saturating_add(T::DbWeight::get().reads(6 as Weight)).saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn set_estate_metadata() -> Weight {
+        (10_000_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn set_estate_attribute() -> Weight {
+        (12_000_000 as Weight).saturating_add(T::DbWeight::get().reads(3 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn freeze_estate_metadata() -> Weight {
+        (9_000_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn force_transfer_asset() -> Weight {
+        (25_000_000 as Weight).saturating_add(T::DbWeight::get().reads(5 as Weight)).saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn set_estate_co_owners() -> Weight {
+        (25_000_000 as Weight).saturating_add(T::DbWeight::get().reads(5 as Weight)).saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn propose_co_ownership_action() -> Weight {
+        (15_000_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn approve_co_ownership_action() -> Weight {
+        (15_000_000 as Weight).saturating_add(T::DbWeight::get().reads(3 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn resolve_co_ownership_deadlock() -> Weight {
+        (12_000_000 as Weight).saturating_add(T::DbWeight::get().reads(1 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn cancel_co_ownership_action() -> Weight {
+        (10_000_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn set_map_bounds() -> Weight {
+        (9_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn extend_map_bounds() -> Weight {
+        (10_000_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn post_lease_offer() -> Weight {
+        (12_000_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn cancel_lease_offer() -> Weight {
+        (9_000_000 as Weight).saturating_add(T::DbWeight::get().reads(1 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn accept_lease_offer() -> Weight {
+        (25_000_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight)).saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn top_up_lease_deposit() -> Weight {
+        (20_000_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight)).saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn collect_rent() -> Weight {
+        (20_000_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight)).saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn request_estate_loan() -> Weight {
+        (27_000_000 as Weight).saturating_add(T::DbWeight::get().reads(5 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn cancel_estate_loan_request() -> Weight {
+        (20_000_000 as Weight).saturating_add(T::DbWeight::get().reads(1 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn fund_estate_loan() -> Weight {
+        (52_000_000 as Weight).saturating_add(T::DbWeight::get().reads(3 as Weight)).saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn repay_estate_loan() -> Weight {
+        (50_000_000 as Weight).saturating_add(T::DbWeight::get().reads(3 as Weight)).saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn liquidate_estate_loan() -> Weight {
+        (60_000_000 as Weight).saturating_add(T::DbWeight::get().reads(4 as Weight)).saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn set_deployment_whitelist() -> Weight {
+        (21_000_000 as Weight).saturating_add(T::DbWeight::get().reads(1 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn add_deployer() -> Weight {
+        (23_000_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn remove_deployer() -> Weight {
+        (23_000_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn set_land_unit_upgrade_cost() -> Weight {
+        (18_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn upgrade_land_unit() -> Weight {
+        (45_000_000 as Weight).saturating_add(T::DbWeight::get().reads(4 as Weight)).saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn freeze_estate() -> Weight {
+        (24_000_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight)).saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn unfreeze_estate() -> Weight {
+        (22_000_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight)).saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn create_land_gift_card_pool() -> Weight {
+        (30_000_000 as Weight).saturating_add(T::DbWeight::get().reads(3 as Weight)).saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn close_land_gift_card_pool() -> Weight {
+        (30_000_000 as Weight).saturating_add(T::DbWeight::get().reads(3 as Weight)).saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn buy_land_gift_card() -> Weight {
+        (70_000_000 as Weight).saturating_add(T::DbWeight::get().reads(6 as Weight)).saturating_add(T::DbWeight::get().writes(7 as Weight))
+    }
+    fn redeem_land_gift_card() -> Weight {
+        (55_000_000 as Weight).saturating_add(T::DbWeight::get().reads(6 as Weight)).saturating_add(T::DbWeight::get().writes(6 as Weight))
+    }
+    fn refund_expired_land_gift_card() -> Weight {
+        (40_000_000 as Weight).saturating_add(T::DbWeight::get().reads(5 as Weight)).saturating_add(T::DbWeight::get().writes(5 as Weight))
+    }
+    fn revert_sunset_land_units() -> Weight {
+        (60_000_000 as Weight).saturating_add(T::DbWeight::get().reads(6 as Weight)).saturating_add(T::DbWeight::get().writes(6 as Weight))
+    }
+    fn set_estate_royalty() -> Weight {
+        (17_800_000 as Weight).saturating_add(T::DbWeight::get().reads(1 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn set_land_buyer_gate() -> Weight {
+        (21_000_000 as Weight).saturating_add(T::DbWeight::get().reads(1 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn add_land_buyer() -> Weight {
+        (23_000_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn remove_land_buyer() -> Weight {
+        (23_000_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
 }
@@ -86,4 +86,34 @@ impl<T: frame_system::Config> metaverse::WeightInfo for WeightInfo<T> {
             .saturating_add(T::DbWeight::get().reads(7 as Weight))
             .saturating_add(T::DbWeight::get().writes(5 as Weight))
     }
+    fn set_staking_boost_class() -> Weight {
+        (13_000_000 as Weight).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn lock_staking_boost() -> Weight {
+        (42_000_000 as Weight).saturating_add(T::DbWeight::get().reads(5 as Weight)).saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn initiate_metaverse_sunset() -> Weight {
+        (30_000_000 as Weight).saturating_add(T::DbWeight::get().reads(3 as Weight)).saturating_add(T::DbWeight::get().writes(3 as Weight))
+    }
+    fn retire_metaverse() -> Weight {
+        (25_000_000 as Weight).saturating_add(T::DbWeight::get().reads(3 as Weight)).saturating_add(T::DbWeight::get().writes(4 as Weight))
+    }
+    fn fund_metaverse_treasury() -> Weight {
+        (31_000_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight)).saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn spend_from_treasury() -> Weight {
+        (33_000_000 as Weight).saturating_add(T::DbWeight::get().reads(4 as Weight)).saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
+    fn grant_metaverse_role() -> Weight {
+        (18_000_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn revoke_metaverse_role() -> Weight {
+        (17_000_000 as Weight).saturating_add(T::DbWeight::get().reads(2 as Weight)).saturating_add(T::DbWeight::get().writes(1 as Weight))
+    }
+    fn flag_stale_metaverse() -> Weight {
+        (45_000_000 as Weight).saturating_add(T::DbWeight::get().reads(6 as Weight)).saturating_add(T::DbWeight::get().writes(5 as Weight))
+    }
+    fn update_metaverse_metadata() -> Weight {
+        (16_000_000 as Weight).saturating_add(T::DbWeight::get().reads(3 as Weight)).saturating_add(T::DbWeight::get().writes(2 as Weight))
+    }
 }
@@ -1017,7 +1017,7 @@ impl orml_tokens::Config for Runtime {
 	type CurrencyId = FungibleTokenId;
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
-	type OnDust = orml_tokens::TransferDust<Runtime, TreasuryModuleAccount>;
+	type OnDust = currencies::DustHandler<Runtime>;
 	type MaxLocks = MaxLocks;
 	type DustRemovalWhitelist = Nothing;
 }
@@ -1031,12 +1031,35 @@ impl currencies::Config for Runtime {
 	type MultiSocialCurrency = Tokens;
 	type NativeCurrency = BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
 	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type TreasuryAccount = TreasuryModuleAccount;
+	type UpdateOrigin = EnsureRootOrHalfCouncil;
+}
+
+/// BABE VRF output of the parent block. Blocks authored in secondary slots carry no VRF output,
+/// a reveal then waits for a block with one.
+pub struct BabeVrfRandomness;
+
+impl frame_support::traits::Randomness<Hash, BlockNumber> for BabeVrfRandomness {
+	fn random(subject: &[u8]) -> (Hash, BlockNumber) {
+		match <pallet_babe::ParentBlockRandomness<Runtime> as frame_support::traits::Randomness<
+			Option<Hash>,
+			BlockNumber,
+		>>::random(subject)
+		{
+			(Some(random), known_since) => (random, known_since),
+			(None, _) => (Hash::default(), 0),
+		}
+	}
 }
 
 parameter_types! {
 	pub MaxBatchTransfer: u32 = 100;
 	pub MaxBatchMinting: u32 = 1000;
 	pub MaxNftMetadata: u32 = 1024;
+	pub MaxRarityAttributes: u32 = 16;
+	pub MaxTypedAttributes: u32 = 32;
+	pub MaxNestingDepth: u32 = 5;
+	pub GenerativeRevealDelay: BlockNumber = 10;
 	pub PromotionIncentive: Balance = 1 * DOLLARS;
 	pub AssetMintingFee: Balance = 1 * DOLLARS;
 	pub ClassMintingFee: Balance = 2 * DOLLARS;
@@ -1057,6 +1080,15 @@ impl nft::Config for Runtime {
 	type PromotionIncentive = PromotionIncentive;
 	type AssetMintingFee = AssetMintingFee;
 	type ClassMintingFee = ClassMintingFee;
+	type ActivityRecorder = ();
+	type MaxRarityAttributes = MaxRarityAttributes;
+	type MaxTypedAttributes = MaxTypedAttributes;
+	type MaxNestingDepth = MaxNestingDepth;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as traits::Verify>::Signer;
+	type Randomness = BabeVrfRandomness;
+	type GenerativeRevealDelay = GenerativeRevealDelay;
+	type OnNftTransfer = Estate;
 }
 
 parameter_types! {
@@ -1077,6 +1109,13 @@ parameter_types! {
 	pub MaxMetaverseMetadata: u32 = 1024;
 	pub MinContribution: Balance = 1 * DOLLARS;
 	pub MaxNumberOfStakersPerMetaverse: u32 = 512;
+	pub MaxStakingBoost: Perbill = Perbill::from_percent(100);
+	pub const MaxActiveStakingBoosts: u32 = 3;
+	pub const MetaverseSunsetPeriod: BlockNumber = 30 * DAYS;
+	pub const MetaverseBackingUnbondingPeriod: BlockNumber = 7 * DAYS;
+	pub const MaxBackersPerMetaverse: u32 = 512;
+	pub const StaleMetaverseRounds: primitives::RoundIndex = 90;
+	pub const StaleMetaverseSlash: Perbill = Perbill::from_percent(50);
 }
 
 impl metaverse::Config for Runtime {
@@ -1086,12 +1125,22 @@ impl metaverse::Config for Runtime {
 	type MaxMetaverseMetadata = MaxMetaverseMetadata;
 	type MinContribution = MinContribution;
 	type MetaverseCouncil = EnsureRootOrHalfMetaverseCouncil;
+	type MetaverseGovernanceOrigin = governance::EnsureMetaverse;
 	type WeightInfo = weights::module_metaverse::WeightInfo<Runtime>;
 	type MetaverseRegistrationDeposit = MinContribution;
 	type MinStakingAmount = MinContribution;
 	type MaxNumberOfStakersPerMetaverse = MaxNumberOfStakersPerMetaverse;
+	type MaxStakingBoost = MaxStakingBoost;
+	type MaxActiveStakingBoosts = MaxActiveStakingBoosts;
+	type SunsetPeriod = MetaverseSunsetPeriod;
+	type SunsetHandler = (Estate, Continuum);
 	type MultiCurrency = Currencies;
 	type NFTHandler = Nft;
+	type BackingUnbondingPeriod = MetaverseBackingUnbondingPeriod;
+	type MaxBackersPerMetaverse = MaxBackersPerMetaverse;
+	type RoundHandler = Mining;
+	type StaleMetaverseRounds = StaleMetaverseRounds;
+	type StaleMetaverseSlash = StaleMetaverseSlash;
 }
 
 parameter_types! {
@@ -1101,6 +1150,16 @@ parameter_types! {
 	pub const MinimumStake: Balance = 5 * DOLLARS;
 	/// Reward payments are delayed by 2 hours (2 * 300 * block_time)
 	pub const RewardPaymentDelay: u32 = 2;
+	pub const MaxEstateAttributes: u32 = 32;
+	// Court ordered force transfer is only available for enterprise runtimes
+	pub const ForceTransferEnabled: bool = false;
+	pub const MaxCoOwners: u32 = 10;
+	pub const MaxEstateProvenance: u32 = 20;
+	pub const MaxLandUnitLevel: u32 = 10;
+	pub const GiftCardCurrencyId: FungibleTokenId = FungibleTokenId::Stable(0);
+	pub const MaxGiftCardPoolSize: u32 = 100;
+	pub const MaxLeaseExpiriesPerBlock: u32 = 50;
+	pub const CoOwnershipDeadlockPeriod: BlockNumber = 14 * DAYS; // Council can resolve stuck co-owner proposals
 }
 
 impl estate::Config for Runtime {
@@ -1116,14 +1175,52 @@ impl estate::Config for Runtime {
 	type MinimumStake = MinimumStake;
 	type RewardPaymentDelay = RewardPaymentDelay;
 	type NFTTokenizationSource = Nft;
+	type MaxEstateMetadata = MaxNftMetadata;
+	type MaxEstateAttributes = MaxEstateAttributes;
+	type ForceTransferOrigin = EnsureRoot<AccountId>;
+	type ForceTransferEnabled = ForceTransferEnabled;
+	type MaxCoOwners = MaxCoOwners;
+	type CoOwnershipDeadlockPeriod = CoOwnershipDeadlockPeriod;
+	type MaxEstateProvenance = MaxEstateProvenance;
+	type FungibleTokenCurrency = Currencies;
+	type MiningResourceId = MiningResourceCurrencyId;
+	type MaxLandUnitLevel = MaxLandUnitLevel;
+	type GiftCardCurrencyId = GiftCardCurrencyId;
+	type MaxGiftCardPoolSize = MaxGiftCardPoolSize;
+	type MaxLeaseExpiriesPerBlock = MaxLeaseExpiriesPerBlock;
+	type MiningBurnRecorder = Mining;
+	type ActivityRecorder = ();
 }
 
 parameter_types! {
 	pub const AuctionTimeToClose: u32 = 100800; // Default 100800 Blocks
 	pub const ContinuumSessionDuration: BlockNumber = 43200; // Default 43200 Blocks
 	pub const SpotAuctionChillingDuration: BlockNumber = 43200; // Default 43200 Blocks
+	pub const ContinuumVoteLockingPeriod: BlockNumber = 43200; // Default 43200 Blocks
+	pub const ContinuumStandardVoteWeight: Balance = 1 * DOLLARS;
+	pub const MaxSpotsReleasedPerBlock: u32 = 50;
 	pub const MinimumAuctionDuration: BlockNumber = 300; // Minimum duration is 300 blocks
-	pub const RoyaltyFee: u16 = 10; // Loyalty fee 0.1%
+	pub const NetworkFee: Perbill = Perbill::from_perthousand(1); // Network fee 0.1%
+	pub const MetaverseFeeShare: Perbill = Perbill::from_percent(50);
+	pub const ExpansionRightPeriod: BlockNumber = 14400; // Adjacent land owners bid first
+	pub const MaxBundleItems: u32 = 20; // Maximum items listed in a bundle auction
+	pub const ArchiveEraLength: BlockNumber = 7 * DAYS; // Settled auctions are archived per week
+	pub const AuctionRetentionPeriod: BlockNumber = 30 * DAYS; // Settled auctions are kept a month before pruning
+	pub const InstallmentDownPayment: Perbill = Perbill::from_percent(20);
+	pub const InstallmentPeriod: BlockNumber = 7 * DAYS;
+	pub const InstallmentCount: u32 = 12;
+	pub const InstallmentDefaultForfeit: Perbill = Perbill::from_percent(10);
+	pub const SealedBidDeposit: Balance = 1 * DOLLARS;
+	pub const SealedBidRevealPeriod: BlockNumber = 1 * DAYS;
+	pub const AntiSnipeThreshold: BlockNumber = 5 * MINUTES;
+	pub const AntiSnipeExtension: BlockNumber = 10 * MINUTES;
+	pub const MaxAuctionExtensions: u32 = 10;
+	pub const MaxOffersPerItem: u32 = 50;
+	pub const AuctionCancellationPenalty: Perbill = Perbill::from_percent(10);
+	pub const MaxSettlementsPerBlock: u32 = 100;
+	pub const MaxAuctionAllowlistSize: u32 = 1000;
+	pub const MaxRevealSaltLength: u32 = 64;
+	pub const DefaultBidIncrement: Perbill = Perbill::from_percent(5);
 }
 
 impl auction::Config for Runtime {
@@ -1136,7 +1233,35 @@ impl auction::Config for Runtime {
 	type MetaverseInfoSource = Metaverse;
 	type MinimumAuctionDuration = MinimumAuctionDuration;
 	type EstateHandler = Estate;
-	type RoyaltyFee = RoyaltyFee;
+	type NetworkFee = NetworkFee;
+	type NetworkFeeAccount = TreasuryModuleAccount;
+	type MetaverseFeeShare = MetaverseFeeShare;
+	type ExpansionRightPeriod = ExpansionRightPeriod;
+	// Standalone chain has no partner marketplaces on sibling chains
+	type CrossListingHandler = ();
+	type CrossChainOrigin = frame_system::EnsureNever<(auction_manager::MarketplaceId, AccountId)>;
+	type MaxBundleItems = MaxBundleItems;
+	type ArchiveEraLength = ArchiveEraLength;
+	type AuctionRetentionPeriod = AuctionRetentionPeriod;
+	type InstallmentDownPayment = InstallmentDownPayment;
+	type InstallmentPeriod = InstallmentPeriod;
+	type InstallmentCount = InstallmentCount;
+	type InstallmentDefaultForfeit = InstallmentDefaultForfeit;
+	type SealedBidDeposit = SealedBidDeposit;
+	type SealedBidRevealPeriod = SealedBidRevealPeriod;
+	type AntiSnipeThreshold = AntiSnipeThreshold;
+	type AntiSnipeExtension = AntiSnipeExtension;
+	type MaxAuctionExtensions = MaxAuctionExtensions;
+	type MaxOffersPerItem = MaxOffersPerItem;
+	type AuctionCancellationPenalty = AuctionCancellationPenalty;
+	type MaxSettlementsPerBlock = MaxSettlementsPerBlock;
+	type MaxAuctionAllowlistSize = MaxAuctionAllowlistSize;
+	type UndeployedLandBlockHandler = Estate;
+	type LandSaleOrigin = pallet_collective::EnsureProportionMoreThan<_1, _2, AccountId, CouncilCollective>;
+	type LandSaleTreasury = TreasuryModuleAccount;
+	type MaxRevealSaltLength = MaxRevealSaltLength;
+	type DefaultBidIncrement = DefaultBidIncrement;
+	type ActivityRecorder = ();
 }
 
 impl continuum::Config for Runtime {
@@ -1149,6 +1274,13 @@ impl continuum::Config for Runtime {
 	type ContinuumTreasury = MetaverseNetworkTreasuryPalletId;
 	type Currency = Balances;
 	type MetaverseInfoSource = Metaverse;
+	type VoteLockingPeriod = ContinuumVoteLockingPeriod;
+	type StandardVoteWeight = ContinuumStandardVoteWeight;
+	type MaxSpotsReleasedPerBlock = MaxSpotsReleasedPerBlock;
+}
+
+parameter_types! {
+	pub const PoolLiquidityLockPeriod: BlockNumber = 180 * DAYS;
 }
 
 impl tokenization::Config for Runtime {
@@ -1160,6 +1292,8 @@ impl tokenization::Config for Runtime {
 	type LiquidityPoolManager = Swap;
 	type MinVestedTransfer = MinVestedTransfer;
 	type VestedTransferOrigin = EnsureRootOrMetaverseTreasury;
+	type PoolLiquidityLockPeriod = PoolLiquidityLockPeriod;
+	type MetaverseGovernanceOrigin = governance::EnsureMetaverse;
 }
 
 parameter_types! {
@@ -1208,6 +1342,25 @@ pub type EnsureRootOrHalfMetaverseCouncil = EnsureOneOf<
 parameter_types! {
 	//Mining Resource Currency Id
 	pub const MiningResourceCurrencyId: FungibleTokenId = FungibleTokenId::MiningResource(0);
+	pub const MinimumIssuanceShare: Perbill = Perbill::from_percent(25);
+	pub const IdealLandParticipation: Perbill = Perbill::from_percent(50);
+	pub const HalvingPeriod: BlockNumber = 4 * 365 * DAYS;
+	pub const MiningRoundHistoryDepth: u32 = 1000;
+	pub const MetaverseBackerRewardShare: Perbill = Perbill::from_percent(50);
+	pub const MaxMiningPayoutsPerBlock: u32 = 200;
+	pub const MaxMiningBoostClasses: u32 = 20;
+}
+
+/// Onboarding quests completed through land deployment and NFT minting
+pub struct OnboardingQuests;
+
+impl primitives::OnboardingQuestTrait<AccountId> for OnboardingQuests {
+	fn is_quest_completed(who: &AccountId, quest: primitives::OnboardingQuest) -> bool {
+		match quest {
+			primitives::OnboardingQuest::DeployFirstLand => Estate::has_deployed_land(who),
+			primitives::OnboardingQuest::MintFirstNft => Nft::has_minted_nft(who),
+		}
+	}
 }
 
 impl mining::Config for Runtime {
@@ -1216,6 +1369,19 @@ impl mining::Config for Runtime {
 	type BitMiningTreasury = BitMiningTreasury;
 	type BitMiningResourceId = MiningResourceCurrencyId;
 	type AdminOrigin = EnsureRootOrMetaverseTreasury;
+	type TreasuryAccount = TreasuryModuleAccount;
+	type QuestHandler = OnboardingQuests;
+	type MetaverseInfoSource = Metaverse;
+	type MetaverseBackingHandler = Metaverse;
+	type MetaverseBackerRewardShare = MetaverseBackerRewardShare;
+	type MinimumIssuanceShare = MinimumIssuanceShare;
+	type IdealLandParticipation = IdealLandParticipation;
+	type HalvingPeriod = HalvingPeriod;
+	type IssuancePauseOrigin = EnsureRootOrHalfCouncil;
+	type RoundHistoryDepth = MiningRoundHistoryDepth;
+	type NFTHandler = Nft;
+	type MaxMiningPayoutsPerBlock = MaxMiningPayoutsPerBlock;
+	type MaxMiningBoostClasses = MaxMiningBoostClasses;
 }
 
 parameter_types! {
@@ -1305,7 +1471,13 @@ impl InstanceFilter<Call> for ProposalType {
 	fn filter(&self, c: &Call) -> bool {
 		match self {
 			ProposalType::Any => true,
-			ProposalType::JustMetaverse => matches!(c, Call::Metaverse(..)),
+			ProposalType::JustMetaverse => matches!(
+				c,
+				Call::Metaverse(..)
+					| Call::Governance(governance::Call::update_referendum_parameters { .. })
+					| Call::Tokenization(tokenization::Call::mint_local_token { .. })
+					| Call::Tokenization(tokenization::Call::burn_local_token { .. })
+			),
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -1313,6 +1485,22 @@ impl InstanceFilter<Call> for ProposalType {
 	}
 }
 
+/// Local referendum can only enact calls that affect the metaverse it was raised in
+pub struct LocalProposalScope;
+
+impl governance::MetaverseProposalScope<Call> for LocalProposalScope {
+	fn is_in_scope(call: &Call, metaverse_id: primitives::MetaverseId) -> bool {
+		match call {
+			Call::Metaverse(metaverse::Call::spend_from_treasury { metaverse_id: id, .. })
+			| Call::Metaverse(metaverse::Call::update_metaverse_metadata { metaverse_id: id, .. })
+			| Call::Governance(governance::Call::update_referendum_parameters { metaverse_id: id, .. })
+			| Call::Tokenization(tokenization::Call::mint_local_token { metaverse_id: id, .. })
+			| Call::Tokenization(tokenization::Call::burn_local_token { metaverse_id: id, .. }) => *id == metaverse_id,
+			_ => false,
+		}
+	}
+}
+
 impl governance::Config for Runtime {
 	type Event = Event;
 	type DefaultPreimageByteDeposit = DefaultPreimageByteDeposit;
@@ -1332,6 +1520,8 @@ impl governance::Config for Runtime {
 	type MetaverseLandInfo = Estate;
 	type MetaverseCouncil = EnsureRootOrMetaverseTreasury;
 	type ProposalType = ProposalType;
+	type ProposalScope = LocalProposalScope;
+	type MetaverseGovernanceOrigin = governance::EnsureMetaverse;
 }
 
 construct_runtime!(
@@ -1389,7 +1579,7 @@ construct_runtime!(
 
 		// Governance
 		Council: pallet_collective::<Instance1>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>},
-		Governance: governance::{Pallet, Call ,Storage, Event<T>},
+		Governance: governance::{Pallet, Call ,Storage, Event<T>, Origin},
 		Democracy: pallet_democracy::{Pallet, Call, Storage, Config<T>, Event<T>},
 
 		// External consensus support
@@ -1,124 +1,57 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Decode, Encode};
+use frame_support::{traits::ConstU32, BoundedVec};
+use impl_trait_for_tuples::impl_for_tuples;
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use sp_runtime::{DispatchError, DispatchResult, Perbill, RuntimeDebug};
+use sp_runtime::{DispatchError, Perbill, RuntimeDebug};
 use sp_std::{collections::btree_map::BTreeMap, prelude::*, vec::Vec};
 
 use primitives::staking::RoundInfo;
 use primitives::{
-	AssetId, ClassId, FungibleTokenId, GroupCollectionId, ItemId, MetaverseId, TokenId, UndeployedLandBlockId,
-	UndeployedLandBlockType,
+	AssetId, Balance, ClassId, FungibleTokenId, ItemId, MetaverseId, UndeployedLandBlockId, UndeployedLandBlockType,
 };
 
-#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-pub enum TokenType {
-	Transferable,
-	BoundToAddress,
-}
-
-impl TokenType {
-	pub fn is_transferable(&self) -> bool {
-		match *self {
-			TokenType::Transferable => true,
-			_ => false,
-		}
-	}
-}
-
-impl Default for TokenType {
-	fn default() -> Self {
-		TokenType::Transferable
-	}
-}
-
-#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-pub enum CollectionType {
-	Collectable,
-	Wearable,
-	Executable(Vec<u8>),
-}
-
-// Collection extension for fast retrieval
-impl CollectionType {
-	pub fn is_collectable(&self) -> bool {
-		match *self {
-			CollectionType::Collectable => true,
-			_ => false,
-		}
-	}
-
-	pub fn is_executable(&self) -> bool {
-		match *self {
-			CollectionType::Executable(_) => true,
-			_ => false,
-		}
-	}
-
-	pub fn is_wearable(&self) -> bool {
-		match *self {
-			CollectionType::Wearable => true,
-			_ => false,
-		}
-	}
-}
-
-impl Default for CollectionType {
-	fn default() -> Self {
-		CollectionType::Collectable
-	}
-}
+pub use primitives::nft::{
+	AttributeValue, CollectionType, MintVoucher, NFTTrait, NftAirdrop, NftAssetData, NftClassData, NftClassDataV1,
+	NftGroupCollectionData, OnNftTransfer, TokenType, WearableSlot,
+};
 
 pub type NftMetadata = Vec<u8>;
 pub type Attributes = BTreeMap<Vec<u8>, Vec<u8>>;
 
-#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
-pub struct NftGroupCollectionData {
-	pub name: NftMetadata,
-	// Metadata from ipfs
-	pub properties: NftMetadata,
-}
-
-#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
-#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-pub struct NftClassData<Balance> {
-	// Minimum balance to create a collection of Asset
-	pub deposit: Balance,
-	pub attributes: Attributes,
-	pub token_type: TokenType,
-	pub collection_type: CollectionType,
-	pub is_locked: bool,
-	pub royalty_fee: Perbill,
-}
-
-#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
-#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-pub struct NftClassDataV1<Balance> {
-	// Minimum balance to create a collection of Asset
-	pub deposit: Balance,
-	pub attributes: Attributes,
-	pub token_type: TokenType,
-	pub collection_type: CollectionType,
-}
-
-#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo)]
-#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-pub struct NftAssetData<Balance> {
-	// Deposit balance to create each token
-	pub deposit: Balance,
-	pub attributes: Attributes,
-}
-
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct MetaverseAssetData {
 	pub image: Vec<u8>,
 }
 
-pub type MetaverseMetadata = Vec<u8>;
+/// Version of the metaverse metadata schema
+pub const METAVERSE_METADATA_VERSION: u8 = 1;
+/// Schema version of metadata migrated from the legacy opaque bytes
+pub const LEGACY_METAVERSE_METADATA_VERSION: u8 = 0;
+
+/// Metaverse name, up to 64 bytes
+pub type MetaverseName = BoundedVec<u8, ConstU32<64>>;
+/// IPFS content identifier, up to 64 bytes
+pub type ContentId = BoundedVec<u8, ConstU32<64>>;
+/// External URL, up to 256 bytes
+pub type ExternalUrl = BoundedVec<u8, ConstU32<256>>;
+
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+pub struct MetaverseMetadata {
+	/// Version of the schema the metadata follows
+	pub version: u8,
+	/// Display name of the metaverse
+	pub name: MetaverseName,
+	/// Content identifier of the metaverse description
+	pub description: ContentId,
+	/// Content identifier of the cover image
+	pub cover_image: ContentId,
+	/// Website of the metaverse
+	pub external_url: ExternalUrl,
+}
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct MetaverseInfo<AccountId> {
@@ -132,6 +65,15 @@ pub struct MetaverseInfo<AccountId> {
 	pub is_frozen: bool,
 }
 
+/// Metaverse info stored with opaque metadata bytes, before the versioned metadata schema
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct MetaverseInfoV0<AccountId> {
+	pub owner: AccountId,
+	pub metadata: Vec<u8>,
+	pub currency_id: FungibleTokenId,
+	pub is_frozen: bool,
+}
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct MetaverseFund<AccountId, Balance> {
 	/// The fund account of this metaverse
@@ -144,6 +86,17 @@ pub struct MetaverseFund<AccountId, Balance> {
 	pub currency_id: FungibleTokenId,
 }
 
+/// Metaverse privileges the owner can grant to other accounts, the owner always holds them all
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum MetaverseRole {
+	/// Moderates content published in the metaverse
+	Moderator,
+	/// Deploys land blocks and builds estates in the metaverse
+	Builder,
+	/// Spends from the metaverse treasury
+	Treasurer,
+}
+
 pub trait MetaverseTrait<AccountId> {
 	/// Check the ownership of this metaverse
 	fn check_ownership(who: &AccountId, metaverse_id: &MetaverseId) -> bool;
@@ -157,6 +110,30 @@ pub trait MetaverseTrait<AccountId> {
 	fn get_metaverse_land_class(metaverse_id: MetaverseId) -> ClassId;
 	/// Get the estate class for a specific metaverse
 	fn get_metaverse_estate_class(metaverse_id: MetaverseId) -> ClassId;
+	/// Get the treasury account of a specific metaverse
+	fn get_metaverse_treasury(metaverse_id: MetaverseId) -> AccountId;
+	/// Check if the metaverse is being decommissioned or already retired
+	fn is_metaverse_sunset(_metaverse_id: MetaverseId) -> bool {
+		false
+	}
+	/// Check if the account is the metaverse owner or has been granted the role
+	fn has_metaverse_role(who: &AccountId, metaverse_id: &MetaverseId, _role: MetaverseRole) -> bool {
+		Self::check_ownership(who, metaverse_id)
+	}
+	/// Record activity in the metaverse, metaverses without activity can be flagged as stale
+	fn record_metaverse_activity(_metaverse_id: MetaverseId) {}
+}
+
+/// Wind down activity which can not outlive a metaverse entering sunset
+pub trait MetaverseSunsetHandler {
+	fn on_metaverse_sunset(metaverse_id: MetaverseId);
+}
+
+#[impl_for_tuples(5)]
+impl MetaverseSunsetHandler for Tuple {
+	fn on_metaverse_sunset(metaverse_id: MetaverseId) {
+		for_tuples!( #( Tuple::on_metaverse_sunset(metaverse_id); )* );
+	}
 }
 
 pub trait MetaverseLandTrait<AccountId> {
@@ -164,6 +141,8 @@ pub trait MetaverseLandTrait<AccountId> {
 	fn get_user_land_units(who: &AccountId, metaverse_id: &MetaverseId) -> Vec<(i32, i32)>;
 	/// Check if this user own the metaverse
 	fn is_user_own_metaverse_land(who: &AccountId, metaverse_id: &MetaverseId) -> bool;
+	/// Get number of land units deployed in the metaverse
+	fn get_metaverse_land_units_count(metaverse_id: &MetaverseId) -> u64;
 }
 
 pub trait UndeployedLandBlocksTrait<AccountId> {
@@ -189,57 +168,38 @@ pub trait UndeployedLandBlocksTrait<AccountId> {
 	) -> Result<UndeployedLandBlockId, DispatchError>;
 }
 
-pub trait NFTTrait<AccountId, Balance> {
-	/// Token identifier
-	type TokenId;
-	/// Token class identifier
-	type ClassId;
-	/// Check the ownership of this nft asset
-	fn check_ownership(who: &AccountId, asset_id: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError>;
-	/// Check the ownership of this nft tuple
-	fn check_nft_ownership(who: &AccountId, nft: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError>;
-	/// Get the detail of this nft
-	fn get_nft_detail(asset_id: (Self::ClassId, Self::TokenId)) -> Result<NftClassData<Balance>, DispatchError>;
-	/// Get the detail of this nft
-	fn get_nft_group_collection(nft_collection: &Self::ClassId) -> Result<GroupCollectionId, DispatchError>;
-	/// Check if collection and class exist
-	fn check_collection_and_class(
-		collection_id: GroupCollectionId,
-		class_id: Self::ClassId,
-	) -> Result<bool, DispatchError>;
-	/// Create NFT token class
-	fn create_token_class(
-		sender: &AccountId,
-		metadata: NftMetadata,
-		attributes: Attributes,
-		collection_id: GroupCollectionId,
-		token_type: TokenType,
-		collection_type: CollectionType,
-		royalty_fee: Perbill,
-	) -> Result<ClassId, DispatchError>;
-	/// Mint NFT token
-	fn mint_token(
-		sender: &AccountId,
-		class_id: ClassId,
-		metadata: NftMetadata,
-		attributes: Attributes,
-	) -> Result<TokenId, DispatchError>;
-	/// Burn nft
-	fn burn_nft(account: &AccountId, nft: &(Self::ClassId, Self::TokenId)) -> DispatchResult;
-	/// Check if item is on listing
-	fn check_item_on_listing(class_id: Self::ClassId, token_id: Self::TokenId) -> Result<bool, DispatchError>;
-	/// transfer nft
-	fn transfer_nft(sender: &AccountId, to: &AccountId, nft: &(Self::ClassId, Self::TokenId)) -> DispatchResult;
-	/// Is Nft transferable
-	fn is_transferable(nft: &(Self::ClassId, Self::TokenId)) -> Result<bool, DispatchError>;
-	/// Get collection account fund
-	fn get_class_fund(class_id: &Self::ClassId) -> AccountId;
-}
-
 pub trait RoundTrait<BlockNumber> {
 	fn get_current_round_info() -> RoundInfo<BlockNumber>;
 }
 
+/// Runtime invariant checked at block finalization, a violation enables safe mode
+pub trait InvariantMonitor {
+	/// Name of the violated invariant, if any. One-off violations are consumed by the check.
+	fn violated_invariant() -> Option<Vec<u8>>;
+}
+
+#[impl_for_tuples(5)]
+impl InvariantMonitor for Tuple {
+	fn violated_invariant() -> Option<Vec<u8>> {
+		let mut violated = None;
+		for_tuples!( #(
+			if let Some(invariant) = Tuple::violated_invariant() {
+				violated = violated.or(Some(invariant));
+			}
+		)* );
+		violated
+	}
+}
+
+/// Mining resource burned outside of the mining pallet, counted towards its total burned
+pub trait MiningBurnRecorder {
+	fn record_mining_burn(amount: Balance);
+}
+
+impl MiningBurnRecorder for () {
+	fn record_mining_burn(_amount: Balance) {}
+}
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
 pub struct MiningRange<T> {
@@ -273,9 +233,9 @@ impl<T: Ord + Copy> From<T> for MiningRange<T> {
 pub struct MiningResourceRateInfo {
 	/// annual inflation rate
 	pub rate: Perbill,
-	/// land staking reward percentage (4 decimals)
+	/// land staking share of round issuance
 	pub staking_reward: Perbill,
-	/// metaverse staking reward percentage (4 decimals)
+	/// metaverse staking share of round issuance
 	pub mining_reward: Perbill,
 }
 
@@ -288,6 +248,12 @@ impl MiningResourceRateInfo {
 		}
 	}
 
+	/// Staking and mining shares must split the whole round issuance
+	pub fn is_valid(&self) -> bool {
+		(self.staking_reward.deconstruct() as u64).saturating_add(self.mining_reward.deconstruct() as u64)
+			== Perbill::one().deconstruct() as u64
+	}
+
 	/// kBIT and Land unit ratio
 	pub fn set_rate(&mut self, rate: Perbill) {
 		self.rate = rate;